@@ -0,0 +1,21 @@
+use serde::{Serialize, de::DeserializeOwned};
+use vpp_api_encoding::wire::{VppDecode, VppEncode};
+
+/// Decodes `T` from untrusted bytes the way the transports do, and encodes it again.
+///
+/// Both the bincode path and the wire codec are exercised. Decoding may fail,
+/// but neither step may panic.
+pub fn decode_and_reencode<T>(data: &[u8])
+where
+    T: Serialize + DeserializeOwned + VppEncode + VppDecode,
+{
+    let config = bincode_next::config::legacy()
+        .with_big_endian()
+        .with_fixed_int_encoding();
+    if let Ok((value, _)) = bincode_next::serde::decode_from_slice::<T, _>(data, config) {
+        let _ = bincode_next::serde::encode_to_vec(&value, config);
+    }
+    if let Ok((value, _)) = vpp_api_encoding::wire::decode_from_slice::<T>(data) {
+        let _ = vpp_api_encoding::wire::encode_to_vec(&value);
+    }
+}
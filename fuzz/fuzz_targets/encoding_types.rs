@@ -0,0 +1,24 @@
+#![no_main]
+
+mod common;
+
+use common::decode_and_reencode;
+use libfuzzer_sys::fuzz_target;
+use typenum::{U4, U16, U64};
+use vpp_api_client::interface_types::{IfStatusFlags, SubIfFlags};
+use vpp_api_client::tunnel_types::TunnelEncapDecapFlags;
+use vpp_api_encoding::typ::*;
+
+fuzz_target!(|data: &[u8]| {
+    decode_and_reencode::<FixedSizeString<U64>>(data);
+    decode_and_reencode::<VariableSizeString>(data);
+    decode_and_reencode::<F64>(data);
+    decode_and_reencode::<FixedSizeArray<u8, U16>>(data);
+    decode_and_reencode::<FixedSizeArray<u32, U4>>(data);
+    decode_and_reencode::<VariableSizeArray<u8>>(data);
+    decode_and_reencode::<VariableSizeArray<u32>>(data);
+    decode_and_reencode::<VariableSizeArray<VariableSizeString>>(data);
+    decode_and_reencode::<EnumFlag<IfStatusFlags>>(data);
+    decode_and_reencode::<EnumFlag<SubIfFlags>>(data);
+    decode_and_reencode::<EnumFlag<TunnelEncapDecapFlags>>(data);
+});
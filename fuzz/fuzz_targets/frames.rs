@@ -0,0 +1,27 @@
+#![no_main]
+
+use bytes::BytesMut;
+use libfuzzer_sys::fuzz_target;
+use tokio_util::codec::Decoder;
+use vpp_api_transport::VppFrameCodec;
+
+// Feeds the socket framing arbitrary bytes, split into reads at arbitrary points.
+fuzz_target!(|input: (Vec<u8>, Vec<u16>)| {
+    let (data, splits) = input;
+    let mut codec = VppFrameCodec::with_max_length(1 << 16);
+    let mut src = BytesMut::new();
+    let mut rest = &data[..];
+    for split in splits.into_iter().chain([u16::MAX]) {
+        let (read, next) = rest.split_at((split as usize).min(rest.len()));
+        src.extend_from_slice(read);
+        rest = next;
+        loop {
+            match codec.decode(&mut src) {
+                Ok(Some(_)) => continue,
+                Ok(None) => break,
+                Err(_) => return,
+            }
+        }
+    }
+    let _ = codec.decode_eof(&mut src);
+});
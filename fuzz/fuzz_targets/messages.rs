@@ -0,0 +1,23 @@
+#![no_main]
+
+mod common;
+
+use common::decode_and_reencode;
+use libfuzzer_sys::fuzz_target;
+use vpp_api_client::acl::AclDetails;
+use vpp_api_client::interface::{SwInterfaceDetails, SwInterfaceEvent, SwInterfaceSetFlags};
+use vpp_api_client::ip::{IpAddressDetails, IpRouteDetails};
+use vpp_api_client::vlib::CliInbandReply;
+use vpp_api_client::vpe::ShowVersionReply;
+
+// A sample of generated messages covering strings, arrays, unions, enums and flags.
+fuzz_target!(|data: &[u8]| {
+    decode_and_reencode::<ShowVersionReply>(data);
+    decode_and_reencode::<CliInbandReply>(data);
+    decode_and_reencode::<SwInterfaceSetFlags>(data);
+    decode_and_reencode::<SwInterfaceEvent>(data);
+    decode_and_reencode::<SwInterfaceDetails>(data);
+    decode_and_reencode::<IpAddressDetails>(data);
+    decode_and_reencode::<IpRouteDetails>(data);
+    decode_and_reencode::<AclDetails>(data);
+});
@@ -41,6 +41,7 @@ generate_api() {
         --parse-type "Tree" \
         --package-name "${VERSION}" \
         --package-path "${DEST}" \
+        --errno-file "${WORKSPACE_ROOT}/vpp-api-transport/src/api_errno.rs" \
         --print-message-names \
         --create-binding \
         --create-package \
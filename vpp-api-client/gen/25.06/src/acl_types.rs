@@ -2,18 +2,17 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::ethernet_types::*;
 // Implementation for acl_rule
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct AclRule {
 	pub is_permit: AclAction,
 	pub src_prefix: Prefix,
@@ -27,20 +26,55 @@ pub struct AclRule {
 	pub tcp_flags_value: u8,
 }
 // Implementation for macip_acl_rule
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct MacipAclRule {
 	pub is_permit: AclAction,
 	pub src_mac: MacAddress,
 	pub src_mac_mask: MacAddress,
 	pub src_prefix: Prefix,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum AclAction {
 	 ACL_ACTION_API_DENY=0,
 	 ACL_ACTION_API_PERMIT=1,
 	 ACL_ACTION_API_PERMIT_REFLECT=2,
+	 Unknown(u32),
 }
 impl Default for AclAction {
 	fn default() -> Self { AclAction::ACL_ACTION_API_DENY }
 }
+impl AsU32 for AclAction {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 AclAction::ACL_ACTION_API_DENY => 0,
+			 AclAction::ACL_ACTION_API_PERMIT => 1,
+			 AclAction::ACL_ACTION_API_PERMIT_REFLECT => 2,
+			 AclAction::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(AclAction::ACL_ACTION_API_DENY),
+			 1 => Some(AclAction::ACL_ACTION_API_PERMIT),
+			 2 => Some(AclAction::ACL_ACTION_API_PERMIT_REFLECT),
+			 value => Some(AclAction::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for AclAction {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("AclAction value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for AclAction {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(AclAction::Unknown(value)))
+	 }
+}
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
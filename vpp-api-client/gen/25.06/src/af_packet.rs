@@ -2,51 +2,115 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum AfPacketMode {
 	 AF_PACKET_API_MODE_ETHERNET=1,
 	 AF_PACKET_API_MODE_IP=2,
+	 Unknown(u32),
 }
 impl Default for AfPacketMode {
 	fn default() -> Self { AfPacketMode::AF_PACKET_API_MODE_ETHERNET }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+impl AsU32 for AfPacketMode {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 AfPacketMode::AF_PACKET_API_MODE_ETHERNET => 1,
+			 AfPacketMode::AF_PACKET_API_MODE_IP => 2,
+			 AfPacketMode::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 1 => Some(AfPacketMode::AF_PACKET_API_MODE_ETHERNET),
+			 2 => Some(AfPacketMode::AF_PACKET_API_MODE_IP),
+			 value => Some(AfPacketMode::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for AfPacketMode {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for AfPacketMode {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(AfPacketMode::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum AfPacketFlags {
 	 AF_PACKET_API_FLAG_QDISC_BYPASS=1,
 	 AF_PACKET_API_FLAG_CKSUM_GSO=2,
 	 AF_PACKET_API_FLAG_VERSION_2=8,
+	 Unknown(u32),
 }
 impl Default for AfPacketFlags {
 	fn default() -> Self { AfPacketFlags::AF_PACKET_API_FLAG_QDISC_BYPASS }
 }
-impl AsEnumFlag for AfPacketFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for AfPacketFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 AfPacketFlags::AF_PACKET_API_FLAG_QDISC_BYPASS => 1,
+			 AfPacketFlags::AF_PACKET_API_FLAG_CKSUM_GSO => 2,
+			 AfPacketFlags::AF_PACKET_API_FLAG_VERSION_2 => 8,
+			 AfPacketFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => AfPacketFlags::AF_PACKET_API_FLAG_QDISC_BYPASS,
-			 2 => AfPacketFlags::AF_PACKET_API_FLAG_CKSUM_GSO,
-			 8 => AfPacketFlags::AF_PACKET_API_FLAG_VERSION_2,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(AfPacketFlags::AF_PACKET_API_FLAG_QDISC_BYPASS),
+			 2 => Some(AfPacketFlags::AF_PACKET_API_FLAG_CKSUM_GSO),
+			 8 => Some(AfPacketFlags::AF_PACKET_API_FLAG_VERSION_2),
+			 value => Some(AfPacketFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for AfPacketFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for AfPacketFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(AfPacketFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for AfPacketFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0xb
+	}
+}
+impl std::ops::BitOr for AfPacketFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_packet_create_a190415f)]
 pub struct AfPacketCreate {
 	pub client_index: u32,
@@ -55,14 +119,14 @@ pub struct AfPacketCreate {
 	pub use_random_hw_addr: bool,
 	pub host_if_name: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_packet_create_reply_5383d31f)]
 pub struct AfPacketCreateReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_packet_create_v2_4aff0436)]
 pub struct AfPacketCreateV2 {
 	pub client_index: u32,
@@ -77,14 +141,14 @@ pub struct AfPacketCreateV2 {
 	pub flags: u32,
 	pub num_rx_queues: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_packet_create_v2_reply_5383d31f)]
 pub struct AfPacketCreateV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_packet_create_v3_b3a809d4)]
 pub struct AfPacketCreateV3 {
 	pub client_index: u32,
@@ -101,27 +165,27 @@ pub struct AfPacketCreateV3 {
 	pub num_rx_queues: u16,
 	pub num_tx_queues: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_packet_create_v3_reply_5383d31f)]
 pub struct AfPacketCreateV3Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_packet_delete_863fa648)]
 pub struct AfPacketDelete {
 	pub client_index: u32,
 	pub context: u32,
 	pub host_if_name: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_packet_delete_reply_e8d4e804)]
 pub struct AfPacketDeleteReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_packet_set_l4_cksum_offload_319cd5c8)]
 pub struct AfPacketSetL4CksumOffload {
 	pub client_index: u32,
@@ -129,22 +193,37 @@ pub struct AfPacketSetL4CksumOffload {
 	pub sw_if_index: InterfaceIndex,
 	pub set: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_packet_set_l4_cksum_offload_reply_e8d4e804)]
 pub struct AfPacketSetL4CksumOffloadReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_packet_dump_51077d14)]
 pub struct AfPacketDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_packet_details_58c7c042)]
 pub struct AfPacketDetails {
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 	pub host_if_name: FixedSizeString<typenum::U64>,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"af_packet_create_a190415f",
+	"af_packet_create_reply_5383d31f",
+	"af_packet_create_v2_4aff0436",
+	"af_packet_create_v2_reply_5383d31f",
+	"af_packet_create_v3_b3a809d4",
+	"af_packet_create_v3_reply_5383d31f",
+	"af_packet_delete_863fa648",
+	"af_packet_delete_reply_e8d4e804",
+	"af_packet_set_l4_cksum_offload_319cd5c8",
+	"af_packet_set_l4_cksum_offload_reply_e8d4e804",
+	"af_packet_dump_51077d14",
+	"af_packet_details_58c7c042",
+];
@@ -2,47 +2,112 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum AfXdpMode {
 	 AF_XDP_API_MODE_AUTO=0,
 	 AF_XDP_API_MODE_COPY=1,
 	 AF_XDP_API_MODE_ZERO_COPY=2,
+	 Unknown(u32),
 }
 impl Default for AfXdpMode {
 	fn default() -> Self { AfXdpMode::AF_XDP_API_MODE_AUTO }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+impl AsU32 for AfXdpMode {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 AfXdpMode::AF_XDP_API_MODE_AUTO => 0,
+			 AfXdpMode::AF_XDP_API_MODE_COPY => 1,
+			 AfXdpMode::AF_XDP_API_MODE_ZERO_COPY => 2,
+			 AfXdpMode::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(AfXdpMode::AF_XDP_API_MODE_AUTO),
+			 1 => Some(AfXdpMode::AF_XDP_API_MODE_COPY),
+			 2 => Some(AfXdpMode::AF_XDP_API_MODE_ZERO_COPY),
+			 value => Some(AfXdpMode::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for AfXdpMode {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for AfXdpMode {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(AfXdpMode::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u8)]
 pub enum AfXdpFlag {
 	 AF_XDP_API_FLAGS_NO_SYSCALL_LOCK=1,
+	 Unknown(u32),
 }
 impl Default for AfXdpFlag {
 	fn default() -> Self { AfXdpFlag::AF_XDP_API_FLAGS_NO_SYSCALL_LOCK }
 }
-impl AsEnumFlag for AfXdpFlag {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for AfXdpFlag {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 AfXdpFlag::AF_XDP_API_FLAGS_NO_SYSCALL_LOCK => 1,
+			 AfXdpFlag::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => AfXdpFlag::AF_XDP_API_FLAGS_NO_SYSCALL_LOCK,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(AfXdpFlag::AF_XDP_API_FLAGS_NO_SYSCALL_LOCK),
+			 value => Some(AfXdpFlag::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for AfXdpFlag {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("AfXdpFlag value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for AfXdpFlag {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(AfXdpFlag::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for AfXdpFlag {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 8 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x1
+	}
+}
+impl std::ops::BitOr for AfXdpFlag {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_xdp_create_v3_cf4b1827)]
 pub struct AfXdpCreateV3 {
 	pub client_index: u32,
@@ -57,23 +122,30 @@ pub struct AfXdpCreateV3 {
 	pub prog: FixedSizeString<typenum::U256>,
 	pub netns: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_xdp_create_v3_reply_5383d31f)]
 pub struct AfXdpCreateV3Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_xdp_delete_f9e6675e)]
 pub struct AfXdpDelete {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(af_xdp_delete_reply_e8d4e804)]
 pub struct AfXdpDeleteReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"af_xdp_create_v3_cf4b1827",
+	"af_xdp_create_v3_reply_5383d31f",
+	"af_xdp_delete_f9e6675e",
+	"af_xdp_delete_reply_e8d4e804",
+];
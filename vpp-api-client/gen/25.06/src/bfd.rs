@@ -2,59 +2,91 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum BfdState {
 	 BFD_STATE_API_ADMIN_DOWN=0,
 	 BFD_STATE_API_DOWN=1,
 	 BFD_STATE_API_INIT=2,
 	 BFD_STATE_API_UP=3,
+	 Unknown(u32),
 }
 impl Default for BfdState {
 	fn default() -> Self { BfdState::BFD_STATE_API_ADMIN_DOWN }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for BfdState {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 BfdState::BFD_STATE_API_ADMIN_DOWN => 0,
+			 BfdState::BFD_STATE_API_DOWN => 1,
+			 BfdState::BFD_STATE_API_INIT => 2,
+			 BfdState::BFD_STATE_API_UP => 3,
+			 BfdState::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(BfdState::BFD_STATE_API_ADMIN_DOWN),
+			 1 => Some(BfdState::BFD_STATE_API_DOWN),
+			 2 => Some(BfdState::BFD_STATE_API_INIT),
+			 3 => Some(BfdState::BFD_STATE_API_UP),
+			 value => Some(BfdState::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for BfdState {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for BfdState {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(BfdState::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_set_echo_source_f9e6675e)]
 pub struct BfdUdpSetEchoSource {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_set_echo_source_reply_e8d4e804)]
 pub struct BfdUdpSetEchoSourceReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_del_echo_source_51077d14)]
 pub struct BfdUdpDelEchoSource {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_del_echo_source_reply_e8d4e804)]
 pub struct BfdUdpDelEchoSourceReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_get_echo_source_51077d14)]
 pub struct BfdUdpGetEchoSource {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_get_echo_source_reply_e3d736a1)]
 pub struct BfdUdpGetEchoSourceReply {
 	pub context: u32,
@@ -66,7 +98,7 @@ pub struct BfdUdpGetEchoSourceReply {
 	pub have_usable_ip6: bool,
 	pub ip6_addr: Ip6Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_add_939cd26a)]
 pub struct BfdUdpAdd {
 	pub client_index: u32,
@@ -81,13 +113,13 @@ pub struct BfdUdpAdd {
 	pub bfd_key_id: u8,
 	pub conf_key_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_add_reply_e8d4e804)]
 pub struct BfdUdpAddReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_upd_939cd26a)]
 pub struct BfdUdpUpd {
 	pub client_index: u32,
@@ -102,14 +134,14 @@ pub struct BfdUdpUpd {
 	pub bfd_key_id: u8,
 	pub conf_key_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_upd_reply_1992deab)]
 pub struct BfdUdpUpdReply {
 	pub context: u32,
 	pub retval: i32,
 	pub stats_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_mod_913df085)]
 pub struct BfdUdpMod {
 	pub client_index: u32,
@@ -121,13 +153,13 @@ pub struct BfdUdpMod {
 	pub peer_addr: Address,
 	pub detect_mult: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_mod_reply_e8d4e804)]
 pub struct BfdUdpModReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_del_dcb13a89)]
 pub struct BfdUdpDel {
 	pub client_index: u32,
@@ -136,19 +168,19 @@ pub struct BfdUdpDel {
 	pub local_addr: Address,
 	pub peer_addr: Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_del_reply_e8d4e804)]
 pub struct BfdUdpDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_session_dump_51077d14)]
 pub struct BfdUdpSessionDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_session_details_09fb2f2d)]
 pub struct BfdUdpSessionDetails {
 	pub context: u32,
@@ -163,7 +195,7 @@ pub struct BfdUdpSessionDetails {
 	pub desired_min_tx: u32,
 	pub detect_mult: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_session_set_flags_04b4bdfd)]
 pub struct BfdUdpSessionSetFlags {
 	pub client_index: u32,
@@ -173,13 +205,13 @@ pub struct BfdUdpSessionSetFlags {
 	pub peer_addr: Address,
 	 pub flags: EnumFlag<IfStatusFlags>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_session_set_flags_reply_e8d4e804)]
 pub struct BfdUdpSessionSetFlagsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_bfd_events_c5e2af94)]
 pub struct WantBfdEvents {
 	pub client_index: u32,
@@ -187,13 +219,13 @@ pub struct WantBfdEvents {
 	pub enable_disable: bool,
 	pub pid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_bfd_events_reply_e8d4e804)]
 pub struct WantBfdEventsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_session_event_8eaaf062)]
 pub struct BfdUdpSessionEvent {
 	pub client_index: u32,
@@ -209,7 +241,7 @@ pub struct BfdUdpSessionEvent {
 	pub desired_min_tx: u32,
 	pub detect_mult: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_auth_set_key_690b8877)]
 pub struct BfdAuthSetKey {
 	pub client_index: u32,
@@ -219,32 +251,32 @@ pub struct BfdAuthSetKey {
 	pub auth_type: u8,
 	pub key: FixedSizeArray<u8, typenum::U20>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_auth_set_key_reply_e8d4e804)]
 pub struct BfdAuthSetKeyReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_auth_del_key_65310b22)]
 pub struct BfdAuthDelKey {
 	pub client_index: u32,
 	pub context: u32,
 	pub conf_key_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_auth_del_key_reply_e8d4e804)]
 pub struct BfdAuthDelKeyReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_auth_keys_dump_51077d14)]
 pub struct BfdAuthKeysDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_auth_keys_details_84130e9f)]
 pub struct BfdAuthKeysDetails {
 	pub context: u32,
@@ -252,7 +284,7 @@ pub struct BfdAuthKeysDetails {
 	pub use_count: u32,
 	pub auth_type: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_auth_activate_21fd1bdb)]
 pub struct BfdUdpAuthActivate {
 	pub client_index: u32,
@@ -264,13 +296,13 @@ pub struct BfdUdpAuthActivate {
 	pub bfd_key_id: u8,
 	pub conf_key_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_auth_activate_reply_e8d4e804)]
 pub struct BfdUdpAuthActivateReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_auth_deactivate_9a05e2e0)]
 pub struct BfdUdpAuthDeactivate {
 	pub client_index: u32,
@@ -280,21 +312,57 @@ pub struct BfdUdpAuthDeactivate {
 	pub peer_addr: Address,
 	pub is_delayed: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_auth_deactivate_reply_e8d4e804)]
 pub struct BfdUdpAuthDeactivateReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_enable_multihop_51077d14)]
 pub struct BfdUdpEnableMultihop {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bfd_udp_enable_multihop_reply_e8d4e804)]
 pub struct BfdUdpEnableMultihopReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"bfd_udp_set_echo_source_f9e6675e",
+	"bfd_udp_set_echo_source_reply_e8d4e804",
+	"bfd_udp_del_echo_source_51077d14",
+	"bfd_udp_del_echo_source_reply_e8d4e804",
+	"bfd_udp_get_echo_source_51077d14",
+	"bfd_udp_get_echo_source_reply_e3d736a1",
+	"bfd_udp_add_939cd26a",
+	"bfd_udp_add_reply_e8d4e804",
+	"bfd_udp_upd_939cd26a",
+	"bfd_udp_upd_reply_1992deab",
+	"bfd_udp_mod_913df085",
+	"bfd_udp_mod_reply_e8d4e804",
+	"bfd_udp_del_dcb13a89",
+	"bfd_udp_del_reply_e8d4e804",
+	"bfd_udp_session_dump_51077d14",
+	"bfd_udp_session_details_09fb2f2d",
+	"bfd_udp_session_set_flags_04b4bdfd",
+	"bfd_udp_session_set_flags_reply_e8d4e804",
+	"want_bfd_events_c5e2af94",
+	"want_bfd_events_reply_e8d4e804",
+	"bfd_udp_session_event_8eaaf062",
+	"bfd_auth_set_key_690b8877",
+	"bfd_auth_set_key_reply_e8d4e804",
+	"bfd_auth_del_key_65310b22",
+	"bfd_auth_del_key_reply_e8d4e804",
+	"bfd_auth_keys_dump_51077d14",
+	"bfd_auth_keys_details_84130e9f",
+	"bfd_udp_auth_activate_21fd1bdb",
+	"bfd_udp_auth_activate_reply_e8d4e804",
+	"bfd_udp_auth_deactivate_9a05e2e0",
+	"bfd_udp_auth_deactivate_reply_e8d4e804",
+	"bfd_udp_enable_multihop_51077d14",
+	"bfd_udp_enable_multihop_reply_e8d4e804",
+];
@@ -2,17 +2,16 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum BondMode {
 	 BOND_API_MODE_ROUND_ROBIN=1,
@@ -20,11 +19,46 @@ pub enum BondMode {
 	 BOND_API_MODE_XOR=3,
 	 BOND_API_MODE_BROADCAST=4,
 	 BOND_API_MODE_LACP=5,
+	 Unknown(u32),
 }
 impl Default for BondMode {
 	fn default() -> Self { BondMode::BOND_API_MODE_ROUND_ROBIN }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for BondMode {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 BondMode::BOND_API_MODE_ROUND_ROBIN => 1,
+			 BondMode::BOND_API_MODE_ACTIVE_BACKUP => 2,
+			 BondMode::BOND_API_MODE_XOR => 3,
+			 BondMode::BOND_API_MODE_BROADCAST => 4,
+			 BondMode::BOND_API_MODE_LACP => 5,
+			 BondMode::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 1 => Some(BondMode::BOND_API_MODE_ROUND_ROBIN),
+			 2 => Some(BondMode::BOND_API_MODE_ACTIVE_BACKUP),
+			 3 => Some(BondMode::BOND_API_MODE_XOR),
+			 4 => Some(BondMode::BOND_API_MODE_BROADCAST),
+			 5 => Some(BondMode::BOND_API_MODE_LACP),
+			 value => Some(BondMode::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for BondMode {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for BondMode {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(BondMode::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum BondLbAlgo {
 	 BOND_API_LB_ALGO_L2=0,
@@ -33,11 +67,48 @@ pub enum BondLbAlgo {
 	 BOND_API_LB_ALGO_RR=3,
 	 BOND_API_LB_ALGO_BC=4,
 	 BOND_API_LB_ALGO_AB=5,
+	 Unknown(u32),
 }
 impl Default for BondLbAlgo {
 	fn default() -> Self { BondLbAlgo::BOND_API_LB_ALGO_L2 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for BondLbAlgo {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 BondLbAlgo::BOND_API_LB_ALGO_L2 => 0,
+			 BondLbAlgo::BOND_API_LB_ALGO_L34 => 1,
+			 BondLbAlgo::BOND_API_LB_ALGO_L23 => 2,
+			 BondLbAlgo::BOND_API_LB_ALGO_RR => 3,
+			 BondLbAlgo::BOND_API_LB_ALGO_BC => 4,
+			 BondLbAlgo::BOND_API_LB_ALGO_AB => 5,
+			 BondLbAlgo::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(BondLbAlgo::BOND_API_LB_ALGO_L2),
+			 1 => Some(BondLbAlgo::BOND_API_LB_ALGO_L34),
+			 2 => Some(BondLbAlgo::BOND_API_LB_ALGO_L23),
+			 3 => Some(BondLbAlgo::BOND_API_LB_ALGO_RR),
+			 4 => Some(BondLbAlgo::BOND_API_LB_ALGO_BC),
+			 5 => Some(BondLbAlgo::BOND_API_LB_ALGO_AB),
+			 value => Some(BondLbAlgo::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for BondLbAlgo {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for BondLbAlgo {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(BondLbAlgo::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_create_f1dbd4ff)]
 pub struct BondCreate {
 	pub client_index: u32,
@@ -49,14 +120,14 @@ pub struct BondCreate {
 	pub lb: BondLbAlgo,
 	pub numa_only: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_create_reply_5383d31f)]
 pub struct BondCreateReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_create2_912fda76)]
 pub struct BondCreate2 {
 	pub client_index: u32,
@@ -69,27 +140,27 @@ pub struct BondCreate2 {
 	pub mac_address: MacAddress,
 	pub id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_create2_reply_5383d31f)]
 pub struct BondCreate2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_delete_f9e6675e)]
 pub struct BondDelete {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_delete_reply_e8d4e804)]
 pub struct BondDeleteReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_enslave_e7d14948)]
 pub struct BondEnslave {
 	pub client_index: u32,
@@ -99,13 +170,13 @@ pub struct BondEnslave {
 	pub is_passive: bool,
 	pub is_long_timeout: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_enslave_reply_e8d4e804)]
 pub struct BondEnslaveReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_add_member_e7d14948)]
 pub struct BondAddMember {
 	pub client_index: u32,
@@ -115,45 +186,45 @@ pub struct BondAddMember {
 	pub is_passive: bool,
 	pub is_long_timeout: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_add_member_reply_e8d4e804)]
 pub struct BondAddMemberReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_detach_slave_f9e6675e)]
 pub struct BondDetachSlave {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_detach_slave_reply_e8d4e804)]
 pub struct BondDetachSlaveReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_detach_member_f9e6675e)]
 pub struct BondDetachMember {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bond_detach_member_reply_e8d4e804)]
 pub struct BondDetachMemberReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_bond_dump_51077d14)]
 pub struct SwInterfaceBondDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_bond_details_bb7c929b)]
 pub struct SwInterfaceBondDetails {
 	pub context: u32,
@@ -166,14 +237,14 @@ pub struct SwInterfaceBondDetails {
 	pub slaves: u32,
 	pub interface_name: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_bond_interface_dump_f9e6675e)]
 pub struct SwBondInterfaceDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_bond_interface_details_9428a69c)]
 pub struct SwBondInterfaceDetails {
 	pub context: u32,
@@ -186,14 +257,14 @@ pub struct SwBondInterfaceDetails {
 	pub members: u32,
 	pub interface_name: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_slave_dump_f9e6675e)]
 pub struct SwInterfaceSlaveDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_slave_details_3c4a0e23)]
 pub struct SwInterfaceSlaveDetails {
 	pub context: u32,
@@ -204,14 +275,14 @@ pub struct SwInterfaceSlaveDetails {
 	pub is_local_numa: bool,
 	pub weight: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_member_interface_dump_f9e6675e)]
 pub struct SwMemberInterfaceDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_member_interface_details_3c4a0e23)]
 pub struct SwMemberInterfaceDetails {
 	pub context: u32,
@@ -222,7 +293,7 @@ pub struct SwMemberInterfaceDetails {
 	pub is_local_numa: bool,
 	pub weight: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_bond_weight_deb510a0)]
 pub struct SwInterfaceSetBondWeight {
 	pub client_index: u32,
@@ -230,9 +301,36 @@ pub struct SwInterfaceSetBondWeight {
 	pub sw_if_index: InterfaceIndex,
 	pub weight: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_bond_weight_reply_e8d4e804)]
 pub struct SwInterfaceSetBondWeightReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"bond_create_f1dbd4ff",
+	"bond_create_reply_5383d31f",
+	"bond_create2_912fda76",
+	"bond_create2_reply_5383d31f",
+	"bond_delete_f9e6675e",
+	"bond_delete_reply_e8d4e804",
+	"bond_enslave_e7d14948",
+	"bond_enslave_reply_e8d4e804",
+	"bond_add_member_e7d14948",
+	"bond_add_member_reply_e8d4e804",
+	"bond_detach_slave_f9e6675e",
+	"bond_detach_slave_reply_e8d4e804",
+	"bond_detach_member_f9e6675e",
+	"bond_detach_member_reply_e8d4e804",
+	"sw_interface_bond_dump_51077d14",
+	"sw_interface_bond_details_bb7c929b",
+	"sw_bond_interface_dump_f9e6675e",
+	"sw_bond_interface_details_9428a69c",
+	"sw_interface_slave_dump_f9e6675e",
+	"sw_interface_slave_details_3c4a0e23",
+	"sw_member_interface_dump_f9e6675e",
+	"sw_member_interface_details_3c4a0e23",
+	"sw_interface_set_bond_weight_deb510a0",
+	"sw_interface_set_bond_weight_reply_e8d4e804",
+];
@@ -2,15 +2,14 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bpf_trace_filter_set_3171346e)]
 pub struct BpfTraceFilterSet {
 	pub client_index: u32,
@@ -18,13 +17,13 @@ pub struct BpfTraceFilterSet {
 	pub is_add: bool,
 	pub filter: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bpf_trace_filter_set_reply_e8d4e804)]
 pub struct BpfTraceFilterSetReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bpf_trace_filter_set_v2_5615acbf)]
 pub struct BpfTraceFilterSetV2 {
 	pub client_index: u32,
@@ -33,9 +32,16 @@ pub struct BpfTraceFilterSetV2 {
 	pub optimize: bool,
 	pub filter: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bpf_trace_filter_set_v2_reply_e8d4e804)]
 pub struct BpfTraceFilterSetV2Reply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"bpf_trace_filter_set_3171346e",
+	"bpf_trace_filter_set_reply_e8d4e804",
+	"bpf_trace_filter_set_v2_5615acbf",
+	"bpf_trace_filter_set_v2_reply_e8d4e804",
+];
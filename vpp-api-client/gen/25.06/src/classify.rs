@@ -2,46 +2,141 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum ClassifyAction {
 	 CLASSIFY_API_ACTION_NONE=0,
 	 CLASSIFY_API_ACTION_SET_IP4_FIB_INDEX=1,
 	 CLASSIFY_API_ACTION_SET_IP6_FIB_INDEX=2,
 	 CLASSIFY_API_ACTION_SET_METADATA=3,
+	 Unknown(u32),
 }
 impl Default for ClassifyAction {
 	fn default() -> Self { ClassifyAction::CLASSIFY_API_ACTION_NONE }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for ClassifyAction {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 ClassifyAction::CLASSIFY_API_ACTION_NONE => 0,
+			 ClassifyAction::CLASSIFY_API_ACTION_SET_IP4_FIB_INDEX => 1,
+			 ClassifyAction::CLASSIFY_API_ACTION_SET_IP6_FIB_INDEX => 2,
+			 ClassifyAction::CLASSIFY_API_ACTION_SET_METADATA => 3,
+			 ClassifyAction::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(ClassifyAction::CLASSIFY_API_ACTION_NONE),
+			 1 => Some(ClassifyAction::CLASSIFY_API_ACTION_SET_IP4_FIB_INDEX),
+			 2 => Some(ClassifyAction::CLASSIFY_API_ACTION_SET_IP6_FIB_INDEX),
+			 3 => Some(ClassifyAction::CLASSIFY_API_ACTION_SET_METADATA),
+			 value => Some(ClassifyAction::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for ClassifyAction {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("ClassifyAction value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for ClassifyAction {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(ClassifyAction::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum PolicerClassifyTable {
 	 POLICER_CLASSIFY_API_TABLE_IP4=0,
 	 POLICER_CLASSIFY_API_TABLE_IP6=1,
 	 POLICER_CLASSIFY_API_TABLE_L2=2,
+	 Unknown(u32),
 }
 impl Default for PolicerClassifyTable {
 	fn default() -> Self { PolicerClassifyTable::POLICER_CLASSIFY_API_TABLE_IP4 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for PolicerClassifyTable {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 PolicerClassifyTable::POLICER_CLASSIFY_API_TABLE_IP4 => 0,
+			 PolicerClassifyTable::POLICER_CLASSIFY_API_TABLE_IP6 => 1,
+			 PolicerClassifyTable::POLICER_CLASSIFY_API_TABLE_L2 => 2,
+			 PolicerClassifyTable::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(PolicerClassifyTable::POLICER_CLASSIFY_API_TABLE_IP4),
+			 1 => Some(PolicerClassifyTable::POLICER_CLASSIFY_API_TABLE_IP6),
+			 2 => Some(PolicerClassifyTable::POLICER_CLASSIFY_API_TABLE_L2),
+			 value => Some(PolicerClassifyTable::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for PolicerClassifyTable {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("PolicerClassifyTable value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for PolicerClassifyTable {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(PolicerClassifyTable::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum FlowClassifyTable {
 	 FLOW_CLASSIFY_API_TABLE_IP4=0,
 	 FLOW_CLASSIFY_API_TABLE_IP6=1,
+	 Unknown(u32),
 }
 impl Default for FlowClassifyTable {
 	fn default() -> Self { FlowClassifyTable::FLOW_CLASSIFY_API_TABLE_IP4 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for FlowClassifyTable {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FlowClassifyTable::FLOW_CLASSIFY_API_TABLE_IP4 => 0,
+			 FlowClassifyTable::FLOW_CLASSIFY_API_TABLE_IP6 => 1,
+			 FlowClassifyTable::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(FlowClassifyTable::FLOW_CLASSIFY_API_TABLE_IP4),
+			 1 => Some(FlowClassifyTable::FLOW_CLASSIFY_API_TABLE_IP6),
+			 value => Some(FlowClassifyTable::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for FlowClassifyTable {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("FlowClassifyTable value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FlowClassifyTable {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FlowClassifyTable::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_add_del_table_6849e39e)]
 pub struct ClassifyAddDelTable {
 	pub client_index: u32,
@@ -58,9 +153,10 @@ pub struct ClassifyAddDelTable {
 	pub current_data_flag: u8,
 	pub current_data_offset: i16,
 	pub mask_len: u32,
+	#[vpp(count = "mask_len")]
 	pub mask: VariableSizeArray<u8>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_add_del_table_reply_05486349)]
 pub struct ClassifyAddDelTableReply {
 	pub context: u32,
@@ -69,7 +165,7 @@ pub struct ClassifyAddDelTableReply {
 	pub skip_n_vectors: u32,
 	pub match_n_vectors: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_add_del_session_f20879f0)]
 pub struct ClassifyAddDelSession {
 	pub client_index: u32,
@@ -82,15 +178,16 @@ pub struct ClassifyAddDelSession {
 	pub action: ClassifyAction,
 	pub metadata: u32,
 	pub match_len: u32,
+	#[vpp(count = "match_len")]
 	pub mach: VariableSizeArray<u8>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_add_del_session_reply_e8d4e804)]
 pub struct ClassifyAddDelSessionReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(policer_classify_set_interface_de7ad708)]
 pub struct PolicerClassifySetInterface {
 	pub client_index: u32,
@@ -101,13 +198,13 @@ pub struct PolicerClassifySetInterface {
 	pub l2_table_index: u32,
 	pub is_add: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(policer_classify_set_interface_reply_e8d4e804)]
 pub struct PolicerClassifySetInterfaceReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(policer_classify_dump_56cbb5fb)]
 pub struct PolicerClassifyDump {
 	pub client_index: u32,
@@ -115,35 +212,36 @@ pub struct PolicerClassifyDump {
 	pub typ: PolicerClassifyTable,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(policer_classify_details_dfd08765)]
 pub struct PolicerClassifyDetails {
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 	pub table_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_table_ids_51077d14)]
 pub struct ClassifyTableIds {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_table_ids_reply_d1d20e1d)]
 pub struct ClassifyTableIdsReply {
 	pub context: u32,
 	pub retval: i32,
 	pub count: u32,
+	#[vpp(count = "count")]
 	pub ids: VariableSizeArray<u32>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_table_by_interface_f9e6675e)]
 pub struct ClassifyTableByInterface {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_table_by_interface_reply_ed4197db)]
 pub struct ClassifyTableByInterfaceReply {
 	pub context: u32,
@@ -153,14 +251,14 @@ pub struct ClassifyTableByInterfaceReply {
 	pub ip4_table_id: u32,
 	pub ip6_table_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_table_info_0cca2cd9)]
 pub struct ClassifyTableInfo {
 	pub client_index: u32,
 	pub context: u32,
 	pub table_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_table_info_reply_4a573c0e)]
 pub struct ClassifyTableInfoReply {
 	pub context: u32,
@@ -173,16 +271,17 @@ pub struct ClassifyTableInfoReply {
 	pub next_table_index: u32,
 	pub miss_next_index: u32,
 	pub mask_length: u32,
+	#[vpp(count = "mask_length")]
 	pub mask: VariableSizeArray<u8>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_session_dump_0cca2cd9)]
 pub struct ClassifySessionDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub table_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_session_details_60e3ef94)]
 pub struct ClassifySessionDetails {
 	pub context: u32,
@@ -192,9 +291,10 @@ pub struct ClassifySessionDetails {
 	pub advance: i32,
 	pub opaque_index: u32,
 	pub match_length: u32,
+	#[vpp(count = "match_length")]
 	pub mach: VariableSizeArray<u8>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flow_classify_set_interface_b6192f1c)]
 pub struct FlowClassifySetInterface {
 	pub client_index: u32,
@@ -204,13 +304,13 @@ pub struct FlowClassifySetInterface {
 	pub ip6_table_index: u32,
 	pub is_add: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flow_classify_set_interface_reply_e8d4e804)]
 pub struct FlowClassifySetInterfaceReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flow_classify_dump_25dd3e4c)]
 pub struct FlowClassifyDump {
 	pub client_index: u32,
@@ -218,14 +318,14 @@ pub struct FlowClassifyDump {
 	pub typ: FlowClassifyTable,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flow_classify_details_dfd08765)]
 pub struct FlowClassifyDetails {
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 	pub table_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_set_interface_ip_table_e0b097c7)]
 pub struct ClassifySetInterfaceIpTable {
 	pub client_index: u32,
@@ -234,13 +334,13 @@ pub struct ClassifySetInterfaceIpTable {
 	pub sw_if_index: InterfaceIndex,
 	pub table_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_set_interface_ip_table_reply_e8d4e804)]
 pub struct ClassifySetInterfaceIpTableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_set_interface_l2_tables_5a6ddf65)]
 pub struct ClassifySetInterfaceL2Tables {
 	pub client_index: u32,
@@ -251,13 +351,13 @@ pub struct ClassifySetInterfaceL2Tables {
 	pub other_table_index: u32,
 	pub is_input: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_set_interface_l2_tables_reply_e8d4e804)]
 pub struct ClassifySetInterfaceL2TablesReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(input_acl_set_interface_de7ad708)]
 pub struct InputAclSetInterface {
 	pub client_index: u32,
@@ -268,13 +368,13 @@ pub struct InputAclSetInterface {
 	pub l2_table_index: u32,
 	pub is_add: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(input_acl_set_interface_reply_e8d4e804)]
 pub struct InputAclSetInterfaceReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(punt_acl_add_del_a93bf3a0)]
 pub struct PuntAclAddDel {
 	pub client_index: u32,
@@ -283,19 +383,19 @@ pub struct PuntAclAddDel {
 	pub ip6_table_index: u32,
 	pub is_add: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(punt_acl_add_del_reply_e8d4e804)]
 pub struct PuntAclAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(punt_acl_get_51077d14)]
 pub struct PuntAclGet {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(punt_acl_get_reply_8409b9dd)]
 pub struct PuntAclGetReply {
 	pub context: u32,
@@ -303,7 +403,7 @@ pub struct PuntAclGetReply {
 	pub ip4_table_index: u32,
 	pub ip6_table_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(output_acl_set_interface_de7ad708)]
 pub struct OutputAclSetInterface {
 	pub client_index: u32,
@@ -314,13 +414,13 @@ pub struct OutputAclSetInterface {
 	pub l2_table_index: u32,
 	pub is_add: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(output_acl_set_interface_reply_e8d4e804)]
 pub struct OutputAclSetInterfaceReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_pcap_lookup_table_e1b4cc6b)]
 pub struct ClassifyPcapLookupTable {
 	pub client_index: u32,
@@ -329,16 +429,17 @@ pub struct ClassifyPcapLookupTable {
 	pub skip_n_vectors: u32,
 	pub match_n_vectors: u32,
 	pub mask_len: u32,
+	#[vpp(count = "mask_len")]
 	pub mask: VariableSizeArray<u8>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_pcap_lookup_table_reply_9c6c6773)]
 pub struct ClassifyPcapLookupTableReply {
 	pub context: u32,
 	pub retval: i32,
 	pub table_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_pcap_set_table_006051b3)]
 pub struct ClassifyPcapSetTable {
 	pub client_index: u32,
@@ -347,29 +448,30 @@ pub struct ClassifyPcapSetTable {
 	pub table_index: u32,
 	pub sort_masks: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_pcap_set_table_reply_9c6c6773)]
 pub struct ClassifyPcapSetTableReply {
 	pub context: u32,
 	pub retval: i32,
 	pub table_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_pcap_get_tables_f9e6675e)]
 pub struct ClassifyPcapGetTables {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_pcap_get_tables_reply_5f5bc9e6)]
 pub struct ClassifyPcapGetTablesReply {
 	pub context: u32,
 	pub retval: i32,
 	pub count: u32,
+	#[vpp(count = "count")]
 	pub indices: VariableSizeArray<u32>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_trace_lookup_table_3f7b72e4)]
 pub struct ClassifyTraceLookupTable {
 	pub client_index: u32,
@@ -377,16 +479,17 @@ pub struct ClassifyTraceLookupTable {
 	pub skip_n_vectors: u32,
 	pub match_n_vectors: u32,
 	pub mask_len: u32,
+	#[vpp(count = "mask_len")]
 	pub mask: VariableSizeArray<u8>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_trace_lookup_table_reply_9c6c6773)]
 pub struct ClassifyTraceLookupTableReply {
 	pub context: u32,
 	pub retval: i32,
 	pub table_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_trace_set_table_3909b55a)]
 pub struct ClassifyTraceSetTable {
 	pub client_index: u32,
@@ -394,24 +497,72 @@ pub struct ClassifyTraceSetTable {
 	pub table_index: u32,
 	pub sort_masks: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_trace_set_table_reply_9c6c6773)]
 pub struct ClassifyTraceSetTableReply {
 	pub context: u32,
 	pub retval: i32,
 	pub table_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_trace_get_tables_51077d14)]
 pub struct ClassifyTraceGetTables {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(classify_trace_get_tables_reply_5f5bc9e6)]
 pub struct ClassifyTraceGetTablesReply {
 	pub context: u32,
 	pub retval: i32,
 	pub count: u32,
+	#[vpp(count = "count")]
 	pub indices: VariableSizeArray<u32>,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"classify_add_del_table_6849e39e",
+	"classify_add_del_table_reply_05486349",
+	"classify_add_del_session_f20879f0",
+	"classify_add_del_session_reply_e8d4e804",
+	"policer_classify_set_interface_de7ad708",
+	"policer_classify_set_interface_reply_e8d4e804",
+	"policer_classify_dump_56cbb5fb",
+	"policer_classify_details_dfd08765",
+	"classify_table_ids_51077d14",
+	"classify_table_ids_reply_d1d20e1d",
+	"classify_table_by_interface_f9e6675e",
+	"classify_table_by_interface_reply_ed4197db",
+	"classify_table_info_0cca2cd9",
+	"classify_table_info_reply_4a573c0e",
+	"classify_session_dump_0cca2cd9",
+	"classify_session_details_60e3ef94",
+	"flow_classify_set_interface_b6192f1c",
+	"flow_classify_set_interface_reply_e8d4e804",
+	"flow_classify_dump_25dd3e4c",
+	"flow_classify_details_dfd08765",
+	"classify_set_interface_ip_table_e0b097c7",
+	"classify_set_interface_ip_table_reply_e8d4e804",
+	"classify_set_interface_l2_tables_5a6ddf65",
+	"classify_set_interface_l2_tables_reply_e8d4e804",
+	"input_acl_set_interface_de7ad708",
+	"input_acl_set_interface_reply_e8d4e804",
+	"punt_acl_add_del_a93bf3a0",
+	"punt_acl_add_del_reply_e8d4e804",
+	"punt_acl_get_51077d14",
+	"punt_acl_get_reply_8409b9dd",
+	"output_acl_set_interface_de7ad708",
+	"output_acl_set_interface_reply_e8d4e804",
+	"classify_pcap_lookup_table_e1b4cc6b",
+	"classify_pcap_lookup_table_reply_9c6c6773",
+	"classify_pcap_set_table_006051b3",
+	"classify_pcap_set_table_reply_9c6c6773",
+	"classify_pcap_get_tables_f9e6675e",
+	"classify_pcap_get_tables_reply_5f5bc9e6",
+	"classify_trace_lookup_table_3f7b72e4",
+	"classify_trace_lookup_table_reply_9c6c6773",
+	"classify_trace_set_table_3909b55a",
+	"classify_trace_set_table_reply_9c6c6773",
+	"classify_trace_get_tables_51077d14",
+	"classify_trace_get_tables_reply_5f5bc9e6",
+];
@@ -2,47 +2,108 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum CryptoDispatchMode {
 	 CRYPTO_ASYNC_DISPATCH_POLLING=0,
 	 CRYPTO_ASYNC_DISPATCH_INTERRUPT=1,
+	 Unknown(u32),
 }
 impl Default for CryptoDispatchMode {
 	fn default() -> Self { CryptoDispatchMode::CRYPTO_ASYNC_DISPATCH_POLLING }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for CryptoDispatchMode {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 CryptoDispatchMode::CRYPTO_ASYNC_DISPATCH_POLLING => 0,
+			 CryptoDispatchMode::CRYPTO_ASYNC_DISPATCH_INTERRUPT => 1,
+			 CryptoDispatchMode::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(CryptoDispatchMode::CRYPTO_ASYNC_DISPATCH_POLLING),
+			 1 => Some(CryptoDispatchMode::CRYPTO_ASYNC_DISPATCH_INTERRUPT),
+			 value => Some(CryptoDispatchMode::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for CryptoDispatchMode {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("CryptoDispatchMode value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for CryptoDispatchMode {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(CryptoDispatchMode::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum CryptoOpClassType {
 	 CRYPTO_API_OP_SIMPLE=0,
 	 CRYPTO_API_OP_CHAINED=1,
 	 CRYPTO_API_OP_BOTH=2,
+	 Unknown(u32),
 }
 impl Default for CryptoOpClassType {
 	fn default() -> Self { CryptoOpClassType::CRYPTO_API_OP_SIMPLE }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for CryptoOpClassType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 CryptoOpClassType::CRYPTO_API_OP_SIMPLE => 0,
+			 CryptoOpClassType::CRYPTO_API_OP_CHAINED => 1,
+			 CryptoOpClassType::CRYPTO_API_OP_BOTH => 2,
+			 CryptoOpClassType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(CryptoOpClassType::CRYPTO_API_OP_SIMPLE),
+			 1 => Some(CryptoOpClassType::CRYPTO_API_OP_CHAINED),
+			 2 => Some(CryptoOpClassType::CRYPTO_API_OP_BOTH),
+			 value => Some(CryptoOpClassType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for CryptoOpClassType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("CryptoOpClassType value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for CryptoOpClassType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(CryptoOpClassType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(crypto_set_async_dispatch_5ca4adc0)]
 pub struct CryptoSetAsyncDispatch {
 	pub client_index: u32,
 	pub context: u32,
 	pub mode: CryptoDispatchMode,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(crypto_set_async_dispatch_reply_e8d4e804)]
 pub struct CryptoSetAsyncDispatchReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(crypto_set_async_dispatch_v2_667d2d54)]
 pub struct CryptoSetAsyncDispatchV2 {
 	pub client_index: u32,
@@ -50,13 +111,13 @@ pub struct CryptoSetAsyncDispatchV2 {
 	pub mode: CryptoDispatchMode,
 	pub adaptive: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(crypto_set_async_dispatch_v2_reply_e8d4e804)]
 pub struct CryptoSetAsyncDispatchV2Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(crypto_set_handler_ce9ad00d)]
 pub struct CryptoSetHandler {
 	pub client_index: u32,
@@ -66,9 +127,18 @@ pub struct CryptoSetHandler {
 	pub oct: CryptoOpClassType,
 	pub is_async: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(crypto_set_handler_reply_e8d4e804)]
 pub struct CryptoSetHandlerReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"crypto_set_async_dispatch_5ca4adc0",
+	"crypto_set_async_dispatch_reply_e8d4e804",
+	"crypto_set_async_dispatch_v2_667d2d54",
+	"crypto_set_async_dispatch_v2_reply_e8d4e804",
+	"crypto_set_handler_ce9ad00d",
+	"crypto_set_handler_reply_e8d4e804",
+];
@@ -2,16 +2,15 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(feature_enable_disable_7531c862)]
 pub struct FeatureEnableDisable {
 	pub client_index: u32,
@@ -21,13 +20,13 @@ pub struct FeatureEnableDisable {
 	pub arc_name: FixedSizeString<typenum::U64>,
 	pub feature_name: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(feature_enable_disable_reply_e8d4e804)]
 pub struct FeatureEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(feature_is_enabled_55db09e2)]
 pub struct FeatureIsEnabled {
 	pub client_index: u32,
@@ -36,7 +35,7 @@ pub struct FeatureIsEnabled {
 	pub feature_name: FixedSizeString<typenum::U64>,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(feature_is_enabled_reply_03f284b5)]
 pub struct FeatureIsEnabledReply {
 	pub client_index: u32,
@@ -44,3 +43,10 @@ pub struct FeatureIsEnabledReply {
 	pub retval: i32,
 	pub is_enabled: bool,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"feature_enable_disable_7531c862",
+	"feature_enable_disable_reply_e8d4e804",
+	"feature_is_enabled_55db09e2",
+	"feature_is_enabled_reply_03f284b5",
+];
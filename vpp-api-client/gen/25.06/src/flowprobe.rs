@@ -2,86 +2,223 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u8)]
 pub enum FlowprobeWhichFlags {
 	 FLOWPROBE_WHICH_FLAG_IP4=1,
 	 FLOWPROBE_WHICH_FLAG_L2=2,
 	 FLOWPROBE_WHICH_FLAG_IP6=4,
+	 Unknown(u32),
 }
 impl Default for FlowprobeWhichFlags {
 	fn default() -> Self { FlowprobeWhichFlags::FLOWPROBE_WHICH_FLAG_IP4 }
 }
-impl AsEnumFlag for FlowprobeWhichFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for FlowprobeWhichFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FlowprobeWhichFlags::FLOWPROBE_WHICH_FLAG_IP4 => 1,
+			 FlowprobeWhichFlags::FLOWPROBE_WHICH_FLAG_L2 => 2,
+			 FlowprobeWhichFlags::FLOWPROBE_WHICH_FLAG_IP6 => 4,
+			 FlowprobeWhichFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => FlowprobeWhichFlags::FLOWPROBE_WHICH_FLAG_IP4,
-			 2 => FlowprobeWhichFlags::FLOWPROBE_WHICH_FLAG_L2,
-			 4 => FlowprobeWhichFlags::FLOWPROBE_WHICH_FLAG_IP6,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(FlowprobeWhichFlags::FLOWPROBE_WHICH_FLAG_IP4),
+			 2 => Some(FlowprobeWhichFlags::FLOWPROBE_WHICH_FLAG_L2),
+			 4 => Some(FlowprobeWhichFlags::FLOWPROBE_WHICH_FLAG_IP6),
+			 value => Some(FlowprobeWhichFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for FlowprobeWhichFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("FlowprobeWhichFlags value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FlowprobeWhichFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FlowprobeWhichFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for FlowprobeWhichFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 8 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x7
+	}
+}
+impl std::ops::BitOr for FlowprobeWhichFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum FlowprobeWhich {
 	 FLOWPROBE_WHICH_IP4=0,
 	 FLOWPROBE_WHICH_IP6=1,
 	 FLOWPROBE_WHICH_L2=2,
+	 Unknown(u32),
 }
 impl Default for FlowprobeWhich {
 	fn default() -> Self { FlowprobeWhich::FLOWPROBE_WHICH_IP4 }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+impl AsU32 for FlowprobeWhich {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FlowprobeWhich::FLOWPROBE_WHICH_IP4 => 0,
+			 FlowprobeWhich::FLOWPROBE_WHICH_IP6 => 1,
+			 FlowprobeWhich::FLOWPROBE_WHICH_L2 => 2,
+			 FlowprobeWhich::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(FlowprobeWhich::FLOWPROBE_WHICH_IP4),
+			 1 => Some(FlowprobeWhich::FLOWPROBE_WHICH_IP6),
+			 2 => Some(FlowprobeWhich::FLOWPROBE_WHICH_L2),
+			 value => Some(FlowprobeWhich::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for FlowprobeWhich {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("FlowprobeWhich value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FlowprobeWhich {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FlowprobeWhich::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u8)]
 pub enum FlowprobeRecordFlags {
 	 FLOWPROBE_RECORD_FLAG_L2=1,
 	 FLOWPROBE_RECORD_FLAG_L3=2,
 	 FLOWPROBE_RECORD_FLAG_L4=4,
+	 Unknown(u32),
 }
 impl Default for FlowprobeRecordFlags {
 	fn default() -> Self { FlowprobeRecordFlags::FLOWPROBE_RECORD_FLAG_L2 }
 }
-impl AsEnumFlag for FlowprobeRecordFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for FlowprobeRecordFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FlowprobeRecordFlags::FLOWPROBE_RECORD_FLAG_L2 => 1,
+			 FlowprobeRecordFlags::FLOWPROBE_RECORD_FLAG_L3 => 2,
+			 FlowprobeRecordFlags::FLOWPROBE_RECORD_FLAG_L4 => 4,
+			 FlowprobeRecordFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => FlowprobeRecordFlags::FLOWPROBE_RECORD_FLAG_L2,
-			 2 => FlowprobeRecordFlags::FLOWPROBE_RECORD_FLAG_L3,
-			 4 => FlowprobeRecordFlags::FLOWPROBE_RECORD_FLAG_L4,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(FlowprobeRecordFlags::FLOWPROBE_RECORD_FLAG_L2),
+			 2 => Some(FlowprobeRecordFlags::FLOWPROBE_RECORD_FLAG_L3),
+			 4 => Some(FlowprobeRecordFlags::FLOWPROBE_RECORD_FLAG_L4),
+			 value => Some(FlowprobeRecordFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for FlowprobeRecordFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("FlowprobeRecordFlags value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FlowprobeRecordFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FlowprobeRecordFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for FlowprobeRecordFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 8 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x7
+	}
+}
+impl std::ops::BitOr for FlowprobeRecordFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum FlowprobeDirection {
 	 FLOWPROBE_DIRECTION_RX=0,
 	 FLOWPROBE_DIRECTION_TX=1,
 	 FLOWPROBE_DIRECTION_BOTH=2,
+	 Unknown(u32),
 }
 impl Default for FlowprobeDirection {
 	fn default() -> Self { FlowprobeDirection::FLOWPROBE_DIRECTION_RX }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for FlowprobeDirection {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FlowprobeDirection::FLOWPROBE_DIRECTION_RX => 0,
+			 FlowprobeDirection::FLOWPROBE_DIRECTION_TX => 1,
+			 FlowprobeDirection::FLOWPROBE_DIRECTION_BOTH => 2,
+			 FlowprobeDirection::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(FlowprobeDirection::FLOWPROBE_DIRECTION_RX),
+			 1 => Some(FlowprobeDirection::FLOWPROBE_DIRECTION_TX),
+			 2 => Some(FlowprobeDirection::FLOWPROBE_DIRECTION_BOTH),
+			 value => Some(FlowprobeDirection::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for FlowprobeDirection {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("FlowprobeDirection value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FlowprobeDirection {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FlowprobeDirection::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flowprobe_tx_interface_add_del_b782c976)]
 pub struct FlowprobeTxInterfaceAddDel {
 	pub client_index: u32,
@@ -90,13 +227,13 @@ pub struct FlowprobeTxInterfaceAddDel {
 	 pub which: EnumFlag<FlowprobeWhichFlags>,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flowprobe_tx_interface_add_del_reply_e8d4e804)]
 pub struct FlowprobeTxInterfaceAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flowprobe_interface_add_del_3420739c)]
 pub struct FlowprobeInterfaceAddDel {
 	pub client_index: u32,
@@ -106,20 +243,20 @@ pub struct FlowprobeInterfaceAddDel {
 	pub direction: FlowprobeDirection,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flowprobe_interface_add_del_reply_e8d4e804)]
 pub struct FlowprobeInterfaceAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flowprobe_interface_dump_f9e6675e)]
 pub struct FlowprobeInterfaceDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flowprobe_interface_details_427d77e0)]
 pub struct FlowprobeInterfaceDetails {
 	pub context: u32,
@@ -127,7 +264,7 @@ pub struct FlowprobeInterfaceDetails {
 	pub direction: FlowprobeDirection,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flowprobe_params_baa46c09)]
 pub struct FlowprobeParams {
 	pub client_index: u32,
@@ -136,13 +273,13 @@ pub struct FlowprobeParams {
 	pub active_timer: u32,
 	pub passive_timer: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flowprobe_params_reply_e8d4e804)]
 pub struct FlowprobeParamsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flowprobe_set_params_baa46c09)]
 pub struct FlowprobeSetParams {
 	pub client_index: u32,
@@ -151,19 +288,19 @@ pub struct FlowprobeSetParams {
 	pub active_timer: u32,
 	pub passive_timer: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flowprobe_set_params_reply_e8d4e804)]
 pub struct FlowprobeSetParamsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flowprobe_get_params_51077d14)]
 pub struct FlowprobeGetParams {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(flowprobe_get_params_reply_f350d621)]
 pub struct FlowprobeGetParamsReply {
 	pub context: u32,
@@ -172,3 +309,18 @@ pub struct FlowprobeGetParamsReply {
 	pub active_timer: u32,
 	pub passive_timer: u32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"flowprobe_tx_interface_add_del_b782c976",
+	"flowprobe_tx_interface_add_del_reply_e8d4e804",
+	"flowprobe_interface_add_del_3420739c",
+	"flowprobe_interface_add_del_reply_e8d4e804",
+	"flowprobe_interface_dump_f9e6675e",
+	"flowprobe_interface_details_427d77e0",
+	"flowprobe_params_baa46c09",
+	"flowprobe_params_reply_e8d4e804",
+	"flowprobe_set_params_baa46c09",
+	"flowprobe_set_params_reply_e8d4e804",
+	"flowprobe_get_params_51077d14",
+	"flowprobe_get_params_reply_f350d621",
+];
@@ -2,19 +2,18 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::tunnel_types::*;
 use crate::interface_types::*;
 // Implementation for gre_tunnel
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct GreTunnel {
 	pub typ: GreTunnelType,
 	pub mode: TunnelMode,
@@ -26,17 +25,49 @@ pub struct GreTunnel {
 	pub src: Address,
 	pub dst: Address,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum GreTunnelType {
 	 GRE_API_TUNNEL_TYPE_L3=0,
 	 GRE_API_TUNNEL_TYPE_TEB=1,
 	 GRE_API_TUNNEL_TYPE_ERSPAN=2,
+	 Unknown(u32),
 }
 impl Default for GreTunnelType {
 	fn default() -> Self { GreTunnelType::GRE_API_TUNNEL_TYPE_L3 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for GreTunnelType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 GreTunnelType::GRE_API_TUNNEL_TYPE_L3 => 0,
+			 GreTunnelType::GRE_API_TUNNEL_TYPE_TEB => 1,
+			 GreTunnelType::GRE_API_TUNNEL_TYPE_ERSPAN => 2,
+			 GreTunnelType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(GreTunnelType::GRE_API_TUNNEL_TYPE_L3),
+			 1 => Some(GreTunnelType::GRE_API_TUNNEL_TYPE_TEB),
+			 2 => Some(GreTunnelType::GRE_API_TUNNEL_TYPE_ERSPAN),
+			 value => Some(GreTunnelType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for GreTunnelType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("GreTunnelType value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for GreTunnelType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(GreTunnelType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gre_tunnel_add_del_a27d7f17)]
 pub struct GreTunnelAddDel {
 	pub client_index: u32,
@@ -44,23 +75,30 @@ pub struct GreTunnelAddDel {
 	pub is_add: bool,
 	pub tunnel: GreTunnel,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gre_tunnel_add_del_reply_5383d31f)]
 pub struct GreTunnelAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gre_tunnel_dump_f9e6675e)]
 pub struct GreTunnelDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gre_tunnel_details_24435433)]
 pub struct GreTunnelDetails {
 	pub context: u32,
 	pub tunnel: GreTunnel,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"gre_tunnel_add_del_a27d7f17",
+	"gre_tunnel_add_del_reply_5383d31f",
+	"gre_tunnel_dump_f9e6675e",
+	"gre_tunnel_details_24435433",
+];
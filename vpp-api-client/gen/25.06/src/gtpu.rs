@@ -2,18 +2,17 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 // Implementation for sw_if_counters
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct SwIfCounters {
 	pub packets_rx: u64,
 	pub packets_tx: u64,
@@ -21,35 +20,101 @@ pub struct SwIfCounters {
 	pub bytes_tx: u64,
 }
 // Implementation for tunnel_metrics
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct TunnelMetrics {
 	pub sw_if_index: InterfaceIndex,
 	pub reserved: u32,
 	pub counters: SwIfCounters,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum GtpuForwardingType {
 	 GTPU_API_FORWARDING_NONE=0,
 	 GTPU_API_FORWARDING_BAD_HEADER=1,
 	 GTPU_API_FORWARDING_UNKNOWN_TEID=2,
 	 GTPU_API_FORWARDING_UNKNOWN_TYPE=4,
+	 Unknown(u32),
 }
 impl Default for GtpuForwardingType {
 	fn default() -> Self { GtpuForwardingType::GTPU_API_FORWARDING_NONE }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for GtpuForwardingType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 GtpuForwardingType::GTPU_API_FORWARDING_NONE => 0,
+			 GtpuForwardingType::GTPU_API_FORWARDING_BAD_HEADER => 1,
+			 GtpuForwardingType::GTPU_API_FORWARDING_UNKNOWN_TEID => 2,
+			 GtpuForwardingType::GTPU_API_FORWARDING_UNKNOWN_TYPE => 4,
+			 GtpuForwardingType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(GtpuForwardingType::GTPU_API_FORWARDING_NONE),
+			 1 => Some(GtpuForwardingType::GTPU_API_FORWARDING_BAD_HEADER),
+			 2 => Some(GtpuForwardingType::GTPU_API_FORWARDING_UNKNOWN_TEID),
+			 4 => Some(GtpuForwardingType::GTPU_API_FORWARDING_UNKNOWN_TYPE),
+			 value => Some(GtpuForwardingType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for GtpuForwardingType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for GtpuForwardingType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(GtpuForwardingType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum GtpuDecapNextType {
 	 GTPU_API_DECAP_NEXT_DROP=0,
 	 GTPU_API_DECAP_NEXT_L2=1,
 	 GTPU_API_DECAP_NEXT_IP4=2,
 	 GTPU_API_DECAP_NEXT_IP6=3,
+	 Unknown(u32),
 }
 impl Default for GtpuDecapNextType {
 	fn default() -> Self { GtpuDecapNextType::GTPU_API_DECAP_NEXT_DROP }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for GtpuDecapNextType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 GtpuDecapNextType::GTPU_API_DECAP_NEXT_DROP => 0,
+			 GtpuDecapNextType::GTPU_API_DECAP_NEXT_L2 => 1,
+			 GtpuDecapNextType::GTPU_API_DECAP_NEXT_IP4 => 2,
+			 GtpuDecapNextType::GTPU_API_DECAP_NEXT_IP6 => 3,
+			 GtpuDecapNextType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(GtpuDecapNextType::GTPU_API_DECAP_NEXT_DROP),
+			 1 => Some(GtpuDecapNextType::GTPU_API_DECAP_NEXT_L2),
+			 2 => Some(GtpuDecapNextType::GTPU_API_DECAP_NEXT_IP4),
+			 3 => Some(GtpuDecapNextType::GTPU_API_DECAP_NEXT_IP6),
+			 value => Some(GtpuDecapNextType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for GtpuDecapNextType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for GtpuDecapNextType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(GtpuDecapNextType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_add_del_tunnel_ca983a2b)]
 pub struct GtpuAddDelTunnel {
 	pub client_index: u32,
@@ -63,14 +128,14 @@ pub struct GtpuAddDelTunnel {
 	pub teid: u32,
 	pub tteid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_add_del_tunnel_reply_5383d31f)]
 pub struct GtpuAddDelTunnelReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_add_del_tunnel_v2_a0c30713)]
 pub struct GtpuAddDelTunnelV2 {
 	pub client_index: u32,
@@ -86,7 +151,7 @@ pub struct GtpuAddDelTunnelV2 {
 	pub pdu_extension: bool,
 	pub qfi: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_add_del_tunnel_v2_reply_62b41304)]
 pub struct GtpuAddDelTunnelV2Reply {
 	pub context: u32,
@@ -94,7 +159,7 @@ pub struct GtpuAddDelTunnelV2Reply {
 	pub sw_if_index: InterfaceIndex,
 	pub counters: SwIfCounters,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_tunnel_update_tteid_79f33816)]
 pub struct GtpuTunnelUpdateTteid {
 	pub client_index: u32,
@@ -104,20 +169,20 @@ pub struct GtpuTunnelUpdateTteid {
 	pub teid: u32,
 	pub tteid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_tunnel_update_tteid_reply_e8d4e804)]
 pub struct GtpuTunnelUpdateTteidReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_tunnel_dump_f9e6675e)]
 pub struct GtpuTunnelDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_tunnel_details_27f434ae)]
 pub struct GtpuTunnelDetails {
 	pub context: u32,
@@ -130,14 +195,14 @@ pub struct GtpuTunnelDetails {
 	pub teid: u32,
 	pub tteid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_tunnel_v2_dump_f9e6675e)]
 pub struct GtpuTunnelV2Dump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_tunnel_v2_details_8bf4ba92)]
 pub struct GtpuTunnelV2Details {
 	pub context: u32,
@@ -155,7 +220,7 @@ pub struct GtpuTunnelV2Details {
 	pub forwarding_type: GtpuForwardingType,
 	pub counters: SwIfCounters,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_gtpu_bypass_65247409)]
 pub struct SwInterfaceSetGtpuBypass {
 	pub client_index: u32,
@@ -164,13 +229,13 @@ pub struct SwInterfaceSetGtpuBypass {
 	pub is_ipv6: bool,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_gtpu_bypass_reply_e8d4e804)]
 pub struct SwInterfaceSetGtpuBypassReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_offload_rx_f0b08786)]
 pub struct GtpuOffloadRx {
 	pub client_index: u32,
@@ -179,13 +244,13 @@ pub struct GtpuOffloadRx {
 	pub sw_if_index: u32,
 	pub enable: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_offload_rx_reply_e8d4e804)]
 pub struct GtpuOffloadRxReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_add_del_forward_c6ccce13)]
 pub struct GtpuAddDelForward {
 	pub client_index: u32,
@@ -196,14 +261,14 @@ pub struct GtpuAddDelForward {
 	pub encap_vrf_id: u32,
 	pub decap_next_index: GtpuDecapNextType,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_add_del_forward_reply_5383d31f)]
 pub struct GtpuAddDelForwardReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_get_transfer_counts_61410788)]
 pub struct GtpuGetTransferCounts {
 	pub client_index: u32,
@@ -211,11 +276,33 @@ pub struct GtpuGetTransferCounts {
 	pub sw_if_index_start: InterfaceIndex,
 	pub capacity: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gtpu_get_transfer_counts_reply_e35f04bc)]
 pub struct GtpuGetTransferCountsReply {
 	pub context: u32,
 	pub retval: i32,
 	pub count: u32,
+	#[vpp(count = "count")]
 	pub tunnels: VariableSizeArray<TunnelMetrics>,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"gtpu_add_del_tunnel_ca983a2b",
+	"gtpu_add_del_tunnel_reply_5383d31f",
+	"gtpu_add_del_tunnel_v2_a0c30713",
+	"gtpu_add_del_tunnel_v2_reply_62b41304",
+	"gtpu_tunnel_update_tteid_79f33816",
+	"gtpu_tunnel_update_tteid_reply_e8d4e804",
+	"gtpu_tunnel_dump_f9e6675e",
+	"gtpu_tunnel_details_27f434ae",
+	"gtpu_tunnel_v2_dump_f9e6675e",
+	"gtpu_tunnel_v2_details_8bf4ba92",
+	"sw_interface_set_gtpu_bypass_65247409",
+	"sw_interface_set_gtpu_bypass_reply_e8d4e804",
+	"gtpu_offload_rx_f0b08786",
+	"gtpu_offload_rx_reply_e8d4e804",
+	"gtpu_add_del_forward_c6ccce13",
+	"gtpu_add_del_forward_reply_5383d31f",
+	"gtpu_get_transfer_counts_61410788",
+	"gtpu_get_transfer_counts_reply_e35f04bc",
+];
@@ -2,63 +2,121 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 // Implementation for igmp_group
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct IgmpGroup {
 	pub filter: FilterMode,
 	pub n_srcs: u8,
 	pub sw_if_index: InterfaceIndex,
 	pub gaddr: Ip4Address,
+	#[vpp(count = "n_srcs")]
 	pub saddrs: VariableSizeArray<Ip4Address>,
 }
 // Implementation for group_prefix
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct GroupPrefix {
 	pub typ: GroupPrefixType,
 	pub prefix: Prefix,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum FilterMode {
 	 EXCLUDE=0,
 	 INCLUDE=1,
+	 Unknown(u32),
 }
 impl Default for FilterMode {
 	fn default() -> Self { FilterMode::EXCLUDE }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for FilterMode {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FilterMode::EXCLUDE => 0,
+			 FilterMode::INCLUDE => 1,
+			 FilterMode::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(FilterMode::EXCLUDE),
+			 1 => Some(FilterMode::INCLUDE),
+			 value => Some(FilterMode::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for FilterMode {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FilterMode {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FilterMode::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum GroupPrefixType {
 	 ASM=0,
 	 SSM=1,
+	 Unknown(u32),
 }
 impl Default for GroupPrefixType {
 	fn default() -> Self { GroupPrefixType::ASM }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for GroupPrefixType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 GroupPrefixType::ASM => 0,
+			 GroupPrefixType::SSM => 1,
+			 GroupPrefixType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(GroupPrefixType::ASM),
+			 1 => Some(GroupPrefixType::SSM),
+			 value => Some(GroupPrefixType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for GroupPrefixType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for GroupPrefixType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(GroupPrefixType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_listen_19a49f1e)]
 pub struct IgmpListen {
 	pub client_index: u32,
 	pub context: u32,
 	pub group: IgmpGroup,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_listen_reply_e8d4e804)]
 pub struct IgmpListenReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_enable_disable_b1edfb96)]
 pub struct IgmpEnableDisable {
 	pub client_index: u32,
@@ -67,13 +125,13 @@ pub struct IgmpEnableDisable {
 	pub mode: u8,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_enable_disable_reply_e8d4e804)]
 pub struct IgmpEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_proxy_device_add_del_0b9be9ce)]
 pub struct IgmpProxyDeviceAddDel {
 	pub client_index: u32,
@@ -82,13 +140,13 @@ pub struct IgmpProxyDeviceAddDel {
 	pub vrf_id: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_proxy_device_add_del_reply_e8d4e804)]
 pub struct IgmpProxyDeviceAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_proxy_device_add_del_interface_1a9ec24a)]
 pub struct IgmpProxyDeviceAddDelInterface {
 	pub client_index: u32,
@@ -97,20 +155,20 @@ pub struct IgmpProxyDeviceAddDelInterface {
 	pub vrf_id: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_proxy_device_add_del_interface_reply_e8d4e804)]
 pub struct IgmpProxyDeviceAddDelInterfaceReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_dump_f9e6675e)]
 pub struct IgmpDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_details_38f09929)]
 pub struct IgmpDetails {
 	pub context: u32,
@@ -118,20 +176,20 @@ pub struct IgmpDetails {
 	pub saddr: Ip4Address,
 	pub gaddr: Ip4Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_clear_interface_f9e6675e)]
 pub struct IgmpClearInterface {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_clear_interface_reply_e8d4e804)]
 pub struct IgmpClearInterfaceReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_igmp_events_cfaccc1f)]
 pub struct WantIgmpEvents {
 	pub client_index: u32,
@@ -139,13 +197,13 @@ pub struct WantIgmpEvents {
 	pub enable: u32,
 	pub pid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_igmp_events_reply_e8d4e804)]
 pub struct WantIgmpEventsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_event_85fe93ec)]
 pub struct IgmpEvent {
 	pub sw_if_index: InterfaceIndex,
@@ -153,28 +211,50 @@ pub struct IgmpEvent {
 	pub saddr: Ip4Address,
 	pub gaddr: Ip4Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_group_prefix_set_5b14a5ce)]
 pub struct IgmpGroupPrefixSet {
 	pub client_index: u32,
 	pub context: u32,
 	pub gp: GroupPrefix,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_group_prefix_set_reply_e8d4e804)]
 pub struct IgmpGroupPrefixSetReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_group_prefix_dump_51077d14)]
 pub struct IgmpGroupPrefixDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(igmp_group_prefix_details_259ccd81)]
 pub struct IgmpGroupPrefixDetails {
 	pub context: u32,
 	pub gp: GroupPrefix,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"igmp_listen_19a49f1e",
+	"igmp_listen_reply_e8d4e804",
+	"igmp_enable_disable_b1edfb96",
+	"igmp_enable_disable_reply_e8d4e804",
+	"igmp_proxy_device_add_del_0b9be9ce",
+	"igmp_proxy_device_add_del_reply_e8d4e804",
+	"igmp_proxy_device_add_del_interface_1a9ec24a",
+	"igmp_proxy_device_add_del_interface_reply_e8d4e804",
+	"igmp_dump_f9e6675e",
+	"igmp_details_38f09929",
+	"igmp_clear_interface_f9e6675e",
+	"igmp_clear_interface_reply_e8d4e804",
+	"want_igmp_events_cfaccc1f",
+	"want_igmp_events_reply_e8d4e804",
+	"igmp_event_85fe93ec",
+	"igmp_group_prefix_set_5b14a5ce",
+	"igmp_group_prefix_set_reply_e8d4e804",
+	"igmp_group_prefix_dump_51077d14",
+	"igmp_group_prefix_details_259ccd81",
+];
@@ -2,25 +2,24 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 // Implementation for ikev2_id
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2Id {
 	pub typ: u8,
 	pub data_len: u8,
 	pub data: FixedSizeString<typenum::U64>,
 }
 // Implementation for ikev2_ts
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2Ts {
 	pub sa_index: u32,
 	pub child_sa_index: u32,
@@ -32,21 +31,22 @@ pub struct Ikev2Ts {
 	pub end_addr: Address,
 }
 // Implementation for ikev2_auth
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2Auth {
 	pub method: u8,
 	pub hex: u8,
 	pub data_len: u32,
+	#[vpp(count = "data_len")]
 	pub data: VariableSizeArray<u8>,
 }
 // Implementation for ikev2_responder
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2Responder {
 	pub sw_if_index: InterfaceIndex,
 	pub addr: Address,
 }
 // Implementation for ikev2_ike_transforms
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2IkeTransforms {
 	pub crypto_alg: u8,
 	pub crypto_key_size: u32,
@@ -54,14 +54,14 @@ pub struct Ikev2IkeTransforms {
 	pub dh_group: u8,
 }
 // Implementation for ikev2_esp_transforms
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2EspTransforms {
 	pub crypto_alg: u8,
 	pub crypto_key_size: u32,
 	pub integ_alg: u8,
 }
 // Implementation for ikev2_profile
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2Profile {
 	pub name: FixedSizeString<typenum::U64>,
 	pub loc_id: Ikev2Id,
@@ -82,7 +82,7 @@ pub struct Ikev2Profile {
 	pub auth: Ikev2Auth,
 }
 // Implementation for ikev2_sa_transform
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2SaTransform {
 	pub transform_type: u8,
 	pub transform_id: u16,
@@ -92,7 +92,7 @@ pub struct Ikev2SaTransform {
 	pub dh_group: u8,
 }
 // Implementation for ikev2_keys
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2Keys {
 	pub sk_d: FixedSizeArray<u8, typenum::U64>,
 	pub sk_d_len: u8,
@@ -110,7 +110,7 @@ pub struct Ikev2Keys {
 	pub sk_pr_len: u8,
 }
 // Implementation for ikev2_child_sa
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2ChildSa {
 	pub sa_index: u32,
 	pub child_sa_index: u32,
@@ -122,7 +122,7 @@ pub struct Ikev2ChildSa {
 	pub esn: Ikev2SaTransform,
 }
 // Implementation for ikev2_child_sa_v2
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2ChildSaV2 {
 	pub sa_index: u32,
 	pub child_sa_index: u32,
@@ -135,7 +135,7 @@ pub struct Ikev2ChildSaV2 {
 	pub uptime: f64,
 }
 // Implementation for ikev2_sa_stats
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2SaStats {
 	pub n_keepalives: u16,
 	pub n_rekey_req: u16,
@@ -145,7 +145,7 @@ pub struct Ikev2SaStats {
 	pub n_init_sa_retransmit: u16,
 }
 // Implementation for ikev2_sa
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2Sa {
 	pub sa_index: u32,
 	pub profile_index: u32,
@@ -163,7 +163,7 @@ pub struct Ikev2Sa {
 	pub stats: Ikev2SaStats,
 }
 // Implementation for ikev2_sa_v2
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2SaV2 {
 	pub sa_index: u32,
 	pub profile_name: FixedSizeString<typenum::U64>,
@@ -182,7 +182,7 @@ pub struct Ikev2SaV2 {
 	pub stats: Ikev2SaStats,
 }
 // Implementation for ikev2_sa_v3
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ikev2SaV3 {
 	pub sa_index: u32,
 	pub profile_name: FixedSizeString<typenum::U64>,
@@ -201,7 +201,7 @@ pub struct Ikev2SaV3 {
 	pub stats: Ikev2SaStats,
 	pub uptime: f64,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum Ikev2State {
 	 UNKNOWN=0,
@@ -212,7 +212,51 @@ pub enum Ikev2State {
 	 NOTIFY_AND_DELETE=5,
 	 TS_UNACCEPTABLE=6,
 	 NO_PROPOSAL_CHOSEN=7,
+	 Unknown(u32),
 }
 impl Default for Ikev2State {
 	fn default() -> Self { Ikev2State::UNKNOWN }
 }
+impl AsU32 for Ikev2State {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 Ikev2State::UNKNOWN => 0,
+			 Ikev2State::SA_INIT => 1,
+			 Ikev2State::DELETED => 2,
+			 Ikev2State::AUTH_FAILED => 3,
+			 Ikev2State::AUTHENTICATED => 4,
+			 Ikev2State::NOTIFY_AND_DELETE => 5,
+			 Ikev2State::TS_UNACCEPTABLE => 6,
+			 Ikev2State::NO_PROPOSAL_CHOSEN => 7,
+			 Ikev2State::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(Ikev2State::UNKNOWN),
+			 1 => Some(Ikev2State::SA_INIT),
+			 2 => Some(Ikev2State::DELETED),
+			 3 => Some(Ikev2State::AUTH_FAILED),
+			 4 => Some(Ikev2State::AUTHENTICATED),
+			 5 => Some(Ikev2State::NOTIFY_AND_DELETE),
+			 6 => Some(Ikev2State::TS_UNACCEPTABLE),
+			 7 => Some(Ikev2State::NO_PROPOSAL_CHOSEN),
+			 value => Some(Ikev2State::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for Ikev2State {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for Ikev2State {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(Ikev2State::Unknown(value)))
+	 }
+}
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
@@ -2,74 +2,145 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
 // Implementation for ip_neighbor
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct IpNeighbor {
 	pub sw_if_index: InterfaceIndex,
 	pub flags: EnumFlag<IpNeighborFlags>,
 	pub mac_address: MacAddress,
 	pub ip_address: Address,
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u8)]
 pub enum IpNeighborFlags {
 	 IP_API_NEIGHBOR_FLAG_NONE=0,
 	 IP_API_NEIGHBOR_FLAG_STATIC=1,
 	 IP_API_NEIGHBOR_FLAG_NO_FIB_ENTRY=2,
+	 Unknown(u32),
 }
 impl Default for IpNeighborFlags {
 	fn default() -> Self { IpNeighborFlags::IP_API_NEIGHBOR_FLAG_NONE }
 }
-impl AsEnumFlag for IpNeighborFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for IpNeighborFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IpNeighborFlags::IP_API_NEIGHBOR_FLAG_NONE => 0,
+			 IpNeighborFlags::IP_API_NEIGHBOR_FLAG_STATIC => 1,
+			 IpNeighborFlags::IP_API_NEIGHBOR_FLAG_NO_FIB_ENTRY => 2,
+			 IpNeighborFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 0 => IpNeighborFlags::IP_API_NEIGHBOR_FLAG_NONE,
-			 1 => IpNeighborFlags::IP_API_NEIGHBOR_FLAG_STATIC,
-			 2 => IpNeighborFlags::IP_API_NEIGHBOR_FLAG_NO_FIB_ENTRY,
-			_ => panic!("Invalid Enum Descriminant")
+			 0 => Some(IpNeighborFlags::IP_API_NEIGHBOR_FLAG_NONE),
+			 1 => Some(IpNeighborFlags::IP_API_NEIGHBOR_FLAG_STATIC),
+			 2 => Some(IpNeighborFlags::IP_API_NEIGHBOR_FLAG_NO_FIB_ENTRY),
+			 value => Some(IpNeighborFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for IpNeighborFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("IpNeighborFlags value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IpNeighborFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IpNeighborFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for IpNeighborFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 8 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x3
+	}
+}
+impl std::ops::BitOr for IpNeighborFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum IpNeighborEventFlags {
 	 IP_NEIGHBOR_API_EVENT_FLAG_ADDED=1,
 	 IP_NEIGHBOR_API_EVENT_FLAG_REMOVED=2,
+	 Unknown(u32),
 }
 impl Default for IpNeighborEventFlags {
 	fn default() -> Self { IpNeighborEventFlags::IP_NEIGHBOR_API_EVENT_FLAG_ADDED }
 }
-impl AsEnumFlag for IpNeighborEventFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for IpNeighborEventFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IpNeighborEventFlags::IP_NEIGHBOR_API_EVENT_FLAG_ADDED => 1,
+			 IpNeighborEventFlags::IP_NEIGHBOR_API_EVENT_FLAG_REMOVED => 2,
+			 IpNeighborEventFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => IpNeighborEventFlags::IP_NEIGHBOR_API_EVENT_FLAG_ADDED,
-			 2 => IpNeighborEventFlags::IP_NEIGHBOR_API_EVENT_FLAG_REMOVED,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(IpNeighborEventFlags::IP_NEIGHBOR_API_EVENT_FLAG_ADDED),
+			 2 => Some(IpNeighborEventFlags::IP_NEIGHBOR_API_EVENT_FLAG_REMOVED),
+			 value => Some(IpNeighborEventFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for IpNeighborEventFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IpNeighborEventFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IpNeighborEventFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for IpNeighborEventFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x3
+	}
+}
+impl std::ops::BitOr for IpNeighborEventFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_add_del_0607c257)]
 pub struct IpNeighborAddDel {
 	pub client_index: u32,
@@ -77,14 +148,14 @@ pub struct IpNeighborAddDel {
 	pub is_add: bool,
 	pub neighbor: IpNeighbor,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_add_del_reply_1992deab)]
 pub struct IpNeighborAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 	pub stats_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_dump_d817a484)]
 pub struct IpNeighborDump {
 	pub client_index: u32,
@@ -92,14 +163,14 @@ pub struct IpNeighborDump {
 	pub sw_if_index: InterfaceIndex,
 	pub af: AddressFamily,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_details_e29d79f0)]
 pub struct IpNeighborDetails {
 	pub context: u32,
 	pub age: f64,
 	pub neighbor: IpNeighbor,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_config_f4a5cf44)]
 pub struct IpNeighborConfig {
 	pub client_index: u32,
@@ -109,20 +180,20 @@ pub struct IpNeighborConfig {
 	pub max_age: u32,
 	pub recycle: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_config_reply_e8d4e804)]
 pub struct IpNeighborConfigReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_config_get_a5db7bf7)]
 pub struct IpNeighborConfigGet {
 	pub client_index: u32,
 	pub context: u32,
 	pub af: AddressFamily,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_config_get_reply_798e6fdd)]
 pub struct IpNeighborConfigGetReply {
 	pub context: u32,
@@ -132,31 +203,31 @@ pub struct IpNeighborConfigGetReply {
 	pub max_age: u32,
 	pub recycle: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_replace_begin_51077d14)]
 pub struct IpNeighborReplaceBegin {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_replace_begin_reply_e8d4e804)]
 pub struct IpNeighborReplaceBeginReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_replace_end_51077d14)]
 pub struct IpNeighborReplaceEnd {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_replace_end_reply_e8d4e804)]
 pub struct IpNeighborReplaceEndReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_flush_16aa35d2)]
 pub struct IpNeighborFlush {
 	pub client_index: u32,
@@ -164,13 +235,13 @@ pub struct IpNeighborFlush {
 	pub af: AddressFamily,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_flush_reply_e8d4e804)]
 pub struct IpNeighborFlushReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_ip_neighbor_events_73e70a86)]
 pub struct WantIpNeighborEvents {
 	pub client_index: u32,
@@ -180,20 +251,20 @@ pub struct WantIpNeighborEvents {
 	pub ip: Address,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_ip_neighbor_events_reply_e8d4e804)]
 pub struct WantIpNeighborEventsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_event_bdb092b2)]
 pub struct IpNeighborEvent {
 	pub client_index: u32,
 	pub pid: u32,
 	pub neighbor: IpNeighbor,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_ip_neighbor_events_v2_73e70a86)]
 pub struct WantIpNeighborEventsV2 {
 	pub client_index: u32,
@@ -203,13 +274,13 @@ pub struct WantIpNeighborEventsV2 {
 	pub ip: Address,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_ip_neighbor_events_v2_reply_e8d4e804)]
 pub struct WantIpNeighborEventsV2Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(ip_neighbor_event_v2_c1d53dc0)]
 pub struct IpNeighborEventV2 {
 	pub client_index: u32,
@@ -217,3 +288,26 @@ pub struct IpNeighborEventV2 {
 	 pub flags: EnumFlag<IpNeighborEventFlags>,
 	pub neighbor: IpNeighbor,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"ip_neighbor_add_del_0607c257",
+	"ip_neighbor_add_del_reply_1992deab",
+	"ip_neighbor_dump_d817a484",
+	"ip_neighbor_details_e29d79f0",
+	"ip_neighbor_config_f4a5cf44",
+	"ip_neighbor_config_reply_e8d4e804",
+	"ip_neighbor_config_get_a5db7bf7",
+	"ip_neighbor_config_get_reply_798e6fdd",
+	"ip_neighbor_replace_begin_51077d14",
+	"ip_neighbor_replace_begin_reply_e8d4e804",
+	"ip_neighbor_replace_end_51077d14",
+	"ip_neighbor_replace_end_reply_e8d4e804",
+	"ip_neighbor_flush_16aa35d2",
+	"ip_neighbor_flush_reply_e8d4e804",
+	"want_ip_neighbor_events_73e70a86",
+	"want_ip_neighbor_events_reply_e8d4e804",
+	"ip_neighbor_event_bdb092b2",
+	"want_ip_neighbor_events_v2_73e70a86",
+	"want_ip_neighbor_events_v2_reply_e8d4e804",
+	"ip_neighbor_event_v2_c1d53dc0",
+];
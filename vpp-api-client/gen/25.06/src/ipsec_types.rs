@@ -2,25 +2,24 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::tunnel_types::*;
 use crate::interface_types::*;
 // Implementation for key
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Key {
 	pub length: u8,
 	pub data: FixedSizeArray<u8, typenum::U128>,
 }
 // Implementation for ipsec_spd_entry
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct IpsecSpdEntry {
 	pub spd_id: u32,
 	pub priority: i32,
@@ -38,7 +37,7 @@ pub struct IpsecSpdEntry {
 	pub local_port_stop: u16,
 }
 // Implementation for ipsec_spd_entry_v2
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct IpsecSpdEntryV2 {
 	pub spd_id: u32,
 	pub priority: i32,
@@ -56,7 +55,7 @@ pub struct IpsecSpdEntryV2 {
 	pub local_port_stop: u16,
 }
 // Implementation for ipsec_sad_entry
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct IpsecSadEntry {
 	pub sad_id: u32,
 	pub spi: u32,
@@ -74,7 +73,7 @@ pub struct IpsecSadEntry {
 	pub udp_dst_port: u16,
 }
 // Implementation for ipsec_sad_entry_v2
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct IpsecSadEntryV2 {
 	pub sad_id: u32,
 	pub spi: u32,
@@ -94,7 +93,7 @@ pub struct IpsecSadEntryV2 {
 	pub udp_dst_port: u16,
 }
 // Implementation for ipsec_sad_entry_v3
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct IpsecSadEntryV3 {
 	pub sad_id: u32,
 	pub spi: u32,
@@ -110,7 +109,7 @@ pub struct IpsecSadEntryV3 {
 	pub udp_dst_port: u16,
 }
 // Implementation for ipsec_sad_entry_v4
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct IpsecSadEntryV4 {
 	pub sad_id: u32,
 	pub spi: u32,
@@ -126,7 +125,7 @@ pub struct IpsecSadEntryV4 {
 	pub udp_dst_port: u16,
 	pub anti_replay_window_size: u32,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum IpsecCryptoAlg {
 	 IPSEC_API_CRYPTO_ALG_NONE=0,
@@ -145,11 +144,68 @@ pub enum IpsecCryptoAlg {
 	 IPSEC_API_CRYPTO_ALG_AES_NULL_GMAC_128=13,
 	 IPSEC_API_CRYPTO_ALG_AES_NULL_GMAC_192=14,
 	 IPSEC_API_CRYPTO_ALG_AES_NULL_GMAC_256=15,
+	 Unknown(u32),
 }
 impl Default for IpsecCryptoAlg {
 	fn default() -> Self { IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_NONE }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for IpsecCryptoAlg {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_NONE => 0,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_CBC_128 => 1,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_CBC_192 => 2,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_CBC_256 => 3,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_CTR_128 => 4,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_CTR_192 => 5,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_CTR_256 => 6,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_GCM_128 => 7,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_GCM_192 => 8,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_GCM_256 => 9,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_DES_CBC => 10,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_3DES_CBC => 11,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_CHACHA20_POLY1305 => 12,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_NULL_GMAC_128 => 13,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_NULL_GMAC_192 => 14,
+			 IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_NULL_GMAC_256 => 15,
+			 IpsecCryptoAlg::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_NONE),
+			 1 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_CBC_128),
+			 2 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_CBC_192),
+			 3 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_CBC_256),
+			 4 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_CTR_128),
+			 5 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_CTR_192),
+			 6 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_CTR_256),
+			 7 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_GCM_128),
+			 8 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_GCM_192),
+			 9 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_GCM_256),
+			 10 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_DES_CBC),
+			 11 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_3DES_CBC),
+			 12 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_CHACHA20_POLY1305),
+			 13 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_NULL_GMAC_128),
+			 14 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_NULL_GMAC_192),
+			 15 => Some(IpsecCryptoAlg::IPSEC_API_CRYPTO_ALG_AES_NULL_GMAC_256),
+			 value => Some(IpsecCryptoAlg::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for IpsecCryptoAlg {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IpsecCryptoAlg {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IpsecCryptoAlg::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum IpsecIntegAlg {
 	 IPSEC_API_INTEG_ALG_NONE=0,
@@ -159,11 +215,51 @@ pub enum IpsecIntegAlg {
 	 IPSEC_API_INTEG_ALG_SHA_256_128=4,
 	 IPSEC_API_INTEG_ALG_SHA_384_192=5,
 	 IPSEC_API_INTEG_ALG_SHA_512_256=6,
+	 Unknown(u32),
 }
 impl Default for IpsecIntegAlg {
 	fn default() -> Self { IpsecIntegAlg::IPSEC_API_INTEG_ALG_NONE }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+impl AsU32 for IpsecIntegAlg {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IpsecIntegAlg::IPSEC_API_INTEG_ALG_NONE => 0,
+			 IpsecIntegAlg::IPSEC_API_INTEG_ALG_MD5_96 => 1,
+			 IpsecIntegAlg::IPSEC_API_INTEG_ALG_SHA1_96 => 2,
+			 IpsecIntegAlg::IPSEC_API_INTEG_ALG_SHA_256_96 => 3,
+			 IpsecIntegAlg::IPSEC_API_INTEG_ALG_SHA_256_128 => 4,
+			 IpsecIntegAlg::IPSEC_API_INTEG_ALG_SHA_384_192 => 5,
+			 IpsecIntegAlg::IPSEC_API_INTEG_ALG_SHA_512_256 => 6,
+			 IpsecIntegAlg::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(IpsecIntegAlg::IPSEC_API_INTEG_ALG_NONE),
+			 1 => Some(IpsecIntegAlg::IPSEC_API_INTEG_ALG_MD5_96),
+			 2 => Some(IpsecIntegAlg::IPSEC_API_INTEG_ALG_SHA1_96),
+			 3 => Some(IpsecIntegAlg::IPSEC_API_INTEG_ALG_SHA_256_96),
+			 4 => Some(IpsecIntegAlg::IPSEC_API_INTEG_ALG_SHA_256_128),
+			 5 => Some(IpsecIntegAlg::IPSEC_API_INTEG_ALG_SHA_384_192),
+			 6 => Some(IpsecIntegAlg::IPSEC_API_INTEG_ALG_SHA_512_256),
+			 value => Some(IpsecIntegAlg::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for IpsecIntegAlg {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IpsecIntegAlg {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IpsecIntegAlg::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum IpsecSadFlags {
 	 IPSEC_API_SAD_FLAG_NONE=0,
 	 IPSEC_API_SAD_FLAG_USE_ESN=1,
@@ -173,48 +269,153 @@ pub enum IpsecSadFlags {
 	 IPSEC_API_SAD_FLAG_UDP_ENCAP=16,
 	 IPSEC_API_SAD_FLAG_IS_INBOUND=64,
 	 IPSEC_API_SAD_FLAG_ASYNC=128,
+	 Unknown(u32),
 }
 impl Default for IpsecSadFlags {
 	fn default() -> Self { IpsecSadFlags::IPSEC_API_SAD_FLAG_NONE }
 }
-impl AsEnumFlag for IpsecSadFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for IpsecSadFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IpsecSadFlags::IPSEC_API_SAD_FLAG_NONE => 0,
+			 IpsecSadFlags::IPSEC_API_SAD_FLAG_USE_ESN => 1,
+			 IpsecSadFlags::IPSEC_API_SAD_FLAG_USE_ANTI_REPLAY => 2,
+			 IpsecSadFlags::IPSEC_API_SAD_FLAG_IS_TUNNEL => 4,
+			 IpsecSadFlags::IPSEC_API_SAD_FLAG_IS_TUNNEL_V6 => 8,
+			 IpsecSadFlags::IPSEC_API_SAD_FLAG_UDP_ENCAP => 16,
+			 IpsecSadFlags::IPSEC_API_SAD_FLAG_IS_INBOUND => 64,
+			 IpsecSadFlags::IPSEC_API_SAD_FLAG_ASYNC => 128,
+			 IpsecSadFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 0 => IpsecSadFlags::IPSEC_API_SAD_FLAG_NONE,
-			 1 => IpsecSadFlags::IPSEC_API_SAD_FLAG_USE_ESN,
-			 2 => IpsecSadFlags::IPSEC_API_SAD_FLAG_USE_ANTI_REPLAY,
-			 4 => IpsecSadFlags::IPSEC_API_SAD_FLAG_IS_TUNNEL,
-			 8 => IpsecSadFlags::IPSEC_API_SAD_FLAG_IS_TUNNEL_V6,
-			 16 => IpsecSadFlags::IPSEC_API_SAD_FLAG_UDP_ENCAP,
-			 64 => IpsecSadFlags::IPSEC_API_SAD_FLAG_IS_INBOUND,
-			 128 => IpsecSadFlags::IPSEC_API_SAD_FLAG_ASYNC,
-			_ => panic!("Invalid Enum Descriminant")
+			 0 => Some(IpsecSadFlags::IPSEC_API_SAD_FLAG_NONE),
+			 1 => Some(IpsecSadFlags::IPSEC_API_SAD_FLAG_USE_ESN),
+			 2 => Some(IpsecSadFlags::IPSEC_API_SAD_FLAG_USE_ANTI_REPLAY),
+			 4 => Some(IpsecSadFlags::IPSEC_API_SAD_FLAG_IS_TUNNEL),
+			 8 => Some(IpsecSadFlags::IPSEC_API_SAD_FLAG_IS_TUNNEL_V6),
+			 16 => Some(IpsecSadFlags::IPSEC_API_SAD_FLAG_UDP_ENCAP),
+			 64 => Some(IpsecSadFlags::IPSEC_API_SAD_FLAG_IS_INBOUND),
+			 128 => Some(IpsecSadFlags::IPSEC_API_SAD_FLAG_ASYNC),
+			 value => Some(IpsecSadFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for IpsecSadFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IpsecSadFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IpsecSadFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for IpsecSadFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0xdf
+	}
+}
+impl std::ops::BitOr for IpsecSadFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum IpsecProto {
 	 IPSEC_API_PROTO_ESP=50,
 	 IPSEC_API_PROTO_AH=51,
+	 Unknown(u32),
 }
 impl Default for IpsecProto {
 	fn default() -> Self { IpsecProto::IPSEC_API_PROTO_ESP }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for IpsecProto {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IpsecProto::IPSEC_API_PROTO_ESP => 50,
+			 IpsecProto::IPSEC_API_PROTO_AH => 51,
+			 IpsecProto::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 50 => Some(IpsecProto::IPSEC_API_PROTO_ESP),
+			 51 => Some(IpsecProto::IPSEC_API_PROTO_AH),
+			 value => Some(IpsecProto::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for IpsecProto {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IpsecProto {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IpsecProto::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum IpsecSpdAction {
 	 IPSEC_API_SPD_ACTION_BYPASS=0,
 	 IPSEC_API_SPD_ACTION_DISCARD=1,
 	 IPSEC_API_SPD_ACTION_RESOLVE=2,
 	 IPSEC_API_SPD_ACTION_PROTECT=3,
+	 Unknown(u32),
 }
 impl Default for IpsecSpdAction {
 	fn default() -> Self { IpsecSpdAction::IPSEC_API_SPD_ACTION_BYPASS }
 }
+impl AsU32 for IpsecSpdAction {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IpsecSpdAction::IPSEC_API_SPD_ACTION_BYPASS => 0,
+			 IpsecSpdAction::IPSEC_API_SPD_ACTION_DISCARD => 1,
+			 IpsecSpdAction::IPSEC_API_SPD_ACTION_RESOLVE => 2,
+			 IpsecSpdAction::IPSEC_API_SPD_ACTION_PROTECT => 3,
+			 IpsecSpdAction::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(IpsecSpdAction::IPSEC_API_SPD_ACTION_BYPASS),
+			 1 => Some(IpsecSpdAction::IPSEC_API_SPD_ACTION_DISCARD),
+			 2 => Some(IpsecSpdAction::IPSEC_API_SPD_ACTION_RESOLVE),
+			 3 => Some(IpsecSpdAction::IPSEC_API_SPD_ACTION_PROTECT),
+			 value => Some(IpsecSpdAction::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for IpsecSpdAction {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IpsecSpdAction {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IpsecSpdAction::Unknown(value)))
+	 }
+}
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
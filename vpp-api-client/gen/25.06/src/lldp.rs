@@ -2,17 +2,16 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum PortIdSubtype {
 	 PORT_ID_SUBTYPE_RESERVED=0,
@@ -23,11 +22,52 @@ pub enum PortIdSubtype {
 	 PORT_ID_SUBTYPE_INTF_NAME=5,
 	 PORT_ID_SUBTYPE_AGENT_CIRCUIT_ID=6,
 	 PORT_ID_SUBTYPE_LOCAL=7,
+	 Unknown(u32),
 }
 impl Default for PortIdSubtype {
 	fn default() -> Self { PortIdSubtype::PORT_ID_SUBTYPE_RESERVED }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for PortIdSubtype {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 PortIdSubtype::PORT_ID_SUBTYPE_RESERVED => 0,
+			 PortIdSubtype::PORT_ID_SUBTYPE_INTF_ALIAS => 1,
+			 PortIdSubtype::PORT_ID_SUBTYPE_PORT_COMP => 2,
+			 PortIdSubtype::PORT_ID_SUBTYPE_MAC_ADDR => 3,
+			 PortIdSubtype::PORT_ID_SUBTYPE_NET_ADDR => 4,
+			 PortIdSubtype::PORT_ID_SUBTYPE_INTF_NAME => 5,
+			 PortIdSubtype::PORT_ID_SUBTYPE_AGENT_CIRCUIT_ID => 6,
+			 PortIdSubtype::PORT_ID_SUBTYPE_LOCAL => 7,
+			 PortIdSubtype::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(PortIdSubtype::PORT_ID_SUBTYPE_RESERVED),
+			 1 => Some(PortIdSubtype::PORT_ID_SUBTYPE_INTF_ALIAS),
+			 2 => Some(PortIdSubtype::PORT_ID_SUBTYPE_PORT_COMP),
+			 3 => Some(PortIdSubtype::PORT_ID_SUBTYPE_MAC_ADDR),
+			 4 => Some(PortIdSubtype::PORT_ID_SUBTYPE_NET_ADDR),
+			 5 => Some(PortIdSubtype::PORT_ID_SUBTYPE_INTF_NAME),
+			 6 => Some(PortIdSubtype::PORT_ID_SUBTYPE_AGENT_CIRCUIT_ID),
+			 7 => Some(PortIdSubtype::PORT_ID_SUBTYPE_LOCAL),
+			 value => Some(PortIdSubtype::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for PortIdSubtype {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for PortIdSubtype {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(PortIdSubtype::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum ChassisIdSubtype {
 	 CHASSIS_ID_SUBTYPE_RESERVED=0,
@@ -38,11 +78,52 @@ pub enum ChassisIdSubtype {
 	 CHASSIS_ID_SUBTYPE_NET_ADDR=5,
 	 CHASSIS_ID_SUBTYPE_INTF_NAME=6,
 	 CHASSIS_ID_SUBTYPE_LOCAL=7,
+	 Unknown(u32),
 }
 impl Default for ChassisIdSubtype {
 	fn default() -> Self { ChassisIdSubtype::CHASSIS_ID_SUBTYPE_RESERVED }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for ChassisIdSubtype {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 ChassisIdSubtype::CHASSIS_ID_SUBTYPE_RESERVED => 0,
+			 ChassisIdSubtype::CHASSIS_ID_SUBTYPE_CHASSIS_COMP => 1,
+			 ChassisIdSubtype::CHASSIS_ID_SUBTYPE_INTF_ALIAS => 2,
+			 ChassisIdSubtype::CHASSIS_ID_SUBTYPE_PORT_COMP => 3,
+			 ChassisIdSubtype::CHASSIS_ID_SUBTYPE_MAC_ADDR => 4,
+			 ChassisIdSubtype::CHASSIS_ID_SUBTYPE_NET_ADDR => 5,
+			 ChassisIdSubtype::CHASSIS_ID_SUBTYPE_INTF_NAME => 6,
+			 ChassisIdSubtype::CHASSIS_ID_SUBTYPE_LOCAL => 7,
+			 ChassisIdSubtype::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(ChassisIdSubtype::CHASSIS_ID_SUBTYPE_RESERVED),
+			 1 => Some(ChassisIdSubtype::CHASSIS_ID_SUBTYPE_CHASSIS_COMP),
+			 2 => Some(ChassisIdSubtype::CHASSIS_ID_SUBTYPE_INTF_ALIAS),
+			 3 => Some(ChassisIdSubtype::CHASSIS_ID_SUBTYPE_PORT_COMP),
+			 4 => Some(ChassisIdSubtype::CHASSIS_ID_SUBTYPE_MAC_ADDR),
+			 5 => Some(ChassisIdSubtype::CHASSIS_ID_SUBTYPE_NET_ADDR),
+			 6 => Some(ChassisIdSubtype::CHASSIS_ID_SUBTYPE_INTF_NAME),
+			 7 => Some(ChassisIdSubtype::CHASSIS_ID_SUBTYPE_LOCAL),
+			 value => Some(ChassisIdSubtype::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for ChassisIdSubtype {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for ChassisIdSubtype {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(ChassisIdSubtype::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lldp_config_c14445df)]
 pub struct LldpConfig {
 	pub client_index: u32,
@@ -51,13 +132,13 @@ pub struct LldpConfig {
 	pub tx_interval: u32,
 	pub system_name: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lldp_config_reply_e8d4e804)]
 pub struct LldpConfigReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_lldp_57afbcd4)]
 pub struct SwInterfaceSetLldp {
 	pub client_index: u32,
@@ -69,27 +150,27 @@ pub struct SwInterfaceSetLldp {
 	pub enable: bool,
 	pub port_desc: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_lldp_reply_e8d4e804)]
 pub struct SwInterfaceSetLldpReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lldp_dump_f75ba505)]
 pub struct LldpDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lldp_dump_reply_53b48f5d)]
 pub struct LldpDumpReply {
 	pub context: u32,
 	pub retval: i32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lldp_details_c2d226cd)]
 pub struct LldpDetails {
 	pub context: u32,
@@ -104,3 +185,13 @@ pub struct LldpDetails {
 	pub port_id_subtype: PortIdSubtype,
 	pub chassis_id_subtype: ChassisIdSubtype,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"lldp_config_c14445df",
+	"lldp_config_reply_e8d4e804",
+	"sw_interface_set_lldp_57afbcd4",
+	"sw_interface_set_lldp_reply_e8d4e804",
+	"lldp_dump_f75ba505",
+	"lldp_dump_reply_53b48f5d",
+	"lldp_details_c2d226cd",
+];
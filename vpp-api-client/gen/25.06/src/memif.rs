@@ -2,36 +2,95 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum MemifRole {
 	 MEMIF_ROLE_API_MASTER=0,
 	 MEMIF_ROLE_API_SLAVE=1,
+	 Unknown(u32),
 }
 impl Default for MemifRole {
 	fn default() -> Self { MemifRole::MEMIF_ROLE_API_MASTER }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for MemifRole {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 MemifRole::MEMIF_ROLE_API_MASTER => 0,
+			 MemifRole::MEMIF_ROLE_API_SLAVE => 1,
+			 MemifRole::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(MemifRole::MEMIF_ROLE_API_MASTER),
+			 1 => Some(MemifRole::MEMIF_ROLE_API_SLAVE),
+			 value => Some(MemifRole::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for MemifRole {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for MemifRole {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(MemifRole::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum MemifMode {
 	 MEMIF_MODE_API_ETHERNET=0,
 	 MEMIF_MODE_API_IP=1,
 	 MEMIF_MODE_API_PUNT_INJECT=2,
+	 Unknown(u32),
 }
 impl Default for MemifMode {
 	fn default() -> Self { MemifMode::MEMIF_MODE_API_ETHERNET }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for MemifMode {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 MemifMode::MEMIF_MODE_API_ETHERNET => 0,
+			 MemifMode::MEMIF_MODE_API_IP => 1,
+			 MemifMode::MEMIF_MODE_API_PUNT_INJECT => 2,
+			 MemifMode::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(MemifMode::MEMIF_MODE_API_ETHERNET),
+			 1 => Some(MemifMode::MEMIF_MODE_API_IP),
+			 2 => Some(MemifMode::MEMIF_MODE_API_PUNT_INJECT),
+			 value => Some(MemifMode::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for MemifMode {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for MemifMode {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(MemifMode::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_socket_filename_add_del_a2ce1a10)]
 pub struct MemifSocketFilenameAddDel {
 	pub client_index: u32,
@@ -40,13 +99,13 @@ pub struct MemifSocketFilenameAddDel {
 	pub socket_id: u32,
 	pub socket_filename: FixedSizeString<typenum::U108>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_socket_filename_add_del_reply_e8d4e804)]
 pub struct MemifSocketFilenameAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_socket_filename_add_del_v2_34223bdf)]
 pub struct MemifSocketFilenameAddDelV2 {
 	pub client_index: u32,
@@ -55,14 +114,14 @@ pub struct MemifSocketFilenameAddDelV2 {
 	pub socket_id: u32,
 	pub socket_filename: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_socket_filename_add_del_v2_reply_9f29bdb9)]
 pub struct MemifSocketFilenameAddDelV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub socket_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_create_b1b25061)]
 pub struct MemifCreate {
 	pub client_index: u32,
@@ -79,14 +138,14 @@ pub struct MemifCreate {
 	pub hw_addr: MacAddress,
 	pub secret: FixedSizeString<typenum::U24>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_create_reply_5383d31f)]
 pub struct MemifCreateReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_create_v2_8c7de5f7)]
 pub struct MemifCreateV2 {
 	pub client_index: u32,
@@ -104,40 +163,40 @@ pub struct MemifCreateV2 {
 	pub hw_addr: MacAddress,
 	pub secret: FixedSizeString<typenum::U24>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_create_v2_reply_5383d31f)]
 pub struct MemifCreateV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_delete_f9e6675e)]
 pub struct MemifDelete {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_delete_reply_e8d4e804)]
 pub struct MemifDeleteReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_socket_filename_details_7ff326f7)]
 pub struct MemifSocketFilenameDetails {
 	pub context: u32,
 	pub socket_id: u32,
 	pub socket_filename: FixedSizeString<typenum::U108>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_socket_filename_dump_51077d14)]
 pub struct MemifSocketFilenameDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_details_da34feb9)]
 pub struct MemifDetails {
 	pub context: u32,
@@ -153,9 +212,26 @@ pub struct MemifDetails {
 	 pub flags: EnumFlag<IfStatusFlags>,
 	pub if_name: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(memif_dump_51077d14)]
 pub struct MemifDump {
 	pub client_index: u32,
 	pub context: u32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"memif_socket_filename_add_del_a2ce1a10",
+	"memif_socket_filename_add_del_reply_e8d4e804",
+	"memif_socket_filename_add_del_v2_34223bdf",
+	"memif_socket_filename_add_del_v2_reply_9f29bdb9",
+	"memif_create_b1b25061",
+	"memif_create_reply_5383d31f",
+	"memif_create_v2_8c7de5f7",
+	"memif_create_v2_reply_5383d31f",
+	"memif_delete_f9e6675e",
+	"memif_delete_reply_e8d4e804",
+	"memif_socket_filename_details_7ff326f7",
+	"memif_socket_filename_dump_51077d14",
+	"memif_details_da34feb9",
+	"memif_dump_51077d14",
+];
@@ -2,52 +2,90 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::fib_types::*;
 // Implementation for mfib_path
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct MfibPath {
 	pub itf_flags: EnumFlag<MfibItfFlags>,
 	pub path: FibPath,
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum MfibEntryFlags {
 	 MFIB_API_ENTRY_FLAG_NONE=0,
 	 MFIB_API_ENTRY_FLAG_SIGNAL=1,
 	 MFIB_API_ENTRY_FLAG_DROP=2,
 	 MFIB_API_ENTRY_FLAG_CONNECTED=4,
 	 MFIB_API_ENTRY_FLAG_ACCEPT_ALL_ITF=8,
+	 Unknown(u32),
 }
 impl Default for MfibEntryFlags {
 	fn default() -> Self { MfibEntryFlags::MFIB_API_ENTRY_FLAG_NONE }
 }
-impl AsEnumFlag for MfibEntryFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for MfibEntryFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 MfibEntryFlags::MFIB_API_ENTRY_FLAG_NONE => 0,
+			 MfibEntryFlags::MFIB_API_ENTRY_FLAG_SIGNAL => 1,
+			 MfibEntryFlags::MFIB_API_ENTRY_FLAG_DROP => 2,
+			 MfibEntryFlags::MFIB_API_ENTRY_FLAG_CONNECTED => 4,
+			 MfibEntryFlags::MFIB_API_ENTRY_FLAG_ACCEPT_ALL_ITF => 8,
+			 MfibEntryFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 0 => MfibEntryFlags::MFIB_API_ENTRY_FLAG_NONE,
-			 1 => MfibEntryFlags::MFIB_API_ENTRY_FLAG_SIGNAL,
-			 2 => MfibEntryFlags::MFIB_API_ENTRY_FLAG_DROP,
-			 4 => MfibEntryFlags::MFIB_API_ENTRY_FLAG_CONNECTED,
-			 8 => MfibEntryFlags::MFIB_API_ENTRY_FLAG_ACCEPT_ALL_ITF,
-			_ => panic!("Invalid Enum Descriminant")
+			 0 => Some(MfibEntryFlags::MFIB_API_ENTRY_FLAG_NONE),
+			 1 => Some(MfibEntryFlags::MFIB_API_ENTRY_FLAG_SIGNAL),
+			 2 => Some(MfibEntryFlags::MFIB_API_ENTRY_FLAG_DROP),
+			 4 => Some(MfibEntryFlags::MFIB_API_ENTRY_FLAG_CONNECTED),
+			 8 => Some(MfibEntryFlags::MFIB_API_ENTRY_FLAG_ACCEPT_ALL_ITF),
+			 value => Some(MfibEntryFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for MfibEntryFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for MfibEntryFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(MfibEntryFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for MfibEntryFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0xf
+	}
+}
+impl std::ops::BitOr for MfibEntryFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum MfibItfFlags {
 	 MFIB_API_ITF_FLAG_NONE=0,
 	 MFIB_API_ITF_FLAG_NEGATE_SIGNAL=1,
@@ -55,26 +93,67 @@ pub enum MfibItfFlags {
 	 MFIB_API_ITF_FLAG_FORWARD=4,
 	 MFIB_API_ITF_FLAG_SIGNAL_PRESENT=8,
 	 MFIB_API_ITF_FLAG_DONT_PRESERVE=16,
+	 Unknown(u32),
 }
 impl Default for MfibItfFlags {
 	fn default() -> Self { MfibItfFlags::MFIB_API_ITF_FLAG_NONE }
 }
-impl AsEnumFlag for MfibItfFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for MfibItfFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 MfibItfFlags::MFIB_API_ITF_FLAG_NONE => 0,
+			 MfibItfFlags::MFIB_API_ITF_FLAG_NEGATE_SIGNAL => 1,
+			 MfibItfFlags::MFIB_API_ITF_FLAG_ACCEPT => 2,
+			 MfibItfFlags::MFIB_API_ITF_FLAG_FORWARD => 4,
+			 MfibItfFlags::MFIB_API_ITF_FLAG_SIGNAL_PRESENT => 8,
+			 MfibItfFlags::MFIB_API_ITF_FLAG_DONT_PRESERVE => 16,
+			 MfibItfFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 0 => MfibItfFlags::MFIB_API_ITF_FLAG_NONE,
-			 1 => MfibItfFlags::MFIB_API_ITF_FLAG_NEGATE_SIGNAL,
-			 2 => MfibItfFlags::MFIB_API_ITF_FLAG_ACCEPT,
-			 4 => MfibItfFlags::MFIB_API_ITF_FLAG_FORWARD,
-			 8 => MfibItfFlags::MFIB_API_ITF_FLAG_SIGNAL_PRESENT,
-			 16 => MfibItfFlags::MFIB_API_ITF_FLAG_DONT_PRESERVE,
-			_ => panic!("Invalid Enum Descriminant")
+			 0 => Some(MfibItfFlags::MFIB_API_ITF_FLAG_NONE),
+			 1 => Some(MfibItfFlags::MFIB_API_ITF_FLAG_NEGATE_SIGNAL),
+			 2 => Some(MfibItfFlags::MFIB_API_ITF_FLAG_ACCEPT),
+			 4 => Some(MfibItfFlags::MFIB_API_ITF_FLAG_FORWARD),
+			 8 => Some(MfibItfFlags::MFIB_API_ITF_FLAG_SIGNAL_PRESENT),
+			 16 => Some(MfibItfFlags::MFIB_API_ITF_FLAG_DONT_PRESERVE),
+			 value => Some(MfibItfFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for MfibItfFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for MfibItfFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(MfibItfFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for MfibItfFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x1f
+	}
+}
+impl std::ops::BitOr for MfibItfFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
@@ -131,3 +131,3763 @@ pub mod vxlan;
 pub mod vxlan_gpe;
 pub mod vxlan_gpe_ioam_export;
 pub mod wireguard;
+
+/// The name and CRC of every message in these bindings, as used in the VPP message table.
+pub const MESSAGE_NAMES_AND_CRCS: &[&str] = &[
+	"abf_itf_attach_add_del_25c8621b",
+	"abf_itf_attach_add_del_reply_e8d4e804",
+	"abf_itf_attach_details_7819523e",
+	"abf_itf_attach_dump_51077d14",
+	"abf_plugin_get_version_51077d14",
+	"abf_plugin_get_version_reply_9b32cf86",
+	"abf_policy_add_del_c6131197",
+	"abf_policy_add_del_reply_e8d4e804",
+	"abf_policy_details_b7487fa4",
+	"abf_policy_dump_51077d14",
+	"acl_add_replace_ee5c2f18",
+	"acl_add_replace_reply_ac407b0c",
+	"acl_del_ef34fea4",
+	"acl_del_reply_e8d4e804",
+	"acl_details_95babae0",
+	"acl_dump_ef34fea4",
+	"acl_interface_add_del_4b54bebd",
+	"acl_interface_add_del_reply_e8d4e804",
+	"acl_interface_etype_whitelist_details_cc2bfded",
+	"acl_interface_etype_whitelist_dump_f9e6675e",
+	"acl_interface_list_details_e695d256",
+	"acl_interface_list_dump_f9e6675e",
+	"acl_interface_set_acl_list_473982bd",
+	"acl_interface_set_acl_list_reply_e8d4e804",
+	"acl_interface_set_etype_whitelist_3f5c2d2d",
+	"acl_interface_set_etype_whitelist_reply_e8d4e804",
+	"acl_plugin_control_ping_51077d14",
+	"acl_plugin_control_ping_reply_f6b0b8ca",
+	"acl_plugin_get_conn_table_max_entries_51077d14",
+	"acl_plugin_get_conn_table_max_entries_reply_7a096d3d",
+	"acl_plugin_get_version_51077d14",
+	"acl_plugin_get_version_reply_9b32cf86",
+	"acl_plugin_use_hash_lookup_get_51077d14",
+	"acl_plugin_use_hash_lookup_get_reply_5392ad31",
+	"acl_plugin_use_hash_lookup_set_b3e225d2",
+	"acl_plugin_use_hash_lookup_set_reply_e8d4e804",
+	"acl_stats_intf_counters_enable_b3e225d2",
+	"acl_stats_intf_counters_enable_reply_e8d4e804",
+	"add_del_ip_punt_redirect_v2_9e804227",
+	"add_del_ip_punt_redirect_v2_reply_e8d4e804",
+	"add_node_next_2457116d",
+	"add_node_next_reply_2ed75f32",
+	"adl_allowlist_enable_disable_ea88828d",
+	"adl_allowlist_enable_disable_reply_e8d4e804",
+	"adl_interface_enable_disable_5501adee",
+	"adl_interface_enable_disable_reply_e8d4e804",
+	"af_packet_create_a190415f",
+	"af_packet_create_reply_5383d31f",
+	"af_packet_create_v2_4aff0436",
+	"af_packet_create_v2_reply_5383d31f",
+	"af_packet_create_v3_b3a809d4",
+	"af_packet_create_v3_reply_5383d31f",
+	"af_packet_delete_863fa648",
+	"af_packet_delete_reply_e8d4e804",
+	"af_packet_details_58c7c042",
+	"af_packet_dump_51077d14",
+	"af_packet_set_l4_cksum_offload_319cd5c8",
+	"af_packet_set_l4_cksum_offload_reply_e8d4e804",
+	"af_xdp_create_v3_cf4b1827",
+	"af_xdp_create_v3_reply_5383d31f",
+	"af_xdp_delete_f9e6675e",
+	"af_xdp_delete_reply_e8d4e804",
+	"api_versions_51077d14",
+	"api_versions_reply_5f0d99d6",
+	"app_add_cert_key_pair_02eb8016",
+	"app_add_cert_key_pair_reply_b42958d0",
+	"app_attach_5f4a260d",
+	"app_attach_reply_5c89c3b0",
+	"app_del_cert_key_pair_8ac76db6",
+	"app_del_cert_key_pair_reply_e8d4e804",
+	"app_namespace_add_del_6306aecb",
+	"app_namespace_add_del_reply_85137120",
+	"app_namespace_add_del_v2_ee0755cf",
+	"app_namespace_add_del_v2_reply_85137120",
+	"app_namespace_add_del_v3_8a7e40a1",
+	"app_namespace_add_del_v3_reply_85137120",
+	"app_namespace_add_del_v4_42c1d824",
+	"app_namespace_add_del_v4_reply_85137120",
+	"app_worker_add_del_753253dc",
+	"app_worker_add_del_reply_5735ffe7",
+	"application_detach_51077d14",
+	"application_detach_reply_e8d4e804",
+	"arping_48817482",
+	"arping_acd_48817482",
+	"arping_acd_reply_e08c3b05",
+	"arping_reply_bb9d1cbd",
+	"auto_sdl_config_14f30db8",
+	"auto_sdl_config_reply_e8d4e804",
+	"avf_create_daab8ae2",
+	"avf_create_reply_5383d31f",
+	"avf_delete_f9e6675e",
+	"avf_delete_reply_e8d4e804",
+	"bd_ip_mac_add_del_0257c869",
+	"bd_ip_mac_add_del_reply_e8d4e804",
+	"bd_ip_mac_details_545af86a",
+	"bd_ip_mac_dump_c25fdce6",
+	"bd_ip_mac_flush_c25fdce6",
+	"bd_ip_mac_flush_reply_e8d4e804",
+	"bfd_auth_del_key_65310b22",
+	"bfd_auth_del_key_reply_e8d4e804",
+	"bfd_auth_keys_details_84130e9f",
+	"bfd_auth_keys_dump_51077d14",
+	"bfd_auth_set_key_690b8877",
+	"bfd_auth_set_key_reply_e8d4e804",
+	"bfd_udp_add_939cd26a",
+	"bfd_udp_add_reply_e8d4e804",
+	"bfd_udp_auth_activate_21fd1bdb",
+	"bfd_udp_auth_activate_reply_e8d4e804",
+	"bfd_udp_auth_deactivate_9a05e2e0",
+	"bfd_udp_auth_deactivate_reply_e8d4e804",
+	"bfd_udp_del_dcb13a89",
+	"bfd_udp_del_echo_source_51077d14",
+	"bfd_udp_del_echo_source_reply_e8d4e804",
+	"bfd_udp_del_reply_e8d4e804",
+	"bfd_udp_enable_multihop_51077d14",
+	"bfd_udp_enable_multihop_reply_e8d4e804",
+	"bfd_udp_get_echo_source_51077d14",
+	"bfd_udp_get_echo_source_reply_e3d736a1",
+	"bfd_udp_mod_913df085",
+	"bfd_udp_mod_reply_e8d4e804",
+	"bfd_udp_session_details_09fb2f2d",
+	"bfd_udp_session_dump_51077d14",
+	"bfd_udp_session_event_8eaaf062",
+	"bfd_udp_session_set_flags_04b4bdfd",
+	"bfd_udp_session_set_flags_reply_e8d4e804",
+	"bfd_udp_set_echo_source_f9e6675e",
+	"bfd_udp_set_echo_source_reply_e8d4e804",
+	"bfd_udp_upd_939cd26a",
+	"bfd_udp_upd_reply_1992deab",
+	"bier_disp_entry_add_del_9eb80cb4",
+	"bier_disp_entry_add_del_reply_e8d4e804",
+	"bier_disp_entry_details_84c218f1",
+	"bier_disp_entry_dump_b5fa54ad",
+	"bier_disp_table_add_del_889657ac",
+	"bier_disp_table_add_del_reply_e8d4e804",
+	"bier_disp_table_details_d27942c0",
+	"bier_disp_table_dump_51077d14",
+	"bier_imp_add_3856dc3d",
+	"bier_imp_add_reply_d49c5793",
+	"bier_imp_del_7d45edf6",
+	"bier_imp_del_reply_e8d4e804",
+	"bier_imp_details_b76192df",
+	"bier_imp_dump_51077d14",
+	"bier_route_add_del_fd02f3ea",
+	"bier_route_add_del_reply_e8d4e804",
+	"bier_route_details_4008caee",
+	"bier_route_dump_38339846",
+	"bier_table_add_del_35e59209",
+	"bier_table_add_del_reply_e8d4e804",
+	"bier_table_details_fc44a9dd",
+	"bier_table_dump_51077d14",
+	"bond_add_member_e7d14948",
+	"bond_add_member_reply_e8d4e804",
+	"bond_create2_912fda76",
+	"bond_create2_reply_5383d31f",
+	"bond_create_f1dbd4ff",
+	"bond_create_reply_5383d31f",
+	"bond_delete_f9e6675e",
+	"bond_delete_reply_e8d4e804",
+	"bond_detach_member_f9e6675e",
+	"bond_detach_member_reply_e8d4e804",
+	"bond_detach_slave_f9e6675e",
+	"bond_detach_slave_reply_e8d4e804",
+	"bond_enslave_e7d14948",
+	"bond_enslave_reply_e8d4e804",
+	"bpf_trace_filter_set_3171346e",
+	"bpf_trace_filter_set_reply_e8d4e804",
+	"bpf_trace_filter_set_v2_5615acbf",
+	"bpf_trace_filter_set_v2_reply_e8d4e804",
+	"bridge_domain_add_del_600b7170",
+	"bridge_domain_add_del_reply_e8d4e804",
+	"bridge_domain_add_del_v2_600b7170",
+	"bridge_domain_add_del_v2_reply_fcb1e980",
+	"bridge_domain_details_0fa506fd",
+	"bridge_domain_dump_74396a43",
+	"bridge_domain_set_default_learn_limit_f097ffce",
+	"bridge_domain_set_default_learn_limit_reply_e8d4e804",
+	"bridge_domain_set_learn_limit_89c52b5f",
+	"bridge_domain_set_learn_limit_reply_e8d4e804",
+	"bridge_domain_set_mac_age_b537ad7b",
+	"bridge_domain_set_mac_age_reply_e8d4e804",
+	"bridge_flags_1b0c5fbd",
+	"bridge_flags_reply_29b2a2b3",
+	"bvi_create_f5398559",
+	"bvi_create_reply_5383d31f",
+	"bvi_delete_f9e6675e",
+	"bvi_delete_reply_e8d4e804",
+	"cdp_enable_disable_2e7b47df",
+	"cdp_enable_disable_reply_e8d4e804",
+	"classify_add_del_session_f20879f0",
+	"classify_add_del_session_reply_e8d4e804",
+	"classify_add_del_table_6849e39e",
+	"classify_add_del_table_reply_05486349",
+	"classify_pcap_get_tables_f9e6675e",
+	"classify_pcap_get_tables_reply_5f5bc9e6",
+	"classify_pcap_lookup_table_e1b4cc6b",
+	"classify_pcap_lookup_table_reply_9c6c6773",
+	"classify_pcap_set_table_006051b3",
+	"classify_pcap_set_table_reply_9c6c6773",
+	"classify_session_details_60e3ef94",
+	"classify_session_dump_0cca2cd9",
+	"classify_set_interface_ip_table_e0b097c7",
+	"classify_set_interface_ip_table_reply_e8d4e804",
+	"classify_set_interface_l2_tables_5a6ddf65",
+	"classify_set_interface_l2_tables_reply_e8d4e804",
+	"classify_table_by_interface_f9e6675e",
+	"classify_table_by_interface_reply_ed4197db",
+	"classify_table_ids_51077d14",
+	"classify_table_ids_reply_d1d20e1d",
+	"classify_table_info_0cca2cd9",
+	"classify_table_info_reply_4a573c0e",
+	"classify_trace_get_tables_51077d14",
+	"classify_trace_get_tables_reply_5f5bc9e6",
+	"classify_trace_lookup_table_3f7b72e4",
+	"classify_trace_lookup_table_reply_9c6c6773",
+	"classify_trace_set_table_3909b55a",
+	"classify_trace_set_table_reply_9c6c6773",
+	"cli_23bfbfff",
+	"cli_inband_f8377302",
+	"cli_inband_reply_05879051",
+	"cli_reply_06d68297",
+	"cnat_get_snat_addresses_51077d14",
+	"cnat_get_snat_addresses_reply_879513c1",
+	"cnat_session_details_7e5017c7",
+	"cnat_session_dump_51077d14",
+	"cnat_session_purge_51077d14",
+	"cnat_session_purge_reply_e8d4e804",
+	"cnat_set_snat_addresses_d997e96c",
+	"cnat_set_snat_addresses_reply_e8d4e804",
+	"cnat_set_snat_policy_d3e6eaf4",
+	"cnat_set_snat_policy_reply_e8d4e804",
+	"cnat_snat_policy_add_del_exclude_pfx_e26dd79a",
+	"cnat_snat_policy_add_del_exclude_pfx_reply_e8d4e804",
+	"cnat_snat_policy_add_del_if_4ebb8d02",
+	"cnat_snat_policy_add_del_if_reply_e8d4e804",
+	"cnat_translation_del_3a91bde5",
+	"cnat_translation_del_reply_e8d4e804",
+	"cnat_translation_details_1a5140b7",
+	"cnat_translation_dump_51077d14",
+	"cnat_translation_update_f8d40bc5",
+	"cnat_translation_update_reply_e2fc8294",
+	"collect_detailed_interface_stats_5501adee",
+	"collect_detailed_interface_stats_reply_e8d4e804",
+	"control_ping_51077d14",
+	"control_ping_reply_f6b0b8ca",
+	"create_loopback_42bb5d22",
+	"create_loopback_instance_d36a3ee2",
+	"create_loopback_instance_reply_5383d31f",
+	"create_loopback_reply_5383d31f",
+	"create_subif_790ca755",
+	"create_subif_reply_5383d31f",
+	"create_vhost_user_if_c785c6fc",
+	"create_vhost_user_if_reply_5383d31f",
+	"create_vhost_user_if_v2_dba1cc1d",
+	"create_vhost_user_if_v2_reply_5383d31f",
+	"create_vlan_subif_af34ac8b",
+	"create_vlan_subif_reply_5383d31f",
+	"crypto_set_async_dispatch_5ca4adc0",
+	"crypto_set_async_dispatch_reply_e8d4e804",
+	"crypto_set_async_dispatch_v2_667d2d54",
+	"crypto_set_async_dispatch_v2_reply_e8d4e804",
+	"crypto_set_handler_ce9ad00d",
+	"crypto_set_handler_reply_e8d4e804",
+	"crypto_sw_scheduler_set_worker_b4274502",
+	"crypto_sw_scheduler_set_worker_reply_e8d4e804",
+	"ct6_enable_disable_5d02ac02",
+	"ct6_enable_disable_reply_e8d4e804",
+	"delete_loopback_f9e6675e",
+	"delete_loopback_reply_e8d4e804",
+	"delete_subif_f9e6675e",
+	"delete_subif_reply_e8d4e804",
+	"delete_vhost_user_if_f9e6675e",
+	"delete_vhost_user_if_reply_e8d4e804",
+	"det44_add_del_map_1150a190",
+	"det44_add_del_map_reply_e8d4e804",
+	"det44_close_session_in_3c68e073",
+	"det44_close_session_in_reply_e8d4e804",
+	"det44_close_session_out_f6b259d1",
+	"det44_close_session_out_reply_e8d4e804",
+	"det44_forward_7f8a89cd",
+	"det44_forward_reply_a8ccbdc0",
+	"det44_get_timeouts_51077d14",
+	"det44_get_timeouts_reply_3c4df4e1",
+	"det44_interface_add_del_feature_dc17a836",
+	"det44_interface_add_del_feature_reply_e8d4e804",
+	"det44_interface_details_e60cc5be",
+	"det44_interface_dump_51077d14",
+	"det44_map_details_ad91dc83",
+	"det44_map_dump_51077d14",
+	"det44_plugin_enable_disable_617b6bf8",
+	"det44_plugin_enable_disable_reply_e8d4e804",
+	"det44_reverse_a7573fe1",
+	"det44_reverse_reply_34066d48",
+	"det44_session_details_27f3c171",
+	"det44_session_dump_e45a3af7",
+	"det44_set_timeouts_d4746b16",
+	"det44_set_timeouts_reply_e8d4e804",
+	"dev_attach_44b725fc",
+	"dev_attach_reply_6082b181",
+	"dev_create_port_if_dbdf06f3",
+	"dev_create_port_if_reply_243c2374",
+	"dev_detach_afae52d6",
+	"dev_detach_reply_c8d74455",
+	"dev_remove_port_if_529cb13f",
+	"dev_remove_port_if_reply_c8d74455",
+	"dhcp6_client_enable_disable_ae6cfcfb",
+	"dhcp6_client_enable_disable_reply_e8d4e804",
+	"dhcp6_clients_enable_disable_b3e225d2",
+	"dhcp6_clients_enable_disable_reply_e8d4e804",
+	"dhcp6_duid_ll_set_0f6ca323",
+	"dhcp6_duid_ll_set_reply_e8d4e804",
+	"dhcp6_pd_client_enable_disable_a75a0772",
+	"dhcp6_pd_client_enable_disable_reply_e8d4e804",
+	"dhcp6_pd_reply_event_5e878029",
+	"dhcp6_pd_send_client_message_3739fd8d",
+	"dhcp6_pd_send_client_message_reply_e8d4e804",
+	"dhcp6_reply_event_85b7b17e",
+	"dhcp6_send_client_message_f8222476",
+	"dhcp6_send_client_message_reply_e8d4e804",
+	"dhcp_client_config_1af013ea",
+	"dhcp_client_config_reply_e8d4e804",
+	"dhcp_client_details_8897b2d8",
+	"dhcp_client_detect_enable_disable_ae6cfcfb",
+	"dhcp_client_detect_enable_disable_reply_e8d4e804",
+	"dhcp_client_dump_51077d14",
+	"dhcp_compl_event_e18124b7",
+	"dhcp_plugin_control_ping_51077d14",
+	"dhcp_plugin_control_ping_reply_f6b0b8ca",
+	"dhcp_plugin_get_version_51077d14",
+	"dhcp_plugin_get_version_reply_9b32cf86",
+	"dhcp_proxy_config_4058a689",
+	"dhcp_proxy_config_reply_e8d4e804",
+	"dhcp_proxy_details_dcbaf540",
+	"dhcp_proxy_dump_5c5b063f",
+	"dhcp_proxy_set_vss_50537301",
+	"dhcp_proxy_set_vss_reply_e8d4e804",
+	"dns_enable_disable_8050327d",
+	"dns_enable_disable_reply_e8d4e804",
+	"dns_name_server_add_del_3bb05d8c",
+	"dns_name_server_add_del_reply_e8d4e804",
+	"dns_resolve_ip_ae96a1a3",
+	"dns_resolve_ip_reply_49ed78d6",
+	"dns_resolve_name_c6566676",
+	"dns_resolve_name_reply_c2d758c3",
+	"dslite_add_del_pool_addr_range_de2a5b02",
+	"dslite_add_del_pool_addr_range_reply_e8d4e804",
+	"dslite_address_details_ec26d648",
+	"dslite_address_dump_51077d14",
+	"dslite_get_aftr_addr_51077d14",
+	"dslite_get_aftr_addr_reply_8e23608e",
+	"dslite_get_b4_addr_51077d14",
+	"dslite_get_b4_addr_reply_8e23608e",
+	"dslite_set_aftr_addr_78b50fdf",
+	"dslite_set_aftr_addr_reply_e8d4e804",
+	"dslite_set_b4_addr_78b50fdf",
+	"dslite_set_b4_addr_reply_e8d4e804",
+	"feature_enable_disable_7531c862",
+	"feature_enable_disable_reply_e8d4e804",
+	"feature_gso_enable_disable_5501adee",
+	"feature_gso_enable_disable_reply_e8d4e804",
+	"feature_is_enabled_55db09e2",
+	"feature_is_enabled_reply_03f284b5",
+	"fib_source_add_b3ac2aec",
+	"fib_source_add_reply_604fd6f1",
+	"fib_source_details_8668acdb",
+	"fib_source_dump_51077d14",
+	"flow_add_f946ed84",
+	"flow_add_reply_8587dc85",
+	"flow_add_v2_5b757558",
+	"flow_add_v2_reply_8587dc85",
+	"flow_classify_details_dfd08765",
+	"flow_classify_dump_25dd3e4c",
+	"flow_classify_set_interface_b6192f1c",
+	"flow_classify_set_interface_reply_e8d4e804",
+	"flow_del_b6b9b02c",
+	"flow_del_reply_e8d4e804",
+	"flow_disable_2024be69",
+	"flow_disable_reply_e8d4e804",
+	"flow_enable_2024be69",
+	"flow_enable_reply_e8d4e804",
+	"flowprobe_get_params_51077d14",
+	"flowprobe_get_params_reply_f350d621",
+	"flowprobe_interface_add_del_3420739c",
+	"flowprobe_interface_add_del_reply_e8d4e804",
+	"flowprobe_interface_details_427d77e0",
+	"flowprobe_interface_dump_f9e6675e",
+	"flowprobe_params_baa46c09",
+	"flowprobe_params_reply_e8d4e804",
+	"flowprobe_set_params_baa46c09",
+	"flowprobe_set_params_reply_e8d4e804",
+	"flowprobe_tx_interface_add_del_b782c976",
+	"flowprobe_tx_interface_add_del_reply_e8d4e804",
+	"geneve_add_del_tunnel2_8c2a9999",
+	"geneve_add_del_tunnel2_reply_5383d31f",
+	"geneve_add_del_tunnel_99445831",
+	"geneve_add_del_tunnel_reply_5383d31f",
+	"geneve_tunnel_details_6b16eb24",
+	"geneve_tunnel_dump_f9e6675e",
+	"get_api_json_51077d14",
+	"get_api_json_reply_ea715b59",
+	"get_f64_endian_value_809fcd44",
+	"get_f64_endian_value_reply_7e02e404",
+	"get_f64_increment_by_one_b64f027e",
+	"get_f64_increment_by_one_reply_d25dbaa3",
+	"get_first_msg_id_ebf79a66",
+	"get_first_msg_id_reply_7d337472",
+	"get_next_index_2457116d",
+	"get_next_index_reply_2ed75f32",
+	"get_node_graph_51077d14",
+	"get_node_graph_reply_06d68297",
+	"get_node_index_f1984c64",
+	"get_node_index_reply_a8600b89",
+	"gpe_add_del_fwd_entry_f0847644",
+	"gpe_add_del_fwd_entry_reply_efe5f176",
+	"gpe_add_del_iface_3ccff273",
+	"gpe_add_del_iface_reply_e8d4e804",
+	"gpe_add_del_native_fwd_rpath_43fc8b54",
+	"gpe_add_del_native_fwd_rpath_reply_e8d4e804",
+	"gpe_enable_disable_c264d7bf",
+	"gpe_enable_disable_reply_e8d4e804",
+	"gpe_fwd_entries_get_8d1f2fe9",
+	"gpe_fwd_entries_get_reply_c4844876",
+	"gpe_fwd_entry_path_details_483df51a",
+	"gpe_fwd_entry_path_dump_39bce980",
+	"gpe_fwd_entry_vnis_get_51077d14",
+	"gpe_fwd_entry_vnis_get_reply_aa70da20",
+	"gpe_get_encap_mode_51077d14",
+	"gpe_get_encap_mode_reply_36e3f7ca",
+	"gpe_native_fwd_rpaths_get_f652ceb4",
+	"gpe_native_fwd_rpaths_get_reply_7a1ca5a2",
+	"gpe_set_encap_mode_bd819eac",
+	"gpe_set_encap_mode_reply_e8d4e804",
+	"gre_tunnel_add_del_a27d7f17",
+	"gre_tunnel_add_del_reply_5383d31f",
+	"gre_tunnel_details_24435433",
+	"gre_tunnel_dump_f9e6675e",
+	"gtpu_add_del_forward_c6ccce13",
+	"gtpu_add_del_forward_reply_5383d31f",
+	"gtpu_add_del_tunnel_ca983a2b",
+	"gtpu_add_del_tunnel_reply_5383d31f",
+	"gtpu_add_del_tunnel_v2_a0c30713",
+	"gtpu_add_del_tunnel_v2_reply_62b41304",
+	"gtpu_get_transfer_counts_61410788",
+	"gtpu_get_transfer_counts_reply_e35f04bc",
+	"gtpu_offload_rx_f0b08786",
+	"gtpu_offload_rx_reply_e8d4e804",
+	"gtpu_tunnel_details_27f434ae",
+	"gtpu_tunnel_dump_f9e6675e",
+	"gtpu_tunnel_update_tteid_79f33816",
+	"gtpu_tunnel_update_tteid_reply_e8d4e804",
+	"gtpu_tunnel_v2_details_8bf4ba92",
+	"gtpu_tunnel_v2_dump_f9e6675e",
+	"http_static_enable_v4_37540bfc",
+	"http_static_enable_v4_reply_e8d4e804",
+	"http_static_enable_v5_8bf84069",
+	"http_static_enable_v5_reply_e8d4e804",
+	"hw_interface_set_mtu_e6746899",
+	"hw_interface_set_mtu_reply_e8d4e804",
+	"idpf_create_2ba86d91",
+	"idpf_create_reply_5383d31f",
+	"idpf_delete_f9e6675e",
+	"idpf_delete_reply_e8d4e804",
+	"igmp_clear_interface_f9e6675e",
+	"igmp_clear_interface_reply_e8d4e804",
+	"igmp_details_38f09929",
+	"igmp_dump_f9e6675e",
+	"igmp_enable_disable_b1edfb96",
+	"igmp_enable_disable_reply_e8d4e804",
+	"igmp_event_85fe93ec",
+	"igmp_group_prefix_details_259ccd81",
+	"igmp_group_prefix_dump_51077d14",
+	"igmp_group_prefix_set_5b14a5ce",
+	"igmp_group_prefix_set_reply_e8d4e804",
+	"igmp_listen_19a49f1e",
+	"igmp_listen_reply_e8d4e804",
+	"igmp_proxy_device_add_del_0b9be9ce",
+	"igmp_proxy_device_add_del_interface_1a9ec24a",
+	"igmp_proxy_device_add_del_interface_reply_e8d4e804",
+	"igmp_proxy_device_add_del_reply_e8d4e804",
+	"ikev2_child_sa_details_ff67741f",
+	"ikev2_child_sa_dump_01eab609",
+	"ikev2_child_sa_v2_details_1db62aa2",
+	"ikev2_child_sa_v2_dump_01eab609",
+	"ikev2_get_sleep_interval_51077d14",
+	"ikev2_get_sleep_interval_reply_78ab91dc",
+	"ikev2_initiate_del_child_sa_7f004d2e",
+	"ikev2_initiate_del_child_sa_reply_e8d4e804",
+	"ikev2_initiate_del_ike_sa_8d125bdd",
+	"ikev2_initiate_del_ike_sa_reply_e8d4e804",
+	"ikev2_initiate_rekey_child_sa_7f004d2e",
+	"ikev2_initiate_rekey_child_sa_reply_e8d4e804",
+	"ikev2_initiate_sa_init_ebf79a66",
+	"ikev2_initiate_sa_init_reply_e8d4e804",
+	"ikev2_nonce_get_7fe9ad51",
+	"ikev2_nonce_get_reply_1b37a342",
+	"ikev2_plugin_get_version_51077d14",
+	"ikev2_plugin_get_version_reply_9b32cf86",
+	"ikev2_plugin_set_sleep_interval_b7c096ae",
+	"ikev2_plugin_set_sleep_interval_reply_e8d4e804",
+	"ikev2_profile_add_del_2c925b55",
+	"ikev2_profile_add_del_reply_e8d4e804",
+	"ikev2_profile_details_670d01d9",
+	"ikev2_profile_disable_natt_ebf79a66",
+	"ikev2_profile_disable_natt_reply_e8d4e804",
+	"ikev2_profile_dump_51077d14",
+	"ikev2_profile_set_auth_642c97cd",
+	"ikev2_profile_set_auth_reply_e8d4e804",
+	"ikev2_profile_set_id_4d7e2418",
+	"ikev2_profile_set_id_reply_e8d4e804",
+	"ikev2_profile_set_ipsec_udp_port_615ce758",
+	"ikev2_profile_set_ipsec_udp_port_reply_e8d4e804",
+	"ikev2_profile_set_liveness_6bdf4d65",
+	"ikev2_profile_set_liveness_reply_e8d4e804",
+	"ikev2_profile_set_ts_8eb8cfd1",
+	"ikev2_profile_set_ts_reply_e8d4e804",
+	"ikev2_profile_set_udp_encap_ebf79a66",
+	"ikev2_profile_set_udp_encap_reply_e8d4e804",
+	"ikev2_sa_details_937c22d5",
+	"ikev2_sa_dump_51077d14",
+	"ikev2_sa_v2_details_a616e604",
+	"ikev2_sa_v2_dump_51077d14",
+	"ikev2_sa_v3_details_85c9a941",
+	"ikev2_sa_v3_dump_51077d14",
+	"ikev2_set_esp_transforms_a63dc205",
+	"ikev2_set_esp_transforms_reply_e8d4e804",
+	"ikev2_set_ike_transforms_076d7378",
+	"ikev2_set_ike_transforms_reply_e8d4e804",
+	"ikev2_set_local_key_799b69ec",
+	"ikev2_set_local_key_reply_e8d4e804",
+	"ikev2_set_responder_a2055df1",
+	"ikev2_set_responder_hostname_350d6949",
+	"ikev2_set_responder_hostname_reply_e8d4e804",
+	"ikev2_set_responder_reply_e8d4e804",
+	"ikev2_set_sa_lifetime_7039feaa",
+	"ikev2_set_sa_lifetime_reply_e8d4e804",
+	"ikev2_set_tunnel_interface_ca67182c",
+	"ikev2_set_tunnel_interface_reply_e8d4e804",
+	"ikev2_traffic_selector_details_518cb06f",
+	"ikev2_traffic_selector_dump_a7385e33",
+	"input_acl_set_interface_de7ad708",
+	"input_acl_set_interface_reply_e8d4e804",
+	"interface_name_renumber_2b8858b8",
+	"interface_name_renumber_reply_e8d4e804",
+	"ioam_cache_ip6_enable_disable_47705c03",
+	"ioam_cache_ip6_enable_disable_reply_e8d4e804",
+	"ioam_disable_6b16a45e",
+	"ioam_disable_reply_e8d4e804",
+	"ioam_enable_51ccd868",
+	"ioam_enable_reply_e8d4e804",
+	"ioam_export_ip6_enable_disable_d4c76d3a",
+	"ioam_export_ip6_enable_disable_reply_e8d4e804",
+	"ip6_add_del_address_using_prefix_3982f30a",
+	"ip6_add_del_address_using_prefix_reply_e8d4e804",
+	"ip6_nd_address_autoconfig_9e14a4a7",
+	"ip6_nd_address_autoconfig_reply_e8d4e804",
+	"ip6_ra_event_0364c1c5",
+	"ip6nd_proxy_add_del_c2e4a686",
+	"ip6nd_proxy_add_del_reply_e8d4e804",
+	"ip6nd_proxy_details_30b9ff4a",
+	"ip6nd_proxy_dump_51077d14",
+	"ip6nd_proxy_enable_disable_7daa1e3a",
+	"ip6nd_proxy_enable_disable_reply_e8d4e804",
+	"ip6nd_send_router_solicitation_e5de609c",
+	"ip6nd_send_router_solicitation_reply_e8d4e804",
+	"ip_address_details_ee29b797",
+	"ip_address_dump_2d033de4",
+	"ip_container_proxy_add_del_7df1dff1",
+	"ip_container_proxy_add_del_reply_e8d4e804",
+	"ip_container_proxy_details_a8085523",
+	"ip_container_proxy_dump_51077d14",
+	"ip_details_eb152d07",
+	"ip_dump_98d231ca",
+	"ip_local_reass_enable_disable_34e2ccc4",
+	"ip_local_reass_enable_disable_reply_e8d4e804",
+	"ip_local_reass_get_51077d14",
+	"ip_local_reass_get_reply_3e93a702",
+	"ip_mroute_add_del_0dd7e790",
+	"ip_mroute_add_del_reply_1992deab",
+	"ip_mroute_details_c5cb23fc",
+	"ip_mroute_dump_b9d2e09e",
+	"ip_mtable_details_b9d2e09e",
+	"ip_mtable_dump_51077d14",
+	"ip_neighbor_add_del_0607c257",
+	"ip_neighbor_add_del_reply_1992deab",
+	"ip_neighbor_config_f4a5cf44",
+	"ip_neighbor_config_get_a5db7bf7",
+	"ip_neighbor_config_get_reply_798e6fdd",
+	"ip_neighbor_config_reply_e8d4e804",
+	"ip_neighbor_details_e29d79f0",
+	"ip_neighbor_dump_d817a484",
+	"ip_neighbor_event_bdb092b2",
+	"ip_neighbor_event_v2_c1d53dc0",
+	"ip_neighbor_flush_16aa35d2",
+	"ip_neighbor_flush_reply_e8d4e804",
+	"ip_neighbor_replace_begin_51077d14",
+	"ip_neighbor_replace_begin_reply_e8d4e804",
+	"ip_neighbor_replace_end_51077d14",
+	"ip_neighbor_replace_end_reply_e8d4e804",
+	"ip_path_mtu_details_ac9539a7",
+	"ip_path_mtu_get_f75ba505",
+	"ip_path_mtu_get_reply_53b48f5d",
+	"ip_path_mtu_replace_begin_51077d14",
+	"ip_path_mtu_replace_begin_reply_e8d4e804",
+	"ip_path_mtu_replace_end_51077d14",
+	"ip_path_mtu_replace_end_reply_e8d4e804",
+	"ip_path_mtu_update_10bbe5cb",
+	"ip_path_mtu_update_reply_e8d4e804",
+	"ip_punt_police_db867cea",
+	"ip_punt_police_reply_e8d4e804",
+	"ip_punt_redirect_6580f635",
+	"ip_punt_redirect_details_2cef63e7",
+	"ip_punt_redirect_dump_2d033de4",
+	"ip_punt_redirect_reply_e8d4e804",
+	"ip_punt_redirect_v2_details_7ba42e1d",
+	"ip_punt_redirect_v2_dump_d817a484",
+	"ip_reassembly_enable_disable_eb77968d",
+	"ip_reassembly_enable_disable_reply_e8d4e804",
+	"ip_reassembly_get_ea13ff63",
+	"ip_reassembly_get_reply_d5eb8d34",
+	"ip_reassembly_set_16467d25",
+	"ip_reassembly_set_reply_e8d4e804",
+	"ip_route_add_del_b8ecfe0d",
+	"ip_route_add_del_reply_1992deab",
+	"ip_route_add_del_v2_521ef330",
+	"ip_route_add_del_v2_reply_1992deab",
+	"ip_route_details_bda8f315",
+	"ip_route_dump_b9d2e09e",
+	"ip_route_lookup_710d6471",
+	"ip_route_lookup_reply_5d8febcb",
+	"ip_route_lookup_v2_710d6471",
+	"ip_route_lookup_v2_reply_84cc9e03",
+	"ip_route_v2_details_b09aa6c0",
+	"ip_route_v2_dump_d16f72e6",
+	"ip_session_redirect_add_2f78ffda",
+	"ip_session_redirect_add_reply_e8d4e804",
+	"ip_session_redirect_add_v2_0765f51f",
+	"ip_session_redirect_add_v2_reply_e8d4e804",
+	"ip_session_redirect_del_fb643388",
+	"ip_session_redirect_del_reply_e8d4e804",
+	"ip_session_redirect_details_4487a233",
+	"ip_session_redirect_dump_33554253",
+	"ip_source_and_port_range_check_add_del_92a067e3",
+	"ip_source_and_port_range_check_add_del_reply_e8d4e804",
+	"ip_source_and_port_range_check_interface_add_del_e1ba8987",
+	"ip_source_and_port_range_check_interface_add_del_reply_e8d4e804",
+	"ip_table_add_del_0ffdaec0",
+	"ip_table_add_del_reply_e8d4e804",
+	"ip_table_add_del_v2_14e5081f",
+	"ip_table_add_del_v2_reply_e8d4e804",
+	"ip_table_allocate_b9d2e09e",
+	"ip_table_allocate_reply_1728303a",
+	"ip_table_details_c79fca0f",
+	"ip_table_dump_51077d14",
+	"ip_table_flush_b9d2e09e",
+	"ip_table_flush_reply_e8d4e804",
+	"ip_table_replace_begin_b9d2e09e",
+	"ip_table_replace_begin_reply_e8d4e804",
+	"ip_table_replace_end_b9d2e09e",
+	"ip_table_replace_end_reply_e8d4e804",
+	"ip_unnumbered_details_cc59bd42",
+	"ip_unnumbered_dump_f9e6675e",
+	"ipfix_all_exporter_details_0dedbfe4",
+	"ipfix_all_exporter_get_f75ba505",
+	"ipfix_all_exporter_get_reply_53b48f5d",
+	"ipfix_classify_stream_details_2903539d",
+	"ipfix_classify_stream_dump_51077d14",
+	"ipfix_classify_table_add_del_3e449bb9",
+	"ipfix_classify_table_add_del_reply_e8d4e804",
+	"ipfix_classify_table_details_1af8c28c",
+	"ipfix_classify_table_dump_51077d14",
+	"ipfix_exporter_create_delete_0753a768",
+	"ipfix_exporter_create_delete_reply_9ffac24b",
+	"ipfix_exporter_details_0dedbfe4",
+	"ipfix_exporter_dump_51077d14",
+	"ipfix_flush_51077d14",
+	"ipfix_flush_reply_e8d4e804",
+	"ipip_6rd_add_tunnel_b9ec1863",
+	"ipip_6rd_add_tunnel_reply_5383d31f",
+	"ipip_6rd_del_tunnel_f9e6675e",
+	"ipip_6rd_del_tunnel_reply_e8d4e804",
+	"ipip_add_tunnel_2ac399f5",
+	"ipip_add_tunnel_reply_5383d31f",
+	"ipip_del_tunnel_f9e6675e",
+	"ipip_del_tunnel_reply_e8d4e804",
+	"ipip_tunnel_details_d31cb34e",
+	"ipip_tunnel_dump_f9e6675e",
+	"ipsec_backend_details_ee601c29",
+	"ipsec_backend_dump_51077d14",
+	"ipsec_interface_add_del_spd_80f80cbb",
+	"ipsec_interface_add_del_spd_reply_e8d4e804",
+	"ipsec_itf_create_6f50b3bc",
+	"ipsec_itf_create_reply_5383d31f",
+	"ipsec_itf_delete_f9e6675e",
+	"ipsec_itf_delete_reply_e8d4e804",
+	"ipsec_itf_details_548a73b8",
+	"ipsec_itf_dump_f9e6675e",
+	"ipsec_sa_details_345d14a7",
+	"ipsec_sa_dump_2076c2f4",
+	"ipsec_sa_v2_details_e2130051",
+	"ipsec_sa_v2_dump_2076c2f4",
+	"ipsec_sa_v3_details_2fc991ee",
+	"ipsec_sa_v3_dump_2076c2f4",
+	"ipsec_sa_v4_details_87a322d7",
+	"ipsec_sa_v4_dump_2076c2f4",
+	"ipsec_sa_v5_details_3cfecfbd",
+	"ipsec_sa_v5_dump_2076c2f4",
+	"ipsec_sad_bind_0649c0d9",
+	"ipsec_sad_bind_reply_e8d4e804",
+	"ipsec_sad_entry_add_50229353",
+	"ipsec_sad_entry_add_del_ab64b5c6",
+	"ipsec_sad_entry_add_del_reply_9ffac24b",
+	"ipsec_sad_entry_add_del_v2_aca78b27",
+	"ipsec_sad_entry_add_del_v2_reply_9ffac24b",
+	"ipsec_sad_entry_add_del_v3_c77ebd92",
+	"ipsec_sad_entry_add_del_v3_reply_9ffac24b",
+	"ipsec_sad_entry_add_reply_9ffac24b",
+	"ipsec_sad_entry_add_v2_9611297a",
+	"ipsec_sad_entry_add_v2_reply_9ffac24b",
+	"ipsec_sad_entry_del_3a91bde5",
+	"ipsec_sad_entry_del_reply_e8d4e804",
+	"ipsec_sad_entry_update_1412af86",
+	"ipsec_sad_entry_update_reply_e8d4e804",
+	"ipsec_sad_unbind_2076c2f4",
+	"ipsec_sad_unbind_reply_e8d4e804",
+	"ipsec_select_backend_5bcfd3b7",
+	"ipsec_select_backend_reply_e8d4e804",
+	"ipsec_set_async_mode_a6465f7c",
+	"ipsec_set_async_mode_reply_e8d4e804",
+	"ipsec_spd_add_del_20e89a95",
+	"ipsec_spd_add_del_reply_e8d4e804",
+	"ipsec_spd_details_5813d7a2",
+	"ipsec_spd_dump_afefbf7d",
+	"ipsec_spd_entry_add_del_338b7411",
+	"ipsec_spd_entry_add_del_reply_9ffac24b",
+	"ipsec_spd_entry_add_del_v2_7bfe69fc",
+	"ipsec_spd_entry_add_del_v2_reply_9ffac24b",
+	"ipsec_spd_interface_details_7a0bcf3e",
+	"ipsec_spd_interface_dump_8971de19",
+	"ipsec_spds_details_a04bb254",
+	"ipsec_spds_dump_51077d14",
+	"ipsec_tunnel_protect_del_cd239930",
+	"ipsec_tunnel_protect_del_reply_e8d4e804",
+	"ipsec_tunnel_protect_details_21663a50",
+	"ipsec_tunnel_protect_dump_f9e6675e",
+	"ipsec_tunnel_protect_update_30d5f133",
+	"ipsec_tunnel_protect_update_reply_e8d4e804",
+	"l2_arp_term_event_6963e07a",
+	"l2_fib_clear_table_51077d14",
+	"l2_fib_clear_table_reply_e8d4e804",
+	"l2_fib_table_details_a44ef6b8",
+	"l2_fib_table_dump_c25fdce6",
+	"l2_flags_fc41cfe8",
+	"l2_flags_reply_29b2a2b3",
+	"l2_interface_efp_filter_5501adee",
+	"l2_interface_efp_filter_reply_e8d4e804",
+	"l2_interface_pbb_tag_rewrite_38e802a8",
+	"l2_interface_pbb_tag_rewrite_reply_e8d4e804",
+	"l2_interface_vlan_tag_rewrite_62cc0bbc",
+	"l2_interface_vlan_tag_rewrite_reply_e8d4e804",
+	"l2_macs_event_44b8fd64",
+	"l2_patch_add_del_a1f6a6f3",
+	"l2_patch_add_del_reply_e8d4e804",
+	"l2_xconnect_details_472b6b67",
+	"l2_xconnect_dump_51077d14",
+	"l2fib_add_del_eddda487",
+	"l2fib_add_del_reply_e8d4e804",
+	"l2fib_flush_all_51077d14",
+	"l2fib_flush_all_reply_e8d4e804",
+	"l2fib_flush_bd_c25fdce6",
+	"l2fib_flush_bd_reply_e8d4e804",
+	"l2fib_flush_int_f9e6675e",
+	"l2fib_flush_int_reply_e8d4e804",
+	"l2fib_set_scan_delay_a3b968a4",
+	"l2fib_set_scan_delay_reply_e8d4e804",
+	"l2tpv3_create_tunnel_15bed0c2",
+	"l2tpv3_create_tunnel_reply_5383d31f",
+	"l2tpv3_interface_enable_disable_3865946c",
+	"l2tpv3_interface_enable_disable_reply_e8d4e804",
+	"l2tpv3_set_lookup_key_c9892c86",
+	"l2tpv3_set_lookup_key_reply_e8d4e804",
+	"l2tpv3_set_tunnel_cookies_b3f4faf7",
+	"l2tpv3_set_tunnel_cookies_reply_e8d4e804",
+	"l3xc_del_e7dbef91",
+	"l3xc_del_reply_e8d4e804",
+	"l3xc_details_bc5bf852",
+	"l3xc_dump_f9e6675e",
+	"l3xc_plugin_get_version_51077d14",
+	"l3xc_plugin_get_version_reply_9b32cf86",
+	"l3xc_update_e96aabdf",
+	"l3xc_update_reply_1992deab",
+	"lb_add_del_as_35d72500",
+	"lb_add_del_as_reply_e8d4e804",
+	"lb_add_del_intf_nat4_47d6e753",
+	"lb_add_del_intf_nat4_reply_e8d4e804",
+	"lb_add_del_intf_nat6_47d6e753",
+	"lb_add_del_intf_nat6_reply_e8d4e804",
+	"lb_add_del_vip_6fa569c7",
+	"lb_add_del_vip_reply_e8d4e804",
+	"lb_add_del_vip_v2_7c520e0f",
+	"lb_add_del_vip_v2_reply_e8d4e804",
+	"lb_as_details_8d24c29e",
+	"lb_as_dump_1063f819",
+	"lb_conf_56cd3261",
+	"lb_conf_reply_e8d4e804",
+	"lb_flush_vip_1063f819",
+	"lb_flush_vip_reply_e8d4e804",
+	"lb_vip_details_1329ec9b",
+	"lb_vip_dump_56110cb7",
+	"lcp_default_ns_get_51077d14",
+	"lcp_default_ns_get_reply_5102feee",
+	"lcp_default_ns_set_69749409",
+	"lcp_default_ns_set_reply_e8d4e804",
+	"lcp_ethertype_enable_f893dae1",
+	"lcp_ethertype_enable_reply_e8d4e804",
+	"lcp_ethertype_get_51077d14",
+	"lcp_ethertype_get_reply_db48c31e",
+	"lcp_itf_pair_add_del_40482b80",
+	"lcp_itf_pair_add_del_reply_e8d4e804",
+	"lcp_itf_pair_add_del_v2_40482b80",
+	"lcp_itf_pair_add_del_v2_reply_39452f52",
+	"lcp_itf_pair_add_del_v3_40482b80",
+	"lcp_itf_pair_add_del_v3_reply_c2502663",
+	"lcp_itf_pair_details_8b5481af",
+	"lcp_itf_pair_get_f75ba505",
+	"lcp_itf_pair_get_reply_53b48f5d",
+	"lcp_itf_pair_get_v2_47250981",
+	"lcp_itf_pair_get_v2_reply_53b48f5d",
+	"lcp_itf_pair_replace_begin_51077d14",
+	"lcp_itf_pair_replace_begin_reply_e8d4e804",
+	"lcp_itf_pair_replace_end_51077d14",
+	"lcp_itf_pair_replace_end_reply_e8d4e804",
+	"lisp_add_del_adjacency_2ce0e6f6",
+	"lisp_add_del_adjacency_reply_e8d4e804",
+	"lisp_add_del_local_eid_4e5a83a2",
+	"lisp_add_del_local_eid_reply_e8d4e804",
+	"lisp_add_del_locator_af4d8f13",
+	"lisp_add_del_locator_reply_e8d4e804",
+	"lisp_add_del_locator_set_6fcd6471",
+	"lisp_add_del_locator_set_reply_b6666db4",
+	"lisp_add_del_map_request_itr_rlocs_6be88e45",
+	"lisp_add_del_map_request_itr_rlocs_reply_e8d4e804",
+	"lisp_add_del_map_resolver_ce19e32d",
+	"lisp_add_del_map_resolver_reply_e8d4e804",
+	"lisp_add_del_map_server_ce19e32d",
+	"lisp_add_del_map_server_reply_e8d4e804",
+	"lisp_add_del_remote_mapping_6d5c789e",
+	"lisp_add_del_remote_mapping_reply_e8d4e804",
+	"lisp_adjacencies_get_8d1f2fe9",
+	"lisp_adjacencies_get_reply_807257bf",
+	"lisp_eid_table_add_del_map_9481416b",
+	"lisp_eid_table_add_del_map_reply_e8d4e804",
+	"lisp_eid_table_details_1c29f792",
+	"lisp_eid_table_dump_629468b5",
+	"lisp_eid_table_map_details_0b6859e2",
+	"lisp_eid_table_map_dump_d6cf0c3d",
+	"lisp_eid_table_vni_details_64abc01e",
+	"lisp_eid_table_vni_dump_51077d14",
+	"lisp_enable_disable_c264d7bf",
+	"lisp_enable_disable_reply_e8d4e804",
+	"lisp_get_map_request_itr_rlocs_51077d14",
+	"lisp_get_map_request_itr_rlocs_reply_76580f3a",
+	"lisp_locator_details_2c620ffe",
+	"lisp_locator_dump_b954fad7",
+	"lisp_locator_set_details_5b33a105",
+	"lisp_locator_set_dump_c2cb5922",
+	"lisp_map_register_enable_disable_c264d7bf",
+	"lisp_map_register_enable_disable_reply_e8d4e804",
+	"lisp_map_request_mode_f43c26ae",
+	"lisp_map_request_mode_reply_e8d4e804",
+	"lisp_map_resolver_details_3e78fc57",
+	"lisp_map_resolver_dump_51077d14",
+	"lisp_map_server_details_3e78fc57",
+	"lisp_map_server_dump_51077d14",
+	"lisp_pitr_set_locator_set_486e2b76",
+	"lisp_pitr_set_locator_set_reply_e8d4e804",
+	"lisp_rloc_probe_enable_disable_c264d7bf",
+	"lisp_rloc_probe_enable_disable_reply_e8d4e804",
+	"lisp_use_petr_d87dbad9",
+	"lisp_use_petr_reply_e8d4e804",
+	"lldp_config_c14445df",
+	"lldp_config_reply_e8d4e804",
+	"lldp_details_c2d226cd",
+	"lldp_dump_f75ba505",
+	"lldp_dump_reply_53b48f5d",
+	"log_details_03d61cc0",
+	"log_dump_6ab31753",
+	"macip_acl_add_ce6fbad0",
+	"macip_acl_add_replace_2a461dd4",
+	"macip_acl_add_replace_reply_ac407b0c",
+	"macip_acl_add_reply_ac407b0c",
+	"macip_acl_del_ef34fea4",
+	"macip_acl_del_reply_e8d4e804",
+	"macip_acl_details_27135b59",
+	"macip_acl_dump_ef34fea4",
+	"macip_acl_interface_add_del_4b8690b1",
+	"macip_acl_interface_add_del_reply_e8d4e804",
+	"macip_acl_interface_get_51077d14",
+	"macip_acl_interface_get_reply_accf9b05",
+	"macip_acl_interface_list_details_a0c5d56d",
+	"macip_acl_interface_list_dump_f9e6675e",
+	"mactime_add_del_range_cb56e877",
+	"mactime_add_del_range_reply_e8d4e804",
+	"mactime_details_da25b13a",
+	"mactime_dump_8f454e23",
+	"mactime_dump_reply_49bcc753",
+	"mactime_enable_disable_3865946c",
+	"mactime_enable_disable_reply_e8d4e804",
+	"map_add_del_rule_c65b32f7",
+	"map_add_del_rule_reply_e8d4e804",
+	"map_add_domain_249f195c",
+	"map_add_domain_reply_3e6d4e2c",
+	"map_del_domain_8ac76db6",
+	"map_del_domain_reply_e8d4e804",
+	"map_domain_details_796edb50",
+	"map_domain_dump_51077d14",
+	"map_domains_get_f75ba505",
+	"map_domains_get_reply_53b48f5d",
+	"map_if_enable_disable_59bb32f4",
+	"map_if_enable_disable_reply_e8d4e804",
+	"map_param_add_del_pre_resolve_dae5af03",
+	"map_param_add_del_pre_resolve_reply_e8d4e804",
+	"map_param_get_51077d14",
+	"map_param_get_reply_26272c90",
+	"map_param_set_fragmentation_9ff54d90",
+	"map_param_set_fragmentation_reply_e8d4e804",
+	"map_param_set_icmp6_5d01f8c1",
+	"map_param_set_icmp6_reply_e8d4e804",
+	"map_param_set_icmp_58210cbf",
+	"map_param_set_icmp_reply_e8d4e804",
+	"map_param_set_security_check_6abe9836",
+	"map_param_set_security_check_reply_e8d4e804",
+	"map_param_set_tcp_87a825d9",
+	"map_param_set_tcp_reply_e8d4e804",
+	"map_param_set_traffic_class_9cac455c",
+	"map_param_set_traffic_class_reply_e8d4e804",
+	"map_rule_details_c7cbeea5",
+	"map_rule_dump_e43e6ff6",
+	"map_summary_stats_51077d14",
+	"map_summary_stats_reply_0e4ace0e",
+	"mdata_enable_disable_2e7b47df",
+	"mdata_enable_disable_reply_e8d4e804",
+	"memclnt_create_9c5e1c2f",
+	"memclnt_create_reply_42ec4560",
+	"memclnt_create_v2_c4bd4882",
+	"memclnt_create_v2_reply_42ec4560",
+	"memclnt_delete_7e1c04e3",
+	"memclnt_delete_reply_3d3b6312",
+	"memclnt_keepalive_51077d14",
+	"memclnt_keepalive_reply_e8d4e804",
+	"memclnt_read_timeout_c3a3a452",
+	"memclnt_rx_thread_suspend_c3a3a452",
+	"memif_create_b1b25061",
+	"memif_create_reply_5383d31f",
+	"memif_create_v2_8c7de5f7",
+	"memif_create_v2_reply_5383d31f",
+	"memif_delete_f9e6675e",
+	"memif_delete_reply_e8d4e804",
+	"memif_details_da34feb9",
+	"memif_dump_51077d14",
+	"memif_socket_filename_add_del_a2ce1a10",
+	"memif_socket_filename_add_del_reply_e8d4e804",
+	"memif_socket_filename_add_del_v2_34223bdf",
+	"memif_socket_filename_add_del_v2_reply_9f29bdb9",
+	"memif_socket_filename_details_7ff326f7",
+	"memif_socket_filename_dump_51077d14",
+	"mfib_signal_details_6f4a4cfb",
+	"mfib_signal_dump_51077d14",
+	"modify_vhost_user_if_0e71d40b",
+	"modify_vhost_user_if_reply_e8d4e804",
+	"modify_vhost_user_if_v2_b2483771",
+	"modify_vhost_user_if_v2_reply_e8d4e804",
+	"mpls_interface_details_0b45011c",
+	"mpls_interface_dump_f9e6675e",
+	"mpls_ip_bind_unbind_c7533b32",
+	"mpls_ip_bind_unbind_reply_e8d4e804",
+	"mpls_route_add_del_8e1d1e07",
+	"mpls_route_add_del_reply_1992deab",
+	"mpls_route_details_9b5043dc",
+	"mpls_route_dump_935fdefa",
+	"mpls_table_add_del_57817512",
+	"mpls_table_add_del_reply_e8d4e804",
+	"mpls_table_details_f03ecdc8",
+	"mpls_table_dump_51077d14",
+	"mpls_tunnel_add_del_44350ac1",
+	"mpls_tunnel_add_del_reply_afb01472",
+	"mpls_tunnel_details_57118ae3",
+	"mpls_tunnel_dump_f9e6675e",
+	"mss_clamp_details_d3a4de61",
+	"mss_clamp_enable_disable_d31b44e3",
+	"mss_clamp_enable_disable_reply_e8d4e804",
+	"mss_clamp_get_47250981",
+	"mss_clamp_get_reply_53b48f5d",
+	"nat44_add_del_address_range_6f2b8055",
+	"nat44_add_del_address_range_reply_e8d4e804",
+	"nat44_add_del_identity_mapping_02faaa22",
+	"nat44_add_del_identity_mapping_reply_e8d4e804",
+	"nat44_add_del_interface_addr_4aed50c0",
+	"nat44_add_del_interface_addr_reply_e8d4e804",
+	"nat44_add_del_lb_static_mapping_4f68ee9d",
+	"nat44_add_del_lb_static_mapping_reply_e8d4e804",
+	"nat44_add_del_static_mapping_5ae5f03e",
+	"nat44_add_del_static_mapping_reply_e8d4e804",
+	"nat44_add_del_static_mapping_v2_5e205f1a",
+	"nat44_add_del_static_mapping_v2_reply_e8d4e804",
+	"nat44_address_details_0d1beac1",
+	"nat44_address_dump_51077d14",
+	"nat44_del_session_15a5bf8c",
+	"nat44_del_session_reply_e8d4e804",
+	"nat44_ed_add_del_output_interface_47d6e753",
+	"nat44_ed_add_del_output_interface_reply_e8d4e804",
+	"nat44_ed_add_del_vrf_route_59187407",
+	"nat44_ed_add_del_vrf_route_reply_e8d4e804",
+	"nat44_ed_add_del_vrf_table_08330904",
+	"nat44_ed_add_del_vrf_table_reply_e8d4e804",
+	"nat44_ed_output_interface_details_0b45011c",
+	"nat44_ed_output_interface_get_f75ba505",
+	"nat44_ed_output_interface_get_reply_53b48f5d",
+	"nat44_ed_plugin_enable_disable_be17f8dd",
+	"nat44_ed_plugin_enable_disable_reply_e8d4e804",
+	"nat44_ed_set_fq_options_2399bd71",
+	"nat44_ed_set_fq_options_reply_e8d4e804",
+	"nat44_ed_show_fq_options_51077d14",
+	"nat44_ed_show_fq_options_reply_7213b545",
+	"nat44_ed_vrf_tables_details_7b264e4f",
+	"nat44_ed_vrf_tables_dump_51077d14",
+	"nat44_ed_vrf_tables_v2_details_7b264e4f",
+	"nat44_ed_vrf_tables_v2_dump_51077d14",
+	"nat44_ei_add_del_address_range_35f21abc",
+	"nat44_ei_add_del_address_range_reply_e8d4e804",
+	"nat44_ei_add_del_identity_mapping_cb8606b9",
+	"nat44_ei_add_del_identity_mapping_reply_e8d4e804",
+	"nat44_ei_add_del_interface_addr_883abbcc",
+	"nat44_ei_add_del_interface_addr_reply_e8d4e804",
+	"nat44_ei_add_del_output_interface_47d6e753",
+	"nat44_ei_add_del_output_interface_reply_e8d4e804",
+	"nat44_ei_add_del_static_mapping_b404b7fe",
+	"nat44_ei_add_del_static_mapping_reply_e8d4e804",
+	"nat44_ei_address_details_318f1202",
+	"nat44_ei_address_dump_51077d14",
+	"nat44_ei_del_session_74969ffe",
+	"nat44_ei_del_session_reply_e8d4e804",
+	"nat44_ei_del_user_99a9f998",
+	"nat44_ei_del_user_reply_e8d4e804",
+	"nat44_ei_forwarding_enable_disable_b3e225d2",
+	"nat44_ei_forwarding_enable_disable_reply_e8d4e804",
+	"nat44_ei_get_addr_and_port_alloc_alg_51077d14",
+	"nat44_ei_get_addr_and_port_alloc_alg_reply_3607a7d0",
+	"nat44_ei_get_mss_clamping_51077d14",
+	"nat44_ei_get_mss_clamping_reply_1c0b2a78",
+	"nat44_ei_ha_flush_51077d14",
+	"nat44_ei_ha_flush_reply_e8d4e804",
+	"nat44_ei_ha_get_failover_51077d14",
+	"nat44_ei_ha_get_failover_reply_a67d8752",
+	"nat44_ei_ha_get_listener_51077d14",
+	"nat44_ei_ha_get_listener_reply_123ea41f",
+	"nat44_ei_ha_resync_c8ab9e03",
+	"nat44_ei_ha_resync_completed_event_fdc598fb",
+	"nat44_ei_ha_resync_reply_e8d4e804",
+	"nat44_ei_ha_set_failover_718246af",
+	"nat44_ei_ha_set_failover_reply_e8d4e804",
+	"nat44_ei_ha_set_listener_e4a8cb4e",
+	"nat44_ei_ha_set_listener_reply_e8d4e804",
+	"nat44_ei_identity_mapping_details_30d53e26",
+	"nat44_ei_identity_mapping_dump_51077d14",
+	"nat44_ei_interface_add_del_feature_63a2db8b",
+	"nat44_ei_interface_add_del_feature_reply_e8d4e804",
+	"nat44_ei_interface_add_del_output_feature_63a2db8b",
+	"nat44_ei_interface_add_del_output_feature_reply_e8d4e804",
+	"nat44_ei_interface_addr_details_0b45011c",
+	"nat44_ei_interface_addr_dump_51077d14",
+	"nat44_ei_interface_details_f446e508",
+	"nat44_ei_interface_dump_51077d14",
+	"nat44_ei_interface_output_feature_details_f446e508",
+	"nat44_ei_interface_output_feature_dump_51077d14",
+	"nat44_ei_ipfix_enable_disable_9af4a2d2",
+	"nat44_ei_ipfix_enable_disable_reply_e8d4e804",
+	"nat44_ei_output_interface_details_0b45011c",
+	"nat44_ei_output_interface_get_f75ba505",
+	"nat44_ei_output_interface_get_reply_53b48f5d",
+	"nat44_ei_plugin_enable_disable_bf692144",
+	"nat44_ei_plugin_enable_disable_reply_e8d4e804",
+	"nat44_ei_set_addr_and_port_alloc_alg_deeb746f",
+	"nat44_ei_set_addr_and_port_alloc_alg_reply_e8d4e804",
+	"nat44_ei_set_fq_options_2399bd71",
+	"nat44_ei_set_fq_options_reply_e8d4e804",
+	"nat44_ei_set_log_level_70076bfe",
+	"nat44_ei_set_log_level_reply_e8d4e804",
+	"nat44_ei_set_mss_clamping_25e90abb",
+	"nat44_ei_set_mss_clamping_reply_e8d4e804",
+	"nat44_ei_set_timeouts_d4746b16",
+	"nat44_ei_set_timeouts_reply_e8d4e804",
+	"nat44_ei_set_workers_da926638",
+	"nat44_ei_set_workers_reply_e8d4e804",
+	"nat44_ei_show_fq_options_51077d14",
+	"nat44_ei_show_fq_options_reply_7213b545",
+	"nat44_ei_show_running_config_51077d14",
+	"nat44_ei_show_running_config_reply_41b66a81",
+	"nat44_ei_static_mapping_details_6b51ca6e",
+	"nat44_ei_static_mapping_dump_51077d14",
+	"nat44_ei_user_details_355896c2",
+	"nat44_ei_user_dump_51077d14",
+	"nat44_ei_user_session_details_19b7c0ac",
+	"nat44_ei_user_session_dump_e1899c98",
+	"nat44_ei_user_session_v2_details_5bd3e9d6",
+	"nat44_ei_user_session_v2_dump_e1899c98",
+	"nat44_ei_worker_details_84bf06fc",
+	"nat44_ei_worker_dump_51077d14",
+	"nat44_forwarding_enable_disable_b3e225d2",
+	"nat44_forwarding_enable_disable_reply_e8d4e804",
+	"nat44_identity_mapping_details_2a52a030",
+	"nat44_identity_mapping_dump_51077d14",
+	"nat44_interface_add_del_feature_f3699b83",
+	"nat44_interface_add_del_feature_reply_e8d4e804",
+	"nat44_interface_addr_details_e4aca9ca",
+	"nat44_interface_addr_dump_51077d14",
+	"nat44_interface_details_5d286289",
+	"nat44_interface_dump_51077d14",
+	"nat44_lb_static_mapping_add_del_local_7ca47547",
+	"nat44_lb_static_mapping_add_del_local_reply_e8d4e804",
+	"nat44_lb_static_mapping_details_ed5ce876",
+	"nat44_lb_static_mapping_dump_51077d14",
+	"nat44_set_session_limit_8899bbb1",
+	"nat44_set_session_limit_reply_e8d4e804",
+	"nat44_show_running_config_51077d14",
+	"nat44_show_running_config_reply_93d8e267",
+	"nat44_static_mapping_details_06cb40b2",
+	"nat44_static_mapping_dump_51077d14",
+	"nat44_user_details_355896c2",
+	"nat44_user_dump_51077d14",
+	"nat44_user_session_details_2cf6e16d",
+	"nat44_user_session_dump_e1899c98",
+	"nat44_user_session_v2_details_fd42b729",
+	"nat44_user_session_v2_dump_e1899c98",
+	"nat44_user_session_v3_details_edae926e",
+	"nat44_user_session_v3_dump_e1899c98",
+	"nat64_add_del_interface_addr_47d6e753",
+	"nat64_add_del_interface_addr_reply_e8d4e804",
+	"nat64_add_del_interface_f3699b83",
+	"nat64_add_del_interface_reply_e8d4e804",
+	"nat64_add_del_pool_addr_range_a3b944e3",
+	"nat64_add_del_pool_addr_range_reply_e8d4e804",
+	"nat64_add_del_prefix_727b2f4c",
+	"nat64_add_del_prefix_reply_e8d4e804",
+	"nat64_add_del_static_bib_1c404de5",
+	"nat64_add_del_static_bib_reply_e8d4e804",
+	"nat64_bib_details_43bc3ddf",
+	"nat64_bib_dump_cfcb6b75",
+	"nat64_get_timeouts_51077d14",
+	"nat64_get_timeouts_reply_3c4df4e1",
+	"nat64_interface_details_5d286289",
+	"nat64_interface_dump_51077d14",
+	"nat64_plugin_enable_disable_45948b90",
+	"nat64_plugin_enable_disable_reply_e8d4e804",
+	"nat64_pool_addr_details_9bb99cdb",
+	"nat64_pool_addr_dump_51077d14",
+	"nat64_prefix_details_20568de3",
+	"nat64_prefix_dump_51077d14",
+	"nat64_set_timeouts_d4746b16",
+	"nat64_set_timeouts_reply_e8d4e804",
+	"nat64_st_details_dd3361ed",
+	"nat64_st_dump_cfcb6b75",
+	"nat66_add_del_interface_f3699b83",
+	"nat66_add_del_interface_reply_e8d4e804",
+	"nat66_add_del_static_mapping_3ed88f71",
+	"nat66_add_del_static_mapping_reply_e8d4e804",
+	"nat66_interface_details_5d286289",
+	"nat66_interface_dump_51077d14",
+	"nat66_plugin_enable_disable_56f2f83b",
+	"nat66_plugin_enable_disable_reply_e8d4e804",
+	"nat66_static_mapping_details_df39654b",
+	"nat66_static_mapping_dump_51077d14",
+	"nat_det_add_del_map_1150a190",
+	"nat_det_add_del_map_reply_e8d4e804",
+	"nat_det_close_session_in_3c68e073",
+	"nat_det_close_session_in_reply_e8d4e804",
+	"nat_det_close_session_out_f6b259d1",
+	"nat_det_close_session_out_reply_e8d4e804",
+	"nat_det_forward_7f8a89cd",
+	"nat_det_forward_reply_a8ccbdc0",
+	"nat_det_map_details_ad91dc83",
+	"nat_det_map_dump_51077d14",
+	"nat_det_reverse_a7573fe1",
+	"nat_det_reverse_reply_34066d48",
+	"nat_det_session_details_27f3c171",
+	"nat_det_session_dump_e45a3af7",
+	"nat_get_mss_clamping_51077d14",
+	"nat_get_mss_clamping_reply_1c0b2a78",
+	"nat_ipfix_enable_disable_9af4a2d2",
+	"nat_ipfix_enable_disable_reply_e8d4e804",
+	"nat_set_mss_clamping_25e90abb",
+	"nat_set_mss_clamping_reply_e8d4e804",
+	"nat_set_timeouts_d4746b16",
+	"nat_set_timeouts_reply_e8d4e804",
+	"nat_set_workers_da926638",
+	"nat_set_workers_reply_e8d4e804",
+	"nat_worker_details_84bf06fc",
+	"nat_worker_dump_51077d14",
+	"npt66_binding_add_del_8aa10a52",
+	"npt66_binding_add_del_reply_e8d4e804",
+	"nsh_add_del_entry_7dea480b",
+	"nsh_add_del_entry_reply_6296a9eb",
+	"nsh_add_del_map_0a0f42b0",
+	"nsh_add_del_map_reply_b2b127ef",
+	"nsh_entry_details_046fb556",
+	"nsh_entry_dump_cdaf8ccb",
+	"nsh_map_details_2fefcf49",
+	"nsh_map_dump_8fc06b82",
+	"nsim_configure2_64de8ed3",
+	"nsim_configure2_reply_e8d4e804",
+	"nsim_configure_16ed400f",
+	"nsim_configure_reply_e8d4e804",
+	"nsim_cross_connect_enable_disable_9c3ead86",
+	"nsim_cross_connect_enable_disable_reply_e8d4e804",
+	"nsim_output_feature_enable_disable_3865946c",
+	"nsim_output_feature_enable_disable_reply_e8d4e804",
+	"one_add_del_adjacency_9e830312",
+	"one_add_del_adjacency_reply_e8d4e804",
+	"one_add_del_l2_arp_entry_1aa5e8b3",
+	"one_add_del_l2_arp_entry_reply_e8d4e804",
+	"one_add_del_local_eid_4e5a83a2",
+	"one_add_del_local_eid_reply_e8d4e804",
+	"one_add_del_locator_af4d8f13",
+	"one_add_del_locator_reply_e8d4e804",
+	"one_add_del_locator_set_6fcd6471",
+	"one_add_del_locator_set_reply_b6666db4",
+	"one_add_del_map_request_itr_rlocs_6be88e45",
+	"one_add_del_map_request_itr_rlocs_reply_e8d4e804",
+	"one_add_del_map_resolver_ce19e32d",
+	"one_add_del_map_resolver_reply_e8d4e804",
+	"one_add_del_map_server_ce19e32d",
+	"one_add_del_map_server_reply_e8d4e804",
+	"one_add_del_ndp_entry_0f8a287c",
+	"one_add_del_ndp_entry_reply_e8d4e804",
+	"one_add_del_remote_mapping_6d5c789e",
+	"one_add_del_remote_mapping_reply_e8d4e804",
+	"one_adjacencies_get_8d1f2fe9",
+	"one_adjacencies_get_reply_085bab89",
+	"one_eid_table_add_del_map_9481416b",
+	"one_eid_table_add_del_map_reply_e8d4e804",
+	"one_eid_table_details_1c29f792",
+	"one_eid_table_dump_bd190269",
+	"one_eid_table_map_details_0b6859e2",
+	"one_eid_table_map_dump_d6cf0c3d",
+	"one_eid_table_vni_details_64abc01e",
+	"one_eid_table_vni_dump_51077d14",
+	"one_enable_disable_c264d7bf",
+	"one_enable_disable_petr_mode_c264d7bf",
+	"one_enable_disable_petr_mode_reply_e8d4e804",
+	"one_enable_disable_pitr_mode_c264d7bf",
+	"one_enable_disable_pitr_mode_reply_e8d4e804",
+	"one_enable_disable_reply_e8d4e804",
+	"one_enable_disable_xtr_mode_c264d7bf",
+	"one_enable_disable_xtr_mode_reply_e8d4e804",
+	"one_get_map_request_itr_rlocs_51077d14",
+	"one_get_map_request_itr_rlocs_reply_76580f3a",
+	"one_get_transport_protocol_51077d14",
+	"one_get_transport_protocol_reply_62a28eb3",
+	"one_l2_arp_bd_get_51077d14",
+	"one_l2_arp_bd_get_reply_221ac888",
+	"one_l2_arp_entries_get_4d418cf4",
+	"one_l2_arp_entries_get_reply_b0dd200f",
+	"one_locator_details_2c620ffe",
+	"one_locator_dump_9b11076c",
+	"one_locator_set_details_5b33a105",
+	"one_locator_set_dump_71190768",
+	"one_map_register_enable_disable_c264d7bf",
+	"one_map_register_enable_disable_reply_e8d4e804",
+	"one_map_register_fallback_threshold_f7d4a475",
+	"one_map_register_fallback_threshold_reply_e8d4e804",
+	"one_map_register_set_ttl_dd59f1f3",
+	"one_map_register_set_ttl_reply_e8d4e804",
+	"one_map_request_mode_ffa5d2f5",
+	"one_map_request_mode_reply_e8d4e804",
+	"one_map_resolver_details_3e78fc57",
+	"one_map_resolver_dump_51077d14",
+	"one_map_server_details_3e78fc57",
+	"one_map_server_dump_51077d14",
+	"one_ndp_bd_get_51077d14",
+	"one_ndp_bd_get_reply_221ac888",
+	"one_ndp_entries_get_4d418cf4",
+	"one_ndp_entries_get_reply_70719b1a",
+	"one_nsh_set_locator_set_486e2b76",
+	"one_nsh_set_locator_set_reply_e8d4e804",
+	"one_pitr_set_locator_set_486e2b76",
+	"one_pitr_set_locator_set_reply_e8d4e804",
+	"one_rloc_probe_enable_disable_c264d7bf",
+	"one_rloc_probe_enable_disable_reply_e8d4e804",
+	"one_set_transport_protocol_07b6b85f",
+	"one_set_transport_protocol_reply_e8d4e804",
+	"one_show_petr_mode_51077d14",
+	"one_show_petr_mode_reply_f15abb16",
+	"one_show_pitr_mode_51077d14",
+	"one_show_pitr_mode_reply_f15abb16",
+	"one_show_xtr_mode_51077d14",
+	"one_show_xtr_mode_reply_f15abb16",
+	"one_stats_details_2eb74678",
+	"one_stats_dump_51077d14",
+	"one_stats_enable_disable_c264d7bf",
+	"one_stats_enable_disable_reply_e8d4e804",
+	"one_stats_flush_51077d14",
+	"one_stats_flush_reply_e8d4e804",
+	"one_use_petr_d87dbad9",
+	"one_use_petr_reply_e8d4e804",
+	"output_acl_set_interface_de7ad708",
+	"output_acl_set_interface_reply_e8d4e804",
+	"p2p_ethernet_add_36a1a6dc",
+	"p2p_ethernet_add_reply_5383d31f",
+	"p2p_ethernet_del_62f81c8c",
+	"p2p_ethernet_del_reply_e8d4e804",
+	"pcap_set_filter_function_616abb92",
+	"pcap_set_filter_function_reply_e8d4e804",
+	"pcap_trace_off_51077d14",
+	"pcap_trace_off_reply_e8d4e804",
+	"pcap_trace_on_cb39e968",
+	"pcap_trace_on_reply_e8d4e804",
+	"pg_capture_3712fb6c",
+	"pg_capture_reply_e8d4e804",
+	"pg_create_interface_b7c893d7",
+	"pg_create_interface_reply_5383d31f",
+	"pg_create_interface_v2_8657466a",
+	"pg_create_interface_v2_reply_5383d31f",
+	"pg_delete_interface_f9e6675e",
+	"pg_delete_interface_reply_e8d4e804",
+	"pg_enable_disable_01f94f3a",
+	"pg_enable_disable_reply_e8d4e804",
+	"pg_interface_enable_disable_coalesce_a2ef99e7",
+	"pg_interface_enable_disable_coalesce_reply_e8d4e804",
+	"ping_finished_event_397ccf72",
+	"pipe_create_bb263bd3",
+	"pipe_create_reply_b7ce310c",
+	"pipe_delete_f9e6675e",
+	"pipe_delete_reply_e8d4e804",
+	"pipe_details_c52b799d",
+	"pipe_dump_51077d14",
+	"pnat_binding_add_946ee0b7",
+	"pnat_binding_add_reply_4cd980a7",
+	"pnat_binding_add_v2_946ee0b7",
+	"pnat_binding_add_v2_reply_4cd980a7",
+	"pnat_binding_attach_6e074232",
+	"pnat_binding_attach_reply_e8d4e804",
+	"pnat_binding_del_9259df7b",
+	"pnat_binding_del_reply_e8d4e804",
+	"pnat_binding_detach_6e074232",
+	"pnat_binding_detach_reply_e8d4e804",
+	"pnat_bindings_details_08fb2815",
+	"pnat_bindings_get_f75ba505",
+	"pnat_bindings_get_reply_53b48f5d",
+	"pnat_flow_lookup_1ef8747c",
+	"pnat_flow_lookup_reply_4cd980a7",
+	"pnat_interfaces_details_4cb09493",
+	"pnat_interfaces_get_f75ba505",
+	"pnat_interfaces_get_reply_53b48f5d",
+	"policer_add_4d949e35",
+	"policer_add_del_2b31dd38",
+	"policer_add_del_reply_a177cef2",
+	"policer_add_reply_a177cef2",
+	"policer_bind_dcf516f9",
+	"policer_bind_reply_e8d4e804",
+	"policer_bind_v2_f87bd3c0",
+	"policer_bind_v2_reply_e8d4e804",
+	"policer_classify_details_dfd08765",
+	"policer_classify_dump_56cbb5fb",
+	"policer_classify_set_interface_de7ad708",
+	"policer_classify_set_interface_reply_e8d4e804",
+	"policer_del_7ff7912e",
+	"policer_del_reply_e8d4e804",
+	"policer_details_72d0e248",
+	"policer_dump_35f1ae0f",
+	"policer_dump_v2_7ff7912e",
+	"policer_input_233f0ef5",
+	"policer_input_reply_e8d4e804",
+	"policer_input_v2_8388eb84",
+	"policer_input_v2_reply_e8d4e804",
+	"policer_output_233f0ef5",
+	"policer_output_reply_e8d4e804",
+	"policer_output_v2_8388eb84",
+	"policer_output_v2_reply_e8d4e804",
+	"policer_reset_7ff7912e",
+	"policer_reset_reply_e8d4e804",
+	"policer_update_fd039ef0",
+	"policer_update_reply_e8d4e804",
+	"pot_profile_activate_0770af98",
+	"pot_profile_activate_reply_e8d4e804",
+	"pot_profile_add_ad5da3a3",
+	"pot_profile_add_reply_e8d4e804",
+	"pot_profile_del_cd63f53b",
+	"pot_profile_del_reply_e8d4e804",
+	"pot_profile_show_config_details_b7ce0618",
+	"pot_profile_show_config_dump_005b7d59",
+	"pppoe_add_del_cp_eacd9aaa",
+	"pppoe_add_del_cp_reply_e8d4e804",
+	"pppoe_add_del_session_f6fd759e",
+	"pppoe_add_del_session_reply_5383d31f",
+	"pppoe_session_details_4b8e8a4a",
+	"pppoe_session_dump_f9e6675e",
+	"proxy_arp_add_del_1823c3e7",
+	"proxy_arp_add_del_reply_e8d4e804",
+	"proxy_arp_details_5b948673",
+	"proxy_arp_dump_51077d14",
+	"proxy_arp_intfc_details_f6458e5f",
+	"proxy_arp_intfc_dump_51077d14",
+	"proxy_arp_intfc_enable_disable_ae6cfcfb",
+	"proxy_arp_intfc_enable_disable_reply_e8d4e804",
+	"punt_acl_add_del_a93bf3a0",
+	"punt_acl_add_del_reply_e8d4e804",
+	"punt_acl_get_51077d14",
+	"punt_acl_get_reply_8409b9dd",
+	"punt_reason_details_2c9d4a40",
+	"punt_reason_dump_5c0dd4fe",
+	"punt_socket_deregister_75afa766",
+	"punt_socket_deregister_reply_e8d4e804",
+	"punt_socket_details_330466e4",
+	"punt_socket_dump_916fb004",
+	"punt_socket_register_7875badb",
+	"punt_socket_register_reply_bd30ae90",
+	"pvti_interface_create_a1e95595",
+	"pvti_interface_create_reply_5383d31f",
+	"pvti_interface_delete_f9e6675e",
+	"pvti_interface_delete_reply_e8d4e804",
+	"pvti_interface_details_a26072b7",
+	"pvti_interface_dump_f9e6675e",
+	"qos_egress_map_delete_3a91bde5",
+	"qos_egress_map_delete_reply_e8d4e804",
+	"qos_egress_map_details_46c5653c",
+	"qos_egress_map_dump_51077d14",
+	"qos_egress_map_update_6d1c065f",
+	"qos_egress_map_update_reply_e8d4e804",
+	"qos_mark_details_89fe81a9",
+	"qos_mark_details_reply_e8d4e804",
+	"qos_mark_dump_f9e6675e",
+	"qos_mark_enable_disable_1a010f74",
+	"qos_mark_enable_disable_reply_e8d4e804",
+	"qos_record_details_a425d4d3",
+	"qos_record_dump_51077d14",
+	"qos_record_enable_disable_2f1a4a38",
+	"qos_record_enable_disable_reply_e8d4e804",
+	"qos_store_details_3ee0aad7",
+	"qos_store_dump_51077d14",
+	"qos_store_enable_disable_f3abcc8b",
+	"qos_store_enable_disable_reply_e8d4e804",
+	"rdma_create_076fe418",
+	"rdma_create_reply_5383d31f",
+	"rdma_create_v2_5826a4f3",
+	"rdma_create_v2_reply_5383d31f",
+	"rdma_create_v3_c6287ea8",
+	"rdma_create_v3_reply_5383d31f",
+	"rdma_create_v4_c6287ea8",
+	"rdma_create_v4_reply_5383d31f",
+	"rdma_delete_f9e6675e",
+	"rdma_delete_reply_e8d4e804",
+	"rpc_call_7e8a2c95",
+	"rpc_call_reply_e8d4e804",
+	"rx_thread_exit_c3a3a452",
+	"session_enable_disable_c264d7bf",
+	"session_enable_disable_reply_e8d4e804",
+	"session_enable_disable_v2_f09fbf32",
+	"session_enable_disable_v2_reply_e8d4e804",
+	"session_rule_add_del_82a90af5",
+	"session_rule_add_del_reply_e8d4e804",
+	"session_rules_details_4ef746e7",
+	"session_rules_dump_51077d14",
+	"session_rules_v2_details_f91993dc",
+	"session_rules_v2_dump_51077d14",
+	"session_sapi_enable_disable_c264d7bf",
+	"session_sapi_enable_disable_reply_e8d4e804",
+	"session_sdl_add_del_faeb89fc",
+	"session_sdl_add_del_reply_e8d4e804",
+	"session_sdl_add_del_v2_7f89d3fa",
+	"session_sdl_add_del_v2_reply_e8d4e804",
+	"session_sdl_details_9a8ef5d0",
+	"session_sdl_dump_51077d14",
+	"session_sdl_v2_details_0a057683",
+	"session_sdl_v2_dump_51077d14",
+	"session_sdl_v3_details_829e367f",
+	"session_sdl_v3_dump_51077d14",
+	"set_ip_flow_hash_084ee09e",
+	"set_ip_flow_hash_reply_e8d4e804",
+	"set_ip_flow_hash_router_id_03e4f48e",
+	"set_ip_flow_hash_router_id_reply_e8d4e804",
+	"set_ip_flow_hash_v2_6d132100",
+	"set_ip_flow_hash_v2_reply_e8d4e804",
+	"set_ip_flow_hash_v3_b7876e07",
+	"set_ip_flow_hash_v3_reply_e8d4e804",
+	"set_ipfix_classify_stream_c9cbe053",
+	"set_ipfix_classify_stream_reply_e8d4e804",
+	"set_ipfix_exporter_5530c8a0",
+	"set_ipfix_exporter_reply_e8d4e804",
+	"set_punt_47d0e347",
+	"set_punt_reply_e8d4e804",
+	"sflow_enable_disable_8499814f",
+	"sflow_enable_disable_reply_e8d4e804",
+	"sflow_header_bytes_get_51077d14",
+	"sflow_header_bytes_get_reply_624c95b9",
+	"sflow_header_bytes_set_5baf56f3",
+	"sflow_header_bytes_set_reply_e8d4e804",
+	"sflow_interface_details_b7b9143f",
+	"sflow_interface_dump_451a727d",
+	"sflow_polling_interval_get_51077d14",
+	"sflow_polling_interval_get_reply_e929801c",
+	"sflow_polling_interval_set_7f19cb51",
+	"sflow_polling_interval_set_reply_e8d4e804",
+	"sflow_sampling_rate_get_51077d14",
+	"sflow_sampling_rate_get_reply_9c8c8236",
+	"sflow_sampling_rate_set_94778f50",
+	"sflow_sampling_rate_set_reply_e8d4e804",
+	"show_lisp_map_register_state_51077d14",
+	"show_lisp_map_register_state_reply_e33a377b",
+	"show_lisp_map_request_mode_51077d14",
+	"show_lisp_map_request_mode_reply_5b05038e",
+	"show_lisp_pitr_51077d14",
+	"show_lisp_pitr_reply_27aa69b1",
+	"show_lisp_rloc_probe_state_51077d14",
+	"show_lisp_rloc_probe_state_reply_e33a377b",
+	"show_lisp_status_51077d14",
+	"show_lisp_status_reply_9e8f10c0",
+	"show_lisp_use_petr_51077d14",
+	"show_lisp_use_petr_reply_22b9a4b0",
+	"show_one_map_register_fallback_threshold_51077d14",
+	"show_one_map_register_fallback_threshold_reply_c93a9113",
+	"show_one_map_register_state_51077d14",
+	"show_one_map_register_state_reply_f15abb16",
+	"show_one_map_register_ttl_51077d14",
+	"show_one_map_register_ttl_reply_fa83dd66",
+	"show_one_map_request_mode_51077d14",
+	"show_one_map_request_mode_reply_d41f3c1d",
+	"show_one_nsh_mapping_51077d14",
+	"show_one_nsh_mapping_reply_46478c02",
+	"show_one_pitr_51077d14",
+	"show_one_pitr_reply_a2d1a49f",
+	"show_one_rloc_probe_state_51077d14",
+	"show_one_rloc_probe_state_reply_f15abb16",
+	"show_one_stats_enable_disable_51077d14",
+	"show_one_stats_enable_disable_reply_f15abb16",
+	"show_one_status_51077d14",
+	"show_one_status_reply_961bb25b",
+	"show_one_use_petr_51077d14",
+	"show_one_use_petr_reply_84a03528",
+	"show_threads_51077d14",
+	"show_threads_reply_efd78e83",
+	"show_version_51077d14",
+	"show_version_reply_c919bde1",
+	"show_vpe_system_time_51077d14",
+	"show_vpe_system_time_reply_7ffd8193",
+	"sock_init_shm_51646d92",
+	"sock_init_shm_reply_e8d4e804",
+	"sockclnt_create_455fb9c4",
+	"sockclnt_create_reply_35166268",
+	"sockclnt_delete_8ac76db6",
+	"sockclnt_delete_reply_8f38b1ee",
+	"sr_localsid_add_del_5a36c324",
+	"sr_localsid_add_del_reply_e8d4e804",
+	"sr_localsids_details_2e9221b9",
+	"sr_localsids_dump_51077d14",
+	"sr_localsids_with_packet_stats_details_ce0b1ce0",
+	"sr_localsids_with_packet_stats_dump_51077d14",
+	"sr_mobile_localsid_add_del_b85a7ed7",
+	"sr_mobile_localsid_add_del_reply_e8d4e804",
+	"sr_mobile_policy_add_8f051658",
+	"sr_mobile_policy_add_reply_e8d4e804",
+	"sr_mpls_policy_add_a1a70c70",
+	"sr_mpls_policy_add_reply_e8d4e804",
+	"sr_mpls_policy_assign_endpoint_color_0e7eb978",
+	"sr_mpls_policy_assign_endpoint_color_reply_e8d4e804",
+	"sr_mpls_policy_del_e29d34fa",
+	"sr_mpls_policy_del_reply_e8d4e804",
+	"sr_mpls_policy_mod_88482c17",
+	"sr_mpls_policy_mod_reply_e8d4e804",
+	"sr_mpls_steering_add_del_64acff63",
+	"sr_mpls_steering_add_del_reply_e8d4e804",
+	"sr_policies_details_db6ff2a1",
+	"sr_policies_dump_51077d14",
+	"sr_policies_v2_details_96dcb699",
+	"sr_policies_v2_dump_51077d14",
+	"sr_policies_with_sl_index_details_ca2e9bc8",
+	"sr_policies_with_sl_index_dump_51077d14",
+	"sr_policy_add_44ac92e8",
+	"sr_policy_add_reply_e8d4e804",
+	"sr_policy_add_v2_f6297f36",
+	"sr_policy_add_v2_reply_e8d4e804",
+	"sr_policy_del_cb4d48d5",
+	"sr_policy_del_reply_e8d4e804",
+	"sr_policy_mod_b97bb56e",
+	"sr_policy_mod_reply_e8d4e804",
+	"sr_policy_mod_v2_c0544823",
+	"sr_policy_mod_v2_reply_e8d4e804",
+	"sr_pt_iface_add_852c0cda",
+	"sr_pt_iface_add_reply_e8d4e804",
+	"sr_pt_iface_del_f9e6675e",
+	"sr_pt_iface_del_reply_e8d4e804",
+	"sr_pt_iface_details_1f472f85",
+	"sr_pt_iface_dump_51077d14",
+	"sr_set_encap_hop_limit_aa75d7d0",
+	"sr_set_encap_hop_limit_reply_e8d4e804",
+	"sr_set_encap_source_d3bad5e1",
+	"sr_set_encap_source_reply_e8d4e804",
+	"sr_steering_add_del_e46b0a0f",
+	"sr_steering_add_del_reply_e8d4e804",
+	"sr_steering_pol_details_d41258c9",
+	"sr_steering_pol_dump_51077d14",
+	"stn_add_del_rule_224c6edd",
+	"stn_add_del_rule_reply_e8d4e804",
+	"stn_rules_details_a51935a6",
+	"stn_rules_dump_51077d14",
+	"svs_details_6282cd55",
+	"svs_dump_51077d14",
+	"svs_enable_disable_634b89d2",
+	"svs_enable_disable_reply_e8d4e804",
+	"svs_plugin_get_version_51077d14",
+	"svs_plugin_get_version_reply_9b32cf86",
+	"svs_route_add_del_e49bc63c",
+	"svs_route_add_del_reply_e8d4e804",
+	"svs_table_add_del_7d21cb2a",
+	"svs_table_add_del_reply_e8d4e804",
+	"sw_bond_interface_details_9428a69c",
+	"sw_bond_interface_dump_f9e6675e",
+	"sw_if_l2tpv3_tunnel_details_50b88993",
+	"sw_if_l2tpv3_tunnel_dump_51077d14",
+	"sw_interface_add_del_address_5463d73b",
+	"sw_interface_add_del_address_reply_e8d4e804",
+	"sw_interface_add_del_mac_address_638bb9f4",
+	"sw_interface_add_del_mac_address_reply_e8d4e804",
+	"sw_interface_address_replace_begin_51077d14",
+	"sw_interface_address_replace_begin_reply_e8d4e804",
+	"sw_interface_address_replace_end_51077d14",
+	"sw_interface_address_replace_end_reply_e8d4e804",
+	"sw_interface_bond_details_bb7c929b",
+	"sw_interface_bond_dump_51077d14",
+	"sw_interface_clear_stats_f9e6675e",
+	"sw_interface_clear_stats_reply_e8d4e804",
+	"sw_interface_details_6c221fc7",
+	"sw_interface_dump_aa610c27",
+	"sw_interface_event_2d3d95a7",
+	"sw_interface_get_mac_address_f9e6675e",
+	"sw_interface_get_mac_address_reply_40ef2c08",
+	"sw_interface_get_table_2d033de4",
+	"sw_interface_get_table_reply_a6eb0109",
+	"sw_interface_ip4_enable_disable_ae6cfcfb",
+	"sw_interface_ip4_enable_disable_reply_e8d4e804",
+	"sw_interface_ip6_enable_disable_ae6cfcfb",
+	"sw_interface_ip6_enable_disable_reply_e8d4e804",
+	"sw_interface_ip6_get_link_local_address_f9e6675e",
+	"sw_interface_ip6_get_link_local_address_reply_d16b7130",
+	"sw_interface_ip6_set_link_local_address_1c10f15f",
+	"sw_interface_ip6_set_link_local_address_reply_e8d4e804",
+	"sw_interface_ip6nd_ra_config_3eb00b1c",
+	"sw_interface_ip6nd_ra_config_reply_e8d4e804",
+	"sw_interface_ip6nd_ra_details_d3198de5",
+	"sw_interface_ip6nd_ra_dump_f9e6675e",
+	"sw_interface_ip6nd_ra_prefix_82cc1b28",
+	"sw_interface_ip6nd_ra_prefix_reply_e8d4e804",
+	"sw_interface_lacp_details_d9a83d2f",
+	"sw_interface_lacp_dump_51077d14",
+	"sw_interface_rx_placement_details_9e44a7ce",
+	"sw_interface_rx_placement_dump_f9e6675e",
+	"sw_interface_set_bond_weight_deb510a0",
+	"sw_interface_set_bond_weight_reply_e8d4e804",
+	"sw_interface_set_flags_f5aec1b8",
+	"sw_interface_set_flags_reply_e8d4e804",
+	"sw_interface_set_geneve_bypass_65247409",
+	"sw_interface_set_geneve_bypass_reply_e8d4e804",
+	"sw_interface_set_gtpu_bypass_65247409",
+	"sw_interface_set_gtpu_bypass_reply_e8d4e804",
+	"sw_interface_set_interface_name_45a1d548",
+	"sw_interface_set_interface_name_reply_e8d4e804",
+	"sw_interface_set_ip_directed_broadcast_ae6cfcfb",
+	"sw_interface_set_ip_directed_broadcast_reply_e8d4e804",
+	"sw_interface_set_l2_bridge_d0678b13",
+	"sw_interface_set_l2_bridge_reply_e8d4e804",
+	"sw_interface_set_l2_xconnect_4fa28a85",
+	"sw_interface_set_l2_xconnect_reply_e8d4e804",
+	"sw_interface_set_lldp_57afbcd4",
+	"sw_interface_set_lldp_reply_e8d4e804",
+	"sw_interface_set_mac_address_c536e7eb",
+	"sw_interface_set_mac_address_reply_e8d4e804",
+	"sw_interface_set_mpls_enable_ae6cfcfb",
+	"sw_interface_set_mpls_enable_reply_e8d4e804",
+	"sw_interface_set_mtu_5cbe85e5",
+	"sw_interface_set_mtu_reply_e8d4e804",
+	"sw_interface_set_promisc_d40860d4",
+	"sw_interface_set_promisc_reply_e8d4e804",
+	"sw_interface_set_rx_mode_b04d1cfe",
+	"sw_interface_set_rx_mode_reply_e8d4e804",
+	"sw_interface_set_rx_placement_db65f3c9",
+	"sw_interface_set_rx_placement_reply_e8d4e804",
+	"sw_interface_set_table_df42a577",
+	"sw_interface_set_table_reply_e8d4e804",
+	"sw_interface_set_tx_placement_4e0cd5ff",
+	"sw_interface_set_tx_placement_reply_e8d4e804",
+	"sw_interface_set_unnumbered_154a6439",
+	"sw_interface_set_unnumbered_reply_e8d4e804",
+	"sw_interface_set_vpath_ae6cfcfb",
+	"sw_interface_set_vpath_reply_e8d4e804",
+	"sw_interface_set_vxlan_bypass_65247409",
+	"sw_interface_set_vxlan_bypass_reply_e8d4e804",
+	"sw_interface_set_vxlan_gpe_bypass_65247409",
+	"sw_interface_set_vxlan_gpe_bypass_reply_e8d4e804",
+	"sw_interface_slave_details_3c4a0e23",
+	"sw_interface_slave_dump_f9e6675e",
+	"sw_interface_span_details_8a20e79f",
+	"sw_interface_span_dump_d6cf0c3d",
+	"sw_interface_span_enable_disable_23ddd96b",
+	"sw_interface_span_enable_disable_reply_e8d4e804",
+	"sw_interface_tag_add_del_426f8bc1",
+	"sw_interface_tag_add_del_reply_e8d4e804",
+	"sw_interface_tap_v2_details_1e2b2a47",
+	"sw_interface_tap_v2_dump_f9e6675e",
+	"sw_interface_tx_placement_details_00381a2e",
+	"sw_interface_tx_placement_get_47250981",
+	"sw_interface_tx_placement_get_reply_53b48f5d",
+	"sw_interface_vhost_user_details_0cee1e53",
+	"sw_interface_vhost_user_dump_f9e6675e",
+	"sw_interface_virtio_pci_details_6ca9c167",
+	"sw_interface_virtio_pci_dump_51077d14",
+	"sw_member_interface_details_3c4a0e23",
+	"sw_member_interface_dump_f9e6675e",
+	"sw_vmxnet3_interface_details_6a1a5498",
+	"sw_vmxnet3_interface_dump_f9e6675e",
+	"syslog_get_filter_51077d14",
+	"syslog_get_filter_reply_eb1833f8",
+	"syslog_get_sender_51077d14",
+	"syslog_get_sender_reply_424cfa4e",
+	"syslog_set_filter_571348c3",
+	"syslog_set_filter_reply_e8d4e804",
+	"syslog_set_sender_b8011d0b",
+	"syslog_set_sender_reply_e8d4e804",
+	"tap_create_v2_2d0d6570",
+	"tap_create_v2_reply_5383d31f",
+	"tap_create_v3_3f3fd1df",
+	"tap_create_v3_reply_5383d31f",
+	"tap_delete_v2_f9e6675e",
+	"tap_delete_v2_reply_e8d4e804",
+	"tcp_configure_src_addresses_67eede0d",
+	"tcp_configure_src_addresses_reply_e8d4e804",
+	"teib_details_981ee1a1",
+	"teib_dump_51077d14",
+	"teib_entry_add_del_8016cfd2",
+	"teib_entry_add_del_reply_e8d4e804",
+	"tls_openssl_set_engine_e34d95c1",
+	"tls_openssl_set_engine_reply_e8d4e804",
+	"trace_plugin_msg_ids_f476d3ce",
+	"trace_profile_add_de08aa6d",
+	"trace_profile_add_reply_e8d4e804",
+	"trace_profile_del_51077d14",
+	"trace_profile_del_reply_e8d4e804",
+	"trace_profile_show_config_51077d14",
+	"trace_profile_show_config_reply_0f1d374c",
+	"udp_decap_add_del_d14a4f47",
+	"udp_decap_add_del_reply_e8d4e804",
+	"udp_encap_add_f74a60b1",
+	"udp_encap_add_reply_e2fc8294",
+	"udp_encap_del_3a91bde5",
+	"udp_encap_del_reply_e8d4e804",
+	"udp_encap_details_8cfb9c76",
+	"udp_encap_dump_51077d14",
+	"udp_ping_add_del_fa2628fc",
+	"udp_ping_add_del_reply_e8d4e804",
+	"udp_ping_export_b3e225d2",
+	"udp_ping_export_reply_e8d4e804",
+	"urpf_interface_details_f94b5374",
+	"urpf_interface_dump_f9e6675e",
+	"urpf_update_cc274cd1",
+	"urpf_update_reply_e8d4e804",
+	"urpf_update_v2_b873d028",
+	"urpf_update_v2_reply_e8d4e804",
+	"virtio_pci_create_1944f8db",
+	"virtio_pci_create_reply_5383d31f",
+	"virtio_pci_create_v2_5d096e1a",
+	"virtio_pci_create_v2_reply_5383d31f",
+	"virtio_pci_delete_f9e6675e",
+	"virtio_pci_delete_reply_e8d4e804",
+	"vmxnet3_create_71a07314",
+	"vmxnet3_create_reply_5383d31f",
+	"vmxnet3_delete_f9e6675e",
+	"vmxnet3_delete_reply_e8d4e804",
+	"vmxnet3_details_6a1a5498",
+	"vmxnet3_dump_51077d14",
+	"vrrp_vr_add_del_c5cf15aa",
+	"vrrp_vr_add_del_reply_e8d4e804",
+	"vrrp_vr_del_6029baa1",
+	"vrrp_vr_del_reply_e8d4e804",
+	"vrrp_vr_details_46edcebd",
+	"vrrp_vr_dump_f9e6675e",
+	"vrrp_vr_event_c1fea6a5",
+	"vrrp_vr_peer_details_3d99c108",
+	"vrrp_vr_peer_dump_6fa3f7c4",
+	"vrrp_vr_set_peers_20bec71f",
+	"vrrp_vr_set_peers_reply_e8d4e804",
+	"vrrp_vr_start_stop_0662a3b7",
+	"vrrp_vr_start_stop_reply_e8d4e804",
+	"vrrp_vr_track_if_add_del_d67df299",
+	"vrrp_vr_track_if_add_del_reply_e8d4e804",
+	"vrrp_vr_track_if_details_73c36f81",
+	"vrrp_vr_track_if_dump_a34dfc6d",
+	"vrrp_vr_update_0b51e2f4",
+	"vrrp_vr_update_reply_5317d608",
+	"vxlan_add_del_tunnel_0c09dc80",
+	"vxlan_add_del_tunnel_reply_5383d31f",
+	"vxlan_add_del_tunnel_v2_4f223f40",
+	"vxlan_add_del_tunnel_v2_reply_5383d31f",
+	"vxlan_add_del_tunnel_v3_0072b037",
+	"vxlan_add_del_tunnel_v3_reply_5383d31f",
+	"vxlan_gpe_add_del_tunnel_a645b2b0",
+	"vxlan_gpe_add_del_tunnel_reply_5383d31f",
+	"vxlan_gpe_add_del_tunnel_v2_d62fdb35",
+	"vxlan_gpe_add_del_tunnel_v2_reply_5383d31f",
+	"vxlan_gpe_ioam_disable_6b16a45e",
+	"vxlan_gpe_ioam_disable_reply_e8d4e804",
+	"vxlan_gpe_ioam_enable_2481bef7",
+	"vxlan_gpe_ioam_enable_reply_e8d4e804",
+	"vxlan_gpe_ioam_export_enable_disable_d4c76d3a",
+	"vxlan_gpe_ioam_export_enable_disable_reply_e8d4e804",
+	"vxlan_gpe_ioam_transit_disable_3d3ec657",
+	"vxlan_gpe_ioam_transit_disable_reply_e8d4e804",
+	"vxlan_gpe_ioam_transit_enable_3d3ec657",
+	"vxlan_gpe_ioam_transit_enable_reply_e8d4e804",
+	"vxlan_gpe_ioam_vni_disable_0fbb5fb1",
+	"vxlan_gpe_ioam_vni_disable_reply_e8d4e804",
+	"vxlan_gpe_ioam_vni_enable_0fbb5fb1",
+	"vxlan_gpe_ioam_vni_enable_reply_e8d4e804",
+	"vxlan_gpe_tunnel_details_0968fc8b",
+	"vxlan_gpe_tunnel_dump_f9e6675e",
+	"vxlan_gpe_tunnel_v2_details_06be4870",
+	"vxlan_gpe_tunnel_v2_dump_f9e6675e",
+	"vxlan_offload_rx_9cc95087",
+	"vxlan_offload_rx_reply_e8d4e804",
+	"vxlan_tunnel_details_c3916cb1",
+	"vxlan_tunnel_dump_f9e6675e",
+	"vxlan_tunnel_v2_details_d3bdd4d9",
+	"vxlan_tunnel_v2_dump_f9e6675e",
+	"want_bfd_events_c5e2af94",
+	"want_bfd_events_reply_e8d4e804",
+	"want_dhcp6_pd_reply_events_c5e2af94",
+	"want_dhcp6_pd_reply_events_reply_e8d4e804",
+	"want_dhcp6_reply_events_05b454b5",
+	"want_dhcp6_reply_events_reply_e8d4e804",
+	"want_igmp_events_cfaccc1f",
+	"want_igmp_events_reply_e8d4e804",
+	"want_interface_events_476f5a08",
+	"want_interface_events_reply_e8d4e804",
+	"want_ip6_ra_events_3ec6d6c2",
+	"want_ip6_ra_events_reply_e8d4e804",
+	"want_ip_neighbor_events_73e70a86",
+	"want_ip_neighbor_events_reply_e8d4e804",
+	"want_ip_neighbor_events_v2_73e70a86",
+	"want_ip_neighbor_events_v2_reply_e8d4e804",
+	"want_l2_arp_term_events_3ec6d6c2",
+	"want_l2_arp_term_events_reply_e8d4e804",
+	"want_l2_macs_events2_cc1377b0",
+	"want_l2_macs_events2_reply_e8d4e804",
+	"want_l2_macs_events_9aabdfde",
+	"want_l2_macs_events_reply_e8d4e804",
+	"want_ping_finished_events_e79ee58b",
+	"want_ping_finished_events_reply_e8d4e804",
+	"want_vrrp_vr_events_c5e2af94",
+	"want_vrrp_vr_events_reply_e8d4e804",
+	"want_wireguard_peer_events_3bc666c8",
+	"want_wireguard_peer_events_reply_e8d4e804",
+	"wg_set_async_mode_a6465f7c",
+	"wg_set_async_mode_reply_e8d4e804",
+	"wireguard_interface_create_a530137e",
+	"wireguard_interface_create_reply_5383d31f",
+	"wireguard_interface_delete_f9e6675e",
+	"wireguard_interface_delete_reply_e8d4e804",
+	"wireguard_interface_details_0dd4865d",
+	"wireguard_interface_dump_2c954158",
+	"wireguard_peer_add_9b8aad61",
+	"wireguard_peer_add_reply_084a0cd3",
+	"wireguard_peer_event_4e1b5d67",
+	"wireguard_peer_remove_3b74607a",
+	"wireguard_peer_remove_reply_e8d4e804",
+	"wireguard_peers_details_6a9f6bc3",
+	"wireguard_peers_dump_3b74607a",
+];
+
+/// Calls `visitor` with the type of the message that has the given name and CRC.
+///
+/// Returns `None` if the message is not part of these bindings.
+pub fn visit_message<V: vpp_api_message::VppApiMessageVisitor>(name_crc: &str, visitor: V) -> Option<V::Output> {
+	let output = match name_crc {
+		"sr_localsid_add_del_5a36c324" => visitor.visit::<sr::SrLocalsidAddDel>(),
+		"sr_localsid_add_del_reply_e8d4e804" => visitor.visit::<sr::SrLocalsidAddDelReply>(),
+		"sr_policy_add_44ac92e8" => visitor.visit::<sr::SrPolicyAdd>(),
+		"sr_policy_add_reply_e8d4e804" => visitor.visit::<sr::SrPolicyAddReply>(),
+		"sr_policy_mod_b97bb56e" => visitor.visit::<sr::SrPolicyMod>(),
+		"sr_policy_mod_reply_e8d4e804" => visitor.visit::<sr::SrPolicyModReply>(),
+		"sr_policy_add_v2_f6297f36" => visitor.visit::<sr::SrPolicyAddV2>(),
+		"sr_policy_add_v2_reply_e8d4e804" => visitor.visit::<sr::SrPolicyAddV2Reply>(),
+		"sr_policy_mod_v2_c0544823" => visitor.visit::<sr::SrPolicyModV2>(),
+		"sr_policy_mod_v2_reply_e8d4e804" => visitor.visit::<sr::SrPolicyModV2Reply>(),
+		"sr_policy_del_cb4d48d5" => visitor.visit::<sr::SrPolicyDel>(),
+		"sr_policy_del_reply_e8d4e804" => visitor.visit::<sr::SrPolicyDelReply>(),
+		"sr_set_encap_source_d3bad5e1" => visitor.visit::<sr::SrSetEncapSource>(),
+		"sr_set_encap_source_reply_e8d4e804" => visitor.visit::<sr::SrSetEncapSourceReply>(),
+		"sr_set_encap_hop_limit_aa75d7d0" => visitor.visit::<sr::SrSetEncapHopLimit>(),
+		"sr_set_encap_hop_limit_reply_e8d4e804" => visitor.visit::<sr::SrSetEncapHopLimitReply>(),
+		"sr_steering_add_del_e46b0a0f" => visitor.visit::<sr::SrSteeringAddDel>(),
+		"sr_steering_add_del_reply_e8d4e804" => visitor.visit::<sr::SrSteeringAddDelReply>(),
+		"sr_localsids_dump_51077d14" => visitor.visit::<sr::SrLocalsidsDump>(),
+		"sr_localsids_details_2e9221b9" => visitor.visit::<sr::SrLocalsidsDetails>(),
+		"sr_localsids_with_packet_stats_dump_51077d14" => visitor.visit::<sr::SrLocalsidsWithPacketStatsDump>(),
+		"sr_localsids_with_packet_stats_details_ce0b1ce0" => visitor.visit::<sr::SrLocalsidsWithPacketStatsDetails>(),
+		"sr_policies_dump_51077d14" => visitor.visit::<sr::SrPoliciesDump>(),
+		"sr_policies_details_db6ff2a1" => visitor.visit::<sr::SrPoliciesDetails>(),
+		"sr_policies_v2_dump_51077d14" => visitor.visit::<sr::SrPoliciesV2Dump>(),
+		"sr_policies_v2_details_96dcb699" => visitor.visit::<sr::SrPoliciesV2Details>(),
+		"sr_policies_with_sl_index_dump_51077d14" => visitor.visit::<sr::SrPoliciesWithSlIndexDump>(),
+		"sr_policies_with_sl_index_details_ca2e9bc8" => visitor.visit::<sr::SrPoliciesWithSlIndexDetails>(),
+		"sr_steering_pol_dump_51077d14" => visitor.visit::<sr::SrSteeringPolDump>(),
+		"sr_steering_pol_details_d41258c9" => visitor.visit::<sr::SrSteeringPolDetails>(),
+		"feature_gso_enable_disable_5501adee" => visitor.visit::<gso::FeatureGsoEnableDisable>(),
+		"feature_gso_enable_disable_reply_e8d4e804" => visitor.visit::<gso::FeatureGsoEnableDisableReply>(),
+		"l2_xconnect_details_472b6b67" => visitor.visit::<l2::L2XconnectDetails>(),
+		"l2_xconnect_dump_51077d14" => visitor.visit::<l2::L2XconnectDump>(),
+		"l2_fib_table_details_a44ef6b8" => visitor.visit::<l2::L2FibTableDetails>(),
+		"l2_fib_table_dump_c25fdce6" => visitor.visit::<l2::L2FibTableDump>(),
+		"l2_fib_clear_table_51077d14" => visitor.visit::<l2::L2FibClearTable>(),
+		"l2_fib_clear_table_reply_e8d4e804" => visitor.visit::<l2::L2FibClearTableReply>(),
+		"l2fib_flush_all_51077d14" => visitor.visit::<l2::L2fibFlushAll>(),
+		"l2fib_flush_all_reply_e8d4e804" => visitor.visit::<l2::L2fibFlushAllReply>(),
+		"l2fib_flush_bd_c25fdce6" => visitor.visit::<l2::L2fibFlushBd>(),
+		"l2fib_flush_bd_reply_e8d4e804" => visitor.visit::<l2::L2fibFlushBdReply>(),
+		"l2fib_flush_int_f9e6675e" => visitor.visit::<l2::L2fibFlushInt>(),
+		"l2fib_flush_int_reply_e8d4e804" => visitor.visit::<l2::L2fibFlushIntReply>(),
+		"l2fib_add_del_eddda487" => visitor.visit::<l2::L2fibAddDel>(),
+		"l2fib_add_del_reply_e8d4e804" => visitor.visit::<l2::L2fibAddDelReply>(),
+		"want_l2_macs_events_9aabdfde" => visitor.visit::<l2::WantL2MacsEvents>(),
+		"want_l2_macs_events_reply_e8d4e804" => visitor.visit::<l2::WantL2MacsEventsReply>(),
+		"want_l2_macs_events2_cc1377b0" => visitor.visit::<l2::WantL2MacsEvents2>(),
+		"want_l2_macs_events2_reply_e8d4e804" => visitor.visit::<l2::WantL2MacsEvents2Reply>(),
+		"l2fib_set_scan_delay_a3b968a4" => visitor.visit::<l2::L2fibSetScanDelay>(),
+		"l2fib_set_scan_delay_reply_e8d4e804" => visitor.visit::<l2::L2fibSetScanDelayReply>(),
+		"l2_macs_event_44b8fd64" => visitor.visit::<l2::L2MacsEvent>(),
+		"l2_flags_fc41cfe8" => visitor.visit::<l2::L2Flags>(),
+		"l2_flags_reply_29b2a2b3" => visitor.visit::<l2::L2FlagsReply>(),
+		"bridge_domain_set_mac_age_b537ad7b" => visitor.visit::<l2::BridgeDomainSetMacAge>(),
+		"bridge_domain_set_mac_age_reply_e8d4e804" => visitor.visit::<l2::BridgeDomainSetMacAgeReply>(),
+		"bridge_domain_set_default_learn_limit_f097ffce" => visitor.visit::<l2::BridgeDomainSetDefaultLearnLimit>(),
+		"bridge_domain_set_default_learn_limit_reply_e8d4e804" => visitor.visit::<l2::BridgeDomainSetDefaultLearnLimitReply>(),
+		"bridge_domain_set_learn_limit_89c52b5f" => visitor.visit::<l2::BridgeDomainSetLearnLimit>(),
+		"bridge_domain_set_learn_limit_reply_e8d4e804" => visitor.visit::<l2::BridgeDomainSetLearnLimitReply>(),
+		"bridge_domain_add_del_600b7170" => visitor.visit::<l2::BridgeDomainAddDel>(),
+		"bridge_domain_add_del_reply_e8d4e804" => visitor.visit::<l2::BridgeDomainAddDelReply>(),
+		"bridge_domain_add_del_v2_600b7170" => visitor.visit::<l2::BridgeDomainAddDelV2>(),
+		"bridge_domain_add_del_v2_reply_fcb1e980" => visitor.visit::<l2::BridgeDomainAddDelV2Reply>(),
+		"bridge_domain_dump_74396a43" => visitor.visit::<l2::BridgeDomainDump>(),
+		"bridge_domain_details_0fa506fd" => visitor.visit::<l2::BridgeDomainDetails>(),
+		"bridge_flags_1b0c5fbd" => visitor.visit::<l2::BridgeFlags>(),
+		"bridge_flags_reply_29b2a2b3" => visitor.visit::<l2::BridgeFlagsReply>(),
+		"l2_interface_vlan_tag_rewrite_62cc0bbc" => visitor.visit::<l2::L2InterfaceVlanTagRewrite>(),
+		"l2_interface_vlan_tag_rewrite_reply_e8d4e804" => visitor.visit::<l2::L2InterfaceVlanTagRewriteReply>(),
+		"l2_interface_pbb_tag_rewrite_38e802a8" => visitor.visit::<l2::L2InterfacePbbTagRewrite>(),
+		"l2_interface_pbb_tag_rewrite_reply_e8d4e804" => visitor.visit::<l2::L2InterfacePbbTagRewriteReply>(),
+		"l2_patch_add_del_a1f6a6f3" => visitor.visit::<l2::L2PatchAddDel>(),
+		"l2_patch_add_del_reply_e8d4e804" => visitor.visit::<l2::L2PatchAddDelReply>(),
+		"sw_interface_set_l2_xconnect_4fa28a85" => visitor.visit::<l2::SwInterfaceSetL2Xconnect>(),
+		"sw_interface_set_l2_xconnect_reply_e8d4e804" => visitor.visit::<l2::SwInterfaceSetL2XconnectReply>(),
+		"sw_interface_set_l2_bridge_d0678b13" => visitor.visit::<l2::SwInterfaceSetL2Bridge>(),
+		"sw_interface_set_l2_bridge_reply_e8d4e804" => visitor.visit::<l2::SwInterfaceSetL2BridgeReply>(),
+		"bd_ip_mac_add_del_0257c869" => visitor.visit::<l2::BdIpMacAddDel>(),
+		"bd_ip_mac_add_del_reply_e8d4e804" => visitor.visit::<l2::BdIpMacAddDelReply>(),
+		"bd_ip_mac_flush_c25fdce6" => visitor.visit::<l2::BdIpMacFlush>(),
+		"bd_ip_mac_flush_reply_e8d4e804" => visitor.visit::<l2::BdIpMacFlushReply>(),
+		"bd_ip_mac_details_545af86a" => visitor.visit::<l2::BdIpMacDetails>(),
+		"bd_ip_mac_dump_c25fdce6" => visitor.visit::<l2::BdIpMacDump>(),
+		"l2_interface_efp_filter_5501adee" => visitor.visit::<l2::L2InterfaceEfpFilter>(),
+		"l2_interface_efp_filter_reply_e8d4e804" => visitor.visit::<l2::L2InterfaceEfpFilterReply>(),
+		"sw_interface_set_vpath_ae6cfcfb" => visitor.visit::<l2::SwInterfaceSetVpath>(),
+		"sw_interface_set_vpath_reply_e8d4e804" => visitor.visit::<l2::SwInterfaceSetVpathReply>(),
+		"bvi_create_f5398559" => visitor.visit::<l2::BviCreate>(),
+		"bvi_create_reply_5383d31f" => visitor.visit::<l2::BviCreateReply>(),
+		"bvi_delete_f9e6675e" => visitor.visit::<l2::BviDelete>(),
+		"bvi_delete_reply_e8d4e804" => visitor.visit::<l2::BviDeleteReply>(),
+		"want_l2_arp_term_events_3ec6d6c2" => visitor.visit::<l2::WantL2ArpTermEvents>(),
+		"want_l2_arp_term_events_reply_e8d4e804" => visitor.visit::<l2::WantL2ArpTermEventsReply>(),
+		"l2_arp_term_event_6963e07a" => visitor.visit::<l2::L2ArpTermEvent>(),
+		"ipip_add_tunnel_2ac399f5" => visitor.visit::<ipip::IpipAddTunnel>(),
+		"ipip_add_tunnel_reply_5383d31f" => visitor.visit::<ipip::IpipAddTunnelReply>(),
+		"ipip_del_tunnel_f9e6675e" => visitor.visit::<ipip::IpipDelTunnel>(),
+		"ipip_del_tunnel_reply_e8d4e804" => visitor.visit::<ipip::IpipDelTunnelReply>(),
+		"ipip_6rd_add_tunnel_b9ec1863" => visitor.visit::<ipip::Ipip6rdAddTunnel>(),
+		"ipip_6rd_add_tunnel_reply_5383d31f" => visitor.visit::<ipip::Ipip6rdAddTunnelReply>(),
+		"ipip_6rd_del_tunnel_f9e6675e" => visitor.visit::<ipip::Ipip6rdDelTunnel>(),
+		"ipip_6rd_del_tunnel_reply_e8d4e804" => visitor.visit::<ipip::Ipip6rdDelTunnelReply>(),
+		"ipip_tunnel_dump_f9e6675e" => visitor.visit::<ipip::IpipTunnelDump>(),
+		"ipip_tunnel_details_d31cb34e" => visitor.visit::<ipip::IpipTunnelDetails>(),
+		"crypto_set_async_dispatch_5ca4adc0" => visitor.visit::<crypto::CryptoSetAsyncDispatch>(),
+		"crypto_set_async_dispatch_reply_e8d4e804" => visitor.visit::<crypto::CryptoSetAsyncDispatchReply>(),
+		"crypto_set_async_dispatch_v2_667d2d54" => visitor.visit::<crypto::CryptoSetAsyncDispatchV2>(),
+		"crypto_set_async_dispatch_v2_reply_e8d4e804" => visitor.visit::<crypto::CryptoSetAsyncDispatchV2Reply>(),
+		"crypto_set_handler_ce9ad00d" => visitor.visit::<crypto::CryptoSetHandler>(),
+		"crypto_set_handler_reply_e8d4e804" => visitor.visit::<crypto::CryptoSetHandlerReply>(),
+		"proxy_arp_add_del_1823c3e7" => visitor.visit::<arp::ProxyArpAddDel>(),
+		"proxy_arp_add_del_reply_e8d4e804" => visitor.visit::<arp::ProxyArpAddDelReply>(),
+		"proxy_arp_dump_51077d14" => visitor.visit::<arp::ProxyArpDump>(),
+		"proxy_arp_details_5b948673" => visitor.visit::<arp::ProxyArpDetails>(),
+		"proxy_arp_intfc_enable_disable_ae6cfcfb" => visitor.visit::<arp::ProxyArpIntfcEnableDisable>(),
+		"proxy_arp_intfc_enable_disable_reply_e8d4e804" => visitor.visit::<arp::ProxyArpIntfcEnableDisableReply>(),
+		"proxy_arp_intfc_dump_51077d14" => visitor.visit::<arp::ProxyArpIntfcDump>(),
+		"proxy_arp_intfc_details_f6458e5f" => visitor.visit::<arp::ProxyArpIntfcDetails>(),
+		"feature_enable_disable_7531c862" => visitor.visit::<feature::FeatureEnableDisable>(),
+		"feature_enable_disable_reply_e8d4e804" => visitor.visit::<feature::FeatureEnableDisableReply>(),
+		"feature_is_enabled_55db09e2" => visitor.visit::<feature::FeatureIsEnabled>(),
+		"feature_is_enabled_reply_03f284b5" => visitor.visit::<feature::FeatureIsEnabledReply>(),
+		"app_attach_5f4a260d" => visitor.visit::<session::AppAttach>(),
+		"app_attach_reply_5c89c3b0" => visitor.visit::<session::AppAttachReply>(),
+		"application_detach_51077d14" => visitor.visit::<session::ApplicationDetach>(),
+		"application_detach_reply_e8d4e804" => visitor.visit::<session::ApplicationDetachReply>(),
+		"app_add_cert_key_pair_02eb8016" => visitor.visit::<session::AppAddCertKeyPair>(),
+		"app_add_cert_key_pair_reply_b42958d0" => visitor.visit::<session::AppAddCertKeyPairReply>(),
+		"app_del_cert_key_pair_8ac76db6" => visitor.visit::<session::AppDelCertKeyPair>(),
+		"app_del_cert_key_pair_reply_e8d4e804" => visitor.visit::<session::AppDelCertKeyPairReply>(),
+		"app_worker_add_del_753253dc" => visitor.visit::<session::AppWorkerAddDel>(),
+		"app_worker_add_del_reply_5735ffe7" => visitor.visit::<session::AppWorkerAddDelReply>(),
+		"session_enable_disable_c264d7bf" => visitor.visit::<session::SessionEnableDisable>(),
+		"session_enable_disable_reply_e8d4e804" => visitor.visit::<session::SessionEnableDisableReply>(),
+		"session_enable_disable_v2_f09fbf32" => visitor.visit::<session::SessionEnableDisableV2>(),
+		"session_enable_disable_v2_reply_e8d4e804" => visitor.visit::<session::SessionEnableDisableV2Reply>(),
+		"session_sapi_enable_disable_c264d7bf" => visitor.visit::<session::SessionSapiEnableDisable>(),
+		"session_sapi_enable_disable_reply_e8d4e804" => visitor.visit::<session::SessionSapiEnableDisableReply>(),
+		"app_namespace_add_del_6306aecb" => visitor.visit::<session::AppNamespaceAddDel>(),
+		"app_namespace_add_del_v4_42c1d824" => visitor.visit::<session::AppNamespaceAddDelV4>(),
+		"app_namespace_add_del_v4_reply_85137120" => visitor.visit::<session::AppNamespaceAddDelV4Reply>(),
+		"app_namespace_add_del_v2_ee0755cf" => visitor.visit::<session::AppNamespaceAddDelV2>(),
+		"app_namespace_add_del_v3_8a7e40a1" => visitor.visit::<session::AppNamespaceAddDelV3>(),
+		"app_namespace_add_del_reply_85137120" => visitor.visit::<session::AppNamespaceAddDelReply>(),
+		"app_namespace_add_del_v2_reply_85137120" => visitor.visit::<session::AppNamespaceAddDelV2Reply>(),
+		"app_namespace_add_del_v3_reply_85137120" => visitor.visit::<session::AppNamespaceAddDelV3Reply>(),
+		"session_rule_add_del_82a90af5" => visitor.visit::<session::SessionRuleAddDel>(),
+		"session_rule_add_del_reply_e8d4e804" => visitor.visit::<session::SessionRuleAddDelReply>(),
+		"session_rules_dump_51077d14" => visitor.visit::<session::SessionRulesDump>(),
+		"session_rules_details_4ef746e7" => visitor.visit::<session::SessionRulesDetails>(),
+		"session_rules_v2_dump_51077d14" => visitor.visit::<session::SessionRulesV2Dump>(),
+		"session_rules_v2_details_f91993dc" => visitor.visit::<session::SessionRulesV2Details>(),
+		"session_sdl_add_del_faeb89fc" => visitor.visit::<session::SessionSdlAddDel>(),
+		"session_sdl_add_del_reply_e8d4e804" => visitor.visit::<session::SessionSdlAddDelReply>(),
+		"session_sdl_add_del_v2_7f89d3fa" => visitor.visit::<session::SessionSdlAddDelV2>(),
+		"session_sdl_add_del_v2_reply_e8d4e804" => visitor.visit::<session::SessionSdlAddDelV2Reply>(),
+		"session_sdl_dump_51077d14" => visitor.visit::<session::SessionSdlDump>(),
+		"session_sdl_details_9a8ef5d0" => visitor.visit::<session::SessionSdlDetails>(),
+		"session_sdl_v2_dump_51077d14" => visitor.visit::<session::SessionSdlV2Dump>(),
+		"session_sdl_v2_details_0a057683" => visitor.visit::<session::SessionSdlV2Details>(),
+		"session_sdl_v3_dump_51077d14" => visitor.visit::<session::SessionSdlV3Dump>(),
+		"session_sdl_v3_details_829e367f" => visitor.visit::<session::SessionSdlV3Details>(),
+		"sw_interface_ip6nd_ra_config_3eb00b1c" => visitor.visit::<ip6_nd::SwInterfaceIp6ndRaConfig>(),
+		"sw_interface_ip6nd_ra_config_reply_e8d4e804" => visitor.visit::<ip6_nd::SwInterfaceIp6ndRaConfigReply>(),
+		"sw_interface_ip6nd_ra_prefix_82cc1b28" => visitor.visit::<ip6_nd::SwInterfaceIp6ndRaPrefix>(),
+		"sw_interface_ip6nd_ra_prefix_reply_e8d4e804" => visitor.visit::<ip6_nd::SwInterfaceIp6ndRaPrefixReply>(),
+		"sw_interface_ip6nd_ra_dump_f9e6675e" => visitor.visit::<ip6_nd::SwInterfaceIp6ndRaDump>(),
+		"sw_interface_ip6nd_ra_details_d3198de5" => visitor.visit::<ip6_nd::SwInterfaceIp6ndRaDetails>(),
+		"ip6nd_proxy_enable_disable_7daa1e3a" => visitor.visit::<ip6_nd::Ip6ndProxyEnableDisable>(),
+		"ip6nd_proxy_enable_disable_reply_e8d4e804" => visitor.visit::<ip6_nd::Ip6ndProxyEnableDisableReply>(),
+		"ip6nd_proxy_add_del_c2e4a686" => visitor.visit::<ip6_nd::Ip6ndProxyAddDel>(),
+		"ip6nd_proxy_add_del_reply_e8d4e804" => visitor.visit::<ip6_nd::Ip6ndProxyAddDelReply>(),
+		"ip6nd_proxy_details_30b9ff4a" => visitor.visit::<ip6_nd::Ip6ndProxyDetails>(),
+		"ip6nd_proxy_dump_51077d14" => visitor.visit::<ip6_nd::Ip6ndProxyDump>(),
+		"ip6nd_send_router_solicitation_e5de609c" => visitor.visit::<ip6_nd::Ip6ndSendRouterSolicitation>(),
+		"ip6nd_send_router_solicitation_reply_e8d4e804" => visitor.visit::<ip6_nd::Ip6ndSendRouterSolicitationReply>(),
+		"want_ip6_ra_events_3ec6d6c2" => visitor.visit::<ip6_nd::WantIp6RaEvents>(),
+		"want_ip6_ra_events_reply_e8d4e804" => visitor.visit::<ip6_nd::WantIp6RaEventsReply>(),
+		"ip6_ra_event_0364c1c5" => visitor.visit::<ip6_nd::Ip6RaEvent>(),
+		"set_ipfix_exporter_5530c8a0" => visitor.visit::<ipfix_export::SetIpfixExporter>(),
+		"set_ipfix_exporter_reply_e8d4e804" => visitor.visit::<ipfix_export::SetIpfixExporterReply>(),
+		"ipfix_exporter_dump_51077d14" => visitor.visit::<ipfix_export::IpfixExporterDump>(),
+		"ipfix_exporter_details_0dedbfe4" => visitor.visit::<ipfix_export::IpfixExporterDetails>(),
+		"ipfix_exporter_create_delete_0753a768" => visitor.visit::<ipfix_export::IpfixExporterCreateDelete>(),
+		"ipfix_exporter_create_delete_reply_9ffac24b" => visitor.visit::<ipfix_export::IpfixExporterCreateDeleteReply>(),
+		"ipfix_all_exporter_get_f75ba505" => visitor.visit::<ipfix_export::IpfixAllExporterGet>(),
+		"ipfix_all_exporter_get_reply_53b48f5d" => visitor.visit::<ipfix_export::IpfixAllExporterGetReply>(),
+		"ipfix_all_exporter_details_0dedbfe4" => visitor.visit::<ipfix_export::IpfixAllExporterDetails>(),
+		"set_ipfix_classify_stream_c9cbe053" => visitor.visit::<ipfix_export::SetIpfixClassifyStream>(),
+		"set_ipfix_classify_stream_reply_e8d4e804" => visitor.visit::<ipfix_export::SetIpfixClassifyStreamReply>(),
+		"ipfix_classify_stream_dump_51077d14" => visitor.visit::<ipfix_export::IpfixClassifyStreamDump>(),
+		"ipfix_classify_stream_details_2903539d" => visitor.visit::<ipfix_export::IpfixClassifyStreamDetails>(),
+		"ipfix_classify_table_add_del_3e449bb9" => visitor.visit::<ipfix_export::IpfixClassifyTableAddDel>(),
+		"ipfix_classify_table_add_del_reply_e8d4e804" => visitor.visit::<ipfix_export::IpfixClassifyTableAddDelReply>(),
+		"ipfix_classify_table_dump_51077d14" => visitor.visit::<ipfix_export::IpfixClassifyTableDump>(),
+		"ipfix_classify_table_details_1af8c28c" => visitor.visit::<ipfix_export::IpfixClassifyTableDetails>(),
+		"ipfix_flush_51077d14" => visitor.visit::<ipfix_export::IpfixFlush>(),
+		"ipfix_flush_reply_e8d4e804" => visitor.visit::<ipfix_export::IpfixFlushReply>(),
+		"ipsec_spd_add_del_20e89a95" => visitor.visit::<ipsec::IpsecSpdAddDel>(),
+		"ipsec_spd_add_del_reply_e8d4e804" => visitor.visit::<ipsec::IpsecSpdAddDelReply>(),
+		"ipsec_interface_add_del_spd_80f80cbb" => visitor.visit::<ipsec::IpsecInterfaceAddDelSpd>(),
+		"ipsec_interface_add_del_spd_reply_e8d4e804" => visitor.visit::<ipsec::IpsecInterfaceAddDelSpdReply>(),
+		"ipsec_spd_entry_add_del_338b7411" => visitor.visit::<ipsec::IpsecSpdEntryAddDel>(),
+		"ipsec_spd_entry_add_del_v2_7bfe69fc" => visitor.visit::<ipsec::IpsecSpdEntryAddDelV2>(),
+		"ipsec_spd_entry_add_del_reply_9ffac24b" => visitor.visit::<ipsec::IpsecSpdEntryAddDelReply>(),
+		"ipsec_spd_entry_add_del_v2_reply_9ffac24b" => visitor.visit::<ipsec::IpsecSpdEntryAddDelV2Reply>(),
+		"ipsec_spds_dump_51077d14" => visitor.visit::<ipsec::IpsecSpdsDump>(),
+		"ipsec_spds_details_a04bb254" => visitor.visit::<ipsec::IpsecSpdsDetails>(),
+		"ipsec_spd_dump_afefbf7d" => visitor.visit::<ipsec::IpsecSpdDump>(),
+		"ipsec_spd_details_5813d7a2" => visitor.visit::<ipsec::IpsecSpdDetails>(),
+		"ipsec_sad_entry_add_del_ab64b5c6" => visitor.visit::<ipsec::IpsecSadEntryAddDel>(),
+		"ipsec_sad_entry_add_del_v2_aca78b27" => visitor.visit::<ipsec::IpsecSadEntryAddDelV2>(),
+		"ipsec_sad_entry_add_del_v3_c77ebd92" => visitor.visit::<ipsec::IpsecSadEntryAddDelV3>(),
+		"ipsec_sad_entry_add_50229353" => visitor.visit::<ipsec::IpsecSadEntryAdd>(),
+		"ipsec_sad_entry_add_v2_9611297a" => visitor.visit::<ipsec::IpsecSadEntryAddV2>(),
+		"ipsec_sad_entry_del_3a91bde5" => visitor.visit::<ipsec::IpsecSadEntryDel>(),
+		"ipsec_sad_entry_del_reply_e8d4e804" => visitor.visit::<ipsec::IpsecSadEntryDelReply>(),
+		"ipsec_sad_bind_0649c0d9" => visitor.visit::<ipsec::IpsecSadBind>(),
+		"ipsec_sad_bind_reply_e8d4e804" => visitor.visit::<ipsec::IpsecSadBindReply>(),
+		"ipsec_sad_unbind_2076c2f4" => visitor.visit::<ipsec::IpsecSadUnbind>(),
+		"ipsec_sad_unbind_reply_e8d4e804" => visitor.visit::<ipsec::IpsecSadUnbindReply>(),
+		"ipsec_sad_entry_update_1412af86" => visitor.visit::<ipsec::IpsecSadEntryUpdate>(),
+		"ipsec_sad_entry_update_reply_e8d4e804" => visitor.visit::<ipsec::IpsecSadEntryUpdateReply>(),
+		"ipsec_sad_entry_add_del_reply_9ffac24b" => visitor.visit::<ipsec::IpsecSadEntryAddDelReply>(),
+		"ipsec_sad_entry_add_del_v2_reply_9ffac24b" => visitor.visit::<ipsec::IpsecSadEntryAddDelV2Reply>(),
+		"ipsec_sad_entry_add_del_v3_reply_9ffac24b" => visitor.visit::<ipsec::IpsecSadEntryAddDelV3Reply>(),
+		"ipsec_sad_entry_add_reply_9ffac24b" => visitor.visit::<ipsec::IpsecSadEntryAddReply>(),
+		"ipsec_sad_entry_add_v2_reply_9ffac24b" => visitor.visit::<ipsec::IpsecSadEntryAddV2Reply>(),
+		"ipsec_tunnel_protect_update_30d5f133" => visitor.visit::<ipsec::IpsecTunnelProtectUpdate>(),
+		"ipsec_tunnel_protect_update_reply_e8d4e804" => visitor.visit::<ipsec::IpsecTunnelProtectUpdateReply>(),
+		"ipsec_tunnel_protect_del_cd239930" => visitor.visit::<ipsec::IpsecTunnelProtectDel>(),
+		"ipsec_tunnel_protect_del_reply_e8d4e804" => visitor.visit::<ipsec::IpsecTunnelProtectDelReply>(),
+		"ipsec_tunnel_protect_dump_f9e6675e" => visitor.visit::<ipsec::IpsecTunnelProtectDump>(),
+		"ipsec_tunnel_protect_details_21663a50" => visitor.visit::<ipsec::IpsecTunnelProtectDetails>(),
+		"ipsec_spd_interface_dump_8971de19" => visitor.visit::<ipsec::IpsecSpdInterfaceDump>(),
+		"ipsec_spd_interface_details_7a0bcf3e" => visitor.visit::<ipsec::IpsecSpdInterfaceDetails>(),
+		"ipsec_itf_create_6f50b3bc" => visitor.visit::<ipsec::IpsecItfCreate>(),
+		"ipsec_itf_create_reply_5383d31f" => visitor.visit::<ipsec::IpsecItfCreateReply>(),
+		"ipsec_itf_delete_f9e6675e" => visitor.visit::<ipsec::IpsecItfDelete>(),
+		"ipsec_itf_delete_reply_e8d4e804" => visitor.visit::<ipsec::IpsecItfDeleteReply>(),
+		"ipsec_itf_dump_f9e6675e" => visitor.visit::<ipsec::IpsecItfDump>(),
+		"ipsec_itf_details_548a73b8" => visitor.visit::<ipsec::IpsecItfDetails>(),
+		"ipsec_sa_dump_2076c2f4" => visitor.visit::<ipsec::IpsecSaDump>(),
+		"ipsec_sa_v2_dump_2076c2f4" => visitor.visit::<ipsec::IpsecSaV2Dump>(),
+		"ipsec_sa_v3_dump_2076c2f4" => visitor.visit::<ipsec::IpsecSaV3Dump>(),
+		"ipsec_sa_v4_dump_2076c2f4" => visitor.visit::<ipsec::IpsecSaV4Dump>(),
+		"ipsec_sa_v5_dump_2076c2f4" => visitor.visit::<ipsec::IpsecSaV5Dump>(),
+		"ipsec_sa_details_345d14a7" => visitor.visit::<ipsec::IpsecSaDetails>(),
+		"ipsec_sa_v2_details_e2130051" => visitor.visit::<ipsec::IpsecSaV2Details>(),
+		"ipsec_sa_v3_details_2fc991ee" => visitor.visit::<ipsec::IpsecSaV3Details>(),
+		"ipsec_sa_v4_details_87a322d7" => visitor.visit::<ipsec::IpsecSaV4Details>(),
+		"ipsec_sa_v5_details_3cfecfbd" => visitor.visit::<ipsec::IpsecSaV5Details>(),
+		"ipsec_backend_dump_51077d14" => visitor.visit::<ipsec::IpsecBackendDump>(),
+		"ipsec_backend_details_ee601c29" => visitor.visit::<ipsec::IpsecBackendDetails>(),
+		"ipsec_select_backend_5bcfd3b7" => visitor.visit::<ipsec::IpsecSelectBackend>(),
+		"ipsec_select_backend_reply_e8d4e804" => visitor.visit::<ipsec::IpsecSelectBackendReply>(),
+		"ipsec_set_async_mode_a6465f7c" => visitor.visit::<ipsec::IpsecSetAsyncMode>(),
+		"ipsec_set_async_mode_reply_e8d4e804" => visitor.visit::<ipsec::IpsecSetAsyncModeReply>(),
+		"dev_attach_44b725fc" => visitor.visit::<dev::DevAttach>(),
+		"dev_attach_reply_6082b181" => visitor.visit::<dev::DevAttachReply>(),
+		"dev_detach_afae52d6" => visitor.visit::<dev::DevDetach>(),
+		"dev_detach_reply_c8d74455" => visitor.visit::<dev::DevDetachReply>(),
+		"dev_create_port_if_dbdf06f3" => visitor.visit::<dev::DevCreatePortIf>(),
+		"dev_create_port_if_reply_243c2374" => visitor.visit::<dev::DevCreatePortIfReply>(),
+		"dev_remove_port_if_529cb13f" => visitor.visit::<dev::DevRemovePortIf>(),
+		"dev_remove_port_if_reply_c8d74455" => visitor.visit::<dev::DevRemovePortIfReply>(),
+		"ip_neighbor_add_del_0607c257" => visitor.visit::<ip_neighbor::IpNeighborAddDel>(),
+		"ip_neighbor_add_del_reply_1992deab" => visitor.visit::<ip_neighbor::IpNeighborAddDelReply>(),
+		"ip_neighbor_dump_d817a484" => visitor.visit::<ip_neighbor::IpNeighborDump>(),
+		"ip_neighbor_details_e29d79f0" => visitor.visit::<ip_neighbor::IpNeighborDetails>(),
+		"ip_neighbor_config_f4a5cf44" => visitor.visit::<ip_neighbor::IpNeighborConfig>(),
+		"ip_neighbor_config_reply_e8d4e804" => visitor.visit::<ip_neighbor::IpNeighborConfigReply>(),
+		"ip_neighbor_config_get_a5db7bf7" => visitor.visit::<ip_neighbor::IpNeighborConfigGet>(),
+		"ip_neighbor_config_get_reply_798e6fdd" => visitor.visit::<ip_neighbor::IpNeighborConfigGetReply>(),
+		"ip_neighbor_replace_begin_51077d14" => visitor.visit::<ip_neighbor::IpNeighborReplaceBegin>(),
+		"ip_neighbor_replace_begin_reply_e8d4e804" => visitor.visit::<ip_neighbor::IpNeighborReplaceBeginReply>(),
+		"ip_neighbor_replace_end_51077d14" => visitor.visit::<ip_neighbor::IpNeighborReplaceEnd>(),
+		"ip_neighbor_replace_end_reply_e8d4e804" => visitor.visit::<ip_neighbor::IpNeighborReplaceEndReply>(),
+		"ip_neighbor_flush_16aa35d2" => visitor.visit::<ip_neighbor::IpNeighborFlush>(),
+		"ip_neighbor_flush_reply_e8d4e804" => visitor.visit::<ip_neighbor::IpNeighborFlushReply>(),
+		"want_ip_neighbor_events_73e70a86" => visitor.visit::<ip_neighbor::WantIpNeighborEvents>(),
+		"want_ip_neighbor_events_reply_e8d4e804" => visitor.visit::<ip_neighbor::WantIpNeighborEventsReply>(),
+		"ip_neighbor_event_bdb092b2" => visitor.visit::<ip_neighbor::IpNeighborEvent>(),
+		"want_ip_neighbor_events_v2_73e70a86" => visitor.visit::<ip_neighbor::WantIpNeighborEventsV2>(),
+		"want_ip_neighbor_events_v2_reply_e8d4e804" => visitor.visit::<ip_neighbor::WantIpNeighborEventsV2Reply>(),
+		"ip_neighbor_event_v2_c1d53dc0" => visitor.visit::<ip_neighbor::IpNeighborEventV2>(),
+		"bfd_udp_set_echo_source_f9e6675e" => visitor.visit::<bfd::BfdUdpSetEchoSource>(),
+		"bfd_udp_set_echo_source_reply_e8d4e804" => visitor.visit::<bfd::BfdUdpSetEchoSourceReply>(),
+		"bfd_udp_del_echo_source_51077d14" => visitor.visit::<bfd::BfdUdpDelEchoSource>(),
+		"bfd_udp_del_echo_source_reply_e8d4e804" => visitor.visit::<bfd::BfdUdpDelEchoSourceReply>(),
+		"bfd_udp_get_echo_source_51077d14" => visitor.visit::<bfd::BfdUdpGetEchoSource>(),
+		"bfd_udp_get_echo_source_reply_e3d736a1" => visitor.visit::<bfd::BfdUdpGetEchoSourceReply>(),
+		"bfd_udp_add_939cd26a" => visitor.visit::<bfd::BfdUdpAdd>(),
+		"bfd_udp_add_reply_e8d4e804" => visitor.visit::<bfd::BfdUdpAddReply>(),
+		"bfd_udp_upd_939cd26a" => visitor.visit::<bfd::BfdUdpUpd>(),
+		"bfd_udp_upd_reply_1992deab" => visitor.visit::<bfd::BfdUdpUpdReply>(),
+		"bfd_udp_mod_913df085" => visitor.visit::<bfd::BfdUdpMod>(),
+		"bfd_udp_mod_reply_e8d4e804" => visitor.visit::<bfd::BfdUdpModReply>(),
+		"bfd_udp_del_dcb13a89" => visitor.visit::<bfd::BfdUdpDel>(),
+		"bfd_udp_del_reply_e8d4e804" => visitor.visit::<bfd::BfdUdpDelReply>(),
+		"bfd_udp_session_dump_51077d14" => visitor.visit::<bfd::BfdUdpSessionDump>(),
+		"bfd_udp_session_details_09fb2f2d" => visitor.visit::<bfd::BfdUdpSessionDetails>(),
+		"bfd_udp_session_set_flags_04b4bdfd" => visitor.visit::<bfd::BfdUdpSessionSetFlags>(),
+		"bfd_udp_session_set_flags_reply_e8d4e804" => visitor.visit::<bfd::BfdUdpSessionSetFlagsReply>(),
+		"want_bfd_events_c5e2af94" => visitor.visit::<bfd::WantBfdEvents>(),
+		"want_bfd_events_reply_e8d4e804" => visitor.visit::<bfd::WantBfdEventsReply>(),
+		"bfd_udp_session_event_8eaaf062" => visitor.visit::<bfd::BfdUdpSessionEvent>(),
+		"bfd_auth_set_key_690b8877" => visitor.visit::<bfd::BfdAuthSetKey>(),
+		"bfd_auth_set_key_reply_e8d4e804" => visitor.visit::<bfd::BfdAuthSetKeyReply>(),
+		"bfd_auth_del_key_65310b22" => visitor.visit::<bfd::BfdAuthDelKey>(),
+		"bfd_auth_del_key_reply_e8d4e804" => visitor.visit::<bfd::BfdAuthDelKeyReply>(),
+		"bfd_auth_keys_dump_51077d14" => visitor.visit::<bfd::BfdAuthKeysDump>(),
+		"bfd_auth_keys_details_84130e9f" => visitor.visit::<bfd::BfdAuthKeysDetails>(),
+		"bfd_udp_auth_activate_21fd1bdb" => visitor.visit::<bfd::BfdUdpAuthActivate>(),
+		"bfd_udp_auth_activate_reply_e8d4e804" => visitor.visit::<bfd::BfdUdpAuthActivateReply>(),
+		"bfd_udp_auth_deactivate_9a05e2e0" => visitor.visit::<bfd::BfdUdpAuthDeactivate>(),
+		"bfd_udp_auth_deactivate_reply_e8d4e804" => visitor.visit::<bfd::BfdUdpAuthDeactivateReply>(),
+		"bfd_udp_enable_multihop_51077d14" => visitor.visit::<bfd::BfdUdpEnableMultihop>(),
+		"bfd_udp_enable_multihop_reply_e8d4e804" => visitor.visit::<bfd::BfdUdpEnableMultihopReply>(),
+		"fib_source_add_b3ac2aec" => visitor.visit::<fib::FibSourceAdd>(),
+		"fib_source_add_reply_604fd6f1" => visitor.visit::<fib::FibSourceAddReply>(),
+		"fib_source_dump_51077d14" => visitor.visit::<fib::FibSourceDump>(),
+		"fib_source_details_8668acdb" => visitor.visit::<fib::FibSourceDetails>(),
+		"ip6_nd_address_autoconfig_9e14a4a7" => visitor.visit::<rd_cp::Ip6NdAddressAutoconfig>(),
+		"ip6_nd_address_autoconfig_reply_e8d4e804" => visitor.visit::<rd_cp::Ip6NdAddressAutoconfigReply>(),
+		"policer_bind_dcf516f9" => visitor.visit::<policer::PolicerBind>(),
+		"policer_bind_reply_e8d4e804" => visitor.visit::<policer::PolicerBindReply>(),
+		"policer_bind_v2_f87bd3c0" => visitor.visit::<policer::PolicerBindV2>(),
+		"policer_bind_v2_reply_e8d4e804" => visitor.visit::<policer::PolicerBindV2Reply>(),
+		"policer_input_233f0ef5" => visitor.visit::<policer::PolicerInput>(),
+		"policer_input_reply_e8d4e804" => visitor.visit::<policer::PolicerInputReply>(),
+		"policer_input_v2_8388eb84" => visitor.visit::<policer::PolicerInputV2>(),
+		"policer_input_v2_reply_e8d4e804" => visitor.visit::<policer::PolicerInputV2Reply>(),
+		"policer_output_233f0ef5" => visitor.visit::<policer::PolicerOutput>(),
+		"policer_output_reply_e8d4e804" => visitor.visit::<policer::PolicerOutputReply>(),
+		"policer_output_v2_8388eb84" => visitor.visit::<policer::PolicerOutputV2>(),
+		"policer_output_v2_reply_e8d4e804" => visitor.visit::<policer::PolicerOutputV2Reply>(),
+		"policer_add_del_2b31dd38" => visitor.visit::<policer::PolicerAddDel>(),
+		"policer_add_4d949e35" => visitor.visit::<policer::PolicerAdd>(),
+		"policer_del_7ff7912e" => visitor.visit::<policer::PolicerDel>(),
+		"policer_del_reply_e8d4e804" => visitor.visit::<policer::PolicerDelReply>(),
+		"policer_update_fd039ef0" => visitor.visit::<policer::PolicerUpdate>(),
+		"policer_update_reply_e8d4e804" => visitor.visit::<policer::PolicerUpdateReply>(),
+		"policer_reset_7ff7912e" => visitor.visit::<policer::PolicerReset>(),
+		"policer_reset_reply_e8d4e804" => visitor.visit::<policer::PolicerResetReply>(),
+		"policer_add_del_reply_a177cef2" => visitor.visit::<policer::PolicerAddDelReply>(),
+		"policer_add_reply_a177cef2" => visitor.visit::<policer::PolicerAddReply>(),
+		"policer_dump_35f1ae0f" => visitor.visit::<policer::PolicerDump>(),
+		"policer_dump_v2_7ff7912e" => visitor.visit::<policer::PolicerDumpV2>(),
+		"policer_details_72d0e248" => visitor.visit::<policer::PolicerDetails>(),
+		"qos_store_enable_disable_f3abcc8b" => visitor.visit::<qos::QosStoreEnableDisable>(),
+		"qos_store_enable_disable_reply_e8d4e804" => visitor.visit::<qos::QosStoreEnableDisableReply>(),
+		"qos_store_dump_51077d14" => visitor.visit::<qos::QosStoreDump>(),
+		"qos_store_details_3ee0aad7" => visitor.visit::<qos::QosStoreDetails>(),
+		"qos_record_enable_disable_2f1a4a38" => visitor.visit::<qos::QosRecordEnableDisable>(),
+		"qos_record_enable_disable_reply_e8d4e804" => visitor.visit::<qos::QosRecordEnableDisableReply>(),
+		"qos_record_dump_51077d14" => visitor.visit::<qos::QosRecordDump>(),
+		"qos_record_details_a425d4d3" => visitor.visit::<qos::QosRecordDetails>(),
+		"qos_egress_map_update_6d1c065f" => visitor.visit::<qos::QosEgressMapUpdate>(),
+		"qos_egress_map_update_reply_e8d4e804" => visitor.visit::<qos::QosEgressMapUpdateReply>(),
+		"qos_egress_map_delete_3a91bde5" => visitor.visit::<qos::QosEgressMapDelete>(),
+		"qos_egress_map_delete_reply_e8d4e804" => visitor.visit::<qos::QosEgressMapDeleteReply>(),
+		"qos_egress_map_dump_51077d14" => visitor.visit::<qos::QosEgressMapDump>(),
+		"qos_egress_map_details_46c5653c" => visitor.visit::<qos::QosEgressMapDetails>(),
+		"qos_mark_enable_disable_1a010f74" => visitor.visit::<qos::QosMarkEnableDisable>(),
+		"qos_mark_enable_disable_reply_e8d4e804" => visitor.visit::<qos::QosMarkEnableDisableReply>(),
+		"qos_mark_dump_f9e6675e" => visitor.visit::<qos::QosMarkDump>(),
+		"qos_mark_details_89fe81a9" => visitor.visit::<qos::QosMarkDetails>(),
+		"qos_mark_details_reply_e8d4e804" => visitor.visit::<qos::QosMarkDetailsReply>(),
+		"show_version_51077d14" => visitor.visit::<vpe::ShowVersion>(),
+		"show_version_reply_c919bde1" => visitor.visit::<vpe::ShowVersionReply>(),
+		"show_vpe_system_time_51077d14" => visitor.visit::<vpe::ShowVpeSystemTime>(),
+		"show_vpe_system_time_reply_7ffd8193" => visitor.visit::<vpe::ShowVpeSystemTimeReply>(),
+		"log_dump_6ab31753" => visitor.visit::<vpe::LogDump>(),
+		"log_details_03d61cc0" => visitor.visit::<vpe::LogDetails>(),
+		"virtio_pci_create_1944f8db" => visitor.visit::<virtio::VirtioPciCreate>(),
+		"virtio_pci_create_reply_5383d31f" => visitor.visit::<virtio::VirtioPciCreateReply>(),
+		"virtio_pci_create_v2_5d096e1a" => visitor.visit::<virtio::VirtioPciCreateV2>(),
+		"virtio_pci_create_v2_reply_5383d31f" => visitor.visit::<virtio::VirtioPciCreateV2Reply>(),
+		"virtio_pci_delete_f9e6675e" => visitor.visit::<virtio::VirtioPciDelete>(),
+		"virtio_pci_delete_reply_e8d4e804" => visitor.visit::<virtio::VirtioPciDeleteReply>(),
+		"sw_interface_virtio_pci_dump_51077d14" => visitor.visit::<virtio::SwInterfaceVirtioPciDump>(),
+		"sw_interface_virtio_pci_details_6ca9c167" => visitor.visit::<virtio::SwInterfaceVirtioPciDetails>(),
+		"cli_23bfbfff" => visitor.visit::<vlib::Cli>(),
+		"cli_inband_f8377302" => visitor.visit::<vlib::CliInband>(),
+		"cli_reply_06d68297" => visitor.visit::<vlib::CliReply>(),
+		"cli_inband_reply_05879051" => visitor.visit::<vlib::CliInbandReply>(),
+		"get_node_index_f1984c64" => visitor.visit::<vlib::GetNodeIndex>(),
+		"get_node_index_reply_a8600b89" => visitor.visit::<vlib::GetNodeIndexReply>(),
+		"add_node_next_2457116d" => visitor.visit::<vlib::AddNodeNext>(),
+		"add_node_next_reply_2ed75f32" => visitor.visit::<vlib::AddNodeNextReply>(),
+		"show_threads_51077d14" => visitor.visit::<vlib::ShowThreads>(),
+		"show_threads_reply_efd78e83" => visitor.visit::<vlib::ShowThreadsReply>(),
+		"get_node_graph_51077d14" => visitor.visit::<vlib::GetNodeGraph>(),
+		"get_node_graph_reply_06d68297" => visitor.visit::<vlib::GetNodeGraphReply>(),
+		"get_next_index_2457116d" => visitor.visit::<vlib::GetNextIndex>(),
+		"get_next_index_reply_2ed75f32" => visitor.visit::<vlib::GetNextIndexReply>(),
+		"get_f64_endian_value_809fcd44" => visitor.visit::<vlib::GetF64EndianValue>(),
+		"get_f64_endian_value_reply_7e02e404" => visitor.visit::<vlib::GetF64EndianValueReply>(),
+		"get_f64_increment_by_one_b64f027e" => visitor.visit::<vlib::GetF64IncrementByOne>(),
+		"get_f64_increment_by_one_reply_d25dbaa3" => visitor.visit::<vlib::GetF64IncrementByOneReply>(),
+		"flow_add_f946ed84" => visitor.visit::<flow::FlowAdd>(),
+		"flow_add_v2_5b757558" => visitor.visit::<flow::FlowAddV2>(),
+		"flow_add_reply_8587dc85" => visitor.visit::<flow::FlowAddReply>(),
+		"flow_add_v2_reply_8587dc85" => visitor.visit::<flow::FlowAddV2Reply>(),
+		"flow_del_b6b9b02c" => visitor.visit::<flow::FlowDel>(),
+		"flow_del_reply_e8d4e804" => visitor.visit::<flow::FlowDelReply>(),
+		"flow_enable_2024be69" => visitor.visit::<flow::FlowEnable>(),
+		"flow_enable_reply_e8d4e804" => visitor.visit::<flow::FlowEnableReply>(),
+		"flow_disable_2024be69" => visitor.visit::<flow::FlowDisable>(),
+		"flow_disable_reply_e8d4e804" => visitor.visit::<flow::FlowDisableReply>(),
+		"udp_encap_add_f74a60b1" => visitor.visit::<udp::UdpEncapAdd>(),
+		"udp_encap_add_reply_e2fc8294" => visitor.visit::<udp::UdpEncapAddReply>(),
+		"udp_encap_del_3a91bde5" => visitor.visit::<udp::UdpEncapDel>(),
+		"udp_encap_del_reply_e8d4e804" => visitor.visit::<udp::UdpEncapDelReply>(),
+		"udp_encap_dump_51077d14" => visitor.visit::<udp::UdpEncapDump>(),
+		"udp_encap_details_8cfb9c76" => visitor.visit::<udp::UdpEncapDetails>(),
+		"udp_decap_add_del_d14a4f47" => visitor.visit::<udp::UdpDecapAddDel>(),
+		"udp_decap_add_del_reply_e8d4e804" => visitor.visit::<udp::UdpDecapAddDelReply>(),
+		"pipe_create_bb263bd3" => visitor.visit::<pipe::PipeCreate>(),
+		"pipe_create_reply_b7ce310c" => visitor.visit::<pipe::PipeCreateReply>(),
+		"pipe_delete_f9e6675e" => visitor.visit::<pipe::PipeDelete>(),
+		"pipe_delete_reply_e8d4e804" => visitor.visit::<pipe::PipeDeleteReply>(),
+		"pipe_dump_51077d14" => visitor.visit::<pipe::PipeDump>(),
+		"pipe_details_c52b799d" => visitor.visit::<pipe::PipeDetails>(),
+		"ip_table_add_del_0ffdaec0" => visitor.visit::<ip::IpTableAddDel>(),
+		"ip_table_add_del_reply_e8d4e804" => visitor.visit::<ip::IpTableAddDelReply>(),
+		"ip_table_add_del_v2_14e5081f" => visitor.visit::<ip::IpTableAddDelV2>(),
+		"ip_table_add_del_v2_reply_e8d4e804" => visitor.visit::<ip::IpTableAddDelV2Reply>(),
+		"ip_table_allocate_b9d2e09e" => visitor.visit::<ip::IpTableAllocate>(),
+		"ip_table_allocate_reply_1728303a" => visitor.visit::<ip::IpTableAllocateReply>(),
+		"ip_table_dump_51077d14" => visitor.visit::<ip::IpTableDump>(),
+		"ip_table_replace_begin_b9d2e09e" => visitor.visit::<ip::IpTableReplaceBegin>(),
+		"ip_table_replace_begin_reply_e8d4e804" => visitor.visit::<ip::IpTableReplaceBeginReply>(),
+		"ip_table_replace_end_b9d2e09e" => visitor.visit::<ip::IpTableReplaceEnd>(),
+		"ip_table_replace_end_reply_e8d4e804" => visitor.visit::<ip::IpTableReplaceEndReply>(),
+		"ip_table_flush_b9d2e09e" => visitor.visit::<ip::IpTableFlush>(),
+		"ip_table_flush_reply_e8d4e804" => visitor.visit::<ip::IpTableFlushReply>(),
+		"ip_table_details_c79fca0f" => visitor.visit::<ip::IpTableDetails>(),
+		"ip_route_add_del_b8ecfe0d" => visitor.visit::<ip::IpRouteAddDel>(),
+		"ip_route_add_del_v2_521ef330" => visitor.visit::<ip::IpRouteAddDelV2>(),
+		"ip_route_add_del_reply_1992deab" => visitor.visit::<ip::IpRouteAddDelReply>(),
+		"ip_route_add_del_v2_reply_1992deab" => visitor.visit::<ip::IpRouteAddDelV2Reply>(),
+		"ip_route_dump_b9d2e09e" => visitor.visit::<ip::IpRouteDump>(),
+		"ip_route_v2_dump_d16f72e6" => visitor.visit::<ip::IpRouteV2Dump>(),
+		"ip_route_details_bda8f315" => visitor.visit::<ip::IpRouteDetails>(),
+		"ip_route_v2_details_b09aa6c0" => visitor.visit::<ip::IpRouteV2Details>(),
+		"ip_route_lookup_710d6471" => visitor.visit::<ip::IpRouteLookup>(),
+		"ip_route_lookup_v2_710d6471" => visitor.visit::<ip::IpRouteLookupV2>(),
+		"ip_route_lookup_reply_5d8febcb" => visitor.visit::<ip::IpRouteLookupReply>(),
+		"ip_route_lookup_v2_reply_84cc9e03" => visitor.visit::<ip::IpRouteLookupV2Reply>(),
+		"set_ip_flow_hash_084ee09e" => visitor.visit::<ip::SetIpFlowHash>(),
+		"set_ip_flow_hash_reply_e8d4e804" => visitor.visit::<ip::SetIpFlowHashReply>(),
+		"set_ip_flow_hash_v2_6d132100" => visitor.visit::<ip::SetIpFlowHashV2>(),
+		"set_ip_flow_hash_v2_reply_e8d4e804" => visitor.visit::<ip::SetIpFlowHashV2Reply>(),
+		"set_ip_flow_hash_v3_b7876e07" => visitor.visit::<ip::SetIpFlowHashV3>(),
+		"set_ip_flow_hash_v3_reply_e8d4e804" => visitor.visit::<ip::SetIpFlowHashV3Reply>(),
+		"set_ip_flow_hash_router_id_03e4f48e" => visitor.visit::<ip::SetIpFlowHashRouterId>(),
+		"set_ip_flow_hash_router_id_reply_e8d4e804" => visitor.visit::<ip::SetIpFlowHashRouterIdReply>(),
+		"sw_interface_ip6_enable_disable_ae6cfcfb" => visitor.visit::<ip::SwInterfaceIp6EnableDisable>(),
+		"sw_interface_ip6_enable_disable_reply_e8d4e804" => visitor.visit::<ip::SwInterfaceIp6EnableDisableReply>(),
+		"sw_interface_ip4_enable_disable_ae6cfcfb" => visitor.visit::<ip::SwInterfaceIp4EnableDisable>(),
+		"sw_interface_ip4_enable_disable_reply_e8d4e804" => visitor.visit::<ip::SwInterfaceIp4EnableDisableReply>(),
+		"ip_mtable_dump_51077d14" => visitor.visit::<ip::IpMtableDump>(),
+		"ip_mtable_details_b9d2e09e" => visitor.visit::<ip::IpMtableDetails>(),
+		"ip_mroute_add_del_0dd7e790" => visitor.visit::<ip::IpMrouteAddDel>(),
+		"ip_mroute_add_del_reply_1992deab" => visitor.visit::<ip::IpMrouteAddDelReply>(),
+		"ip_mroute_dump_b9d2e09e" => visitor.visit::<ip::IpMrouteDump>(),
+		"ip_mroute_details_c5cb23fc" => visitor.visit::<ip::IpMrouteDetails>(),
+		"ip_address_details_ee29b797" => visitor.visit::<ip::IpAddressDetails>(),
+		"ip_address_dump_2d033de4" => visitor.visit::<ip::IpAddressDump>(),
+		"ip_unnumbered_details_cc59bd42" => visitor.visit::<ip::IpUnnumberedDetails>(),
+		"ip_unnumbered_dump_f9e6675e" => visitor.visit::<ip::IpUnnumberedDump>(),
+		"ip_details_eb152d07" => visitor.visit::<ip::IpDetails>(),
+		"ip_dump_98d231ca" => visitor.visit::<ip::IpDump>(),
+		"mfib_signal_dump_51077d14" => visitor.visit::<ip::MfibSignalDump>(),
+		"mfib_signal_details_6f4a4cfb" => visitor.visit::<ip::MfibSignalDetails>(),
+		"ip_punt_police_db867cea" => visitor.visit::<ip::IpPuntPolice>(),
+		"ip_punt_police_reply_e8d4e804" => visitor.visit::<ip::IpPuntPoliceReply>(),
+		"ip_punt_redirect_6580f635" => visitor.visit::<ip::IpPuntRedirect>(),
+		"ip_punt_redirect_reply_e8d4e804" => visitor.visit::<ip::IpPuntRedirectReply>(),
+		"ip_punt_redirect_dump_2d033de4" => visitor.visit::<ip::IpPuntRedirectDump>(),
+		"ip_punt_redirect_details_2cef63e7" => visitor.visit::<ip::IpPuntRedirectDetails>(),
+		"add_del_ip_punt_redirect_v2_9e804227" => visitor.visit::<ip::AddDelIpPuntRedirectV2>(),
+		"add_del_ip_punt_redirect_v2_reply_e8d4e804" => visitor.visit::<ip::AddDelIpPuntRedirectV2Reply>(),
+		"ip_punt_redirect_v2_dump_d817a484" => visitor.visit::<ip::IpPuntRedirectV2Dump>(),
+		"ip_punt_redirect_v2_details_7ba42e1d" => visitor.visit::<ip::IpPuntRedirectV2Details>(),
+		"ip_container_proxy_add_del_7df1dff1" => visitor.visit::<ip::IpContainerProxyAddDel>(),
+		"ip_container_proxy_add_del_reply_e8d4e804" => visitor.visit::<ip::IpContainerProxyAddDelReply>(),
+		"ip_container_proxy_dump_51077d14" => visitor.visit::<ip::IpContainerProxyDump>(),
+		"ip_container_proxy_details_a8085523" => visitor.visit::<ip::IpContainerProxyDetails>(),
+		"ip_source_and_port_range_check_add_del_92a067e3" => visitor.visit::<ip::IpSourceAndPortRangeCheckAddDel>(),
+		"ip_source_and_port_range_check_add_del_reply_e8d4e804" => visitor.visit::<ip::IpSourceAndPortRangeCheckAddDelReply>(),
+		"ip_source_and_port_range_check_interface_add_del_e1ba8987" => visitor.visit::<ip::IpSourceAndPortRangeCheckInterfaceAddDel>(),
+		"ip_source_and_port_range_check_interface_add_del_reply_e8d4e804" => visitor.visit::<ip::IpSourceAndPortRangeCheckInterfaceAddDelReply>(),
+		"sw_interface_ip6_set_link_local_address_1c10f15f" => visitor.visit::<ip::SwInterfaceIp6SetLinkLocalAddress>(),
+		"sw_interface_ip6_set_link_local_address_reply_e8d4e804" => visitor.visit::<ip::SwInterfaceIp6SetLinkLocalAddressReply>(),
+		"sw_interface_ip6_get_link_local_address_f9e6675e" => visitor.visit::<ip::SwInterfaceIp6GetLinkLocalAddress>(),
+		"sw_interface_ip6_get_link_local_address_reply_d16b7130" => visitor.visit::<ip::SwInterfaceIp6GetLinkLocalAddressReply>(),
+		"ioam_enable_51ccd868" => visitor.visit::<ip::IoamEnable>(),
+		"ioam_enable_reply_e8d4e804" => visitor.visit::<ip::IoamEnableReply>(),
+		"ioam_disable_6b16a45e" => visitor.visit::<ip::IoamDisable>(),
+		"ioam_disable_reply_e8d4e804" => visitor.visit::<ip::IoamDisableReply>(),
+		"ip_reassembly_set_16467d25" => visitor.visit::<ip::IpReassemblySet>(),
+		"ip_reassembly_set_reply_e8d4e804" => visitor.visit::<ip::IpReassemblySetReply>(),
+		"ip_reassembly_get_ea13ff63" => visitor.visit::<ip::IpReassemblyGet>(),
+		"ip_reassembly_get_reply_d5eb8d34" => visitor.visit::<ip::IpReassemblyGetReply>(),
+		"ip_reassembly_enable_disable_eb77968d" => visitor.visit::<ip::IpReassemblyEnableDisable>(),
+		"ip_reassembly_enable_disable_reply_e8d4e804" => visitor.visit::<ip::IpReassemblyEnableDisableReply>(),
+		"ip_local_reass_enable_disable_34e2ccc4" => visitor.visit::<ip::IpLocalReassEnableDisable>(),
+		"ip_local_reass_enable_disable_reply_e8d4e804" => visitor.visit::<ip::IpLocalReassEnableDisableReply>(),
+		"ip_local_reass_get_51077d14" => visitor.visit::<ip::IpLocalReassGet>(),
+		"ip_local_reass_get_reply_3e93a702" => visitor.visit::<ip::IpLocalReassGetReply>(),
+		"ip_path_mtu_update_10bbe5cb" => visitor.visit::<ip::IpPathMtuUpdate>(),
+		"ip_path_mtu_update_reply_e8d4e804" => visitor.visit::<ip::IpPathMtuUpdateReply>(),
+		"ip_path_mtu_get_f75ba505" => visitor.visit::<ip::IpPathMtuGet>(),
+		"ip_path_mtu_get_reply_53b48f5d" => visitor.visit::<ip::IpPathMtuGetReply>(),
+		"ip_path_mtu_details_ac9539a7" => visitor.visit::<ip::IpPathMtuDetails>(),
+		"ip_path_mtu_replace_begin_51077d14" => visitor.visit::<ip::IpPathMtuReplaceBegin>(),
+		"ip_path_mtu_replace_begin_reply_e8d4e804" => visitor.visit::<ip::IpPathMtuReplaceBeginReply>(),
+		"ip_path_mtu_replace_end_51077d14" => visitor.visit::<ip::IpPathMtuReplaceEnd>(),
+		"ip_path_mtu_replace_end_reply_e8d4e804" => visitor.visit::<ip::IpPathMtuReplaceEndReply>(),
+		"sw_interface_span_enable_disable_23ddd96b" => visitor.visit::<span::SwInterfaceSpanEnableDisable>(),
+		"sw_interface_span_enable_disable_reply_e8d4e804" => visitor.visit::<span::SwInterfaceSpanEnableDisableReply>(),
+		"sw_interface_span_dump_d6cf0c3d" => visitor.visit::<span::SwInterfaceSpanDump>(),
+		"sw_interface_span_details_8a20e79f" => visitor.visit::<span::SwInterfaceSpanDetails>(),
+		"teib_entry_add_del_8016cfd2" => visitor.visit::<teib::TeibEntryAddDel>(),
+		"teib_entry_add_del_reply_e8d4e804" => visitor.visit::<teib::TeibEntryAddDelReply>(),
+		"teib_dump_51077d14" => visitor.visit::<teib::TeibDump>(),
+		"teib_details_981ee1a1" => visitor.visit::<teib::TeibDetails>(),
+		"tcp_configure_src_addresses_67eede0d" => visitor.visit::<tcp::TcpConfigureSrcAddresses>(),
+		"tcp_configure_src_addresses_reply_e8d4e804" => visitor.visit::<tcp::TcpConfigureSrcAddressesReply>(),
+		"classify_add_del_table_6849e39e" => visitor.visit::<classify::ClassifyAddDelTable>(),
+		"classify_add_del_table_reply_05486349" => visitor.visit::<classify::ClassifyAddDelTableReply>(),
+		"classify_add_del_session_f20879f0" => visitor.visit::<classify::ClassifyAddDelSession>(),
+		"classify_add_del_session_reply_e8d4e804" => visitor.visit::<classify::ClassifyAddDelSessionReply>(),
+		"policer_classify_set_interface_de7ad708" => visitor.visit::<classify::PolicerClassifySetInterface>(),
+		"policer_classify_set_interface_reply_e8d4e804" => visitor.visit::<classify::PolicerClassifySetInterfaceReply>(),
+		"policer_classify_dump_56cbb5fb" => visitor.visit::<classify::PolicerClassifyDump>(),
+		"policer_classify_details_dfd08765" => visitor.visit::<classify::PolicerClassifyDetails>(),
+		"classify_table_ids_51077d14" => visitor.visit::<classify::ClassifyTableIds>(),
+		"classify_table_ids_reply_d1d20e1d" => visitor.visit::<classify::ClassifyTableIdsReply>(),
+		"classify_table_by_interface_f9e6675e" => visitor.visit::<classify::ClassifyTableByInterface>(),
+		"classify_table_by_interface_reply_ed4197db" => visitor.visit::<classify::ClassifyTableByInterfaceReply>(),
+		"classify_table_info_0cca2cd9" => visitor.visit::<classify::ClassifyTableInfo>(),
+		"classify_table_info_reply_4a573c0e" => visitor.visit::<classify::ClassifyTableInfoReply>(),
+		"classify_session_dump_0cca2cd9" => visitor.visit::<classify::ClassifySessionDump>(),
+		"classify_session_details_60e3ef94" => visitor.visit::<classify::ClassifySessionDetails>(),
+		"flow_classify_set_interface_b6192f1c" => visitor.visit::<classify::FlowClassifySetInterface>(),
+		"flow_classify_set_interface_reply_e8d4e804" => visitor.visit::<classify::FlowClassifySetInterfaceReply>(),
+		"flow_classify_dump_25dd3e4c" => visitor.visit::<classify::FlowClassifyDump>(),
+		"flow_classify_details_dfd08765" => visitor.visit::<classify::FlowClassifyDetails>(),
+		"classify_set_interface_ip_table_e0b097c7" => visitor.visit::<classify::ClassifySetInterfaceIpTable>(),
+		"classify_set_interface_ip_table_reply_e8d4e804" => visitor.visit::<classify::ClassifySetInterfaceIpTableReply>(),
+		"classify_set_interface_l2_tables_5a6ddf65" => visitor.visit::<classify::ClassifySetInterfaceL2Tables>(),
+		"classify_set_interface_l2_tables_reply_e8d4e804" => visitor.visit::<classify::ClassifySetInterfaceL2TablesReply>(),
+		"input_acl_set_interface_de7ad708" => visitor.visit::<classify::InputAclSetInterface>(),
+		"input_acl_set_interface_reply_e8d4e804" => visitor.visit::<classify::InputAclSetInterfaceReply>(),
+		"punt_acl_add_del_a93bf3a0" => visitor.visit::<classify::PuntAclAddDel>(),
+		"punt_acl_add_del_reply_e8d4e804" => visitor.visit::<classify::PuntAclAddDelReply>(),
+		"punt_acl_get_51077d14" => visitor.visit::<classify::PuntAclGet>(),
+		"punt_acl_get_reply_8409b9dd" => visitor.visit::<classify::PuntAclGetReply>(),
+		"output_acl_set_interface_de7ad708" => visitor.visit::<classify::OutputAclSetInterface>(),
+		"output_acl_set_interface_reply_e8d4e804" => visitor.visit::<classify::OutputAclSetInterfaceReply>(),
+		"classify_pcap_lookup_table_e1b4cc6b" => visitor.visit::<classify::ClassifyPcapLookupTable>(),
+		"classify_pcap_lookup_table_reply_9c6c6773" => visitor.visit::<classify::ClassifyPcapLookupTableReply>(),
+		"classify_pcap_set_table_006051b3" => visitor.visit::<classify::ClassifyPcapSetTable>(),
+		"classify_pcap_set_table_reply_9c6c6773" => visitor.visit::<classify::ClassifyPcapSetTableReply>(),
+		"classify_pcap_get_tables_f9e6675e" => visitor.visit::<classify::ClassifyPcapGetTables>(),
+		"classify_pcap_get_tables_reply_5f5bc9e6" => visitor.visit::<classify::ClassifyPcapGetTablesReply>(),
+		"classify_trace_lookup_table_3f7b72e4" => visitor.visit::<classify::ClassifyTraceLookupTable>(),
+		"classify_trace_lookup_table_reply_9c6c6773" => visitor.visit::<classify::ClassifyTraceLookupTableReply>(),
+		"classify_trace_set_table_3909b55a" => visitor.visit::<classify::ClassifyTraceSetTable>(),
+		"classify_trace_set_table_reply_9c6c6773" => visitor.visit::<classify::ClassifyTraceSetTableReply>(),
+		"classify_trace_get_tables_51077d14" => visitor.visit::<classify::ClassifyTraceGetTables>(),
+		"classify_trace_get_tables_reply_5f5bc9e6" => visitor.visit::<classify::ClassifyTraceGetTablesReply>(),
+		"memclnt_create_9c5e1c2f" => visitor.visit::<memclnt::MemclntCreate>(),
+		"memclnt_create_reply_42ec4560" => visitor.visit::<memclnt::MemclntCreateReply>(),
+		"memclnt_delete_7e1c04e3" => visitor.visit::<memclnt::MemclntDelete>(),
+		"memclnt_delete_reply_3d3b6312" => visitor.visit::<memclnt::MemclntDeleteReply>(),
+		"rx_thread_exit_c3a3a452" => visitor.visit::<memclnt::RxThreadExit>(),
+		"memclnt_rx_thread_suspend_c3a3a452" => visitor.visit::<memclnt::MemclntRxThreadSuspend>(),
+		"memclnt_read_timeout_c3a3a452" => visitor.visit::<memclnt::MemclntReadTimeout>(),
+		"rpc_call_7e8a2c95" => visitor.visit::<memclnt::RpcCall>(),
+		"rpc_call_reply_e8d4e804" => visitor.visit::<memclnt::RpcCallReply>(),
+		"get_first_msg_id_ebf79a66" => visitor.visit::<memclnt::GetFirstMsgId>(),
+		"get_first_msg_id_reply_7d337472" => visitor.visit::<memclnt::GetFirstMsgIdReply>(),
+		"api_versions_51077d14" => visitor.visit::<memclnt::ApiVersions>(),
+		"api_versions_reply_5f0d99d6" => visitor.visit::<memclnt::ApiVersionsReply>(),
+		"trace_plugin_msg_ids_f476d3ce" => visitor.visit::<memclnt::TracePluginMsgIds>(),
+		"sockclnt_create_455fb9c4" => visitor.visit::<memclnt::SockclntCreate>(),
+		"sockclnt_create_reply_35166268" => visitor.visit::<memclnt::SockclntCreateReply>(),
+		"sockclnt_delete_8ac76db6" => visitor.visit::<memclnt::SockclntDelete>(),
+		"sockclnt_delete_reply_8f38b1ee" => visitor.visit::<memclnt::SockclntDeleteReply>(),
+		"sock_init_shm_51646d92" => visitor.visit::<memclnt::SockInitShm>(),
+		"sock_init_shm_reply_e8d4e804" => visitor.visit::<memclnt::SockInitShmReply>(),
+		"memclnt_keepalive_51077d14" => visitor.visit::<memclnt::MemclntKeepalive>(),
+		"memclnt_keepalive_reply_e8d4e804" => visitor.visit::<memclnt::MemclntKeepaliveReply>(),
+		"control_ping_51077d14" => visitor.visit::<memclnt::ControlPing>(),
+		"control_ping_reply_f6b0b8ca" => visitor.visit::<memclnt::ControlPingReply>(),
+		"memclnt_create_v2_c4bd4882" => visitor.visit::<memclnt::MemclntCreateV2>(),
+		"memclnt_create_v2_reply_42ec4560" => visitor.visit::<memclnt::MemclntCreateV2Reply>(),
+		"get_api_json_51077d14" => visitor.visit::<memclnt::GetApiJson>(),
+		"get_api_json_reply_ea715b59" => visitor.visit::<memclnt::GetApiJsonReply>(),
+		"tap_create_v3_3f3fd1df" => visitor.visit::<tapv2::TapCreateV3>(),
+		"tap_create_v3_reply_5383d31f" => visitor.visit::<tapv2::TapCreateV3Reply>(),
+		"tap_create_v2_2d0d6570" => visitor.visit::<tapv2::TapCreateV2>(),
+		"tap_create_v2_reply_5383d31f" => visitor.visit::<tapv2::TapCreateV2Reply>(),
+		"tap_delete_v2_f9e6675e" => visitor.visit::<tapv2::TapDeleteV2>(),
+		"tap_delete_v2_reply_e8d4e804" => visitor.visit::<tapv2::TapDeleteV2Reply>(),
+		"sw_interface_tap_v2_dump_f9e6675e" => visitor.visit::<tapv2::SwInterfaceTapV2Dump>(),
+		"sw_interface_tap_v2_details_1e2b2a47" => visitor.visit::<tapv2::SwInterfaceTapV2Details>(),
+		"bier_table_add_del_35e59209" => visitor.visit::<bier::BierTableAddDel>(),
+		"bier_table_add_del_reply_e8d4e804" => visitor.visit::<bier::BierTableAddDelReply>(),
+		"bier_table_dump_51077d14" => visitor.visit::<bier::BierTableDump>(),
+		"bier_table_details_fc44a9dd" => visitor.visit::<bier::BierTableDetails>(),
+		"bier_route_add_del_fd02f3ea" => visitor.visit::<bier::BierRouteAddDel>(),
+		"bier_route_add_del_reply_e8d4e804" => visitor.visit::<bier::BierRouteAddDelReply>(),
+		"bier_route_dump_38339846" => visitor.visit::<bier::BierRouteDump>(),
+		"bier_route_details_4008caee" => visitor.visit::<bier::BierRouteDetails>(),
+		"bier_imp_add_3856dc3d" => visitor.visit::<bier::BierImpAdd>(),
+		"bier_imp_add_reply_d49c5793" => visitor.visit::<bier::BierImpAddReply>(),
+		"bier_imp_del_7d45edf6" => visitor.visit::<bier::BierImpDel>(),
+		"bier_imp_del_reply_e8d4e804" => visitor.visit::<bier::BierImpDelReply>(),
+		"bier_imp_dump_51077d14" => visitor.visit::<bier::BierImpDump>(),
+		"bier_imp_details_b76192df" => visitor.visit::<bier::BierImpDetails>(),
+		"bier_disp_table_add_del_889657ac" => visitor.visit::<bier::BierDispTableAddDel>(),
+		"bier_disp_table_add_del_reply_e8d4e804" => visitor.visit::<bier::BierDispTableAddDelReply>(),
+		"bier_disp_table_dump_51077d14" => visitor.visit::<bier::BierDispTableDump>(),
+		"bier_disp_table_details_d27942c0" => visitor.visit::<bier::BierDispTableDetails>(),
+		"bier_disp_entry_add_del_9eb80cb4" => visitor.visit::<bier::BierDispEntryAddDel>(),
+		"bier_disp_entry_add_del_reply_e8d4e804" => visitor.visit::<bier::BierDispEntryAddDelReply>(),
+		"bier_disp_entry_dump_b5fa54ad" => visitor.visit::<bier::BierDispEntryDump>(),
+		"bier_disp_entry_details_84c218f1" => visitor.visit::<bier::BierDispEntryDetails>(),
+		"p2p_ethernet_add_36a1a6dc" => visitor.visit::<p2p_ethernet::P2pEthernetAdd>(),
+		"p2p_ethernet_add_reply_5383d31f" => visitor.visit::<p2p_ethernet::P2pEthernetAddReply>(),
+		"p2p_ethernet_del_62f81c8c" => visitor.visit::<p2p_ethernet::P2pEthernetDel>(),
+		"p2p_ethernet_del_reply_e8d4e804" => visitor.visit::<p2p_ethernet::P2pEthernetDelReply>(),
+		"sw_interface_set_flags_f5aec1b8" => visitor.visit::<interface::SwInterfaceSetFlags>(),
+		"sw_interface_set_flags_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceSetFlagsReply>(),
+		"sw_interface_set_promisc_d40860d4" => visitor.visit::<interface::SwInterfaceSetPromisc>(),
+		"sw_interface_set_promisc_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceSetPromiscReply>(),
+		"hw_interface_set_mtu_e6746899" => visitor.visit::<interface::HwInterfaceSetMtu>(),
+		"hw_interface_set_mtu_reply_e8d4e804" => visitor.visit::<interface::HwInterfaceSetMtuReply>(),
+		"sw_interface_set_mtu_5cbe85e5" => visitor.visit::<interface::SwInterfaceSetMtu>(),
+		"sw_interface_set_mtu_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceSetMtuReply>(),
+		"sw_interface_set_ip_directed_broadcast_ae6cfcfb" => visitor.visit::<interface::SwInterfaceSetIpDirectedBroadcast>(),
+		"sw_interface_set_ip_directed_broadcast_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceSetIpDirectedBroadcastReply>(),
+		"sw_interface_event_2d3d95a7" => visitor.visit::<interface::SwInterfaceEvent>(),
+		"want_interface_events_476f5a08" => visitor.visit::<interface::WantInterfaceEvents>(),
+		"want_interface_events_reply_e8d4e804" => visitor.visit::<interface::WantInterfaceEventsReply>(),
+		"sw_interface_details_6c221fc7" => visitor.visit::<interface::SwInterfaceDetails>(),
+		"sw_interface_dump_aa610c27" => visitor.visit::<interface::SwInterfaceDump>(),
+		"sw_interface_add_del_address_5463d73b" => visitor.visit::<interface::SwInterfaceAddDelAddress>(),
+		"sw_interface_add_del_address_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceAddDelAddressReply>(),
+		"sw_interface_address_replace_begin_51077d14" => visitor.visit::<interface::SwInterfaceAddressReplaceBegin>(),
+		"sw_interface_address_replace_begin_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceAddressReplaceBeginReply>(),
+		"sw_interface_address_replace_end_51077d14" => visitor.visit::<interface::SwInterfaceAddressReplaceEnd>(),
+		"sw_interface_address_replace_end_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceAddressReplaceEndReply>(),
+		"sw_interface_set_table_df42a577" => visitor.visit::<interface::SwInterfaceSetTable>(),
+		"sw_interface_set_table_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceSetTableReply>(),
+		"sw_interface_get_table_2d033de4" => visitor.visit::<interface::SwInterfaceGetTable>(),
+		"sw_interface_get_table_reply_a6eb0109" => visitor.visit::<interface::SwInterfaceGetTableReply>(),
+		"sw_interface_set_unnumbered_154a6439" => visitor.visit::<interface::SwInterfaceSetUnnumbered>(),
+		"sw_interface_set_unnumbered_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceSetUnnumberedReply>(),
+		"sw_interface_clear_stats_f9e6675e" => visitor.visit::<interface::SwInterfaceClearStats>(),
+		"sw_interface_clear_stats_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceClearStatsReply>(),
+		"sw_interface_tag_add_del_426f8bc1" => visitor.visit::<interface::SwInterfaceTagAddDel>(),
+		"sw_interface_tag_add_del_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceTagAddDelReply>(),
+		"sw_interface_add_del_mac_address_638bb9f4" => visitor.visit::<interface::SwInterfaceAddDelMacAddress>(),
+		"sw_interface_add_del_mac_address_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceAddDelMacAddressReply>(),
+		"sw_interface_set_mac_address_c536e7eb" => visitor.visit::<interface::SwInterfaceSetMacAddress>(),
+		"sw_interface_set_mac_address_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceSetMacAddressReply>(),
+		"sw_interface_get_mac_address_f9e6675e" => visitor.visit::<interface::SwInterfaceGetMacAddress>(),
+		"sw_interface_get_mac_address_reply_40ef2c08" => visitor.visit::<interface::SwInterfaceGetMacAddressReply>(),
+		"sw_interface_set_rx_mode_b04d1cfe" => visitor.visit::<interface::SwInterfaceSetRxMode>(),
+		"sw_interface_set_rx_mode_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceSetRxModeReply>(),
+		"sw_interface_set_rx_placement_db65f3c9" => visitor.visit::<interface::SwInterfaceSetRxPlacement>(),
+		"sw_interface_set_rx_placement_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceSetRxPlacementReply>(),
+		"sw_interface_set_tx_placement_4e0cd5ff" => visitor.visit::<interface::SwInterfaceSetTxPlacement>(),
+		"sw_interface_set_tx_placement_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceSetTxPlacementReply>(),
+		"sw_interface_set_interface_name_45a1d548" => visitor.visit::<interface::SwInterfaceSetInterfaceName>(),
+		"sw_interface_set_interface_name_reply_e8d4e804" => visitor.visit::<interface::SwInterfaceSetInterfaceNameReply>(),
+		"sw_interface_rx_placement_dump_f9e6675e" => visitor.visit::<interface::SwInterfaceRxPlacementDump>(),
+		"sw_interface_rx_placement_details_9e44a7ce" => visitor.visit::<interface::SwInterfaceRxPlacementDetails>(),
+		"sw_interface_tx_placement_get_47250981" => visitor.visit::<interface::SwInterfaceTxPlacementGet>(),
+		"sw_interface_tx_placement_get_reply_53b48f5d" => visitor.visit::<interface::SwInterfaceTxPlacementGetReply>(),
+		"sw_interface_tx_placement_details_00381a2e" => visitor.visit::<interface::SwInterfaceTxPlacementDetails>(),
+		"interface_name_renumber_2b8858b8" => visitor.visit::<interface::InterfaceNameRenumber>(),
+		"interface_name_renumber_reply_e8d4e804" => visitor.visit::<interface::InterfaceNameRenumberReply>(),
+		"create_subif_790ca755" => visitor.visit::<interface::CreateSubif>(),
+		"create_subif_reply_5383d31f" => visitor.visit::<interface::CreateSubifReply>(),
+		"create_vlan_subif_af34ac8b" => visitor.visit::<interface::CreateVlanSubif>(),
+		"create_vlan_subif_reply_5383d31f" => visitor.visit::<interface::CreateVlanSubifReply>(),
+		"delete_subif_f9e6675e" => visitor.visit::<interface::DeleteSubif>(),
+		"delete_subif_reply_e8d4e804" => visitor.visit::<interface::DeleteSubifReply>(),
+		"create_loopback_42bb5d22" => visitor.visit::<interface::CreateLoopback>(),
+		"create_loopback_reply_5383d31f" => visitor.visit::<interface::CreateLoopbackReply>(),
+		"create_loopback_instance_d36a3ee2" => visitor.visit::<interface::CreateLoopbackInstance>(),
+		"create_loopback_instance_reply_5383d31f" => visitor.visit::<interface::CreateLoopbackInstanceReply>(),
+		"delete_loopback_f9e6675e" => visitor.visit::<interface::DeleteLoopback>(),
+		"delete_loopback_reply_e8d4e804" => visitor.visit::<interface::DeleteLoopbackReply>(),
+		"collect_detailed_interface_stats_5501adee" => visitor.visit::<interface::CollectDetailedInterfaceStats>(),
+		"collect_detailed_interface_stats_reply_e8d4e804" => visitor.visit::<interface::CollectDetailedInterfaceStatsReply>(),
+		"pcap_set_filter_function_616abb92" => visitor.visit::<interface::PcapSetFilterFunction>(),
+		"pcap_set_filter_function_reply_e8d4e804" => visitor.visit::<interface::PcapSetFilterFunctionReply>(),
+		"pcap_trace_on_cb39e968" => visitor.visit::<interface::PcapTraceOn>(),
+		"pcap_trace_on_reply_e8d4e804" => visitor.visit::<interface::PcapTraceOnReply>(),
+		"pcap_trace_off_51077d14" => visitor.visit::<interface::PcapTraceOff>(),
+		"pcap_trace_off_reply_e8d4e804" => visitor.visit::<interface::PcapTraceOffReply>(),
+		"mpls_ip_bind_unbind_c7533b32" => visitor.visit::<mpls::MplsIpBindUnbind>(),
+		"mpls_ip_bind_unbind_reply_e8d4e804" => visitor.visit::<mpls::MplsIpBindUnbindReply>(),
+		"mpls_tunnel_add_del_44350ac1" => visitor.visit::<mpls::MplsTunnelAddDel>(),
+		"mpls_tunnel_add_del_reply_afb01472" => visitor.visit::<mpls::MplsTunnelAddDelReply>(),
+		"mpls_tunnel_dump_f9e6675e" => visitor.visit::<mpls::MplsTunnelDump>(),
+		"mpls_tunnel_details_57118ae3" => visitor.visit::<mpls::MplsTunnelDetails>(),
+		"mpls_interface_dump_f9e6675e" => visitor.visit::<mpls::MplsInterfaceDump>(),
+		"mpls_interface_details_0b45011c" => visitor.visit::<mpls::MplsInterfaceDetails>(),
+		"mpls_table_add_del_57817512" => visitor.visit::<mpls::MplsTableAddDel>(),
+		"mpls_table_add_del_reply_e8d4e804" => visitor.visit::<mpls::MplsTableAddDelReply>(),
+		"mpls_table_dump_51077d14" => visitor.visit::<mpls::MplsTableDump>(),
+		"mpls_table_details_f03ecdc8" => visitor.visit::<mpls::MplsTableDetails>(),
+		"mpls_route_add_del_8e1d1e07" => visitor.visit::<mpls::MplsRouteAddDel>(),
+		"mpls_route_add_del_reply_1992deab" => visitor.visit::<mpls::MplsRouteAddDelReply>(),
+		"mpls_route_dump_935fdefa" => visitor.visit::<mpls::MplsRouteDump>(),
+		"mpls_route_details_9b5043dc" => visitor.visit::<mpls::MplsRouteDetails>(),
+		"sw_interface_set_mpls_enable_ae6cfcfb" => visitor.visit::<mpls::SwInterfaceSetMplsEnable>(),
+		"sw_interface_set_mpls_enable_reply_e8d4e804" => visitor.visit::<mpls::SwInterfaceSetMplsEnableReply>(),
+		"sr_pt_iface_dump_51077d14" => visitor.visit::<sr_pt::SrPtIfaceDump>(),
+		"sr_pt_iface_details_1f472f85" => visitor.visit::<sr_pt::SrPtIfaceDetails>(),
+		"sr_pt_iface_add_852c0cda" => visitor.visit::<sr_pt::SrPtIfaceAdd>(),
+		"sr_pt_iface_add_reply_e8d4e804" => visitor.visit::<sr_pt::SrPtIfaceAddReply>(),
+		"sr_pt_iface_del_f9e6675e" => visitor.visit::<sr_pt::SrPtIfaceDel>(),
+		"sr_pt_iface_del_reply_e8d4e804" => visitor.visit::<sr_pt::SrPtIfaceDelReply>(),
+		"bond_create_f1dbd4ff" => visitor.visit::<bond::BondCreate>(),
+		"bond_create_reply_5383d31f" => visitor.visit::<bond::BondCreateReply>(),
+		"bond_create2_912fda76" => visitor.visit::<bond::BondCreate2>(),
+		"bond_create2_reply_5383d31f" => visitor.visit::<bond::BondCreate2Reply>(),
+		"bond_delete_f9e6675e" => visitor.visit::<bond::BondDelete>(),
+		"bond_delete_reply_e8d4e804" => visitor.visit::<bond::BondDeleteReply>(),
+		"bond_enslave_e7d14948" => visitor.visit::<bond::BondEnslave>(),
+		"bond_enslave_reply_e8d4e804" => visitor.visit::<bond::BondEnslaveReply>(),
+		"bond_add_member_e7d14948" => visitor.visit::<bond::BondAddMember>(),
+		"bond_add_member_reply_e8d4e804" => visitor.visit::<bond::BondAddMemberReply>(),
+		"bond_detach_slave_f9e6675e" => visitor.visit::<bond::BondDetachSlave>(),
+		"bond_detach_slave_reply_e8d4e804" => visitor.visit::<bond::BondDetachSlaveReply>(),
+		"bond_detach_member_f9e6675e" => visitor.visit::<bond::BondDetachMember>(),
+		"bond_detach_member_reply_e8d4e804" => visitor.visit::<bond::BondDetachMemberReply>(),
+		"sw_interface_bond_dump_51077d14" => visitor.visit::<bond::SwInterfaceBondDump>(),
+		"sw_interface_bond_details_bb7c929b" => visitor.visit::<bond::SwInterfaceBondDetails>(),
+		"sw_bond_interface_dump_f9e6675e" => visitor.visit::<bond::SwBondInterfaceDump>(),
+		"sw_bond_interface_details_9428a69c" => visitor.visit::<bond::SwBondInterfaceDetails>(),
+		"sw_interface_slave_dump_f9e6675e" => visitor.visit::<bond::SwInterfaceSlaveDump>(),
+		"sw_interface_slave_details_3c4a0e23" => visitor.visit::<bond::SwInterfaceSlaveDetails>(),
+		"sw_member_interface_dump_f9e6675e" => visitor.visit::<bond::SwMemberInterfaceDump>(),
+		"sw_member_interface_details_3c4a0e23" => visitor.visit::<bond::SwMemberInterfaceDetails>(),
+		"sw_interface_set_bond_weight_deb510a0" => visitor.visit::<bond::SwInterfaceSetBondWeight>(),
+		"sw_interface_set_bond_weight_reply_e8d4e804" => visitor.visit::<bond::SwInterfaceSetBondWeightReply>(),
+		"pg_create_interface_b7c893d7" => visitor.visit::<pg::PgCreateInterface>(),
+		"pg_create_interface_v2_8657466a" => visitor.visit::<pg::PgCreateInterfaceV2>(),
+		"pg_create_interface_reply_5383d31f" => visitor.visit::<pg::PgCreateInterfaceReply>(),
+		"pg_create_interface_v2_reply_5383d31f" => visitor.visit::<pg::PgCreateInterfaceV2Reply>(),
+		"pg_delete_interface_f9e6675e" => visitor.visit::<pg::PgDeleteInterface>(),
+		"pg_delete_interface_reply_e8d4e804" => visitor.visit::<pg::PgDeleteInterfaceReply>(),
+		"pg_interface_enable_disable_coalesce_a2ef99e7" => visitor.visit::<pg::PgInterfaceEnableDisableCoalesce>(),
+		"pg_interface_enable_disable_coalesce_reply_e8d4e804" => visitor.visit::<pg::PgInterfaceEnableDisableCoalesceReply>(),
+		"pg_capture_3712fb6c" => visitor.visit::<pg::PgCapture>(),
+		"pg_capture_reply_e8d4e804" => visitor.visit::<pg::PgCaptureReply>(),
+		"pg_enable_disable_01f94f3a" => visitor.visit::<pg::PgEnableDisable>(),
+		"pg_enable_disable_reply_e8d4e804" => visitor.visit::<pg::PgEnableDisableReply>(),
+		"set_punt_47d0e347" => visitor.visit::<punt::SetPunt>(),
+		"set_punt_reply_e8d4e804" => visitor.visit::<punt::SetPuntReply>(),
+		"punt_socket_register_7875badb" => visitor.visit::<punt::PuntSocketRegister>(),
+		"punt_socket_register_reply_bd30ae90" => visitor.visit::<punt::PuntSocketRegisterReply>(),
+		"punt_socket_dump_916fb004" => visitor.visit::<punt::PuntSocketDump>(),
+		"punt_socket_details_330466e4" => visitor.visit::<punt::PuntSocketDetails>(),
+		"punt_socket_deregister_75afa766" => visitor.visit::<punt::PuntSocketDeregister>(),
+		"punt_socket_deregister_reply_e8d4e804" => visitor.visit::<punt::PuntSocketDeregisterReply>(),
+		"punt_reason_dump_5c0dd4fe" => visitor.visit::<punt::PuntReasonDump>(),
+		"punt_reason_details_2c9d4a40" => visitor.visit::<punt::PuntReasonDetails>(),
+		"syslog_set_sender_b8011d0b" => visitor.visit::<syslog::SyslogSetSender>(),
+		"syslog_set_sender_reply_e8d4e804" => visitor.visit::<syslog::SyslogSetSenderReply>(),
+		"syslog_get_sender_51077d14" => visitor.visit::<syslog::SyslogGetSender>(),
+		"syslog_get_sender_reply_424cfa4e" => visitor.visit::<syslog::SyslogGetSenderReply>(),
+		"syslog_set_filter_571348c3" => visitor.visit::<syslog::SyslogSetFilter>(),
+		"syslog_set_filter_reply_e8d4e804" => visitor.visit::<syslog::SyslogSetFilterReply>(),
+		"syslog_get_filter_51077d14" => visitor.visit::<syslog::SyslogGetFilter>(),
+		"syslog_get_filter_reply_eb1833f8" => visitor.visit::<syslog::SyslogGetFilterReply>(),
+		"lcp_default_ns_set_69749409" => visitor.visit::<lcp::LcpDefaultNsSet>(),
+		"lcp_default_ns_set_reply_e8d4e804" => visitor.visit::<lcp::LcpDefaultNsSetReply>(),
+		"lcp_default_ns_get_51077d14" => visitor.visit::<lcp::LcpDefaultNsGet>(),
+		"lcp_default_ns_get_reply_5102feee" => visitor.visit::<lcp::LcpDefaultNsGetReply>(),
+		"lcp_itf_pair_add_del_40482b80" => visitor.visit::<lcp::LcpItfPairAddDel>(),
+		"lcp_itf_pair_add_del_reply_e8d4e804" => visitor.visit::<lcp::LcpItfPairAddDelReply>(),
+		"lcp_itf_pair_add_del_v2_40482b80" => visitor.visit::<lcp::LcpItfPairAddDelV2>(),
+		"lcp_itf_pair_add_del_v2_reply_39452f52" => visitor.visit::<lcp::LcpItfPairAddDelV2Reply>(),
+		"lcp_itf_pair_add_del_v3_40482b80" => visitor.visit::<lcp::LcpItfPairAddDelV3>(),
+		"lcp_itf_pair_add_del_v3_reply_c2502663" => visitor.visit::<lcp::LcpItfPairAddDelV3Reply>(),
+		"lcp_itf_pair_get_f75ba505" => visitor.visit::<lcp::LcpItfPairGet>(),
+		"lcp_itf_pair_get_reply_53b48f5d" => visitor.visit::<lcp::LcpItfPairGetReply>(),
+		"lcp_itf_pair_get_v2_47250981" => visitor.visit::<lcp::LcpItfPairGetV2>(),
+		"lcp_itf_pair_get_v2_reply_53b48f5d" => visitor.visit::<lcp::LcpItfPairGetV2Reply>(),
+		"lcp_itf_pair_details_8b5481af" => visitor.visit::<lcp::LcpItfPairDetails>(),
+		"lcp_ethertype_enable_f893dae1" => visitor.visit::<lcp::LcpEthertypeEnable>(),
+		"lcp_ethertype_enable_reply_e8d4e804" => visitor.visit::<lcp::LcpEthertypeEnableReply>(),
+		"lcp_ethertype_get_51077d14" => visitor.visit::<lcp::LcpEthertypeGet>(),
+		"lcp_ethertype_get_reply_db48c31e" => visitor.visit::<lcp::LcpEthertypeGetReply>(),
+		"lcp_itf_pair_replace_begin_51077d14" => visitor.visit::<lcp::LcpItfPairReplaceBegin>(),
+		"lcp_itf_pair_replace_begin_reply_e8d4e804" => visitor.visit::<lcp::LcpItfPairReplaceBeginReply>(),
+		"lcp_itf_pair_replace_end_51077d14" => visitor.visit::<lcp::LcpItfPairReplaceEnd>(),
+		"lcp_itf_pair_replace_end_reply_e8d4e804" => visitor.visit::<lcp::LcpItfPairReplaceEndReply>(),
+		"avf_create_daab8ae2" => visitor.visit::<avf::AvfCreate>(),
+		"avf_create_reply_5383d31f" => visitor.visit::<avf::AvfCreateReply>(),
+		"avf_delete_f9e6675e" => visitor.visit::<avf::AvfDelete>(),
+		"avf_delete_reply_e8d4e804" => visitor.visit::<avf::AvfDeleteReply>(),
+		"ct6_enable_disable_5d02ac02" => visitor.visit::<ct6::Ct6EnableDisable>(),
+		"ct6_enable_disable_reply_e8d4e804" => visitor.visit::<ct6::Ct6EnableDisableReply>(),
+		"sflow_enable_disable_8499814f" => visitor.visit::<sflow::SflowEnableDisable>(),
+		"sflow_enable_disable_reply_e8d4e804" => visitor.visit::<sflow::SflowEnableDisableReply>(),
+		"sflow_sampling_rate_get_51077d14" => visitor.visit::<sflow::SflowSamplingRateGet>(),
+		"sflow_sampling_rate_get_reply_9c8c8236" => visitor.visit::<sflow::SflowSamplingRateGetReply>(),
+		"sflow_sampling_rate_set_94778f50" => visitor.visit::<sflow::SflowSamplingRateSet>(),
+		"sflow_sampling_rate_set_reply_e8d4e804" => visitor.visit::<sflow::SflowSamplingRateSetReply>(),
+		"sflow_polling_interval_set_7f19cb51" => visitor.visit::<sflow::SflowPollingIntervalSet>(),
+		"sflow_polling_interval_set_reply_e8d4e804" => visitor.visit::<sflow::SflowPollingIntervalSetReply>(),
+		"sflow_polling_interval_get_51077d14" => visitor.visit::<sflow::SflowPollingIntervalGet>(),
+		"sflow_polling_interval_get_reply_e929801c" => visitor.visit::<sflow::SflowPollingIntervalGetReply>(),
+		"sflow_header_bytes_set_5baf56f3" => visitor.visit::<sflow::SflowHeaderBytesSet>(),
+		"sflow_header_bytes_set_reply_e8d4e804" => visitor.visit::<sflow::SflowHeaderBytesSetReply>(),
+		"sflow_header_bytes_get_51077d14" => visitor.visit::<sflow::SflowHeaderBytesGet>(),
+		"sflow_header_bytes_get_reply_624c95b9" => visitor.visit::<sflow::SflowHeaderBytesGetReply>(),
+		"sflow_interface_dump_451a727d" => visitor.visit::<sflow::SflowInterfaceDump>(),
+		"sflow_interface_details_b7b9143f" => visitor.visit::<sflow::SflowInterfaceDetails>(),
+		"lb_conf_56cd3261" => visitor.visit::<lb::LbConf>(),
+		"lb_conf_reply_e8d4e804" => visitor.visit::<lb::LbConfReply>(),
+		"lb_add_del_vip_6fa569c7" => visitor.visit::<lb::LbAddDelVip>(),
+		"lb_add_del_vip_reply_e8d4e804" => visitor.visit::<lb::LbAddDelVipReply>(),
+		"lb_add_del_vip_v2_7c520e0f" => visitor.visit::<lb::LbAddDelVipV2>(),
+		"lb_add_del_vip_v2_reply_e8d4e804" => visitor.visit::<lb::LbAddDelVipV2Reply>(),
+		"lb_add_del_as_35d72500" => visitor.visit::<lb::LbAddDelAs>(),
+		"lb_add_del_as_reply_e8d4e804" => visitor.visit::<lb::LbAddDelAsReply>(),
+		"lb_flush_vip_1063f819" => visitor.visit::<lb::LbFlushVip>(),
+		"lb_flush_vip_reply_e8d4e804" => visitor.visit::<lb::LbFlushVipReply>(),
+		"lb_vip_dump_56110cb7" => visitor.visit::<lb::LbVipDump>(),
+		"lb_vip_details_1329ec9b" => visitor.visit::<lb::LbVipDetails>(),
+		"lb_as_dump_1063f819" => visitor.visit::<lb::LbAsDump>(),
+		"lb_as_details_8d24c29e" => visitor.visit::<lb::LbAsDetails>(),
+		"lb_add_del_intf_nat4_47d6e753" => visitor.visit::<lb::LbAddDelIntfNat4>(),
+		"lb_add_del_intf_nat4_reply_e8d4e804" => visitor.visit::<lb::LbAddDelIntfNat4Reply>(),
+		"lb_add_del_intf_nat6_47d6e753" => visitor.visit::<lb::LbAddDelIntfNat6>(),
+		"lb_add_del_intf_nat6_reply_e8d4e804" => visitor.visit::<lb::LbAddDelIntfNat6Reply>(),
+		"l2tpv3_create_tunnel_15bed0c2" => visitor.visit::<l2tp::L2tpv3CreateTunnel>(),
+		"l2tpv3_create_tunnel_reply_5383d31f" => visitor.visit::<l2tp::L2tpv3CreateTunnelReply>(),
+		"l2tpv3_set_tunnel_cookies_b3f4faf7" => visitor.visit::<l2tp::L2tpv3SetTunnelCookies>(),
+		"l2tpv3_set_tunnel_cookies_reply_e8d4e804" => visitor.visit::<l2tp::L2tpv3SetTunnelCookiesReply>(),
+		"sw_if_l2tpv3_tunnel_details_50b88993" => visitor.visit::<l2tp::SwIfL2tpv3TunnelDetails>(),
+		"sw_if_l2tpv3_tunnel_dump_51077d14" => visitor.visit::<l2tp::SwIfL2tpv3TunnelDump>(),
+		"l2tpv3_interface_enable_disable_3865946c" => visitor.visit::<l2tp::L2tpv3InterfaceEnableDisable>(),
+		"l2tpv3_interface_enable_disable_reply_e8d4e804" => visitor.visit::<l2tp::L2tpv3InterfaceEnableDisableReply>(),
+		"l2tpv3_set_lookup_key_c9892c86" => visitor.visit::<l2tp::L2tpv3SetLookupKey>(),
+		"l2tpv3_set_lookup_key_reply_e8d4e804" => visitor.visit::<l2tp::L2tpv3SetLookupKeyReply>(),
+		"sr_mpls_policy_add_a1a70c70" => visitor.visit::<sr_mpls::SrMplsPolicyAdd>(),
+		"sr_mpls_policy_add_reply_e8d4e804" => visitor.visit::<sr_mpls::SrMplsPolicyAddReply>(),
+		"sr_mpls_policy_mod_88482c17" => visitor.visit::<sr_mpls::SrMplsPolicyMod>(),
+		"sr_mpls_policy_mod_reply_e8d4e804" => visitor.visit::<sr_mpls::SrMplsPolicyModReply>(),
+		"sr_mpls_policy_del_e29d34fa" => visitor.visit::<sr_mpls::SrMplsPolicyDel>(),
+		"sr_mpls_policy_del_reply_e8d4e804" => visitor.visit::<sr_mpls::SrMplsPolicyDelReply>(),
+		"sr_mpls_steering_add_del_64acff63" => visitor.visit::<sr_mpls::SrMplsSteeringAddDel>(),
+		"sr_mpls_steering_add_del_reply_e8d4e804" => visitor.visit::<sr_mpls::SrMplsSteeringAddDelReply>(),
+		"sr_mpls_policy_assign_endpoint_color_0e7eb978" => visitor.visit::<sr_mpls::SrMplsPolicyAssignEndpointColor>(),
+		"sr_mpls_policy_assign_endpoint_color_reply_e8d4e804" => visitor.visit::<sr_mpls::SrMplsPolicyAssignEndpointColorReply>(),
+		"abf_plugin_get_version_51077d14" => visitor.visit::<abf::AbfPluginGetVersion>(),
+		"abf_plugin_get_version_reply_9b32cf86" => visitor.visit::<abf::AbfPluginGetVersionReply>(),
+		"abf_policy_add_del_c6131197" => visitor.visit::<abf::AbfPolicyAddDel>(),
+		"abf_policy_add_del_reply_e8d4e804" => visitor.visit::<abf::AbfPolicyAddDelReply>(),
+		"abf_policy_details_b7487fa4" => visitor.visit::<abf::AbfPolicyDetails>(),
+		"abf_policy_dump_51077d14" => visitor.visit::<abf::AbfPolicyDump>(),
+		"abf_itf_attach_add_del_25c8621b" => visitor.visit::<abf::AbfItfAttachAddDel>(),
+		"abf_itf_attach_add_del_reply_e8d4e804" => visitor.visit::<abf::AbfItfAttachAddDelReply>(),
+		"abf_itf_attach_details_7819523e" => visitor.visit::<abf::AbfItfAttachDetails>(),
+		"abf_itf_attach_dump_51077d14" => visitor.visit::<abf::AbfItfAttachDump>(),
+		"mactime_enable_disable_3865946c" => visitor.visit::<mactime::MactimeEnableDisable>(),
+		"mactime_enable_disable_reply_e8d4e804" => visitor.visit::<mactime::MactimeEnableDisableReply>(),
+		"mactime_add_del_range_cb56e877" => visitor.visit::<mactime::MactimeAddDelRange>(),
+		"mactime_add_del_range_reply_e8d4e804" => visitor.visit::<mactime::MactimeAddDelRangeReply>(),
+		"mactime_dump_8f454e23" => visitor.visit::<mactime::MactimeDump>(),
+		"mactime_details_da25b13a" => visitor.visit::<mactime::MactimeDetails>(),
+		"mactime_dump_reply_49bcc753" => visitor.visit::<mactime::MactimeDumpReply>(),
+		"dslite_add_del_pool_addr_range_de2a5b02" => visitor.visit::<dslite::DsliteAddDelPoolAddrRange>(),
+		"dslite_add_del_pool_addr_range_reply_e8d4e804" => visitor.visit::<dslite::DsliteAddDelPoolAddrRangeReply>(),
+		"dslite_address_dump_51077d14" => visitor.visit::<dslite::DsliteAddressDump>(),
+		"dslite_address_details_ec26d648" => visitor.visit::<dslite::DsliteAddressDetails>(),
+		"dslite_set_aftr_addr_78b50fdf" => visitor.visit::<dslite::DsliteSetAftrAddr>(),
+		"dslite_set_aftr_addr_reply_e8d4e804" => visitor.visit::<dslite::DsliteSetAftrAddrReply>(),
+		"dslite_get_aftr_addr_51077d14" => visitor.visit::<dslite::DsliteGetAftrAddr>(),
+		"dslite_get_aftr_addr_reply_8e23608e" => visitor.visit::<dslite::DsliteGetAftrAddrReply>(),
+		"dslite_set_b4_addr_78b50fdf" => visitor.visit::<dslite::DsliteSetB4Addr>(),
+		"dslite_set_b4_addr_reply_e8d4e804" => visitor.visit::<dslite::DsliteSetB4AddrReply>(),
+		"dslite_get_b4_addr_51077d14" => visitor.visit::<dslite::DsliteGetB4Addr>(),
+		"dslite_get_b4_addr_reply_8e23608e" => visitor.visit::<dslite::DsliteGetB4AddrReply>(),
+		"gpe_add_del_fwd_entry_f0847644" => visitor.visit::<lisp_gpe::GpeAddDelFwdEntry>(),
+		"gpe_add_del_fwd_entry_reply_efe5f176" => visitor.visit::<lisp_gpe::GpeAddDelFwdEntryReply>(),
+		"gpe_enable_disable_c264d7bf" => visitor.visit::<lisp_gpe::GpeEnableDisable>(),
+		"gpe_enable_disable_reply_e8d4e804" => visitor.visit::<lisp_gpe::GpeEnableDisableReply>(),
+		"gpe_add_del_iface_3ccff273" => visitor.visit::<lisp_gpe::GpeAddDelIface>(),
+		"gpe_add_del_iface_reply_e8d4e804" => visitor.visit::<lisp_gpe::GpeAddDelIfaceReply>(),
+		"gpe_fwd_entry_vnis_get_51077d14" => visitor.visit::<lisp_gpe::GpeFwdEntryVnisGet>(),
+		"gpe_fwd_entry_vnis_get_reply_aa70da20" => visitor.visit::<lisp_gpe::GpeFwdEntryVnisGetReply>(),
+		"gpe_fwd_entries_get_8d1f2fe9" => visitor.visit::<lisp_gpe::GpeFwdEntriesGet>(),
+		"gpe_fwd_entries_get_reply_c4844876" => visitor.visit::<lisp_gpe::GpeFwdEntriesGetReply>(),
+		"gpe_fwd_entry_path_dump_39bce980" => visitor.visit::<lisp_gpe::GpeFwdEntryPathDump>(),
+		"gpe_fwd_entry_path_details_483df51a" => visitor.visit::<lisp_gpe::GpeFwdEntryPathDetails>(),
+		"gpe_set_encap_mode_bd819eac" => visitor.visit::<lisp_gpe::GpeSetEncapMode>(),
+		"gpe_set_encap_mode_reply_e8d4e804" => visitor.visit::<lisp_gpe::GpeSetEncapModeReply>(),
+		"gpe_get_encap_mode_51077d14" => visitor.visit::<lisp_gpe::GpeGetEncapMode>(),
+		"gpe_get_encap_mode_reply_36e3f7ca" => visitor.visit::<lisp_gpe::GpeGetEncapModeReply>(),
+		"gpe_add_del_native_fwd_rpath_43fc8b54" => visitor.visit::<lisp_gpe::GpeAddDelNativeFwdRpath>(),
+		"gpe_add_del_native_fwd_rpath_reply_e8d4e804" => visitor.visit::<lisp_gpe::GpeAddDelNativeFwdRpathReply>(),
+		"gpe_native_fwd_rpaths_get_f652ceb4" => visitor.visit::<lisp_gpe::GpeNativeFwdRpathsGet>(),
+		"gpe_native_fwd_rpaths_get_reply_7a1ca5a2" => visitor.visit::<lisp_gpe::GpeNativeFwdRpathsGetReply>(),
+		"l3xc_plugin_get_version_51077d14" => visitor.visit::<l3xc::L3xcPluginGetVersion>(),
+		"l3xc_plugin_get_version_reply_9b32cf86" => visitor.visit::<l3xc::L3xcPluginGetVersionReply>(),
+		"l3xc_update_e96aabdf" => visitor.visit::<l3xc::L3xcUpdate>(),
+		"l3xc_update_reply_1992deab" => visitor.visit::<l3xc::L3xcUpdateReply>(),
+		"l3xc_del_e7dbef91" => visitor.visit::<l3xc::L3xcDel>(),
+		"l3xc_del_reply_e8d4e804" => visitor.visit::<l3xc::L3xcDelReply>(),
+		"l3xc_dump_f9e6675e" => visitor.visit::<l3xc::L3xcDump>(),
+		"l3xc_details_bc5bf852" => visitor.visit::<l3xc::L3xcDetails>(),
+		"lldp_config_c14445df" => visitor.visit::<lldp::LldpConfig>(),
+		"lldp_config_reply_e8d4e804" => visitor.visit::<lldp::LldpConfigReply>(),
+		"sw_interface_set_lldp_57afbcd4" => visitor.visit::<lldp::SwInterfaceSetLldp>(),
+		"sw_interface_set_lldp_reply_e8d4e804" => visitor.visit::<lldp::SwInterfaceSetLldpReply>(),
+		"lldp_dump_f75ba505" => visitor.visit::<lldp::LldpDump>(),
+		"lldp_dump_reply_53b48f5d" => visitor.visit::<lldp::LldpDumpReply>(),
+		"lldp_details_c2d226cd" => visitor.visit::<lldp::LldpDetails>(),
+		"geneve_add_del_tunnel_99445831" => visitor.visit::<geneve::GeneveAddDelTunnel>(),
+		"geneve_add_del_tunnel_reply_5383d31f" => visitor.visit::<geneve::GeneveAddDelTunnelReply>(),
+		"geneve_add_del_tunnel2_8c2a9999" => visitor.visit::<geneve::GeneveAddDelTunnel2>(),
+		"geneve_add_del_tunnel2_reply_5383d31f" => visitor.visit::<geneve::GeneveAddDelTunnel2Reply>(),
+		"geneve_tunnel_dump_f9e6675e" => visitor.visit::<geneve::GeneveTunnelDump>(),
+		"geneve_tunnel_details_6b16eb24" => visitor.visit::<geneve::GeneveTunnelDetails>(),
+		"sw_interface_set_geneve_bypass_65247409" => visitor.visit::<geneve::SwInterfaceSetGeneveBypass>(),
+		"sw_interface_set_geneve_bypass_reply_e8d4e804" => visitor.visit::<geneve::SwInterfaceSetGeneveBypassReply>(),
+		"flowprobe_tx_interface_add_del_b782c976" => visitor.visit::<flowprobe::FlowprobeTxInterfaceAddDel>(),
+		"flowprobe_tx_interface_add_del_reply_e8d4e804" => visitor.visit::<flowprobe::FlowprobeTxInterfaceAddDelReply>(),
+		"flowprobe_interface_add_del_3420739c" => visitor.visit::<flowprobe::FlowprobeInterfaceAddDel>(),
+		"flowprobe_interface_add_del_reply_e8d4e804" => visitor.visit::<flowprobe::FlowprobeInterfaceAddDelReply>(),
+		"flowprobe_interface_dump_f9e6675e" => visitor.visit::<flowprobe::FlowprobeInterfaceDump>(),
+		"flowprobe_interface_details_427d77e0" => visitor.visit::<flowprobe::FlowprobeInterfaceDetails>(),
+		"flowprobe_params_baa46c09" => visitor.visit::<flowprobe::FlowprobeParams>(),
+		"flowprobe_params_reply_e8d4e804" => visitor.visit::<flowprobe::FlowprobeParamsReply>(),
+		"flowprobe_set_params_baa46c09" => visitor.visit::<flowprobe::FlowprobeSetParams>(),
+		"flowprobe_set_params_reply_e8d4e804" => visitor.visit::<flowprobe::FlowprobeSetParamsReply>(),
+		"flowprobe_get_params_51077d14" => visitor.visit::<flowprobe::FlowprobeGetParams>(),
+		"flowprobe_get_params_reply_f350d621" => visitor.visit::<flowprobe::FlowprobeGetParamsReply>(),
+		"one_add_del_locator_set_6fcd6471" => visitor.visit::<one::OneAddDelLocatorSet>(),
+		"one_add_del_locator_set_reply_b6666db4" => visitor.visit::<one::OneAddDelLocatorSetReply>(),
+		"one_add_del_locator_af4d8f13" => visitor.visit::<one::OneAddDelLocator>(),
+		"one_add_del_locator_reply_e8d4e804" => visitor.visit::<one::OneAddDelLocatorReply>(),
+		"one_add_del_local_eid_4e5a83a2" => visitor.visit::<one::OneAddDelLocalEid>(),
+		"one_add_del_local_eid_reply_e8d4e804" => visitor.visit::<one::OneAddDelLocalEidReply>(),
+		"one_map_register_set_ttl_dd59f1f3" => visitor.visit::<one::OneMapRegisterSetTtl>(),
+		"one_map_register_set_ttl_reply_e8d4e804" => visitor.visit::<one::OneMapRegisterSetTtlReply>(),
+		"show_one_map_register_ttl_51077d14" => visitor.visit::<one::ShowOneMapRegisterTtl>(),
+		"show_one_map_register_ttl_reply_fa83dd66" => visitor.visit::<one::ShowOneMapRegisterTtlReply>(),
+		"one_add_del_map_server_ce19e32d" => visitor.visit::<one::OneAddDelMapServer>(),
+		"one_add_del_map_server_reply_e8d4e804" => visitor.visit::<one::OneAddDelMapServerReply>(),
+		"one_add_del_map_resolver_ce19e32d" => visitor.visit::<one::OneAddDelMapResolver>(),
+		"one_add_del_map_resolver_reply_e8d4e804" => visitor.visit::<one::OneAddDelMapResolverReply>(),
+		"one_enable_disable_c264d7bf" => visitor.visit::<one::OneEnableDisable>(),
+		"one_enable_disable_reply_e8d4e804" => visitor.visit::<one::OneEnableDisableReply>(),
+		"one_nsh_set_locator_set_486e2b76" => visitor.visit::<one::OneNshSetLocatorSet>(),
+		"one_nsh_set_locator_set_reply_e8d4e804" => visitor.visit::<one::OneNshSetLocatorSetReply>(),
+		"one_pitr_set_locator_set_486e2b76" => visitor.visit::<one::OnePitrSetLocatorSet>(),
+		"one_pitr_set_locator_set_reply_e8d4e804" => visitor.visit::<one::OnePitrSetLocatorSetReply>(),
+		"one_use_petr_d87dbad9" => visitor.visit::<one::OneUsePetr>(),
+		"one_use_petr_reply_e8d4e804" => visitor.visit::<one::OneUsePetrReply>(),
+		"show_one_use_petr_51077d14" => visitor.visit::<one::ShowOneUsePetr>(),
+		"show_one_use_petr_reply_84a03528" => visitor.visit::<one::ShowOneUsePetrReply>(),
+		"show_one_rloc_probe_state_51077d14" => visitor.visit::<one::ShowOneRlocProbeState>(),
+		"show_one_rloc_probe_state_reply_f15abb16" => visitor.visit::<one::ShowOneRlocProbeStateReply>(),
+		"one_rloc_probe_enable_disable_c264d7bf" => visitor.visit::<one::OneRlocProbeEnableDisable>(),
+		"one_rloc_probe_enable_disable_reply_e8d4e804" => visitor.visit::<one::OneRlocProbeEnableDisableReply>(),
+		"one_map_register_enable_disable_c264d7bf" => visitor.visit::<one::OneMapRegisterEnableDisable>(),
+		"one_map_register_enable_disable_reply_e8d4e804" => visitor.visit::<one::OneMapRegisterEnableDisableReply>(),
+		"show_one_map_register_state_51077d14" => visitor.visit::<one::ShowOneMapRegisterState>(),
+		"show_one_map_register_state_reply_f15abb16" => visitor.visit::<one::ShowOneMapRegisterStateReply>(),
+		"one_map_request_mode_ffa5d2f5" => visitor.visit::<one::OneMapRequestMode>(),
+		"one_map_request_mode_reply_e8d4e804" => visitor.visit::<one::OneMapRequestModeReply>(),
+		"show_one_map_request_mode_51077d14" => visitor.visit::<one::ShowOneMapRequestMode>(),
+		"show_one_map_request_mode_reply_d41f3c1d" => visitor.visit::<one::ShowOneMapRequestModeReply>(),
+		"one_add_del_remote_mapping_6d5c789e" => visitor.visit::<one::OneAddDelRemoteMapping>(),
+		"one_add_del_remote_mapping_reply_e8d4e804" => visitor.visit::<one::OneAddDelRemoteMappingReply>(),
+		"one_add_del_l2_arp_entry_1aa5e8b3" => visitor.visit::<one::OneAddDelL2ArpEntry>(),
+		"one_add_del_l2_arp_entry_reply_e8d4e804" => visitor.visit::<one::OneAddDelL2ArpEntryReply>(),
+		"one_l2_arp_entries_get_4d418cf4" => visitor.visit::<one::OneL2ArpEntriesGet>(),
+		"one_l2_arp_entries_get_reply_b0dd200f" => visitor.visit::<one::OneL2ArpEntriesGetReply>(),
+		"one_add_del_ndp_entry_0f8a287c" => visitor.visit::<one::OneAddDelNdpEntry>(),
+		"one_add_del_ndp_entry_reply_e8d4e804" => visitor.visit::<one::OneAddDelNdpEntryReply>(),
+		"one_ndp_entries_get_4d418cf4" => visitor.visit::<one::OneNdpEntriesGet>(),
+		"one_ndp_entries_get_reply_70719b1a" => visitor.visit::<one::OneNdpEntriesGetReply>(),
+		"one_set_transport_protocol_07b6b85f" => visitor.visit::<one::OneSetTransportProtocol>(),
+		"one_set_transport_protocol_reply_e8d4e804" => visitor.visit::<one::OneSetTransportProtocolReply>(),
+		"one_get_transport_protocol_51077d14" => visitor.visit::<one::OneGetTransportProtocol>(),
+		"one_get_transport_protocol_reply_62a28eb3" => visitor.visit::<one::OneGetTransportProtocolReply>(),
+		"one_ndp_bd_get_51077d14" => visitor.visit::<one::OneNdpBdGet>(),
+		"one_ndp_bd_get_reply_221ac888" => visitor.visit::<one::OneNdpBdGetReply>(),
+		"one_l2_arp_bd_get_51077d14" => visitor.visit::<one::OneL2ArpBdGet>(),
+		"one_l2_arp_bd_get_reply_221ac888" => visitor.visit::<one::OneL2ArpBdGetReply>(),
+		"one_add_del_adjacency_9e830312" => visitor.visit::<one::OneAddDelAdjacency>(),
+		"one_add_del_adjacency_reply_e8d4e804" => visitor.visit::<one::OneAddDelAdjacencyReply>(),
+		"one_add_del_map_request_itr_rlocs_6be88e45" => visitor.visit::<one::OneAddDelMapRequestItrRlocs>(),
+		"one_add_del_map_request_itr_rlocs_reply_e8d4e804" => visitor.visit::<one::OneAddDelMapRequestItrRlocsReply>(),
+		"one_eid_table_add_del_map_9481416b" => visitor.visit::<one::OneEidTableAddDelMap>(),
+		"one_eid_table_add_del_map_reply_e8d4e804" => visitor.visit::<one::OneEidTableAddDelMapReply>(),
+		"one_locator_dump_9b11076c" => visitor.visit::<one::OneLocatorDump>(),
+		"one_locator_details_2c620ffe" => visitor.visit::<one::OneLocatorDetails>(),
+		"one_locator_set_details_5b33a105" => visitor.visit::<one::OneLocatorSetDetails>(),
+		"one_locator_set_dump_71190768" => visitor.visit::<one::OneLocatorSetDump>(),
+		"one_eid_table_details_1c29f792" => visitor.visit::<one::OneEidTableDetails>(),
+		"one_eid_table_dump_bd190269" => visitor.visit::<one::OneEidTableDump>(),
+		"one_adjacencies_get_reply_085bab89" => visitor.visit::<one::OneAdjacenciesGetReply>(),
+		"one_adjacencies_get_8d1f2fe9" => visitor.visit::<one::OneAdjacenciesGet>(),
+		"one_eid_table_map_details_0b6859e2" => visitor.visit::<one::OneEidTableMapDetails>(),
+		"one_eid_table_map_dump_d6cf0c3d" => visitor.visit::<one::OneEidTableMapDump>(),
+		"one_eid_table_vni_dump_51077d14" => visitor.visit::<one::OneEidTableVniDump>(),
+		"one_eid_table_vni_details_64abc01e" => visitor.visit::<one::OneEidTableVniDetails>(),
+		"one_map_resolver_details_3e78fc57" => visitor.visit::<one::OneMapResolverDetails>(),
+		"one_map_resolver_dump_51077d14" => visitor.visit::<one::OneMapResolverDump>(),
+		"one_map_server_details_3e78fc57" => visitor.visit::<one::OneMapServerDetails>(),
+		"one_map_server_dump_51077d14" => visitor.visit::<one::OneMapServerDump>(),
+		"show_one_status_51077d14" => visitor.visit::<one::ShowOneStatus>(),
+		"show_one_status_reply_961bb25b" => visitor.visit::<one::ShowOneStatusReply>(),
+		"one_get_map_request_itr_rlocs_51077d14" => visitor.visit::<one::OneGetMapRequestItrRlocs>(),
+		"one_get_map_request_itr_rlocs_reply_76580f3a" => visitor.visit::<one::OneGetMapRequestItrRlocsReply>(),
+		"show_one_nsh_mapping_51077d14" => visitor.visit::<one::ShowOneNshMapping>(),
+		"show_one_nsh_mapping_reply_46478c02" => visitor.visit::<one::ShowOneNshMappingReply>(),
+		"show_one_pitr_51077d14" => visitor.visit::<one::ShowOnePitr>(),
+		"show_one_pitr_reply_a2d1a49f" => visitor.visit::<one::ShowOnePitrReply>(),
+		"one_stats_dump_51077d14" => visitor.visit::<one::OneStatsDump>(),
+		"one_stats_details_2eb74678" => visitor.visit::<one::OneStatsDetails>(),
+		"one_stats_flush_51077d14" => visitor.visit::<one::OneStatsFlush>(),
+		"one_stats_flush_reply_e8d4e804" => visitor.visit::<one::OneStatsFlushReply>(),
+		"one_stats_enable_disable_c264d7bf" => visitor.visit::<one::OneStatsEnableDisable>(),
+		"one_stats_enable_disable_reply_e8d4e804" => visitor.visit::<one::OneStatsEnableDisableReply>(),
+		"show_one_stats_enable_disable_51077d14" => visitor.visit::<one::ShowOneStatsEnableDisable>(),
+		"show_one_stats_enable_disable_reply_f15abb16" => visitor.visit::<one::ShowOneStatsEnableDisableReply>(),
+		"one_map_register_fallback_threshold_f7d4a475" => visitor.visit::<one::OneMapRegisterFallbackThreshold>(),
+		"one_map_register_fallback_threshold_reply_e8d4e804" => visitor.visit::<one::OneMapRegisterFallbackThresholdReply>(),
+		"show_one_map_register_fallback_threshold_51077d14" => visitor.visit::<one::ShowOneMapRegisterFallbackThreshold>(),
+		"show_one_map_register_fallback_threshold_reply_c93a9113" => visitor.visit::<one::ShowOneMapRegisterFallbackThresholdReply>(),
+		"one_enable_disable_xtr_mode_c264d7bf" => visitor.visit::<one::OneEnableDisableXtrMode>(),
+		"one_enable_disable_xtr_mode_reply_e8d4e804" => visitor.visit::<one::OneEnableDisableXtrModeReply>(),
+		"one_show_xtr_mode_51077d14" => visitor.visit::<one::OneShowXtrMode>(),
+		"one_show_xtr_mode_reply_f15abb16" => visitor.visit::<one::OneShowXtrModeReply>(),
+		"one_enable_disable_petr_mode_c264d7bf" => visitor.visit::<one::OneEnableDisablePetrMode>(),
+		"one_enable_disable_petr_mode_reply_e8d4e804" => visitor.visit::<one::OneEnableDisablePetrModeReply>(),
+		"one_show_petr_mode_51077d14" => visitor.visit::<one::OneShowPetrMode>(),
+		"one_show_petr_mode_reply_f15abb16" => visitor.visit::<one::OneShowPetrModeReply>(),
+		"one_enable_disable_pitr_mode_c264d7bf" => visitor.visit::<one::OneEnableDisablePitrMode>(),
+		"one_enable_disable_pitr_mode_reply_e8d4e804" => visitor.visit::<one::OneEnableDisablePitrModeReply>(),
+		"one_show_pitr_mode_51077d14" => visitor.visit::<one::OneShowPitrMode>(),
+		"one_show_pitr_mode_reply_f15abb16" => visitor.visit::<one::OneShowPitrModeReply>(),
+		"cnat_translation_update_f8d40bc5" => visitor.visit::<cnat::CnatTranslationUpdate>(),
+		"cnat_translation_update_reply_e2fc8294" => visitor.visit::<cnat::CnatTranslationUpdateReply>(),
+		"cnat_translation_del_3a91bde5" => visitor.visit::<cnat::CnatTranslationDel>(),
+		"cnat_translation_del_reply_e8d4e804" => visitor.visit::<cnat::CnatTranslationDelReply>(),
+		"cnat_translation_details_1a5140b7" => visitor.visit::<cnat::CnatTranslationDetails>(),
+		"cnat_translation_dump_51077d14" => visitor.visit::<cnat::CnatTranslationDump>(),
+		"cnat_session_purge_51077d14" => visitor.visit::<cnat::CnatSessionPurge>(),
+		"cnat_session_purge_reply_e8d4e804" => visitor.visit::<cnat::CnatSessionPurgeReply>(),
+		"cnat_session_details_7e5017c7" => visitor.visit::<cnat::CnatSessionDetails>(),
+		"cnat_session_dump_51077d14" => visitor.visit::<cnat::CnatSessionDump>(),
+		"cnat_set_snat_addresses_d997e96c" => visitor.visit::<cnat::CnatSetSnatAddresses>(),
+		"cnat_set_snat_addresses_reply_e8d4e804" => visitor.visit::<cnat::CnatSetSnatAddressesReply>(),
+		"cnat_get_snat_addresses_51077d14" => visitor.visit::<cnat::CnatGetSnatAddresses>(),
+		"cnat_get_snat_addresses_reply_879513c1" => visitor.visit::<cnat::CnatGetSnatAddressesReply>(),
+		"cnat_snat_policy_add_del_exclude_pfx_e26dd79a" => visitor.visit::<cnat::CnatSnatPolicyAddDelExcludePfx>(),
+		"cnat_snat_policy_add_del_exclude_pfx_reply_e8d4e804" => visitor.visit::<cnat::CnatSnatPolicyAddDelExcludePfxReply>(),
+		"cnat_snat_policy_add_del_if_4ebb8d02" => visitor.visit::<cnat::CnatSnatPolicyAddDelIf>(),
+		"cnat_snat_policy_add_del_if_reply_e8d4e804" => visitor.visit::<cnat::CnatSnatPolicyAddDelIfReply>(),
+		"cnat_set_snat_policy_d3e6eaf4" => visitor.visit::<cnat::CnatSetSnatPolicy>(),
+		"cnat_set_snat_policy_reply_e8d4e804" => visitor.visit::<cnat::CnatSetSnatPolicyReply>(),
+		"arping_48817482" => visitor.visit::<arping::Arping>(),
+		"arping_reply_bb9d1cbd" => visitor.visit::<arping::ArpingReply>(),
+		"arping_acd_48817482" => visitor.visit::<arping::ArpingAcd>(),
+		"arping_acd_reply_e08c3b05" => visitor.visit::<arping::ArpingAcdReply>(),
+		"idpf_create_2ba86d91" => visitor.visit::<idpf::IdpfCreate>(),
+		"idpf_create_reply_5383d31f" => visitor.visit::<idpf::IdpfCreateReply>(),
+		"idpf_delete_f9e6675e" => visitor.visit::<idpf::IdpfDelete>(),
+		"idpf_delete_reply_e8d4e804" => visitor.visit::<idpf::IdpfDeleteReply>(),
+		"vxlan_gpe_ioam_enable_2481bef7" => visitor.visit::<ioam_vxlan_gpe::VxlanGpeIoamEnable>(),
+		"vxlan_gpe_ioam_enable_reply_e8d4e804" => visitor.visit::<ioam_vxlan_gpe::VxlanGpeIoamEnableReply>(),
+		"vxlan_gpe_ioam_disable_6b16a45e" => visitor.visit::<ioam_vxlan_gpe::VxlanGpeIoamDisable>(),
+		"vxlan_gpe_ioam_disable_reply_e8d4e804" => visitor.visit::<ioam_vxlan_gpe::VxlanGpeIoamDisableReply>(),
+		"vxlan_gpe_ioam_vni_enable_0fbb5fb1" => visitor.visit::<ioam_vxlan_gpe::VxlanGpeIoamVniEnable>(),
+		"vxlan_gpe_ioam_vni_enable_reply_e8d4e804" => visitor.visit::<ioam_vxlan_gpe::VxlanGpeIoamVniEnableReply>(),
+		"vxlan_gpe_ioam_vni_disable_0fbb5fb1" => visitor.visit::<ioam_vxlan_gpe::VxlanGpeIoamVniDisable>(),
+		"vxlan_gpe_ioam_vni_disable_reply_e8d4e804" => visitor.visit::<ioam_vxlan_gpe::VxlanGpeIoamVniDisableReply>(),
+		"vxlan_gpe_ioam_transit_enable_3d3ec657" => visitor.visit::<ioam_vxlan_gpe::VxlanGpeIoamTransitEnable>(),
+		"vxlan_gpe_ioam_transit_enable_reply_e8d4e804" => visitor.visit::<ioam_vxlan_gpe::VxlanGpeIoamTransitEnableReply>(),
+		"vxlan_gpe_ioam_transit_disable_3d3ec657" => visitor.visit::<ioam_vxlan_gpe::VxlanGpeIoamTransitDisable>(),
+		"vxlan_gpe_ioam_transit_disable_reply_e8d4e804" => visitor.visit::<ioam_vxlan_gpe::VxlanGpeIoamTransitDisableReply>(),
+		"vxlan_add_del_tunnel_0c09dc80" => visitor.visit::<vxlan::VxlanAddDelTunnel>(),
+		"vxlan_add_del_tunnel_v2_4f223f40" => visitor.visit::<vxlan::VxlanAddDelTunnelV2>(),
+		"vxlan_add_del_tunnel_v3_0072b037" => visitor.visit::<vxlan::VxlanAddDelTunnelV3>(),
+		"vxlan_add_del_tunnel_reply_5383d31f" => visitor.visit::<vxlan::VxlanAddDelTunnelReply>(),
+		"vxlan_add_del_tunnel_v2_reply_5383d31f" => visitor.visit::<vxlan::VxlanAddDelTunnelV2Reply>(),
+		"vxlan_add_del_tunnel_v3_reply_5383d31f" => visitor.visit::<vxlan::VxlanAddDelTunnelV3Reply>(),
+		"vxlan_tunnel_dump_f9e6675e" => visitor.visit::<vxlan::VxlanTunnelDump>(),
+		"vxlan_tunnel_v2_dump_f9e6675e" => visitor.visit::<vxlan::VxlanTunnelV2Dump>(),
+		"vxlan_tunnel_details_c3916cb1" => visitor.visit::<vxlan::VxlanTunnelDetails>(),
+		"vxlan_tunnel_v2_details_d3bdd4d9" => visitor.visit::<vxlan::VxlanTunnelV2Details>(),
+		"sw_interface_set_vxlan_bypass_65247409" => visitor.visit::<vxlan::SwInterfaceSetVxlanBypass>(),
+		"sw_interface_set_vxlan_bypass_reply_e8d4e804" => visitor.visit::<vxlan::SwInterfaceSetVxlanBypassReply>(),
+		"vxlan_offload_rx_9cc95087" => visitor.visit::<vxlan::VxlanOffloadRx>(),
+		"vxlan_offload_rx_reply_e8d4e804" => visitor.visit::<vxlan::VxlanOffloadRxReply>(),
+		"af_packet_create_a190415f" => visitor.visit::<af_packet::AfPacketCreate>(),
+		"af_packet_create_reply_5383d31f" => visitor.visit::<af_packet::AfPacketCreateReply>(),
+		"af_packet_create_v2_4aff0436" => visitor.visit::<af_packet::AfPacketCreateV2>(),
+		"af_packet_create_v2_reply_5383d31f" => visitor.visit::<af_packet::AfPacketCreateV2Reply>(),
+		"af_packet_create_v3_b3a809d4" => visitor.visit::<af_packet::AfPacketCreateV3>(),
+		"af_packet_create_v3_reply_5383d31f" => visitor.visit::<af_packet::AfPacketCreateV3Reply>(),
+		"af_packet_delete_863fa648" => visitor.visit::<af_packet::AfPacketDelete>(),
+		"af_packet_delete_reply_e8d4e804" => visitor.visit::<af_packet::AfPacketDeleteReply>(),
+		"af_packet_set_l4_cksum_offload_319cd5c8" => visitor.visit::<af_packet::AfPacketSetL4CksumOffload>(),
+		"af_packet_set_l4_cksum_offload_reply_e8d4e804" => visitor.visit::<af_packet::AfPacketSetL4CksumOffloadReply>(),
+		"af_packet_dump_51077d14" => visitor.visit::<af_packet::AfPacketDump>(),
+		"af_packet_details_58c7c042" => visitor.visit::<af_packet::AfPacketDetails>(),
+		"nsh_add_del_entry_7dea480b" => visitor.visit::<nsh::NshAddDelEntry>(),
+		"nsh_add_del_entry_reply_6296a9eb" => visitor.visit::<nsh::NshAddDelEntryReply>(),
+		"nsh_entry_dump_cdaf8ccb" => visitor.visit::<nsh::NshEntryDump>(),
+		"nsh_entry_details_046fb556" => visitor.visit::<nsh::NshEntryDetails>(),
+		"nsh_add_del_map_0a0f42b0" => visitor.visit::<nsh::NshAddDelMap>(),
+		"nsh_add_del_map_reply_b2b127ef" => visitor.visit::<nsh::NshAddDelMapReply>(),
+		"nsh_map_dump_8fc06b82" => visitor.visit::<nsh::NshMapDump>(),
+		"nsh_map_details_2fefcf49" => visitor.visit::<nsh::NshMapDetails>(),
+		"af_xdp_create_v3_cf4b1827" => visitor.visit::<af_xdp::AfXdpCreateV3>(),
+		"af_xdp_create_v3_reply_5383d31f" => visitor.visit::<af_xdp::AfXdpCreateV3Reply>(),
+		"af_xdp_delete_f9e6675e" => visitor.visit::<af_xdp::AfXdpDelete>(),
+		"af_xdp_delete_reply_e8d4e804" => visitor.visit::<af_xdp::AfXdpDeleteReply>(),
+		"want_ping_finished_events_e79ee58b" => visitor.visit::<ping::WantPingFinishedEvents>(),
+		"want_ping_finished_events_reply_e8d4e804" => visitor.visit::<ping::WantPingFinishedEventsReply>(),
+		"ping_finished_event_397ccf72" => visitor.visit::<ping::PingFinishedEvent>(),
+		"create_vhost_user_if_c785c6fc" => visitor.visit::<vhost_user::CreateVhostUserIf>(),
+		"create_vhost_user_if_reply_5383d31f" => visitor.visit::<vhost_user::CreateVhostUserIfReply>(),
+		"modify_vhost_user_if_0e71d40b" => visitor.visit::<vhost_user::ModifyVhostUserIf>(),
+		"modify_vhost_user_if_reply_e8d4e804" => visitor.visit::<vhost_user::ModifyVhostUserIfReply>(),
+		"create_vhost_user_if_v2_dba1cc1d" => visitor.visit::<vhost_user::CreateVhostUserIfV2>(),
+		"create_vhost_user_if_v2_reply_5383d31f" => visitor.visit::<vhost_user::CreateVhostUserIfV2Reply>(),
+		"modify_vhost_user_if_v2_b2483771" => visitor.visit::<vhost_user::ModifyVhostUserIfV2>(),
+		"modify_vhost_user_if_v2_reply_e8d4e804" => visitor.visit::<vhost_user::ModifyVhostUserIfV2Reply>(),
+		"delete_vhost_user_if_f9e6675e" => visitor.visit::<vhost_user::DeleteVhostUserIf>(),
+		"delete_vhost_user_if_reply_e8d4e804" => visitor.visit::<vhost_user::DeleteVhostUserIfReply>(),
+		"sw_interface_vhost_user_details_0cee1e53" => visitor.visit::<vhost_user::SwInterfaceVhostUserDetails>(),
+		"sw_interface_vhost_user_dump_f9e6675e" => visitor.visit::<vhost_user::SwInterfaceVhostUserDump>(),
+		"dhcp_plugin_get_version_51077d14" => visitor.visit::<dhcp::DhcpPluginGetVersion>(),
+		"dhcp_plugin_get_version_reply_9b32cf86" => visitor.visit::<dhcp::DhcpPluginGetVersionReply>(),
+		"dhcp_plugin_control_ping_51077d14" => visitor.visit::<dhcp::DhcpPluginControlPing>(),
+		"dhcp_plugin_control_ping_reply_f6b0b8ca" => visitor.visit::<dhcp::DhcpPluginControlPingReply>(),
+		"dhcp_proxy_config_4058a689" => visitor.visit::<dhcp::DhcpProxyConfig>(),
+		"dhcp_proxy_config_reply_e8d4e804" => visitor.visit::<dhcp::DhcpProxyConfigReply>(),
+		"dhcp_proxy_set_vss_50537301" => visitor.visit::<dhcp::DhcpProxySetVss>(),
+		"dhcp_proxy_set_vss_reply_e8d4e804" => visitor.visit::<dhcp::DhcpProxySetVssReply>(),
+		"dhcp_client_config_1af013ea" => visitor.visit::<dhcp::DhcpClientConfig>(),
+		"dhcp_client_config_reply_e8d4e804" => visitor.visit::<dhcp::DhcpClientConfigReply>(),
+		"dhcp_compl_event_e18124b7" => visitor.visit::<dhcp::DhcpComplEvent>(),
+		"dhcp_client_dump_51077d14" => visitor.visit::<dhcp::DhcpClientDump>(),
+		"dhcp_client_details_8897b2d8" => visitor.visit::<dhcp::DhcpClientDetails>(),
+		"dhcp_proxy_dump_5c5b063f" => visitor.visit::<dhcp::DhcpProxyDump>(),
+		"dhcp_proxy_details_dcbaf540" => visitor.visit::<dhcp::DhcpProxyDetails>(),
+		"dhcp_client_detect_enable_disable_ae6cfcfb" => visitor.visit::<dhcp::DhcpClientDetectEnableDisable>(),
+		"dhcp_client_detect_enable_disable_reply_e8d4e804" => visitor.visit::<dhcp::DhcpClientDetectEnableDisableReply>(),
+		"dhcp6_duid_ll_set_0f6ca323" => visitor.visit::<dhcp::Dhcp6DuidLlSet>(),
+		"dhcp6_duid_ll_set_reply_e8d4e804" => visitor.visit::<dhcp::Dhcp6DuidLlSetReply>(),
+		"dhcp6_clients_enable_disable_b3e225d2" => visitor.visit::<dhcp::Dhcp6ClientsEnableDisable>(),
+		"dhcp6_clients_enable_disable_reply_e8d4e804" => visitor.visit::<dhcp::Dhcp6ClientsEnableDisableReply>(),
+		"dhcp6_send_client_message_f8222476" => visitor.visit::<dhcp::Dhcp6SendClientMessage>(),
+		"dhcp6_send_client_message_reply_e8d4e804" => visitor.visit::<dhcp::Dhcp6SendClientMessageReply>(),
+		"dhcp6_pd_send_client_message_3739fd8d" => visitor.visit::<dhcp::Dhcp6PdSendClientMessage>(),
+		"dhcp6_pd_send_client_message_reply_e8d4e804" => visitor.visit::<dhcp::Dhcp6PdSendClientMessageReply>(),
+		"want_dhcp6_reply_events_05b454b5" => visitor.visit::<dhcp::WantDhcp6ReplyEvents>(),
+		"want_dhcp6_reply_events_reply_e8d4e804" => visitor.visit::<dhcp::WantDhcp6ReplyEventsReply>(),
+		"want_dhcp6_pd_reply_events_c5e2af94" => visitor.visit::<dhcp::WantDhcp6PdReplyEvents>(),
+		"want_dhcp6_pd_reply_events_reply_e8d4e804" => visitor.visit::<dhcp::WantDhcp6PdReplyEventsReply>(),
+		"dhcp6_reply_event_85b7b17e" => visitor.visit::<dhcp::Dhcp6ReplyEvent>(),
+		"dhcp6_pd_reply_event_5e878029" => visitor.visit::<dhcp::Dhcp6PdReplyEvent>(),
+		"nsim_cross_connect_enable_disable_9c3ead86" => visitor.visit::<nsim::NsimCrossConnectEnableDisable>(),
+		"nsim_cross_connect_enable_disable_reply_e8d4e804" => visitor.visit::<nsim::NsimCrossConnectEnableDisableReply>(),
+		"nsim_output_feature_enable_disable_3865946c" => visitor.visit::<nsim::NsimOutputFeatureEnableDisable>(),
+		"nsim_output_feature_enable_disable_reply_e8d4e804" => visitor.visit::<nsim::NsimOutputFeatureEnableDisableReply>(),
+		"nsim_configure_16ed400f" => visitor.visit::<nsim::NsimConfigure>(),
+		"nsim_configure_reply_e8d4e804" => visitor.visit::<nsim::NsimConfigureReply>(),
+		"nsim_configure2_64de8ed3" => visitor.visit::<nsim::NsimConfigure2>(),
+		"nsim_configure2_reply_e8d4e804" => visitor.visit::<nsim::NsimConfigure2Reply>(),
+		"npt66_binding_add_del_8aa10a52" => visitor.visit::<npt66::Npt66BindingAddDel>(),
+		"npt66_binding_add_del_reply_e8d4e804" => visitor.visit::<npt66::Npt66BindingAddDelReply>(),
+		"vxlan_gpe_ioam_export_enable_disable_d4c76d3a" => visitor.visit::<vxlan_gpe_ioam_export::VxlanGpeIoamExportEnableDisable>(),
+		"vxlan_gpe_ioam_export_enable_disable_reply_e8d4e804" => visitor.visit::<vxlan_gpe_ioam_export::VxlanGpeIoamExportEnableDisableReply>(),
+		"dhcp6_pd_client_enable_disable_a75a0772" => visitor.visit::<dhcp6_pd_client_cp::Dhcp6PdClientEnableDisable>(),
+		"dhcp6_pd_client_enable_disable_reply_e8d4e804" => visitor.visit::<dhcp6_pd_client_cp::Dhcp6PdClientEnableDisableReply>(),
+		"ip6_add_del_address_using_prefix_3982f30a" => visitor.visit::<dhcp6_pd_client_cp::Ip6AddDelAddressUsingPrefix>(),
+		"ip6_add_del_address_using_prefix_reply_e8d4e804" => visitor.visit::<dhcp6_pd_client_cp::Ip6AddDelAddressUsingPrefixReply>(),
+		"ioam_export_ip6_enable_disable_d4c76d3a" => visitor.visit::<ioam_export::IoamExportIp6EnableDisable>(),
+		"ioam_export_ip6_enable_disable_reply_e8d4e804" => visitor.visit::<ioam_export::IoamExportIp6EnableDisableReply>(),
+		"vxlan_gpe_add_del_tunnel_a645b2b0" => visitor.visit::<vxlan_gpe::VxlanGpeAddDelTunnel>(),
+		"vxlan_gpe_add_del_tunnel_v2_d62fdb35" => visitor.visit::<vxlan_gpe::VxlanGpeAddDelTunnelV2>(),
+		"vxlan_gpe_add_del_tunnel_reply_5383d31f" => visitor.visit::<vxlan_gpe::VxlanGpeAddDelTunnelReply>(),
+		"vxlan_gpe_add_del_tunnel_v2_reply_5383d31f" => visitor.visit::<vxlan_gpe::VxlanGpeAddDelTunnelV2Reply>(),
+		"vxlan_gpe_tunnel_dump_f9e6675e" => visitor.visit::<vxlan_gpe::VxlanGpeTunnelDump>(),
+		"vxlan_gpe_tunnel_v2_dump_f9e6675e" => visitor.visit::<vxlan_gpe::VxlanGpeTunnelV2Dump>(),
+		"vxlan_gpe_tunnel_details_0968fc8b" => visitor.visit::<vxlan_gpe::VxlanGpeTunnelDetails>(),
+		"vxlan_gpe_tunnel_v2_details_06be4870" => visitor.visit::<vxlan_gpe::VxlanGpeTunnelV2Details>(),
+		"sw_interface_set_vxlan_gpe_bypass_65247409" => visitor.visit::<vxlan_gpe::SwInterfaceSetVxlanGpeBypass>(),
+		"sw_interface_set_vxlan_gpe_bypass_reply_e8d4e804" => visitor.visit::<vxlan_gpe::SwInterfaceSetVxlanGpeBypassReply>(),
+		"det44_plugin_enable_disable_617b6bf8" => visitor.visit::<det44::Det44PluginEnableDisable>(),
+		"det44_plugin_enable_disable_reply_e8d4e804" => visitor.visit::<det44::Det44PluginEnableDisableReply>(),
+		"det44_interface_add_del_feature_dc17a836" => visitor.visit::<det44::Det44InterfaceAddDelFeature>(),
+		"det44_interface_add_del_feature_reply_e8d4e804" => visitor.visit::<det44::Det44InterfaceAddDelFeatureReply>(),
+		"det44_interface_dump_51077d14" => visitor.visit::<det44::Det44InterfaceDump>(),
+		"det44_interface_details_e60cc5be" => visitor.visit::<det44::Det44InterfaceDetails>(),
+		"det44_add_del_map_1150a190" => visitor.visit::<det44::Det44AddDelMap>(),
+		"det44_add_del_map_reply_e8d4e804" => visitor.visit::<det44::Det44AddDelMapReply>(),
+		"det44_forward_7f8a89cd" => visitor.visit::<det44::Det44Forward>(),
+		"det44_forward_reply_a8ccbdc0" => visitor.visit::<det44::Det44ForwardReply>(),
+		"det44_reverse_a7573fe1" => visitor.visit::<det44::Det44Reverse>(),
+		"det44_reverse_reply_34066d48" => visitor.visit::<det44::Det44ReverseReply>(),
+		"det44_map_dump_51077d14" => visitor.visit::<det44::Det44MapDump>(),
+		"det44_map_details_ad91dc83" => visitor.visit::<det44::Det44MapDetails>(),
+		"det44_close_session_out_f6b259d1" => visitor.visit::<det44::Det44CloseSessionOut>(),
+		"det44_close_session_out_reply_e8d4e804" => visitor.visit::<det44::Det44CloseSessionOutReply>(),
+		"det44_close_session_in_3c68e073" => visitor.visit::<det44::Det44CloseSessionIn>(),
+		"det44_close_session_in_reply_e8d4e804" => visitor.visit::<det44::Det44CloseSessionInReply>(),
+		"det44_session_dump_e45a3af7" => visitor.visit::<det44::Det44SessionDump>(),
+		"det44_session_details_27f3c171" => visitor.visit::<det44::Det44SessionDetails>(),
+		"det44_set_timeouts_d4746b16" => visitor.visit::<det44::Det44SetTimeouts>(),
+		"det44_set_timeouts_reply_e8d4e804" => visitor.visit::<det44::Det44SetTimeoutsReply>(),
+		"det44_get_timeouts_51077d14" => visitor.visit::<det44::Det44GetTimeouts>(),
+		"det44_get_timeouts_reply_3c4df4e1" => visitor.visit::<det44::Det44GetTimeoutsReply>(),
+		"nat_det_add_del_map_1150a190" => visitor.visit::<det44::NatDetAddDelMap>(),
+		"nat_det_add_del_map_reply_e8d4e804" => visitor.visit::<det44::NatDetAddDelMapReply>(),
+		"nat_det_forward_7f8a89cd" => visitor.visit::<det44::NatDetForward>(),
+		"nat_det_forward_reply_a8ccbdc0" => visitor.visit::<det44::NatDetForwardReply>(),
+		"nat_det_reverse_a7573fe1" => visitor.visit::<det44::NatDetReverse>(),
+		"nat_det_reverse_reply_34066d48" => visitor.visit::<det44::NatDetReverseReply>(),
+		"nat_det_map_dump_51077d14" => visitor.visit::<det44::NatDetMapDump>(),
+		"nat_det_map_details_ad91dc83" => visitor.visit::<det44::NatDetMapDetails>(),
+		"nat_det_close_session_out_f6b259d1" => visitor.visit::<det44::NatDetCloseSessionOut>(),
+		"nat_det_close_session_out_reply_e8d4e804" => visitor.visit::<det44::NatDetCloseSessionOutReply>(),
+		"nat_det_close_session_in_3c68e073" => visitor.visit::<det44::NatDetCloseSessionIn>(),
+		"nat_det_close_session_in_reply_e8d4e804" => visitor.visit::<det44::NatDetCloseSessionInReply>(),
+		"nat_det_session_dump_e45a3af7" => visitor.visit::<det44::NatDetSessionDump>(),
+		"nat_det_session_details_27f3c171" => visitor.visit::<det44::NatDetSessionDetails>(),
+		"ip_session_redirect_add_2f78ffda" => visitor.visit::<ip_session_redirect::IpSessionRedirectAdd>(),
+		"ip_session_redirect_add_reply_e8d4e804" => visitor.visit::<ip_session_redirect::IpSessionRedirectAddReply>(),
+		"ip_session_redirect_add_v2_0765f51f" => visitor.visit::<ip_session_redirect::IpSessionRedirectAddV2>(),
+		"ip_session_redirect_add_v2_reply_e8d4e804" => visitor.visit::<ip_session_redirect::IpSessionRedirectAddV2Reply>(),
+		"ip_session_redirect_del_fb643388" => visitor.visit::<ip_session_redirect::IpSessionRedirectDel>(),
+		"ip_session_redirect_del_reply_e8d4e804" => visitor.visit::<ip_session_redirect::IpSessionRedirectDelReply>(),
+		"ip_session_redirect_dump_33554253" => visitor.visit::<ip_session_redirect::IpSessionRedirectDump>(),
+		"ip_session_redirect_details_4487a233" => visitor.visit::<ip_session_redirect::IpSessionRedirectDetails>(),
+		"igmp_listen_19a49f1e" => visitor.visit::<igmp::IgmpListen>(),
+		"igmp_listen_reply_e8d4e804" => visitor.visit::<igmp::IgmpListenReply>(),
+		"igmp_enable_disable_b1edfb96" => visitor.visit::<igmp::IgmpEnableDisable>(),
+		"igmp_enable_disable_reply_e8d4e804" => visitor.visit::<igmp::IgmpEnableDisableReply>(),
+		"igmp_proxy_device_add_del_0b9be9ce" => visitor.visit::<igmp::IgmpProxyDeviceAddDel>(),
+		"igmp_proxy_device_add_del_reply_e8d4e804" => visitor.visit::<igmp::IgmpProxyDeviceAddDelReply>(),
+		"igmp_proxy_device_add_del_interface_1a9ec24a" => visitor.visit::<igmp::IgmpProxyDeviceAddDelInterface>(),
+		"igmp_proxy_device_add_del_interface_reply_e8d4e804" => visitor.visit::<igmp::IgmpProxyDeviceAddDelInterfaceReply>(),
+		"igmp_dump_f9e6675e" => visitor.visit::<igmp::IgmpDump>(),
+		"igmp_details_38f09929" => visitor.visit::<igmp::IgmpDetails>(),
+		"igmp_clear_interface_f9e6675e" => visitor.visit::<igmp::IgmpClearInterface>(),
+		"igmp_clear_interface_reply_e8d4e804" => visitor.visit::<igmp::IgmpClearInterfaceReply>(),
+		"want_igmp_events_cfaccc1f" => visitor.visit::<igmp::WantIgmpEvents>(),
+		"want_igmp_events_reply_e8d4e804" => visitor.visit::<igmp::WantIgmpEventsReply>(),
+		"igmp_event_85fe93ec" => visitor.visit::<igmp::IgmpEvent>(),
+		"igmp_group_prefix_set_5b14a5ce" => visitor.visit::<igmp::IgmpGroupPrefixSet>(),
+		"igmp_group_prefix_set_reply_e8d4e804" => visitor.visit::<igmp::IgmpGroupPrefixSetReply>(),
+		"igmp_group_prefix_dump_51077d14" => visitor.visit::<igmp::IgmpGroupPrefixDump>(),
+		"igmp_group_prefix_details_259ccd81" => visitor.visit::<igmp::IgmpGroupPrefixDetails>(),
+		"mss_clamp_enable_disable_d31b44e3" => visitor.visit::<mss_clamp::MssClampEnableDisable>(),
+		"mss_clamp_enable_disable_reply_e8d4e804" => visitor.visit::<mss_clamp::MssClampEnableDisableReply>(),
+		"mss_clamp_get_47250981" => visitor.visit::<mss_clamp::MssClampGet>(),
+		"mss_clamp_get_reply_53b48f5d" => visitor.visit::<mss_clamp::MssClampGetReply>(),
+		"mss_clamp_details_d3a4de61" => visitor.visit::<mss_clamp::MssClampDetails>(),
+		"cdp_enable_disable_2e7b47df" => visitor.visit::<cdp::CdpEnableDisable>(),
+		"cdp_enable_disable_reply_e8d4e804" => visitor.visit::<cdp::CdpEnableDisableReply>(),
+		"pvti_interface_create_a1e95595" => visitor.visit::<pvti::PvtiInterfaceCreate>(),
+		"pvti_interface_create_reply_5383d31f" => visitor.visit::<pvti::PvtiInterfaceCreateReply>(),
+		"pvti_interface_delete_f9e6675e" => visitor.visit::<pvti::PvtiInterfaceDelete>(),
+		"pvti_interface_delete_reply_e8d4e804" => visitor.visit::<pvti::PvtiInterfaceDeleteReply>(),
+		"pvti_interface_dump_f9e6675e" => visitor.visit::<pvti::PvtiInterfaceDump>(),
+		"pvti_interface_details_a26072b7" => visitor.visit::<pvti::PvtiInterfaceDetails>(),
+		"nat64_plugin_enable_disable_45948b90" => visitor.visit::<nat64::Nat64PluginEnableDisable>(),
+		"nat64_plugin_enable_disable_reply_e8d4e804" => visitor.visit::<nat64::Nat64PluginEnableDisableReply>(),
+		"nat64_set_timeouts_d4746b16" => visitor.visit::<nat64::Nat64SetTimeouts>(),
+		"nat64_set_timeouts_reply_e8d4e804" => visitor.visit::<nat64::Nat64SetTimeoutsReply>(),
+		"nat64_get_timeouts_51077d14" => visitor.visit::<nat64::Nat64GetTimeouts>(),
+		"nat64_get_timeouts_reply_3c4df4e1" => visitor.visit::<nat64::Nat64GetTimeoutsReply>(),
+		"nat64_add_del_pool_addr_range_a3b944e3" => visitor.visit::<nat64::Nat64AddDelPoolAddrRange>(),
+		"nat64_add_del_pool_addr_range_reply_e8d4e804" => visitor.visit::<nat64::Nat64AddDelPoolAddrRangeReply>(),
+		"nat64_pool_addr_dump_51077d14" => visitor.visit::<nat64::Nat64PoolAddrDump>(),
+		"nat64_pool_addr_details_9bb99cdb" => visitor.visit::<nat64::Nat64PoolAddrDetails>(),
+		"nat64_add_del_interface_f3699b83" => visitor.visit::<nat64::Nat64AddDelInterface>(),
+		"nat64_add_del_interface_reply_e8d4e804" => visitor.visit::<nat64::Nat64AddDelInterfaceReply>(),
+		"nat64_interface_dump_51077d14" => visitor.visit::<nat64::Nat64InterfaceDump>(),
+		"nat64_interface_details_5d286289" => visitor.visit::<nat64::Nat64InterfaceDetails>(),
+		"nat64_add_del_static_bib_1c404de5" => visitor.visit::<nat64::Nat64AddDelStaticBib>(),
+		"nat64_add_del_static_bib_reply_e8d4e804" => visitor.visit::<nat64::Nat64AddDelStaticBibReply>(),
+		"nat64_bib_dump_cfcb6b75" => visitor.visit::<nat64::Nat64BibDump>(),
+		"nat64_bib_details_43bc3ddf" => visitor.visit::<nat64::Nat64BibDetails>(),
+		"nat64_st_dump_cfcb6b75" => visitor.visit::<nat64::Nat64StDump>(),
+		"nat64_st_details_dd3361ed" => visitor.visit::<nat64::Nat64StDetails>(),
+		"nat64_add_del_prefix_727b2f4c" => visitor.visit::<nat64::Nat64AddDelPrefix>(),
+		"nat64_add_del_prefix_reply_e8d4e804" => visitor.visit::<nat64::Nat64AddDelPrefixReply>(),
+		"nat64_prefix_dump_51077d14" => visitor.visit::<nat64::Nat64PrefixDump>(),
+		"nat64_prefix_details_20568de3" => visitor.visit::<nat64::Nat64PrefixDetails>(),
+		"nat64_add_del_interface_addr_47d6e753" => visitor.visit::<nat64::Nat64AddDelInterfaceAddr>(),
+		"nat64_add_del_interface_addr_reply_e8d4e804" => visitor.visit::<nat64::Nat64AddDelInterfaceAddrReply>(),
+		"auto_sdl_config_14f30db8" => visitor.visit::<auto_sdl::AutoSdlConfig>(),
+		"auto_sdl_config_reply_e8d4e804" => visitor.visit::<auto_sdl::AutoSdlConfigReply>(),
+		"udp_ping_add_del_fa2628fc" => visitor.visit::<udp_ping::UdpPingAddDel>(),
+		"udp_ping_add_del_reply_e8d4e804" => visitor.visit::<udp_ping::UdpPingAddDelReply>(),
+		"udp_ping_export_b3e225d2" => visitor.visit::<udp_ping::UdpPingExport>(),
+		"udp_ping_export_reply_e8d4e804" => visitor.visit::<udp_ping::UdpPingExportReply>(),
+		"stn_add_del_rule_224c6edd" => visitor.visit::<stn::StnAddDelRule>(),
+		"stn_add_del_rule_reply_e8d4e804" => visitor.visit::<stn::StnAddDelRuleReply>(),
+		"stn_rules_dump_51077d14" => visitor.visit::<stn::StnRulesDump>(),
+		"stn_rules_details_a51935a6" => visitor.visit::<stn::StnRulesDetails>(),
+		"dhcp6_client_enable_disable_ae6cfcfb" => visitor.visit::<dhcp6_ia_na_client_cp::Dhcp6ClientEnableDisable>(),
+		"dhcp6_client_enable_disable_reply_e8d4e804" => visitor.visit::<dhcp6_ia_na_client_cp::Dhcp6ClientEnableDisableReply>(),
+		"trace_profile_add_de08aa6d" => visitor.visit::<trace::TraceProfileAdd>(),
+		"trace_profile_add_reply_e8d4e804" => visitor.visit::<trace::TraceProfileAddReply>(),
+		"trace_profile_del_51077d14" => visitor.visit::<trace::TraceProfileDel>(),
+		"trace_profile_del_reply_e8d4e804" => visitor.visit::<trace::TraceProfileDelReply>(),
+		"trace_profile_show_config_51077d14" => visitor.visit::<trace::TraceProfileShowConfig>(),
+		"trace_profile_show_config_reply_0f1d374c" => visitor.visit::<trace::TraceProfileShowConfigReply>(),
+		"tls_openssl_set_engine_e34d95c1" => visitor.visit::<tls_openssl::TlsOpensslSetEngine>(),
+		"tls_openssl_set_engine_reply_e8d4e804" => visitor.visit::<tls_openssl::TlsOpensslSetEngineReply>(),
+		"memif_socket_filename_add_del_a2ce1a10" => visitor.visit::<memif::MemifSocketFilenameAddDel>(),
+		"memif_socket_filename_add_del_reply_e8d4e804" => visitor.visit::<memif::MemifSocketFilenameAddDelReply>(),
+		"memif_socket_filename_add_del_v2_34223bdf" => visitor.visit::<memif::MemifSocketFilenameAddDelV2>(),
+		"memif_socket_filename_add_del_v2_reply_9f29bdb9" => visitor.visit::<memif::MemifSocketFilenameAddDelV2Reply>(),
+		"memif_create_b1b25061" => visitor.visit::<memif::MemifCreate>(),
+		"memif_create_reply_5383d31f" => visitor.visit::<memif::MemifCreateReply>(),
+		"memif_create_v2_8c7de5f7" => visitor.visit::<memif::MemifCreateV2>(),
+		"memif_create_v2_reply_5383d31f" => visitor.visit::<memif::MemifCreateV2Reply>(),
+		"memif_delete_f9e6675e" => visitor.visit::<memif::MemifDelete>(),
+		"memif_delete_reply_e8d4e804" => visitor.visit::<memif::MemifDeleteReply>(),
+		"memif_socket_filename_details_7ff326f7" => visitor.visit::<memif::MemifSocketFilenameDetails>(),
+		"memif_socket_filename_dump_51077d14" => visitor.visit::<memif::MemifSocketFilenameDump>(),
+		"memif_details_da34feb9" => visitor.visit::<memif::MemifDetails>(),
+		"memif_dump_51077d14" => visitor.visit::<memif::MemifDump>(),
+		"vmxnet3_create_71a07314" => visitor.visit::<vmxnet3::Vmxnet3Create>(),
+		"vmxnet3_create_reply_5383d31f" => visitor.visit::<vmxnet3::Vmxnet3CreateReply>(),
+		"vmxnet3_delete_f9e6675e" => visitor.visit::<vmxnet3::Vmxnet3Delete>(),
+		"vmxnet3_delete_reply_e8d4e804" => visitor.visit::<vmxnet3::Vmxnet3DeleteReply>(),
+		"vmxnet3_details_6a1a5498" => visitor.visit::<vmxnet3::Vmxnet3Details>(),
+		"vmxnet3_dump_51077d14" => visitor.visit::<vmxnet3::Vmxnet3Dump>(),
+		"sw_vmxnet3_interface_dump_f9e6675e" => visitor.visit::<vmxnet3::SwVmxnet3InterfaceDump>(),
+		"sw_vmxnet3_interface_details_6a1a5498" => visitor.visit::<vmxnet3::SwVmxnet3InterfaceDetails>(),
+		"http_static_enable_v4_37540bfc" => visitor.visit::<http_static::HttpStaticEnableV4>(),
+		"http_static_enable_v4_reply_e8d4e804" => visitor.visit::<http_static::HttpStaticEnableV4Reply>(),
+		"http_static_enable_v5_8bf84069" => visitor.visit::<http_static::HttpStaticEnableV5>(),
+		"http_static_enable_v5_reply_e8d4e804" => visitor.visit::<http_static::HttpStaticEnableV5Reply>(),
+		"gre_tunnel_add_del_a27d7f17" => visitor.visit::<gre::GreTunnelAddDel>(),
+		"gre_tunnel_add_del_reply_5383d31f" => visitor.visit::<gre::GreTunnelAddDelReply>(),
+		"gre_tunnel_dump_f9e6675e" => visitor.visit::<gre::GreTunnelDump>(),
+		"gre_tunnel_details_24435433" => visitor.visit::<gre::GreTunnelDetails>(),
+		"pppoe_add_del_session_f6fd759e" => visitor.visit::<pppoe::PppoeAddDelSession>(),
+		"pppoe_add_del_session_reply_5383d31f" => visitor.visit::<pppoe::PppoeAddDelSessionReply>(),
+		"pppoe_session_dump_f9e6675e" => visitor.visit::<pppoe::PppoeSessionDump>(),
+		"pppoe_session_details_4b8e8a4a" => visitor.visit::<pppoe::PppoeSessionDetails>(),
+		"pppoe_add_del_cp_eacd9aaa" => visitor.visit::<pppoe::PppoeAddDelCp>(),
+		"pppoe_add_del_cp_reply_e8d4e804" => visitor.visit::<pppoe::PppoeAddDelCpReply>(),
+		"pnat_binding_add_946ee0b7" => visitor.visit::<pnat::PnatBindingAdd>(),
+		"pnat_binding_add_reply_4cd980a7" => visitor.visit::<pnat::PnatBindingAddReply>(),
+		"pnat_binding_add_v2_946ee0b7" => visitor.visit::<pnat::PnatBindingAddV2>(),
+		"pnat_binding_add_v2_reply_4cd980a7" => visitor.visit::<pnat::PnatBindingAddV2Reply>(),
+		"pnat_binding_del_9259df7b" => visitor.visit::<pnat::PnatBindingDel>(),
+		"pnat_binding_del_reply_e8d4e804" => visitor.visit::<pnat::PnatBindingDelReply>(),
+		"pnat_binding_attach_6e074232" => visitor.visit::<pnat::PnatBindingAttach>(),
+		"pnat_binding_attach_reply_e8d4e804" => visitor.visit::<pnat::PnatBindingAttachReply>(),
+		"pnat_binding_detach_6e074232" => visitor.visit::<pnat::PnatBindingDetach>(),
+		"pnat_binding_detach_reply_e8d4e804" => visitor.visit::<pnat::PnatBindingDetachReply>(),
+		"pnat_bindings_get_f75ba505" => visitor.visit::<pnat::PnatBindingsGet>(),
+		"pnat_bindings_get_reply_53b48f5d" => visitor.visit::<pnat::PnatBindingsGetReply>(),
+		"pnat_bindings_details_08fb2815" => visitor.visit::<pnat::PnatBindingsDetails>(),
+		"pnat_interfaces_get_f75ba505" => visitor.visit::<pnat::PnatInterfacesGet>(),
+		"pnat_interfaces_get_reply_53b48f5d" => visitor.visit::<pnat::PnatInterfacesGetReply>(),
+		"pnat_interfaces_details_4cb09493" => visitor.visit::<pnat::PnatInterfacesDetails>(),
+		"pnat_flow_lookup_1ef8747c" => visitor.visit::<pnat::PnatFlowLookup>(),
+		"pnat_flow_lookup_reply_4cd980a7" => visitor.visit::<pnat::PnatFlowLookupReply>(),
+		"adl_interface_enable_disable_5501adee" => visitor.visit::<adl::AdlInterfaceEnableDisable>(),
+		"adl_interface_enable_disable_reply_e8d4e804" => visitor.visit::<adl::AdlInterfaceEnableDisableReply>(),
+		"adl_allowlist_enable_disable_ea88828d" => visitor.visit::<adl::AdlAllowlistEnableDisable>(),
+		"adl_allowlist_enable_disable_reply_e8d4e804" => visitor.visit::<adl::AdlAllowlistEnableDisableReply>(),
+		"gtpu_add_del_tunnel_ca983a2b" => visitor.visit::<gtpu::GtpuAddDelTunnel>(),
+		"gtpu_add_del_tunnel_reply_5383d31f" => visitor.visit::<gtpu::GtpuAddDelTunnelReply>(),
+		"gtpu_add_del_tunnel_v2_a0c30713" => visitor.visit::<gtpu::GtpuAddDelTunnelV2>(),
+		"gtpu_add_del_tunnel_v2_reply_62b41304" => visitor.visit::<gtpu::GtpuAddDelTunnelV2Reply>(),
+		"gtpu_tunnel_update_tteid_79f33816" => visitor.visit::<gtpu::GtpuTunnelUpdateTteid>(),
+		"gtpu_tunnel_update_tteid_reply_e8d4e804" => visitor.visit::<gtpu::GtpuTunnelUpdateTteidReply>(),
+		"gtpu_tunnel_dump_f9e6675e" => visitor.visit::<gtpu::GtpuTunnelDump>(),
+		"gtpu_tunnel_details_27f434ae" => visitor.visit::<gtpu::GtpuTunnelDetails>(),
+		"gtpu_tunnel_v2_dump_f9e6675e" => visitor.visit::<gtpu::GtpuTunnelV2Dump>(),
+		"gtpu_tunnel_v2_details_8bf4ba92" => visitor.visit::<gtpu::GtpuTunnelV2Details>(),
+		"sw_interface_set_gtpu_bypass_65247409" => visitor.visit::<gtpu::SwInterfaceSetGtpuBypass>(),
+		"sw_interface_set_gtpu_bypass_reply_e8d4e804" => visitor.visit::<gtpu::SwInterfaceSetGtpuBypassReply>(),
+		"gtpu_offload_rx_f0b08786" => visitor.visit::<gtpu::GtpuOffloadRx>(),
+		"gtpu_offload_rx_reply_e8d4e804" => visitor.visit::<gtpu::GtpuOffloadRxReply>(),
+		"gtpu_add_del_forward_c6ccce13" => visitor.visit::<gtpu::GtpuAddDelForward>(),
+		"gtpu_add_del_forward_reply_5383d31f" => visitor.visit::<gtpu::GtpuAddDelForwardReply>(),
+		"gtpu_get_transfer_counts_61410788" => visitor.visit::<gtpu::GtpuGetTransferCounts>(),
+		"gtpu_get_transfer_counts_reply_e35f04bc" => visitor.visit::<gtpu::GtpuGetTransferCountsReply>(),
+		"map_add_domain_249f195c" => visitor.visit::<map::MapAddDomain>(),
+		"map_add_domain_reply_3e6d4e2c" => visitor.visit::<map::MapAddDomainReply>(),
+		"map_del_domain_8ac76db6" => visitor.visit::<map::MapDelDomain>(),
+		"map_del_domain_reply_e8d4e804" => visitor.visit::<map::MapDelDomainReply>(),
+		"map_add_del_rule_c65b32f7" => visitor.visit::<map::MapAddDelRule>(),
+		"map_add_del_rule_reply_e8d4e804" => visitor.visit::<map::MapAddDelRuleReply>(),
+		"map_domains_get_f75ba505" => visitor.visit::<map::MapDomainsGet>(),
+		"map_domains_get_reply_53b48f5d" => visitor.visit::<map::MapDomainsGetReply>(),
+		"map_domain_dump_51077d14" => visitor.visit::<map::MapDomainDump>(),
+		"map_domain_details_796edb50" => visitor.visit::<map::MapDomainDetails>(),
+		"map_rule_dump_e43e6ff6" => visitor.visit::<map::MapRuleDump>(),
+		"map_rule_details_c7cbeea5" => visitor.visit::<map::MapRuleDetails>(),
+		"map_if_enable_disable_59bb32f4" => visitor.visit::<map::MapIfEnableDisable>(),
+		"map_if_enable_disable_reply_e8d4e804" => visitor.visit::<map::MapIfEnableDisableReply>(),
+		"map_summary_stats_51077d14" => visitor.visit::<map::MapSummaryStats>(),
+		"map_summary_stats_reply_0e4ace0e" => visitor.visit::<map::MapSummaryStatsReply>(),
+		"map_param_set_fragmentation_9ff54d90" => visitor.visit::<map::MapParamSetFragmentation>(),
+		"map_param_set_fragmentation_reply_e8d4e804" => visitor.visit::<map::MapParamSetFragmentationReply>(),
+		"map_param_set_icmp_58210cbf" => visitor.visit::<map::MapParamSetIcmp>(),
+		"map_param_set_icmp_reply_e8d4e804" => visitor.visit::<map::MapParamSetIcmpReply>(),
+		"map_param_set_icmp6_5d01f8c1" => visitor.visit::<map::MapParamSetIcmp6>(),
+		"map_param_set_icmp6_reply_e8d4e804" => visitor.visit::<map::MapParamSetIcmp6Reply>(),
+		"map_param_add_del_pre_resolve_dae5af03" => visitor.visit::<map::MapParamAddDelPreResolve>(),
+		"map_param_add_del_pre_resolve_reply_e8d4e804" => visitor.visit::<map::MapParamAddDelPreResolveReply>(),
+		"map_param_set_security_check_6abe9836" => visitor.visit::<map::MapParamSetSecurityCheck>(),
+		"map_param_set_security_check_reply_e8d4e804" => visitor.visit::<map::MapParamSetSecurityCheckReply>(),
+		"map_param_set_traffic_class_9cac455c" => visitor.visit::<map::MapParamSetTrafficClass>(),
+		"map_param_set_traffic_class_reply_e8d4e804" => visitor.visit::<map::MapParamSetTrafficClassReply>(),
+		"map_param_set_tcp_87a825d9" => visitor.visit::<map::MapParamSetTcp>(),
+		"map_param_set_tcp_reply_e8d4e804" => visitor.visit::<map::MapParamSetTcpReply>(),
+		"map_param_get_51077d14" => visitor.visit::<map::MapParamGet>(),
+		"map_param_get_reply_26272c90" => visitor.visit::<map::MapParamGetReply>(),
+		"lisp_add_del_locator_set_6fcd6471" => visitor.visit::<lisp::LispAddDelLocatorSet>(),
+		"lisp_add_del_locator_set_reply_b6666db4" => visitor.visit::<lisp::LispAddDelLocatorSetReply>(),
+		"lisp_add_del_locator_af4d8f13" => visitor.visit::<lisp::LispAddDelLocator>(),
+		"lisp_add_del_locator_reply_e8d4e804" => visitor.visit::<lisp::LispAddDelLocatorReply>(),
+		"lisp_add_del_local_eid_4e5a83a2" => visitor.visit::<lisp::LispAddDelLocalEid>(),
+		"lisp_add_del_local_eid_reply_e8d4e804" => visitor.visit::<lisp::LispAddDelLocalEidReply>(),
+		"lisp_add_del_map_server_ce19e32d" => visitor.visit::<lisp::LispAddDelMapServer>(),
+		"lisp_add_del_map_server_reply_e8d4e804" => visitor.visit::<lisp::LispAddDelMapServerReply>(),
+		"lisp_add_del_map_resolver_ce19e32d" => visitor.visit::<lisp::LispAddDelMapResolver>(),
+		"lisp_add_del_map_resolver_reply_e8d4e804" => visitor.visit::<lisp::LispAddDelMapResolverReply>(),
+		"lisp_enable_disable_c264d7bf" => visitor.visit::<lisp::LispEnableDisable>(),
+		"lisp_enable_disable_reply_e8d4e804" => visitor.visit::<lisp::LispEnableDisableReply>(),
+		"lisp_pitr_set_locator_set_486e2b76" => visitor.visit::<lisp::LispPitrSetLocatorSet>(),
+		"lisp_pitr_set_locator_set_reply_e8d4e804" => visitor.visit::<lisp::LispPitrSetLocatorSetReply>(),
+		"lisp_use_petr_d87dbad9" => visitor.visit::<lisp::LispUsePetr>(),
+		"lisp_use_petr_reply_e8d4e804" => visitor.visit::<lisp::LispUsePetrReply>(),
+		"show_lisp_use_petr_51077d14" => visitor.visit::<lisp::ShowLispUsePetr>(),
+		"show_lisp_use_petr_reply_22b9a4b0" => visitor.visit::<lisp::ShowLispUsePetrReply>(),
+		"show_lisp_rloc_probe_state_51077d14" => visitor.visit::<lisp::ShowLispRlocProbeState>(),
+		"show_lisp_rloc_probe_state_reply_e33a377b" => visitor.visit::<lisp::ShowLispRlocProbeStateReply>(),
+		"lisp_rloc_probe_enable_disable_c264d7bf" => visitor.visit::<lisp::LispRlocProbeEnableDisable>(),
+		"lisp_rloc_probe_enable_disable_reply_e8d4e804" => visitor.visit::<lisp::LispRlocProbeEnableDisableReply>(),
+		"lisp_map_register_enable_disable_c264d7bf" => visitor.visit::<lisp::LispMapRegisterEnableDisable>(),
+		"lisp_map_register_enable_disable_reply_e8d4e804" => visitor.visit::<lisp::LispMapRegisterEnableDisableReply>(),
+		"show_lisp_map_register_state_51077d14" => visitor.visit::<lisp::ShowLispMapRegisterState>(),
+		"show_lisp_map_register_state_reply_e33a377b" => visitor.visit::<lisp::ShowLispMapRegisterStateReply>(),
+		"lisp_map_request_mode_f43c26ae" => visitor.visit::<lisp::LispMapRequestMode>(),
+		"lisp_map_request_mode_reply_e8d4e804" => visitor.visit::<lisp::LispMapRequestModeReply>(),
+		"show_lisp_map_request_mode_51077d14" => visitor.visit::<lisp::ShowLispMapRequestMode>(),
+		"show_lisp_map_request_mode_reply_5b05038e" => visitor.visit::<lisp::ShowLispMapRequestModeReply>(),
+		"lisp_add_del_remote_mapping_6d5c789e" => visitor.visit::<lisp::LispAddDelRemoteMapping>(),
+		"lisp_add_del_remote_mapping_reply_e8d4e804" => visitor.visit::<lisp::LispAddDelRemoteMappingReply>(),
+		"lisp_add_del_adjacency_2ce0e6f6" => visitor.visit::<lisp::LispAddDelAdjacency>(),
+		"lisp_add_del_adjacency_reply_e8d4e804" => visitor.visit::<lisp::LispAddDelAdjacencyReply>(),
+		"lisp_add_del_map_request_itr_rlocs_6be88e45" => visitor.visit::<lisp::LispAddDelMapRequestItrRlocs>(),
+		"lisp_add_del_map_request_itr_rlocs_reply_e8d4e804" => visitor.visit::<lisp::LispAddDelMapRequestItrRlocsReply>(),
+		"lisp_eid_table_add_del_map_9481416b" => visitor.visit::<lisp::LispEidTableAddDelMap>(),
+		"lisp_eid_table_add_del_map_reply_e8d4e804" => visitor.visit::<lisp::LispEidTableAddDelMapReply>(),
+		"lisp_locator_dump_b954fad7" => visitor.visit::<lisp::LispLocatorDump>(),
+		"lisp_locator_details_2c620ffe" => visitor.visit::<lisp::LispLocatorDetails>(),
+		"lisp_locator_set_details_5b33a105" => visitor.visit::<lisp::LispLocatorSetDetails>(),
+		"lisp_locator_set_dump_c2cb5922" => visitor.visit::<lisp::LispLocatorSetDump>(),
+		"lisp_eid_table_details_1c29f792" => visitor.visit::<lisp::LispEidTableDetails>(),
+		"lisp_eid_table_dump_629468b5" => visitor.visit::<lisp::LispEidTableDump>(),
+		"lisp_adjacencies_get_reply_807257bf" => visitor.visit::<lisp::LispAdjacenciesGetReply>(),
+		"lisp_adjacencies_get_8d1f2fe9" => visitor.visit::<lisp::LispAdjacenciesGet>(),
+		"lisp_eid_table_map_details_0b6859e2" => visitor.visit::<lisp::LispEidTableMapDetails>(),
+		"lisp_eid_table_map_dump_d6cf0c3d" => visitor.visit::<lisp::LispEidTableMapDump>(),
+		"lisp_eid_table_vni_dump_51077d14" => visitor.visit::<lisp::LispEidTableVniDump>(),
+		"lisp_eid_table_vni_details_64abc01e" => visitor.visit::<lisp::LispEidTableVniDetails>(),
+		"lisp_map_resolver_details_3e78fc57" => visitor.visit::<lisp::LispMapResolverDetails>(),
+		"lisp_map_resolver_dump_51077d14" => visitor.visit::<lisp::LispMapResolverDump>(),
+		"lisp_map_server_details_3e78fc57" => visitor.visit::<lisp::LispMapServerDetails>(),
+		"lisp_map_server_dump_51077d14" => visitor.visit::<lisp::LispMapServerDump>(),
+		"show_lisp_status_51077d14" => visitor.visit::<lisp::ShowLispStatus>(),
+		"show_lisp_status_reply_9e8f10c0" => visitor.visit::<lisp::ShowLispStatusReply>(),
+		"lisp_get_map_request_itr_rlocs_51077d14" => visitor.visit::<lisp::LispGetMapRequestItrRlocs>(),
+		"lisp_get_map_request_itr_rlocs_reply_76580f3a" => visitor.visit::<lisp::LispGetMapRequestItrRlocsReply>(),
+		"show_lisp_pitr_51077d14" => visitor.visit::<lisp::ShowLispPitr>(),
+		"show_lisp_pitr_reply_27aa69b1" => visitor.visit::<lisp::ShowLispPitrReply>(),
+		"nat66_plugin_enable_disable_56f2f83b" => visitor.visit::<nat66::Nat66PluginEnableDisable>(),
+		"nat66_plugin_enable_disable_reply_e8d4e804" => visitor.visit::<nat66::Nat66PluginEnableDisableReply>(),
+		"nat66_add_del_interface_f3699b83" => visitor.visit::<nat66::Nat66AddDelInterface>(),
+		"nat66_add_del_interface_reply_e8d4e804" => visitor.visit::<nat66::Nat66AddDelInterfaceReply>(),
+		"nat66_interface_dump_51077d14" => visitor.visit::<nat66::Nat66InterfaceDump>(),
+		"nat66_interface_details_5d286289" => visitor.visit::<nat66::Nat66InterfaceDetails>(),
+		"nat66_add_del_static_mapping_3ed88f71" => visitor.visit::<nat66::Nat66AddDelStaticMapping>(),
+		"nat66_add_del_static_mapping_reply_e8d4e804" => visitor.visit::<nat66::Nat66AddDelStaticMappingReply>(),
+		"nat66_static_mapping_dump_51077d14" => visitor.visit::<nat66::Nat66StaticMappingDump>(),
+		"nat66_static_mapping_details_df39654b" => visitor.visit::<nat66::Nat66StaticMappingDetails>(),
+		"urpf_update_cc274cd1" => visitor.visit::<urpf::UrpfUpdate>(),
+		"urpf_update_reply_e8d4e804" => visitor.visit::<urpf::UrpfUpdateReply>(),
+		"urpf_update_v2_b873d028" => visitor.visit::<urpf::UrpfUpdateV2>(),
+		"urpf_update_v2_reply_e8d4e804" => visitor.visit::<urpf::UrpfUpdateV2Reply>(),
+		"urpf_interface_dump_f9e6675e" => visitor.visit::<urpf::UrpfInterfaceDump>(),
+		"urpf_interface_details_f94b5374" => visitor.visit::<urpf::UrpfInterfaceDetails>(),
+		"acl_plugin_get_version_51077d14" => visitor.visit::<acl::AclPluginGetVersion>(),
+		"acl_plugin_get_version_reply_9b32cf86" => visitor.visit::<acl::AclPluginGetVersionReply>(),
+		"acl_plugin_control_ping_51077d14" => visitor.visit::<acl::AclPluginControlPing>(),
+		"acl_plugin_control_ping_reply_f6b0b8ca" => visitor.visit::<acl::AclPluginControlPingReply>(),
+		"acl_plugin_get_conn_table_max_entries_51077d14" => visitor.visit::<acl::AclPluginGetConnTableMaxEntries>(),
+		"acl_plugin_get_conn_table_max_entries_reply_7a096d3d" => visitor.visit::<acl::AclPluginGetConnTableMaxEntriesReply>(),
+		"acl_add_replace_ee5c2f18" => visitor.visit::<acl::AclAddReplace>(),
+		"acl_add_replace_reply_ac407b0c" => visitor.visit::<acl::AclAddReplaceReply>(),
+		"acl_del_ef34fea4" => visitor.visit::<acl::AclDel>(),
+		"acl_del_reply_e8d4e804" => visitor.visit::<acl::AclDelReply>(),
+		"acl_interface_add_del_4b54bebd" => visitor.visit::<acl::AclInterfaceAddDel>(),
+		"acl_interface_add_del_reply_e8d4e804" => visitor.visit::<acl::AclInterfaceAddDelReply>(),
+		"acl_interface_set_acl_list_473982bd" => visitor.visit::<acl::AclInterfaceSetAclList>(),
+		"acl_interface_set_acl_list_reply_e8d4e804" => visitor.visit::<acl::AclInterfaceSetAclListReply>(),
+		"acl_dump_ef34fea4" => visitor.visit::<acl::AclDump>(),
+		"acl_details_95babae0" => visitor.visit::<acl::AclDetails>(),
+		"acl_interface_list_dump_f9e6675e" => visitor.visit::<acl::AclInterfaceListDump>(),
+		"acl_interface_list_details_e695d256" => visitor.visit::<acl::AclInterfaceListDetails>(),
+		"macip_acl_add_ce6fbad0" => visitor.visit::<acl::MacipAclAdd>(),
+		"macip_acl_add_reply_ac407b0c" => visitor.visit::<acl::MacipAclAddReply>(),
+		"macip_acl_add_replace_2a461dd4" => visitor.visit::<acl::MacipAclAddReplace>(),
+		"macip_acl_add_replace_reply_ac407b0c" => visitor.visit::<acl::MacipAclAddReplaceReply>(),
+		"macip_acl_del_ef34fea4" => visitor.visit::<acl::MacipAclDel>(),
+		"macip_acl_del_reply_e8d4e804" => visitor.visit::<acl::MacipAclDelReply>(),
+		"macip_acl_interface_add_del_4b8690b1" => visitor.visit::<acl::MacipAclInterfaceAddDel>(),
+		"macip_acl_interface_add_del_reply_e8d4e804" => visitor.visit::<acl::MacipAclInterfaceAddDelReply>(),
+		"macip_acl_dump_ef34fea4" => visitor.visit::<acl::MacipAclDump>(),
+		"macip_acl_details_27135b59" => visitor.visit::<acl::MacipAclDetails>(),
+		"macip_acl_interface_get_51077d14" => visitor.visit::<acl::MacipAclInterfaceGet>(),
+		"macip_acl_interface_get_reply_accf9b05" => visitor.visit::<acl::MacipAclInterfaceGetReply>(),
+		"macip_acl_interface_list_dump_f9e6675e" => visitor.visit::<acl::MacipAclInterfaceListDump>(),
+		"macip_acl_interface_list_details_a0c5d56d" => visitor.visit::<acl::MacipAclInterfaceListDetails>(),
+		"acl_interface_set_etype_whitelist_3f5c2d2d" => visitor.visit::<acl::AclInterfaceSetEtypeWhitelist>(),
+		"acl_interface_set_etype_whitelist_reply_e8d4e804" => visitor.visit::<acl::AclInterfaceSetEtypeWhitelistReply>(),
+		"acl_interface_etype_whitelist_dump_f9e6675e" => visitor.visit::<acl::AclInterfaceEtypeWhitelistDump>(),
+		"acl_interface_etype_whitelist_details_cc2bfded" => visitor.visit::<acl::AclInterfaceEtypeWhitelistDetails>(),
+		"acl_stats_intf_counters_enable_b3e225d2" => visitor.visit::<acl::AclStatsIntfCountersEnable>(),
+		"acl_stats_intf_counters_enable_reply_e8d4e804" => visitor.visit::<acl::AclStatsIntfCountersEnableReply>(),
+		"acl_plugin_use_hash_lookup_set_b3e225d2" => visitor.visit::<acl::AclPluginUseHashLookupSet>(),
+		"acl_plugin_use_hash_lookup_set_reply_e8d4e804" => visitor.visit::<acl::AclPluginUseHashLookupSetReply>(),
+		"acl_plugin_use_hash_lookup_get_51077d14" => visitor.visit::<acl::AclPluginUseHashLookupGet>(),
+		"acl_plugin_use_hash_lookup_get_reply_5392ad31" => visitor.visit::<acl::AclPluginUseHashLookupGetReply>(),
+		"bpf_trace_filter_set_3171346e" => visitor.visit::<bpf_trace_filter::BpfTraceFilterSet>(),
+		"bpf_trace_filter_set_reply_e8d4e804" => visitor.visit::<bpf_trace_filter::BpfTraceFilterSetReply>(),
+		"bpf_trace_filter_set_v2_5615acbf" => visitor.visit::<bpf_trace_filter::BpfTraceFilterSetV2>(),
+		"bpf_trace_filter_set_v2_reply_e8d4e804" => visitor.visit::<bpf_trace_filter::BpfTraceFilterSetV2Reply>(),
+		"sw_interface_lacp_dump_51077d14" => visitor.visit::<lacp::SwInterfaceLacpDump>(),
+		"sw_interface_lacp_details_d9a83d2f" => visitor.visit::<lacp::SwInterfaceLacpDetails>(),
+		"mdata_enable_disable_2e7b47df" => visitor.visit::<mdata::MdataEnableDisable>(),
+		"mdata_enable_disable_reply_e8d4e804" => visitor.visit::<mdata::MdataEnableDisableReply>(),
+		"ikev2_plugin_get_version_51077d14" => visitor.visit::<ikev2::Ikev2PluginGetVersion>(),
+		"ikev2_plugin_get_version_reply_9b32cf86" => visitor.visit::<ikev2::Ikev2PluginGetVersionReply>(),
+		"ikev2_plugin_set_sleep_interval_b7c096ae" => visitor.visit::<ikev2::Ikev2PluginSetSleepInterval>(),
+		"ikev2_plugin_set_sleep_interval_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2PluginSetSleepIntervalReply>(),
+		"ikev2_get_sleep_interval_51077d14" => visitor.visit::<ikev2::Ikev2GetSleepInterval>(),
+		"ikev2_get_sleep_interval_reply_78ab91dc" => visitor.visit::<ikev2::Ikev2GetSleepIntervalReply>(),
+		"ikev2_profile_dump_51077d14" => visitor.visit::<ikev2::Ikev2ProfileDump>(),
+		"ikev2_profile_details_670d01d9" => visitor.visit::<ikev2::Ikev2ProfileDetails>(),
+		"ikev2_sa_dump_51077d14" => visitor.visit::<ikev2::Ikev2SaDump>(),
+		"ikev2_sa_v2_dump_51077d14" => visitor.visit::<ikev2::Ikev2SaV2Dump>(),
+		"ikev2_sa_v3_dump_51077d14" => visitor.visit::<ikev2::Ikev2SaV3Dump>(),
+		"ikev2_sa_details_937c22d5" => visitor.visit::<ikev2::Ikev2SaDetails>(),
+		"ikev2_sa_v2_details_a616e604" => visitor.visit::<ikev2::Ikev2SaV2Details>(),
+		"ikev2_sa_v3_details_85c9a941" => visitor.visit::<ikev2::Ikev2SaV3Details>(),
+		"ikev2_child_sa_dump_01eab609" => visitor.visit::<ikev2::Ikev2ChildSaDump>(),
+		"ikev2_child_sa_details_ff67741f" => visitor.visit::<ikev2::Ikev2ChildSaDetails>(),
+		"ikev2_child_sa_v2_dump_01eab609" => visitor.visit::<ikev2::Ikev2ChildSaV2Dump>(),
+		"ikev2_child_sa_v2_details_1db62aa2" => visitor.visit::<ikev2::Ikev2ChildSaV2Details>(),
+		"ikev2_nonce_get_7fe9ad51" => visitor.visit::<ikev2::Ikev2NonceGet>(),
+		"ikev2_nonce_get_reply_1b37a342" => visitor.visit::<ikev2::Ikev2NonceGetReply>(),
+		"ikev2_traffic_selector_dump_a7385e33" => visitor.visit::<ikev2::Ikev2TrafficSelectorDump>(),
+		"ikev2_traffic_selector_details_518cb06f" => visitor.visit::<ikev2::Ikev2TrafficSelectorDetails>(),
+		"ikev2_profile_add_del_2c925b55" => visitor.visit::<ikev2::Ikev2ProfileAddDel>(),
+		"ikev2_profile_add_del_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2ProfileAddDelReply>(),
+		"ikev2_profile_set_auth_642c97cd" => visitor.visit::<ikev2::Ikev2ProfileSetAuth>(),
+		"ikev2_profile_set_auth_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2ProfileSetAuthReply>(),
+		"ikev2_profile_set_id_4d7e2418" => visitor.visit::<ikev2::Ikev2ProfileSetId>(),
+		"ikev2_profile_set_id_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2ProfileSetIdReply>(),
+		"ikev2_profile_disable_natt_ebf79a66" => visitor.visit::<ikev2::Ikev2ProfileDisableNatt>(),
+		"ikev2_profile_disable_natt_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2ProfileDisableNattReply>(),
+		"ikev2_profile_set_ts_8eb8cfd1" => visitor.visit::<ikev2::Ikev2ProfileSetTs>(),
+		"ikev2_profile_set_ts_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2ProfileSetTsReply>(),
+		"ikev2_set_local_key_799b69ec" => visitor.visit::<ikev2::Ikev2SetLocalKey>(),
+		"ikev2_set_local_key_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2SetLocalKeyReply>(),
+		"ikev2_set_tunnel_interface_ca67182c" => visitor.visit::<ikev2::Ikev2SetTunnelInterface>(),
+		"ikev2_set_tunnel_interface_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2SetTunnelInterfaceReply>(),
+		"ikev2_set_responder_a2055df1" => visitor.visit::<ikev2::Ikev2SetResponder>(),
+		"ikev2_set_responder_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2SetResponderReply>(),
+		"ikev2_set_responder_hostname_350d6949" => visitor.visit::<ikev2::Ikev2SetResponderHostname>(),
+		"ikev2_set_responder_hostname_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2SetResponderHostnameReply>(),
+		"ikev2_set_ike_transforms_076d7378" => visitor.visit::<ikev2::Ikev2SetIkeTransforms>(),
+		"ikev2_set_ike_transforms_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2SetIkeTransformsReply>(),
+		"ikev2_set_esp_transforms_a63dc205" => visitor.visit::<ikev2::Ikev2SetEspTransforms>(),
+		"ikev2_set_esp_transforms_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2SetEspTransformsReply>(),
+		"ikev2_set_sa_lifetime_7039feaa" => visitor.visit::<ikev2::Ikev2SetSaLifetime>(),
+		"ikev2_set_sa_lifetime_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2SetSaLifetimeReply>(),
+		"ikev2_initiate_sa_init_ebf79a66" => visitor.visit::<ikev2::Ikev2InitiateSaInit>(),
+		"ikev2_initiate_sa_init_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2InitiateSaInitReply>(),
+		"ikev2_initiate_del_ike_sa_8d125bdd" => visitor.visit::<ikev2::Ikev2InitiateDelIkeSa>(),
+		"ikev2_initiate_del_ike_sa_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2InitiateDelIkeSaReply>(),
+		"ikev2_initiate_del_child_sa_7f004d2e" => visitor.visit::<ikev2::Ikev2InitiateDelChildSa>(),
+		"ikev2_initiate_del_child_sa_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2InitiateDelChildSaReply>(),
+		"ikev2_initiate_rekey_child_sa_7f004d2e" => visitor.visit::<ikev2::Ikev2InitiateRekeyChildSa>(),
+		"ikev2_initiate_rekey_child_sa_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2InitiateRekeyChildSaReply>(),
+		"ikev2_profile_set_udp_encap_ebf79a66" => visitor.visit::<ikev2::Ikev2ProfileSetUdpEncap>(),
+		"ikev2_profile_set_udp_encap_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2ProfileSetUdpEncapReply>(),
+		"ikev2_profile_set_ipsec_udp_port_615ce758" => visitor.visit::<ikev2::Ikev2ProfileSetIpsecUdpPort>(),
+		"ikev2_profile_set_ipsec_udp_port_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2ProfileSetIpsecUdpPortReply>(),
+		"ikev2_profile_set_liveness_6bdf4d65" => visitor.visit::<ikev2::Ikev2ProfileSetLiveness>(),
+		"ikev2_profile_set_liveness_reply_e8d4e804" => visitor.visit::<ikev2::Ikev2ProfileSetLivenessReply>(),
+		"ioam_cache_ip6_enable_disable_47705c03" => visitor.visit::<ioam_cache::IoamCacheIp6EnableDisable>(),
+		"ioam_cache_ip6_enable_disable_reply_e8d4e804" => visitor.visit::<ioam_cache::IoamCacheIp6EnableDisableReply>(),
+		"nat44_ed_plugin_enable_disable_be17f8dd" => visitor.visit::<nat44_ed::Nat44EdPluginEnableDisable>(),
+		"nat44_ed_plugin_enable_disable_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44EdPluginEnableDisableReply>(),
+		"nat44_forwarding_enable_disable_b3e225d2" => visitor.visit::<nat44_ed::Nat44ForwardingEnableDisable>(),
+		"nat44_forwarding_enable_disable_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44ForwardingEnableDisableReply>(),
+		"nat_ipfix_enable_disable_9af4a2d2" => visitor.visit::<nat44_ed::NatIpfixEnableDisable>(),
+		"nat_ipfix_enable_disable_reply_e8d4e804" => visitor.visit::<nat44_ed::NatIpfixEnableDisableReply>(),
+		"nat_set_timeouts_d4746b16" => visitor.visit::<nat44_ed::NatSetTimeouts>(),
+		"nat_set_timeouts_reply_e8d4e804" => visitor.visit::<nat44_ed::NatSetTimeoutsReply>(),
+		"nat44_set_session_limit_8899bbb1" => visitor.visit::<nat44_ed::Nat44SetSessionLimit>(),
+		"nat44_set_session_limit_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44SetSessionLimitReply>(),
+		"nat44_show_running_config_51077d14" => visitor.visit::<nat44_ed::Nat44ShowRunningConfig>(),
+		"nat44_show_running_config_reply_93d8e267" => visitor.visit::<nat44_ed::Nat44ShowRunningConfigReply>(),
+		"nat_set_workers_da926638" => visitor.visit::<nat44_ed::NatSetWorkers>(),
+		"nat_set_workers_reply_e8d4e804" => visitor.visit::<nat44_ed::NatSetWorkersReply>(),
+		"nat_worker_dump_51077d14" => visitor.visit::<nat44_ed::NatWorkerDump>(),
+		"nat_worker_details_84bf06fc" => visitor.visit::<nat44_ed::NatWorkerDetails>(),
+		"nat44_ed_add_del_vrf_table_08330904" => visitor.visit::<nat44_ed::Nat44EdAddDelVrfTable>(),
+		"nat44_ed_add_del_vrf_table_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44EdAddDelVrfTableReply>(),
+		"nat44_ed_add_del_vrf_route_59187407" => visitor.visit::<nat44_ed::Nat44EdAddDelVrfRoute>(),
+		"nat44_ed_add_del_vrf_route_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44EdAddDelVrfRouteReply>(),
+		"nat44_ed_vrf_tables_dump_51077d14" => visitor.visit::<nat44_ed::Nat44EdVrfTablesDump>(),
+		"nat44_ed_vrf_tables_details_7b264e4f" => visitor.visit::<nat44_ed::Nat44EdVrfTablesDetails>(),
+		"nat44_ed_vrf_tables_v2_dump_51077d14" => visitor.visit::<nat44_ed::Nat44EdVrfTablesV2Dump>(),
+		"nat44_ed_vrf_tables_v2_details_7b264e4f" => visitor.visit::<nat44_ed::Nat44EdVrfTablesV2Details>(),
+		"nat_set_mss_clamping_25e90abb" => visitor.visit::<nat44_ed::NatSetMssClamping>(),
+		"nat_set_mss_clamping_reply_e8d4e804" => visitor.visit::<nat44_ed::NatSetMssClampingReply>(),
+		"nat_get_mss_clamping_51077d14" => visitor.visit::<nat44_ed::NatGetMssClamping>(),
+		"nat_get_mss_clamping_reply_1c0b2a78" => visitor.visit::<nat44_ed::NatGetMssClampingReply>(),
+		"nat44_ed_set_fq_options_2399bd71" => visitor.visit::<nat44_ed::Nat44EdSetFqOptions>(),
+		"nat44_ed_set_fq_options_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44EdSetFqOptionsReply>(),
+		"nat44_ed_show_fq_options_51077d14" => visitor.visit::<nat44_ed::Nat44EdShowFqOptions>(),
+		"nat44_ed_show_fq_options_reply_7213b545" => visitor.visit::<nat44_ed::Nat44EdShowFqOptionsReply>(),
+		"nat44_add_del_interface_addr_4aed50c0" => visitor.visit::<nat44_ed::Nat44AddDelInterfaceAddr>(),
+		"nat44_add_del_interface_addr_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44AddDelInterfaceAddrReply>(),
+		"nat44_interface_addr_dump_51077d14" => visitor.visit::<nat44_ed::Nat44InterfaceAddrDump>(),
+		"nat44_interface_addr_details_e4aca9ca" => visitor.visit::<nat44_ed::Nat44InterfaceAddrDetails>(),
+		"nat44_add_del_address_range_6f2b8055" => visitor.visit::<nat44_ed::Nat44AddDelAddressRange>(),
+		"nat44_add_del_address_range_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44AddDelAddressRangeReply>(),
+		"nat44_address_dump_51077d14" => visitor.visit::<nat44_ed::Nat44AddressDump>(),
+		"nat44_address_details_0d1beac1" => visitor.visit::<nat44_ed::Nat44AddressDetails>(),
+		"nat44_interface_add_del_feature_f3699b83" => visitor.visit::<nat44_ed::Nat44InterfaceAddDelFeature>(),
+		"nat44_interface_add_del_feature_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44InterfaceAddDelFeatureReply>(),
+		"nat44_interface_dump_51077d14" => visitor.visit::<nat44_ed::Nat44InterfaceDump>(),
+		"nat44_interface_details_5d286289" => visitor.visit::<nat44_ed::Nat44InterfaceDetails>(),
+		"nat44_ed_add_del_output_interface_47d6e753" => visitor.visit::<nat44_ed::Nat44EdAddDelOutputInterface>(),
+		"nat44_ed_add_del_output_interface_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44EdAddDelOutputInterfaceReply>(),
+		"nat44_ed_output_interface_get_f75ba505" => visitor.visit::<nat44_ed::Nat44EdOutputInterfaceGet>(),
+		"nat44_ed_output_interface_get_reply_53b48f5d" => visitor.visit::<nat44_ed::Nat44EdOutputInterfaceGetReply>(),
+		"nat44_ed_output_interface_details_0b45011c" => visitor.visit::<nat44_ed::Nat44EdOutputInterfaceDetails>(),
+		"nat44_add_del_static_mapping_5ae5f03e" => visitor.visit::<nat44_ed::Nat44AddDelStaticMapping>(),
+		"nat44_add_del_static_mapping_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44AddDelStaticMappingReply>(),
+		"nat44_add_del_static_mapping_v2_5e205f1a" => visitor.visit::<nat44_ed::Nat44AddDelStaticMappingV2>(),
+		"nat44_add_del_static_mapping_v2_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44AddDelStaticMappingV2Reply>(),
+		"nat44_static_mapping_dump_51077d14" => visitor.visit::<nat44_ed::Nat44StaticMappingDump>(),
+		"nat44_static_mapping_details_06cb40b2" => visitor.visit::<nat44_ed::Nat44StaticMappingDetails>(),
+		"nat44_add_del_identity_mapping_02faaa22" => visitor.visit::<nat44_ed::Nat44AddDelIdentityMapping>(),
+		"nat44_add_del_identity_mapping_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44AddDelIdentityMappingReply>(),
+		"nat44_identity_mapping_dump_51077d14" => visitor.visit::<nat44_ed::Nat44IdentityMappingDump>(),
+		"nat44_identity_mapping_details_2a52a030" => visitor.visit::<nat44_ed::Nat44IdentityMappingDetails>(),
+		"nat44_add_del_lb_static_mapping_4f68ee9d" => visitor.visit::<nat44_ed::Nat44AddDelLbStaticMapping>(),
+		"nat44_add_del_lb_static_mapping_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44AddDelLbStaticMappingReply>(),
+		"nat44_lb_static_mapping_add_del_local_7ca47547" => visitor.visit::<nat44_ed::Nat44LbStaticMappingAddDelLocal>(),
+		"nat44_lb_static_mapping_add_del_local_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44LbStaticMappingAddDelLocalReply>(),
+		"nat44_lb_static_mapping_dump_51077d14" => visitor.visit::<nat44_ed::Nat44LbStaticMappingDump>(),
+		"nat44_lb_static_mapping_details_ed5ce876" => visitor.visit::<nat44_ed::Nat44LbStaticMappingDetails>(),
+		"nat44_del_session_15a5bf8c" => visitor.visit::<nat44_ed::Nat44DelSession>(),
+		"nat44_del_session_reply_e8d4e804" => visitor.visit::<nat44_ed::Nat44DelSessionReply>(),
+		"nat44_user_dump_51077d14" => visitor.visit::<nat44_ed::Nat44UserDump>(),
+		"nat44_user_details_355896c2" => visitor.visit::<nat44_ed::Nat44UserDetails>(),
+		"nat44_user_session_dump_e1899c98" => visitor.visit::<nat44_ed::Nat44UserSessionDump>(),
+		"nat44_user_session_details_2cf6e16d" => visitor.visit::<nat44_ed::Nat44UserSessionDetails>(),
+		"nat44_user_session_v2_dump_e1899c98" => visitor.visit::<nat44_ed::Nat44UserSessionV2Dump>(),
+		"nat44_user_session_v2_details_fd42b729" => visitor.visit::<nat44_ed::Nat44UserSessionV2Details>(),
+		"nat44_user_session_v3_details_edae926e" => visitor.visit::<nat44_ed::Nat44UserSessionV3Details>(),
+		"nat44_user_session_v3_dump_e1899c98" => visitor.visit::<nat44_ed::Nat44UserSessionV3Dump>(),
+		"pot_profile_add_ad5da3a3" => visitor.visit::<pot::PotProfileAdd>(),
+		"pot_profile_add_reply_e8d4e804" => visitor.visit::<pot::PotProfileAddReply>(),
+		"pot_profile_activate_0770af98" => visitor.visit::<pot::PotProfileActivate>(),
+		"pot_profile_activate_reply_e8d4e804" => visitor.visit::<pot::PotProfileActivateReply>(),
+		"pot_profile_del_cd63f53b" => visitor.visit::<pot::PotProfileDel>(),
+		"pot_profile_del_reply_e8d4e804" => visitor.visit::<pot::PotProfileDelReply>(),
+		"pot_profile_show_config_dump_005b7d59" => visitor.visit::<pot::PotProfileShowConfigDump>(),
+		"pot_profile_show_config_details_b7ce0618" => visitor.visit::<pot::PotProfileShowConfigDetails>(),
+		"rdma_create_076fe418" => visitor.visit::<rdma::RdmaCreate>(),
+		"rdma_create_v2_5826a4f3" => visitor.visit::<rdma::RdmaCreateV2>(),
+		"rdma_create_v3_c6287ea8" => visitor.visit::<rdma::RdmaCreateV3>(),
+		"rdma_create_v4_c6287ea8" => visitor.visit::<rdma::RdmaCreateV4>(),
+		"rdma_create_reply_5383d31f" => visitor.visit::<rdma::RdmaCreateReply>(),
+		"rdma_create_v2_reply_5383d31f" => visitor.visit::<rdma::RdmaCreateV2Reply>(),
+		"rdma_create_v3_reply_5383d31f" => visitor.visit::<rdma::RdmaCreateV3Reply>(),
+		"rdma_create_v4_reply_5383d31f" => visitor.visit::<rdma::RdmaCreateV4Reply>(),
+		"rdma_delete_f9e6675e" => visitor.visit::<rdma::RdmaDelete>(),
+		"rdma_delete_reply_e8d4e804" => visitor.visit::<rdma::RdmaDeleteReply>(),
+		"vrrp_vr_add_del_c5cf15aa" => visitor.visit::<vrrp::VrrpVrAddDel>(),
+		"vrrp_vr_add_del_reply_e8d4e804" => visitor.visit::<vrrp::VrrpVrAddDelReply>(),
+		"vrrp_vr_update_0b51e2f4" => visitor.visit::<vrrp::VrrpVrUpdate>(),
+		"vrrp_vr_update_reply_5317d608" => visitor.visit::<vrrp::VrrpVrUpdateReply>(),
+		"vrrp_vr_del_6029baa1" => visitor.visit::<vrrp::VrrpVrDel>(),
+		"vrrp_vr_del_reply_e8d4e804" => visitor.visit::<vrrp::VrrpVrDelReply>(),
+		"vrrp_vr_dump_f9e6675e" => visitor.visit::<vrrp::VrrpVrDump>(),
+		"vrrp_vr_details_46edcebd" => visitor.visit::<vrrp::VrrpVrDetails>(),
+		"vrrp_vr_start_stop_0662a3b7" => visitor.visit::<vrrp::VrrpVrStartStop>(),
+		"vrrp_vr_start_stop_reply_e8d4e804" => visitor.visit::<vrrp::VrrpVrStartStopReply>(),
+		"vrrp_vr_set_peers_20bec71f" => visitor.visit::<vrrp::VrrpVrSetPeers>(),
+		"vrrp_vr_set_peers_reply_e8d4e804" => visitor.visit::<vrrp::VrrpVrSetPeersReply>(),
+		"vrrp_vr_peer_dump_6fa3f7c4" => visitor.visit::<vrrp::VrrpVrPeerDump>(),
+		"vrrp_vr_peer_details_3d99c108" => visitor.visit::<vrrp::VrrpVrPeerDetails>(),
+		"vrrp_vr_track_if_add_del_d67df299" => visitor.visit::<vrrp::VrrpVrTrackIfAddDel>(),
+		"vrrp_vr_track_if_add_del_reply_e8d4e804" => visitor.visit::<vrrp::VrrpVrTrackIfAddDelReply>(),
+		"vrrp_vr_track_if_dump_a34dfc6d" => visitor.visit::<vrrp::VrrpVrTrackIfDump>(),
+		"vrrp_vr_track_if_details_73c36f81" => visitor.visit::<vrrp::VrrpVrTrackIfDetails>(),
+		"vrrp_vr_event_c1fea6a5" => visitor.visit::<vrrp::VrrpVrEvent>(),
+		"want_vrrp_vr_events_c5e2af94" => visitor.visit::<vrrp::WantVrrpVrEvents>(),
+		"want_vrrp_vr_events_reply_e8d4e804" => visitor.visit::<vrrp::WantVrrpVrEventsReply>(),
+		"svs_plugin_get_version_51077d14" => visitor.visit::<svs::SvsPluginGetVersion>(),
+		"svs_plugin_get_version_reply_9b32cf86" => visitor.visit::<svs::SvsPluginGetVersionReply>(),
+		"svs_table_add_del_7d21cb2a" => visitor.visit::<svs::SvsTableAddDel>(),
+		"svs_table_add_del_reply_e8d4e804" => visitor.visit::<svs::SvsTableAddDelReply>(),
+		"svs_route_add_del_e49bc63c" => visitor.visit::<svs::SvsRouteAddDel>(),
+		"svs_route_add_del_reply_e8d4e804" => visitor.visit::<svs::SvsRouteAddDelReply>(),
+		"svs_enable_disable_634b89d2" => visitor.visit::<svs::SvsEnableDisable>(),
+		"svs_enable_disable_reply_e8d4e804" => visitor.visit::<svs::SvsEnableDisableReply>(),
+		"svs_dump_51077d14" => visitor.visit::<svs::SvsDump>(),
+		"svs_details_6282cd55" => visitor.visit::<svs::SvsDetails>(),
+		"dns_enable_disable_8050327d" => visitor.visit::<dns::DnsEnableDisable>(),
+		"dns_enable_disable_reply_e8d4e804" => visitor.visit::<dns::DnsEnableDisableReply>(),
+		"dns_name_server_add_del_3bb05d8c" => visitor.visit::<dns::DnsNameServerAddDel>(),
+		"dns_name_server_add_del_reply_e8d4e804" => visitor.visit::<dns::DnsNameServerAddDelReply>(),
+		"dns_resolve_name_c6566676" => visitor.visit::<dns::DnsResolveName>(),
+		"dns_resolve_name_reply_c2d758c3" => visitor.visit::<dns::DnsResolveNameReply>(),
+		"dns_resolve_ip_ae96a1a3" => visitor.visit::<dns::DnsResolveIp>(),
+		"dns_resolve_ip_reply_49ed78d6" => visitor.visit::<dns::DnsResolveIpReply>(),
+		"wireguard_interface_create_a530137e" => visitor.visit::<wireguard::WireguardInterfaceCreate>(),
+		"wireguard_interface_create_reply_5383d31f" => visitor.visit::<wireguard::WireguardInterfaceCreateReply>(),
+		"wireguard_interface_delete_f9e6675e" => visitor.visit::<wireguard::WireguardInterfaceDelete>(),
+		"wireguard_interface_delete_reply_e8d4e804" => visitor.visit::<wireguard::WireguardInterfaceDeleteReply>(),
+		"wireguard_interface_dump_2c954158" => visitor.visit::<wireguard::WireguardInterfaceDump>(),
+		"wireguard_interface_details_0dd4865d" => visitor.visit::<wireguard::WireguardInterfaceDetails>(),
+		"want_wireguard_peer_events_3bc666c8" => visitor.visit::<wireguard::WantWireguardPeerEvents>(),
+		"want_wireguard_peer_events_reply_e8d4e804" => visitor.visit::<wireguard::WantWireguardPeerEventsReply>(),
+		"wireguard_peer_event_4e1b5d67" => visitor.visit::<wireguard::WireguardPeerEvent>(),
+		"wireguard_peer_add_9b8aad61" => visitor.visit::<wireguard::WireguardPeerAdd>(),
+		"wireguard_peer_add_reply_084a0cd3" => visitor.visit::<wireguard::WireguardPeerAddReply>(),
+		"wireguard_peer_remove_3b74607a" => visitor.visit::<wireguard::WireguardPeerRemove>(),
+		"wireguard_peer_remove_reply_e8d4e804" => visitor.visit::<wireguard::WireguardPeerRemoveReply>(),
+		"wireguard_peers_dump_3b74607a" => visitor.visit::<wireguard::WireguardPeersDump>(),
+		"wireguard_peers_details_6a9f6bc3" => visitor.visit::<wireguard::WireguardPeersDetails>(),
+		"wg_set_async_mode_a6465f7c" => visitor.visit::<wireguard::WgSetAsyncMode>(),
+		"wg_set_async_mode_reply_e8d4e804" => visitor.visit::<wireguard::WgSetAsyncModeReply>(),
+		"sr_mobile_localsid_add_del_b85a7ed7" => visitor.visit::<sr_mobile::SrMobileLocalsidAddDel>(),
+		"sr_mobile_localsid_add_del_reply_e8d4e804" => visitor.visit::<sr_mobile::SrMobileLocalsidAddDelReply>(),
+		"sr_mobile_policy_add_8f051658" => visitor.visit::<sr_mobile::SrMobilePolicyAdd>(),
+		"sr_mobile_policy_add_reply_e8d4e804" => visitor.visit::<sr_mobile::SrMobilePolicyAddReply>(),
+		"crypto_sw_scheduler_set_worker_b4274502" => visitor.visit::<crypto_sw_scheduler::CryptoSwSchedulerSetWorker>(),
+		"crypto_sw_scheduler_set_worker_reply_e8d4e804" => visitor.visit::<crypto_sw_scheduler::CryptoSwSchedulerSetWorkerReply>(),
+		"nat44_ei_plugin_enable_disable_bf692144" => visitor.visit::<nat44_ei::Nat44EiPluginEnableDisable>(),
+		"nat44_ei_plugin_enable_disable_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiPluginEnableDisableReply>(),
+		"nat44_ei_show_running_config_51077d14" => visitor.visit::<nat44_ei::Nat44EiShowRunningConfig>(),
+		"nat44_ei_show_running_config_reply_41b66a81" => visitor.visit::<nat44_ei::Nat44EiShowRunningConfigReply>(),
+		"nat44_ei_set_log_level_70076bfe" => visitor.visit::<nat44_ei::Nat44EiSetLogLevel>(),
+		"nat44_ei_set_log_level_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiSetLogLevelReply>(),
+		"nat44_ei_set_workers_da926638" => visitor.visit::<nat44_ei::Nat44EiSetWorkers>(),
+		"nat44_ei_set_workers_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiSetWorkersReply>(),
+		"nat44_ei_worker_dump_51077d14" => visitor.visit::<nat44_ei::Nat44EiWorkerDump>(),
+		"nat44_ei_worker_details_84bf06fc" => visitor.visit::<nat44_ei::Nat44EiWorkerDetails>(),
+		"nat44_ei_ipfix_enable_disable_9af4a2d2" => visitor.visit::<nat44_ei::Nat44EiIpfixEnableDisable>(),
+		"nat44_ei_ipfix_enable_disable_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiIpfixEnableDisableReply>(),
+		"nat44_ei_set_timeouts_d4746b16" => visitor.visit::<nat44_ei::Nat44EiSetTimeouts>(),
+		"nat44_ei_set_timeouts_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiSetTimeoutsReply>(),
+		"nat44_ei_set_addr_and_port_alloc_alg_deeb746f" => visitor.visit::<nat44_ei::Nat44EiSetAddrAndPortAllocAlg>(),
+		"nat44_ei_set_addr_and_port_alloc_alg_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiSetAddrAndPortAllocAlgReply>(),
+		"nat44_ei_get_addr_and_port_alloc_alg_51077d14" => visitor.visit::<nat44_ei::Nat44EiGetAddrAndPortAllocAlg>(),
+		"nat44_ei_get_addr_and_port_alloc_alg_reply_3607a7d0" => visitor.visit::<nat44_ei::Nat44EiGetAddrAndPortAllocAlgReply>(),
+		"nat44_ei_set_mss_clamping_25e90abb" => visitor.visit::<nat44_ei::Nat44EiSetMssClamping>(),
+		"nat44_ei_set_mss_clamping_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiSetMssClampingReply>(),
+		"nat44_ei_get_mss_clamping_51077d14" => visitor.visit::<nat44_ei::Nat44EiGetMssClamping>(),
+		"nat44_ei_get_mss_clamping_reply_1c0b2a78" => visitor.visit::<nat44_ei::Nat44EiGetMssClampingReply>(),
+		"nat44_ei_ha_set_listener_e4a8cb4e" => visitor.visit::<nat44_ei::Nat44EiHaSetListener>(),
+		"nat44_ei_ha_set_listener_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiHaSetListenerReply>(),
+		"nat44_ei_ha_set_failover_718246af" => visitor.visit::<nat44_ei::Nat44EiHaSetFailover>(),
+		"nat44_ei_ha_set_failover_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiHaSetFailoverReply>(),
+		"nat44_ei_ha_get_listener_51077d14" => visitor.visit::<nat44_ei::Nat44EiHaGetListener>(),
+		"nat44_ei_ha_get_listener_reply_123ea41f" => visitor.visit::<nat44_ei::Nat44EiHaGetListenerReply>(),
+		"nat44_ei_ha_get_failover_51077d14" => visitor.visit::<nat44_ei::Nat44EiHaGetFailover>(),
+		"nat44_ei_ha_get_failover_reply_a67d8752" => visitor.visit::<nat44_ei::Nat44EiHaGetFailoverReply>(),
+		"nat44_ei_ha_flush_51077d14" => visitor.visit::<nat44_ei::Nat44EiHaFlush>(),
+		"nat44_ei_ha_flush_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiHaFlushReply>(),
+		"nat44_ei_ha_resync_c8ab9e03" => visitor.visit::<nat44_ei::Nat44EiHaResync>(),
+		"nat44_ei_ha_resync_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiHaResyncReply>(),
+		"nat44_ei_ha_resync_completed_event_fdc598fb" => visitor.visit::<nat44_ei::Nat44EiHaResyncCompletedEvent>(),
+		"nat44_ei_del_user_99a9f998" => visitor.visit::<nat44_ei::Nat44EiDelUser>(),
+		"nat44_ei_del_user_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiDelUserReply>(),
+		"nat44_ei_add_del_address_range_35f21abc" => visitor.visit::<nat44_ei::Nat44EiAddDelAddressRange>(),
+		"nat44_ei_add_del_address_range_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiAddDelAddressRangeReply>(),
+		"nat44_ei_address_dump_51077d14" => visitor.visit::<nat44_ei::Nat44EiAddressDump>(),
+		"nat44_ei_address_details_318f1202" => visitor.visit::<nat44_ei::Nat44EiAddressDetails>(),
+		"nat44_ei_interface_add_del_feature_63a2db8b" => visitor.visit::<nat44_ei::Nat44EiInterfaceAddDelFeature>(),
+		"nat44_ei_interface_add_del_feature_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiInterfaceAddDelFeatureReply>(),
+		"nat44_ei_interface_dump_51077d14" => visitor.visit::<nat44_ei::Nat44EiInterfaceDump>(),
+		"nat44_ei_interface_details_f446e508" => visitor.visit::<nat44_ei::Nat44EiInterfaceDetails>(),
+		"nat44_ei_interface_add_del_output_feature_63a2db8b" => visitor.visit::<nat44_ei::Nat44EiInterfaceAddDelOutputFeature>(),
+		"nat44_ei_interface_add_del_output_feature_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiInterfaceAddDelOutputFeatureReply>(),
+		"nat44_ei_interface_output_feature_dump_51077d14" => visitor.visit::<nat44_ei::Nat44EiInterfaceOutputFeatureDump>(),
+		"nat44_ei_interface_output_feature_details_f446e508" => visitor.visit::<nat44_ei::Nat44EiInterfaceOutputFeatureDetails>(),
+		"nat44_ei_add_del_output_interface_47d6e753" => visitor.visit::<nat44_ei::Nat44EiAddDelOutputInterface>(),
+		"nat44_ei_add_del_output_interface_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiAddDelOutputInterfaceReply>(),
+		"nat44_ei_output_interface_get_f75ba505" => visitor.visit::<nat44_ei::Nat44EiOutputInterfaceGet>(),
+		"nat44_ei_output_interface_get_reply_53b48f5d" => visitor.visit::<nat44_ei::Nat44EiOutputInterfaceGetReply>(),
+		"nat44_ei_output_interface_details_0b45011c" => visitor.visit::<nat44_ei::Nat44EiOutputInterfaceDetails>(),
+		"nat44_ei_add_del_static_mapping_b404b7fe" => visitor.visit::<nat44_ei::Nat44EiAddDelStaticMapping>(),
+		"nat44_ei_add_del_static_mapping_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiAddDelStaticMappingReply>(),
+		"nat44_ei_static_mapping_dump_51077d14" => visitor.visit::<nat44_ei::Nat44EiStaticMappingDump>(),
+		"nat44_ei_static_mapping_details_6b51ca6e" => visitor.visit::<nat44_ei::Nat44EiStaticMappingDetails>(),
+		"nat44_ei_add_del_identity_mapping_cb8606b9" => visitor.visit::<nat44_ei::Nat44EiAddDelIdentityMapping>(),
+		"nat44_ei_add_del_identity_mapping_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiAddDelIdentityMappingReply>(),
+		"nat44_ei_identity_mapping_dump_51077d14" => visitor.visit::<nat44_ei::Nat44EiIdentityMappingDump>(),
+		"nat44_ei_identity_mapping_details_30d53e26" => visitor.visit::<nat44_ei::Nat44EiIdentityMappingDetails>(),
+		"nat44_ei_add_del_interface_addr_883abbcc" => visitor.visit::<nat44_ei::Nat44EiAddDelInterfaceAddr>(),
+		"nat44_ei_add_del_interface_addr_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiAddDelInterfaceAddrReply>(),
+		"nat44_ei_interface_addr_dump_51077d14" => visitor.visit::<nat44_ei::Nat44EiInterfaceAddrDump>(),
+		"nat44_ei_interface_addr_details_0b45011c" => visitor.visit::<nat44_ei::Nat44EiInterfaceAddrDetails>(),
+		"nat44_ei_user_dump_51077d14" => visitor.visit::<nat44_ei::Nat44EiUserDump>(),
+		"nat44_ei_user_details_355896c2" => visitor.visit::<nat44_ei::Nat44EiUserDetails>(),
+		"nat44_ei_user_session_dump_e1899c98" => visitor.visit::<nat44_ei::Nat44EiUserSessionDump>(),
+		"nat44_ei_user_session_details_19b7c0ac" => visitor.visit::<nat44_ei::Nat44EiUserSessionDetails>(),
+		"nat44_ei_user_session_v2_dump_e1899c98" => visitor.visit::<nat44_ei::Nat44EiUserSessionV2Dump>(),
+		"nat44_ei_user_session_v2_details_5bd3e9d6" => visitor.visit::<nat44_ei::Nat44EiUserSessionV2Details>(),
+		"nat44_ei_del_session_74969ffe" => visitor.visit::<nat44_ei::Nat44EiDelSession>(),
+		"nat44_ei_del_session_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiDelSessionReply>(),
+		"nat44_ei_forwarding_enable_disable_b3e225d2" => visitor.visit::<nat44_ei::Nat44EiForwardingEnableDisable>(),
+		"nat44_ei_forwarding_enable_disable_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiForwardingEnableDisableReply>(),
+		"nat44_ei_set_fq_options_2399bd71" => visitor.visit::<nat44_ei::Nat44EiSetFqOptions>(),
+		"nat44_ei_set_fq_options_reply_e8d4e804" => visitor.visit::<nat44_ei::Nat44EiSetFqOptionsReply>(),
+		"nat44_ei_show_fq_options_51077d14" => visitor.visit::<nat44_ei::Nat44EiShowFqOptions>(),
+		"nat44_ei_show_fq_options_reply_7213b545" => visitor.visit::<nat44_ei::Nat44EiShowFqOptionsReply>(),
+		_ => return None,
+	};
+	Some(output)
+}
+
+pub use vpp_api_transport::VppApiError;
@@ -2,26 +2,57 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum MssClampDir {
 	 MSS_CLAMP_DIR_NONE=0,
 	 MSS_CLAMP_DIR_RX=1,
 	 MSS_CLAMP_DIR_TX=2,
+	 Unknown(u32),
 }
 impl Default for MssClampDir {
 	fn default() -> Self { MssClampDir::MSS_CLAMP_DIR_NONE }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for MssClampDir {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 MssClampDir::MSS_CLAMP_DIR_NONE => 0,
+			 MssClampDir::MSS_CLAMP_DIR_RX => 1,
+			 MssClampDir::MSS_CLAMP_DIR_TX => 2,
+			 MssClampDir::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(MssClampDir::MSS_CLAMP_DIR_NONE),
+			 1 => Some(MssClampDir::MSS_CLAMP_DIR_RX),
+			 2 => Some(MssClampDir::MSS_CLAMP_DIR_TX),
+			 value => Some(MssClampDir::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for MssClampDir {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("MssClampDir value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for MssClampDir {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(MssClampDir::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mss_clamp_enable_disable_d31b44e3)]
 pub struct MssClampEnableDisable {
 	pub client_index: u32,
@@ -32,13 +63,13 @@ pub struct MssClampEnableDisable {
 	pub ipv4_direction: MssClampDir,
 	pub ipv6_direction: MssClampDir,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mss_clamp_enable_disable_reply_e8d4e804)]
 pub struct MssClampEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mss_clamp_get_47250981)]
 pub struct MssClampGet {
 	pub client_index: u32,
@@ -46,14 +77,14 @@ pub struct MssClampGet {
 	pub cursor: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mss_clamp_get_reply_53b48f5d)]
 pub struct MssClampGetReply {
 	pub context: u32,
 	pub retval: i32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mss_clamp_details_d3a4de61)]
 pub struct MssClampDetails {
 	pub context: u32,
@@ -63,3 +94,11 @@ pub struct MssClampDetails {
 	pub ipv4_direction: MssClampDir,
 	pub ipv6_direction: MssClampDir,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"mss_clamp_enable_disable_d31b44e3",
+	"mss_clamp_enable_disable_reply_e8d4e804",
+	"mss_clamp_get_47250981",
+	"mss_clamp_get_reply_53b48f5d",
+	"mss_clamp_details_d3a4de61",
+];
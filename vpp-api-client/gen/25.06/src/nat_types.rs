@@ -2,23 +2,22 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 // Implementation for nat_timeouts
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct NatTimeouts {
 	pub udp: u32,
 	pub tcp_established: u32,
 	pub tcp_transitory: u32,
 	pub icmp: u32,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum NatLogLevel {
 	 NAT_LOG_NONE=0,
@@ -27,11 +26,50 @@ pub enum NatLogLevel {
 	 NAT_LOG_NOTICE=3,
 	 NAT_LOG_INFO=4,
 	 NAT_LOG_DEBUG=5,
+	 Unknown(u32),
 }
 impl Default for NatLogLevel {
 	fn default() -> Self { NatLogLevel::NAT_LOG_NONE }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+impl AsU32 for NatLogLevel {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 NatLogLevel::NAT_LOG_NONE => 0,
+			 NatLogLevel::NAT_LOG_ERROR => 1,
+			 NatLogLevel::NAT_LOG_WARNING => 2,
+			 NatLogLevel::NAT_LOG_NOTICE => 3,
+			 NatLogLevel::NAT_LOG_INFO => 4,
+			 NatLogLevel::NAT_LOG_DEBUG => 5,
+			 NatLogLevel::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(NatLogLevel::NAT_LOG_NONE),
+			 1 => Some(NatLogLevel::NAT_LOG_ERROR),
+			 2 => Some(NatLogLevel::NAT_LOG_WARNING),
+			 3 => Some(NatLogLevel::NAT_LOG_NOTICE),
+			 4 => Some(NatLogLevel::NAT_LOG_INFO),
+			 5 => Some(NatLogLevel::NAT_LOG_DEBUG),
+			 value => Some(NatLogLevel::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for NatLogLevel {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("NatLogLevel value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for NatLogLevel {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(NatLogLevel::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u8)]
 pub enum NatConfigFlags {
 	 NAT_IS_NONE=0,
 	 NAT_IS_TWICE_NAT=1,
@@ -42,29 +80,74 @@ pub enum NatConfigFlags {
 	 NAT_IS_INSIDE=32,
 	 NAT_IS_STATIC=64,
 	 NAT_IS_EXT_HOST_VALID=128,
+	 Unknown(u32),
 }
 impl Default for NatConfigFlags {
 	fn default() -> Self { NatConfigFlags::NAT_IS_NONE }
 }
-impl AsEnumFlag for NatConfigFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for NatConfigFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 NatConfigFlags::NAT_IS_NONE => 0,
+			 NatConfigFlags::NAT_IS_TWICE_NAT => 1,
+			 NatConfigFlags::NAT_IS_SELF_TWICE_NAT => 2,
+			 NatConfigFlags::NAT_IS_OUT2IN_ONLY => 4,
+			 NatConfigFlags::NAT_IS_ADDR_ONLY => 8,
+			 NatConfigFlags::NAT_IS_OUTSIDE => 16,
+			 NatConfigFlags::NAT_IS_INSIDE => 32,
+			 NatConfigFlags::NAT_IS_STATIC => 64,
+			 NatConfigFlags::NAT_IS_EXT_HOST_VALID => 128,
+			 NatConfigFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 0 => NatConfigFlags::NAT_IS_NONE,
-			 1 => NatConfigFlags::NAT_IS_TWICE_NAT,
-			 2 => NatConfigFlags::NAT_IS_SELF_TWICE_NAT,
-			 4 => NatConfigFlags::NAT_IS_OUT2IN_ONLY,
-			 8 => NatConfigFlags::NAT_IS_ADDR_ONLY,
-			 16 => NatConfigFlags::NAT_IS_OUTSIDE,
-			 32 => NatConfigFlags::NAT_IS_INSIDE,
-			 64 => NatConfigFlags::NAT_IS_STATIC,
-			 128 => NatConfigFlags::NAT_IS_EXT_HOST_VALID,
-			_ => panic!("Invalid Enum Descriminant")
+			 0 => Some(NatConfigFlags::NAT_IS_NONE),
+			 1 => Some(NatConfigFlags::NAT_IS_TWICE_NAT),
+			 2 => Some(NatConfigFlags::NAT_IS_SELF_TWICE_NAT),
+			 4 => Some(NatConfigFlags::NAT_IS_OUT2IN_ONLY),
+			 8 => Some(NatConfigFlags::NAT_IS_ADDR_ONLY),
+			 16 => Some(NatConfigFlags::NAT_IS_OUTSIDE),
+			 32 => Some(NatConfigFlags::NAT_IS_INSIDE),
+			 64 => Some(NatConfigFlags::NAT_IS_STATIC),
+			 128 => Some(NatConfigFlags::NAT_IS_EXT_HOST_VALID),
+			 value => Some(NatConfigFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for NatConfigFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("NatConfigFlags value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for NatConfigFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(NatConfigFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for NatConfigFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 8 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0xff
+	}
+}
+impl std::ops::BitOr for NatConfigFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
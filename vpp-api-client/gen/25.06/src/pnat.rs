@@ -2,18 +2,17 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 // Implementation for pnat_match_tuple
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct PnatMatchTuple {
 	pub src: Ip4Address,
 	pub dst: Ip4Address,
@@ -23,7 +22,7 @@ pub struct PnatMatchTuple {
 	pub mask: PnatMask,
 }
 // Implementation for pnat_rewrite_tuple
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct PnatRewriteTuple {
 	pub src: Ip4Address,
 	pub dst: Ip4Address,
@@ -34,7 +33,8 @@ pub struct PnatRewriteTuple {
 	pub to_offset: u8,
 	pub clear_offset: u8,
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum PnatMask {
 	 PNAT_SA=1,
 	 PNAT_DA=2,
@@ -43,41 +43,111 @@ pub enum PnatMask {
 	 PNAT_COPY_BYTE=16,
 	 PNAT_CLEAR_BYTE=32,
 	 PNAT_PROTO=64,
+	 Unknown(u32),
 }
 impl Default for PnatMask {
 	fn default() -> Self { PnatMask::PNAT_SA }
 }
-impl AsEnumFlag for PnatMask {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for PnatMask {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 PnatMask::PNAT_SA => 1,
+			 PnatMask::PNAT_DA => 2,
+			 PnatMask::PNAT_SPORT => 4,
+			 PnatMask::PNAT_DPORT => 8,
+			 PnatMask::PNAT_COPY_BYTE => 16,
+			 PnatMask::PNAT_CLEAR_BYTE => 32,
+			 PnatMask::PNAT_PROTO => 64,
+			 PnatMask::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => PnatMask::PNAT_SA,
-			 2 => PnatMask::PNAT_DA,
-			 4 => PnatMask::PNAT_SPORT,
-			 8 => PnatMask::PNAT_DPORT,
-			 16 => PnatMask::PNAT_COPY_BYTE,
-			 32 => PnatMask::PNAT_CLEAR_BYTE,
-			 64 => PnatMask::PNAT_PROTO,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(PnatMask::PNAT_SA),
+			 2 => Some(PnatMask::PNAT_DA),
+			 4 => Some(PnatMask::PNAT_SPORT),
+			 8 => Some(PnatMask::PNAT_DPORT),
+			 16 => Some(PnatMask::PNAT_COPY_BYTE),
+			 32 => Some(PnatMask::PNAT_CLEAR_BYTE),
+			 64 => Some(PnatMask::PNAT_PROTO),
+			 value => Some(PnatMask::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for PnatMask {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for PnatMask {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(PnatMask::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for PnatMask {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x7f
+	}
+}
+impl std::ops::BitOr for PnatMask {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum PnatAttachmentPoint {
 	 PNAT_IP4_INPUT=0,
 	 PNAT_IP4_OUTPUT=1,
 	 PNAT_ATTACHMENT_POINT_MAX=2,
+	 Unknown(u32),
 }
 impl Default for PnatAttachmentPoint {
 	fn default() -> Self { PnatAttachmentPoint::PNAT_IP4_INPUT }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for PnatAttachmentPoint {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 PnatAttachmentPoint::PNAT_IP4_INPUT => 0,
+			 PnatAttachmentPoint::PNAT_IP4_OUTPUT => 1,
+			 PnatAttachmentPoint::PNAT_ATTACHMENT_POINT_MAX => 2,
+			 PnatAttachmentPoint::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(PnatAttachmentPoint::PNAT_IP4_INPUT),
+			 1 => Some(PnatAttachmentPoint::PNAT_IP4_OUTPUT),
+			 2 => Some(PnatAttachmentPoint::PNAT_ATTACHMENT_POINT_MAX),
+			 value => Some(PnatAttachmentPoint::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for PnatAttachmentPoint {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for PnatAttachmentPoint {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(PnatAttachmentPoint::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_binding_add_946ee0b7)]
 pub struct PnatBindingAdd {
 	pub client_index: u32,
@@ -85,14 +155,14 @@ pub struct PnatBindingAdd {
 	pub mach: PnatMatchTuple,
 	pub rewrite: PnatRewriteTuple,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_binding_add_reply_4cd980a7)]
 pub struct PnatBindingAddReply {
 	pub context: u32,
 	pub retval: i32,
 	pub binding_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_binding_add_v2_946ee0b7)]
 pub struct PnatBindingAddV2 {
 	pub client_index: u32,
@@ -100,27 +170,27 @@ pub struct PnatBindingAddV2 {
 	pub mach: PnatMatchTuple,
 	pub rewrite: PnatRewriteTuple,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_binding_add_v2_reply_4cd980a7)]
 pub struct PnatBindingAddV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub binding_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_binding_del_9259df7b)]
 pub struct PnatBindingDel {
 	pub client_index: u32,
 	pub context: u32,
 	pub binding_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_binding_del_reply_e8d4e804)]
 pub struct PnatBindingDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_binding_attach_6e074232)]
 pub struct PnatBindingAttach {
 	pub client_index: u32,
@@ -129,13 +199,13 @@ pub struct PnatBindingAttach {
 	pub attachment: PnatAttachmentPoint,
 	pub binding_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_binding_attach_reply_e8d4e804)]
 pub struct PnatBindingAttachReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_binding_detach_6e074232)]
 pub struct PnatBindingDetach {
 	pub client_index: u32,
@@ -144,48 +214,48 @@ pub struct PnatBindingDetach {
 	pub attachment: PnatAttachmentPoint,
 	pub binding_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_binding_detach_reply_e8d4e804)]
 pub struct PnatBindingDetachReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_bindings_get_f75ba505)]
 pub struct PnatBindingsGet {
 	pub client_index: u32,
 	pub context: u32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_bindings_get_reply_53b48f5d)]
 pub struct PnatBindingsGetReply {
 	pub context: u32,
 	pub retval: i32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_bindings_details_08fb2815)]
 pub struct PnatBindingsDetails {
 	pub context: u32,
 	pub mach: PnatMatchTuple,
 	pub rewrite: PnatRewriteTuple,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_interfaces_get_f75ba505)]
 pub struct PnatInterfacesGet {
 	pub client_index: u32,
 	pub context: u32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_interfaces_get_reply_53b48f5d)]
 pub struct PnatInterfacesGetReply {
 	pub context: u32,
 	pub retval: i32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_interfaces_details_4cb09493)]
 pub struct PnatInterfacesDetails {
 	pub context: u32,
@@ -193,7 +263,7 @@ pub struct PnatInterfacesDetails {
 	pub enabled: FixedSizeArray<bool, typenum::U2>,
 	pub lookup_mask: FixedSizeArray<PnatMask, typenum::U2>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_flow_lookup_1ef8747c)]
 pub struct PnatFlowLookup {
 	pub client_index: u32,
@@ -202,10 +272,31 @@ pub struct PnatFlowLookup {
 	pub attachment: PnatAttachmentPoint,
 	pub mach: PnatMatchTuple,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pnat_flow_lookup_reply_4cd980a7)]
 pub struct PnatFlowLookupReply {
 	pub context: u32,
 	pub retval: i32,
 	pub binding_index: u32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"pnat_binding_add_946ee0b7",
+	"pnat_binding_add_reply_4cd980a7",
+	"pnat_binding_add_v2_946ee0b7",
+	"pnat_binding_add_v2_reply_4cd980a7",
+	"pnat_binding_del_9259df7b",
+	"pnat_binding_del_reply_e8d4e804",
+	"pnat_binding_attach_6e074232",
+	"pnat_binding_attach_reply_e8d4e804",
+	"pnat_binding_detach_6e074232",
+	"pnat_binding_detach_reply_e8d4e804",
+	"pnat_bindings_get_f75ba505",
+	"pnat_bindings_get_reply_53b48f5d",
+	"pnat_bindings_details_08fb2815",
+	"pnat_interfaces_get_f75ba505",
+	"pnat_interfaces_get_reply_53b48f5d",
+	"pnat_interfaces_details_4cb09493",
+	"pnat_flow_lookup_1ef8747c",
+	"pnat_flow_lookup_reply_4cd980a7",
+];
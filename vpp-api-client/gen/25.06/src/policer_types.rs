@@ -2,22 +2,21 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 // Implementation for sse2_qos_action
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Sse2QosAction {
 	pub typ: Sse2QosActionType,
 	pub dscp: u8,
 }
 // Implementation for policer_config
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct PolicerConfig {
 	pub cir: u32,
 	pub eir: u32,
@@ -31,28 +30,94 @@ pub struct PolicerConfig {
 	pub exceed_action: Sse2QosAction,
 	pub violate_action: Sse2QosAction,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum Sse2QosRateType {
 	 SSE2_QOS_RATE_API_KBPS=0,
 	 SSE2_QOS_RATE_API_PPS=1,
 	 SSE2_QOS_RATE_API_INVALID=2,
+	 Unknown(u32),
 }
 impl Default for Sse2QosRateType {
 	fn default() -> Self { Sse2QosRateType::SSE2_QOS_RATE_API_KBPS }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for Sse2QosRateType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 Sse2QosRateType::SSE2_QOS_RATE_API_KBPS => 0,
+			 Sse2QosRateType::SSE2_QOS_RATE_API_PPS => 1,
+			 Sse2QosRateType::SSE2_QOS_RATE_API_INVALID => 2,
+			 Sse2QosRateType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(Sse2QosRateType::SSE2_QOS_RATE_API_KBPS),
+			 1 => Some(Sse2QosRateType::SSE2_QOS_RATE_API_PPS),
+			 2 => Some(Sse2QosRateType::SSE2_QOS_RATE_API_INVALID),
+			 value => Some(Sse2QosRateType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for Sse2QosRateType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("Sse2QosRateType value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for Sse2QosRateType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(Sse2QosRateType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum Sse2QosRoundType {
 	 SSE2_QOS_ROUND_API_TO_CLOSEST=0,
 	 SSE2_QOS_ROUND_API_TO_UP=1,
 	 SSE2_QOS_ROUND_API_TO_DOWN=2,
 	 SSE2_QOS_ROUND_API_INVALID=3,
+	 Unknown(u32),
 }
 impl Default for Sse2QosRoundType {
 	fn default() -> Self { Sse2QosRoundType::SSE2_QOS_ROUND_API_TO_CLOSEST }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for Sse2QosRoundType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 Sse2QosRoundType::SSE2_QOS_ROUND_API_TO_CLOSEST => 0,
+			 Sse2QosRoundType::SSE2_QOS_ROUND_API_TO_UP => 1,
+			 Sse2QosRoundType::SSE2_QOS_ROUND_API_TO_DOWN => 2,
+			 Sse2QosRoundType::SSE2_QOS_ROUND_API_INVALID => 3,
+			 Sse2QosRoundType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(Sse2QosRoundType::SSE2_QOS_ROUND_API_TO_CLOSEST),
+			 1 => Some(Sse2QosRoundType::SSE2_QOS_ROUND_API_TO_UP),
+			 2 => Some(Sse2QosRoundType::SSE2_QOS_ROUND_API_TO_DOWN),
+			 3 => Some(Sse2QosRoundType::SSE2_QOS_ROUND_API_INVALID),
+			 value => Some(Sse2QosRoundType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for Sse2QosRoundType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("Sse2QosRoundType value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for Sse2QosRoundType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(Sse2QosRoundType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum Sse2QosPolicerType {
 	 SSE2_QOS_POLICER_TYPE_API_1R2C=0,
@@ -61,17 +126,90 @@ pub enum Sse2QosPolicerType {
 	 SSE2_QOS_POLICER_TYPE_API_2R3C_RFC_4115=3,
 	 SSE2_QOS_POLICER_TYPE_API_2R3C_RFC_MEF5CF1=4,
 	 SSE2_QOS_POLICER_TYPE_API_MAX=5,
+	 Unknown(u32),
 }
 impl Default for Sse2QosPolicerType {
 	fn default() -> Self { Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_1R2C }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for Sse2QosPolicerType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_1R2C => 0,
+			 Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_1R3C_RFC_2697 => 1,
+			 Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_2R3C_RFC_2698 => 2,
+			 Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_2R3C_RFC_4115 => 3,
+			 Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_2R3C_RFC_MEF5CF1 => 4,
+			 Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_MAX => 5,
+			 Sse2QosPolicerType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_1R2C),
+			 1 => Some(Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_1R3C_RFC_2697),
+			 2 => Some(Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_2R3C_RFC_2698),
+			 3 => Some(Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_2R3C_RFC_4115),
+			 4 => Some(Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_2R3C_RFC_MEF5CF1),
+			 5 => Some(Sse2QosPolicerType::SSE2_QOS_POLICER_TYPE_API_MAX),
+			 value => Some(Sse2QosPolicerType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for Sse2QosPolicerType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("Sse2QosPolicerType value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for Sse2QosPolicerType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(Sse2QosPolicerType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum Sse2QosActionType {
 	 SSE2_QOS_ACTION_API_DROP=0,
 	 SSE2_QOS_ACTION_API_TRANSMIT=1,
 	 SSE2_QOS_ACTION_API_MARK_AND_TRANSMIT=2,
+	 Unknown(u32),
 }
 impl Default for Sse2QosActionType {
 	fn default() -> Self { Sse2QosActionType::SSE2_QOS_ACTION_API_DROP }
 }
+impl AsU32 for Sse2QosActionType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 Sse2QosActionType::SSE2_QOS_ACTION_API_DROP => 0,
+			 Sse2QosActionType::SSE2_QOS_ACTION_API_TRANSMIT => 1,
+			 Sse2QosActionType::SSE2_QOS_ACTION_API_MARK_AND_TRANSMIT => 2,
+			 Sse2QosActionType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(Sse2QosActionType::SSE2_QOS_ACTION_API_DROP),
+			 1 => Some(Sse2QosActionType::SSE2_QOS_ACTION_API_TRANSMIT),
+			 2 => Some(Sse2QosActionType::SSE2_QOS_ACTION_API_MARK_AND_TRANSMIT),
+			 value => Some(Sse2QosActionType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for Sse2QosActionType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("Sse2QosActionType value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for Sse2QosActionType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(Sse2QosActionType::Unknown(value)))
+	 }
+}
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
@@ -2,15 +2,14 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pot_profile_add_ad5da3a3)]
 pub struct PotProfileAdd {
 	pub client_index: u32,
@@ -25,13 +24,13 @@ pub struct PotProfileAdd {
 	pub polynomial_public: u64,
 	pub list_name: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pot_profile_add_reply_e8d4e804)]
 pub struct PotProfileAddReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pot_profile_activate_0770af98)]
 pub struct PotProfileActivate {
 	pub client_index: u32,
@@ -39,33 +38,33 @@ pub struct PotProfileActivate {
 	pub id: u8,
 	pub list_name: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pot_profile_activate_reply_e8d4e804)]
 pub struct PotProfileActivateReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pot_profile_del_cd63f53b)]
 pub struct PotProfileDel {
 	pub client_index: u32,
 	pub context: u32,
 	pub list_name: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pot_profile_del_reply_e8d4e804)]
 pub struct PotProfileDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pot_profile_show_config_dump_005b7d59)]
 pub struct PotProfileShowConfigDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub id: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pot_profile_show_config_details_b7ce0618)]
 pub struct PotProfileShowConfigDetails {
 	pub context: u32,
@@ -79,3 +78,14 @@ pub struct PotProfileShowConfigDetails {
 	pub lpc: u64,
 	pub polynomial_public: u64,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"pot_profile_add_ad5da3a3",
+	"pot_profile_add_reply_e8d4e804",
+	"pot_profile_activate_0770af98",
+	"pot_profile_activate_reply_e8d4e804",
+	"pot_profile_del_cd63f53b",
+	"pot_profile_del_reply_e8d4e804",
+	"pot_profile_show_config_dump_005b7d59",
+	"pot_profile_show_config_details_b7ce0618",
+];
@@ -2,48 +2,144 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum RdmaMode {
 	 RDMA_API_MODE_AUTO=0,
 	 RDMA_API_MODE_IBV=1,
 	 RDMA_API_MODE_DV=2,
+	 Unknown(u32),
 }
 impl Default for RdmaMode {
 	fn default() -> Self { RdmaMode::RDMA_API_MODE_AUTO }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for RdmaMode {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 RdmaMode::RDMA_API_MODE_AUTO => 0,
+			 RdmaMode::RDMA_API_MODE_IBV => 1,
+			 RdmaMode::RDMA_API_MODE_DV => 2,
+			 RdmaMode::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(RdmaMode::RDMA_API_MODE_AUTO),
+			 1 => Some(RdmaMode::RDMA_API_MODE_IBV),
+			 2 => Some(RdmaMode::RDMA_API_MODE_DV),
+			 value => Some(RdmaMode::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for RdmaMode {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for RdmaMode {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(RdmaMode::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum RdmaRss4 {
 	 RDMA_API_RSS4_AUTO=0,
 	 RDMA_API_RSS4_IP=1,
 	 RDMA_API_RSS4_IP_UDP=2,
 	 RDMA_API_RSS4_IP_TCP=3,
+	 Unknown(u32),
 }
 impl Default for RdmaRss4 {
 	fn default() -> Self { RdmaRss4::RDMA_API_RSS4_AUTO }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for RdmaRss4 {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 RdmaRss4::RDMA_API_RSS4_AUTO => 0,
+			 RdmaRss4::RDMA_API_RSS4_IP => 1,
+			 RdmaRss4::RDMA_API_RSS4_IP_UDP => 2,
+			 RdmaRss4::RDMA_API_RSS4_IP_TCP => 3,
+			 RdmaRss4::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(RdmaRss4::RDMA_API_RSS4_AUTO),
+			 1 => Some(RdmaRss4::RDMA_API_RSS4_IP),
+			 2 => Some(RdmaRss4::RDMA_API_RSS4_IP_UDP),
+			 3 => Some(RdmaRss4::RDMA_API_RSS4_IP_TCP),
+			 value => Some(RdmaRss4::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for RdmaRss4 {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for RdmaRss4 {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(RdmaRss4::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum RdmaRss6 {
 	 RDMA_API_RSS6_AUTO=0,
 	 RDMA_API_RSS6_IP=1,
 	 RDMA_API_RSS6_IP_UDP=2,
 	 RDMA_API_RSS6_IP_TCP=3,
+	 Unknown(u32),
 }
 impl Default for RdmaRss6 {
 	fn default() -> Self { RdmaRss6::RDMA_API_RSS6_AUTO }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for RdmaRss6 {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 RdmaRss6::RDMA_API_RSS6_AUTO => 0,
+			 RdmaRss6::RDMA_API_RSS6_IP => 1,
+			 RdmaRss6::RDMA_API_RSS6_IP_UDP => 2,
+			 RdmaRss6::RDMA_API_RSS6_IP_TCP => 3,
+			 RdmaRss6::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(RdmaRss6::RDMA_API_RSS6_AUTO),
+			 1 => Some(RdmaRss6::RDMA_API_RSS6_IP),
+			 2 => Some(RdmaRss6::RDMA_API_RSS6_IP_UDP),
+			 3 => Some(RdmaRss6::RDMA_API_RSS6_IP_TCP),
+			 value => Some(RdmaRss6::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for RdmaRss6 {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for RdmaRss6 {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(RdmaRss6::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(rdma_create_076fe418)]
 pub struct RdmaCreate {
 	pub client_index: u32,
@@ -55,7 +151,7 @@ pub struct RdmaCreate {
 	pub txq_size: u16,
 	pub mode: RdmaMode,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(rdma_create_v2_5826a4f3)]
 pub struct RdmaCreateV2 {
 	pub client_index: u32,
@@ -69,7 +165,7 @@ pub struct RdmaCreateV2 {
 	pub no_multi_seg: bool,
 	pub max_pktlen: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(rdma_create_v3_c6287ea8)]
 pub struct RdmaCreateV3 {
 	pub client_index: u32,
@@ -85,7 +181,7 @@ pub struct RdmaCreateV3 {
 	pub rss4: RdmaRss4,
 	pub rss6: RdmaRss6,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(rdma_create_v4_c6287ea8)]
 pub struct RdmaCreateV4 {
 	pub client_index: u32,
@@ -101,44 +197,57 @@ pub struct RdmaCreateV4 {
 	pub rss4: RdmaRss4,
 	pub rss6: RdmaRss6,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(rdma_create_reply_5383d31f)]
 pub struct RdmaCreateReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(rdma_create_v2_reply_5383d31f)]
 pub struct RdmaCreateV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(rdma_create_v3_reply_5383d31f)]
 pub struct RdmaCreateV3Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(rdma_create_v4_reply_5383d31f)]
 pub struct RdmaCreateV4Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(rdma_delete_f9e6675e)]
 pub struct RdmaDelete {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(rdma_delete_reply_e8d4e804)]
 pub struct RdmaDeleteReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"rdma_create_076fe418",
+	"rdma_create_v2_5826a4f3",
+	"rdma_create_v3_c6287ea8",
+	"rdma_create_v4_c6287ea8",
+	"rdma_create_reply_5383d31f",
+	"rdma_create_v2_reply_5383d31f",
+	"rdma_create_v3_reply_5383d31f",
+	"rdma_create_v4_reply_5383d31f",
+	"rdma_delete_f9e6675e",
+	"rdma_delete_reply_e8d4e804",
+];
@@ -2,31 +2,30 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 // Implementation for sdl_rule
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct SdlRule {
 	pub lcl: Prefix,
 	pub action_index: u32,
 	pub tag: FixedSizeString<typenum::U64>,
 }
 // Implementation for sdl_rule_v2
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct SdlRuleV2 {
 	pub rmt: Prefix,
 	pub action_index: u32,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum TransportProto {
 	 TRANSPORT_PROTO_API_TCP=0,
@@ -34,32 +33,133 @@ pub enum TransportProto {
 	 TRANSPORT_PROTO_API_NONE=2,
 	 TRANSPORT_PROTO_API_TLS=3,
 	 TRANSPORT_PROTO_API_QUIC=4,
+	 Unknown(u32),
 }
 impl Default for TransportProto {
 	fn default() -> Self { TransportProto::TRANSPORT_PROTO_API_TCP }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for TransportProto {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 TransportProto::TRANSPORT_PROTO_API_TCP => 0,
+			 TransportProto::TRANSPORT_PROTO_API_UDP => 1,
+			 TransportProto::TRANSPORT_PROTO_API_NONE => 2,
+			 TransportProto::TRANSPORT_PROTO_API_TLS => 3,
+			 TransportProto::TRANSPORT_PROTO_API_QUIC => 4,
+			 TransportProto::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(TransportProto::TRANSPORT_PROTO_API_TCP),
+			 1 => Some(TransportProto::TRANSPORT_PROTO_API_UDP),
+			 2 => Some(TransportProto::TRANSPORT_PROTO_API_NONE),
+			 3 => Some(TransportProto::TRANSPORT_PROTO_API_TLS),
+			 4 => Some(TransportProto::TRANSPORT_PROTO_API_QUIC),
+			 value => Some(TransportProto::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for TransportProto {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("TransportProto value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for TransportProto {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(TransportProto::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum RtBackendEngine {
 	 RT_BACKEND_ENGINE_API_DISABLE=0,
 	 RT_BACKEND_ENGINE_API_RULE_TABLE=1,
 	 RT_BACKEND_ENGINE_API_NONE=2,
 	 RT_BACKEND_ENGINE_API_SDL=3,
+	 Unknown(u32),
 }
 impl Default for RtBackendEngine {
 	fn default() -> Self { RtBackendEngine::RT_BACKEND_ENGINE_API_DISABLE }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for RtBackendEngine {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 RtBackendEngine::RT_BACKEND_ENGINE_API_DISABLE => 0,
+			 RtBackendEngine::RT_BACKEND_ENGINE_API_RULE_TABLE => 1,
+			 RtBackendEngine::RT_BACKEND_ENGINE_API_NONE => 2,
+			 RtBackendEngine::RT_BACKEND_ENGINE_API_SDL => 3,
+			 RtBackendEngine::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(RtBackendEngine::RT_BACKEND_ENGINE_API_DISABLE),
+			 1 => Some(RtBackendEngine::RT_BACKEND_ENGINE_API_RULE_TABLE),
+			 2 => Some(RtBackendEngine::RT_BACKEND_ENGINE_API_NONE),
+			 3 => Some(RtBackendEngine::RT_BACKEND_ENGINE_API_SDL),
+			 value => Some(RtBackendEngine::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for RtBackendEngine {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("RtBackendEngine value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for RtBackendEngine {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(RtBackendEngine::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum SessionRuleScope {
 	 SESSION_RULE_SCOPE_API_GLOBAL=0,
 	 SESSION_RULE_SCOPE_API_LOCAL=1,
 	 SESSION_RULE_SCOPE_API_BOTH=2,
+	 Unknown(u32),
 }
 impl Default for SessionRuleScope {
 	fn default() -> Self { SessionRuleScope::SESSION_RULE_SCOPE_API_GLOBAL }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for SessionRuleScope {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 SessionRuleScope::SESSION_RULE_SCOPE_API_GLOBAL => 0,
+			 SessionRuleScope::SESSION_RULE_SCOPE_API_LOCAL => 1,
+			 SessionRuleScope::SESSION_RULE_SCOPE_API_BOTH => 2,
+			 SessionRuleScope::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(SessionRuleScope::SESSION_RULE_SCOPE_API_GLOBAL),
+			 1 => Some(SessionRuleScope::SESSION_RULE_SCOPE_API_LOCAL),
+			 2 => Some(SessionRuleScope::SESSION_RULE_SCOPE_API_BOTH),
+			 value => Some(SessionRuleScope::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for SessionRuleScope {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for SessionRuleScope {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(SessionRuleScope::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_attach_5f4a260d)]
 pub struct AppAttach {
 	pub client_index: u32,
@@ -67,7 +167,7 @@ pub struct AppAttach {
 	pub options: FixedSizeArray<u64, typenum::U18>,
 	pub namespace_id: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_attach_reply_5c89c3b0)]
 pub struct AppAttachReply {
 	pub context: u32,
@@ -82,48 +182,49 @@ pub struct AppAttachReply {
 	pub segment_handle: u64,
 	pub segment_name: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(application_detach_51077d14)]
 pub struct ApplicationDetach {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(application_detach_reply_e8d4e804)]
 pub struct ApplicationDetachReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_add_cert_key_pair_02eb8016)]
 pub struct AppAddCertKeyPair {
 	pub client_index: u32,
 	pub context: u32,
 	pub cert_len: u16,
 	pub certkey_len: u16,
+	#[vpp(count = "certkey_len")]
 	pub certkey: VariableSizeArray<u8>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_add_cert_key_pair_reply_b42958d0)]
 pub struct AppAddCertKeyPairReply {
 	pub context: u32,
 	pub retval: i32,
 	pub index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_del_cert_key_pair_8ac76db6)]
 pub struct AppDelCertKeyPair {
 	pub client_index: u32,
 	pub context: u32,
 	pub index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_del_cert_key_pair_reply_e8d4e804)]
 pub struct AppDelCertKeyPairReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_worker_add_del_753253dc)]
 pub struct AppWorkerAddDel {
 	pub client_index: u32,
@@ -132,7 +233,7 @@ pub struct AppWorkerAddDel {
 	pub wrk_index: u32,
 	pub is_add: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_worker_add_del_reply_5735ffe7)]
 pub struct AppWorkerAddDelReply {
 	pub context: u32,
@@ -145,46 +246,46 @@ pub struct AppWorkerAddDelReply {
 	pub is_add: bool,
 	pub segment_name: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_enable_disable_c264d7bf)]
 pub struct SessionEnableDisable {
 	pub client_index: u32,
 	pub context: u32,
 	pub is_enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_enable_disable_reply_e8d4e804)]
 pub struct SessionEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_enable_disable_v2_f09fbf32)]
 pub struct SessionEnableDisableV2 {
 	pub client_index: u32,
 	pub context: u32,
 	pub rt_engine_type: RtBackendEngine,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_enable_disable_v2_reply_e8d4e804)]
 pub struct SessionEnableDisableV2Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_sapi_enable_disable_c264d7bf)]
 pub struct SessionSapiEnableDisable {
 	pub client_index: u32,
 	pub context: u32,
 	pub is_enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_sapi_enable_disable_reply_e8d4e804)]
 pub struct SessionSapiEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_namespace_add_del_6306aecb)]
 pub struct AppNamespaceAddDel {
 	pub client_index: u32,
@@ -195,7 +296,7 @@ pub struct AppNamespaceAddDel {
 	pub ip6_fib_id: u32,
 	pub namespace_id: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_namespace_add_del_v4_42c1d824)]
 pub struct AppNamespaceAddDelV4 {
 	pub client_index: u32,
@@ -208,14 +309,14 @@ pub struct AppNamespaceAddDelV4 {
 	pub namespace_id: FixedSizeString<typenum::U64>,
 	pub sock_name: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_namespace_add_del_v4_reply_85137120)]
 pub struct AppNamespaceAddDelV4Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub appns_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_namespace_add_del_v2_ee0755cf)]
 pub struct AppNamespaceAddDelV2 {
 	pub client_index: u32,
@@ -227,7 +328,7 @@ pub struct AppNamespaceAddDelV2 {
 	pub namespace_id: FixedSizeString<typenum::U64>,
 	pub netns: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_namespace_add_del_v3_8a7e40a1)]
 pub struct AppNamespaceAddDelV3 {
 	pub client_index: u32,
@@ -241,28 +342,28 @@ pub struct AppNamespaceAddDelV3 {
 	pub netns: FixedSizeString<typenum::U64>,
 	pub sock_name: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_namespace_add_del_reply_85137120)]
 pub struct AppNamespaceAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 	pub appns_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_namespace_add_del_v2_reply_85137120)]
 pub struct AppNamespaceAddDelV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub appns_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(app_namespace_add_del_v3_reply_85137120)]
 pub struct AppNamespaceAddDelV3Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub appns_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_rule_add_del_82a90af5)]
 pub struct SessionRuleAddDel {
 	pub client_index: u32,
@@ -278,19 +379,19 @@ pub struct SessionRuleAddDel {
 	pub scope: SessionRuleScope,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_rule_add_del_reply_e8d4e804)]
 pub struct SessionRuleAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_rules_dump_51077d14)]
 pub struct SessionRulesDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_rules_details_4ef746e7)]
 pub struct SessionRulesDetails {
 	pub context: u32,
@@ -304,13 +405,13 @@ pub struct SessionRulesDetails {
 	pub scope: SessionRuleScope,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_rules_v2_dump_51077d14)]
 pub struct SessionRulesV2Dump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_rules_v2_details_f91993dc)]
 pub struct SessionRulesV2Details {
 	pub context: u32,
@@ -323,9 +424,10 @@ pub struct SessionRulesV2Details {
 	pub scope: SessionRuleScope,
 	pub tag: FixedSizeString<typenum::U64>,
 	pub count: u32,
+	#[vpp(count = "count")]
 	pub appns_index: VariableSizeArray<u32>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_sdl_add_del_faeb89fc)]
 pub struct SessionSdlAddDel {
 	pub client_index: u32,
@@ -333,15 +435,16 @@ pub struct SessionSdlAddDel {
 	pub appns_index: u32,
 	pub is_add: bool,
 	pub count: u32,
+	#[vpp(count = "count")]
 	pub r: VariableSizeArray<SdlRule>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_sdl_add_del_reply_e8d4e804)]
 pub struct SessionSdlAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_sdl_add_del_v2_7f89d3fa)]
 pub struct SessionSdlAddDelV2 {
 	pub client_index: u32,
@@ -349,21 +452,22 @@ pub struct SessionSdlAddDelV2 {
 	pub appns_index: u32,
 	pub is_add: bool,
 	pub count: u32,
+	#[vpp(count = "count")]
 	pub r: VariableSizeArray<SdlRuleV2>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_sdl_add_del_v2_reply_e8d4e804)]
 pub struct SessionSdlAddDelV2Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_sdl_dump_51077d14)]
 pub struct SessionSdlDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_sdl_details_9a8ef5d0)]
 pub struct SessionSdlDetails {
 	pub context: u32,
@@ -372,13 +476,13 @@ pub struct SessionSdlDetails {
 	pub appns_index: u32,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_sdl_v2_dump_51077d14)]
 pub struct SessionSdlV2Dump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_sdl_v2_details_0a057683)]
 pub struct SessionSdlV2Details {
 	pub context: u32,
@@ -387,13 +491,13 @@ pub struct SessionSdlV2Details {
 	pub appns_index: u32,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_sdl_v3_dump_51077d14)]
 pub struct SessionSdlV3Dump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(session_sdl_v3_details_829e367f)]
 pub struct SessionSdlV3Details {
 	pub context: u32,
@@ -401,5 +505,49 @@ pub struct SessionSdlV3Details {
 	pub action_index: u32,
 	pub tag: FixedSizeString<typenum::U64>,
 	pub count: u32,
+	#[vpp(count = "count")]
 	pub appns_index: VariableSizeArray<u32>,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"app_attach_5f4a260d",
+	"app_attach_reply_5c89c3b0",
+	"application_detach_51077d14",
+	"application_detach_reply_e8d4e804",
+	"app_add_cert_key_pair_02eb8016",
+	"app_add_cert_key_pair_reply_b42958d0",
+	"app_del_cert_key_pair_8ac76db6",
+	"app_del_cert_key_pair_reply_e8d4e804",
+	"app_worker_add_del_753253dc",
+	"app_worker_add_del_reply_5735ffe7",
+	"session_enable_disable_c264d7bf",
+	"session_enable_disable_reply_e8d4e804",
+	"session_enable_disable_v2_f09fbf32",
+	"session_enable_disable_v2_reply_e8d4e804",
+	"session_sapi_enable_disable_c264d7bf",
+	"session_sapi_enable_disable_reply_e8d4e804",
+	"app_namespace_add_del_6306aecb",
+	"app_namespace_add_del_v4_42c1d824",
+	"app_namespace_add_del_v4_reply_85137120",
+	"app_namespace_add_del_v2_ee0755cf",
+	"app_namespace_add_del_v3_8a7e40a1",
+	"app_namespace_add_del_reply_85137120",
+	"app_namespace_add_del_v2_reply_85137120",
+	"app_namespace_add_del_v3_reply_85137120",
+	"session_rule_add_del_82a90af5",
+	"session_rule_add_del_reply_e8d4e804",
+	"session_rules_dump_51077d14",
+	"session_rules_details_4ef746e7",
+	"session_rules_v2_dump_51077d14",
+	"session_rules_v2_details_f91993dc",
+	"session_sdl_add_del_faeb89fc",
+	"session_sdl_add_del_reply_e8d4e804",
+	"session_sdl_add_del_v2_7f89d3fa",
+	"session_sdl_add_del_v2_reply_e8d4e804",
+	"session_sdl_dump_51077d14",
+	"session_sdl_details_9a8ef5d0",
+	"session_sdl_v2_dump_51077d14",
+	"session_sdl_v2_details_0a057683",
+	"session_sdl_v3_dump_51077d14",
+	"session_sdl_v3_details_829e367f",
+];
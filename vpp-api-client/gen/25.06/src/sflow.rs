@@ -2,16 +2,15 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_enable_disable_8499814f)]
 pub struct SflowEnableDisable {
 	pub client_index: u32,
@@ -19,97 +18,116 @@ pub struct SflowEnableDisable {
 	pub enable_disable: bool,
 	pub hw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_enable_disable_reply_e8d4e804)]
 pub struct SflowEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_sampling_rate_get_51077d14)]
 pub struct SflowSamplingRateGet {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_sampling_rate_get_reply_9c8c8236)]
 pub struct SflowSamplingRateGetReply {
 	pub context: u32,
 	pub sampling_N: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_sampling_rate_set_94778f50)]
 pub struct SflowSamplingRateSet {
 	pub client_index: u32,
 	pub context: u32,
 	pub sampling_N: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_sampling_rate_set_reply_e8d4e804)]
 pub struct SflowSamplingRateSetReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_polling_interval_set_7f19cb51)]
 pub struct SflowPollingIntervalSet {
 	pub client_index: u32,
 	pub context: u32,
 	pub polling_S: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_polling_interval_set_reply_e8d4e804)]
 pub struct SflowPollingIntervalSetReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_polling_interval_get_51077d14)]
 pub struct SflowPollingIntervalGet {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_polling_interval_get_reply_e929801c)]
 pub struct SflowPollingIntervalGetReply {
 	pub context: u32,
 	pub polling_S: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_header_bytes_set_5baf56f3)]
 pub struct SflowHeaderBytesSet {
 	pub client_index: u32,
 	pub context: u32,
 	pub header_B: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_header_bytes_set_reply_e8d4e804)]
 pub struct SflowHeaderBytesSetReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_header_bytes_get_51077d14)]
 pub struct SflowHeaderBytesGet {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_header_bytes_get_reply_624c95b9)]
 pub struct SflowHeaderBytesGetReply {
 	pub context: u32,
 	pub header_B: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_interface_dump_451a727d)]
 pub struct SflowInterfaceDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub hw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sflow_interface_details_b7b9143f)]
 pub struct SflowInterfaceDetails {
 	pub context: u32,
 	pub hw_if_index: InterfaceIndex,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"sflow_enable_disable_8499814f",
+	"sflow_enable_disable_reply_e8d4e804",
+	"sflow_sampling_rate_get_51077d14",
+	"sflow_sampling_rate_get_reply_9c8c8236",
+	"sflow_sampling_rate_set_94778f50",
+	"sflow_sampling_rate_set_reply_e8d4e804",
+	"sflow_polling_interval_set_7f19cb51",
+	"sflow_polling_interval_set_reply_e8d4e804",
+	"sflow_polling_interval_get_51077d14",
+	"sflow_polling_interval_get_reply_e929801c",
+	"sflow_header_bytes_set_5baf56f3",
+	"sflow_header_bytes_set_reply_e8d4e804",
+	"sflow_header_bytes_get_51077d14",
+	"sflow_header_bytes_get_reply_624c95b9",
+	"sflow_interface_dump_451a727d",
+	"sflow_interface_details_b7b9143f",
+];
@@ -2,27 +2,59 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum SpanState {
 	 SPAN_STATE_API_DISABLED=0,
 	 SPAN_STATE_API_RX=1,
 	 SPAN_STATE_API_TX=2,
 	 SPAN_STATE_API_RX_TX=3,
+	 Unknown(u32),
 }
 impl Default for SpanState {
 	fn default() -> Self { SpanState::SPAN_STATE_API_DISABLED }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for SpanState {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 SpanState::SPAN_STATE_API_DISABLED => 0,
+			 SpanState::SPAN_STATE_API_RX => 1,
+			 SpanState::SPAN_STATE_API_TX => 2,
+			 SpanState::SPAN_STATE_API_RX_TX => 3,
+			 SpanState::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(SpanState::SPAN_STATE_API_DISABLED),
+			 1 => Some(SpanState::SPAN_STATE_API_RX),
+			 2 => Some(SpanState::SPAN_STATE_API_TX),
+			 3 => Some(SpanState::SPAN_STATE_API_RX_TX),
+			 value => Some(SpanState::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for SpanState {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for SpanState {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(SpanState::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_span_enable_disable_23ddd96b)]
 pub struct SwInterfaceSpanEnableDisable {
 	pub client_index: u32,
@@ -32,20 +64,20 @@ pub struct SwInterfaceSpanEnableDisable {
 	pub state: SpanState,
 	pub is_l2: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_span_enable_disable_reply_e8d4e804)]
 pub struct SwInterfaceSpanEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_span_dump_d6cf0c3d)]
 pub struct SwInterfaceSpanDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub is_l2: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_span_details_8a20e79f)]
 pub struct SwInterfaceSpanDetails {
 	pub context: u32,
@@ -54,3 +86,10 @@ pub struct SwInterfaceSpanDetails {
 	pub state: SpanState,
 	pub is_l2: bool,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"sw_interface_span_enable_disable_23ddd96b",
+	"sw_interface_span_enable_disable_reply_e8d4e804",
+	"sw_interface_span_dump_d6cf0c3d",
+	"sw_interface_span_details_8a20e79f",
+];
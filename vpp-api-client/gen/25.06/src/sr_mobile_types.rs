@@ -2,22 +2,58 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum SrMobileNhtype {
 	 SRV6_NHTYPE_API_NONE=0,
 	 SRV6_NHTYPE_API_IPV4=1,
 	 SRV6_NHTYPE_API_IPV6=2,
 	 SRV6_NHTYPE_API_NON_IP=3,
+	 Unknown(u32),
 }
 impl Default for SrMobileNhtype {
 	fn default() -> Self { SrMobileNhtype::SRV6_NHTYPE_API_NONE }
 }
+impl AsU32 for SrMobileNhtype {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 SrMobileNhtype::SRV6_NHTYPE_API_NONE => 0,
+			 SrMobileNhtype::SRV6_NHTYPE_API_IPV4 => 1,
+			 SrMobileNhtype::SRV6_NHTYPE_API_IPV6 => 2,
+			 SrMobileNhtype::SRV6_NHTYPE_API_NON_IP => 3,
+			 SrMobileNhtype::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(SrMobileNhtype::SRV6_NHTYPE_API_NONE),
+			 1 => Some(SrMobileNhtype::SRV6_NHTYPE_API_IPV4),
+			 2 => Some(SrMobileNhtype::SRV6_NHTYPE_API_IPV6),
+			 3 => Some(SrMobileNhtype::SRV6_NHTYPE_API_NON_IP),
+			 value => Some(SrMobileNhtype::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for SrMobileNhtype {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("SrMobileNhtype value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for SrMobileNhtype {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(SrMobileNhtype::Unknown(value)))
+	 }
+}
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
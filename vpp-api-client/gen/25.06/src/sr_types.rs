@@ -2,26 +2,59 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum SrPolicyOp {
 	 SR_POLICY_OP_API_NONE=0,
 	 SR_POLICY_OP_API_ADD=1,
 	 SR_POLICY_OP_API_DEL=2,
 	 SR_POLICY_OP_API_MOD=3,
+	 Unknown(u32),
 }
 impl Default for SrPolicyOp {
 	fn default() -> Self { SrPolicyOp::SR_POLICY_OP_API_NONE }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for SrPolicyOp {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 SrPolicyOp::SR_POLICY_OP_API_NONE => 0,
+			 SrPolicyOp::SR_POLICY_OP_API_ADD => 1,
+			 SrPolicyOp::SR_POLICY_OP_API_DEL => 2,
+			 SrPolicyOp::SR_POLICY_OP_API_MOD => 3,
+			 SrPolicyOp::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(SrPolicyOp::SR_POLICY_OP_API_NONE),
+			 1 => Some(SrPolicyOp::SR_POLICY_OP_API_ADD),
+			 2 => Some(SrPolicyOp::SR_POLICY_OP_API_DEL),
+			 3 => Some(SrPolicyOp::SR_POLICY_OP_API_MOD),
+			 value => Some(SrPolicyOp::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for SrPolicyOp {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("SrPolicyOp value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for SrPolicyOp {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(SrPolicyOp::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum SrBehavior {
 	 SR_BEHAVIOR_API_END=1,
@@ -37,17 +70,104 @@ pub enum SrBehavior {
 	 SR_BEHAVIOR_API_END_UN_PERF=11,
 	 SR_BEHAVIOR_API_END_UN=12,
 	 SR_BEHAVIOR_API_UA=13,
+	 Unknown(u32),
 }
 impl Default for SrBehavior {
 	fn default() -> Self { SrBehavior::SR_BEHAVIOR_API_END }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for SrBehavior {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 SrBehavior::SR_BEHAVIOR_API_END => 1,
+			 SrBehavior::SR_BEHAVIOR_API_X => 2,
+			 SrBehavior::SR_BEHAVIOR_API_T => 3,
+			 SrBehavior::SR_BEHAVIOR_API_D_FIRST => 4,
+			 SrBehavior::SR_BEHAVIOR_API_DX2 => 5,
+			 SrBehavior::SR_BEHAVIOR_API_DX6 => 6,
+			 SrBehavior::SR_BEHAVIOR_API_DX4 => 7,
+			 SrBehavior::SR_BEHAVIOR_API_DT6 => 8,
+			 SrBehavior::SR_BEHAVIOR_API_DT4 => 9,
+			 SrBehavior::SR_BEHAVIOR_API_LAST => 10,
+			 SrBehavior::SR_BEHAVIOR_API_END_UN_PERF => 11,
+			 SrBehavior::SR_BEHAVIOR_API_END_UN => 12,
+			 SrBehavior::SR_BEHAVIOR_API_UA => 13,
+			 SrBehavior::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 1 => Some(SrBehavior::SR_BEHAVIOR_API_END),
+			 2 => Some(SrBehavior::SR_BEHAVIOR_API_X),
+			 3 => Some(SrBehavior::SR_BEHAVIOR_API_T),
+			 4 => Some(SrBehavior::SR_BEHAVIOR_API_D_FIRST),
+			 5 => Some(SrBehavior::SR_BEHAVIOR_API_DX2),
+			 6 => Some(SrBehavior::SR_BEHAVIOR_API_DX6),
+			 7 => Some(SrBehavior::SR_BEHAVIOR_API_DX4),
+			 8 => Some(SrBehavior::SR_BEHAVIOR_API_DT6),
+			 9 => Some(SrBehavior::SR_BEHAVIOR_API_DT4),
+			 10 => Some(SrBehavior::SR_BEHAVIOR_API_LAST),
+			 11 => Some(SrBehavior::SR_BEHAVIOR_API_END_UN_PERF),
+			 12 => Some(SrBehavior::SR_BEHAVIOR_API_END_UN),
+			 13 => Some(SrBehavior::SR_BEHAVIOR_API_UA),
+			 value => Some(SrBehavior::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for SrBehavior {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("SrBehavior value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for SrBehavior {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(SrBehavior::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum SrSteer {
 	 SR_STEER_API_L2=2,
 	 SR_STEER_API_IPV4=4,
 	 SR_STEER_API_IPV6=6,
+	 Unknown(u32),
 }
 impl Default for SrSteer {
 	fn default() -> Self { SrSteer::SR_STEER_API_L2 }
 }
+impl AsU32 for SrSteer {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 SrSteer::SR_STEER_API_L2 => 2,
+			 SrSteer::SR_STEER_API_IPV4 => 4,
+			 SrSteer::SR_STEER_API_IPV6 => 6,
+			 SrSteer::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 2 => Some(SrSteer::SR_STEER_API_L2),
+			 4 => Some(SrSteer::SR_STEER_API_IPV4),
+			 6 => Some(SrSteer::SR_STEER_API_IPV6),
+			 value => Some(SrSteer::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for SrSteer {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("SrSteer value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for SrSteer {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(SrSteer::Unknown(value)))
+	 }
+}
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
@@ -2,16 +2,15 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum SyslogSeverity {
 	 SYSLOG_API_SEVERITY_EMERG=0,
@@ -22,11 +21,52 @@ pub enum SyslogSeverity {
 	 SYSLOG_API_SEVERITY_NOTICE=5,
 	 SYSLOG_API_SEVERITY_INFO=6,
 	 SYSLOG_API_SEVERITY_DBG=7,
+	 Unknown(u32),
 }
 impl Default for SyslogSeverity {
 	fn default() -> Self { SyslogSeverity::SYSLOG_API_SEVERITY_EMERG }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for SyslogSeverity {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 SyslogSeverity::SYSLOG_API_SEVERITY_EMERG => 0,
+			 SyslogSeverity::SYSLOG_API_SEVERITY_ALERT => 1,
+			 SyslogSeverity::SYSLOG_API_SEVERITY_CRIT => 2,
+			 SyslogSeverity::SYSLOG_API_SEVERITY_ERR => 3,
+			 SyslogSeverity::SYSLOG_API_SEVERITY_WARN => 4,
+			 SyslogSeverity::SYSLOG_API_SEVERITY_NOTICE => 5,
+			 SyslogSeverity::SYSLOG_API_SEVERITY_INFO => 6,
+			 SyslogSeverity::SYSLOG_API_SEVERITY_DBG => 7,
+			 SyslogSeverity::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(SyslogSeverity::SYSLOG_API_SEVERITY_EMERG),
+			 1 => Some(SyslogSeverity::SYSLOG_API_SEVERITY_ALERT),
+			 2 => Some(SyslogSeverity::SYSLOG_API_SEVERITY_CRIT),
+			 3 => Some(SyslogSeverity::SYSLOG_API_SEVERITY_ERR),
+			 4 => Some(SyslogSeverity::SYSLOG_API_SEVERITY_WARN),
+			 5 => Some(SyslogSeverity::SYSLOG_API_SEVERITY_NOTICE),
+			 6 => Some(SyslogSeverity::SYSLOG_API_SEVERITY_INFO),
+			 7 => Some(SyslogSeverity::SYSLOG_API_SEVERITY_DBG),
+			 value => Some(SyslogSeverity::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for SyslogSeverity {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for SyslogSeverity {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(SyslogSeverity::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(syslog_set_sender_b8011d0b)]
 pub struct SyslogSetSender {
 	pub client_index: u32,
@@ -37,19 +77,19 @@ pub struct SyslogSetSender {
 	pub vrf_id: u32,
 	pub max_msg_size: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(syslog_set_sender_reply_e8d4e804)]
 pub struct SyslogSetSenderReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(syslog_get_sender_51077d14)]
 pub struct SyslogGetSender {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(syslog_get_sender_reply_424cfa4e)]
 pub struct SyslogGetSenderReply {
 	pub context: u32,
@@ -60,29 +100,40 @@ pub struct SyslogGetSenderReply {
 	pub vrf_id: u32,
 	pub max_msg_size: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(syslog_set_filter_571348c3)]
 pub struct SyslogSetFilter {
 	pub client_index: u32,
 	pub context: u32,
 	pub severity: SyslogSeverity,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(syslog_set_filter_reply_e8d4e804)]
 pub struct SyslogSetFilterReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(syslog_get_filter_51077d14)]
 pub struct SyslogGetFilter {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(syslog_get_filter_reply_eb1833f8)]
 pub struct SyslogGetFilterReply {
 	pub context: u32,
 	pub retval: i32,
 	pub severity: SyslogSeverity,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"syslog_set_sender_b8011d0b",
+	"syslog_set_sender_reply_e8d4e804",
+	"syslog_get_sender_51077d14",
+	"syslog_get_sender_reply_424cfa4e",
+	"syslog_set_filter_571348c3",
+	"syslog_set_filter_reply_e8d4e804",
+	"syslog_get_filter_51077d14",
+	"syslog_get_filter_reply_eb1833f8",
+];
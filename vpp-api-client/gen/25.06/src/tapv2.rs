@@ -2,18 +2,18 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum TapFlags {
 	 TAP_API_FLAG_GSO=1,
 	 TAP_API_FLAG_CSUM_OFFLOAD=2,
@@ -24,33 +24,74 @@ pub enum TapFlags {
 	 TAP_API_FLAG_PACKED=64,
 	 TAP_API_FLAG_IN_ORDER=128,
 	 TAP_API_FLAG_CONSISTENT_QP=256,
+	 Unknown(u32),
 }
 impl Default for TapFlags {
 	fn default() -> Self { TapFlags::TAP_API_FLAG_GSO }
 }
-impl AsEnumFlag for TapFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for TapFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 TapFlags::TAP_API_FLAG_GSO => 1,
+			 TapFlags::TAP_API_FLAG_CSUM_OFFLOAD => 2,
+			 TapFlags::TAP_API_FLAG_PERSIST => 4,
+			 TapFlags::TAP_API_FLAG_ATTACH => 8,
+			 TapFlags::TAP_API_FLAG_TUN => 16,
+			 TapFlags::TAP_API_FLAG_GRO_COALESCE => 32,
+			 TapFlags::TAP_API_FLAG_PACKED => 64,
+			 TapFlags::TAP_API_FLAG_IN_ORDER => 128,
+			 TapFlags::TAP_API_FLAG_CONSISTENT_QP => 256,
+			 TapFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => TapFlags::TAP_API_FLAG_GSO,
-			 2 => TapFlags::TAP_API_FLAG_CSUM_OFFLOAD,
-			 4 => TapFlags::TAP_API_FLAG_PERSIST,
-			 8 => TapFlags::TAP_API_FLAG_ATTACH,
-			 16 => TapFlags::TAP_API_FLAG_TUN,
-			 32 => TapFlags::TAP_API_FLAG_GRO_COALESCE,
-			 64 => TapFlags::TAP_API_FLAG_PACKED,
-			 128 => TapFlags::TAP_API_FLAG_IN_ORDER,
-			 256 => TapFlags::TAP_API_FLAG_CONSISTENT_QP,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(TapFlags::TAP_API_FLAG_GSO),
+			 2 => Some(TapFlags::TAP_API_FLAG_CSUM_OFFLOAD),
+			 4 => Some(TapFlags::TAP_API_FLAG_PERSIST),
+			 8 => Some(TapFlags::TAP_API_FLAG_ATTACH),
+			 16 => Some(TapFlags::TAP_API_FLAG_TUN),
+			 32 => Some(TapFlags::TAP_API_FLAG_GRO_COALESCE),
+			 64 => Some(TapFlags::TAP_API_FLAG_PACKED),
+			 128 => Some(TapFlags::TAP_API_FLAG_IN_ORDER),
+			 256 => Some(TapFlags::TAP_API_FLAG_CONSISTENT_QP),
+			 value => Some(TapFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for TapFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for TapFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(TapFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for TapFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x1ff
+	}
+}
+impl std::ops::BitOr for TapFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(tap_create_v3_3f3fd1df)]
 pub struct TapCreateV3 {
 	pub client_index: u32,
@@ -83,14 +124,14 @@ pub struct TapCreateV3 {
 	pub host_bridge: FixedSizeString<typenum::U64>,
 	pub tag: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(tap_create_v3_reply_5383d31f)]
 pub struct TapCreateV3Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(tap_create_v2_2d0d6570)]
 pub struct TapCreateV2 {
 	pub client_index: u32,
@@ -122,34 +163,34 @@ pub struct TapCreateV2 {
 	pub host_bridge: FixedSizeString<typenum::U64>,
 	pub tag: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(tap_create_v2_reply_5383d31f)]
 pub struct TapCreateV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(tap_delete_v2_f9e6675e)]
 pub struct TapDeleteV2 {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(tap_delete_v2_reply_e8d4e804)]
 pub struct TapDeleteV2Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_tap_v2_dump_f9e6675e)]
 pub struct SwInterfaceTapV2Dump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_tap_v2_details_1e2b2a47)]
 pub struct SwInterfaceTapV2Details {
 	pub context: u32,
@@ -167,3 +208,14 @@ pub struct SwInterfaceTapV2Details {
 	pub host_namespace: FixedSizeString<typenum::U64>,
 	pub host_bridge: FixedSizeString<typenum::U64>,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"tap_create_v3_3f3fd1df",
+	"tap_create_v3_reply_5383d31f",
+	"tap_create_v2_2d0d6570",
+	"tap_create_v2_reply_5383d31f",
+	"tap_delete_v2_f9e6675e",
+	"tap_delete_v2_reply_e8d4e804",
+	"sw_interface_tap_v2_dump_f9e6675e",
+	"sw_interface_tap_v2_details_1e2b2a47",
+];
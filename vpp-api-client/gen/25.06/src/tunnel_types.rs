@@ -2,18 +2,17 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 // Implementation for tunnel
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Tunnel {
 	pub instance: u32,
 	pub src: Address,
@@ -26,7 +25,8 @@ pub struct Tunnel {
 	pub dscp: IpDscp,
 	pub hop_limit: u8,
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u8)]
 pub enum TunnelEncapDecapFlags {
 	 TUNNEL_API_ENCAP_DECAP_FLAG_NONE=0,
 	 TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_DF=1,
@@ -37,59 +37,169 @@ pub enum TunnelEncapDecapFlags {
 	 TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_INNER_HASH=32,
 	 TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_HOP_LIMIT=64,
 	 TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_FLOW_LABEL=128,
+	 Unknown(u32),
 }
 impl Default for TunnelEncapDecapFlags {
 	fn default() -> Self { TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_NONE }
 }
-impl AsEnumFlag for TunnelEncapDecapFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for TunnelEncapDecapFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_NONE => 0,
+			 TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_DF => 1,
+			 TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_SET_DF => 2,
+			 TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_DSCP => 4,
+			 TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_ECN => 8,
+			 TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_DECAP_COPY_ECN => 16,
+			 TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_INNER_HASH => 32,
+			 TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_HOP_LIMIT => 64,
+			 TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_FLOW_LABEL => 128,
+			 TunnelEncapDecapFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 0 => TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_NONE,
-			 1 => TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_DF,
-			 2 => TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_SET_DF,
-			 4 => TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_DSCP,
-			 8 => TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_ECN,
-			 16 => TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_DECAP_COPY_ECN,
-			 32 => TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_INNER_HASH,
-			 64 => TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_HOP_LIMIT,
-			 128 => TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_FLOW_LABEL,
-			_ => panic!("Invalid Enum Descriminant")
+			 0 => Some(TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_NONE),
+			 1 => Some(TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_DF),
+			 2 => Some(TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_SET_DF),
+			 4 => Some(TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_DSCP),
+			 8 => Some(TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_ECN),
+			 16 => Some(TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_DECAP_COPY_ECN),
+			 32 => Some(TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_INNER_HASH),
+			 64 => Some(TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_HOP_LIMIT),
+			 128 => Some(TunnelEncapDecapFlags::TUNNEL_API_ENCAP_DECAP_FLAG_ENCAP_COPY_FLOW_LABEL),
+			 value => Some(TunnelEncapDecapFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for TunnelEncapDecapFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("TunnelEncapDecapFlags value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for TunnelEncapDecapFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(TunnelEncapDecapFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for TunnelEncapDecapFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 8 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0xff
+	}
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl std::ops::BitOr for TunnelEncapDecapFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum TunnelMode {
 	 TUNNEL_API_MODE_P2P=0,
 	 TUNNEL_API_MODE_MP=1,
+	 Unknown(u32),
 }
 impl Default for TunnelMode {
 	fn default() -> Self { TunnelMode::TUNNEL_API_MODE_P2P }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+impl AsU32 for TunnelMode {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 TunnelMode::TUNNEL_API_MODE_P2P => 0,
+			 TunnelMode::TUNNEL_API_MODE_MP => 1,
+			 TunnelMode::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(TunnelMode::TUNNEL_API_MODE_P2P),
+			 1 => Some(TunnelMode::TUNNEL_API_MODE_MP),
+			 value => Some(TunnelMode::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for TunnelMode {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("TunnelMode value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for TunnelMode {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(TunnelMode::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u8)]
 pub enum TunnelFlags {
 	 TUNNEL_API_FLAG_TRACK_MTU=1,
+	 Unknown(u32),
 }
 impl Default for TunnelFlags {
 	fn default() -> Self { TunnelFlags::TUNNEL_API_FLAG_TRACK_MTU }
 }
-impl AsEnumFlag for TunnelFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for TunnelFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 TunnelFlags::TUNNEL_API_FLAG_TRACK_MTU => 1,
+			 TunnelFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => TunnelFlags::TUNNEL_API_FLAG_TRACK_MTU,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(TunnelFlags::TUNNEL_API_FLAG_TRACK_MTU),
+			 value => Some(TunnelFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for TunnelFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("TunnelFlags value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for TunnelFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(TunnelFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for TunnelFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 8 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x1
+	}
+}
+impl std::ops::BitOr for TunnelFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
@@ -2,17 +2,16 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 // Implementation for udp_encap
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct UdpEncap {
 	pub table_id: u32,
 	pub src_port: u16,
@@ -22,62 +21,93 @@ pub struct UdpEncap {
 	pub id: u32,
 }
 // Implementation for udp_decap
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct UdpDecap {
 	pub is_ip4: u8,
 	pub port: u16,
 	pub next_proto: UdpDecapNextProto,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum UdpDecapNextProto {
 	 UDP_API_DECAP_PROTO_IP4=0,
 	 UDP_API_DECAP_PROTO_IP6=1,
 	 UDP_API_DECAP_PROTO_MPLS=2,
+	 Unknown(u32),
 }
 impl Default for UdpDecapNextProto {
 	fn default() -> Self { UdpDecapNextProto::UDP_API_DECAP_PROTO_IP4 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for UdpDecapNextProto {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 UdpDecapNextProto::UDP_API_DECAP_PROTO_IP4 => 0,
+			 UdpDecapNextProto::UDP_API_DECAP_PROTO_IP6 => 1,
+			 UdpDecapNextProto::UDP_API_DECAP_PROTO_MPLS => 2,
+			 UdpDecapNextProto::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(UdpDecapNextProto::UDP_API_DECAP_PROTO_IP4),
+			 1 => Some(UdpDecapNextProto::UDP_API_DECAP_PROTO_IP6),
+			 2 => Some(UdpDecapNextProto::UDP_API_DECAP_PROTO_MPLS),
+			 value => Some(UdpDecapNextProto::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for UdpDecapNextProto {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for UdpDecapNextProto {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(UdpDecapNextProto::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(udp_encap_add_f74a60b1)]
 pub struct UdpEncapAdd {
 	pub client_index: u32,
 	pub context: u32,
 	pub udp_encap: UdpEncap,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(udp_encap_add_reply_e2fc8294)]
 pub struct UdpEncapAddReply {
 	pub context: u32,
 	pub retval: i32,
 	pub id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(udp_encap_del_3a91bde5)]
 pub struct UdpEncapDel {
 	pub client_index: u32,
 	pub context: u32,
 	pub id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(udp_encap_del_reply_e8d4e804)]
 pub struct UdpEncapDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(udp_encap_dump_51077d14)]
 pub struct UdpEncapDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(udp_encap_details_8cfb9c76)]
 pub struct UdpEncapDetails {
 	pub context: u32,
 	pub udp_encap: UdpEncap,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(udp_decap_add_del_d14a4f47)]
 pub struct UdpDecapAddDel {
 	pub client_index: u32,
@@ -85,9 +115,20 @@ pub struct UdpDecapAddDel {
 	pub is_add: bool,
 	pub udp_decap: UdpDecap,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(udp_decap_add_del_reply_e8d4e804)]
 pub struct UdpDecapAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"udp_encap_add_f74a60b1",
+	"udp_encap_add_reply_e2fc8294",
+	"udp_encap_del_3a91bde5",
+	"udp_encap_del_reply_e8d4e804",
+	"udp_encap_dump_51077d14",
+	"udp_encap_details_8cfb9c76",
+	"udp_decap_add_del_d14a4f47",
+	"udp_decap_add_del_reply_e8d4e804",
+];
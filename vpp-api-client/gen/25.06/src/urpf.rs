@@ -2,28 +2,59 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::fib_types::*;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum UrpfMode {
 	 URPF_API_MODE_OFF=0,
 	 URPF_API_MODE_LOOSE=1,
 	 URPF_API_MODE_STRICT=2,
+	 Unknown(u32),
 }
 impl Default for UrpfMode {
 	fn default() -> Self { UrpfMode::URPF_API_MODE_OFF }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for UrpfMode {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 UrpfMode::URPF_API_MODE_OFF => 0,
+			 UrpfMode::URPF_API_MODE_LOOSE => 1,
+			 UrpfMode::URPF_API_MODE_STRICT => 2,
+			 UrpfMode::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(UrpfMode::URPF_API_MODE_OFF),
+			 1 => Some(UrpfMode::URPF_API_MODE_LOOSE),
+			 2 => Some(UrpfMode::URPF_API_MODE_STRICT),
+			 value => Some(UrpfMode::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for UrpfMode {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("UrpfMode value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for UrpfMode {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(UrpfMode::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(urpf_update_cc274cd1)]
 pub struct UrpfUpdate {
 	pub client_index: u32,
@@ -33,13 +64,13 @@ pub struct UrpfUpdate {
 	pub af: AddressFamily,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(urpf_update_reply_e8d4e804)]
 pub struct UrpfUpdateReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(urpf_update_v2_b873d028)]
 pub struct UrpfUpdateV2 {
 	pub client_index: u32,
@@ -50,20 +81,20 @@ pub struct UrpfUpdateV2 {
 	pub sw_if_index: InterfaceIndex,
 	pub table_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(urpf_update_v2_reply_e8d4e804)]
 pub struct UrpfUpdateV2Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(urpf_interface_dump_f9e6675e)]
 pub struct UrpfInterfaceDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(urpf_interface_details_f94b5374)]
 pub struct UrpfInterfaceDetails {
 	pub context: u32,
@@ -73,3 +104,12 @@ pub struct UrpfInterfaceDetails {
 	pub af: AddressFamily,
 	pub table_id: u32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"urpf_update_cc274cd1",
+	"urpf_update_reply_e8d4e804",
+	"urpf_update_v2_b873d028",
+	"urpf_update_v2_reply_e8d4e804",
+	"urpf_interface_dump_f9e6675e",
+	"urpf_interface_details_f94b5374",
+];
@@ -2,18 +2,17 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
 use crate::virtio_types::*;
 use crate::ethernet_types::*;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(create_vhost_user_if_c785c6fc)]
 pub struct CreateVhostUserIf {
 	pub client_index: u32,
@@ -30,14 +29,14 @@ pub struct CreateVhostUserIf {
 	pub mac_address: MacAddress,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(create_vhost_user_if_reply_5383d31f)]
 pub struct CreateVhostUserIfReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(modify_vhost_user_if_0e71d40b)]
 pub struct ModifyVhostUserIf {
 	pub client_index: u32,
@@ -50,13 +49,13 @@ pub struct ModifyVhostUserIf {
 	pub enable_packed: bool,
 	pub custom_dev_instance: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(modify_vhost_user_if_reply_e8d4e804)]
 pub struct ModifyVhostUserIfReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(create_vhost_user_if_v2_dba1cc1d)]
 pub struct CreateVhostUserIfV2 {
 	pub client_index: u32,
@@ -74,14 +73,14 @@ pub struct CreateVhostUserIfV2 {
 	pub mac_address: MacAddress,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(create_vhost_user_if_v2_reply_5383d31f)]
 pub struct CreateVhostUserIfV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(modify_vhost_user_if_v2_b2483771)]
 pub struct ModifyVhostUserIfV2 {
 	pub client_index: u32,
@@ -95,26 +94,26 @@ pub struct ModifyVhostUserIfV2 {
 	pub enable_event_idx: bool,
 	pub custom_dev_instance: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(modify_vhost_user_if_v2_reply_e8d4e804)]
 pub struct ModifyVhostUserIfV2Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(delete_vhost_user_if_f9e6675e)]
 pub struct DeleteVhostUserIf {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(delete_vhost_user_if_reply_e8d4e804)]
 pub struct DeleteVhostUserIfReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_vhost_user_details_0cee1e53)]
 pub struct SwInterfaceVhostUserDetails {
 	pub context: u32,
@@ -128,10 +127,25 @@ pub struct SwInterfaceVhostUserDetails {
 	pub num_regions: u32,
 	pub sock_errno: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_vhost_user_dump_f9e6675e)]
 pub struct SwInterfaceVhostUserDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"create_vhost_user_if_c785c6fc",
+	"create_vhost_user_if_reply_5383d31f",
+	"modify_vhost_user_if_0e71d40b",
+	"modify_vhost_user_if_reply_e8d4e804",
+	"create_vhost_user_if_v2_dba1cc1d",
+	"create_vhost_user_if_v2_reply_5383d31f",
+	"modify_vhost_user_if_v2_b2483771",
+	"modify_vhost_user_if_v2_reply_e8d4e804",
+	"delete_vhost_user_if_f9e6675e",
+	"delete_vhost_user_if_reply_e8d4e804",
+	"sw_interface_vhost_user_details_0cee1e53",
+	"sw_interface_vhost_user_dump_f9e6675e",
+];
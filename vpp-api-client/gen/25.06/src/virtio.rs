@@ -2,18 +2,18 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::pci_types::*;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum VirtioFlags {
 	 VIRTIO_API_FLAG_GSO=1,
 	 VIRTIO_API_FLAG_CSUM_OFFLOAD=2,
@@ -22,31 +22,70 @@ pub enum VirtioFlags {
 	 VIRTIO_API_FLAG_IN_ORDER=16,
 	 VIRTIO_API_FLAG_BUFFERING=32,
 	 VIRTIO_API_FLAG_RSS=64,
+	 Unknown(u32),
 }
 impl Default for VirtioFlags {
 	fn default() -> Self { VirtioFlags::VIRTIO_API_FLAG_GSO }
 }
-impl AsEnumFlag for VirtioFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for VirtioFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 VirtioFlags::VIRTIO_API_FLAG_GSO => 1,
+			 VirtioFlags::VIRTIO_API_FLAG_CSUM_OFFLOAD => 2,
+			 VirtioFlags::VIRTIO_API_FLAG_GRO_COALESCE => 4,
+			 VirtioFlags::VIRTIO_API_FLAG_PACKED => 8,
+			 VirtioFlags::VIRTIO_API_FLAG_IN_ORDER => 16,
+			 VirtioFlags::VIRTIO_API_FLAG_BUFFERING => 32,
+			 VirtioFlags::VIRTIO_API_FLAG_RSS => 64,
+			 VirtioFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => VirtioFlags::VIRTIO_API_FLAG_GSO,
-			 2 => VirtioFlags::VIRTIO_API_FLAG_CSUM_OFFLOAD,
-			 4 => VirtioFlags::VIRTIO_API_FLAG_GRO_COALESCE,
-			 8 => VirtioFlags::VIRTIO_API_FLAG_PACKED,
-			 16 => VirtioFlags::VIRTIO_API_FLAG_IN_ORDER,
-			 32 => VirtioFlags::VIRTIO_API_FLAG_BUFFERING,
-			 64 => VirtioFlags::VIRTIO_API_FLAG_RSS,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(VirtioFlags::VIRTIO_API_FLAG_GSO),
+			 2 => Some(VirtioFlags::VIRTIO_API_FLAG_CSUM_OFFLOAD),
+			 4 => Some(VirtioFlags::VIRTIO_API_FLAG_GRO_COALESCE),
+			 8 => Some(VirtioFlags::VIRTIO_API_FLAG_PACKED),
+			 16 => Some(VirtioFlags::VIRTIO_API_FLAG_IN_ORDER),
+			 32 => Some(VirtioFlags::VIRTIO_API_FLAG_BUFFERING),
+			 64 => Some(VirtioFlags::VIRTIO_API_FLAG_RSS),
+			 value => Some(VirtioFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for VirtioFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for VirtioFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(VirtioFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for VirtioFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x7f
+	}
+}
+impl std::ops::BitOr for VirtioFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(virtio_pci_create_1944f8db)]
 pub struct VirtioPciCreate {
 	pub client_index: u32,
@@ -58,14 +97,14 @@ pub struct VirtioPciCreate {
 	pub checksum_offload_enabled: bool,
 	pub features: u64,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(virtio_pci_create_reply_5383d31f)]
 pub struct VirtioPciCreateReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(virtio_pci_create_v2_5d096e1a)]
 pub struct VirtioPciCreateV2 {
 	pub client_index: u32,
@@ -76,33 +115,33 @@ pub struct VirtioPciCreateV2 {
 	 pub virtio_flags: EnumFlag<VirtioFlags>,
 	pub features: u64,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(virtio_pci_create_v2_reply_5383d31f)]
 pub struct VirtioPciCreateV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(virtio_pci_delete_f9e6675e)]
 pub struct VirtioPciDelete {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(virtio_pci_delete_reply_e8d4e804)]
 pub struct VirtioPciDeleteReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_virtio_pci_dump_51077d14)]
 pub struct SwInterfaceVirtioPciDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_virtio_pci_details_6ca9c167)]
 pub struct SwInterfaceVirtioPciDetails {
 	pub context: u32,
@@ -113,3 +152,14 @@ pub struct SwInterfaceVirtioPciDetails {
 	pub rx_ring_sz: u16,
 	pub features: u64,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"virtio_pci_create_1944f8db",
+	"virtio_pci_create_reply_5383d31f",
+	"virtio_pci_create_v2_5d096e1a",
+	"virtio_pci_create_v2_reply_5383d31f",
+	"virtio_pci_delete_f9e6675e",
+	"virtio_pci_delete_reply_e8d4e804",
+	"sw_interface_virtio_pci_dump_51077d14",
+	"sw_interface_virtio_pci_details_6ca9c167",
+];
@@ -2,17 +2,16 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_add_del_tunnel_0c09dc80)]
 pub struct VxlanAddDelTunnel {
 	pub client_index: u32,
@@ -26,7 +25,7 @@ pub struct VxlanAddDelTunnel {
 	pub decap_next_index: u32,
 	pub vni: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_add_del_tunnel_v2_4f223f40)]
 pub struct VxlanAddDelTunnelV2 {
 	pub client_index: u32,
@@ -42,7 +41,7 @@ pub struct VxlanAddDelTunnelV2 {
 	pub decap_next_index: u32,
 	pub vni: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_add_del_tunnel_v3_0072b037)]
 pub struct VxlanAddDelTunnelV3 {
 	pub client_index: u32,
@@ -59,42 +58,42 @@ pub struct VxlanAddDelTunnelV3 {
 	pub vni: u32,
 	pub is_l3: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_add_del_tunnel_reply_5383d31f)]
 pub struct VxlanAddDelTunnelReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_add_del_tunnel_v2_reply_5383d31f)]
 pub struct VxlanAddDelTunnelV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_add_del_tunnel_v3_reply_5383d31f)]
 pub struct VxlanAddDelTunnelV3Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_tunnel_dump_f9e6675e)]
 pub struct VxlanTunnelDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_tunnel_v2_dump_f9e6675e)]
 pub struct VxlanTunnelV2Dump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_tunnel_details_c3916cb1)]
 pub struct VxlanTunnelDetails {
 	pub context: u32,
@@ -107,7 +106,7 @@ pub struct VxlanTunnelDetails {
 	pub decap_next_index: u32,
 	pub vni: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_tunnel_v2_details_d3bdd4d9)]
 pub struct VxlanTunnelV2Details {
 	pub context: u32,
@@ -122,7 +121,7 @@ pub struct VxlanTunnelV2Details {
 	pub decap_next_index: u32,
 	pub vni: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_vxlan_bypass_65247409)]
 pub struct SwInterfaceSetVxlanBypass {
 	pub client_index: u32,
@@ -131,13 +130,13 @@ pub struct SwInterfaceSetVxlanBypass {
 	pub is_ipv6: bool,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_vxlan_bypass_reply_e8d4e804)]
 pub struct SwInterfaceSetVxlanBypassReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_offload_rx_9cc95087)]
 pub struct VxlanOffloadRx {
 	pub client_index: u32,
@@ -146,9 +145,26 @@ pub struct VxlanOffloadRx {
 	pub sw_if_index: InterfaceIndex,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_offload_rx_reply_e8d4e804)]
 pub struct VxlanOffloadRxReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"vxlan_add_del_tunnel_0c09dc80",
+	"vxlan_add_del_tunnel_v2_4f223f40",
+	"vxlan_add_del_tunnel_v3_0072b037",
+	"vxlan_add_del_tunnel_reply_5383d31f",
+	"vxlan_add_del_tunnel_v2_reply_5383d31f",
+	"vxlan_add_del_tunnel_v3_reply_5383d31f",
+	"vxlan_tunnel_dump_f9e6675e",
+	"vxlan_tunnel_v2_dump_f9e6675e",
+	"vxlan_tunnel_details_c3916cb1",
+	"vxlan_tunnel_v2_details_d3bdd4d9",
+	"sw_interface_set_vxlan_bypass_65247409",
+	"sw_interface_set_vxlan_bypass_reply_e8d4e804",
+	"vxlan_offload_rx_9cc95087",
+	"vxlan_offload_rx_reply_e8d4e804",
+];
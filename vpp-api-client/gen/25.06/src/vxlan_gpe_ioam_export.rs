@@ -2,16 +2,15 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_export_enable_disable_d4c76d3a)]
 pub struct VxlanGpeIoamExportEnableDisable {
 	pub client_index: u32,
@@ -20,9 +19,14 @@ pub struct VxlanGpeIoamExportEnableDisable {
 	pub collector_address: Ip4Address,
 	pub src_address: Ip4Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_export_enable_disable_reply_e8d4e804)]
 pub struct VxlanGpeIoamExportEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"vxlan_gpe_ioam_export_enable_disable_d4c76d3a",
+	"vxlan_gpe_ioam_export_enable_disable_reply_e8d4e804",
+];
@@ -1,6 +1,7 @@
 use crate::interface::*;
 use crate::interface_types::IfStatusFlags;
 use crate::ip_types::*;
+use futures::TryStreamExt;
 use vpp_api_transport::afunix::client::Client;
 
 #[tokio::test]
@@ -10,9 +11,19 @@ async fn test_vpp_functions() {
     assert_eq!(vl_msg_id_res.is_ok(), true);
 }
 
+#[tokio::test]
+async fn test_compatibility_report() {
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let report = client.compatibility_report(crate::MESSAGE_NAMES_AND_CRCS);
+    println!("{}", report);
+    let used = [SwInterfaceDump::get_message_name_and_crc(), SwInterfaceDetails::get_message_name_and_crc()];
+    assert!(client.compatibility_report(&used).is_compatible());
+    client.disconnect().await.unwrap();
+}
+
 #[tokio::test]
 async fn test_sw_interface_add_del_address() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
 
     let create_interface: SwInterfaceAddDelAddressReply = client.send_rcv(
         SwInterfaceAddDelAddress {
@@ -33,12 +44,12 @@ async fn test_sw_interface_add_del_address() {
 
     assert_ne!(create_interface.context, 0);
     println!("create_interface {:?}", create_interface);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_set_flags() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
 
     let create_interface: SwInterfaceSetFlagsReply = client.send_rcv(
         SwInterfaceSetFlags {
@@ -56,12 +67,12 @@ async fn test_sw_interface_set_flags() {
 
     assert_ne!(create_interface.context, 0);
     println!("SwInterfaceSetFlagsReply {:?}", create_interface);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_set_promisc() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
     
     let set_promisc_reply: SwInterfaceSetPromiscReply = client.send_rcv(
         SwInterfaceSetPromisc {
@@ -74,12 +85,12 @@ async fn test_sw_interface_set_promisc() {
 
     assert_ne!(set_promisc_reply.context, 0);
     println!("SwInterfaceSetPromiscReply {:?}", set_promisc_reply);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_hw_interface_set_mtu() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
 
     let set_mtu_reply: HwInterfaceSetMtuReply = client.send_rcv(
         HwInterfaceSetMtu {
@@ -92,12 +103,12 @@ async fn test_hw_interface_set_mtu() {
 
     assert_ne!(set_mtu_reply.context, 0);
     println!("HwInterfaceSetMtuReply {:?}", set_mtu_reply);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_set_mtu() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
 
     let set_mtu_reply: SwInterfaceSetMtuReply = client.send_rcv(
         SwInterfaceSetMtu {
@@ -110,12 +121,12 @@ async fn test_sw_interface_set_mtu() {
 
     assert_ne!(set_mtu_reply.context, 0);
     println!("SwInterfaceSetMtuReply {:?}", set_mtu_reply);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_set_ip_directed_broadcast() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
 
     let set_ip_directed_broadcast: SwInterfaceSetIpDirectedBroadcastReply = client.send_rcv(
         SwInterfaceSetIpDirectedBroadcast {
@@ -128,12 +139,12 @@ async fn test_sw_interface_set_ip_directed_broadcast() {
 
     assert_ne!(set_ip_directed_broadcast.context, 0);
     println!("SwInterfaceSetIpDirectedBroadcastReply {:?}", set_ip_directed_broadcast);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_want_interface_events() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
 
     let reply: WantInterfaceEventsReply = client.send_rcv(
         WantInterfaceEvents {
@@ -146,12 +157,12 @@ async fn test_want_interface_events() {
 
     assert_ne!(reply.context, 0);
     println!("WantInterfaceEventsReply {:?}", reply);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_address_replace_begin() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
 
     let reply: SwInterfaceAddressReplaceBeginReply = client.send_rcv(
         SwInterfaceAddressReplaceBegin {
@@ -162,12 +173,12 @@ async fn test_sw_interface_address_replace_begin() {
 
     assert_ne!(reply.context, 0);
     println!("SwInterfaceAddressReplaceBeginReply {:?}", reply);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_address_replace_end() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
 
     let reply: SwInterfaceAddressReplaceEndReply = client.send_rcv(
         SwInterfaceAddressReplaceEnd {
@@ -178,12 +189,12 @@ async fn test_sw_interface_address_replace_end() {
 
     assert_ne!(reply.context, 0);
     println!("SwInterfaceAddressReplaceEndReply {:?}", reply);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_set_table() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
 
     let reply: SwInterfaceSetTableReply = client.send_rcv(
         SwInterfaceSetTable {
@@ -196,12 +207,12 @@ async fn test_sw_interface_set_table() {
     ).await.unwrap();
     println!("SwInterfaceSetTableReply {:?}", reply);
     assert_ne!(reply.context, 0);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_get_table() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
     let reply: SwInterfaceGetTableReply = client.send_rcv(
         SwInterfaceGetTable {
             client_index: 0,
@@ -212,12 +223,12 @@ async fn test_sw_interface_get_table() {
     ).await.unwrap();
     println!("SwInterfaceGetTableReply {:?}", reply);
     assert_ne!(reply.context, 0);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_set_unnumbered() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
     let reply: SwInterfaceSetUnnumberedReply = client.send_rcv(
         SwInterfaceSetUnnumbered {
             client_index: 0,
@@ -229,12 +240,12 @@ async fn test_sw_interface_set_unnumbered() {
     ).await.unwrap();
     println!("SwInterfaceSetUnnumberedReply {:?}", reply);
     assert_ne!(reply.context, 0);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_clear_stats() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
     let reply: SwInterfaceClearStatsReply = client.send_rcv(
         SwInterfaceClearStats {
             client_index: 0,
@@ -244,12 +255,12 @@ async fn test_sw_interface_clear_stats() {
     ).await.unwrap();
     println!("SwInterfaceClearStatsReply {:?}", reply);
     assert_ne!(reply.context, 0);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_tag_add_del() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
     let reply: SwInterfaceTagAddDelReply = client.send_rcv(
         SwInterfaceTagAddDel {
             client_index: 0,
@@ -261,12 +272,12 @@ async fn test_sw_interface_tag_add_del() {
     ).await.unwrap();
     println!("SwInterfaceTagAddDelReply {:?}", reply);
     assert_ne!(reply.context, 0);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_add_del_mac_address() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
     let reply: SwInterfaceAddDelMacAddressReply = client.send_rcv(
         SwInterfaceAddDelMacAddress {
             client_index: 0,
@@ -278,12 +289,12 @@ async fn test_sw_interface_add_del_mac_address() {
     ).await.unwrap();
     println!("SwInterfaceAddDelMacAddressReply {:?}", reply);
     assert_ne!(reply.context, 0);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_set_mac_address() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
     let reply: SwInterfaceSetMacAddressReply = client.send_rcv(
         SwInterfaceSetMacAddress {
             client_index: 0,
@@ -294,12 +305,12 @@ async fn test_sw_interface_set_mac_address() {
     ).await.unwrap();
     println!("SwInterfaceSetMacAddressReply {:?}", reply);
     assert_ne!(reply.context, 0);
-    client.disconnect();
+    client.disconnect().await.unwrap();
 }
 
 #[tokio::test]
 async fn test_sw_interface_get_mac_address() {
-    let mut client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
     let reply: SwInterfaceGetMacAddressReply = client.send_rcv(
         SwInterfaceGetMacAddress {
             client_index: 0,
@@ -309,5 +320,37 @@ async fn test_sw_interface_get_mac_address() {
     ).await.unwrap();
     println!("SwInterfaceGetMacAddressReply {:?}", reply);
     assert_ne!(reply.context, 0);
-    client.disconnect();
+    client.disconnect().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_sw_interface_dump() {
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let interfaces: Vec<SwInterfaceDetails> = client.send_dump::<_, SwInterfaceDetails>(
+        SwInterfaceDump {
+            client_index: 0,
+            context: 0,
+            sw_if_index: u32::MAX,
+            name_filter_valid: false,
+            name_filter: "".try_into().unwrap(),
+        }
+    ).await.unwrap().try_collect().await.unwrap();
+    println!("SwInterfaceDetails {:?}", interfaces);
+    assert!(!interfaces.is_empty());
+    client.disconnect().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_subscribe_interface_events() {
+    let client = Client::connect("/run/vpp/api.sock","api-test").await.unwrap();
+    let events = client.subscribe::<_, SwInterfaceEvent>(
+        WantInterfaceEvents {
+            client_index: 0,
+            context: 0,
+            enable_disable: 0,
+            pid: 0,
+        }
+    ).await.unwrap();
+    drop(events);
+    client.disconnect().await.unwrap();
 }
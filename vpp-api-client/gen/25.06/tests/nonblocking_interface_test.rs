@@ -16,7 +16,7 @@ async fn test_vpp_functions() {
 
 #[tokio::test]
 async fn test_sw_interface_add_del_address() {
-    let mut client = new_client().await;
+    let client = new_client().await;
 
     let create_interface: SwInterfaceAddDelAddressReply = client.send_rcv(
         SwInterfaceAddDelAddress {
@@ -41,7 +41,7 @@ async fn test_sw_interface_add_del_address() {
 
 #[tokio::test]
 async fn test_sw_interface_set_flags() {
-    let mut client = new_client().await;
+    let client = new_client().await;
 
     let create_interface: SwInterfaceSetFlagsReply = client.send_rcv(
         SwInterfaceSetFlags {
@@ -63,7 +63,7 @@ async fn test_sw_interface_set_flags() {
 
 #[tokio::test]
 async fn test_sw_interface_set_promisc() {
-    let mut client = new_client().await;
+    let client = new_client().await;
     
     let set_promisc_reply: SwInterfaceSetPromiscReply = client.send_rcv(
         SwInterfaceSetPromisc {
@@ -80,7 +80,7 @@ async fn test_sw_interface_set_promisc() {
 
 #[tokio::test]
 async fn test_hw_interface_set_mtu() {
-    let mut client = new_client().await;
+    let client = new_client().await;
 
     let set_mtu_reply: HwInterfaceSetMtuReply = client.send_rcv(
         HwInterfaceSetMtu {
@@ -98,7 +98,7 @@ async fn test_hw_interface_set_mtu() {
 
 #[tokio::test]
 async fn test_sw_interface_set_mtu() {
-    let mut client = new_client().await;
+    let client = new_client().await;
 
     let set_mtu_reply: SwInterfaceSetMtuReply = client.send_rcv(
         SwInterfaceSetMtu {
@@ -116,7 +116,7 @@ async fn test_sw_interface_set_mtu() {
 
 #[tokio::test]
 async fn test_sw_interface_set_ip_directed_broadcast() {
-    let mut client = new_client().await;
+    let client = new_client().await;
 
     let set_ip_directed_broadcast: SwInterfaceSetIpDirectedBroadcastReply = client.send_rcv(
         SwInterfaceSetIpDirectedBroadcast {
@@ -134,7 +134,7 @@ async fn test_sw_interface_set_ip_directed_broadcast() {
 
 #[tokio::test]
 async fn test_want_interface_events() {
-    let mut client = new_client().await;
+    let client = new_client().await;
 
     let reply: WantInterfaceEventsReply = client.send_rcv(
         WantInterfaceEvents {
@@ -152,7 +152,7 @@ async fn test_want_interface_events() {
 
 #[tokio::test]
 async fn test_sw_interface_address_replace_begin() {
-    let mut client = new_client().await;
+    let client = new_client().await;
 
     let reply: SwInterfaceAddressReplaceBeginReply = client.send_rcv(
         SwInterfaceAddressReplaceBegin {
@@ -168,7 +168,7 @@ async fn test_sw_interface_address_replace_begin() {
 
 #[tokio::test]
 async fn test_sw_interface_address_replace_end() {
-    let mut client = new_client().await;
+    let client = new_client().await;
 
     let reply: SwInterfaceAddressReplaceEndReply = client.send_rcv(
         SwInterfaceAddressReplaceEnd {
@@ -184,7 +184,7 @@ async fn test_sw_interface_address_replace_end() {
 
 #[tokio::test]
 async fn test_sw_interface_set_table() {
-    let mut client = new_client().await;
+    let client = new_client().await;
 
     let reply: SwInterfaceSetTableReply = client.send_rcv(
         SwInterfaceSetTable {
@@ -202,7 +202,7 @@ async fn test_sw_interface_set_table() {
 
 #[tokio::test]
 async fn test_sw_interface_get_table() {
-    let mut client = new_client().await;
+    let client = new_client().await;
     let reply: SwInterfaceGetTableReply = client.send_rcv(
         SwInterfaceGetTable {
             client_index: 0,
@@ -218,7 +218,7 @@ async fn test_sw_interface_get_table() {
 
 #[tokio::test]
 async fn test_sw_interface_set_unnumbered() {
-    let mut client = new_client().await;
+    let client = new_client().await;
     let reply: SwInterfaceSetUnnumberedReply = client.send_rcv(
         SwInterfaceSetUnnumbered {
             client_index: 0,
@@ -235,7 +235,7 @@ async fn test_sw_interface_set_unnumbered() {
 
 #[tokio::test]
 async fn test_sw_interface_clear_stats() {
-    let mut client = new_client().await;
+    let client = new_client().await;
     let reply: SwInterfaceClearStatsReply = client.send_rcv(
         SwInterfaceClearStats {
             client_index: 0,
@@ -250,7 +250,7 @@ async fn test_sw_interface_clear_stats() {
 
 #[tokio::test]
 async fn test_sw_interface_tag_add_del() {
-    let mut client = new_client().await;
+    let client = new_client().await;
     let reply: SwInterfaceTagAddDelReply = client.send_rcv(
         SwInterfaceTagAddDel {
             client_index: 0,
@@ -267,7 +267,7 @@ async fn test_sw_interface_tag_add_del() {
 
 #[tokio::test]
 async fn test_sw_interface_add_del_mac_address() {
-    let mut client = new_client().await;
+    let client = new_client().await;
     let reply: SwInterfaceAddDelMacAddressReply = client.send_rcv(
         SwInterfaceAddDelMacAddress {
             client_index: 0,
@@ -284,7 +284,7 @@ async fn test_sw_interface_add_del_mac_address() {
 
 #[tokio::test]
 async fn test_sw_interface_set_mac_address() {
-    let mut client = new_client().await;
+    let client = new_client().await;
     let reply: SwInterfaceSetMacAddressReply = client.send_rcv(
         SwInterfaceSetMacAddress {
             client_index: 0,
@@ -300,7 +300,7 @@ async fn test_sw_interface_set_mac_address() {
 
 #[tokio::test]
 async fn test_sw_interface_get_mac_address() {
-    let mut client = new_client().await;
+    let client = new_client().await;
     let reply: SwInterfaceGetMacAddressReply = client.send_rcv(
         SwInterfaceGetMacAddress {
             client_index: 0,
@@ -0,0 +1,122 @@
+use crate::fib_types::*;
+use crate::interface::*;
+use crate::interface_types::IfStatusFlags;
+use crate::ip::*;
+use crate::ip_types::*;
+use crate::vlib::*;
+use serde::Serialize;
+use vpp_api_encoding::wire::{decode_from_slice, encode_to_vec, VppDecode, VppEncode};
+
+/// Checks that the wire codec writes the same bytes as bincode, and reads them back.
+fn assert_same_as_bincode<T: Serialize + VppEncode + VppDecode>(msg: &T) {
+    let config = bincode_next::config::legacy()
+        .with_big_endian()
+        .with_fixed_int_encoding();
+    let expected = bincode_next::serde::encode_to_vec(msg, config).unwrap();
+    let encoded = encode_to_vec(msg).unwrap();
+    assert_eq!(encoded, expected, "{}", std::any::type_name::<T>());
+
+    let (decoded, len) = decode_from_slice::<T>(&encoded).unwrap();
+    assert_eq!(len, encoded.len());
+    assert_eq!(encode_to_vec(&decoded).unwrap(), encoded);
+}
+
+#[test]
+fn test_wire_encoding_matches_bincode() {
+    assert_same_as_bincode(&SwInterfaceSetFlags {
+        client_index: 1,
+        context: 2,
+        sw_if_index: 3,
+        flags: IfStatusFlags::IF_STATUS_API_FLAG_ADMIN_UP.into(),
+    });
+    assert_same_as_bincode(&CliInbandReply {
+        context: 7,
+        retval: -1,
+        reply: "vpp v25.06".try_into().unwrap(),
+    });
+
+    assert_same_as_bincode(&route_add(2, 2));
+}
+
+/// Builds a route to 10.0.0.0/8 with `paths` paths, claiming to have `n_paths`.
+fn route_add(n_paths: u8, paths: usize) -> IpRouteAddDel {
+    let path = FibPath {
+        sw_if_index: 1,
+        weight: 1,
+        typ: FibPathType::FIB_API_PATH_TYPE_LOCAL,
+        proto: FibPathNhProto::FIB_API_PATH_NH_PROTO_IP6,
+        nh: FibPathNh {
+            address: AddressUnion::new_Ip4Address([192, 168, 1, 1]),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    IpRouteAddDel {
+        client_index: 1,
+        context: 9,
+        is_add: true,
+        is_multipath: false,
+        route: IpRoute {
+            table_id: 0,
+            stats_index: 0,
+            prefix: Prefix {
+                address: Address {
+                    af: AddressFamily::ADDRESS_IP4,
+                    un: AddressUnion::new_Ip4Address([10, 0, 0, 0]),
+                },
+                len: 8,
+            },
+            n_paths,
+            paths: VariableSizeArray(vec![path; paths]),
+        },
+    }
+}
+
+#[test]
+fn test_wire_encoding_counts_arrays() {
+    let encoded = encode_to_vec(&route_add(0, 3)).unwrap();
+    assert_eq!(encoded, encode_to_vec(&route_add(3, 3)).unwrap());
+    assert!(encode_to_vec(&route_add(2, 3)).is_err());
+
+    let mut trailing = encoded.clone();
+    trailing.extend([0; 8]);
+    let (decoded, len) = decode_from_slice::<IpRouteAddDel>(&trailing).unwrap();
+    assert_eq!(len, encoded.len());
+    assert_eq!(decoded.route.n_paths, 3);
+    assert_eq!(decoded.route.paths.0.len(), 3);
+}
+
+#[test]
+fn test_wire_encoding_keeps_unknown_values() {
+    // an address family and an interface flag this API does not know about
+    let address = [7, 10, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    let (decoded, _) = decode_from_slice::<Address>(&address).unwrap();
+    assert_eq!(decoded.af, AddressFamily::Unknown(7));
+    assert_same_as_bincode(&decoded);
+    assert_eq!(encode_to_vec(&decoded).unwrap(), address);
+
+    let set_flags = [0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0x80, 1];
+    let (decoded, _) = decode_from_slice::<SwInterfaceSetFlags>(&set_flags).unwrap();
+    assert!(decoded.flags.contains(IfStatusFlags::IF_STATUS_API_FLAG_ADMIN_UP));
+    assert!(decoded.flags.contains(IfStatusFlags::Unknown(0x8000)));
+    assert_eq!(decoded.flags.bits(), 0x8001);
+    assert_same_as_bincode(&decoded);
+    assert_eq!(encode_to_vec(&decoded).unwrap(), set_flags);
+}
+
+#[test]
+fn test_wire_encoding_reuses_buffer() {
+    let mut buf = Vec::with_capacity(64);
+    for context in 0..3 {
+        buf.clear();
+        CliInbandReply {
+            context,
+            retval: 0,
+            reply: "ok".try_into().unwrap(),
+        }
+        .vpp_encode(&mut buf)
+        .unwrap();
+        assert_eq!(buf, [0, 0, 0, context as u8, 0, 0, 0, 0, 0, 0, 0, 2, b'o', b'k']);
+    }
+    assert!(CliInbandReply::vpp_decode(&mut &buf[..13]).is_err());
+}
@@ -2,16 +2,15 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(adl_interface_enable_disable_5501adee)]
 pub struct AdlInterfaceEnableDisable {
 	pub client_index: u32,
@@ -19,13 +18,13 @@ pub struct AdlInterfaceEnableDisable {
 	pub sw_if_index: InterfaceIndex,
 	pub enable_disable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(adl_interface_enable_disable_reply_e8d4e804)]
 pub struct AdlInterfaceEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(adl_allowlist_enable_disable_ea88828d)]
 pub struct AdlAllowlistEnableDisable {
 	pub client_index: u32,
@@ -36,9 +35,16 @@ pub struct AdlAllowlistEnableDisable {
 	pub ip6: bool,
 	pub default_adl: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(adl_allowlist_enable_disable_reply_e8d4e804)]
 pub struct AdlAllowlistEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"adl_interface_enable_disable_5501adee",
+	"adl_interface_enable_disable_reply_e8d4e804",
+	"adl_allowlist_enable_disable_ea88828d",
+	"adl_allowlist_enable_disable_reply_e8d4e804",
+];
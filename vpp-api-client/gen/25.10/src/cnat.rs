@@ -2,13 +2,12 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::fib_types::*;
@@ -17,7 +16,7 @@ use crate::ip::*;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
 // Implementation for cnat_endpoint
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct CnatEndpoint {
 	pub addr: Address,
 	pub sw_if_index: InterfaceIndex,
@@ -25,14 +24,14 @@ pub struct CnatEndpoint {
 	pub port: u16,
 }
 // Implementation for cnat_endpoint_tuple
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct CnatEndpointTuple {
 	pub dst_ep: CnatEndpoint,
 	pub src_ep: CnatEndpoint,
 	pub flags: u8,
 }
 // Implementation for cnat_translation
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct CnatTranslation {
 	pub vip: CnatEndpoint,
 	pub id: u32,
@@ -42,10 +41,11 @@ pub struct CnatTranslation {
 	pub lb_type: CnatLbType,
 	pub n_paths: u32,
 	pub flow_hash_config: IpFlowHashConfigV2,
+	#[vpp(count = "n_paths")]
 	pub paths: VariableSizeArray<CnatEndpointTuple>,
 }
 // Implementation for cnat_session
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct CnatSession {
 	pub src: CnatEndpoint,
 	pub dst: CnatEndpoint,
@@ -54,144 +54,311 @@ pub struct CnatSession {
 	pub location: u8,
 	pub timestamp: f64,
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u8)]
 pub enum CnatTranslationFlags {
 	 CNAT_TRANSLATION_ALLOC_PORT=1,
 	 CNAT_TRANSLATION_NO_RETURN_SESSION=4,
+	 Unknown(u32),
 }
 impl Default for CnatTranslationFlags {
 	fn default() -> Self { CnatTranslationFlags::CNAT_TRANSLATION_ALLOC_PORT }
 }
-impl AsEnumFlag for CnatTranslationFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for CnatTranslationFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 CnatTranslationFlags::CNAT_TRANSLATION_ALLOC_PORT => 1,
+			 CnatTranslationFlags::CNAT_TRANSLATION_NO_RETURN_SESSION => 4,
+			 CnatTranslationFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => CnatTranslationFlags::CNAT_TRANSLATION_ALLOC_PORT,
-			 4 => CnatTranslationFlags::CNAT_TRANSLATION_NO_RETURN_SESSION,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(CnatTranslationFlags::CNAT_TRANSLATION_ALLOC_PORT),
+			 4 => Some(CnatTranslationFlags::CNAT_TRANSLATION_NO_RETURN_SESSION),
+			 value => Some(CnatTranslationFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for CnatTranslationFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("CnatTranslationFlags value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for CnatTranslationFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(CnatTranslationFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for CnatTranslationFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 8 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x5
+	}
+}
+impl std::ops::BitOr for CnatTranslationFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u8)]
 pub enum CnatEndpointTupleFlags {
 	 CNAT_EPT_NO_NAT=1,
+	 Unknown(u32),
 }
 impl Default for CnatEndpointTupleFlags {
 	fn default() -> Self { CnatEndpointTupleFlags::CNAT_EPT_NO_NAT }
 }
-impl AsEnumFlag for CnatEndpointTupleFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for CnatEndpointTupleFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 CnatEndpointTupleFlags::CNAT_EPT_NO_NAT => 1,
+			 CnatEndpointTupleFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => CnatEndpointTupleFlags::CNAT_EPT_NO_NAT,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(CnatEndpointTupleFlags::CNAT_EPT_NO_NAT),
+			 value => Some(CnatEndpointTupleFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for CnatEndpointTupleFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("CnatEndpointTupleFlags value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for CnatEndpointTupleFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(CnatEndpointTupleFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for CnatEndpointTupleFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 8 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x1
+	}
+}
+impl std::ops::BitOr for CnatEndpointTupleFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum CnatLbType {
 	 CNAT_LB_TYPE_DEFAULT=0,
 	 CNAT_LB_TYPE_MAGLEV=1,
+	 Unknown(u32),
 }
 impl Default for CnatLbType {
 	fn default() -> Self { CnatLbType::CNAT_LB_TYPE_DEFAULT }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for CnatLbType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 CnatLbType::CNAT_LB_TYPE_DEFAULT => 0,
+			 CnatLbType::CNAT_LB_TYPE_MAGLEV => 1,
+			 CnatLbType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(CnatLbType::CNAT_LB_TYPE_DEFAULT),
+			 1 => Some(CnatLbType::CNAT_LB_TYPE_MAGLEV),
+			 value => Some(CnatLbType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for CnatLbType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("CnatLbType value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for CnatLbType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(CnatLbType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum CnatSnatPolicyTable {
 	 CNAT_POLICY_INCLUDE_V4=0,
 	 CNAT_POLICY_INCLUDE_V6=1,
 	 CNAT_POLICY_POD=2,
 	 CNAT_POLICY_HOST=3,
+	 Unknown(u32),
 }
 impl Default for CnatSnatPolicyTable {
 	fn default() -> Self { CnatSnatPolicyTable::CNAT_POLICY_INCLUDE_V4 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for CnatSnatPolicyTable {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 CnatSnatPolicyTable::CNAT_POLICY_INCLUDE_V4 => 0,
+			 CnatSnatPolicyTable::CNAT_POLICY_INCLUDE_V6 => 1,
+			 CnatSnatPolicyTable::CNAT_POLICY_POD => 2,
+			 CnatSnatPolicyTable::CNAT_POLICY_HOST => 3,
+			 CnatSnatPolicyTable::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(CnatSnatPolicyTable::CNAT_POLICY_INCLUDE_V4),
+			 1 => Some(CnatSnatPolicyTable::CNAT_POLICY_INCLUDE_V6),
+			 2 => Some(CnatSnatPolicyTable::CNAT_POLICY_POD),
+			 3 => Some(CnatSnatPolicyTable::CNAT_POLICY_HOST),
+			 value => Some(CnatSnatPolicyTable::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for CnatSnatPolicyTable {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("CnatSnatPolicyTable value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for CnatSnatPolicyTable {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(CnatSnatPolicyTable::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum CnatSnatPolicies {
 	 CNAT_POLICY_NONE=0,
 	 CNAT_POLICY_IF_PFX=1,
 	 CNAT_POLICY_K8S=2,
+	 Unknown(u32),
 }
 impl Default for CnatSnatPolicies {
 	fn default() -> Self { CnatSnatPolicies::CNAT_POLICY_NONE }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for CnatSnatPolicies {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 CnatSnatPolicies::CNAT_POLICY_NONE => 0,
+			 CnatSnatPolicies::CNAT_POLICY_IF_PFX => 1,
+			 CnatSnatPolicies::CNAT_POLICY_K8S => 2,
+			 CnatSnatPolicies::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(CnatSnatPolicies::CNAT_POLICY_NONE),
+			 1 => Some(CnatSnatPolicies::CNAT_POLICY_IF_PFX),
+			 2 => Some(CnatSnatPolicies::CNAT_POLICY_K8S),
+			 value => Some(CnatSnatPolicies::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for CnatSnatPolicies {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("CnatSnatPolicies value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for CnatSnatPolicies {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(CnatSnatPolicies::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_translation_update_f8d40bc5)]
 pub struct CnatTranslationUpdate {
 	pub client_index: u32,
 	pub context: u32,
 	pub translation: CnatTranslation,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_translation_update_reply_e2fc8294)]
 pub struct CnatTranslationUpdateReply {
 	pub context: u32,
 	pub retval: i32,
 	pub id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_translation_del_3a91bde5)]
 pub struct CnatTranslationDel {
 	pub client_index: u32,
 	pub context: u32,
 	pub id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_translation_del_reply_e8d4e804)]
 pub struct CnatTranslationDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_translation_details_1a5140b7)]
 pub struct CnatTranslationDetails {
 	pub context: u32,
 	pub translation: CnatTranslation,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_translation_dump_51077d14)]
 pub struct CnatTranslationDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_session_purge_51077d14)]
 pub struct CnatSessionPurge {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_session_purge_reply_e8d4e804)]
 pub struct CnatSessionPurgeReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_session_details_7e5017c7)]
 pub struct CnatSessionDetails {
 	pub context: u32,
 	pub session: CnatSession,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_session_dump_51077d14)]
 pub struct CnatSessionDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_set_snat_addresses_d997e96c)]
 pub struct CnatSetSnatAddresses {
 	pub client_index: u32,
@@ -200,19 +367,19 @@ pub struct CnatSetSnatAddresses {
 	pub snat_ip6: Ip6Address,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_set_snat_addresses_reply_e8d4e804)]
 pub struct CnatSetSnatAddressesReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_get_snat_addresses_51077d14)]
 pub struct CnatGetSnatAddresses {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_get_snat_addresses_reply_879513c1)]
 pub struct CnatGetSnatAddressesReply {
 	pub context: u32,
@@ -222,7 +389,7 @@ pub struct CnatGetSnatAddressesReply {
 	pub snat_ip6: Ip6Address,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_snat_policy_add_del_exclude_pfx_e26dd79a)]
 pub struct CnatSnatPolicyAddDelExcludePfx {
 	pub client_index: u32,
@@ -230,13 +397,13 @@ pub struct CnatSnatPolicyAddDelExcludePfx {
 	pub is_add: u8,
 	pub prefix: Prefix,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_snat_policy_add_del_exclude_pfx_reply_e8d4e804)]
 pub struct CnatSnatPolicyAddDelExcludePfxReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_snat_policy_add_del_if_4ebb8d02)]
 pub struct CnatSnatPolicyAddDelIf {
 	pub client_index: u32,
@@ -245,22 +412,45 @@ pub struct CnatSnatPolicyAddDelIf {
 	pub is_add: u8,
 	pub table: CnatSnatPolicyTable,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_snat_policy_add_del_if_reply_e8d4e804)]
 pub struct CnatSnatPolicyAddDelIfReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_set_snat_policy_d3e6eaf4)]
 pub struct CnatSetSnatPolicy {
 	pub client_index: u32,
 	pub context: u32,
 	pub policy: CnatSnatPolicies,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(cnat_set_snat_policy_reply_e8d4e804)]
 pub struct CnatSetSnatPolicyReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"cnat_translation_update_f8d40bc5",
+	"cnat_translation_update_reply_e2fc8294",
+	"cnat_translation_del_3a91bde5",
+	"cnat_translation_del_reply_e8d4e804",
+	"cnat_translation_details_1a5140b7",
+	"cnat_translation_dump_51077d14",
+	"cnat_session_purge_51077d14",
+	"cnat_session_purge_reply_e8d4e804",
+	"cnat_session_details_7e5017c7",
+	"cnat_session_dump_51077d14",
+	"cnat_set_snat_addresses_d997e96c",
+	"cnat_set_snat_addresses_reply_e8d4e804",
+	"cnat_get_snat_addresses_51077d14",
+	"cnat_get_snat_addresses_reply_879513c1",
+	"cnat_snat_policy_add_del_exclude_pfx_e26dd79a",
+	"cnat_snat_policy_add_del_exclude_pfx_reply_e8d4e804",
+	"cnat_snat_policy_add_del_if_4ebb8d02",
+	"cnat_snat_policy_add_del_if_reply_e8d4e804",
+	"cnat_set_snat_policy_d3e6eaf4",
+	"cnat_set_snat_policy_reply_e8d4e804",
+];
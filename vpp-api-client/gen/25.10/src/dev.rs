@@ -2,59 +2,127 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum DevFlags {
 	 VL_API_DEV_FLAG_NO_STATS=1,
+	 Unknown(u32),
 }
 impl Default for DevFlags {
 	fn default() -> Self { DevFlags::VL_API_DEV_FLAG_NO_STATS }
 }
-impl AsEnumFlag for DevFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for DevFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 DevFlags::VL_API_DEV_FLAG_NO_STATS => 1,
+			 DevFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => DevFlags::VL_API_DEV_FLAG_NO_STATS,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(DevFlags::VL_API_DEV_FLAG_NO_STATS),
+			 value => Some(DevFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for DevFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for DevFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(DevFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for DevFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x1
+	}
+}
+impl std::ops::BitOr for DevFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum DevPortFlags {
 	 VL_API_DEV_PORT_FLAG_INTERRUPT_MODE=1,
 	 VL_API_DEV_PORT_FLAG_CONSISTENT_QP=2,
+	 Unknown(u32),
 }
 impl Default for DevPortFlags {
 	fn default() -> Self { DevPortFlags::VL_API_DEV_PORT_FLAG_INTERRUPT_MODE }
 }
-impl AsEnumFlag for DevPortFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for DevPortFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 DevPortFlags::VL_API_DEV_PORT_FLAG_INTERRUPT_MODE => 1,
+			 DevPortFlags::VL_API_DEV_PORT_FLAG_CONSISTENT_QP => 2,
+			 DevPortFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => DevPortFlags::VL_API_DEV_PORT_FLAG_INTERRUPT_MODE,
-			 2 => DevPortFlags::VL_API_DEV_PORT_FLAG_CONSISTENT_QP,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(DevPortFlags::VL_API_DEV_PORT_FLAG_INTERRUPT_MODE),
+			 2 => Some(DevPortFlags::VL_API_DEV_PORT_FLAG_CONSISTENT_QP),
+			 value => Some(DevPortFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for DevPortFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for DevPortFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(DevPortFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for DevPortFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x3
+	}
+}
+impl std::ops::BitOr for DevPortFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dev_attach_44b725fc)]
 pub struct DevAttach {
 	pub client_index: u32,
@@ -64,7 +132,7 @@ pub struct DevAttach {
 	 pub flags: EnumFlag<DevFlags>,
 	pub args: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dev_attach_reply_6082b181)]
 pub struct DevAttachReply {
 	pub context: u32,
@@ -72,21 +140,21 @@ pub struct DevAttachReply {
 	pub retval: i32,
 	pub error_string: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dev_detach_afae52d6)]
 pub struct DevDetach {
 	pub client_index: u32,
 	pub context: u32,
 	pub dev_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dev_detach_reply_c8d74455)]
 pub struct DevDetachReply {
 	pub context: u32,
 	pub retval: i32,
 	pub error_string: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dev_create_port_if_dbdf06f3)]
 pub struct DevCreatePortIf {
 	pub client_index: u32,
@@ -101,7 +169,7 @@ pub struct DevCreatePortIf {
 	 pub flags: EnumFlag<DevPortFlags>,
 	pub args: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dev_create_port_if_reply_243c2374)]
 pub struct DevCreatePortIfReply {
 	pub client_index: u32,
@@ -110,17 +178,28 @@ pub struct DevCreatePortIfReply {
 	pub retval: i32,
 	pub error_string: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dev_remove_port_if_529cb13f)]
 pub struct DevRemovePortIf {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dev_remove_port_if_reply_c8d74455)]
 pub struct DevRemovePortIfReply {
 	pub context: u32,
 	pub retval: i32,
 	pub error_string: VariableSizeString,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"dev_attach_44b725fc",
+	"dev_attach_reply_6082b181",
+	"dev_detach_afae52d6",
+	"dev_detach_reply_c8d74455",
+	"dev_create_port_if_dbdf06f3",
+	"dev_create_port_if_reply_243c2374",
+	"dev_remove_port_if_529cb13f",
+	"dev_remove_port_if_reply_c8d74455",
+];
@@ -2,19 +2,18 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
 // Implementation for dhcp_client
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct DhcpClient {
 	pub sw_if_index: InterfaceIndex,
 	pub hostname: FixedSizeString<typenum::U64>,
@@ -25,12 +24,12 @@ pub struct DhcpClient {
 	pub pid: u32,
 }
 // Implementation for domain_server
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct DomainServer {
 	pub address: Address,
 }
 // Implementation for dhcp_lease
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct DhcpLease {
 	pub sw_if_index: InterfaceIndex,
 	pub state: DhcpClientState,
@@ -41,50 +40,115 @@ pub struct DhcpLease {
 	pub router_address: Address,
 	pub host_mac: MacAddress,
 	pub count: u8,
+	#[vpp(count = "count")]
 	pub domain_server: VariableSizeArray<DomainServer>,
 }
 // Implementation for dhcp_server
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct DhcpServer {
 	pub server_vrf_id: u32,
 	pub dhcp_server: Address,
 }
 // Implementation for dhcp6_address_info
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Dhcp6AddressInfo {
 	pub address: Ip6Address,
 	pub valid_time: u32,
 	pub preferred_time: u32,
 }
 // Implementation for dhcp6_pd_prefix_info
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Dhcp6PdPrefixInfo {
 	pub prefix: Ip6Prefix,
 	pub valid_time: u32,
 	pub preferred_time: u32,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum VssType {
 	 VSS_TYPE_API_ASCII=0,
 	 VSS_TYPE_API_VPN_ID=1,
 	 VSS_TYPE_API_INVALID=123,
 	 VSS_TYPE_API_DEFAULT=255,
+	 Unknown(u32),
 }
 impl Default for VssType {
 	fn default() -> Self { VssType::VSS_TYPE_API_ASCII }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for VssType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 VssType::VSS_TYPE_API_ASCII => 0,
+			 VssType::VSS_TYPE_API_VPN_ID => 1,
+			 VssType::VSS_TYPE_API_INVALID => 123,
+			 VssType::VSS_TYPE_API_DEFAULT => 255,
+			 VssType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(VssType::VSS_TYPE_API_ASCII),
+			 1 => Some(VssType::VSS_TYPE_API_VPN_ID),
+			 123 => Some(VssType::VSS_TYPE_API_INVALID),
+			 255 => Some(VssType::VSS_TYPE_API_DEFAULT),
+			 value => Some(VssType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for VssType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for VssType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(VssType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum DhcpClientState {
 	 DHCP_CLIENT_STATE_API_DISCOVER=0,
 	 DHCP_CLIENT_STATE_API_REQUEST=1,
 	 DHCP_CLIENT_STATE_API_BOUND=2,
+	 Unknown(u32),
 }
 impl Default for DhcpClientState {
 	fn default() -> Self { DhcpClientState::DHCP_CLIENT_STATE_API_DISCOVER }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for DhcpClientState {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 DhcpClientState::DHCP_CLIENT_STATE_API_DISCOVER => 0,
+			 DhcpClientState::DHCP_CLIENT_STATE_API_REQUEST => 1,
+			 DhcpClientState::DHCP_CLIENT_STATE_API_BOUND => 2,
+			 DhcpClientState::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(DhcpClientState::DHCP_CLIENT_STATE_API_DISCOVER),
+			 1 => Some(DhcpClientState::DHCP_CLIENT_STATE_API_REQUEST),
+			 2 => Some(DhcpClientState::DHCP_CLIENT_STATE_API_BOUND),
+			 value => Some(DhcpClientState::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for DhcpClientState {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for DhcpClientState {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(DhcpClientState::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum Dhcpv6MsgType {
 	 DHCPV6_MSG_API_SOLICIT=1,
@@ -100,30 +164,81 @@ pub enum Dhcpv6MsgType {
 	 DHCPV6_MSG_API_INFORMATION_REQUEST=11,
 	 DHCPV6_MSG_API_RELAY_FORW=12,
 	 DHCPV6_MSG_API_RELAY_REPL=13,
+	 Unknown(u32),
 }
 impl Default for Dhcpv6MsgType {
 	fn default() -> Self { Dhcpv6MsgType::DHCPV6_MSG_API_SOLICIT }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for Dhcpv6MsgType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 Dhcpv6MsgType::DHCPV6_MSG_API_SOLICIT => 1,
+			 Dhcpv6MsgType::DHCPV6_MSG_API_ADVERTISE => 2,
+			 Dhcpv6MsgType::DHCPV6_MSG_API_REQUEST => 3,
+			 Dhcpv6MsgType::DHCPV6_MSG_API_CONFIRM => 4,
+			 Dhcpv6MsgType::DHCPV6_MSG_API_RENEW => 5,
+			 Dhcpv6MsgType::DHCPV6_MSG_API_REBIND => 6,
+			 Dhcpv6MsgType::DHCPV6_MSG_API_REPLY => 7,
+			 Dhcpv6MsgType::DHCPV6_MSG_API_RELEASE => 8,
+			 Dhcpv6MsgType::DHCPV6_MSG_API_DECLINE => 9,
+			 Dhcpv6MsgType::DHCPV6_MSG_API_RECONFIGURE => 10,
+			 Dhcpv6MsgType::DHCPV6_MSG_API_INFORMATION_REQUEST => 11,
+			 Dhcpv6MsgType::DHCPV6_MSG_API_RELAY_FORW => 12,
+			 Dhcpv6MsgType::DHCPV6_MSG_API_RELAY_REPL => 13,
+			 Dhcpv6MsgType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 1 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_SOLICIT),
+			 2 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_ADVERTISE),
+			 3 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_REQUEST),
+			 4 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_CONFIRM),
+			 5 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_RENEW),
+			 6 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_REBIND),
+			 7 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_REPLY),
+			 8 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_RELEASE),
+			 9 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_DECLINE),
+			 10 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_RECONFIGURE),
+			 11 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_INFORMATION_REQUEST),
+			 12 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_RELAY_FORW),
+			 13 => Some(Dhcpv6MsgType::DHCPV6_MSG_API_RELAY_REPL),
+			 value => Some(Dhcpv6MsgType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for Dhcpv6MsgType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for Dhcpv6MsgType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(Dhcpv6MsgType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_plugin_get_version_51077d14)]
 pub struct DhcpPluginGetVersion {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_plugin_get_version_reply_9b32cf86)]
 pub struct DhcpPluginGetVersionReply {
 	pub context: u32,
 	pub major: u32,
 	pub minor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_plugin_control_ping_51077d14)]
 pub struct DhcpPluginControlPing {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_plugin_control_ping_reply_f6b0b8ca)]
 pub struct DhcpPluginControlPingReply {
 	pub context: u32,
@@ -131,7 +246,7 @@ pub struct DhcpPluginControlPingReply {
 	pub client_index: u32,
 	pub vpe_pid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_proxy_config_4058a689)]
 pub struct DhcpProxyConfig {
 	pub client_index: u32,
@@ -142,13 +257,13 @@ pub struct DhcpProxyConfig {
 	pub dhcp_server: Address,
 	pub dhcp_src_address: Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_proxy_config_reply_e8d4e804)]
 pub struct DhcpProxyConfigReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_proxy_set_vss_50537301)]
 pub struct DhcpProxySetVss {
 	pub client_index: u32,
@@ -161,13 +276,13 @@ pub struct DhcpProxySetVss {
 	pub is_ipv6: bool,
 	pub is_add: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_proxy_set_vss_reply_e8d4e804)]
 pub struct DhcpProxySetVssReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_client_config_1af013ea)]
 pub struct DhcpClientConfig {
 	pub client_index: u32,
@@ -175,40 +290,40 @@ pub struct DhcpClientConfig {
 	pub is_add: bool,
 	pub client: DhcpClient,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_client_config_reply_e8d4e804)]
 pub struct DhcpClientConfigReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_compl_event_e18124b7)]
 pub struct DhcpComplEvent {
 	pub client_index: u32,
 	pub pid: u32,
 	pub lease: DhcpLease,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_client_dump_51077d14)]
 pub struct DhcpClientDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_client_details_8897b2d8)]
 pub struct DhcpClientDetails {
 	pub context: u32,
 	pub client: DhcpClient,
 	pub lease: DhcpLease,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_proxy_dump_5c5b063f)]
 pub struct DhcpProxyDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub is_ip6: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_proxy_details_dcbaf540)]
 pub struct DhcpProxyDetails {
 	pub context: u32,
@@ -220,9 +335,10 @@ pub struct DhcpProxyDetails {
 	pub vss_vpn_ascii_id: FixedSizeString<typenum::U129>,
 	pub dhcp_src_address: Address,
 	pub count: u8,
+	#[vpp(count = "count")]
 	pub servers: VariableSizeArray<DhcpServer>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_client_detect_enable_disable_ae6cfcfb)]
 pub struct DhcpClientDetectEnableDisable {
 	pub client_index: u32,
@@ -230,39 +346,39 @@ pub struct DhcpClientDetectEnableDisable {
 	pub sw_if_index: InterfaceIndex,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp_client_detect_enable_disable_reply_e8d4e804)]
 pub struct DhcpClientDetectEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp6_duid_ll_set_0f6ca323)]
 pub struct Dhcp6DuidLlSet {
 	pub client_index: u32,
 	pub context: u32,
 	pub duid_ll: FixedSizeArray<u8, typenum::U10>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp6_duid_ll_set_reply_e8d4e804)]
 pub struct Dhcp6DuidLlSetReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp6_clients_enable_disable_b3e225d2)]
 pub struct Dhcp6ClientsEnableDisable {
 	pub client_index: u32,
 	pub context: u32,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp6_clients_enable_disable_reply_e8d4e804)]
 pub struct Dhcp6ClientsEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp6_send_client_message_f8222476)]
 pub struct Dhcp6SendClientMessage {
 	pub client_index: u32,
@@ -278,15 +394,16 @@ pub struct Dhcp6SendClientMessage {
 	pub T1: u32,
 	pub T2: u32,
 	pub n_addresses: u32,
+	#[vpp(count = "n_addresses")]
 	pub addresses: VariableSizeArray<Dhcp6AddressInfo>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp6_send_client_message_reply_e8d4e804)]
 pub struct Dhcp6SendClientMessageReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp6_pd_send_client_message_3739fd8d)]
 pub struct Dhcp6PdSendClientMessage {
 	pub client_index: u32,
@@ -302,15 +419,16 @@ pub struct Dhcp6PdSendClientMessage {
 	pub T1: u32,
 	pub T2: u32,
 	pub n_prefixes: u32,
+	#[vpp(count = "n_prefixes")]
 	pub prefixes: VariableSizeArray<Dhcp6PdPrefixInfo>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp6_pd_send_client_message_reply_e8d4e804)]
 pub struct Dhcp6PdSendClientMessageReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_dhcp6_reply_events_05b454b5)]
 pub struct WantDhcp6ReplyEvents {
 	pub client_index: u32,
@@ -318,13 +436,13 @@ pub struct WantDhcp6ReplyEvents {
 	pub enable_disable: u8,
 	pub pid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_dhcp6_reply_events_reply_e8d4e804)]
 pub struct WantDhcp6ReplyEventsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_dhcp6_pd_reply_events_c5e2af94)]
 pub struct WantDhcp6PdReplyEvents {
 	pub client_index: u32,
@@ -332,13 +450,13 @@ pub struct WantDhcp6PdReplyEvents {
 	pub enable_disable: bool,
 	pub pid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_dhcp6_pd_reply_events_reply_e8d4e804)]
 pub struct WantDhcp6PdReplyEventsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp6_reply_event_85b7b17e)]
 pub struct Dhcp6ReplyEvent {
 	pub client_index: u32,
@@ -352,9 +470,10 @@ pub struct Dhcp6ReplyEvent {
 	pub status_code: u16,
 	pub preference: u8,
 	pub n_addresses: u32,
+	#[vpp(count = "n_addresses")]
 	pub addresses: VariableSizeArray<Dhcp6AddressInfo>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(dhcp6_pd_reply_event_5e878029)]
 pub struct Dhcp6PdReplyEvent {
 	pub client_index: u32,
@@ -368,5 +487,40 @@ pub struct Dhcp6PdReplyEvent {
 	pub status_code: u16,
 	pub preference: u8,
 	pub n_prefixes: u32,
+	#[vpp(count = "n_prefixes")]
 	pub prefixes: VariableSizeArray<Dhcp6PdPrefixInfo>,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"dhcp_plugin_get_version_51077d14",
+	"dhcp_plugin_get_version_reply_9b32cf86",
+	"dhcp_plugin_control_ping_51077d14",
+	"dhcp_plugin_control_ping_reply_f6b0b8ca",
+	"dhcp_proxy_config_4058a689",
+	"dhcp_proxy_config_reply_e8d4e804",
+	"dhcp_proxy_set_vss_50537301",
+	"dhcp_proxy_set_vss_reply_e8d4e804",
+	"dhcp_client_config_1af013ea",
+	"dhcp_client_config_reply_e8d4e804",
+	"dhcp_compl_event_e18124b7",
+	"dhcp_client_dump_51077d14",
+	"dhcp_client_details_8897b2d8",
+	"dhcp_proxy_dump_5c5b063f",
+	"dhcp_proxy_details_dcbaf540",
+	"dhcp_client_detect_enable_disable_ae6cfcfb",
+	"dhcp_client_detect_enable_disable_reply_e8d4e804",
+	"dhcp6_duid_ll_set_0f6ca323",
+	"dhcp6_duid_ll_set_reply_e8d4e804",
+	"dhcp6_clients_enable_disable_b3e225d2",
+	"dhcp6_clients_enable_disable_reply_e8d4e804",
+	"dhcp6_send_client_message_f8222476",
+	"dhcp6_send_client_message_reply_e8d4e804",
+	"dhcp6_pd_send_client_message_3739fd8d",
+	"dhcp6_pd_send_client_message_reply_e8d4e804",
+	"want_dhcp6_reply_events_05b454b5",
+	"want_dhcp6_reply_events_reply_e8d4e804",
+	"want_dhcp6_pd_reply_events_c5e2af94",
+	"want_dhcp6_pd_reply_events_reply_e8d4e804",
+	"dhcp6_reply_event_85b7b17e",
+	"dhcp6_pd_reply_event_5e878029",
+];
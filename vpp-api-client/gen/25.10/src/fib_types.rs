@@ -2,17 +2,16 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 // Implementation for fib_mpls_label
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FibMplsLabel {
 	pub is_uniform: u8,
 	pub label: u32,
@@ -20,7 +19,7 @@ pub struct FibMplsLabel {
 	pub exp: u8,
 }
 // Implementation for fib_path_nh
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FibPathNh {
 	pub address: AddressUnion,
 	pub via_label: u32,
@@ -28,7 +27,7 @@ pub struct FibPathNh {
 	pub classify_table_index: u32,
 }
 // Implementation for fib_path
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FibPath {
 	pub sw_if_index: u32,
 	pub table_id: u32,
@@ -42,7 +41,7 @@ pub struct FibPath {
 	pub n_labels: u8,
 	pub label_stack: FixedSizeArray<FibMplsLabel, typenum::U16>,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum FibPathNhProto {
 	 FIB_API_PATH_NH_PROTO_IP4=0,
@@ -50,38 +49,110 @@ pub enum FibPathNhProto {
 	 FIB_API_PATH_NH_PROTO_MPLS=2,
 	 FIB_API_PATH_NH_PROTO_ETHERNET=3,
 	 FIB_API_PATH_NH_PROTO_BIER=4,
+	 Unknown(u32),
 }
 impl Default for FibPathNhProto {
 	fn default() -> Self { FibPathNhProto::FIB_API_PATH_NH_PROTO_IP4 }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+impl AsU32 for FibPathNhProto {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FibPathNhProto::FIB_API_PATH_NH_PROTO_IP4 => 0,
+			 FibPathNhProto::FIB_API_PATH_NH_PROTO_IP6 => 1,
+			 FibPathNhProto::FIB_API_PATH_NH_PROTO_MPLS => 2,
+			 FibPathNhProto::FIB_API_PATH_NH_PROTO_ETHERNET => 3,
+			 FibPathNhProto::FIB_API_PATH_NH_PROTO_BIER => 4,
+			 FibPathNhProto::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(FibPathNhProto::FIB_API_PATH_NH_PROTO_IP4),
+			 1 => Some(FibPathNhProto::FIB_API_PATH_NH_PROTO_IP6),
+			 2 => Some(FibPathNhProto::FIB_API_PATH_NH_PROTO_MPLS),
+			 3 => Some(FibPathNhProto::FIB_API_PATH_NH_PROTO_ETHERNET),
+			 4 => Some(FibPathNhProto::FIB_API_PATH_NH_PROTO_BIER),
+			 value => Some(FibPathNhProto::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for FibPathNhProto {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FibPathNhProto {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FibPathNhProto::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum FibPathFlags {
 	 FIB_API_PATH_FLAG_NONE=0,
 	 FIB_API_PATH_FLAG_RESOLVE_VIA_ATTACHED=1,
 	 FIB_API_PATH_FLAG_RESOLVE_VIA_HOST=2,
 	 FIB_API_PATH_FLAG_POP_PW_CW=4,
+	 Unknown(u32),
 }
 impl Default for FibPathFlags {
 	fn default() -> Self { FibPathFlags::FIB_API_PATH_FLAG_NONE }
 }
-impl AsEnumFlag for FibPathFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for FibPathFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FibPathFlags::FIB_API_PATH_FLAG_NONE => 0,
+			 FibPathFlags::FIB_API_PATH_FLAG_RESOLVE_VIA_ATTACHED => 1,
+			 FibPathFlags::FIB_API_PATH_FLAG_RESOLVE_VIA_HOST => 2,
+			 FibPathFlags::FIB_API_PATH_FLAG_POP_PW_CW => 4,
+			 FibPathFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 0 => FibPathFlags::FIB_API_PATH_FLAG_NONE,
-			 1 => FibPathFlags::FIB_API_PATH_FLAG_RESOLVE_VIA_ATTACHED,
-			 2 => FibPathFlags::FIB_API_PATH_FLAG_RESOLVE_VIA_HOST,
-			 4 => FibPathFlags::FIB_API_PATH_FLAG_POP_PW_CW,
-			_ => panic!("Invalid Enum Descriminant")
+			 0 => Some(FibPathFlags::FIB_API_PATH_FLAG_NONE),
+			 1 => Some(FibPathFlags::FIB_API_PATH_FLAG_RESOLVE_VIA_ATTACHED),
+			 2 => Some(FibPathFlags::FIB_API_PATH_FLAG_RESOLVE_VIA_HOST),
+			 4 => Some(FibPathFlags::FIB_API_PATH_FLAG_POP_PW_CW),
+			 value => Some(FibPathFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for FibPathFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FibPathFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FibPathFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for FibPathFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x7
+	}
+}
+impl std::ops::BitOr for FibPathFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum FibPathType {
 	 FIB_API_PATH_TYPE_NORMAL=0,
@@ -95,7 +166,57 @@ pub enum FibPathType {
 	 FIB_API_PATH_TYPE_DVR=8,
 	 FIB_API_PATH_TYPE_INTERFACE_RX=9,
 	 FIB_API_PATH_TYPE_CLASSIFY=10,
+	 Unknown(u32),
 }
 impl Default for FibPathType {
 	fn default() -> Self { FibPathType::FIB_API_PATH_TYPE_NORMAL }
 }
+impl AsU32 for FibPathType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FibPathType::FIB_API_PATH_TYPE_NORMAL => 0,
+			 FibPathType::FIB_API_PATH_TYPE_LOCAL => 1,
+			 FibPathType::FIB_API_PATH_TYPE_DROP => 2,
+			 FibPathType::FIB_API_PATH_TYPE_UDP_ENCAP => 3,
+			 FibPathType::FIB_API_PATH_TYPE_BIER_IMP => 4,
+			 FibPathType::FIB_API_PATH_TYPE_ICMP_UNREACH => 5,
+			 FibPathType::FIB_API_PATH_TYPE_ICMP_PROHIBIT => 6,
+			 FibPathType::FIB_API_PATH_TYPE_SOURCE_LOOKUP => 7,
+			 FibPathType::FIB_API_PATH_TYPE_DVR => 8,
+			 FibPathType::FIB_API_PATH_TYPE_INTERFACE_RX => 9,
+			 FibPathType::FIB_API_PATH_TYPE_CLASSIFY => 10,
+			 FibPathType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(FibPathType::FIB_API_PATH_TYPE_NORMAL),
+			 1 => Some(FibPathType::FIB_API_PATH_TYPE_LOCAL),
+			 2 => Some(FibPathType::FIB_API_PATH_TYPE_DROP),
+			 3 => Some(FibPathType::FIB_API_PATH_TYPE_UDP_ENCAP),
+			 4 => Some(FibPathType::FIB_API_PATH_TYPE_BIER_IMP),
+			 5 => Some(FibPathType::FIB_API_PATH_TYPE_ICMP_UNREACH),
+			 6 => Some(FibPathType::FIB_API_PATH_TYPE_ICMP_PROHIBIT),
+			 7 => Some(FibPathType::FIB_API_PATH_TYPE_SOURCE_LOOKUP),
+			 8 => Some(FibPathType::FIB_API_PATH_TYPE_DVR),
+			 9 => Some(FibPathType::FIB_API_PATH_TYPE_INTERFACE_RX),
+			 10 => Some(FibPathType::FIB_API_PATH_TYPE_CLASSIFY),
+			 value => Some(FibPathType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for FibPathType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FibPathType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FibPathType::Unknown(value)))
+	 }
+}
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
@@ -2,36 +2,35 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::ethernet_types::*;
 // Implementation for generic_pattern
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct GenericPattern {
 	pub spec: FixedSizeArray<u8, typenum::U1024>,
 	pub mask: FixedSizeArray<u8, typenum::U1024>,
 }
 // Implementation for ip_port_and_mask
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct IpPortAndMask {
 	pub port: u16,
 	pub mask: u16,
 }
 // Implementation for ip_prot_and_mask
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct IpProtAndMask {
 	pub prot: IpProto,
 	pub mask: u8,
 }
 // Implementation for flow_ethernet
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowEthernet {
 	pub foo: i32,
 	pub src_addr: MacAddress,
@@ -39,7 +38,7 @@ pub struct FlowEthernet {
 	pub typ: u16,
 }
 // Implementation for flow_ip4
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp4 {
 	pub foo: i32,
 	pub src_addr: Ip4AddressAndMask,
@@ -47,7 +46,7 @@ pub struct FlowIp4 {
 	pub protocol: IpProtAndMask,
 }
 // Implementation for flow_ip6
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp6 {
 	pub foo: i32,
 	pub src_addr: Ip6AddressAndMask,
@@ -55,7 +54,7 @@ pub struct FlowIp6 {
 	pub protocol: IpProtAndMask,
 }
 // Implementation for flow_ip4_n_tuple
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp4NTuple {
 	pub foo: i32,
 	pub src_addr: Ip4AddressAndMask,
@@ -65,7 +64,7 @@ pub struct FlowIp4NTuple {
 	pub dst_port: IpPortAndMask,
 }
 // Implementation for flow_ip6_n_tuple
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp6NTuple {
 	pub foo: i32,
 	pub src_addr: Ip6AddressAndMask,
@@ -75,7 +74,7 @@ pub struct FlowIp6NTuple {
 	pub dst_port: IpPortAndMask,
 }
 // Implementation for flow_ip4_n_tuple_tagged
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp4NTupleTagged {
 	pub foo: i32,
 	pub src_addr: Ip4AddressAndMask,
@@ -85,7 +84,7 @@ pub struct FlowIp4NTupleTagged {
 	pub dst_port: IpPortAndMask,
 }
 // Implementation for flow_ip6_n_tuple_tagged
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp6NTupleTagged {
 	pub foo: i32,
 	pub src_addr: Ip6AddressAndMask,
@@ -95,7 +94,7 @@ pub struct FlowIp6NTupleTagged {
 	pub dst_port: IpPortAndMask,
 }
 // Implementation for flow_ip4_l2tpv3oip
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp4L2tpv3oip {
 	pub foo: i32,
 	pub src_addr: Ip4AddressAndMask,
@@ -104,7 +103,7 @@ pub struct FlowIp4L2tpv3oip {
 	pub session_id: u32,
 }
 // Implementation for flow_ip4_ipsec_esp
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp4IpsecEsp {
 	pub foo: i32,
 	pub src_addr: Ip4AddressAndMask,
@@ -113,7 +112,7 @@ pub struct FlowIp4IpsecEsp {
 	pub spi: u32,
 }
 // Implementation for flow_ip4_ipsec_ah
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp4IpsecAh {
 	pub foo: i32,
 	pub src_addr: Ip4AddressAndMask,
@@ -122,7 +121,7 @@ pub struct FlowIp4IpsecAh {
 	pub spi: u32,
 }
 // Implementation for flow_ip4_vxlan
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp4Vxlan {
 	pub foo: i32,
 	pub src_addr: Ip4AddressAndMask,
@@ -133,7 +132,7 @@ pub struct FlowIp4Vxlan {
 	pub vni: u32,
 }
 // Implementation for flow_ip6_vxlan
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp6Vxlan {
 	pub foo: i32,
 	pub src_addr: Ip6AddressAndMask,
@@ -144,7 +143,7 @@ pub struct FlowIp6Vxlan {
 	pub vni: u32,
 }
 // Implementation for flow_ip4_gtpc
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp4Gtpc {
 	pub foo: i32,
 	pub src_addr: Ip4AddressAndMask,
@@ -155,7 +154,7 @@ pub struct FlowIp4Gtpc {
 	pub teid: u32,
 }
 // Implementation for flow_ip4_gtpu
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowIp4Gtpu {
 	pub foo: i32,
 	pub src_addr: Ip4AddressAndMask,
@@ -166,13 +165,13 @@ pub struct FlowIp4Gtpu {
 	pub teid: u32,
 }
 // Implementation for flow_generic
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowGeneric {
 	pub foo: i32,
 	pub pattern: GenericPattern,
 }
 // Implementation for flow_rule
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowRule {
 	pub typ: FlowType,
 	pub index: u32,
@@ -185,7 +184,7 @@ pub struct FlowRule {
 	pub flow: Flow,
 }
 // Implementation for flow_rule_v2
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct FlowRuleV2 {
 	pub typ: FlowTypeV2,
 	pub index: u32,
@@ -201,7 +200,7 @@ pub struct FlowRuleV2 {
 	pub rss_fun: RssFunction,
 	pub flow: FlowV2,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, Default, VppUnionIdent)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default, VppUnionIdent)]
 #[types(FlowEthernet:18)]
 #[types(FlowIp4:22)]
 #[types(FlowIp6:70)]
@@ -217,7 +216,7 @@ pub struct FlowRuleV2 {
 #[types(FlowIp4Gtpc:34)]
 #[types(FlowIp4Gtpu:34)]
 pub struct Flow(FixedSizeArray<u8, typenum::U82>);
-#[derive(Debug, Clone, Serialize, Deserialize, Default, VppUnionIdent)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default, VppUnionIdent)]
 #[types(FlowEthernet:18)]
 #[types(FlowIp4:22)]
 #[types(FlowIp6:70)]
@@ -234,7 +233,7 @@ pub struct Flow(FixedSizeArray<u8, typenum::U82>);
 #[types(FlowIp4Gtpu:34)]
 #[types(FlowGeneric:6)]
 pub struct FlowV2(FixedSizeArray<u8, typenum::U82>);
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum FlowType {
 	 FLOW_TYPE_ETHERNET=1,
@@ -251,11 +250,64 @@ pub enum FlowType {
 	 FLOW_TYPE_IP6_VXLAN=12,
 	 FLOW_TYPE_IP4_GTPC=13,
 	 FLOW_TYPE_IP4_GTPU=14,
+	 Unknown(u32),
 }
 impl Default for FlowType {
 	fn default() -> Self { FlowType::FLOW_TYPE_ETHERNET }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for FlowType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FlowType::FLOW_TYPE_ETHERNET => 1,
+			 FlowType::FLOW_TYPE_IP4 => 2,
+			 FlowType::FLOW_TYPE_IP6 => 3,
+			 FlowType::FLOW_TYPE_IP4_L2TPV3OIP => 4,
+			 FlowType::FLOW_TYPE_IP4_IPSEC_ESP => 5,
+			 FlowType::FLOW_TYPE_IP4_IPSEC_AH => 6,
+			 FlowType::FLOW_TYPE_IP4_N_TUPLE => 7,
+			 FlowType::FLOW_TYPE_IP6_N_TUPLE => 8,
+			 FlowType::FLOW_TYPE_IP4_N_TUPLE_TAGGED => 9,
+			 FlowType::FLOW_TYPE_IP6_N_TUPLE_TAGGED => 10,
+			 FlowType::FLOW_TYPE_IP4_VXLAN => 11,
+			 FlowType::FLOW_TYPE_IP6_VXLAN => 12,
+			 FlowType::FLOW_TYPE_IP4_GTPC => 13,
+			 FlowType::FLOW_TYPE_IP4_GTPU => 14,
+			 FlowType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 1 => Some(FlowType::FLOW_TYPE_ETHERNET),
+			 2 => Some(FlowType::FLOW_TYPE_IP4),
+			 3 => Some(FlowType::FLOW_TYPE_IP6),
+			 4 => Some(FlowType::FLOW_TYPE_IP4_L2TPV3OIP),
+			 5 => Some(FlowType::FLOW_TYPE_IP4_IPSEC_ESP),
+			 6 => Some(FlowType::FLOW_TYPE_IP4_IPSEC_AH),
+			 7 => Some(FlowType::FLOW_TYPE_IP4_N_TUPLE),
+			 8 => Some(FlowType::FLOW_TYPE_IP6_N_TUPLE),
+			 9 => Some(FlowType::FLOW_TYPE_IP4_N_TUPLE_TAGGED),
+			 10 => Some(FlowType::FLOW_TYPE_IP6_N_TUPLE_TAGGED),
+			 11 => Some(FlowType::FLOW_TYPE_IP4_VXLAN),
+			 12 => Some(FlowType::FLOW_TYPE_IP6_VXLAN),
+			 13 => Some(FlowType::FLOW_TYPE_IP4_GTPC),
+			 14 => Some(FlowType::FLOW_TYPE_IP4_GTPU),
+			 value => Some(FlowType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for FlowType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FlowType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FlowType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum FlowTypeV2 {
 	 FLOW_TYPE_ETHERNET_V2=1,
@@ -273,11 +325,67 @@ pub enum FlowTypeV2 {
 	 FLOW_TYPE_IP4_GTPC_V2=13,
 	 FLOW_TYPE_IP4_GTPU_V2=14,
 	 FLOW_TYPE_GENERIC_V2=15,
+	 Unknown(u32),
 }
 impl Default for FlowTypeV2 {
 	fn default() -> Self { FlowTypeV2::FLOW_TYPE_ETHERNET_V2 }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+impl AsU32 for FlowTypeV2 {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FlowTypeV2::FLOW_TYPE_ETHERNET_V2 => 1,
+			 FlowTypeV2::FLOW_TYPE_IP4_V2 => 2,
+			 FlowTypeV2::FLOW_TYPE_IP6_V2 => 3,
+			 FlowTypeV2::FLOW_TYPE_IP4_L2TPV3OIP_V2 => 4,
+			 FlowTypeV2::FLOW_TYPE_IP4_IPSEC_ESP_V2 => 5,
+			 FlowTypeV2::FLOW_TYPE_IP4_IPSEC_AH_V2 => 6,
+			 FlowTypeV2::FLOW_TYPE_IP4_N_TUPLE_V2 => 7,
+			 FlowTypeV2::FLOW_TYPE_IP6_N_TUPLE_V2 => 8,
+			 FlowTypeV2::FLOW_TYPE_IP4_N_TUPLE_TAGGED_V2 => 9,
+			 FlowTypeV2::FLOW_TYPE_IP6_N_TUPLE_TAGGED_V2 => 10,
+			 FlowTypeV2::FLOW_TYPE_IP4_VXLAN_V2 => 11,
+			 FlowTypeV2::FLOW_TYPE_IP6_VXLAN_V2 => 12,
+			 FlowTypeV2::FLOW_TYPE_IP4_GTPC_V2 => 13,
+			 FlowTypeV2::FLOW_TYPE_IP4_GTPU_V2 => 14,
+			 FlowTypeV2::FLOW_TYPE_GENERIC_V2 => 15,
+			 FlowTypeV2::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 1 => Some(FlowTypeV2::FLOW_TYPE_ETHERNET_V2),
+			 2 => Some(FlowTypeV2::FLOW_TYPE_IP4_V2),
+			 3 => Some(FlowTypeV2::FLOW_TYPE_IP6_V2),
+			 4 => Some(FlowTypeV2::FLOW_TYPE_IP4_L2TPV3OIP_V2),
+			 5 => Some(FlowTypeV2::FLOW_TYPE_IP4_IPSEC_ESP_V2),
+			 6 => Some(FlowTypeV2::FLOW_TYPE_IP4_IPSEC_AH_V2),
+			 7 => Some(FlowTypeV2::FLOW_TYPE_IP4_N_TUPLE_V2),
+			 8 => Some(FlowTypeV2::FLOW_TYPE_IP6_N_TUPLE_V2),
+			 9 => Some(FlowTypeV2::FLOW_TYPE_IP4_N_TUPLE_TAGGED_V2),
+			 10 => Some(FlowTypeV2::FLOW_TYPE_IP6_N_TUPLE_TAGGED_V2),
+			 11 => Some(FlowTypeV2::FLOW_TYPE_IP4_VXLAN_V2),
+			 12 => Some(FlowTypeV2::FLOW_TYPE_IP6_VXLAN_V2),
+			 13 => Some(FlowTypeV2::FLOW_TYPE_IP4_GTPC_V2),
+			 14 => Some(FlowTypeV2::FLOW_TYPE_IP4_GTPU_V2),
+			 15 => Some(FlowTypeV2::FLOW_TYPE_GENERIC_V2),
+			 value => Some(FlowTypeV2::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for FlowTypeV2 {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FlowTypeV2 {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FlowTypeV2::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum FlowAction {
 	 FLOW_ACTION_COUNT=1,
 	 FLOW_ACTION_MARK=2,
@@ -285,30 +393,69 @@ pub enum FlowAction {
 	 FLOW_ACTION_REDIRECT_TO_NODE=8,
 	 FLOW_ACTION_REDIRECT_TO_QUEUE=16,
 	 FLOW_ACTION_DROP=64,
+	 Unknown(u32),
 }
 impl Default for FlowAction {
 	fn default() -> Self { FlowAction::FLOW_ACTION_COUNT }
 }
-impl AsEnumFlag for FlowAction {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for FlowAction {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FlowAction::FLOW_ACTION_COUNT => 1,
+			 FlowAction::FLOW_ACTION_MARK => 2,
+			 FlowAction::FLOW_ACTION_BUFFER_ADVANCE => 4,
+			 FlowAction::FLOW_ACTION_REDIRECT_TO_NODE => 8,
+			 FlowAction::FLOW_ACTION_REDIRECT_TO_QUEUE => 16,
+			 FlowAction::FLOW_ACTION_DROP => 64,
+			 FlowAction::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => FlowAction::FLOW_ACTION_COUNT,
-			 2 => FlowAction::FLOW_ACTION_MARK,
-			 4 => FlowAction::FLOW_ACTION_BUFFER_ADVANCE,
-			 8 => FlowAction::FLOW_ACTION_REDIRECT_TO_NODE,
-			 16 => FlowAction::FLOW_ACTION_REDIRECT_TO_QUEUE,
-			 64 => FlowAction::FLOW_ACTION_DROP,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(FlowAction::FLOW_ACTION_COUNT),
+			 2 => Some(FlowAction::FLOW_ACTION_MARK),
+			 4 => Some(FlowAction::FLOW_ACTION_BUFFER_ADVANCE),
+			 8 => Some(FlowAction::FLOW_ACTION_REDIRECT_TO_NODE),
+			 16 => Some(FlowAction::FLOW_ACTION_REDIRECT_TO_QUEUE),
+			 64 => Some(FlowAction::FLOW_ACTION_DROP),
+			 value => Some(FlowAction::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for FlowAction {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FlowAction {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FlowAction::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for FlowAction {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x5f
+	}
+}
+impl std::ops::BitOr for FlowAction {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum FlowActionV2 {
 	 FLOW_ACTION_COUNT_V2=1,
 	 FLOW_ACTION_MARK_V2=2,
@@ -317,38 +464,113 @@ pub enum FlowActionV2 {
 	 FLOW_ACTION_REDIRECT_TO_QUEUE_V2=16,
 	 FLOW_ACTION_RSS_V2=32,
 	 FLOW_ACTION_DROP_V2=64,
+	 Unknown(u32),
 }
 impl Default for FlowActionV2 {
 	fn default() -> Self { FlowActionV2::FLOW_ACTION_COUNT_V2 }
 }
-impl AsEnumFlag for FlowActionV2 {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for FlowActionV2 {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 FlowActionV2::FLOW_ACTION_COUNT_V2 => 1,
+			 FlowActionV2::FLOW_ACTION_MARK_V2 => 2,
+			 FlowActionV2::FLOW_ACTION_BUFFER_ADVANCE_V2 => 4,
+			 FlowActionV2::FLOW_ACTION_REDIRECT_TO_NODE_V2 => 8,
+			 FlowActionV2::FLOW_ACTION_REDIRECT_TO_QUEUE_V2 => 16,
+			 FlowActionV2::FLOW_ACTION_RSS_V2 => 32,
+			 FlowActionV2::FLOW_ACTION_DROP_V2 => 64,
+			 FlowActionV2::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => FlowActionV2::FLOW_ACTION_COUNT_V2,
-			 2 => FlowActionV2::FLOW_ACTION_MARK_V2,
-			 4 => FlowActionV2::FLOW_ACTION_BUFFER_ADVANCE_V2,
-			 8 => FlowActionV2::FLOW_ACTION_REDIRECT_TO_NODE_V2,
-			 16 => FlowActionV2::FLOW_ACTION_REDIRECT_TO_QUEUE_V2,
-			 32 => FlowActionV2::FLOW_ACTION_RSS_V2,
-			 64 => FlowActionV2::FLOW_ACTION_DROP_V2,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(FlowActionV2::FLOW_ACTION_COUNT_V2),
+			 2 => Some(FlowActionV2::FLOW_ACTION_MARK_V2),
+			 4 => Some(FlowActionV2::FLOW_ACTION_BUFFER_ADVANCE_V2),
+			 8 => Some(FlowActionV2::FLOW_ACTION_REDIRECT_TO_NODE_V2),
+			 16 => Some(FlowActionV2::FLOW_ACTION_REDIRECT_TO_QUEUE_V2),
+			 32 => Some(FlowActionV2::FLOW_ACTION_RSS_V2),
+			 64 => Some(FlowActionV2::FLOW_ACTION_DROP_V2),
+			 value => Some(FlowActionV2::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for FlowActionV2 {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for FlowActionV2 {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(FlowActionV2::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for FlowActionV2 {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x7f
+	}
+}
+impl std::ops::BitOr for FlowActionV2 {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum RssFunction {
 	 RSS_FUNC_DEFAULT=0,
 	 RSS_FUNC_TOEPLITZ=1,
 	 RSS_FUNC_SIMPLE_XOR=2,
 	 RSS_FUNC_SYMMETRIC_TOEPLITZ=3,
+	 Unknown(u32),
 }
 impl Default for RssFunction {
 	fn default() -> Self { RssFunction::RSS_FUNC_DEFAULT }
 }
+impl AsU32 for RssFunction {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 RssFunction::RSS_FUNC_DEFAULT => 0,
+			 RssFunction::RSS_FUNC_TOEPLITZ => 1,
+			 RssFunction::RSS_FUNC_SIMPLE_XOR => 2,
+			 RssFunction::RSS_FUNC_SYMMETRIC_TOEPLITZ => 3,
+			 RssFunction::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(RssFunction::RSS_FUNC_DEFAULT),
+			 1 => Some(RssFunction::RSS_FUNC_TOEPLITZ),
+			 2 => Some(RssFunction::RSS_FUNC_SIMPLE_XOR),
+			 3 => Some(RssFunction::RSS_FUNC_SYMMETRIC_TOEPLITZ),
+			 value => Some(RssFunction::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for RssFunction {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for RssFunction {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(RssFunction::Unknown(value)))
+	 }
+}
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
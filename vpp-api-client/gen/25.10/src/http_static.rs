@@ -2,15 +2,14 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(http_static_enable_v4_37540bfc)]
 pub struct HttpStaticEnableV4 {
 	pub client_index: u32,
@@ -25,13 +24,13 @@ pub struct HttpStaticEnableV4 {
 	pub www_root: FixedSizeString<typenum::U256>,
 	pub uri: FixedSizeString<typenum::U256>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(http_static_enable_v4_reply_e8d4e804)]
 pub struct HttpStaticEnableV4Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(http_static_enable_v5_8bf84069)]
 pub struct HttpStaticEnableV5 {
 	pub client_index: u32,
@@ -47,9 +46,16 @@ pub struct HttpStaticEnableV5 {
 	pub www_root: FixedSizeString<typenum::U256>,
 	pub uri: FixedSizeString<typenum::U256>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(http_static_enable_v5_reply_e8d4e804)]
 pub struct HttpStaticEnableV5Reply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"http_static_enable_v4_37540bfc",
+	"http_static_enable_v4_reply_e8d4e804",
+	"http_static_enable_v5_8bf84069",
+	"http_static_enable_v5_reply_e8d4e804",
+];
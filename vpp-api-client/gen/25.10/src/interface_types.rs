@@ -2,59 +2,158 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum IfStatusFlags {
 	 IF_STATUS_API_FLAG_ADMIN_UP=1,
 	 IF_STATUS_API_FLAG_LINK_UP=2,
+	 Unknown(u32),
 }
 impl Default for IfStatusFlags {
 	fn default() -> Self { IfStatusFlags::IF_STATUS_API_FLAG_ADMIN_UP }
 }
-impl AsEnumFlag for IfStatusFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for IfStatusFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IfStatusFlags::IF_STATUS_API_FLAG_ADMIN_UP => 1,
+			 IfStatusFlags::IF_STATUS_API_FLAG_LINK_UP => 2,
+			 IfStatusFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => IfStatusFlags::IF_STATUS_API_FLAG_ADMIN_UP,
-			 2 => IfStatusFlags::IF_STATUS_API_FLAG_LINK_UP,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(IfStatusFlags::IF_STATUS_API_FLAG_ADMIN_UP),
+			 2 => Some(IfStatusFlags::IF_STATUS_API_FLAG_LINK_UP),
+			 value => Some(IfStatusFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for IfStatusFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IfStatusFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IfStatusFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for IfStatusFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x3
+	}
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl std::ops::BitOr for IfStatusFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum MtuProto {
 	 MTU_PROTO_API_L3=0,
 	 MTU_PROTO_API_IP4=1,
 	 MTU_PROTO_API_IP6=2,
 	 MTU_PROTO_API_MPLS=3,
+	 Unknown(u32),
 }
 impl Default for MtuProto {
 	fn default() -> Self { MtuProto::MTU_PROTO_API_L3 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for MtuProto {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 MtuProto::MTU_PROTO_API_L3 => 0,
+			 MtuProto::MTU_PROTO_API_IP4 => 1,
+			 MtuProto::MTU_PROTO_API_IP6 => 2,
+			 MtuProto::MTU_PROTO_API_MPLS => 3,
+			 MtuProto::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(MtuProto::MTU_PROTO_API_L3),
+			 1 => Some(MtuProto::MTU_PROTO_API_IP4),
+			 2 => Some(MtuProto::MTU_PROTO_API_IP6),
+			 3 => Some(MtuProto::MTU_PROTO_API_MPLS),
+			 value => Some(MtuProto::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for MtuProto {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for MtuProto {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(MtuProto::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum LinkDuplex {
 	 LINK_DUPLEX_API_UNKNOWN=0,
 	 LINK_DUPLEX_API_HALF=1,
 	 LINK_DUPLEX_API_FULL=2,
+	 Unknown(u32),
 }
 impl Default for LinkDuplex {
 	fn default() -> Self { LinkDuplex::LINK_DUPLEX_API_UNKNOWN }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+impl AsU32 for LinkDuplex {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 LinkDuplex::LINK_DUPLEX_API_UNKNOWN => 0,
+			 LinkDuplex::LINK_DUPLEX_API_HALF => 1,
+			 LinkDuplex::LINK_DUPLEX_API_FULL => 2,
+			 LinkDuplex::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(LinkDuplex::LINK_DUPLEX_API_UNKNOWN),
+			 1 => Some(LinkDuplex::LINK_DUPLEX_API_HALF),
+			 2 => Some(LinkDuplex::LINK_DUPLEX_API_FULL),
+			 value => Some(LinkDuplex::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for LinkDuplex {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for LinkDuplex {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(LinkDuplex::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum SubIfFlags {
 	 SUB_IF_API_FLAG_NO_TAGS=1,
 	 SUB_IF_API_FLAG_ONE_TAG=2,
@@ -66,34 +165,76 @@ pub enum SubIfFlags {
 	 SUB_IF_API_FLAG_INNER_VLAN_ID_ANY=128,
 	 SUB_IF_API_FLAG_MASK_VNET=254,
 	 SUB_IF_API_FLAG_DOT1AH=256,
+	 Unknown(u32),
 }
 impl Default for SubIfFlags {
 	fn default() -> Self { SubIfFlags::SUB_IF_API_FLAG_NO_TAGS }
 }
-impl AsEnumFlag for SubIfFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for SubIfFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 SubIfFlags::SUB_IF_API_FLAG_NO_TAGS => 1,
+			 SubIfFlags::SUB_IF_API_FLAG_ONE_TAG => 2,
+			 SubIfFlags::SUB_IF_API_FLAG_TWO_TAGS => 4,
+			 SubIfFlags::SUB_IF_API_FLAG_DOT1AD => 8,
+			 SubIfFlags::SUB_IF_API_FLAG_EXACT_MATCH => 16,
+			 SubIfFlags::SUB_IF_API_FLAG_DEFAULT => 32,
+			 SubIfFlags::SUB_IF_API_FLAG_OUTER_VLAN_ID_ANY => 64,
+			 SubIfFlags::SUB_IF_API_FLAG_INNER_VLAN_ID_ANY => 128,
+			 SubIfFlags::SUB_IF_API_FLAG_MASK_VNET => 254,
+			 SubIfFlags::SUB_IF_API_FLAG_DOT1AH => 256,
+			 SubIfFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => SubIfFlags::SUB_IF_API_FLAG_NO_TAGS,
-			 2 => SubIfFlags::SUB_IF_API_FLAG_ONE_TAG,
-			 4 => SubIfFlags::SUB_IF_API_FLAG_TWO_TAGS,
-			 8 => SubIfFlags::SUB_IF_API_FLAG_DOT1AD,
-			 16 => SubIfFlags::SUB_IF_API_FLAG_EXACT_MATCH,
-			 32 => SubIfFlags::SUB_IF_API_FLAG_DEFAULT,
-			 64 => SubIfFlags::SUB_IF_API_FLAG_OUTER_VLAN_ID_ANY,
-			 128 => SubIfFlags::SUB_IF_API_FLAG_INNER_VLAN_ID_ANY,
-			 254 => SubIfFlags::SUB_IF_API_FLAG_MASK_VNET,
-			 256 => SubIfFlags::SUB_IF_API_FLAG_DOT1AH,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(SubIfFlags::SUB_IF_API_FLAG_NO_TAGS),
+			 2 => Some(SubIfFlags::SUB_IF_API_FLAG_ONE_TAG),
+			 4 => Some(SubIfFlags::SUB_IF_API_FLAG_TWO_TAGS),
+			 8 => Some(SubIfFlags::SUB_IF_API_FLAG_DOT1AD),
+			 16 => Some(SubIfFlags::SUB_IF_API_FLAG_EXACT_MATCH),
+			 32 => Some(SubIfFlags::SUB_IF_API_FLAG_DEFAULT),
+			 64 => Some(SubIfFlags::SUB_IF_API_FLAG_OUTER_VLAN_ID_ANY),
+			 128 => Some(SubIfFlags::SUB_IF_API_FLAG_INNER_VLAN_ID_ANY),
+			 254 => Some(SubIfFlags::SUB_IF_API_FLAG_MASK_VNET),
+			 256 => Some(SubIfFlags::SUB_IF_API_FLAG_DOT1AH),
+			 value => Some(SubIfFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for SubIfFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for SubIfFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(SubIfFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for SubIfFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x1ff
+	}
+}
+impl std::ops::BitOr for SubIfFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum RxMode {
 	 RX_MODE_API_UNKNOWN=0,
@@ -101,28 +242,129 @@ pub enum RxMode {
 	 RX_MODE_API_INTERRUPT=2,
 	 RX_MODE_API_ADAPTIVE=3,
 	 RX_MODE_API_DEFAULT=4,
+	 Unknown(u32),
 }
 impl Default for RxMode {
 	fn default() -> Self { RxMode::RX_MODE_API_UNKNOWN }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for RxMode {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 RxMode::RX_MODE_API_UNKNOWN => 0,
+			 RxMode::RX_MODE_API_POLLING => 1,
+			 RxMode::RX_MODE_API_INTERRUPT => 2,
+			 RxMode::RX_MODE_API_ADAPTIVE => 3,
+			 RxMode::RX_MODE_API_DEFAULT => 4,
+			 RxMode::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(RxMode::RX_MODE_API_UNKNOWN),
+			 1 => Some(RxMode::RX_MODE_API_POLLING),
+			 2 => Some(RxMode::RX_MODE_API_INTERRUPT),
+			 3 => Some(RxMode::RX_MODE_API_ADAPTIVE),
+			 4 => Some(RxMode::RX_MODE_API_DEFAULT),
+			 value => Some(RxMode::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for RxMode {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for RxMode {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(RxMode::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum IfType {
 	 IF_API_TYPE_HARDWARE=0,
 	 IF_API_TYPE_SUB=1,
 	 IF_API_TYPE_P2P=2,
 	 IF_API_TYPE_PIPE=3,
+	 Unknown(u32),
 }
 impl Default for IfType {
 	fn default() -> Self { IfType::IF_API_TYPE_HARDWARE }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for IfType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IfType::IF_API_TYPE_HARDWARE => 0,
+			 IfType::IF_API_TYPE_SUB => 1,
+			 IfType::IF_API_TYPE_P2P => 2,
+			 IfType::IF_API_TYPE_PIPE => 3,
+			 IfType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(IfType::IF_API_TYPE_HARDWARE),
+			 1 => Some(IfType::IF_API_TYPE_SUB),
+			 2 => Some(IfType::IF_API_TYPE_P2P),
+			 3 => Some(IfType::IF_API_TYPE_PIPE),
+			 value => Some(IfType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for IfType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IfType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IfType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum Direction {
 	 RX=0,
 	 TX=1,
+	 Unknown(u32),
 }
 impl Default for Direction {
 	fn default() -> Self { Direction::RX }
 }
+impl AsU32 for Direction {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 Direction::RX => 0,
+			 Direction::TX => 1,
+			 Direction::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(Direction::RX),
+			 1 => Some(Direction::TX),
+			 value => Some(Direction::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for Direction {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("Direction value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for Direction {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(Direction::Unknown(value)))
+	 }
+}
 pub type InterfaceIndex=u32;
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
@@ -2,16 +2,15 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_enable_2481bef7)]
 pub struct VxlanGpeIoamEnable {
 	pub client_index: u32,
@@ -21,26 +20,26 @@ pub struct VxlanGpeIoamEnable {
 	pub pow_enable: bool,
 	pub trace_enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_enable_reply_e8d4e804)]
 pub struct VxlanGpeIoamEnableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_disable_6b16a45e)]
 pub struct VxlanGpeIoamDisable {
 	pub client_index: u32,
 	pub context: u32,
 	pub id: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_disable_reply_e8d4e804)]
 pub struct VxlanGpeIoamDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_vni_enable_0fbb5fb1)]
 pub struct VxlanGpeIoamVniEnable {
 	pub client_index: u32,
@@ -49,13 +48,13 @@ pub struct VxlanGpeIoamVniEnable {
 	pub local: Address,
 	pub remote: Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_vni_enable_reply_e8d4e804)]
 pub struct VxlanGpeIoamVniEnableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_vni_disable_0fbb5fb1)]
 pub struct VxlanGpeIoamVniDisable {
 	pub client_index: u32,
@@ -64,13 +63,13 @@ pub struct VxlanGpeIoamVniDisable {
 	pub local: Address,
 	pub remote: Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_vni_disable_reply_e8d4e804)]
 pub struct VxlanGpeIoamVniDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_transit_enable_3d3ec657)]
 pub struct VxlanGpeIoamTransitEnable {
 	pub client_index: u32,
@@ -78,13 +77,13 @@ pub struct VxlanGpeIoamTransitEnable {
 	pub outer_fib_index: u32,
 	pub dst_addr: Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_transit_enable_reply_e8d4e804)]
 pub struct VxlanGpeIoamTransitEnableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_transit_disable_3d3ec657)]
 pub struct VxlanGpeIoamTransitDisable {
 	pub client_index: u32,
@@ -92,9 +91,24 @@ pub struct VxlanGpeIoamTransitDisable {
 	pub outer_fib_index: u32,
 	pub dst_addr: Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vxlan_gpe_ioam_transit_disable_reply_e8d4e804)]
 pub struct VxlanGpeIoamTransitDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"vxlan_gpe_ioam_enable_2481bef7",
+	"vxlan_gpe_ioam_enable_reply_e8d4e804",
+	"vxlan_gpe_ioam_disable_6b16a45e",
+	"vxlan_gpe_ioam_disable_reply_e8d4e804",
+	"vxlan_gpe_ioam_vni_enable_0fbb5fb1",
+	"vxlan_gpe_ioam_vni_enable_reply_e8d4e804",
+	"vxlan_gpe_ioam_vni_disable_0fbb5fb1",
+	"vxlan_gpe_ioam_vni_disable_reply_e8d4e804",
+	"vxlan_gpe_ioam_transit_enable_3d3ec657",
+	"vxlan_gpe_ioam_transit_enable_reply_e8d4e804",
+	"vxlan_gpe_ioam_transit_disable_3d3ec657",
+	"vxlan_gpe_ioam_transit_disable_reply_e8d4e804",
+];
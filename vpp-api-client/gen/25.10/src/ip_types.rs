@@ -2,40 +2,39 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 // Implementation for address
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Address {
 	pub af: AddressFamily,
 	pub un: AddressUnion,
 }
 // Implementation for prefix
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Prefix {
 	pub address: Address,
 	pub len: u8,
 }
 // Implementation for ip4_address_and_mask
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ip4AddressAndMask {
 	pub addr: Ip4Address,
 	pub mask: Ip4Address,
 }
 // Implementation for ip6_address_and_mask
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ip6AddressAndMask {
 	pub addr: Ip6Address,
 	pub mask: Ip6Address,
 }
 // Implementation for mprefix
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Mprefix {
 	pub af: AddressFamily,
 	pub grp_address_length: u16,
@@ -43,37 +42,67 @@ pub struct Mprefix {
 	pub src_address: AddressUnion,
 }
 // Implementation for ip6_prefix
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ip6Prefix {
 	pub address: Ip6Address,
 	pub len: u8,
 }
 // Implementation for ip4_prefix
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Ip4Prefix {
 	pub address: Ip4Address,
 	pub len: u8,
 }
 // Implementation for prefix_matcher
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct PrefixMatcher {
 	pub le: u8,
 	pub ge: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, Default, VppUnionIdent)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default, VppUnionIdent)]
 #[types(Ip4Address:4)]
 #[types(Ip6Address:16)]
 pub struct AddressUnion(FixedSizeArray<u8, typenum::U16>);
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum AddressFamily {
 	 ADDRESS_IP4=0,
 	 ADDRESS_IP6=1,
+	 Unknown(u32),
 }
 impl Default for AddressFamily {
 	fn default() -> Self { AddressFamily::ADDRESS_IP4 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for AddressFamily {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 AddressFamily::ADDRESS_IP4 => 0,
+			 AddressFamily::ADDRESS_IP6 => 1,
+			 AddressFamily::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(AddressFamily::ADDRESS_IP4),
+			 1 => Some(AddressFamily::ADDRESS_IP6),
+			 value => Some(AddressFamily::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for AddressFamily {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("AddressFamily value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for AddressFamily {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(AddressFamily::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum IpFeatureLocation {
 	 IP_API_FEATURE_INPUT=0,
@@ -81,22 +110,92 @@ pub enum IpFeatureLocation {
 	 IP_API_FEATURE_LOCAL=2,
 	 IP_API_FEATURE_PUNT=3,
 	 IP_API_FEATURE_DROP=4,
+	 Unknown(u32),
 }
 impl Default for IpFeatureLocation {
 	fn default() -> Self { IpFeatureLocation::IP_API_FEATURE_INPUT }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for IpFeatureLocation {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IpFeatureLocation::IP_API_FEATURE_INPUT => 0,
+			 IpFeatureLocation::IP_API_FEATURE_OUTPUT => 1,
+			 IpFeatureLocation::IP_API_FEATURE_LOCAL => 2,
+			 IpFeatureLocation::IP_API_FEATURE_PUNT => 3,
+			 IpFeatureLocation::IP_API_FEATURE_DROP => 4,
+			 IpFeatureLocation::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(IpFeatureLocation::IP_API_FEATURE_INPUT),
+			 1 => Some(IpFeatureLocation::IP_API_FEATURE_OUTPUT),
+			 2 => Some(IpFeatureLocation::IP_API_FEATURE_LOCAL),
+			 3 => Some(IpFeatureLocation::IP_API_FEATURE_PUNT),
+			 4 => Some(IpFeatureLocation::IP_API_FEATURE_DROP),
+			 value => Some(IpFeatureLocation::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for IpFeatureLocation {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("IpFeatureLocation value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IpFeatureLocation {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IpFeatureLocation::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum IpEcn {
 	 IP_API_ECN_NONE=0,
 	 IP_API_ECN_ECT0=1,
 	 IP_API_ECN_ECT1=2,
 	 IP_API_ECN_CE=3,
+	 Unknown(u32),
 }
 impl Default for IpEcn {
 	fn default() -> Self { IpEcn::IP_API_ECN_NONE }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for IpEcn {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IpEcn::IP_API_ECN_NONE => 0,
+			 IpEcn::IP_API_ECN_ECT0 => 1,
+			 IpEcn::IP_API_ECN_ECT1 => 2,
+			 IpEcn::IP_API_ECN_CE => 3,
+			 IpEcn::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(IpEcn::IP_API_ECN_NONE),
+			 1 => Some(IpEcn::IP_API_ECN_ECT0),
+			 2 => Some(IpEcn::IP_API_ECN_ECT1),
+			 3 => Some(IpEcn::IP_API_ECN_CE),
+			 value => Some(IpEcn::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for IpEcn {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("IpEcn value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IpEcn {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IpEcn::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum IpDscp {
 	 IP_API_DSCP_CS0=0,
@@ -120,11 +219,79 @@ pub enum IpDscp {
 	 IP_API_DSCP_EF=46,
 	 IP_API_DSCP_CS6=48,
 	 IP_API_DSCP_CS7=50,
+	 Unknown(u32),
 }
 impl Default for IpDscp {
 	fn default() -> Self { IpDscp::IP_API_DSCP_CS0 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for IpDscp {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IpDscp::IP_API_DSCP_CS0 => 0,
+			 IpDscp::IP_API_DSCP_CS1 => 8,
+			 IpDscp::IP_API_DSCP_AF11 => 10,
+			 IpDscp::IP_API_DSCP_AF12 => 12,
+			 IpDscp::IP_API_DSCP_AF13 => 14,
+			 IpDscp::IP_API_DSCP_CS2 => 16,
+			 IpDscp::IP_API_DSCP_AF21 => 18,
+			 IpDscp::IP_API_DSCP_AF22 => 20,
+			 IpDscp::IP_API_DSCP_AF23 => 22,
+			 IpDscp::IP_API_DSCP_CS3 => 24,
+			 IpDscp::IP_API_DSCP_AF31 => 26,
+			 IpDscp::IP_API_DSCP_AF32 => 28,
+			 IpDscp::IP_API_DSCP_AF33 => 30,
+			 IpDscp::IP_API_DSCP_CS4 => 32,
+			 IpDscp::IP_API_DSCP_AF41 => 34,
+			 IpDscp::IP_API_DSCP_AF42 => 36,
+			 IpDscp::IP_API_DSCP_AF43 => 38,
+			 IpDscp::IP_API_DSCP_CS5 => 40,
+			 IpDscp::IP_API_DSCP_EF => 46,
+			 IpDscp::IP_API_DSCP_CS6 => 48,
+			 IpDscp::IP_API_DSCP_CS7 => 50,
+			 IpDscp::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(IpDscp::IP_API_DSCP_CS0),
+			 8 => Some(IpDscp::IP_API_DSCP_CS1),
+			 10 => Some(IpDscp::IP_API_DSCP_AF11),
+			 12 => Some(IpDscp::IP_API_DSCP_AF12),
+			 14 => Some(IpDscp::IP_API_DSCP_AF13),
+			 16 => Some(IpDscp::IP_API_DSCP_CS2),
+			 18 => Some(IpDscp::IP_API_DSCP_AF21),
+			 20 => Some(IpDscp::IP_API_DSCP_AF22),
+			 22 => Some(IpDscp::IP_API_DSCP_AF23),
+			 24 => Some(IpDscp::IP_API_DSCP_CS3),
+			 26 => Some(IpDscp::IP_API_DSCP_AF31),
+			 28 => Some(IpDscp::IP_API_DSCP_AF32),
+			 30 => Some(IpDscp::IP_API_DSCP_AF33),
+			 32 => Some(IpDscp::IP_API_DSCP_CS4),
+			 34 => Some(IpDscp::IP_API_DSCP_AF41),
+			 36 => Some(IpDscp::IP_API_DSCP_AF42),
+			 38 => Some(IpDscp::IP_API_DSCP_AF43),
+			 40 => Some(IpDscp::IP_API_DSCP_CS5),
+			 46 => Some(IpDscp::IP_API_DSCP_EF),
+			 48 => Some(IpDscp::IP_API_DSCP_CS6),
+			 50 => Some(IpDscp::IP_API_DSCP_CS7),
+			 value => Some(IpDscp::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for IpDscp {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("IpDscp value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IpDscp {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IpDscp::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum IpProto {
 	 IP_API_PROTO_HOPOPT=0,
@@ -140,12 +307,67 @@ pub enum IpProto {
 	 IP_API_PROTO_OSPF=89,
 	 IP_API_PROTO_SCTP=132,
 	 IP_API_PROTO_RESERVED=255,
+	 Unknown(u32)=3,
 }
 impl Default for IpProto {
 	fn default() -> Self { IpProto::IP_API_PROTO_HOPOPT }
 }
+impl AsU32 for IpProto {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 IpProto::IP_API_PROTO_HOPOPT => 0,
+			 IpProto::IP_API_PROTO_ICMP => 1,
+			 IpProto::IP_API_PROTO_IGMP => 2,
+			 IpProto::IP_API_PROTO_TCP => 6,
+			 IpProto::IP_API_PROTO_UDP => 17,
+			 IpProto::IP_API_PROTO_GRE => 47,
+			 IpProto::IP_API_PROTO_ESP => 50,
+			 IpProto::IP_API_PROTO_AH => 51,
+			 IpProto::IP_API_PROTO_ICMP6 => 58,
+			 IpProto::IP_API_PROTO_EIGRP => 88,
+			 IpProto::IP_API_PROTO_OSPF => 89,
+			 IpProto::IP_API_PROTO_SCTP => 132,
+			 IpProto::IP_API_PROTO_RESERVED => 255,
+			 IpProto::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(IpProto::IP_API_PROTO_HOPOPT),
+			 1 => Some(IpProto::IP_API_PROTO_ICMP),
+			 2 => Some(IpProto::IP_API_PROTO_IGMP),
+			 6 => Some(IpProto::IP_API_PROTO_TCP),
+			 17 => Some(IpProto::IP_API_PROTO_UDP),
+			 47 => Some(IpProto::IP_API_PROTO_GRE),
+			 50 => Some(IpProto::IP_API_PROTO_ESP),
+			 51 => Some(IpProto::IP_API_PROTO_AH),
+			 58 => Some(IpProto::IP_API_PROTO_ICMP6),
+			 88 => Some(IpProto::IP_API_PROTO_EIGRP),
+			 89 => Some(IpProto::IP_API_PROTO_OSPF),
+			 132 => Some(IpProto::IP_API_PROTO_SCTP),
+			 255 => Some(IpProto::IP_API_PROTO_RESERVED),
+			 value => Some(IpProto::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for IpProto {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("IpProto value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for IpProto {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(IpProto::Unknown(value)))
+	 }
+}
 pub type Ip4Address=[u8;4];
 pub type Ip6Address=[u8;16];
 pub type AddressWithPrefix=Prefix;
 pub type Ip4AddressWithPrefix=Ip4Prefix;
 pub type Ip6AddressWithPrefix=Ip6Prefix;
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
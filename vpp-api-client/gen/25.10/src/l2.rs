@@ -2,19 +2,18 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
 // Implementation for mac_entry
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct MacEntry {
 	pub sw_if_index: InterfaceIndex,
 	pub mac_addr: MacAddress,
@@ -22,30 +21,62 @@ pub struct MacEntry {
 	pub flags: u8,
 }
 // Implementation for bridge_domain_sw_if
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct BridgeDomainSwIf {
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 	pub shg: u8,
 }
 // Implementation for bd_ip_mac
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct BdIpMac {
 	pub bd_id: u32,
 	pub ip: Address,
 	pub mac: MacAddress,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum MacEventAction {
 	 MAC_EVENT_ACTION_API_ADD=0,
 	 MAC_EVENT_ACTION_API_DELETE=1,
 	 MAC_EVENT_ACTION_API_MOVE=2,
+	 Unknown(u32),
 }
 impl Default for MacEventAction {
 	fn default() -> Self { MacEventAction::MAC_EVENT_ACTION_API_ADD }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+impl AsU32 for MacEventAction {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 MacEventAction::MAC_EVENT_ACTION_API_ADD => 0,
+			 MacEventAction::MAC_EVENT_ACTION_API_DELETE => 1,
+			 MacEventAction::MAC_EVENT_ACTION_API_MOVE => 2,
+			 MacEventAction::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(MacEventAction::MAC_EVENT_ACTION_API_ADD),
+			 1 => Some(MacEventAction::MAC_EVENT_ACTION_API_DELETE),
+			 2 => Some(MacEventAction::MAC_EVENT_ACTION_API_MOVE),
+			 value => Some(MacEventAction::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for MacEventAction {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for MacEventAction {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(MacEventAction::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum BdFlags {
 	 BRIDGE_API_FLAG_NONE=0,
 	 BRIDGE_API_FLAG_LEARN=1,
@@ -54,54 +85,124 @@ pub enum BdFlags {
 	 BRIDGE_API_FLAG_UU_FLOOD=8,
 	 BRIDGE_API_FLAG_ARP_TERM=16,
 	 BRIDGE_API_FLAG_ARP_UFWD=32,
+	 Unknown(u32),
 }
 impl Default for BdFlags {
 	fn default() -> Self { BdFlags::BRIDGE_API_FLAG_NONE }
 }
-impl AsEnumFlag for BdFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for BdFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 BdFlags::BRIDGE_API_FLAG_NONE => 0,
+			 BdFlags::BRIDGE_API_FLAG_LEARN => 1,
+			 BdFlags::BRIDGE_API_FLAG_FWD => 2,
+			 BdFlags::BRIDGE_API_FLAG_FLOOD => 4,
+			 BdFlags::BRIDGE_API_FLAG_UU_FLOOD => 8,
+			 BdFlags::BRIDGE_API_FLAG_ARP_TERM => 16,
+			 BdFlags::BRIDGE_API_FLAG_ARP_UFWD => 32,
+			 BdFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 0 => BdFlags::BRIDGE_API_FLAG_NONE,
-			 1 => BdFlags::BRIDGE_API_FLAG_LEARN,
-			 2 => BdFlags::BRIDGE_API_FLAG_FWD,
-			 4 => BdFlags::BRIDGE_API_FLAG_FLOOD,
-			 8 => BdFlags::BRIDGE_API_FLAG_UU_FLOOD,
-			 16 => BdFlags::BRIDGE_API_FLAG_ARP_TERM,
-			 32 => BdFlags::BRIDGE_API_FLAG_ARP_UFWD,
-			_ => panic!("Invalid Enum Descriminant")
+			 0 => Some(BdFlags::BRIDGE_API_FLAG_NONE),
+			 1 => Some(BdFlags::BRIDGE_API_FLAG_LEARN),
+			 2 => Some(BdFlags::BRIDGE_API_FLAG_FWD),
+			 4 => Some(BdFlags::BRIDGE_API_FLAG_FLOOD),
+			 8 => Some(BdFlags::BRIDGE_API_FLAG_UU_FLOOD),
+			 16 => Some(BdFlags::BRIDGE_API_FLAG_ARP_TERM),
+			 32 => Some(BdFlags::BRIDGE_API_FLAG_ARP_UFWD),
+			 value => Some(BdFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for BdFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for BdFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(BdFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for BdFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x3f
+	}
+}
+impl std::ops::BitOr for BdFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum L2PortType {
 	 L2_API_PORT_TYPE_NORMAL=0,
 	 L2_API_PORT_TYPE_BVI=1,
 	 L2_API_PORT_TYPE_UU_FWD=2,
+	 Unknown(u32),
 }
 impl Default for L2PortType {
 	fn default() -> Self { L2PortType::L2_API_PORT_TYPE_NORMAL }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for L2PortType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 L2PortType::L2_API_PORT_TYPE_NORMAL => 0,
+			 L2PortType::L2_API_PORT_TYPE_BVI => 1,
+			 L2PortType::L2_API_PORT_TYPE_UU_FWD => 2,
+			 L2PortType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(L2PortType::L2_API_PORT_TYPE_NORMAL),
+			 1 => Some(L2PortType::L2_API_PORT_TYPE_BVI),
+			 2 => Some(L2PortType::L2_API_PORT_TYPE_UU_FWD),
+			 value => Some(L2PortType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for L2PortType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for L2PortType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(L2PortType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_xconnect_details_472b6b67)]
 pub struct L2XconnectDetails {
 	pub context: u32,
 	pub rx_sw_if_index: InterfaceIndex,
 	pub tx_sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_xconnect_dump_51077d14)]
 pub struct L2XconnectDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_fib_table_details_a44ef6b8)]
 pub struct L2FibTableDetails {
 	pub context: u32,
@@ -112,64 +213,64 @@ pub struct L2FibTableDetails {
 	pub filter_mac: bool,
 	pub bvi_mac: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_fib_table_dump_c25fdce6)]
 pub struct L2FibTableDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub bd_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_fib_clear_table_51077d14)]
 pub struct L2FibClearTable {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_fib_clear_table_reply_e8d4e804)]
 pub struct L2FibClearTableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2fib_flush_all_51077d14)]
 pub struct L2fibFlushAll {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2fib_flush_all_reply_e8d4e804)]
 pub struct L2fibFlushAllReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2fib_flush_bd_c25fdce6)]
 pub struct L2fibFlushBd {
 	pub client_index: u32,
 	pub context: u32,
 	pub bd_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2fib_flush_bd_reply_e8d4e804)]
 pub struct L2fibFlushBdReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2fib_flush_int_f9e6675e)]
 pub struct L2fibFlushInt {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2fib_flush_int_reply_e8d4e804)]
 pub struct L2fibFlushIntReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2fib_add_del_eddda487)]
 pub struct L2fibAddDel {
 	pub client_index: u32,
@@ -182,13 +283,13 @@ pub struct L2fibAddDel {
 	pub filter_mac: bool,
 	pub bvi_mac: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2fib_add_del_reply_e8d4e804)]
 pub struct L2fibAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_l2_macs_events_9aabdfde)]
 pub struct WantL2MacsEvents {
 	pub client_index: u32,
@@ -199,13 +300,13 @@ pub struct WantL2MacsEvents {
 	pub enable_disable: bool,
 	pub pid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_l2_macs_events_reply_e8d4e804)]
 pub struct WantL2MacsEventsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_l2_macs_events2_cc1377b0)]
 pub struct WantL2MacsEvents2 {
 	pub client_index: u32,
@@ -214,34 +315,35 @@ pub struct WantL2MacsEvents2 {
 	pub enable_disable: bool,
 	pub pid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_l2_macs_events2_reply_e8d4e804)]
 pub struct WantL2MacsEvents2Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2fib_set_scan_delay_a3b968a4)]
 pub struct L2fibSetScanDelay {
 	pub client_index: u32,
 	pub context: u32,
 	pub scan_delay: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2fib_set_scan_delay_reply_e8d4e804)]
 pub struct L2fibSetScanDelayReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_macs_event_44b8fd64)]
 pub struct L2MacsEvent {
 	pub client_index: u32,
 	pub pid: u32,
 	pub n_macs: u32,
+	#[vpp(count = "n_macs")]
 	pub mac: VariableSizeArray<MacEntry>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_flags_fc41cfe8)]
 pub struct L2Flags {
 	pub client_index: u32,
@@ -250,14 +352,14 @@ pub struct L2Flags {
 	pub is_set: bool,
 	pub feature_bitmap: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_flags_reply_29b2a2b3)]
 pub struct L2FlagsReply {
 	pub context: u32,
 	pub retval: i32,
 	pub resulting_feature_bitmap: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_domain_set_mac_age_b537ad7b)]
 pub struct BridgeDomainSetMacAge {
 	pub client_index: u32,
@@ -265,26 +367,26 @@ pub struct BridgeDomainSetMacAge {
 	pub bd_id: u32,
 	pub mac_age: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_domain_set_mac_age_reply_e8d4e804)]
 pub struct BridgeDomainSetMacAgeReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_domain_set_default_learn_limit_f097ffce)]
 pub struct BridgeDomainSetDefaultLearnLimit {
 	pub client_index: u32,
 	pub context: u32,
 	pub learn_limit: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_domain_set_default_learn_limit_reply_e8d4e804)]
 pub struct BridgeDomainSetDefaultLearnLimitReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_domain_set_learn_limit_89c52b5f)]
 pub struct BridgeDomainSetLearnLimit {
 	pub client_index: u32,
@@ -292,13 +394,13 @@ pub struct BridgeDomainSetLearnLimit {
 	pub bd_id: u32,
 	pub learn_limit: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_domain_set_learn_limit_reply_e8d4e804)]
 pub struct BridgeDomainSetLearnLimitReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_domain_add_del_600b7170)]
 pub struct BridgeDomainAddDel {
 	pub client_index: u32,
@@ -314,13 +416,13 @@ pub struct BridgeDomainAddDel {
 	pub bd_tag: FixedSizeString<typenum::U64>,
 	pub is_add: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_domain_add_del_reply_e8d4e804)]
 pub struct BridgeDomainAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_domain_add_del_v2_600b7170)]
 pub struct BridgeDomainAddDelV2 {
 	pub client_index: u32,
@@ -336,14 +438,14 @@ pub struct BridgeDomainAddDelV2 {
 	pub bd_tag: FixedSizeString<typenum::U64>,
 	pub is_add: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_domain_add_del_v2_reply_fcb1e980)]
 pub struct BridgeDomainAddDelV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub bd_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_domain_dump_74396a43)]
 pub struct BridgeDomainDump {
 	pub client_index: u32,
@@ -351,7 +453,7 @@ pub struct BridgeDomainDump {
 	pub bd_id: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_domain_details_0fa506fd)]
 pub struct BridgeDomainDetails {
 	pub context: u32,
@@ -367,9 +469,10 @@ pub struct BridgeDomainDetails {
 	pub bvi_sw_if_index: InterfaceIndex,
 	pub uu_fwd_sw_if_index: InterfaceIndex,
 	pub n_sw_ifs: u32,
+	#[vpp(count = "n_sw_ifs")]
 	pub sw_if_details: VariableSizeArray<BridgeDomainSwIf>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_flags_1b0c5fbd)]
 pub struct BridgeFlags {
 	pub client_index: u32,
@@ -378,14 +481,14 @@ pub struct BridgeFlags {
 	pub is_set: bool,
 	 pub flags: EnumFlag<BdFlags>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bridge_flags_reply_29b2a2b3)]
 pub struct BridgeFlagsReply {
 	pub context: u32,
 	pub retval: i32,
 	pub resulting_feature_bitmap: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_interface_vlan_tag_rewrite_62cc0bbc)]
 pub struct L2InterfaceVlanTagRewrite {
 	pub client_index: u32,
@@ -396,13 +499,13 @@ pub struct L2InterfaceVlanTagRewrite {
 	pub tag1: u32,
 	pub tag2: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_interface_vlan_tag_rewrite_reply_e8d4e804)]
 pub struct L2InterfaceVlanTagRewriteReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_interface_pbb_tag_rewrite_38e802a8)]
 pub struct L2InterfacePbbTagRewrite {
 	pub client_index: u32,
@@ -415,13 +518,13 @@ pub struct L2InterfacePbbTagRewrite {
 	pub b_vlanid: u16,
 	pub i_sid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_interface_pbb_tag_rewrite_reply_e8d4e804)]
 pub struct L2InterfacePbbTagRewriteReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_patch_add_del_a1f6a6f3)]
 pub struct L2PatchAddDel {
 	pub client_index: u32,
@@ -430,13 +533,13 @@ pub struct L2PatchAddDel {
 	pub tx_sw_if_index: InterfaceIndex,
 	pub is_add: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_patch_add_del_reply_e8d4e804)]
 pub struct L2PatchAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_l2_xconnect_4fa28a85)]
 pub struct SwInterfaceSetL2Xconnect {
 	pub client_index: u32,
@@ -445,13 +548,13 @@ pub struct SwInterfaceSetL2Xconnect {
 	pub tx_sw_if_index: InterfaceIndex,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_l2_xconnect_reply_e8d4e804)]
 pub struct SwInterfaceSetL2XconnectReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_l2_bridge_d0678b13)]
 pub struct SwInterfaceSetL2Bridge {
 	pub client_index: u32,
@@ -462,13 +565,13 @@ pub struct SwInterfaceSetL2Bridge {
 	pub shg: u8,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_l2_bridge_reply_e8d4e804)]
 pub struct SwInterfaceSetL2BridgeReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bd_ip_mac_add_del_0257c869)]
 pub struct BdIpMacAddDel {
 	pub client_index: u32,
@@ -476,39 +579,39 @@ pub struct BdIpMacAddDel {
 	pub is_add: bool,
 	pub entry: BdIpMac,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bd_ip_mac_add_del_reply_e8d4e804)]
 pub struct BdIpMacAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bd_ip_mac_flush_c25fdce6)]
 pub struct BdIpMacFlush {
 	pub client_index: u32,
 	pub context: u32,
 	pub bd_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bd_ip_mac_flush_reply_e8d4e804)]
 pub struct BdIpMacFlushReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bd_ip_mac_details_545af86a)]
 pub struct BdIpMacDetails {
 	pub context: u32,
 	pub entry: BdIpMac,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bd_ip_mac_dump_c25fdce6)]
 pub struct BdIpMacDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub bd_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_interface_efp_filter_5501adee)]
 pub struct L2InterfaceEfpFilter {
 	pub client_index: u32,
@@ -516,13 +619,13 @@ pub struct L2InterfaceEfpFilter {
 	pub sw_if_index: InterfaceIndex,
 	pub enable_disable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_interface_efp_filter_reply_e8d4e804)]
 pub struct L2InterfaceEfpFilterReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_vpath_ae6cfcfb)]
 pub struct SwInterfaceSetVpath {
 	pub client_index: u32,
@@ -530,13 +633,13 @@ pub struct SwInterfaceSetVpath {
 	pub sw_if_index: InterfaceIndex,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_vpath_reply_e8d4e804)]
 pub struct SwInterfaceSetVpathReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bvi_create_f5398559)]
 pub struct BviCreate {
 	pub client_index: u32,
@@ -544,27 +647,27 @@ pub struct BviCreate {
 	pub mac: MacAddress,
 	pub user_instance: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bvi_create_reply_5383d31f)]
 pub struct BviCreateReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bvi_delete_f9e6675e)]
 pub struct BviDelete {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(bvi_delete_reply_e8d4e804)]
 pub struct BviDeleteReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_l2_arp_term_events_3ec6d6c2)]
 pub struct WantL2ArpTermEvents {
 	pub client_index: u32,
@@ -572,13 +675,13 @@ pub struct WantL2ArpTermEvents {
 	pub enable: bool,
 	pub pid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_l2_arp_term_events_reply_e8d4e804)]
 pub struct WantL2ArpTermEventsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2_arp_term_event_6963e07a)]
 pub struct L2ArpTermEvent {
 	pub client_index: u32,
@@ -587,3 +690,70 @@ pub struct L2ArpTermEvent {
 	pub sw_if_index: InterfaceIndex,
 	pub mac: MacAddress,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"l2_xconnect_details_472b6b67",
+	"l2_xconnect_dump_51077d14",
+	"l2_fib_table_details_a44ef6b8",
+	"l2_fib_table_dump_c25fdce6",
+	"l2_fib_clear_table_51077d14",
+	"l2_fib_clear_table_reply_e8d4e804",
+	"l2fib_flush_all_51077d14",
+	"l2fib_flush_all_reply_e8d4e804",
+	"l2fib_flush_bd_c25fdce6",
+	"l2fib_flush_bd_reply_e8d4e804",
+	"l2fib_flush_int_f9e6675e",
+	"l2fib_flush_int_reply_e8d4e804",
+	"l2fib_add_del_eddda487",
+	"l2fib_add_del_reply_e8d4e804",
+	"want_l2_macs_events_9aabdfde",
+	"want_l2_macs_events_reply_e8d4e804",
+	"want_l2_macs_events2_cc1377b0",
+	"want_l2_macs_events2_reply_e8d4e804",
+	"l2fib_set_scan_delay_a3b968a4",
+	"l2fib_set_scan_delay_reply_e8d4e804",
+	"l2_macs_event_44b8fd64",
+	"l2_flags_fc41cfe8",
+	"l2_flags_reply_29b2a2b3",
+	"bridge_domain_set_mac_age_b537ad7b",
+	"bridge_domain_set_mac_age_reply_e8d4e804",
+	"bridge_domain_set_default_learn_limit_f097ffce",
+	"bridge_domain_set_default_learn_limit_reply_e8d4e804",
+	"bridge_domain_set_learn_limit_89c52b5f",
+	"bridge_domain_set_learn_limit_reply_e8d4e804",
+	"bridge_domain_add_del_600b7170",
+	"bridge_domain_add_del_reply_e8d4e804",
+	"bridge_domain_add_del_v2_600b7170",
+	"bridge_domain_add_del_v2_reply_fcb1e980",
+	"bridge_domain_dump_74396a43",
+	"bridge_domain_details_0fa506fd",
+	"bridge_flags_1b0c5fbd",
+	"bridge_flags_reply_29b2a2b3",
+	"l2_interface_vlan_tag_rewrite_62cc0bbc",
+	"l2_interface_vlan_tag_rewrite_reply_e8d4e804",
+	"l2_interface_pbb_tag_rewrite_38e802a8",
+	"l2_interface_pbb_tag_rewrite_reply_e8d4e804",
+	"l2_patch_add_del_a1f6a6f3",
+	"l2_patch_add_del_reply_e8d4e804",
+	"sw_interface_set_l2_xconnect_4fa28a85",
+	"sw_interface_set_l2_xconnect_reply_e8d4e804",
+	"sw_interface_set_l2_bridge_d0678b13",
+	"sw_interface_set_l2_bridge_reply_e8d4e804",
+	"bd_ip_mac_add_del_0257c869",
+	"bd_ip_mac_add_del_reply_e8d4e804",
+	"bd_ip_mac_flush_c25fdce6",
+	"bd_ip_mac_flush_reply_e8d4e804",
+	"bd_ip_mac_details_545af86a",
+	"bd_ip_mac_dump_c25fdce6",
+	"l2_interface_efp_filter_5501adee",
+	"l2_interface_efp_filter_reply_e8d4e804",
+	"sw_interface_set_vpath_ae6cfcfb",
+	"sw_interface_set_vpath_reply_e8d4e804",
+	"bvi_create_f5398559",
+	"bvi_create_reply_5383d31f",
+	"bvi_delete_f9e6675e",
+	"bvi_delete_reply_e8d4e804",
+	"want_l2_arp_term_events_3ec6d6c2",
+	"want_l2_arp_term_events_reply_e8d4e804",
+	"l2_arp_term_event_6963e07a",
+];
@@ -2,28 +2,59 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum L2tLookupKey {
 	 L2T_LOOKUP_KEY_API_SRC_ADDR=0,
 	 L2T_LOOKUP_KEY_API_DST_ADDR=1,
 	 L2T_LOOKUP_KEY_API_SESSION_ID=2,
+	 Unknown(u32),
 }
 impl Default for L2tLookupKey {
 	fn default() -> Self { L2tLookupKey::L2T_LOOKUP_KEY_API_SRC_ADDR }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for L2tLookupKey {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 L2tLookupKey::L2T_LOOKUP_KEY_API_SRC_ADDR => 0,
+			 L2tLookupKey::L2T_LOOKUP_KEY_API_DST_ADDR => 1,
+			 L2tLookupKey::L2T_LOOKUP_KEY_API_SESSION_ID => 2,
+			 L2tLookupKey::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(L2tLookupKey::L2T_LOOKUP_KEY_API_SRC_ADDR),
+			 1 => Some(L2tLookupKey::L2T_LOOKUP_KEY_API_DST_ADDR),
+			 2 => Some(L2tLookupKey::L2T_LOOKUP_KEY_API_SESSION_ID),
+			 value => Some(L2tLookupKey::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for L2tLookupKey {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("L2tLookupKey value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for L2tLookupKey {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(L2tLookupKey::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2tpv3_create_tunnel_15bed0c2)]
 pub struct L2tpv3CreateTunnel {
 	pub client_index: u32,
@@ -37,14 +68,14 @@ pub struct L2tpv3CreateTunnel {
 	pub l2_sublayer_present: bool,
 	pub encap_vrf_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2tpv3_create_tunnel_reply_5383d31f)]
 pub struct L2tpv3CreateTunnelReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2tpv3_set_tunnel_cookies_b3f4faf7)]
 pub struct L2tpv3SetTunnelCookies {
 	pub client_index: u32,
@@ -53,13 +84,13 @@ pub struct L2tpv3SetTunnelCookies {
 	pub new_local_cookie: u64,
 	pub new_remote_cookie: u64,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2tpv3_set_tunnel_cookies_reply_e8d4e804)]
 pub struct L2tpv3SetTunnelCookiesReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_if_l2tpv3_tunnel_details_50b88993)]
 pub struct SwIfL2tpv3TunnelDetails {
 	pub context: u32,
@@ -73,13 +104,13 @@ pub struct SwIfL2tpv3TunnelDetails {
 	pub remote_cookie: u64,
 	pub l2_sublayer_present: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_if_l2tpv3_tunnel_dump_51077d14)]
 pub struct SwIfL2tpv3TunnelDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2tpv3_interface_enable_disable_3865946c)]
 pub struct L2tpv3InterfaceEnableDisable {
 	pub client_index: u32,
@@ -87,22 +118,35 @@ pub struct L2tpv3InterfaceEnableDisable {
 	pub enable_disable: bool,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2tpv3_interface_enable_disable_reply_e8d4e804)]
 pub struct L2tpv3InterfaceEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2tpv3_set_lookup_key_c9892c86)]
 pub struct L2tpv3SetLookupKey {
 	pub client_index: u32,
 	pub context: u32,
 	pub key: L2tLookupKey,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(l2tpv3_set_lookup_key_reply_e8d4e804)]
 pub struct L2tpv3SetLookupKeyReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"l2tpv3_create_tunnel_15bed0c2",
+	"l2tpv3_create_tunnel_reply_5383d31f",
+	"l2tpv3_set_tunnel_cookies_b3f4faf7",
+	"l2tpv3_set_tunnel_cookies_reply_e8d4e804",
+	"sw_if_l2tpv3_tunnel_details_50b88993",
+	"sw_if_l2tpv3_tunnel_dump_51077d14",
+	"l2tpv3_interface_enable_disable_3865946c",
+	"l2tpv3_interface_enable_disable_reply_e8d4e804",
+	"l2tpv3_set_lookup_key_c9892c86",
+	"l2tpv3_set_lookup_key_reply_e8d4e804",
+];
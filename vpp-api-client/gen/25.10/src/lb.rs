@@ -2,18 +2,17 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::lb_types::*;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_conf_56cd3261)]
 pub struct LbConf {
 	pub client_index: u32,
@@ -23,13 +22,13 @@ pub struct LbConf {
 	pub sticky_buckets_per_core: u32,
 	pub flow_timeout: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_conf_reply_e8d4e804)]
 pub struct LbConfReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_add_del_vip_6fa569c7)]
 pub struct LbAddDelVip {
 	pub client_index: u32,
@@ -45,13 +44,13 @@ pub struct LbAddDelVip {
 	pub new_flows_table_length: u32,
 	pub is_del: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_add_del_vip_reply_e8d4e804)]
 pub struct LbAddDelVipReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_add_del_vip_v2_7c520e0f)]
 pub struct LbAddDelVipV2 {
 	pub client_index: u32,
@@ -68,13 +67,13 @@ pub struct LbAddDelVipV2 {
 	pub src_ip_sticky: bool,
 	pub is_del: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_add_del_vip_v2_reply_e8d4e804)]
 pub struct LbAddDelVipV2Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_add_del_as_35d72500)]
 pub struct LbAddDelAs {
 	pub client_index: u32,
@@ -86,13 +85,13 @@ pub struct LbAddDelAs {
 	pub is_del: bool,
 	pub is_flush: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_add_del_as_reply_e8d4e804)]
 pub struct LbAddDelAsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_flush_vip_1063f819)]
 pub struct LbFlushVip {
 	pub client_index: u32,
@@ -101,13 +100,13 @@ pub struct LbFlushVip {
 	pub protocol: u8,
 	pub port: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_flush_vip_reply_e8d4e804)]
 pub struct LbFlushVipReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_vip_dump_56110cb7)]
 pub struct LbVipDump {
 	pub client_index: u32,
@@ -117,7 +116,7 @@ pub struct LbVipDump {
 	pub protocol: u8,
 	pub port: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_vip_details_1329ec9b)]
 pub struct LbVipDetails {
 	pub context: u32,
@@ -128,7 +127,7 @@ pub struct LbVipDetails {
 	pub target_port: u16,
 	pub flow_table_length: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_as_dump_1063f819)]
 pub struct LbAsDump {
 	pub client_index: u32,
@@ -137,7 +136,7 @@ pub struct LbAsDump {
 	pub protocol: u8,
 	pub port: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_as_details_8d24c29e)]
 pub struct LbAsDetails {
 	pub context: u32,
@@ -146,7 +145,7 @@ pub struct LbAsDetails {
 	pub flags: u8,
 	pub in_use_since: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_add_del_intf_nat4_47d6e753)]
 pub struct LbAddDelIntfNat4 {
 	pub client_index: u32,
@@ -154,13 +153,13 @@ pub struct LbAddDelIntfNat4 {
 	pub is_add: bool,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_add_del_intf_nat4_reply_e8d4e804)]
 pub struct LbAddDelIntfNat4Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_add_del_intf_nat6_47d6e753)]
 pub struct LbAddDelIntfNat6 {
 	pub client_index: u32,
@@ -168,9 +167,30 @@ pub struct LbAddDelIntfNat6 {
 	pub is_add: bool,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lb_add_del_intf_nat6_reply_e8d4e804)]
 pub struct LbAddDelIntfNat6Reply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"lb_conf_56cd3261",
+	"lb_conf_reply_e8d4e804",
+	"lb_add_del_vip_6fa569c7",
+	"lb_add_del_vip_reply_e8d4e804",
+	"lb_add_del_vip_v2_7c520e0f",
+	"lb_add_del_vip_v2_reply_e8d4e804",
+	"lb_add_del_as_35d72500",
+	"lb_add_del_as_reply_e8d4e804",
+	"lb_flush_vip_1063f819",
+	"lb_flush_vip_reply_e8d4e804",
+	"lb_vip_dump_56110cb7",
+	"lb_vip_details_1329ec9b",
+	"lb_as_dump_1063f819",
+	"lb_as_details_8d24c29e",
+	"lb_add_del_intf_nat4_47d6e753",
+	"lb_add_del_intf_nat4_reply_e8d4e804",
+	"lb_add_del_intf_nat6_47d6e753",
+	"lb_add_del_intf_nat6_reply_e8d4e804",
+];
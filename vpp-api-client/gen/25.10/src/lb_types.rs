@@ -2,33 +2,63 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 // Implementation for lb_vip
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct LbVip {
 	pub pfx: AddressWithPrefix,
 	pub protocol: IpProto,
 	pub port: u16,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum LbSrvType {
 	 LB_API_SRV_TYPE_CLUSTERIP=0,
 	 LB_API_SRV_TYPE_NODEPORT=1,
 	 LB_API_SRV_N_TYPES=2,
+	 Unknown(u32),
 }
 impl Default for LbSrvType {
 	fn default() -> Self { LbSrvType::LB_API_SRV_TYPE_CLUSTERIP }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for LbSrvType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 LbSrvType::LB_API_SRV_TYPE_CLUSTERIP => 0,
+			 LbSrvType::LB_API_SRV_TYPE_NODEPORT => 1,
+			 LbSrvType::LB_API_SRV_N_TYPES => 2,
+			 LbSrvType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(LbSrvType::LB_API_SRV_TYPE_CLUSTERIP),
+			 1 => Some(LbSrvType::LB_API_SRV_TYPE_NODEPORT),
+			 2 => Some(LbSrvType::LB_API_SRV_N_TYPES),
+			 value => Some(LbSrvType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for LbSrvType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for LbSrvType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(LbSrvType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum LbEncapType {
 	 LB_API_ENCAP_TYPE_GRE4=0,
@@ -37,22 +67,92 @@ pub enum LbEncapType {
 	 LB_API_ENCAP_TYPE_NAT4=3,
 	 LB_API_ENCAP_TYPE_NAT6=4,
 	 LB_API_ENCAP_N_TYPES=5,
+	 Unknown(u32),
 }
 impl Default for LbEncapType {
 	fn default() -> Self { LbEncapType::LB_API_ENCAP_TYPE_GRE4 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for LbEncapType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 LbEncapType::LB_API_ENCAP_TYPE_GRE4 => 0,
+			 LbEncapType::LB_API_ENCAP_TYPE_GRE6 => 1,
+			 LbEncapType::LB_API_ENCAP_TYPE_L3DSR => 2,
+			 LbEncapType::LB_API_ENCAP_TYPE_NAT4 => 3,
+			 LbEncapType::LB_API_ENCAP_TYPE_NAT6 => 4,
+			 LbEncapType::LB_API_ENCAP_N_TYPES => 5,
+			 LbEncapType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(LbEncapType::LB_API_ENCAP_TYPE_GRE4),
+			 1 => Some(LbEncapType::LB_API_ENCAP_TYPE_GRE6),
+			 2 => Some(LbEncapType::LB_API_ENCAP_TYPE_L3DSR),
+			 3 => Some(LbEncapType::LB_API_ENCAP_TYPE_NAT4),
+			 4 => Some(LbEncapType::LB_API_ENCAP_TYPE_NAT6),
+			 5 => Some(LbEncapType::LB_API_ENCAP_N_TYPES),
+			 value => Some(LbEncapType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for LbEncapType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for LbEncapType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(LbEncapType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum LbLkpTypeT {
 	 LB_API_LKP_SAME_IP_PORT=0,
 	 LB_API_LKP_DIFF_IP_PORT=1,
 	 LB_API_LKP_ALL_PORT_IP=2,
 	 LB_API_LKP_N_TYPES=3,
+	 Unknown(u32),
 }
 impl Default for LbLkpTypeT {
 	fn default() -> Self { LbLkpTypeT::LB_API_LKP_SAME_IP_PORT }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for LbLkpTypeT {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 LbLkpTypeT::LB_API_LKP_SAME_IP_PORT => 0,
+			 LbLkpTypeT::LB_API_LKP_DIFF_IP_PORT => 1,
+			 LbLkpTypeT::LB_API_LKP_ALL_PORT_IP => 2,
+			 LbLkpTypeT::LB_API_LKP_N_TYPES => 3,
+			 LbLkpTypeT::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(LbLkpTypeT::LB_API_LKP_SAME_IP_PORT),
+			 1 => Some(LbLkpTypeT::LB_API_LKP_DIFF_IP_PORT),
+			 2 => Some(LbLkpTypeT::LB_API_LKP_ALL_PORT_IP),
+			 3 => Some(LbLkpTypeT::LB_API_LKP_N_TYPES),
+			 value => Some(LbLkpTypeT::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for LbLkpTypeT {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for LbLkpTypeT {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(LbLkpTypeT::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum LbVipType {
 	 LB_API_VIP_TYPE_IP6_GRE6=0,
@@ -63,17 +163,92 @@ pub enum LbVipType {
 	 LB_API_VIP_TYPE_IP4_NAT4=5,
 	 LB_API_VIP_TYPE_IP6_NAT6=6,
 	 LB_API_VIP_N_TYPES=7,
+	 Unknown(u32),
 }
 impl Default for LbVipType {
 	fn default() -> Self { LbVipType::LB_API_VIP_TYPE_IP6_GRE6 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for LbVipType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 LbVipType::LB_API_VIP_TYPE_IP6_GRE6 => 0,
+			 LbVipType::LB_API_VIP_TYPE_IP6_GRE4 => 1,
+			 LbVipType::LB_API_VIP_TYPE_IP4_GRE6 => 2,
+			 LbVipType::LB_API_VIP_TYPE_IP4_GRE4 => 3,
+			 LbVipType::LB_API_VIP_TYPE_IP4_L3DSR => 4,
+			 LbVipType::LB_API_VIP_TYPE_IP4_NAT4 => 5,
+			 LbVipType::LB_API_VIP_TYPE_IP6_NAT6 => 6,
+			 LbVipType::LB_API_VIP_N_TYPES => 7,
+			 LbVipType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(LbVipType::LB_API_VIP_TYPE_IP6_GRE6),
+			 1 => Some(LbVipType::LB_API_VIP_TYPE_IP6_GRE4),
+			 2 => Some(LbVipType::LB_API_VIP_TYPE_IP4_GRE6),
+			 3 => Some(LbVipType::LB_API_VIP_TYPE_IP4_GRE4),
+			 4 => Some(LbVipType::LB_API_VIP_TYPE_IP4_L3DSR),
+			 5 => Some(LbVipType::LB_API_VIP_TYPE_IP4_NAT4),
+			 6 => Some(LbVipType::LB_API_VIP_TYPE_IP6_NAT6),
+			 7 => Some(LbVipType::LB_API_VIP_N_TYPES),
+			 value => Some(LbVipType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for LbVipType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for LbVipType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(LbVipType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum LbNatProtocol {
 	 LB_API_NAT_PROTOCOL_UDP=6,
 	 LB_API_NAT_PROTOCOL_TCP=23,
 	 LB_API_NAT_PROTOCOL_ANY=4294967295,
+	 Unknown(u32)=0,
 }
 impl Default for LbNatProtocol {
 	fn default() -> Self { LbNatProtocol::LB_API_NAT_PROTOCOL_UDP }
 }
+impl AsU32 for LbNatProtocol {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 LbNatProtocol::LB_API_NAT_PROTOCOL_UDP => 6,
+			 LbNatProtocol::LB_API_NAT_PROTOCOL_TCP => 23,
+			 LbNatProtocol::LB_API_NAT_PROTOCOL_ANY => 4294967295,
+			 LbNatProtocol::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 6 => Some(LbNatProtocol::LB_API_NAT_PROTOCOL_UDP),
+			 23 => Some(LbNatProtocol::LB_API_NAT_PROTOCOL_TCP),
+			 4294967295 => Some(LbNatProtocol::LB_API_NAT_PROTOCOL_ANY),
+			 value => Some(LbNatProtocol::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for LbNatProtocol {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for LbNatProtocol {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(LbNatProtocol::Unknown(value)))
+	 }
+}
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
@@ -2,50 +2,79 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum LcpItfHostType {
 	 LCP_API_ITF_HOST_TAP=0,
 	 LCP_API_ITF_HOST_TUN=1,
+	 Unknown(u32),
 }
 impl Default for LcpItfHostType {
 	fn default() -> Self { LcpItfHostType::LCP_API_ITF_HOST_TAP }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for LcpItfHostType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 LcpItfHostType::LCP_API_ITF_HOST_TAP => 0,
+			 LcpItfHostType::LCP_API_ITF_HOST_TUN => 1,
+			 LcpItfHostType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(LcpItfHostType::LCP_API_ITF_HOST_TAP),
+			 1 => Some(LcpItfHostType::LCP_API_ITF_HOST_TUN),
+			 value => Some(LcpItfHostType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for LcpItfHostType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("LcpItfHostType value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for LcpItfHostType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(LcpItfHostType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_default_ns_set_69749409)]
 pub struct LcpDefaultNsSet {
 	pub client_index: u32,
 	pub context: u32,
 	pub netns: FixedSizeString<typenum::U32>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_default_ns_set_reply_e8d4e804)]
 pub struct LcpDefaultNsSetReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_default_ns_get_51077d14)]
 pub struct LcpDefaultNsGet {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_default_ns_get_reply_5102feee)]
 pub struct LcpDefaultNsGetReply {
 	pub context: u32,
 	pub netns: FixedSizeString<typenum::U32>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_add_del_40482b80)]
 pub struct LcpItfPairAddDel {
 	pub client_index: u32,
@@ -56,13 +85,13 @@ pub struct LcpItfPairAddDel {
 	pub host_if_type: LcpItfHostType,
 	pub netns: FixedSizeString<typenum::U32>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_add_del_reply_e8d4e804)]
 pub struct LcpItfPairAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_add_del_v2_40482b80)]
 pub struct LcpItfPairAddDelV2 {
 	pub client_index: u32,
@@ -73,14 +102,14 @@ pub struct LcpItfPairAddDelV2 {
 	pub host_if_type: LcpItfHostType,
 	pub netns: FixedSizeString<typenum::U32>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_add_del_v2_reply_39452f52)]
 pub struct LcpItfPairAddDelV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub host_sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_add_del_v3_40482b80)]
 pub struct LcpItfPairAddDelV3 {
 	pub client_index: u32,
@@ -91,7 +120,7 @@ pub struct LcpItfPairAddDelV3 {
 	pub host_if_type: LcpItfHostType,
 	pub netns: FixedSizeString<typenum::U32>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_add_del_v3_reply_c2502663)]
 pub struct LcpItfPairAddDelV3Reply {
 	pub context: u32,
@@ -99,21 +128,21 @@ pub struct LcpItfPairAddDelV3Reply {
 	pub vif_index: u32,
 	pub host_sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_get_f75ba505)]
 pub struct LcpItfPairGet {
 	pub client_index: u32,
 	pub context: u32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_get_reply_53b48f5d)]
 pub struct LcpItfPairGetReply {
 	pub context: u32,
 	pub retval: i32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_get_v2_47250981)]
 pub struct LcpItfPairGetV2 {
 	pub client_index: u32,
@@ -121,14 +150,14 @@ pub struct LcpItfPairGetV2 {
 	pub cursor: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_get_v2_reply_53b48f5d)]
 pub struct LcpItfPairGetV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_details_8b5481af)]
 pub struct LcpItfPairDetails {
 	pub context: u32,
@@ -139,54 +168,81 @@ pub struct LcpItfPairDetails {
 	pub host_if_type: LcpItfHostType,
 	pub netns: FixedSizeString<typenum::U32>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_ethertype_enable_f893dae1)]
 pub struct LcpEthertypeEnable {
 	pub client_index: u32,
 	pub context: u32,
 	pub ethertype: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_ethertype_enable_reply_e8d4e804)]
 pub struct LcpEthertypeEnableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_ethertype_get_51077d14)]
 pub struct LcpEthertypeGet {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_ethertype_get_reply_db48c31e)]
 pub struct LcpEthertypeGetReply {
 	pub context: u32,
 	pub retval: i32,
 	pub count: u16,
+	#[vpp(count = "count")]
 	pub ethertypes: VariableSizeArray<u16>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_replace_begin_51077d14)]
 pub struct LcpItfPairReplaceBegin {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_replace_begin_reply_e8d4e804)]
 pub struct LcpItfPairReplaceBeginReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_replace_end_51077d14)]
 pub struct LcpItfPairReplaceEnd {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(lcp_itf_pair_replace_end_reply_e8d4e804)]
 pub struct LcpItfPairReplaceEndReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"lcp_default_ns_set_69749409",
+	"lcp_default_ns_set_reply_e8d4e804",
+	"lcp_default_ns_get_51077d14",
+	"lcp_default_ns_get_reply_5102feee",
+	"lcp_itf_pair_add_del_40482b80",
+	"lcp_itf_pair_add_del_reply_e8d4e804",
+	"lcp_itf_pair_add_del_v2_40482b80",
+	"lcp_itf_pair_add_del_v2_reply_39452f52",
+	"lcp_itf_pair_add_del_v3_40482b80",
+	"lcp_itf_pair_add_del_v3_reply_c2502663",
+	"lcp_itf_pair_get_f75ba505",
+	"lcp_itf_pair_get_reply_53b48f5d",
+	"lcp_itf_pair_get_v2_47250981",
+	"lcp_itf_pair_get_v2_reply_53b48f5d",
+	"lcp_itf_pair_details_8b5481af",
+	"lcp_ethertype_enable_f893dae1",
+	"lcp_ethertype_enable_reply_e8d4e804",
+	"lcp_ethertype_get_51077d14",
+	"lcp_ethertype_get_reply_db48c31e",
+	"lcp_itf_pair_replace_begin_51077d14",
+	"lcp_itf_pair_replace_begin_reply_e8d4e804",
+	"lcp_itf_pair_replace_end_51077d14",
+	"lcp_itf_pair_replace_end_reply_e8d4e804",
+];
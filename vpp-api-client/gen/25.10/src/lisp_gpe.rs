@@ -2,26 +2,25 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::lisp_types::*;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
 // Implementation for gpe_locator
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct GpeLocator {
 	pub weight: u8,
 	pub addr: Address,
 }
 // Implementation for gpe_fwd_entry
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct GpeFwdEntry {
 	pub fwd_entry_index: u32,
 	pub dp_table: u32,
@@ -31,13 +30,13 @@ pub struct GpeFwdEntry {
 	pub action: u8,
 }
 // Implementation for gpe_native_fwd_rpath
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct GpeNativeFwdRpath {
 	pub fib_index: u32,
 	pub nh_sw_if_index: InterfaceIndex,
 	pub nh_addr: Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_add_del_fwd_entry_f0847644)]
 pub struct GpeAddDelFwdEntry {
 	pub client_index: u32,
@@ -49,29 +48,30 @@ pub struct GpeAddDelFwdEntry {
 	pub dp_table: u32,
 	pub action: u8,
 	pub loc_num: u32,
+	#[vpp(count = "loc_num")]
 	pub locs: VariableSizeArray<GpeLocator>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_add_del_fwd_entry_reply_efe5f176)]
 pub struct GpeAddDelFwdEntryReply {
 	pub context: u32,
 	pub retval: i32,
 	pub fwd_entry_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_enable_disable_c264d7bf)]
 pub struct GpeEnableDisable {
 	pub client_index: u32,
 	pub context: u32,
 	pub is_enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_enable_disable_reply_e8d4e804)]
 pub struct GpeEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_add_del_iface_3ccff273)]
 pub struct GpeAddDelIface {
 	pub client_index: u32,
@@ -81,82 +81,84 @@ pub struct GpeAddDelIface {
 	pub dp_table: u32,
 	pub vni: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_add_del_iface_reply_e8d4e804)]
 pub struct GpeAddDelIfaceReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_fwd_entry_vnis_get_51077d14)]
 pub struct GpeFwdEntryVnisGet {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_fwd_entry_vnis_get_reply_aa70da20)]
 pub struct GpeFwdEntryVnisGetReply {
 	pub context: u32,
 	pub retval: i32,
 	pub count: u32,
+	#[vpp(count = "count")]
 	pub vnis: VariableSizeArray<u32>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_fwd_entries_get_8d1f2fe9)]
 pub struct GpeFwdEntriesGet {
 	pub client_index: u32,
 	pub context: u32,
 	pub vni: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_fwd_entries_get_reply_c4844876)]
 pub struct GpeFwdEntriesGetReply {
 	pub context: u32,
 	pub retval: i32,
 	pub count: u32,
+	#[vpp(count = "count")]
 	pub entries: VariableSizeArray<GpeFwdEntry>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_fwd_entry_path_dump_39bce980)]
 pub struct GpeFwdEntryPathDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub fwd_entry_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_fwd_entry_path_details_483df51a)]
 pub struct GpeFwdEntryPathDetails {
 	pub context: u32,
 	pub lcl_loc: GpeLocator,
 	pub rmt_loc: GpeLocator,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_set_encap_mode_bd819eac)]
 pub struct GpeSetEncapMode {
 	pub client_index: u32,
 	pub context: u32,
 	pub is_vxlan: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_set_encap_mode_reply_e8d4e804)]
 pub struct GpeSetEncapModeReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_get_encap_mode_51077d14)]
 pub struct GpeGetEncapMode {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_get_encap_mode_reply_36e3f7ca)]
 pub struct GpeGetEncapModeReply {
 	pub context: u32,
 	pub retval: i32,
 	pub encap_mode: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_add_del_native_fwd_rpath_43fc8b54)]
 pub struct GpeAddDelNativeFwdRpath {
 	pub client_index: u32,
@@ -166,24 +168,48 @@ pub struct GpeAddDelNativeFwdRpath {
 	pub nh_sw_if_index: InterfaceIndex,
 	pub nh_addr: Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_add_del_native_fwd_rpath_reply_e8d4e804)]
 pub struct GpeAddDelNativeFwdRpathReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_native_fwd_rpaths_get_f652ceb4)]
 pub struct GpeNativeFwdRpathsGet {
 	pub client_index: u32,
 	pub context: u32,
 	pub is_ip4: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(gpe_native_fwd_rpaths_get_reply_7a1ca5a2)]
 pub struct GpeNativeFwdRpathsGetReply {
 	pub context: u32,
 	pub retval: i32,
 	pub count: u32,
+	#[vpp(count = "count")]
 	pub entries: VariableSizeArray<GpeNativeFwdRpath>,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"gpe_add_del_fwd_entry_f0847644",
+	"gpe_add_del_fwd_entry_reply_efe5f176",
+	"gpe_enable_disable_c264d7bf",
+	"gpe_enable_disable_reply_e8d4e804",
+	"gpe_add_del_iface_3ccff273",
+	"gpe_add_del_iface_reply_e8d4e804",
+	"gpe_fwd_entry_vnis_get_51077d14",
+	"gpe_fwd_entry_vnis_get_reply_aa70da20",
+	"gpe_fwd_entries_get_8d1f2fe9",
+	"gpe_fwd_entries_get_reply_c4844876",
+	"gpe_fwd_entry_path_dump_39bce980",
+	"gpe_fwd_entry_path_details_483df51a",
+	"gpe_set_encap_mode_bd819eac",
+	"gpe_set_encap_mode_reply_e8d4e804",
+	"gpe_get_encap_mode_51077d14",
+	"gpe_get_encap_mode_reply_36e3f7ca",
+	"gpe_add_del_native_fwd_rpath_43fc8b54",
+	"gpe_add_del_native_fwd_rpath_reply_e8d4e804",
+	"gpe_native_fwd_rpaths_get_f652ceb4",
+	"gpe_native_fwd_rpaths_get_reply_7a1ca5a2",
+];
@@ -2,71 +2,137 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
 // Implementation for local_locator
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct LocalLocator {
 	pub sw_if_index: InterfaceIndex,
 	pub priority: u8,
 	pub weight: u8,
 }
 // Implementation for remote_locator
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct RemoteLocator {
 	pub priority: u8,
 	pub weight: u8,
 	pub ip_address: Address,
 }
 // Implementation for nsh
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Nsh {
 	pub spi: u32,
 	pub si: u8,
 }
 // Implementation for eid
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Eid {
 	pub typ: EidType,
 	pub address: EidAddress,
 }
 // Implementation for hmac_key
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct HmacKey {
 	pub id: HmacKeyId,
 	pub key: FixedSizeArray<u8, typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, Default, VppUnionIdent)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default, VppUnionIdent)]
 #[types(Prefix:18)]
 #[types(MacAddress:6)]
 #[types(Nsh:5)]
 pub struct EidAddress(FixedSizeArray<u8, typenum::U18>);
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum EidType {
 	 EID_TYPE_API_PREFIX=0,
 	 EID_TYPE_API_MAC=1,
 	 EID_TYPE_API_NSH=2,
+	 Unknown(u32),
 }
 impl Default for EidType {
 	fn default() -> Self { EidType::EID_TYPE_API_PREFIX }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+impl AsU32 for EidType {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 EidType::EID_TYPE_API_PREFIX => 0,
+			 EidType::EID_TYPE_API_MAC => 1,
+			 EidType::EID_TYPE_API_NSH => 2,
+			 EidType::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(EidType::EID_TYPE_API_PREFIX),
+			 1 => Some(EidType::EID_TYPE_API_MAC),
+			 2 => Some(EidType::EID_TYPE_API_NSH),
+			 value => Some(EidType::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for EidType {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("EidType value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for EidType {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(EidType::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum HmacKeyId {
 	 KEY_ID_API_HMAC_NO_KEY=0,
 	 KEY_ID_API_HMAC_SHA_1_96=1,
 	 KEY_ID_API_HMAC_SHA_256_128=2,
+	 Unknown(u32),
 }
 impl Default for HmacKeyId {
 	fn default() -> Self { HmacKeyId::KEY_ID_API_HMAC_NO_KEY }
 }
+impl AsU32 for HmacKeyId {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 HmacKeyId::KEY_ID_API_HMAC_NO_KEY => 0,
+			 HmacKeyId::KEY_ID_API_HMAC_SHA_1_96 => 1,
+			 HmacKeyId::KEY_ID_API_HMAC_SHA_256_128 => 2,
+			 HmacKeyId::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(HmacKeyId::KEY_ID_API_HMAC_NO_KEY),
+			 1 => Some(HmacKeyId::KEY_ID_API_HMAC_SHA_1_96),
+			 2 => Some(HmacKeyId::KEY_ID_API_HMAC_SHA_256_128),
+			 value => Some(HmacKeyId::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for HmacKeyId {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("HmacKeyId value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for HmacKeyId {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(HmacKeyId::Unknown(value)))
+	 }
+}
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
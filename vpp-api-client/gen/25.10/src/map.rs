@@ -2,17 +2,16 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_add_domain_249f195c)]
 pub struct MapAddDomain {
 	pub client_index: u32,
@@ -26,27 +25,27 @@ pub struct MapAddDomain {
 	pub mtu: u16,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_add_domain_reply_3e6d4e2c)]
 pub struct MapAddDomainReply {
 	pub context: u32,
 	pub index: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_del_domain_8ac76db6)]
 pub struct MapDelDomain {
 	pub client_index: u32,
 	pub context: u32,
 	pub index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_del_domain_reply_e8d4e804)]
 pub struct MapDelDomainReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_add_del_rule_c65b32f7)]
 pub struct MapAddDelRule {
 	pub client_index: u32,
@@ -56,33 +55,33 @@ pub struct MapAddDelRule {
 	pub ip6_dst: Ip6Address,
 	pub psid: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_add_del_rule_reply_e8d4e804)]
 pub struct MapAddDelRuleReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_domains_get_f75ba505)]
 pub struct MapDomainsGet {
 	pub client_index: u32,
 	pub context: u32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_domains_get_reply_53b48f5d)]
 pub struct MapDomainsGetReply {
 	pub context: u32,
 	pub retval: i32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_domain_dump_51077d14)]
 pub struct MapDomainDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_domain_details_796edb50)]
 pub struct MapDomainDetails {
 	pub context: u32,
@@ -97,21 +96,21 @@ pub struct MapDomainDetails {
 	pub mtu: u16,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_rule_dump_e43e6ff6)]
 pub struct MapRuleDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub domain_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_rule_details_c7cbeea5)]
 pub struct MapRuleDetails {
 	pub context: u32,
 	pub ip6_dst: Ip6Address,
 	pub psid: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_if_enable_disable_59bb32f4)]
 pub struct MapIfEnableDisable {
 	pub client_index: u32,
@@ -120,19 +119,19 @@ pub struct MapIfEnableDisable {
 	pub is_enable: bool,
 	pub is_translation: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_if_enable_disable_reply_e8d4e804)]
 pub struct MapIfEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_summary_stats_51077d14)]
 pub struct MapSummaryStats {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_summary_stats_reply_0e4ace0e)]
 pub struct MapSummaryStatsReply {
 	pub context: u32,
@@ -143,7 +142,7 @@ pub struct MapSummaryStatsReply {
 	pub total_ip4_fragments: u64,
 	pub total_security_check: FixedSizeArray<u64, typenum::U2>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_set_fragmentation_9ff54d90)]
 pub struct MapParamSetFragmentation {
 	pub client_index: u32,
@@ -151,39 +150,39 @@ pub struct MapParamSetFragmentation {
 	pub inner: bool,
 	pub ignore_df: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_set_fragmentation_reply_e8d4e804)]
 pub struct MapParamSetFragmentationReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_set_icmp_58210cbf)]
 pub struct MapParamSetIcmp {
 	pub client_index: u32,
 	pub context: u32,
 	pub ip4_err_relay_src: Ip4Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_set_icmp_reply_e8d4e804)]
 pub struct MapParamSetIcmpReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_set_icmp6_5d01f8c1)]
 pub struct MapParamSetIcmp6 {
 	pub client_index: u32,
 	pub context: u32,
 	pub enable_unreachable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_set_icmp6_reply_e8d4e804)]
 pub struct MapParamSetIcmp6Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_add_del_pre_resolve_dae5af03)]
 pub struct MapParamAddDelPreResolve {
 	pub client_index: u32,
@@ -192,13 +191,13 @@ pub struct MapParamAddDelPreResolve {
 	pub ip4_nh_address: Ip4Address,
 	pub ip6_nh_address: Ip6Address,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_add_del_pre_resolve_reply_e8d4e804)]
 pub struct MapParamAddDelPreResolveReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_set_security_check_6abe9836)]
 pub struct MapParamSetSecurityCheck {
 	pub client_index: u32,
@@ -206,13 +205,13 @@ pub struct MapParamSetSecurityCheck {
 	pub enable: bool,
 	pub fragments: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_set_security_check_reply_e8d4e804)]
 pub struct MapParamSetSecurityCheckReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_set_traffic_class_9cac455c)]
 pub struct MapParamSetTrafficClass {
 	pub client_index: u32,
@@ -220,32 +219,32 @@ pub struct MapParamSetTrafficClass {
 	pub copy: bool,
 	pub tc_class: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_set_traffic_class_reply_e8d4e804)]
 pub struct MapParamSetTrafficClassReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_set_tcp_87a825d9)]
 pub struct MapParamSetTcp {
 	pub client_index: u32,
 	pub context: u32,
 	pub tcp_mss: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_set_tcp_reply_e8d4e804)]
 pub struct MapParamSetTcpReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_get_51077d14)]
 pub struct MapParamGet {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(map_param_get_reply_26272c90)]
 pub struct MapParamGetReply {
 	pub context: u32,
@@ -265,3 +264,38 @@ pub struct MapParamGetReply {
 	pub tc_copy: bool,
 	pub tc_class: u8,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"map_add_domain_249f195c",
+	"map_add_domain_reply_3e6d4e2c",
+	"map_del_domain_8ac76db6",
+	"map_del_domain_reply_e8d4e804",
+	"map_add_del_rule_c65b32f7",
+	"map_add_del_rule_reply_e8d4e804",
+	"map_domains_get_f75ba505",
+	"map_domains_get_reply_53b48f5d",
+	"map_domain_dump_51077d14",
+	"map_domain_details_796edb50",
+	"map_rule_dump_e43e6ff6",
+	"map_rule_details_c7cbeea5",
+	"map_if_enable_disable_59bb32f4",
+	"map_if_enable_disable_reply_e8d4e804",
+	"map_summary_stats_51077d14",
+	"map_summary_stats_reply_0e4ace0e",
+	"map_param_set_fragmentation_9ff54d90",
+	"map_param_set_fragmentation_reply_e8d4e804",
+	"map_param_set_icmp_58210cbf",
+	"map_param_set_icmp_reply_e8d4e804",
+	"map_param_set_icmp6_5d01f8c1",
+	"map_param_set_icmp6_reply_e8d4e804",
+	"map_param_add_del_pre_resolve_dae5af03",
+	"map_param_add_del_pre_resolve_reply_e8d4e804",
+	"map_param_set_security_check_6abe9836",
+	"map_param_set_security_check_reply_e8d4e804",
+	"map_param_set_traffic_class_9cac455c",
+	"map_param_set_traffic_class_reply_e8d4e804",
+	"map_param_set_tcp_87a825d9",
+	"map_param_set_tcp_reply_e8d4e804",
+	"map_param_get_51077d14",
+	"map_param_get_reply_26272c90",
+];
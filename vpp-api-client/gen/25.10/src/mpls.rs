@@ -2,19 +2,18 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::fib_types::*;
 use crate::interface_types::*;
 // Implementation for mpls_tunnel
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct MplsTunnel {
 	pub mt_sw_if_index: InterfaceIndex,
 	pub mt_tunnel_index: u32,
@@ -22,16 +21,17 @@ pub struct MplsTunnel {
 	pub mt_is_multicast: bool,
 	pub mt_tag: FixedSizeString<typenum::U64>,
 	pub mt_n_paths: u8,
+	#[vpp(count = "mt_n_paths")]
 	pub mt_paths: VariableSizeArray<FibPath>,
 }
 // Implementation for mpls_table
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct MplsTable {
 	pub mt_table_id: u32,
 	pub mt_name: FixedSizeString<typenum::U64>,
 }
 // Implementation for mpls_route
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct MplsRoute {
 	pub mr_table_id: u32,
 	pub mr_label: u32,
@@ -39,9 +39,10 @@ pub struct MplsRoute {
 	pub mr_eos_proto: u8,
 	pub mr_is_multicast: bool,
 	pub mr_n_paths: u8,
+	#[vpp(count = "mr_n_paths")]
 	pub mr_paths: VariableSizeArray<FibPath>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_ip_bind_unbind_c7533b32)]
 pub struct MplsIpBindUnbind {
 	pub client_index: u32,
@@ -52,13 +53,13 @@ pub struct MplsIpBindUnbind {
 	pub mb_is_bind: bool,
 	pub mb_prefix: Prefix,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_ip_bind_unbind_reply_e8d4e804)]
 pub struct MplsIpBindUnbindReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_tunnel_add_del_44350ac1)]
 pub struct MplsTunnelAddDel {
 	pub client_index: u32,
@@ -66,7 +67,7 @@ pub struct MplsTunnelAddDel {
 	pub mt_is_add: bool,
 	pub mt_tunnel: MplsTunnel,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_tunnel_add_del_reply_afb01472)]
 pub struct MplsTunnelAddDelReply {
 	pub context: u32,
@@ -74,33 +75,33 @@ pub struct MplsTunnelAddDelReply {
 	pub sw_if_index: InterfaceIndex,
 	pub tunnel_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_tunnel_dump_f9e6675e)]
 pub struct MplsTunnelDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_tunnel_details_57118ae3)]
 pub struct MplsTunnelDetails {
 	pub context: u32,
 	pub mt_tunnel: MplsTunnel,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_interface_dump_f9e6675e)]
 pub struct MplsInterfaceDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_interface_details_0b45011c)]
 pub struct MplsInterfaceDetails {
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_table_add_del_57817512)]
 pub struct MplsTableAddDel {
 	pub client_index: u32,
@@ -108,25 +109,25 @@ pub struct MplsTableAddDel {
 	pub mt_is_add: bool,
 	pub mt_table: MplsTable,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_table_add_del_reply_e8d4e804)]
 pub struct MplsTableAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_table_dump_51077d14)]
 pub struct MplsTableDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_table_details_f03ecdc8)]
 pub struct MplsTableDetails {
 	pub context: u32,
 	pub mt_table: MplsTable,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_route_add_del_8e1d1e07)]
 pub struct MplsRouteAddDel {
 	pub client_index: u32,
@@ -135,27 +136,27 @@ pub struct MplsRouteAddDel {
 	pub mr_is_multipath: bool,
 	pub mr_route: MplsRoute,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_route_add_del_reply_1992deab)]
 pub struct MplsRouteAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 	pub stats_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_route_dump_935fdefa)]
 pub struct MplsRouteDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub table: MplsTable,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(mpls_route_details_9b5043dc)]
 pub struct MplsRouteDetails {
 	pub context: u32,
 	pub mr_route: MplsRoute,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_mpls_enable_ae6cfcfb)]
 pub struct SwInterfaceSetMplsEnable {
 	pub client_index: u32,
@@ -163,9 +164,30 @@ pub struct SwInterfaceSetMplsEnable {
 	pub sw_if_index: InterfaceIndex,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_interface_set_mpls_enable_reply_e8d4e804)]
 pub struct SwInterfaceSetMplsEnableReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"mpls_ip_bind_unbind_c7533b32",
+	"mpls_ip_bind_unbind_reply_e8d4e804",
+	"mpls_tunnel_add_del_44350ac1",
+	"mpls_tunnel_add_del_reply_afb01472",
+	"mpls_tunnel_dump_f9e6675e",
+	"mpls_tunnel_details_57118ae3",
+	"mpls_interface_dump_f9e6675e",
+	"mpls_interface_details_0b45011c",
+	"mpls_table_add_del_57817512",
+	"mpls_table_add_del_reply_e8d4e804",
+	"mpls_table_dump_51077d14",
+	"mpls_table_details_f03ecdc8",
+	"mpls_route_add_del_8e1d1e07",
+	"mpls_route_add_del_reply_1992deab",
+	"mpls_route_dump_935fdefa",
+	"mpls_route_details_9b5043dc",
+	"sw_interface_set_mpls_enable_ae6cfcfb",
+	"sw_interface_set_mpls_enable_reply_e8d4e804",
+];
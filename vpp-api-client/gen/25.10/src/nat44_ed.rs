@@ -2,55 +2,93 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::nat_types::*;
 use crate::interface_types::*;
 // Implementation for nat44_lb_addr_port
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Nat44LbAddrPort {
 	pub addr: Ip4Address,
 	pub port: u16,
 	pub probability: u8,
 	pub vrf_id: u32,
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u8)]
 pub enum Nat44ConfigFlags {
 	 NAT44_IS_ENDPOINT_INDEPENDENT=0,
 	 NAT44_IS_ENDPOINT_DEPENDENT=1,
 	 NAT44_IS_STATIC_MAPPING_ONLY=2,
 	 NAT44_IS_CONNECTION_TRACKING=4,
 	 NAT44_IS_OUT2IN_DPO=8,
+	 Unknown(u32),
 }
 impl Default for Nat44ConfigFlags {
 	fn default() -> Self { Nat44ConfigFlags::NAT44_IS_ENDPOINT_INDEPENDENT }
 }
-impl AsEnumFlag for Nat44ConfigFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for Nat44ConfigFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 Nat44ConfigFlags::NAT44_IS_ENDPOINT_INDEPENDENT => 0,
+			 Nat44ConfigFlags::NAT44_IS_ENDPOINT_DEPENDENT => 1,
+			 Nat44ConfigFlags::NAT44_IS_STATIC_MAPPING_ONLY => 2,
+			 Nat44ConfigFlags::NAT44_IS_CONNECTION_TRACKING => 4,
+			 Nat44ConfigFlags::NAT44_IS_OUT2IN_DPO => 8,
+			 Nat44ConfigFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 0 => Nat44ConfigFlags::NAT44_IS_ENDPOINT_INDEPENDENT,
-			 1 => Nat44ConfigFlags::NAT44_IS_ENDPOINT_DEPENDENT,
-			 2 => Nat44ConfigFlags::NAT44_IS_STATIC_MAPPING_ONLY,
-			 4 => Nat44ConfigFlags::NAT44_IS_CONNECTION_TRACKING,
-			 8 => Nat44ConfigFlags::NAT44_IS_OUT2IN_DPO,
-			_ => panic!("Invalid Enum Descriminant")
+			 0 => Some(Nat44ConfigFlags::NAT44_IS_ENDPOINT_INDEPENDENT),
+			 1 => Some(Nat44ConfigFlags::NAT44_IS_ENDPOINT_DEPENDENT),
+			 2 => Some(Nat44ConfigFlags::NAT44_IS_STATIC_MAPPING_ONLY),
+			 4 => Some(Nat44ConfigFlags::NAT44_IS_CONNECTION_TRACKING),
+			 8 => Some(Nat44ConfigFlags::NAT44_IS_OUT2IN_DPO),
+			 value => Some(Nat44ConfigFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for Nat44ConfigFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("Nat44ConfigFlags value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for Nat44ConfigFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(Nat44ConfigFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for Nat44ConfigFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 8 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0xf
+	}
+}
+impl std::ops::BitOr for Nat44ConfigFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_plugin_enable_disable_be17f8dd)]
 pub struct Nat44EdPluginEnableDisable {
 	pub client_index: u32,
@@ -62,26 +100,26 @@ pub struct Nat44EdPluginEnableDisable {
 	pub enable: bool,
 	 pub flags: EnumFlag<Nat44ConfigFlags>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_plugin_enable_disable_reply_e8d4e804)]
 pub struct Nat44EdPluginEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_forwarding_enable_disable_b3e225d2)]
 pub struct Nat44ForwardingEnableDisable {
 	pub client_index: u32,
 	pub context: u32,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_forwarding_enable_disable_reply_e8d4e804)]
 pub struct Nat44ForwardingEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat_ipfix_enable_disable_9af4a2d2)]
 pub struct NatIpfixEnableDisable {
 	pub client_index: u32,
@@ -90,13 +128,13 @@ pub struct NatIpfixEnableDisable {
 	pub src_port: u16,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat_ipfix_enable_disable_reply_e8d4e804)]
 pub struct NatIpfixEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat_set_timeouts_d4746b16)]
 pub struct NatSetTimeouts {
 	pub client_index: u32,
@@ -106,13 +144,13 @@ pub struct NatSetTimeouts {
 	pub tcp_transitory: u32,
 	pub icmp: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat_set_timeouts_reply_e8d4e804)]
 pub struct NatSetTimeoutsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_set_session_limit_8899bbb1)]
 pub struct Nat44SetSessionLimit {
 	pub client_index: u32,
@@ -120,19 +158,19 @@ pub struct Nat44SetSessionLimit {
 	pub session_limit: u32,
 	pub vrf_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_set_session_limit_reply_e8d4e804)]
 pub struct Nat44SetSessionLimitReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_show_running_config_51077d14)]
 pub struct Nat44ShowRunningConfig {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_show_running_config_reply_93d8e267)]
 pub struct Nat44ShowRunningConfigReply {
 	pub context: u32,
@@ -150,26 +188,26 @@ pub struct Nat44ShowRunningConfigReply {
 	pub log_level: NatLogLevel,
 	 pub flags: EnumFlag<Nat44ConfigFlags>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat_set_workers_da926638)]
 pub struct NatSetWorkers {
 	pub client_index: u32,
 	pub context: u32,
 	pub worker_mask: u64,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat_set_workers_reply_e8d4e804)]
 pub struct NatSetWorkersReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat_worker_dump_51077d14)]
 pub struct NatWorkerDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat_worker_details_84bf06fc)]
 pub struct NatWorkerDetails {
 	pub context: u32,
@@ -177,7 +215,7 @@ pub struct NatWorkerDetails {
 	pub lcore_id: u32,
 	pub name: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_add_del_vrf_table_08330904)]
 pub struct Nat44EdAddDelVrfTable {
 	pub client_index: u32,
@@ -185,13 +223,13 @@ pub struct Nat44EdAddDelVrfTable {
 	pub table_vrf_id: u32,
 	pub is_add: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_add_del_vrf_table_reply_e8d4e804)]
 pub struct Nat44EdAddDelVrfTableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_add_del_vrf_route_59187407)]
 pub struct Nat44EdAddDelVrfRoute {
 	pub client_index: u32,
@@ -200,41 +238,43 @@ pub struct Nat44EdAddDelVrfRoute {
 	pub vrf_id: u32,
 	pub is_add: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_add_del_vrf_route_reply_e8d4e804)]
 pub struct Nat44EdAddDelVrfRouteReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_vrf_tables_dump_51077d14)]
 pub struct Nat44EdVrfTablesDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_vrf_tables_details_7b264e4f)]
 pub struct Nat44EdVrfTablesDetails {
 	pub context: u32,
 	pub table_vrf_id: u32,
 	pub n_vrf_ids: u32,
+	#[vpp(count = "n_vrf_ids")]
 	pub vrf_ids: VariableSizeArray<u32>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_vrf_tables_v2_dump_51077d14)]
 pub struct Nat44EdVrfTablesV2Dump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_vrf_tables_v2_details_7b264e4f)]
 pub struct Nat44EdVrfTablesV2Details {
 	pub context: u32,
 	pub table_vrf_id: u32,
 	pub n_vrf_ids: u32,
+	#[vpp(count = "n_vrf_ids")]
 	pub vrf_ids: VariableSizeArray<u32>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat_set_mss_clamping_25e90abb)]
 pub struct NatSetMssClamping {
 	pub client_index: u32,
@@ -242,19 +282,19 @@ pub struct NatSetMssClamping {
 	pub mss_value: u16,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat_set_mss_clamping_reply_e8d4e804)]
 pub struct NatSetMssClampingReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat_get_mss_clamping_51077d14)]
 pub struct NatGetMssClamping {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat_get_mss_clamping_reply_1c0b2a78)]
 pub struct NatGetMssClampingReply {
 	pub context: u32,
@@ -262,33 +302,33 @@ pub struct NatGetMssClampingReply {
 	pub mss_value: u16,
 	pub enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_set_fq_options_2399bd71)]
 pub struct Nat44EdSetFqOptions {
 	pub client_index: u32,
 	pub context: u32,
 	pub frame_queue_nelts: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_set_fq_options_reply_e8d4e804)]
 pub struct Nat44EdSetFqOptionsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_show_fq_options_51077d14)]
 pub struct Nat44EdShowFqOptions {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_show_fq_options_reply_7213b545)]
 pub struct Nat44EdShowFqOptionsReply {
 	pub context: u32,
 	pub retval: i32,
 	pub frame_queue_nelts: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_add_del_interface_addr_4aed50c0)]
 pub struct Nat44AddDelInterfaceAddr {
 	pub client_index: u32,
@@ -297,26 +337,26 @@ pub struct Nat44AddDelInterfaceAddr {
 	pub sw_if_index: InterfaceIndex,
 	 pub flags: EnumFlag<NatConfigFlags>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_add_del_interface_addr_reply_e8d4e804)]
 pub struct Nat44AddDelInterfaceAddrReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_interface_addr_dump_51077d14)]
 pub struct Nat44InterfaceAddrDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_interface_addr_details_e4aca9ca)]
 pub struct Nat44InterfaceAddrDetails {
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 	 pub flags: EnumFlag<NatConfigFlags>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_add_del_address_range_6f2b8055)]
 pub struct Nat44AddDelAddressRange {
 	pub client_index: u32,
@@ -327,19 +367,19 @@ pub struct Nat44AddDelAddressRange {
 	pub is_add: bool,
 	 pub flags: EnumFlag<NatConfigFlags>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_add_del_address_range_reply_e8d4e804)]
 pub struct Nat44AddDelAddressRangeReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_address_dump_51077d14)]
 pub struct Nat44AddressDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_address_details_0d1beac1)]
 pub struct Nat44AddressDetails {
 	pub context: u32,
@@ -347,7 +387,7 @@ pub struct Nat44AddressDetails {
 	 pub flags: EnumFlag<NatConfigFlags>,
 	pub vrf_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_interface_add_del_feature_f3699b83)]
 pub struct Nat44InterfaceAddDelFeature {
 	pub client_index: u32,
@@ -356,26 +396,26 @@ pub struct Nat44InterfaceAddDelFeature {
 	 pub flags: EnumFlag<NatConfigFlags>,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_interface_add_del_feature_reply_e8d4e804)]
 pub struct Nat44InterfaceAddDelFeatureReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_interface_dump_51077d14)]
 pub struct Nat44InterfaceDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_interface_details_5d286289)]
 pub struct Nat44InterfaceDetails {
 	pub context: u32,
 	 pub flags: EnumFlag<NatConfigFlags>,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_add_del_output_interface_47d6e753)]
 pub struct Nat44EdAddDelOutputInterface {
 	pub client_index: u32,
@@ -383,33 +423,33 @@ pub struct Nat44EdAddDelOutputInterface {
 	pub is_add: bool,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_add_del_output_interface_reply_e8d4e804)]
 pub struct Nat44EdAddDelOutputInterfaceReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_output_interface_get_f75ba505)]
 pub struct Nat44EdOutputInterfaceGet {
 	pub client_index: u32,
 	pub context: u32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_output_interface_get_reply_53b48f5d)]
 pub struct Nat44EdOutputInterfaceGetReply {
 	pub context: u32,
 	pub retval: i32,
 	pub cursor: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_ed_output_interface_details_0b45011c)]
 pub struct Nat44EdOutputInterfaceDetails {
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_add_del_static_mapping_5ae5f03e)]
 pub struct Nat44AddDelStaticMapping {
 	pub client_index: u32,
@@ -425,13 +465,13 @@ pub struct Nat44AddDelStaticMapping {
 	pub vrf_id: u32,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_add_del_static_mapping_reply_e8d4e804)]
 pub struct Nat44AddDelStaticMappingReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_add_del_static_mapping_v2_5e205f1a)]
 pub struct Nat44AddDelStaticMappingV2 {
 	pub client_index: u32,
@@ -449,19 +489,19 @@ pub struct Nat44AddDelStaticMappingV2 {
 	pub vrf_id: u32,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_add_del_static_mapping_v2_reply_e8d4e804)]
 pub struct Nat44AddDelStaticMappingV2Reply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_static_mapping_dump_51077d14)]
 pub struct Nat44StaticMappingDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_static_mapping_details_06cb40b2)]
 pub struct Nat44StaticMappingDetails {
 	pub context: u32,
@@ -475,7 +515,7 @@ pub struct Nat44StaticMappingDetails {
 	pub vrf_id: u32,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_add_del_identity_mapping_02faaa22)]
 pub struct Nat44AddDelIdentityMapping {
 	pub client_index: u32,
@@ -489,19 +529,19 @@ pub struct Nat44AddDelIdentityMapping {
 	pub vrf_id: u32,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_add_del_identity_mapping_reply_e8d4e804)]
 pub struct Nat44AddDelIdentityMappingReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_identity_mapping_dump_51077d14)]
 pub struct Nat44IdentityMappingDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_identity_mapping_details_2a52a030)]
 pub struct Nat44IdentityMappingDetails {
 	pub context: u32,
@@ -513,7 +553,7 @@ pub struct Nat44IdentityMappingDetails {
 	pub vrf_id: u32,
 	pub tag: FixedSizeString<typenum::U64>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_add_del_lb_static_mapping_4f68ee9d)]
 pub struct Nat44AddDelLbStaticMapping {
 	pub client_index: u32,
@@ -526,15 +566,16 @@ pub struct Nat44AddDelLbStaticMapping {
 	pub affinity: u32,
 	pub tag: FixedSizeString<typenum::U64>,
 	pub local_num: u32,
+	#[vpp(count = "local_num")]
 	pub locals: VariableSizeArray<Nat44LbAddrPort>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_add_del_lb_static_mapping_reply_e8d4e804)]
 pub struct Nat44AddDelLbStaticMappingReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_lb_static_mapping_add_del_local_7ca47547)]
 pub struct Nat44LbStaticMappingAddDelLocal {
 	pub client_index: u32,
@@ -545,19 +586,19 @@ pub struct Nat44LbStaticMappingAddDelLocal {
 	pub protocol: u8,
 	pub local: Nat44LbAddrPort,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_lb_static_mapping_add_del_local_reply_e8d4e804)]
 pub struct Nat44LbStaticMappingAddDelLocalReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_lb_static_mapping_dump_51077d14)]
 pub struct Nat44LbStaticMappingDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_lb_static_mapping_details_ed5ce876)]
 pub struct Nat44LbStaticMappingDetails {
 	pub context: u32,
@@ -568,9 +609,10 @@ pub struct Nat44LbStaticMappingDetails {
 	pub affinity: u32,
 	pub tag: FixedSizeString<typenum::U64>,
 	pub local_num: u32,
+	#[vpp(count = "local_num")]
 	pub locals: VariableSizeArray<Nat44LbAddrPort>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_del_session_15a5bf8c)]
 pub struct Nat44DelSession {
 	pub client_index: u32,
@@ -583,19 +625,19 @@ pub struct Nat44DelSession {
 	pub ext_host_address: Ip4Address,
 	pub ext_host_port: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_del_session_reply_e8d4e804)]
 pub struct Nat44DelSessionReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_user_dump_51077d14)]
 pub struct Nat44UserDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_user_details_355896c2)]
 pub struct Nat44UserDetails {
 	pub context: u32,
@@ -604,7 +646,7 @@ pub struct Nat44UserDetails {
 	pub nsessions: u32,
 	pub nstaticsessions: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_user_session_dump_e1899c98)]
 pub struct Nat44UserSessionDump {
 	pub client_index: u32,
@@ -612,7 +654,7 @@ pub struct Nat44UserSessionDump {
 	pub ip_address: Ip4Address,
 	pub vrf_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_user_session_details_2cf6e16d)]
 pub struct Nat44UserSessionDetails {
 	pub context: u32,
@@ -630,7 +672,7 @@ pub struct Nat44UserSessionDetails {
 	pub ext_host_nat_address: Ip4Address,
 	pub ext_host_nat_port: u16,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_user_session_v2_dump_e1899c98)]
 pub struct Nat44UserSessionV2Dump {
 	pub client_index: u32,
@@ -638,7 +680,7 @@ pub struct Nat44UserSessionV2Dump {
 	pub ip_address: Ip4Address,
 	pub vrf_id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_user_session_v2_details_fd42b729)]
 pub struct Nat44UserSessionV2Details {
 	pub context: u32,
@@ -657,7 +699,7 @@ pub struct Nat44UserSessionV2Details {
 	pub ext_host_nat_port: u16,
 	pub is_timed_out: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_user_session_v3_details_edae926e)]
 pub struct Nat44UserSessionV3Details {
 	pub context: u32,
@@ -677,7 +719,7 @@ pub struct Nat44UserSessionV3Details {
 	pub ext_host_nat_port: u16,
 	pub is_timed_out: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(nat44_user_session_v3_dump_e1899c98)]
 pub struct Nat44UserSessionV3Dump {
 	pub client_index: u32,
@@ -685,3 +727,81 @@ pub struct Nat44UserSessionV3Dump {
 	pub ip_address: Ip4Address,
 	pub vrf_id: u32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"nat44_ed_plugin_enable_disable_be17f8dd",
+	"nat44_ed_plugin_enable_disable_reply_e8d4e804",
+	"nat44_forwarding_enable_disable_b3e225d2",
+	"nat44_forwarding_enable_disable_reply_e8d4e804",
+	"nat_ipfix_enable_disable_9af4a2d2",
+	"nat_ipfix_enable_disable_reply_e8d4e804",
+	"nat_set_timeouts_d4746b16",
+	"nat_set_timeouts_reply_e8d4e804",
+	"nat44_set_session_limit_8899bbb1",
+	"nat44_set_session_limit_reply_e8d4e804",
+	"nat44_show_running_config_51077d14",
+	"nat44_show_running_config_reply_93d8e267",
+	"nat_set_workers_da926638",
+	"nat_set_workers_reply_e8d4e804",
+	"nat_worker_dump_51077d14",
+	"nat_worker_details_84bf06fc",
+	"nat44_ed_add_del_vrf_table_08330904",
+	"nat44_ed_add_del_vrf_table_reply_e8d4e804",
+	"nat44_ed_add_del_vrf_route_59187407",
+	"nat44_ed_add_del_vrf_route_reply_e8d4e804",
+	"nat44_ed_vrf_tables_dump_51077d14",
+	"nat44_ed_vrf_tables_details_7b264e4f",
+	"nat44_ed_vrf_tables_v2_dump_51077d14",
+	"nat44_ed_vrf_tables_v2_details_7b264e4f",
+	"nat_set_mss_clamping_25e90abb",
+	"nat_set_mss_clamping_reply_e8d4e804",
+	"nat_get_mss_clamping_51077d14",
+	"nat_get_mss_clamping_reply_1c0b2a78",
+	"nat44_ed_set_fq_options_2399bd71",
+	"nat44_ed_set_fq_options_reply_e8d4e804",
+	"nat44_ed_show_fq_options_51077d14",
+	"nat44_ed_show_fq_options_reply_7213b545",
+	"nat44_add_del_interface_addr_4aed50c0",
+	"nat44_add_del_interface_addr_reply_e8d4e804",
+	"nat44_interface_addr_dump_51077d14",
+	"nat44_interface_addr_details_e4aca9ca",
+	"nat44_add_del_address_range_6f2b8055",
+	"nat44_add_del_address_range_reply_e8d4e804",
+	"nat44_address_dump_51077d14",
+	"nat44_address_details_0d1beac1",
+	"nat44_interface_add_del_feature_f3699b83",
+	"nat44_interface_add_del_feature_reply_e8d4e804",
+	"nat44_interface_dump_51077d14",
+	"nat44_interface_details_5d286289",
+	"nat44_ed_add_del_output_interface_47d6e753",
+	"nat44_ed_add_del_output_interface_reply_e8d4e804",
+	"nat44_ed_output_interface_get_f75ba505",
+	"nat44_ed_output_interface_get_reply_53b48f5d",
+	"nat44_ed_output_interface_details_0b45011c",
+	"nat44_add_del_static_mapping_5ae5f03e",
+	"nat44_add_del_static_mapping_reply_e8d4e804",
+	"nat44_add_del_static_mapping_v2_5e205f1a",
+	"nat44_add_del_static_mapping_v2_reply_e8d4e804",
+	"nat44_static_mapping_dump_51077d14",
+	"nat44_static_mapping_details_06cb40b2",
+	"nat44_add_del_identity_mapping_02faaa22",
+	"nat44_add_del_identity_mapping_reply_e8d4e804",
+	"nat44_identity_mapping_dump_51077d14",
+	"nat44_identity_mapping_details_2a52a030",
+	"nat44_add_del_lb_static_mapping_4f68ee9d",
+	"nat44_add_del_lb_static_mapping_reply_e8d4e804",
+	"nat44_lb_static_mapping_add_del_local_7ca47547",
+	"nat44_lb_static_mapping_add_del_local_reply_e8d4e804",
+	"nat44_lb_static_mapping_dump_51077d14",
+	"nat44_lb_static_mapping_details_ed5ce876",
+	"nat44_del_session_15a5bf8c",
+	"nat44_del_session_reply_e8d4e804",
+	"nat44_user_dump_51077d14",
+	"nat44_user_details_355896c2",
+	"nat44_user_session_dump_e1899c98",
+	"nat44_user_session_details_2cf6e16d",
+	"nat44_user_session_v2_dump_e1899c98",
+	"nat44_user_session_v2_details_fd42b729",
+	"nat44_user_session_v3_details_edae926e",
+	"nat44_user_session_v3_dump_e1899c98",
+];
@@ -2,53 +2,121 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum PgInterfaceMode {
 	 PG_API_MODE_ETHERNET=0,
 	 PG_API_MODE_IP4=1,
 	 PG_API_MODE_IP6=2,
+	 Unknown(u32),
 }
 impl Default for PgInterfaceMode {
 	fn default() -> Self { PgInterfaceMode::PG_API_MODE_ETHERNET }
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+impl AsU32 for PgInterfaceMode {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 PgInterfaceMode::PG_API_MODE_ETHERNET => 0,
+			 PgInterfaceMode::PG_API_MODE_IP4 => 1,
+			 PgInterfaceMode::PG_API_MODE_IP6 => 2,
+			 PgInterfaceMode::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(PgInterfaceMode::PG_API_MODE_ETHERNET),
+			 1 => Some(PgInterfaceMode::PG_API_MODE_IP4),
+			 2 => Some(PgInterfaceMode::PG_API_MODE_IP6),
+			 value => Some(PgInterfaceMode::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for PgInterfaceMode {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("PgInterfaceMode value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for PgInterfaceMode {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(PgInterfaceMode::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum PgInterfaceFlags {
 	 PG_API_FLAG_NONE=0,
 	 PG_API_FLAG_CSUM_OFFLOAD=1,
 	 PG_API_FLAG_GSO=2,
 	 PG_API_FLAG_GRO_COALESCE=4,
+	 Unknown(u32),
 }
 impl Default for PgInterfaceFlags {
 	fn default() -> Self { PgInterfaceFlags::PG_API_FLAG_NONE }
 }
-impl AsEnumFlag for PgInterfaceFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for PgInterfaceFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 PgInterfaceFlags::PG_API_FLAG_NONE => 0,
+			 PgInterfaceFlags::PG_API_FLAG_CSUM_OFFLOAD => 1,
+			 PgInterfaceFlags::PG_API_FLAG_GSO => 2,
+			 PgInterfaceFlags::PG_API_FLAG_GRO_COALESCE => 4,
+			 PgInterfaceFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 0 => PgInterfaceFlags::PG_API_FLAG_NONE,
-			 1 => PgInterfaceFlags::PG_API_FLAG_CSUM_OFFLOAD,
-			 2 => PgInterfaceFlags::PG_API_FLAG_GSO,
-			 4 => PgInterfaceFlags::PG_API_FLAG_GRO_COALESCE,
-			_ => panic!("Invalid Enum Descriminant")
+			 0 => Some(PgInterfaceFlags::PG_API_FLAG_NONE),
+			 1 => Some(PgInterfaceFlags::PG_API_FLAG_CSUM_OFFLOAD),
+			 2 => Some(PgInterfaceFlags::PG_API_FLAG_GSO),
+			 4 => Some(PgInterfaceFlags::PG_API_FLAG_GRO_COALESCE),
+			 value => Some(PgInterfaceFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for PgInterfaceFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for PgInterfaceFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(PgInterfaceFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for PgInterfaceFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x7
+	}
+}
+impl std::ops::BitOr for PgInterfaceFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_create_interface_b7c893d7)]
 pub struct PgCreateInterface {
 	pub client_index: u32,
@@ -57,7 +125,7 @@ pub struct PgCreateInterface {
 	pub gso_enabled: bool,
 	pub gso_size: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_create_interface_v2_8657466a)]
 pub struct PgCreateInterfaceV2 {
 	pub client_index: u32,
@@ -67,7 +135,7 @@ pub struct PgCreateInterfaceV2 {
 	pub gso_size: u32,
 	pub mode: PgInterfaceMode,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_create_interface_v3_b2aac653)]
 pub struct PgCreateInterfaceV3 {
 	pub client_index: u32,
@@ -77,41 +145,41 @@ pub struct PgCreateInterfaceV3 {
 	pub gso_size: u32,
 	pub mode: PgInterfaceMode,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_create_interface_reply_5383d31f)]
 pub struct PgCreateInterfaceReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_create_interface_v2_reply_5383d31f)]
 pub struct PgCreateInterfaceV2Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_create_interface_v3_reply_5383d31f)]
 pub struct PgCreateInterfaceV3Reply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_delete_interface_f9e6675e)]
 pub struct PgDeleteInterface {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_delete_interface_reply_e8d4e804)]
 pub struct PgDeleteInterfaceReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_interface_enable_disable_coalesce_a2ef99e7)]
 pub struct PgInterfaceEnableDisableCoalesce {
 	pub client_index: u32,
@@ -119,13 +187,13 @@ pub struct PgInterfaceEnableDisableCoalesce {
 	pub sw_if_index: InterfaceIndex,
 	pub coalesce_enabled: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_interface_enable_disable_coalesce_reply_e8d4e804)]
 pub struct PgInterfaceEnableDisableCoalesceReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_capture_3712fb6c)]
 pub struct PgCapture {
 	pub client_index: u32,
@@ -135,13 +203,13 @@ pub struct PgCapture {
 	pub count: u32,
 	pub pcap_file_name: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_capture_reply_e8d4e804)]
 pub struct PgCaptureReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_enable_disable_01f94f3a)]
 pub struct PgEnableDisable {
 	pub client_index: u32,
@@ -149,9 +217,26 @@ pub struct PgEnableDisable {
 	pub is_enabled: bool,
 	pub stream_name: VariableSizeString,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(pg_enable_disable_reply_e8d4e804)]
 pub struct PgEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"pg_create_interface_b7c893d7",
+	"pg_create_interface_v2_8657466a",
+	"pg_create_interface_v3_b2aac653",
+	"pg_create_interface_reply_5383d31f",
+	"pg_create_interface_v2_reply_5383d31f",
+	"pg_create_interface_v3_reply_5383d31f",
+	"pg_delete_interface_f9e6675e",
+	"pg_delete_interface_reply_e8d4e804",
+	"pg_interface_enable_disable_coalesce_a2ef99e7",
+	"pg_interface_enable_disable_coalesce_reply_e8d4e804",
+	"pg_capture_3712fb6c",
+	"pg_capture_reply_e8d4e804",
+	"pg_enable_disable_01f94f3a",
+	"pg_enable_disable_reply_e8d4e804",
+];
@@ -2,59 +2,92 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 // Implementation for qos_store
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct QosStore {
 	pub sw_if_index: InterfaceIndex,
 	pub input_source: QosSource,
 	pub value: u8,
 }
 // Implementation for qos_record
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct QosRecord {
 	pub sw_if_index: InterfaceIndex,
 	pub input_source: QosSource,
 }
 // Implementation for qos_egress_map_row
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct QosEgressMapRow {
 	pub outputs: FixedSizeArray<u8, typenum::U256>,
 }
 // Implementation for qos_egress_map
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct QosEgressMap {
 	pub id: u32,
 	pub rows: FixedSizeArray<QosEgressMapRow, typenum::U4>,
 }
 // Implementation for qos_mark
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct QosMark {
 	pub sw_if_index: u32,
 	pub map_id: u32,
 	pub output_source: QosSource,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u8)]
 pub enum QosSource {
 	 QOS_API_SOURCE_EXT=0,
 	 QOS_API_SOURCE_VLAN=1,
 	 QOS_API_SOURCE_MPLS=2,
 	 QOS_API_SOURCE_IP=3,
+	 Unknown(u32),
 }
 impl Default for QosSource {
 	fn default() -> Self { QosSource::QOS_API_SOURCE_EXT }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for QosSource {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 QosSource::QOS_API_SOURCE_EXT => 0,
+			 QosSource::QOS_API_SOURCE_VLAN => 1,
+			 QosSource::QOS_API_SOURCE_MPLS => 2,
+			 QosSource::QOS_API_SOURCE_IP => 3,
+			 QosSource::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(QosSource::QOS_API_SOURCE_EXT),
+			 1 => Some(QosSource::QOS_API_SOURCE_VLAN),
+			 2 => Some(QosSource::QOS_API_SOURCE_MPLS),
+			 3 => Some(QosSource::QOS_API_SOURCE_IP),
+			 value => Some(QosSource::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for QosSource {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("QosSource value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for QosSource {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(QosSource::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_store_enable_disable_f3abcc8b)]
 pub struct QosStoreEnableDisable {
 	pub client_index: u32,
@@ -62,25 +95,25 @@ pub struct QosStoreEnableDisable {
 	pub enable: bool,
 	pub store: QosStore,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_store_enable_disable_reply_e8d4e804)]
 pub struct QosStoreEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_store_dump_51077d14)]
 pub struct QosStoreDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_store_details_3ee0aad7)]
 pub struct QosStoreDetails {
 	pub context: u32,
 	pub store: QosStore,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_record_enable_disable_2f1a4a38)]
 pub struct QosRecordEnableDisable {
 	pub client_index: u32,
@@ -88,63 +121,63 @@ pub struct QosRecordEnableDisable {
 	pub enable: bool,
 	pub record: QosRecord,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_record_enable_disable_reply_e8d4e804)]
 pub struct QosRecordEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_record_dump_51077d14)]
 pub struct QosRecordDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_record_details_a425d4d3)]
 pub struct QosRecordDetails {
 	pub context: u32,
 	pub record: QosRecord,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_egress_map_update_6d1c065f)]
 pub struct QosEgressMapUpdate {
 	pub client_index: u32,
 	pub context: u32,
 	pub map: QosEgressMap,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_egress_map_update_reply_e8d4e804)]
 pub struct QosEgressMapUpdateReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_egress_map_delete_3a91bde5)]
 pub struct QosEgressMapDelete {
 	pub client_index: u32,
 	pub context: u32,
 	pub id: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_egress_map_delete_reply_e8d4e804)]
 pub struct QosEgressMapDeleteReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_egress_map_dump_51077d14)]
 pub struct QosEgressMapDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_egress_map_details_46c5653c)]
 pub struct QosEgressMapDetails {
 	pub context: u32,
 	pub map: QosEgressMap,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_mark_enable_disable_1a010f74)]
 pub struct QosMarkEnableDisable {
 	pub client_index: u32,
@@ -152,28 +185,50 @@ pub struct QosMarkEnableDisable {
 	pub enable: bool,
 	pub mark: QosMark,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_mark_enable_disable_reply_e8d4e804)]
 pub struct QosMarkEnableDisableReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_mark_dump_f9e6675e)]
 pub struct QosMarkDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_mark_details_89fe81a9)]
 pub struct QosMarkDetails {
 	pub context: u32,
 	pub mark: QosMark,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(qos_mark_details_reply_e8d4e804)]
 pub struct QosMarkDetailsReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"qos_store_enable_disable_f3abcc8b",
+	"qos_store_enable_disable_reply_e8d4e804",
+	"qos_store_dump_51077d14",
+	"qos_store_details_3ee0aad7",
+	"qos_record_enable_disable_2f1a4a38",
+	"qos_record_enable_disable_reply_e8d4e804",
+	"qos_record_dump_51077d14",
+	"qos_record_details_a425d4d3",
+	"qos_egress_map_update_6d1c065f",
+	"qos_egress_map_update_reply_e8d4e804",
+	"qos_egress_map_delete_3a91bde5",
+	"qos_egress_map_delete_reply_e8d4e804",
+	"qos_egress_map_dump_51077d14",
+	"qos_egress_map_details_46c5653c",
+	"qos_mark_enable_disable_1a010f74",
+	"qos_mark_enable_disable_reply_e8d4e804",
+	"qos_mark_dump_f9e6675e",
+	"qos_mark_details_89fe81a9",
+	"qos_mark_details_reply_e8d4e804",
+];
@@ -2,22 +2,21 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sr_pt_iface_dump_51077d14)]
 pub struct SrPtIfaceDump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sr_pt_iface_details_1f472f85)]
 pub struct SrPtIfaceDetails {
 	pub context: u32,
@@ -27,7 +26,7 @@ pub struct SrPtIfaceDetails {
 	pub egress_load: u8,
 	pub tts_template: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sr_pt_iface_add_852c0cda)]
 pub struct SrPtIfaceAdd {
 	pub client_index: u32,
@@ -38,22 +37,31 @@ pub struct SrPtIfaceAdd {
 	pub egress_load: u8,
 	pub tts_template: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sr_pt_iface_add_reply_e8d4e804)]
 pub struct SrPtIfaceAddReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sr_pt_iface_del_f9e6675e)]
 pub struct SrPtIfaceDel {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sr_pt_iface_del_reply_e8d4e804)]
 pub struct SrPtIfaceDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"sr_pt_iface_dump_51077d14",
+	"sr_pt_iface_details_1f472f85",
+	"sr_pt_iface_add_852c0cda",
+	"sr_pt_iface_add_reply_e8d4e804",
+	"sr_pt_iface_del_f9e6675e",
+	"sr_pt_iface_del_reply_e8d4e804",
+];
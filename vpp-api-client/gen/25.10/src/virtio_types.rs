@@ -2,15 +2,15 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum VirtioNetFeaturesFirst32 {
 	 VIRTIO_NET_F_API_CSUM=1,
 	 VIRTIO_NET_F_API_GUEST_CSUM=2,
@@ -28,44 +28,122 @@ pub enum VirtioNetFeaturesFirst32 {
 	 VIRTIO_F_API_ANY_LAYOUT=134217728,
 	 VIRTIO_F_API_INDIRECT_DESC=268435456,
 	 VHOST_USER_F_API_PROTOCOL_FEATURES=1073741824,
+	 Unknown(u32),
 }
 impl Default for VirtioNetFeaturesFirst32 {
 	fn default() -> Self { VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_CSUM }
 }
-impl AsEnumFlag for VirtioNetFeaturesFirst32 {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for VirtioNetFeaturesFirst32 {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_CSUM => 1,
+			 VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_CSUM => 2,
+			 VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_TSO4 => 128,
+			 VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_TSO6 => 256,
+			 VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_UFO => 1024,
+			 VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_HOST_TSO4 => 2048,
+			 VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_HOST_TSO6 => 4096,
+			 VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_HOST_UFO => 16384,
+			 VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_MRG_RXBUF => 32768,
+			 VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_CTRL_VQ => 131072,
+			 VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_ANNOUNCE => 2097152,
+			 VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_MQ => 4194304,
+			 VirtioNetFeaturesFirst32::VHOST_F_API_LOG_ALL => 67108864,
+			 VirtioNetFeaturesFirst32::VIRTIO_F_API_ANY_LAYOUT => 134217728,
+			 VirtioNetFeaturesFirst32::VIRTIO_F_API_INDIRECT_DESC => 268435456,
+			 VirtioNetFeaturesFirst32::VHOST_USER_F_API_PROTOCOL_FEATURES => 1073741824,
+			 VirtioNetFeaturesFirst32::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_CSUM,
-			 2 => VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_CSUM,
-			 128 => VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_TSO4,
-			 256 => VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_TSO6,
-			 1024 => VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_UFO,
-			 2048 => VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_HOST_TSO4,
-			 4096 => VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_HOST_TSO6,
-			 16384 => VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_HOST_UFO,
-			 32768 => VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_MRG_RXBUF,
-			 131072 => VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_CTRL_VQ,
-			 2097152 => VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_ANNOUNCE,
-			 4194304 => VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_MQ,
-			 67108864 => VirtioNetFeaturesFirst32::VHOST_F_API_LOG_ALL,
-			 134217728 => VirtioNetFeaturesFirst32::VIRTIO_F_API_ANY_LAYOUT,
-			 268435456 => VirtioNetFeaturesFirst32::VIRTIO_F_API_INDIRECT_DESC,
-			 1073741824 => VirtioNetFeaturesFirst32::VHOST_USER_F_API_PROTOCOL_FEATURES,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_CSUM),
+			 2 => Some(VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_CSUM),
+			 128 => Some(VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_TSO4),
+			 256 => Some(VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_TSO6),
+			 1024 => Some(VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_UFO),
+			 2048 => Some(VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_HOST_TSO4),
+			 4096 => Some(VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_HOST_TSO6),
+			 16384 => Some(VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_HOST_UFO),
+			 32768 => Some(VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_MRG_RXBUF),
+			 131072 => Some(VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_CTRL_VQ),
+			 2097152 => Some(VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_GUEST_ANNOUNCE),
+			 4194304 => Some(VirtioNetFeaturesFirst32::VIRTIO_NET_F_API_MQ),
+			 67108864 => Some(VirtioNetFeaturesFirst32::VHOST_F_API_LOG_ALL),
+			 134217728 => Some(VirtioNetFeaturesFirst32::VIRTIO_F_API_ANY_LAYOUT),
+			 268435456 => Some(VirtioNetFeaturesFirst32::VIRTIO_F_API_INDIRECT_DESC),
+			 1073741824 => Some(VirtioNetFeaturesFirst32::VHOST_USER_F_API_PROTOCOL_FEATURES),
+			 value => Some(VirtioNetFeaturesFirst32::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for VirtioNetFeaturesFirst32 {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for VirtioNetFeaturesFirst32 {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(VirtioNetFeaturesFirst32::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for VirtioNetFeaturesFirst32 {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x5c62dd83
+	}
+}
+impl std::ops::BitOr for VirtioNetFeaturesFirst32 {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum VirtioNetFeaturesLast32 {
 	 VIRTIO_F_API_VERSION_1=1,
+	 Unknown(u32),
 }
 impl Default for VirtioNetFeaturesLast32 {
 	fn default() -> Self { VirtioNetFeaturesLast32::VIRTIO_F_API_VERSION_1 }
 }
+impl AsU32 for VirtioNetFeaturesLast32 {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 VirtioNetFeaturesLast32::VIRTIO_F_API_VERSION_1 => 1,
+			 VirtioNetFeaturesLast32::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 1 => Some(VirtioNetFeaturesLast32::VIRTIO_F_API_VERSION_1),
+			 value => Some(VirtioNetFeaturesLast32::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for VirtioNetFeaturesLast32 {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for VirtioNetFeaturesLast32 {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(VirtioNetFeaturesLast32::Unknown(value)))
+	 }
+}
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
@@ -2,18 +2,17 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
 // Implementation for vmxnet3_tx_list
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Vmxnet3TxList {
 	pub tx_qsize: u16,
 	pub tx_next: u16,
@@ -21,7 +20,7 @@ pub struct Vmxnet3TxList {
 	pub tx_consume: u16,
 }
 // Implementation for vmxnet3_rx_list
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Vmxnet3RxList {
 	pub rx_qsize: u16,
 	pub rx_fill: FixedSizeArray<u16, typenum::U2>,
@@ -29,7 +28,7 @@ pub struct Vmxnet3RxList {
 	pub rx_produce: FixedSizeArray<u16, typenum::U2>,
 	pub rx_consume: FixedSizeArray<u16, typenum::U2>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vmxnet3_create_71a07314)]
 pub struct Vmxnet3Create {
 	pub client_index: u32,
@@ -43,27 +42,27 @@ pub struct Vmxnet3Create {
 	pub bind: u8,
 	pub enable_gso: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vmxnet3_create_reply_5383d31f)]
 pub struct Vmxnet3CreateReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vmxnet3_delete_f9e6675e)]
 pub struct Vmxnet3Delete {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vmxnet3_delete_reply_e8d4e804)]
 pub struct Vmxnet3DeleteReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vmxnet3_details_6a1a5498)]
 pub struct Vmxnet3Details {
 	pub context: u32,
@@ -78,20 +77,20 @@ pub struct Vmxnet3Details {
 	pub tx_count: u8,
 	pub tx_list: FixedSizeArray<Vmxnet3TxList, typenum::U8>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vmxnet3_dump_51077d14)]
 pub struct Vmxnet3Dump {
 	pub client_index: u32,
 	pub context: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_vmxnet3_interface_dump_f9e6675e)]
 pub struct SwVmxnet3InterfaceDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(sw_vmxnet3_interface_details_6a1a5498)]
 pub struct SwVmxnet3InterfaceDetails {
 	pub context: u32,
@@ -106,3 +105,14 @@ pub struct SwVmxnet3InterfaceDetails {
 	pub tx_count: u8,
 	pub tx_list: FixedSizeArray<Vmxnet3TxList, typenum::U8>,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"vmxnet3_create_71a07314",
+	"vmxnet3_create_reply_5383d31f",
+	"vmxnet3_delete_f9e6675e",
+	"vmxnet3_delete_reply_e8d4e804",
+	"vmxnet3_details_6a1a5498",
+	"vmxnet3_dump_51077d14",
+	"sw_vmxnet3_interface_dump_f9e6675e",
+	"sw_vmxnet3_interface_details_6a1a5498",
+];
@@ -2,16 +2,15 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 // Implementation for version
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct Version {
 	pub major: u32,
 	pub minor: u32,
@@ -19,7 +18,7 @@ pub struct Version {
 	pub pre_release: FixedSizeArray<u8, typenum::U17>,
 	pub build_metadata: FixedSizeArray<u8, typenum::U17>,
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum LogLevel {
 	 VPE_API_LOG_LEVEL_EMERG=0,
@@ -31,9 +30,55 @@ pub enum LogLevel {
 	 VPE_API_LOG_LEVEL_INFO=6,
 	 VPE_API_LOG_LEVEL_DEBUG=7,
 	 VPE_API_LOG_LEVEL_DISABLED=8,
+	 Unknown(u32),
 }
 impl Default for LogLevel {
 	fn default() -> Self { LogLevel::VPE_API_LOG_LEVEL_EMERG }
 }
+impl AsU32 for LogLevel {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 LogLevel::VPE_API_LOG_LEVEL_EMERG => 0,
+			 LogLevel::VPE_API_LOG_LEVEL_ALERT => 1,
+			 LogLevel::VPE_API_LOG_LEVEL_CRIT => 2,
+			 LogLevel::VPE_API_LOG_LEVEL_ERR => 3,
+			 LogLevel::VPE_API_LOG_LEVEL_WARNING => 4,
+			 LogLevel::VPE_API_LOG_LEVEL_NOTICE => 5,
+			 LogLevel::VPE_API_LOG_LEVEL_INFO => 6,
+			 LogLevel::VPE_API_LOG_LEVEL_DEBUG => 7,
+			 LogLevel::VPE_API_LOG_LEVEL_DISABLED => 8,
+			 LogLevel::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(LogLevel::VPE_API_LOG_LEVEL_EMERG),
+			 1 => Some(LogLevel::VPE_API_LOG_LEVEL_ALERT),
+			 2 => Some(LogLevel::VPE_API_LOG_LEVEL_CRIT),
+			 3 => Some(LogLevel::VPE_API_LOG_LEVEL_ERR),
+			 4 => Some(LogLevel::VPE_API_LOG_LEVEL_WARNING),
+			 5 => Some(LogLevel::VPE_API_LOG_LEVEL_NOTICE),
+			 6 => Some(LogLevel::VPE_API_LOG_LEVEL_INFO),
+			 7 => Some(LogLevel::VPE_API_LOG_LEVEL_DEBUG),
+			 8 => Some(LogLevel::VPE_API_LOG_LEVEL_DISABLED),
+			 value => Some(LogLevel::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for LogLevel {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for LogLevel {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(LogLevel::Unknown(value)))
+	 }
+}
 pub type Timestamp=f64;
 pub type Timedelta=f64;
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+];
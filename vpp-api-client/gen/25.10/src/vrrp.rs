@@ -2,26 +2,25 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 use crate::ethernet_types::*;
 // Implementation for vrrp_vr_key
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct VrrpVrKey {
 	pub sw_if_index: InterfaceIndex,
 	pub vr_id: u8,
 	pub is_ipv6: u8,
 }
 // Implementation for vrrp_vr_conf
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct VrrpVrConf {
 	pub sw_if_index: InterfaceIndex,
 	pub vr_id: u8,
@@ -30,13 +29,13 @@ pub struct VrrpVrConf {
 	pub flags: EnumFlag<VrrpVrFlags>,
 }
 // Implementation for vrrp_vr_tracking
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct VrrpVrTracking {
 	pub interfaces_dec: u32,
 	pub priority: u8,
 }
 // Implementation for vrrp_vr_runtime
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct VrrpVrRuntime {
 	pub state: VrrpVrState,
 	pub master_adv_int: u16,
@@ -46,50 +45,120 @@ pub struct VrrpVrRuntime {
 	pub tracking: VrrpVrTracking,
 }
 // Implementation for vrrp_vr_track_if
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct VrrpVrTrackIf {
 	pub sw_if_index: InterfaceIndex,
 	pub priority: u8,
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u32)]
 pub enum VrrpVrFlags {
 	 VRRP_API_VR_PREEMPT=1,
 	 VRRP_API_VR_ACCEPT=2,
 	 VRRP_API_VR_UNICAST=4,
 	 VRRP_API_VR_IPV6=8,
+	 Unknown(u32),
 }
 impl Default for VrrpVrFlags {
 	fn default() -> Self { VrrpVrFlags::VRRP_API_VR_PREEMPT }
 }
-impl AsEnumFlag for VrrpVrFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for VrrpVrFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 VrrpVrFlags::VRRP_API_VR_PREEMPT => 1,
+			 VrrpVrFlags::VRRP_API_VR_ACCEPT => 2,
+			 VrrpVrFlags::VRRP_API_VR_UNICAST => 4,
+			 VrrpVrFlags::VRRP_API_VR_IPV6 => 8,
+			 VrrpVrFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => VrrpVrFlags::VRRP_API_VR_PREEMPT,
-			 2 => VrrpVrFlags::VRRP_API_VR_ACCEPT,
-			 4 => VrrpVrFlags::VRRP_API_VR_UNICAST,
-			 8 => VrrpVrFlags::VRRP_API_VR_IPV6,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(VrrpVrFlags::VRRP_API_VR_PREEMPT),
+			 2 => Some(VrrpVrFlags::VRRP_API_VR_ACCEPT),
+			 4 => Some(VrrpVrFlags::VRRP_API_VR_UNICAST),
+			 8 => Some(VrrpVrFlags::VRRP_API_VR_IPV6),
+			 value => Some(VrrpVrFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for VrrpVrFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for VrrpVrFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(VrrpVrFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for VrrpVrFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 32 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0xf
+	}
+}
+impl std::ops::BitOr for VrrpVrFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
 #[repr(u32)]
 pub enum VrrpVrState {
 	 VRRP_API_VR_STATE_INIT=0,
 	 VRRP_API_VR_STATE_BACKUP=1,
 	 VRRP_API_VR_STATE_MASTER=2,
 	 VRRP_API_VR_STATE_INTF_DOWN=3,
+	 Unknown(u32),
 }
 impl Default for VrrpVrState {
 	fn default() -> Self { VrrpVrState::VRRP_API_VR_STATE_INIT }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+impl AsU32 for VrrpVrState {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 VrrpVrState::VRRP_API_VR_STATE_INIT => 0,
+			 VrrpVrState::VRRP_API_VR_STATE_BACKUP => 1,
+			 VrrpVrState::VRRP_API_VR_STATE_MASTER => 2,
+			 VrrpVrState::VRRP_API_VR_STATE_INTF_DOWN => 3,
+			 VrrpVrState::Unknown(value) => value,
+		 }
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 match data{
+			 0 => Some(VrrpVrState::VRRP_API_VR_STATE_INIT),
+			 1 => Some(VrrpVrState::VRRP_API_VR_STATE_BACKUP),
+			 2 => Some(VrrpVrState::VRRP_API_VR_STATE_MASTER),
+			 3 => Some(VrrpVrState::VRRP_API_VR_STATE_INTF_DOWN),
+			 value => Some(VrrpVrState::Unknown(value)),
+		 }
+	 }
+}
+impl Serialize for VrrpVrState {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 serializer.serialize_u32(value)
+	 }
+}
+impl<'de> Deserialize<'de> for VrrpVrState {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u32::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(VrrpVrState::Unknown(value)))
+	 }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_add_del_c5cf15aa)]
 pub struct VrrpVrAddDel {
 	pub client_index: u32,
@@ -101,15 +170,16 @@ pub struct VrrpVrAddDel {
 	pub interval: u16,
 	 pub flags: EnumFlag<VrrpVrFlags>,
 	pub n_addrs: u8,
+	#[vpp(count = "n_addrs")]
 	pub addrs: VariableSizeArray<Address>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_add_del_reply_e8d4e804)]
 pub struct VrrpVrAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_update_0b51e2f4)]
 pub struct VrrpVrUpdate {
 	pub client_index: u32,
@@ -121,45 +191,47 @@ pub struct VrrpVrUpdate {
 	pub interval: u16,
 	 pub flags: EnumFlag<VrrpVrFlags>,
 	pub n_addrs: u8,
+	#[vpp(count = "n_addrs")]
 	pub addrs: VariableSizeArray<Address>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_update_reply_5317d608)]
 pub struct VrrpVrUpdateReply {
 	pub context: u32,
 	pub retval: i32,
 	pub vrrp_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_del_6029baa1)]
 pub struct VrrpVrDel {
 	pub client_index: u32,
 	pub context: u32,
 	pub vrrp_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_del_reply_e8d4e804)]
 pub struct VrrpVrDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_dump_f9e6675e)]
 pub struct VrrpVrDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_details_46edcebd)]
 pub struct VrrpVrDetails {
 	pub context: u32,
 	pub config: VrrpVrConf,
 	pub runtime: VrrpVrRuntime,
 	pub n_addrs: u8,
+	#[vpp(count = "n_addrs")]
 	pub addrs: VariableSizeArray<Address>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_start_stop_0662a3b7)]
 pub struct VrrpVrStartStop {
 	pub client_index: u32,
@@ -169,13 +241,13 @@ pub struct VrrpVrStartStop {
 	pub is_ipv6: u8,
 	pub is_start: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_start_stop_reply_e8d4e804)]
 pub struct VrrpVrStartStopReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_set_peers_20bec71f)]
 pub struct VrrpVrSetPeers {
 	pub client_index: u32,
@@ -184,15 +256,16 @@ pub struct VrrpVrSetPeers {
 	pub vr_id: u8,
 	pub is_ipv6: u8,
 	pub n_addrs: u8,
+	#[vpp(count = "n_addrs")]
 	pub addrs: VariableSizeArray<Address>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_set_peers_reply_e8d4e804)]
 pub struct VrrpVrSetPeersReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_peer_dump_6fa3f7c4)]
 pub struct VrrpVrPeerDump {
 	pub client_index: u32,
@@ -201,7 +274,7 @@ pub struct VrrpVrPeerDump {
 	pub is_ipv6: u8,
 	pub vr_id: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_peer_details_3d99c108)]
 pub struct VrrpVrPeerDetails {
 	pub client_index: u32,
@@ -210,9 +283,10 @@ pub struct VrrpVrPeerDetails {
 	pub vr_id: u8,
 	pub is_ipv6: u8,
 	pub n_peer_addrs: u8,
+	#[vpp(count = "n_peer_addrs")]
 	pub peer_addrs: VariableSizeArray<Address>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_track_if_add_del_d67df299)]
 pub struct VrrpVrTrackIfAddDel {
 	pub client_index: u32,
@@ -222,15 +296,16 @@ pub struct VrrpVrTrackIfAddDel {
 	pub vr_id: u8,
 	pub is_add: u8,
 	pub n_ifs: u8,
+	#[vpp(count = "n_ifs")]
 	pub ifs: VariableSizeArray<VrrpVrTrackIf>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_track_if_add_del_reply_e8d4e804)]
 pub struct VrrpVrTrackIfAddDelReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_track_if_dump_a34dfc6d)]
 pub struct VrrpVrTrackIfDump {
 	pub client_index: u32,
@@ -240,7 +315,7 @@ pub struct VrrpVrTrackIfDump {
 	pub vr_id: u8,
 	pub dump_all: u8,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_track_if_details_73c36f81)]
 pub struct VrrpVrTrackIfDetails {
 	pub client_index: u32,
@@ -249,9 +324,10 @@ pub struct VrrpVrTrackIfDetails {
 	pub vr_id: u8,
 	pub is_ipv6: u8,
 	pub n_ifs: u8,
+	#[vpp(count = "n_ifs")]
 	pub ifs: VariableSizeArray<VrrpVrTrackIf>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(vrrp_vr_event_c1fea6a5)]
 pub struct VrrpVrEvent {
 	pub client_index: u32,
@@ -260,7 +336,7 @@ pub struct VrrpVrEvent {
 	pub old_state: VrrpVrState,
 	pub new_state: VrrpVrState,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_vrrp_vr_events_c5e2af94)]
 pub struct WantVrrpVrEvents {
 	pub client_index: u32,
@@ -268,9 +344,33 @@ pub struct WantVrrpVrEvents {
 	pub enable_disable: bool,
 	pub pid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_vrrp_vr_events_reply_e8d4e804)]
 pub struct WantVrrpVrEventsReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"vrrp_vr_add_del_c5cf15aa",
+	"vrrp_vr_add_del_reply_e8d4e804",
+	"vrrp_vr_update_0b51e2f4",
+	"vrrp_vr_update_reply_5317d608",
+	"vrrp_vr_del_6029baa1",
+	"vrrp_vr_del_reply_e8d4e804",
+	"vrrp_vr_dump_f9e6675e",
+	"vrrp_vr_details_46edcebd",
+	"vrrp_vr_start_stop_0662a3b7",
+	"vrrp_vr_start_stop_reply_e8d4e804",
+	"vrrp_vr_set_peers_20bec71f",
+	"vrrp_vr_set_peers_reply_e8d4e804",
+	"vrrp_vr_peer_dump_6fa3f7c4",
+	"vrrp_vr_peer_details_3d99c108",
+	"vrrp_vr_track_if_add_del_d67df299",
+	"vrrp_vr_track_if_add_del_reply_e8d4e804",
+	"vrrp_vr_track_if_dump_a34dfc6d",
+	"vrrp_vr_track_if_details_73c36f81",
+	"vrrp_vr_event_c1fea6a5",
+	"want_vrrp_vr_events_c5e2af94",
+	"want_vrrp_vr_events_reply_e8d4e804",
+];
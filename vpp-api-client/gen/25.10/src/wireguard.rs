@@ -2,18 +2,17 @@
    Autogenerated Data, Do not Edit!
 */
 #![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]
-use vpp_api_macros::{VppMessage,VppUnionIdent};
+use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};
 use std::convert::TryInto;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use vpp_api_encoding::typ::*;
 pub use vpp_api_encoding;
 use vpp_api_message::VppApiMessage;
-use serde_repr::{Serialize_repr, Deserialize_repr};
 use typenum;
 use crate::ip_types::*;
 use crate::interface_types::*;
 // Implementation for wireguard_interface
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct WireguardInterface {
 	pub user_instance: u32,
 	pub sw_if_index: InterfaceIndex,
@@ -23,7 +22,7 @@ pub struct WireguardInterface {
 	pub src_ip: Address,
 }
 // Implementation for wireguard_peer
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub struct WireguardPeer {
 	pub peer_index: u32,
 	pub public_key: FixedSizeArray<u8, typenum::U32>,
@@ -34,32 +33,69 @@ pub struct WireguardPeer {
 	pub sw_if_index: InterfaceIndex,
 	pub flags: EnumFlag<WireguardPeerFlags>,
 	pub n_allowed_ips: u8,
+	#[vpp(count = "n_allowed_ips")]
 	pub allowed_ips: VariableSizeArray<Prefix>,
 }
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]
+#[repr(u8)]
 pub enum WireguardPeerFlags {
 	 WIREGUARD_PEER_STATUS_DEAD=1,
 	 WIREGUARD_PEER_ESTABLISHED=2,
+	 Unknown(u32),
 }
 impl Default for WireguardPeerFlags {
 	fn default() -> Self { WireguardPeerFlags::WIREGUARD_PEER_STATUS_DEAD }
 }
-impl AsEnumFlag for WireguardPeerFlags {
-	 fn as_u32(data: &Self) -> u32{
-		 *data as u32
+impl AsU32 for WireguardPeerFlags {
+	 fn as_u32(data: Self) -> u32{
+		 match data{
+			 WireguardPeerFlags::WIREGUARD_PEER_STATUS_DEAD => 1,
+			 WireguardPeerFlags::WIREGUARD_PEER_ESTABLISHED => 2,
+			 WireguardPeerFlags::Unknown(value) => value,
+		 }
 	 }
-	 fn from_u32(data: u32) -> Self{
+	 fn from_u32(data: u32) -> Option<Self>{
 		 match data{
-			 1 => WireguardPeerFlags::WIREGUARD_PEER_STATUS_DEAD,
-			 2 => WireguardPeerFlags::WIREGUARD_PEER_ESTABLISHED,
-			_ => panic!("Invalid Enum Descriminant")
+			 1 => Some(WireguardPeerFlags::WIREGUARD_PEER_STATUS_DEAD),
+			 2 => Some(WireguardPeerFlags::WIREGUARD_PEER_ESTABLISHED),
+			 value => Some(WireguardPeerFlags::Unknown(value)),
 		 }
 	 }
+}
+impl Serialize for WireguardPeerFlags {
+	 fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+		 let value = <Self as AsU32>::as_u32(*self);
+		 let value: u8 = value.try_into().map_err(|_| serde::ser::Error::custom(format!("WireguardPeerFlags value {} does not fit u8", value)))?;
+		 serializer.serialize_u8(value)
+	 }
+}
+impl<'de> Deserialize<'de> for WireguardPeerFlags {
+	 fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{
+		 let value = u32::from(u8::deserialize(deserializer)?);
+		 Ok(<Self as AsU32>::from_u32(value).unwrap_or(WireguardPeerFlags::Unknown(value)))
+	 }
+}
+impl AsEnumFlag for WireguardPeerFlags {
+	 fn as_u32(data: &Self) -> u32{
+		 <Self as AsU32>::as_u32(*data)
+	 }
+	 fn from_u32(data: u32) -> Option<Self>{
+		 <Self as AsU32>::from_u32(data)
+	 }
 	 fn size_of_enum_flag() -> u32{
 		 8 as u32
 	}
+	 fn all_enum_flags() -> u32{
+		 0x3
+	}
+}
+impl std::ops::BitOr for WireguardPeerFlags {
+	 type Output = EnumFlag<Self>;
+	 fn bitor(self, other: Self) -> EnumFlag<Self>{
+		 EnumFlag::from(self) | other
+	 }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_interface_create_a530137e)]
 pub struct WireguardInterfaceCreate {
 	pub client_index: u32,
@@ -67,27 +103,27 @@ pub struct WireguardInterfaceCreate {
 	pub interface: WireguardInterface,
 	pub generate_key: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_interface_create_reply_5383d31f)]
 pub struct WireguardInterfaceCreateReply {
 	pub context: u32,
 	pub retval: i32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_interface_delete_f9e6675e)]
 pub struct WireguardInterfaceDelete {
 	pub client_index: u32,
 	pub context: u32,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_interface_delete_reply_e8d4e804)]
 pub struct WireguardInterfaceDeleteReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_interface_dump_2c954158)]
 pub struct WireguardInterfaceDump {
 	pub client_index: u32,
@@ -95,13 +131,13 @@ pub struct WireguardInterfaceDump {
 	pub show_private_key: bool,
 	pub sw_if_index: InterfaceIndex,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_interface_details_0dd4865d)]
 pub struct WireguardInterfaceDetails {
 	pub context: u32,
 	pub interface: WireguardInterface,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_wireguard_peer_events_3bc666c8)]
 pub struct WantWireguardPeerEvents {
 	pub client_index: u32,
@@ -111,13 +147,13 @@ pub struct WantWireguardPeerEvents {
 	pub enable_disable: u32,
 	pub pid: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(want_wireguard_peer_events_reply_e8d4e804)]
 pub struct WantWireguardPeerEventsReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_peer_event_4e1b5d67)]
 pub struct WireguardPeerEvent {
 	pub client_index: u32,
@@ -125,56 +161,76 @@ pub struct WireguardPeerEvent {
 	pub peer_index: u32,
 	 pub flags: EnumFlag<WireguardPeerFlags>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_peer_add_9b8aad61)]
 pub struct WireguardPeerAdd {
 	pub client_index: u32,
 	pub context: u32,
 	pub peer: WireguardPeer,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_peer_add_reply_084a0cd3)]
 pub struct WireguardPeerAddReply {
 	pub context: u32,
 	pub retval: i32,
 	pub peer_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_peer_remove_3b74607a)]
 pub struct WireguardPeerRemove {
 	pub client_index: u32,
 	pub context: u32,
 	pub peer_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_peer_remove_reply_e8d4e804)]
 pub struct WireguardPeerRemoveReply {
 	pub context: u32,
 	pub retval: i32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_peers_dump_3b74607a)]
 pub struct WireguardPeersDump {
 	pub client_index: u32,
 	pub context: u32,
 	pub peer_index: u32,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wireguard_peers_details_6a9f6bc3)]
 pub struct WireguardPeersDetails {
 	pub context: u32,
 	pub peer: WireguardPeer,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wg_set_async_mode_a6465f7c)]
 pub struct WgSetAsyncMode {
 	pub client_index: u32,
 	pub context: u32,
 	pub async_enable: bool,
 }
-#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]
 #[message_name_and_crc(wg_set_async_mode_reply_e8d4e804)]
 pub struct WgSetAsyncModeReply {
 	pub context: u32,
 	pub retval: i32,
 }
+/// The name and CRC of every message of this module, in the order VPP numbers them.
+pub const MESSAGES: &[&str] = &[
+	"wireguard_interface_create_a530137e",
+	"wireguard_interface_create_reply_5383d31f",
+	"wireguard_interface_delete_f9e6675e",
+	"wireguard_interface_delete_reply_e8d4e804",
+	"wireguard_interface_dump_2c954158",
+	"wireguard_interface_details_0dd4865d",
+	"want_wireguard_peer_events_3bc666c8",
+	"want_wireguard_peer_events_reply_e8d4e804",
+	"wireguard_peer_event_4e1b5d67",
+	"wireguard_peer_add_9b8aad61",
+	"wireguard_peer_add_reply_084a0cd3",
+	"wireguard_peer_remove_3b74607a",
+	"wireguard_peer_remove_reply_e8d4e804",
+	"wireguard_peers_dump_3b74607a",
+	"wireguard_peers_details_6a9f6bc3",
+	"wg_set_async_mode_a6465f7c",
+	"wg_set_async_mode_reply_e8d4e804",
+];
@@ -0,0 +1,75 @@
+use crate::interface::*;
+use crate::interface_types::IfStatusFlags;
+use crate::VppApiError;
+use vpp_api_message::VppApiReply;
+use vpp_api_transport::afunix::client::Client;
+use vpp_api_transport::testing::FakeVpp;
+use vpp_api_transport::TransportError;
+
+#[tokio::test]
+async fn test_fake_sw_interface_set_flags() {
+    let vpp = FakeVpp::start_with_messages(crate::MESSAGE_NAMES_AND_CRCS).unwrap();
+    vpp.on::<SwInterfaceSetFlags, _>(|req| SwInterfaceSetFlagsReply {
+        context: 0,
+        retval: if req.sw_if_index == 1 { 0 } else { -2 },
+    });
+
+    let client = Client::connect(vpp.path(), "api-test").await.unwrap();
+    assert!(client.compatibility_report(crate::MESSAGE_NAMES_AND_CRCS).is_compatible());
+    assert_eq!(client.control_ping().await.unwrap(), 0);
+
+    for (sw_if_index, retval) in [(1, 0), (7, -2)] {
+        let reply: SwInterfaceSetFlagsReply = client.send_rcv(
+            SwInterfaceSetFlags {
+                client_index: 0,
+                context: 0,
+                sw_if_index,
+                flags: vec![IfStatusFlags::IF_STATUS_API_FLAG_ADMIN_UP]
+                    .try_into()
+                    .unwrap(),
+            }
+        ).await.unwrap();
+        assert_ne!(reply.context, 0);
+        assert_eq!(reply.retval, retval);
+    }
+    client.disconnect().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_fake_send_rcv_checked() {
+    let vpp = FakeVpp::start_with_messages(crate::MESSAGE_NAMES_AND_CRCS).unwrap();
+    vpp.on::<SwInterfaceSetFlags, _>(|req| SwInterfaceSetFlagsReply {
+        context: 0,
+        retval: if req.sw_if_index == 1 { 0 } else { -2 },
+    });
+
+    let client = Client::connect(vpp.path(), "api-test").await.unwrap();
+    let request = |sw_if_index| SwInterfaceSetFlags {
+        client_index: 0,
+        context: 0,
+        sw_if_index,
+        flags: vec![IfStatusFlags::IF_STATUS_API_FLAG_ADMIN_UP]
+            .try_into()
+            .unwrap(),
+    };
+    let reply: SwInterfaceSetFlagsReply = client.send_rcv_checked(request(1)).await.unwrap();
+    assert_eq!(reply.retval(), 0);
+
+    let err = client
+        .send_rcv_checked::<_, SwInterfaceSetFlagsReply>(request(7))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        TransportError::Retval {
+            error: VppApiError::InvalidSwIfIndex,
+            ..
+        }
+    ));
+    let error = err.vpp_error().unwrap();
+    assert_eq!(error.name(), "INVALID_SW_IF_INDEX");
+    assert_eq!(error.retval(), -2);
+    assert_eq!(VppApiError::from_retval(0), None);
+    assert_eq!(VppApiError::from_retval(-9999), Some(VppApiError::Unknown(-9999)));
+    client.disconnect().await.unwrap();
+}
@@ -0,0 +1,117 @@
+use anyhow::Result;
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use std::fmt::Debug;
+use vpp_api_encoding::wire::{self, VppDecode, VppEncode};
+use vpp_api_message::{VppApiMessage, VppApiMessageVisitor};
+use vpp_api_transport::trace::{ApiTrace, TraceMessage};
+
+/// The output formats of the decoder.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    /// One line per message, with the decoded message in Rust debug notation
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+/// Decode an API trace saved by VPP with `api trace save <file>`
+#[derive(Parser, Debug)]
+#[clap(version = "1.0")]
+struct Opts {
+    /// The trace file, usually in /tmp
+    trace_file: String,
+
+    /// The output format
+    #[clap(short, long, value_enum, default_value = "text")]
+    format: Format,
+}
+
+/// Decodes the payload of a message into the type the visitor is called with.
+struct Decode<'a> {
+    payload: &'a [u8],
+    format: Format,
+}
+
+impl VppApiMessageVisitor for Decode<'_> {
+    type Output = Result<serde_json::Value>;
+
+    fn visit<T>(self) -> Self::Output
+    where
+        T: VppApiMessage + VppEncode + VppDecode + Serialize + DeserializeOwned + Debug + 'static,
+    {
+        let (message, _): (T, usize) = wire::decode_from_slice(self.payload)?;
+        Ok(match self.format {
+            Format::Text => format!("{message:?}").into(),
+            Format::Json => serde_json::to_value(&message)?,
+        })
+    }
+}
+
+/// Returns the bytes as space separated hex.
+fn hex(data: &[u8]) -> String {
+    data.iter()
+        .map(|b| format!("{b:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Prints one message, decoded if the bindings know it and as hex otherwise.
+fn print_message(index: usize, message: &TraceMessage, format: Format) {
+    let name = message.name_crc.as_deref().unwrap_or("unknown");
+    let decoded = message.name_crc.as_deref().and_then(|name_crc| {
+        vpp_api_client::visit_message(
+            name_crc,
+            Decode {
+                payload: &message.payload,
+                format,
+            },
+        )
+    });
+    let (decoded, error) = match decoded {
+        Some(Ok(decoded)) => (Some(decoded), None),
+        Some(Err(e)) => (None, Some(e.to_string())),
+        None => (None, None),
+    };
+    match format {
+        Format::Text => {
+            let body = match (decoded, error) {
+                (Some(serde_json::Value::String(text)), _) => text,
+                (_, Some(error)) => format!("{} (decode failed: {error})", hex(&message.payload)),
+                _ => hex(&message.payload),
+            };
+            println!("[{index}] {} {name}: {body}", message.msg_id);
+        }
+        Format::Json => {
+            let mut line = json!({
+                "index": index,
+                "msg_id": message.msg_id,
+                "name": message.name_crc,
+            });
+            match decoded {
+                Some(decoded) => line["message"] = decoded,
+                None => {
+                    line["hex"] = hex(&message.payload).into();
+                    if let Some(error) = error {
+                        line["error"] = error.into();
+                    }
+                }
+            }
+            println!("{line}");
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let opts = Opts::parse();
+    let trace = ApiTrace::read(&opts.trace_file)?;
+    if trace.wrapped {
+        eprintln!("the trace buffer wrapped, the oldest messages are missing");
+    }
+    for (index, message) in trace.messages.iter().enumerate() {
+        print_message(index, message, opts.format);
+    }
+    Ok(())
+}
@@ -28,6 +28,30 @@ cfg_if! {
         #[allow(clippy::all)]
         pub mod tests_nonblocking;
 
+        #[cfg(test)]
+        #[path = "../gen/25.10/tests/transport_interface_test.rs"]
+        #[rustfmt::skip]
+        #[allow(clippy::all)]
+        pub mod tests_transport;
+
+        #[cfg(test)]
+        #[path = "../gen/25.10/tests/fake_interface_test.rs"]
+        #[rustfmt::skip]
+        #[allow(clippy::all)]
+        pub mod tests_fake;
+
+        #[cfg(test)]
+        #[path = "../gen/25.10/tests/wire_encoding_test.rs"]
+        #[rustfmt::skip]
+        #[allow(clippy::all)]
+        pub mod tests_wire;
+
+        #[cfg(test)]
+        #[path = "../gen/25.10/tests/types_round_trip_test.rs"]
+        #[rustfmt::skip]
+        #[allow(clippy::all)]
+        pub mod tests_types;
+
     } else if #[cfg(feature = "25_06")] {
 
         #[path = "../gen/25.06/src/mod.rs"]
@@ -54,6 +78,30 @@ cfg_if! {
         #[rustfmt::skip]
         #[allow(clippy::all)]
         pub mod tests_nonblocking;
+
+        #[cfg(test)]
+        #[path = "../gen/25.06/tests/transport_interface_test.rs"]
+        #[rustfmt::skip]
+        #[allow(clippy::all)]
+        pub mod tests_transport;
+
+        #[cfg(test)]
+        #[path = "../gen/25.06/tests/fake_interface_test.rs"]
+        #[rustfmt::skip]
+        #[allow(clippy::all)]
+        pub mod tests_fake;
+
+        #[cfg(test)]
+        #[path = "../gen/25.06/tests/wire_encoding_test.rs"]
+        #[rustfmt::skip]
+        #[allow(clippy::all)]
+        pub mod tests_wire;
+
+        #[cfg(test)]
+        #[path = "../gen/25.06/tests/types_round_trip_test.rs"]
+        #[rustfmt::skip]
+        #[allow(clippy::all)]
+        pub mod tests_types;
     } else {
         compile_error!("You must enable exactly one version feature: e.g. `25_10` or `25_06`");
     }
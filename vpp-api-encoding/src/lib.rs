@@ -1 +1,2 @@
 pub mod typ;
+pub mod wire;
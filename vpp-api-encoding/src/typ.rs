@@ -17,8 +17,14 @@ use std::fmt;
 use std::str::Utf8Error;
 use typenum::{U10, U32, U64, U256};
 
+/// The most elements a decoder reserves room for up front.
+///
+/// Lengths read off the wire are not trusted: past this, a buffer only grows with
+/// the elements actually decoded, which are bounded by the length of the frame.
+const MAX_PREALLOCATION: usize = 4096;
+
 #[derive(Clone, Default)]
-pub struct FixedSizeString<N: ArrayLength>(GenericArray<u8, N>);
+pub struct FixedSizeString<N: ArrayLength>(pub(crate) GenericArray<u8, N>);
 
 impl<N: ArrayLength> fmt::Debug for FixedSizeString<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -154,7 +160,7 @@ impl<'de, N: ArrayLength> Deserialize<'de> for FixedSizeString<N> {
 }
 
 #[derive(Clone, Default)]
-pub struct VariableSizeString(Vec<u8>);
+pub struct VariableSizeString(pub(crate) Vec<u8>);
 
 impl fmt::Debug for VariableSizeString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -199,7 +205,9 @@ impl Serialize for VariableSizeString {
         let data = &self.0;
 
         let len: usize = data.len();
-        let len_u32: u32 = len.try_into().unwrap();
+        let len_u32: u32 = len.try_into().map_err(|_| {
+            serde::ser::Error::custom(format!("string of {len} bytes is too long to encode"))
+        })?;
         let mut seq = serializer.serialize_tuple(len)?;
         seq.serialize_element(&len_u32)?;
         for b in data {
@@ -225,15 +233,15 @@ impl<'de> Deserialize<'de> for VariableSizeString {
             where
                 A: SeqAccess<'de>,
             {
-                let mut res: Vec<u8> = vec![];
                 let length: u32 = seq
                     .next_element()?
-                    .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                let mut res: Vec<u8> = Vec::with_capacity((length as usize).min(MAX_PREALLOCATION));
 
                 for i in 0..length {
                     res.push(
                         seq.next_element()?
-                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                            .ok_or_else(|| serde::de::Error::invalid_length(res.len(), &self))?,
                     );
                 }
 
@@ -268,9 +276,8 @@ impl Serialize for F64 {
     where
         S: Serializer,
     {
-        let data = &self.0;
-        let out = f64::from_bits((data).to_bits().to_be());
-        serializer.serialize_f64(out)
+        // The serializer decides the byte order; VPP expects network order.
+        serializer.serialize_f64(self.0)
     }
 }
 
@@ -290,8 +297,7 @@ impl<'de> Deserialize<'de> for F64 {
             where
                 E: de::Error,
             {
-                let f64_value = f64::from_bits(value.to_bits().to_be());
-                Ok(F64(f64_value))
+                Ok(F64(value))
             }
         }
 
@@ -299,8 +305,7 @@ impl<'de> Deserialize<'de> for F64 {
     }
 }
 
-#[derive(Clone, Default, Deserialize)]
-#[serde(bound = "N: ArrayLength, T: Deserialize<'de> + Default")]
+#[derive(Clone, Default)]
 pub struct FixedSizeArray<T: Default + Debug, N: ArrayLength>(pub GenericArray<T, N>);
 
 impl<T: Debug + Default, N: ArrayLength> fmt::Debug for FixedSizeArray<T, N> {
@@ -349,69 +354,26 @@ impl<T: Serialize + Default + Debug, N: ArrayLength> Serialize for FixedSizeArra
     }
 }
 
-// Copying Fixed Size String Deserialize for u8
-/* impl<'de,T: Deserialize<'de>+Default+Debug, N: ArrayLength<T>> Deserialize<'de> for FixedSizeArray<T,N> {
+impl<'de, T, N> Deserialize<'de> for FixedSizeArray<T, N>
+where
+    T: Deserialize<'de> + Default + Debug,
+    N: ArrayLength,
+{
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct FixedSizeArrayVisitor<N> {
-            marker: PhantomData<N>,
-        };
-        impl<'de, N> Visitor<'de> for FixedSizeArrayVisitor<N>
-        where
-            N: ArrayLength<u8>,
-        {
-            type Value = FixedSizeArray<u8,N>;
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("FixedSizeString")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-            where
-                A: SeqAccess<'de>,
-            {
-                let mut res: GenericArray<u8, N> = Default::default();
-                let length = N::to_u32() as usize;
-
-                for i in 0..length {
-                    let b = seq
-                        .next_element()?
-                        .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
-                    res[i] = b;
-                }
-
-                return Ok(FixedSizeArray(res));
-            }
+        struct FixedSizeArrayVisitor<T, N> {
+            marker: PhantomData<(T, N)>,
         }
-
-        return Ok(deserializer.deserialize_tuple(
-            N::to_u32() as usize,
-            FixedSizeArrayVisitor {
-                marker: PhantomData,
-            },
-        )?);
-    }
-}*/
-
-// FIXME: implement the deserialize manually.
-
-/* impl<'de, 'tde, T: Deserialize<'tde>, N: ArrayLength<T>> Deserialize<'de> for FixedSizeArray<T, N> {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        struct FixedSizeArrayVisitor<N> {
-            marker: PhantomData<N>,
-        };
-        impl<'de, N, T> Visitor<'de> for FixedSizeArrayVisitor<N>
+        impl<'de, T, N> Visitor<'de> for FixedSizeArrayVisitor<T, N>
         where
-            T: Deserialize+Default
-            N: ArrayLength<T>,
+            T: Deserialize<'de> + Default + Debug,
+            N: ArrayLength,
         {
             type Value = FixedSizeArray<T, N>;
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("FixedSizeArray")
+                write!(formatter, "an array of {} elements", N::to_usize())
             }
 
             fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -419,76 +381,38 @@ impl<T: Serialize + Default + Debug, N: ArrayLength> Serialize for FixedSizeArra
                 A: SeqAccess<'de>,
             {
                 let mut res: GenericArray<T, N> = Default::default();
-                let length = N::to_u32() as usize;
-
-                for i in 0..length {
-                    let b = seq
+                for (i, slot) in res.iter_mut().enumerate() {
+                    *slot = seq
                         .next_element()?
-                        .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
-                    res[i] = b as T;
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
                 }
-
-                return Ok(FixedSizeArray(res));
+                Ok(FixedSizeArray(res))
             }
         }
 
-        return Ok(deserializer.deserialize_tuple(
-            N::to_u32() as usize,
+        deserializer.deserialize_tuple(
+            N::to_usize(),
             FixedSizeArrayVisitor {
                 marker: PhantomData,
             },
-        )?);
+        )
     }
 }
-*/
-/* impl<'de, T: Deserialize<'de>+Default,N: ArrayLength<T>> Deserialize<'de> for FixedSizeArray<T,N> {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        struct FixedSizeArrayVisitor<T:Default,N> {
-            marker: PhantomData<N>,
-        };
-        impl<'de, T, N> Visitor<'de> for FixedSizeArrayVisitor<T,N>
-        where
-            T: Deserialize<'de>+Default,
-            N: ArrayLength<T>,
-        {
-            type Value = FixedSizeArray<T,N>;
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("FixedSizeArray")
-            }
 
-            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-            where
-                A: SeqAccess<'de>,
-            {
-                let mut res: GenericArray<T, N> = Default::default();
-                let length = N::to_u32() as usize;
-
-                for i in 0..length {
-                    let b = seq
-                        .next_element()?
-                        .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
-                    res[i] = b;
-                }
+#[derive(Copy, Clone, Default)]
+pub struct SizedEnum<T, X>(pub(crate) T, PhantomData<X>); // This is the sized enum declaration, It's a unit struct
 
-                return Ok(FixedSizeArray(res));
-            }
-        }
+impl<T, X> SizedEnum<T, X> {
+    /// Wraps `value`, to be carried on the wire as an `X`.
+    pub fn new(value: T) -> Self {
+        SizedEnum(value, PhantomData)
+    }
 
-        return Ok(deserializer.deserialize_tuple(
-            N::to_u32() as usize,
-            FixedSizeArrayVisitor {
-                marker: PhantomData,
-            },
-        )?);
+    /// Returns the wrapped enum value.
+    pub fn value(&self) -> &T {
+        &self.0
     }
 }
-*/
-#[derive(Copy, Clone, Default, Deserialize)]
-#[serde(bound = "T: Deserialize<'de> + Default")]
-pub struct SizedEnum<T, X>(T, PhantomData<X>); // This is the sized enum declaration, It's a unit struct
 
 impl<T: Debug, X> fmt::Debug for SizedEnum<T, X> {
     // implement debug trait for sized enum
@@ -520,12 +444,59 @@ impl<T: Serialize + Copy + AsU32 + Debug, X: Serialize + Debug + TryFrom<u32>> S
     }
 }
 
+impl<'de, T: AsU32, X: Deserialize<'de> + Into<u32>> Deserialize<'de> for SizedEnum<T, X> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SizedEnumVisitor<T, X> {
+            marker: PhantomData<(T, X)>,
+        }
+        impl<'de, T: AsU32, X: Deserialize<'de> + Into<u32>> Visitor<'de> for SizedEnumVisitor<T, X> {
+            type Value = SizedEnum<T, X>;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    formatter,
+                    "an enum stored as {}",
+                    std::any::type_name::<X>()
+                )
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let data_x: X = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let data_u32: u32 = data_x.into();
+                T::from_u32(data_u32).map(SizedEnum::new).ok_or_else(|| {
+                    de::Error::custom(format!(
+                        "unknown value {} for {}",
+                        data_u32,
+                        std::any::type_name::<T>()
+                    ))
+                })
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            1,
+            SizedEnumVisitor {
+                marker: PhantomData,
+            },
+        )
+    }
+}
+
 pub trait AsU32 {
     fn as_u32(data: Self) -> u32;
+    /// Returns the value whose discriminant is `data`, or `None` if there is none.
+    fn from_u32(data: u32) -> Option<Self>
+    where
+        Self: Sized;
 }
 
-/* FIXME: add deserializer for SizedEnum */
-
 #[derive(Clone, Default, Debug)]
 pub struct VariableSizeArray<T>(pub Vec<T>);
 
@@ -575,30 +546,14 @@ impl<'de, T: Deserialize<'de> + Debug> Deserialize<'de> for VariableSizeArray<T>
                 A: SeqAccess<'de>,
             {
                 let mut res: Vec<T> = vec![];
-                /*
-                                let length: u32 = seq
-                                    .next_element()?
-                                    .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
-                */
-
-                loop {
-                    let nxt = seq.next_element();
-                    if nxt.is_err() {
-                        break;
-                    }
-                    let nxt = nxt?;
-                    if let Some(nxt) = nxt {
-                        res.push(nxt);
-                    }
-                }
-                /*
-                for i in 0..length {
-                    res.push(
-                        seq.next_element()?
-                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
-                    );
+
+                // The length is not part of the array, so elements are read until
+                // the format ends the sequence. A malformed element is an error rather
+                // than the end of the array; messages with a count field decode through
+                // the wire codec, which reads exactly that many.
+                while let Some(nxt) = seq.next_element()? {
+                    res.push(nxt);
                 }
-                */
 
                 Ok(VariableSizeArray::<T>(res))
             }
@@ -615,62 +570,204 @@ impl<'de, T: Deserialize<'de> + Debug> Deserialize<'de> for VariableSizeArray<T>
 
 pub trait AsEnumFlag {
     fn as_u32(data: &Self) -> u32;
-    fn from_u32(data: u32) -> Self;
+    /// Returns the flag with the value `data`, or `None` if there is no such flag.
+    fn from_u32(data: u32) -> Option<Self>
+    where
+        Self: Sized;
     fn size_of_enum_flag() -> u32;
+    /// Returns the bits of all the flags the type knows about.
+    fn all_enum_flags() -> u32;
 }
 
-#[derive(Clone, Debug)]
-pub struct EnumFlag<T: Clone + Debug + AsEnumFlag>(Vec<T>);
+/// A set of flags of type `T`, kept as the bitmask that goes on the wire.
+///
+/// Bits without a flag in `T` are kept as they are, so flags from a newer VPP
+/// encode back to what was received.
+pub struct EnumFlag<T>(u32, PhantomData<T>);
 
-impl<T> EnumFlag<T>
-where
-    T: Clone + Debug + AsEnumFlag + std::cmp::PartialEq,
-{
-    pub fn contains(&self, other: T) -> bool {
-        self.0.contains(&other)
+impl<T: AsEnumFlag> EnumFlag<T> {
+    /// Returns a set without any flag.
+    pub fn empty() -> Self {
+        EnumFlag::from_bits(0)
+    }
+
+    /// Returns a set with every flag `T` knows about.
+    pub fn all() -> Self {
+        EnumFlag::from_bits(T::all_enum_flags())
+    }
+
+    /// Returns the set with the bits `bits`, whether `T` knows them or not.
+    pub fn from_bits(bits: u32) -> Self {
+        EnumFlag(bits, PhantomData)
+    }
+
+    /// Returns the bitmask of the set.
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns `true` if no bit is set.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all the bits of `flag` are set.
+    ///
+    /// A flag without bits, such as the `*_NONE = 0` flags of VPP, is
+    /// contained in every set.
+    pub fn contains(&self, flag: T) -> bool {
+        let bits = T::as_u32(&flag);
+        self.0 & bits == bits
+    }
+
+    /// Sets the bits of `flag`.
+    pub fn insert(&mut self, flag: T) {
+        self.0 |= T::as_u32(&flag);
+    }
+
+    /// Clears the bits of `flag`, leaving the other bits as they are.
+    pub fn remove(&mut self, flag: T) {
+        self.0 &= !T::as_u32(&flag);
+    }
+
+    /// Iterates over the flags of the set, one per bit, lowest bit first.
+    ///
+    /// Each set bit is turned into a flag with `T::from_u32`, and a bit it
+    /// returns `None` for is skipped, though it is still part of `bits()`.
+    /// The generated flag enums return `Unknown(bit)` for a bit without a
+    /// flag, so for them every set bit is yielded.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        (0..u32::BITS)
+            .map(|bit| 1 << bit)
+            .filter(|bit| self.0 & bit != 0)
+            .filter_map(T::from_u32)
+    }
+
+    /// Returns the bitmask if it fits the width of `T` on the wire.
+    pub(crate) fn sized_bits(&self) -> Result<u32, String> {
+        let size = T::size_of_enum_flag();
+        if !matches!(size, 8 | 16 | 32) {
+            return Err(format!("EnumFlags do not support {} bit type flag", size));
+        }
+        if size < 32 && self.0 >> size != 0 {
+            return Err(format!("flags {:#x} do not fit in {} bits", self.0, size));
+        }
+        Ok(self.0)
     }
 }
 
-impl<T: Clone + Debug + AsEnumFlag> TryFrom<Vec<T>> for EnumFlag<T> {
-    type Error = String;
+impl<T> Clone for EnumFlag<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
 
-    fn try_from(values: Vec<T>) -> Result<Self, Self::Error> {
-        let mut out = vec![];
-        for value in values {
-            out.push(value);
+impl<T> Copy for EnumFlag<T> {}
+
+impl<T> PartialEq for EnumFlag<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for EnumFlag<T> {}
+
+impl<T: AsEnumFlag + Debug> fmt::Debug for EnumFlag<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_set();
+        let mut known = 0;
+        for flag in self.iter() {
+            known |= T::as_u32(&flag);
+            list.entry(&flag);
+        }
+        if self.0 & !known != 0 {
+            list.entry(&format_args!("{:#x}", self.0 & !known));
         }
-        Ok(EnumFlag(out))
+        list.finish()
     }
 }
-impl<T: Clone + Debug + AsEnumFlag> Default for EnumFlag<T> {
+
+impl<T: AsEnumFlag> Default for EnumFlag<T> {
     fn default() -> Self {
-        vec![].try_into().unwrap()
+        EnumFlag::empty()
     }
 }
-impl<T: Clone + Debug + AsEnumFlag> EnumFlag<T> {
-    pub fn sum(&self) -> u32 {
-        let mut sum: u32 = 0;
-        for x in &self.0 {
-            sum += T::as_u32(x); // AsU8 and AsU32 trait needed for using T
+
+impl<T: AsEnumFlag> From<T> for EnumFlag<T> {
+    fn from(flag: T) -> Self {
+        EnumFlag::from_bits(T::as_u32(&flag))
+    }
+}
+
+impl<T: AsEnumFlag> FromIterator<T> for EnumFlag<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(flags: I) -> Self {
+        let mut out = EnumFlag::empty();
+        for flag in flags {
+            out.insert(flag);
         }
-        sum
+        out
+    }
+}
+
+impl<T: AsEnumFlag> TryFrom<Vec<T>> for EnumFlag<T> {
+    type Error = String;
+
+    fn try_from(values: Vec<T>) -> Result<Self, Self::Error> {
+        Ok(values.into_iter().collect())
+    }
+}
+
+impl<T> std::ops::BitOr for EnumFlag<T> {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        EnumFlag(self.0 | other.0, PhantomData)
+    }
+}
+
+impl<T: AsEnumFlag> std::ops::BitOr<T> for EnumFlag<T> {
+    type Output = Self;
+
+    fn bitor(mut self, flag: T) -> Self {
+        self.insert(flag);
+        self
+    }
+}
+
+impl<T> std::ops::BitAnd for EnumFlag<T> {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        EnumFlag(self.0 & other.0, PhantomData)
+    }
+}
+
+impl<T> std::ops::BitOrAssign for EnumFlag<T> {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
     }
 }
-impl<T: Clone + Debug + AsEnumFlag> Serialize for EnumFlag<T> {
+
+impl<T> std::ops::BitAndAssign for EnumFlag<T> {
+    fn bitand_assign(&mut self, other: Self) {
+        self.0 &= other.0;
+    }
+}
+
+impl<T: AsEnumFlag> Serialize for EnumFlag<T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let size: u32 = T::size_of_enum_flag();
-        match size {
-            32 => serializer.serialize_u32(self.sum()),
-            16 => serializer.serialize_u16(self.sum() as u16),
-            8 => serializer.serialize_u8(self.sum() as u8),
-            _ => panic!("EnumFlags do not support {} bit type flag", size),
+        let bits = self.sized_bits().map_err(serde::ser::Error::custom)?;
+        match T::size_of_enum_flag() {
+            32 => serializer.serialize_u32(bits),
+            16 => serializer.serialize_u16(bits as u16),
+            _ => serializer.serialize_u8(bits as u8),
         }
     }
 }
-impl<'de, T: Debug + Clone + AsEnumFlag + Deserialize<'de>> Deserialize<'de> for EnumFlag<T> {
+impl<'de, T: AsEnumFlag> Deserialize<'de> for EnumFlag<T> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -678,10 +775,9 @@ impl<'de, T: Debug + Clone + AsEnumFlag + Deserialize<'de>> Deserialize<'de> for
         struct EnumFlagVisitor<T> {
             marker: PhantomData<T>,
         }
-        // let mut size = 0;
         impl<'de, T> Visitor<'de> for EnumFlagVisitor<T>
         where
-            T: Debug + Clone + AsEnumFlag,
+            T: AsEnumFlag,
         {
             type Value = EnumFlag<T>;
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -692,64 +788,22 @@ impl<'de, T: Debug + Clone + AsEnumFlag + Deserialize<'de>> Deserialize<'de> for
             where
                 E: Error,
             {
-                let mut res: Vec<T> = vec![];
                 trace!("{}", v);
-                /* let number = u32::pow(2, v);
-                let enum_d: T = AsU32::from_u32(number);
-                res.push(enum_d);*/
-                let felix = format!("{:032b}", v);
-                let char_vec: Vec<char> = felix.chars().collect();
-                for c in 0..char_vec.len() {
-                    if char_vec[c] == '1' {
-                        let size: u32 = char_vec.len() as u32 - c as u32 - 1;
-                        let number = u32::pow(2, size);
-                        let enum_d = T::from_u32(number);
-                        res.push(enum_d);
-                    }
-                }
-                Ok(EnumFlag::<T>(res))
+                Ok(EnumFlag::from_bits(v))
             }
             fn visit_u16<E>(self, v: u16) -> Result<Self::Value, E>
             where
                 E: Error,
             {
-                let mut res: Vec<T> = vec![];
                 trace!("{}", v);
-                /* let number = u32::pow(2, v);
-                let enum_d: T = AsU32::from_u32(number);
-                res.push(enum_d);*/
-                let felix = format!("{:016b}", v);
-                let char_vec: Vec<char> = felix.chars().collect();
-                for c in 0..char_vec.len() {
-                    if char_vec[c] == '1' {
-                        let size: u32 = char_vec.len() as u32 - c as u32 - 1;
-                        let number = u32::pow(2, size);
-                        let enum_d = T::from_u32(number);
-                        res.push(enum_d);
-                    }
-                }
-                Ok(EnumFlag::<T>(res))
+                Ok(EnumFlag::from_bits(u32::from(v)))
             }
             fn visit_u8<E>(self, v: u8) -> Result<Self::Value, E>
             where
                 E: Error,
             {
-                let mut res: Vec<T> = vec![];
                 trace!("{}", v);
-                /* let number = u32::pow(2, v);
-                let enum_d: T = AsU32::from_u32(number);
-                res.push(enum_d);*/
-                let felix = format!("{:08b}", v);
-                let char_vec: Vec<char> = felix.chars().collect();
-                for c in 0..char_vec.len() {
-                    if char_vec[c] == '1' {
-                        let size: u32 = char_vec.len() as u32 - c as u32 - 1;
-                        let number = u32::pow(2, size);
-                        let enum_d = T::from_u32(number);
-                        res.push(enum_d);
-                    }
-                }
-                Ok(EnumFlag::<T>(res))
+                Ok(EnumFlag::from_bits(u32::from(v)))
             }
         }
         let size: u32 = T::size_of_enum_flag();
@@ -763,7 +817,10 @@ impl<'de, T: Debug + Clone + AsEnumFlag + Deserialize<'de>> Deserialize<'de> for
             8 => deserializer.deserialize_u8(EnumFlagVisitor {
                 marker: PhantomData,
             }),
-            _ => panic!("Deserializing not supported for {} bit set flags", size),
+            _ => Err(D::Error::custom(format!(
+                "Deserializing not supported for {} bit set flags",
+                size
+            ))),
         }
     }
 }
@@ -795,3 +852,91 @@ pub struct TunnelFlags {}
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct IpFlowHashConfig {}
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    enum TestFlags {
+        A = 1,
+        B = 4,
+    }
+
+    impl AsEnumFlag for TestFlags {
+        fn as_u32(data: &Self) -> u32 {
+            data.clone() as u32
+        }
+        fn from_u32(data: u32) -> Option<Self> {
+            match data {
+                1 => Some(TestFlags::A),
+                4 => Some(TestFlags::B),
+                _ => None,
+            }
+        }
+        fn size_of_enum_flag() -> u32 {
+            8
+        }
+        fn all_enum_flags() -> u32 {
+            5
+        }
+    }
+
+    fn decode<T: for<'de> Deserialize<'de>>(data: &[u8]) -> Result<T, String> {
+        let config = bincode_next::config::legacy()
+            .with_big_endian()
+            .with_fixed_int_encoding();
+        bincode_next::serde::decode_from_slice(data, config)
+            .map(|(value, _)| value)
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_decode_untrusted() {
+        let s: VariableSizeString = decode(&[0, 0, 0, 2, b'o', b'k']).unwrap();
+        assert_eq!(s.to_string(), "ok");
+        // claims 4 GiB, carries three bytes
+        assert!(decode::<VariableSizeString>(&[0xff, 0xff, 0xff, 0xff, 1, 2, 3]).is_err());
+
+        // a truncated element is reported, not dropped
+        assert!(decode::<VariableSizeArray<u32>>(&[0, 0, 0, 1, 0, 0, 0, 2, 0, 0]).is_err());
+
+        let flags: EnumFlag<TestFlags> = decode(&[5]).unwrap();
+        assert!(flags.contains(TestFlags::A) && flags.contains(TestFlags::B));
+        // a bit without a flag is kept
+        let flags: EnumFlag<TestFlags> = decode(&[3]).unwrap();
+        assert_eq!(flags.bits(), 3);
+        assert_eq!(flags.iter().collect::<Vec<_>>(), [TestFlags::A]);
+    }
+
+    #[test]
+    fn test_enum_flag_set_operations() {
+        let mut flags = EnumFlag::empty();
+        assert!(flags.is_empty());
+        flags.insert(TestFlags::B);
+        flags.insert(TestFlags::B);
+        assert_eq!(flags.bits(), 4);
+        assert_eq!(flags | TestFlags::A, EnumFlag::all());
+        assert_eq!(flags & EnumFlag::all(), flags);
+        assert!(!flags.contains(TestFlags::A));
+        flags.remove(TestFlags::B);
+        assert_eq!(flags, EnumFlag::default());
+
+        let flags: EnumFlag<TestFlags> = [TestFlags::A, TestFlags::B, TestFlags::A]
+            .into_iter()
+            .collect();
+        assert_eq!(flags.bits(), 5);
+        assert_eq!(
+            flags.iter().collect::<Vec<_>>(),
+            [TestFlags::A, TestFlags::B]
+        );
+        assert_eq!(
+            EnumFlag::from(TestFlags::A) | EnumFlag::from(TestFlags::B),
+            flags
+        );
+        assert_eq!(
+            format!("{:?}", EnumFlag::<TestFlags>::from_bits(7)),
+            "{A, B, 0x2}"
+        );
+    }
+}
@@ -0,0 +1,517 @@
+use crate::typ::*;
+pub use bytes::BufMut;
+use generic_array::{ArrayLength, GenericArray};
+use std::fmt;
+
+/// The ways encoding to or decoding from the VPP wire layout can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WireError {
+    /// The input ended before a value was complete.
+    UnexpectedEnd {
+        /// The type being decoded.
+        type_name: &'static str,
+        /// The bytes the value needed.
+        needed: usize,
+        /// The bytes that were left.
+        remaining: usize,
+    },
+    /// The bytes, or the value, have no representation on the other side.
+    Invalid(String),
+}
+
+impl fmt::Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WireError::UnexpectedEnd {
+                type_name,
+                needed,
+                remaining,
+            } => write!(f, "{type_name} needs {needed} bytes, only {remaining} left"),
+            WireError::Invalid(reason) => f.write_str(reason),
+        }
+    }
+}
+
+impl std::error::Error for WireError {}
+
+/// Writes a value in the layout VPP uses on the wire.
+///
+/// The layout is that of the packed C structs of VPP: fields in declaration
+/// order without padding, integers and floats in network byte order, fixed
+/// size arrays inline, and variable size strings preceded by their length as
+/// a `u32`. Variable size arrays are written as their elements only; their
+/// length is a field of the message.
+///
+/// Implemented for the primitive types and the types of [`crate::typ`];
+/// `#[derive(VppEncode)]` from `vpp-api-macros` implements it for structs and
+/// fieldless enums.
+pub trait VppEncode {
+    /// Appends the encoded value to `buf`.
+    ///
+    /// The buffer is only ever appended to, so one buffer can be cleared and
+    /// reused for every message, and a frame can be encoded straight into the
+    /// write buffer of a transport.
+    fn vpp_encode<B: BufMut>(&self, buf: &mut B) -> Result<(), WireError>;
+}
+
+/// Reads a value from the layout VPP uses on the wire.
+///
+/// See [`VppEncode`] for the layout. Lengths read off the wire are checked
+/// against the bytes actually left, so untrusted input fails to decode rather
+/// than allocating or panicking.
+pub trait VppDecode: Sized {
+    /// Decodes a value from the front of `input` and advances `input` past it.
+    fn vpp_decode(input: &mut &[u8]) -> Result<Self, WireError>;
+}
+
+/// Encodes a value into a new buffer.
+///
+/// # Arguments
+///
+/// * `value` - The value to encode
+///
+/// # Returns
+///
+/// The encoded bytes, or an error if the value cannot be represented.
+pub fn encode_to_vec<T: VppEncode + ?Sized>(value: &T) -> Result<Vec<u8>, WireError> {
+    let mut buf = vec![];
+    value.vpp_encode(&mut buf)?;
+    Ok(buf)
+}
+
+/// Decodes a value from the front of a slice.
+///
+/// # Arguments
+///
+/// * `slice` - The encoded bytes
+///
+/// # Returns
+///
+/// The value and the number of bytes it took up, or an error if the bytes do not decode.
+pub fn decode_from_slice<T: VppDecode>(slice: &[u8]) -> Result<(T, usize), WireError> {
+    let mut input = slice;
+    let value = T::vpp_decode(&mut input)?;
+    Ok((value, slice.len() - input.len()))
+}
+
+/// Returns the value to write for the count field of a variable array.
+///
+/// A count of zero is filled in with the length of the array, any other count
+/// has to match it. `#[derive(VppEncode)]` calls this for the field named by
+/// `#[vpp(count = "...")]`.
+///
+/// # Arguments
+///
+/// * `count` - The value of the count field
+/// * `len` - The number of elements in the array
+/// * `name` - The name of the count field, for the error
+///
+/// # Returns
+///
+/// The count to encode, or an error if it disagrees with the array or the
+/// array is too long for the count type.
+pub fn linked_count<C>(count: C, len: usize, name: &str) -> Result<C, WireError>
+where
+    C: Copy + Default + PartialEq + TryFrom<usize> + fmt::Display,
+{
+    let actual = C::try_from(len)
+        .map_err(|_| WireError::Invalid(format!("{len} elements do not fit in {name}")))?;
+    if count == C::default() || count == actual {
+        Ok(actual)
+    } else {
+        Err(WireError::Invalid(format!(
+            "{name} is {count}, but the array has {len} elements"
+        )))
+    }
+}
+
+/// Decodes a variable array whose length is carried by another field.
+///
+/// Unlike [`VariableSizeArray`]'s own [`VppDecode`], which reads to the end of
+/// the input, exactly `count` elements are read, so fields after the array
+/// decode as well.
+///
+/// # Arguments
+///
+/// * `input` - The encoded bytes, advanced past the array
+/// * `count` - The number of elements
+///
+/// # Returns
+///
+/// The array, or an error if the input ends before `count` elements.
+pub fn decode_counted<T: VppDecode>(
+    input: &mut &[u8],
+    count: usize,
+) -> Result<VariableSizeArray<T>, WireError> {
+    let mut items = Vec::with_capacity(count.min(input.len()));
+    for _ in 0..count {
+        items.push(T::vpp_decode(input)?);
+    }
+    Ok(VariableSizeArray(items))
+}
+
+/// Splits `len` bytes off the front of `input`.
+fn take<'a, T>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], WireError> {
+    if input.len() < len {
+        return Err(WireError::UnexpectedEnd {
+            type_name: std::any::type_name::<T>(),
+            needed: len,
+            remaining: input.len(),
+        });
+    }
+    let (head, rest) = input.split_at(len);
+    *input = rest;
+    Ok(head)
+}
+
+macro_rules! impl_wire_number {
+    ($($t:ty),*) => {$(
+        impl VppEncode for $t {
+            fn vpp_encode<B: BufMut>(&self, buf: &mut B) -> Result<(), WireError> {
+                buf.put_slice(&self.to_be_bytes());
+                Ok(())
+            }
+        }
+
+        impl VppDecode for $t {
+            fn vpp_decode(input: &mut &[u8]) -> Result<Self, WireError> {
+                let bytes = take::<$t>(input, std::mem::size_of::<$t>())?;
+                Ok(<$t>::from_be_bytes(bytes.try_into().unwrap()))
+            }
+        }
+    )*};
+}
+
+impl_wire_number!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+impl VppEncode for bool {
+    fn vpp_encode<B: BufMut>(&self, buf: &mut B) -> Result<(), WireError> {
+        buf.put_u8(u8::from(*self));
+        Ok(())
+    }
+}
+
+impl VppDecode for bool {
+    fn vpp_decode(input: &mut &[u8]) -> Result<Self, WireError> {
+        match u8::vpp_decode(input)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            v => Err(WireError::Invalid(format!("invalid bool value {v}"))),
+        }
+    }
+}
+
+impl<T: VppEncode, const N: usize> VppEncode for [T; N] {
+    fn vpp_encode<B: BufMut>(&self, buf: &mut B) -> Result<(), WireError> {
+        self.iter().try_for_each(|x| x.vpp_encode(buf))
+    }
+}
+
+impl<T: VppDecode, const N: usize> VppDecode for [T; N] {
+    fn vpp_decode(input: &mut &[u8]) -> Result<Self, WireError> {
+        let items = (0..N)
+            .map(|_| T::vpp_decode(input))
+            .collect::<Result<Vec<T>, _>>()?;
+        Ok(items.try_into().unwrap_or_else(|_| unreachable!()))
+    }
+}
+
+impl VppEncode for F64 {
+    fn vpp_encode<B: BufMut>(&self, buf: &mut B) -> Result<(), WireError> {
+        self.0.vpp_encode(buf)
+    }
+}
+
+impl VppDecode for F64 {
+    fn vpp_decode(input: &mut &[u8]) -> Result<Self, WireError> {
+        Ok(F64(f64::vpp_decode(input)?))
+    }
+}
+
+impl<N: ArrayLength> VppEncode for FixedSizeString<N> {
+    fn vpp_encode<B: BufMut>(&self, buf: &mut B) -> Result<(), WireError> {
+        buf.put_slice(&self.0);
+        Ok(())
+    }
+}
+
+impl<N: ArrayLength> VppDecode for FixedSizeString<N> {
+    fn vpp_decode(input: &mut &[u8]) -> Result<Self, WireError> {
+        let bytes = take::<Self>(input, N::USIZE)?;
+        Ok(FixedSizeString(GenericArray::from_slice(bytes).clone()))
+    }
+}
+
+impl VppEncode for VariableSizeString {
+    fn vpp_encode<B: BufMut>(&self, buf: &mut B) -> Result<(), WireError> {
+        let len = u32::try_from(self.0.len()).map_err(|_| {
+            WireError::Invalid(format!(
+                "string of {} bytes is too long to encode",
+                self.0.len()
+            ))
+        })?;
+        len.vpp_encode(buf)?;
+        buf.put_slice(&self.0);
+        Ok(())
+    }
+}
+
+impl VppDecode for VariableSizeString {
+    fn vpp_decode(input: &mut &[u8]) -> Result<Self, WireError> {
+        let len = u32::vpp_decode(input)? as usize;
+        Ok(VariableSizeString(take::<Self>(input, len)?.to_vec()))
+    }
+}
+
+impl<T: VppEncode + Default + fmt::Debug, N: ArrayLength> VppEncode for FixedSizeArray<T, N> {
+    fn vpp_encode<B: BufMut>(&self, buf: &mut B) -> Result<(), WireError> {
+        self.0.iter().try_for_each(|x| x.vpp_encode(buf))
+    }
+}
+
+impl<T: VppDecode + Default + fmt::Debug, N: ArrayLength> VppDecode for FixedSizeArray<T, N> {
+    fn vpp_decode(input: &mut &[u8]) -> Result<Self, WireError> {
+        let items = (0..N::USIZE)
+            .map(|_| T::vpp_decode(input))
+            .collect::<Result<Vec<T>, _>>()?;
+        Ok(FixedSizeArray(GenericArray::from_iter(items)))
+    }
+}
+
+impl<T: VppEncode> VppEncode for VariableSizeArray<T> {
+    fn vpp_encode<B: BufMut>(&self, buf: &mut B) -> Result<(), WireError> {
+        self.0.iter().try_for_each(|x| x.vpp_encode(buf))
+    }
+}
+
+/// Without a count field the array takes up the rest of the message, so elements
+/// are read until the input runs out. See [`decode_counted`] for arrays with one.
+/// An element that takes up no bytes would never let the input run out, so it
+/// is refused.
+impl<T: VppDecode> VppDecode for VariableSizeArray<T> {
+    fn vpp_decode(input: &mut &[u8]) -> Result<Self, WireError> {
+        let mut items = vec![];
+        while !input.is_empty() {
+            let remaining = input.len();
+            items.push(T::vpp_decode(input)?);
+            if input.len() == remaining {
+                return Err(WireError::Invalid(format!(
+                    "{} takes up no bytes, cannot fill the rest of the message",
+                    std::any::type_name::<T>()
+                )));
+            }
+        }
+        Ok(VariableSizeArray(items))
+    }
+}
+
+impl<T: AsEnumFlag> VppEncode for EnumFlag<T> {
+    fn vpp_encode<B: BufMut>(&self, buf: &mut B) -> Result<(), WireError> {
+        let bits = self.sized_bits().map_err(WireError::Invalid)?;
+        match T::size_of_enum_flag() {
+            32 => bits.vpp_encode(buf),
+            16 => (bits as u16).vpp_encode(buf),
+            _ => (bits as u8).vpp_encode(buf),
+        }
+    }
+}
+
+impl<T: AsEnumFlag> VppDecode for EnumFlag<T> {
+    fn vpp_decode(input: &mut &[u8]) -> Result<Self, WireError> {
+        let bits = match T::size_of_enum_flag() {
+            32 => u32::vpp_decode(input)?,
+            16 => u32::from(u16::vpp_decode(input)?),
+            8 => u32::from(u8::vpp_decode(input)?),
+            size => {
+                return Err(WireError::Invalid(format!(
+                    "EnumFlags do not support {size} bit type flag"
+                )));
+            }
+        };
+        Ok(EnumFlag::from_bits(bits))
+    }
+}
+
+impl<T: Copy + AsU32, X: VppEncode + TryFrom<u32>> VppEncode for SizedEnum<T, X> {
+    fn vpp_encode<B: BufMut>(&self, buf: &mut B) -> Result<(), WireError> {
+        let value = AsU32::as_u32(self.0);
+        X::try_from(value)
+            .map_err(|_| WireError::Invalid(format!("enum value {value} does not fit")))?
+            .vpp_encode(buf)
+    }
+}
+
+impl<T: AsU32, X: VppDecode + Into<u32>> VppDecode for SizedEnum<T, X> {
+    fn vpp_decode(input: &mut &[u8]) -> Result<Self, WireError> {
+        let value: u32 = X::vpp_decode(input)?.into();
+        T::from_u32(value).map(SizedEnum::new).ok_or_else(|| {
+            WireError::Invalid(format!(
+                "unknown value {value} for {}",
+                std::any::type_name::<T>()
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+    use typenum::{U4, U8};
+
+    #[derive(Clone, Debug, PartialEq, Serialize)]
+    enum TestFlags {
+        A = 1,
+        B = 0x100,
+    }
+
+    impl AsEnumFlag for TestFlags {
+        fn as_u32(data: &Self) -> u32 {
+            data.clone() as u32
+        }
+        fn from_u32(data: u32) -> Option<Self> {
+            match data {
+                1 => Some(TestFlags::A),
+                0x100 => Some(TestFlags::B),
+                _ => None,
+            }
+        }
+        fn size_of_enum_flag() -> u32 {
+            16
+        }
+        fn all_enum_flags() -> u32 {
+            0x101
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+    enum TestEnum {
+        Low = 1,
+        High = 0x80,
+    }
+
+    impl AsU32 for TestEnum {
+        fn as_u32(data: Self) -> u32 {
+            data as u32
+        }
+        fn from_u32(data: u32) -> Option<Self> {
+            match data {
+                1 => Some(TestEnum::Low),
+                0x80 => Some(TestEnum::High),
+                _ => None,
+            }
+        }
+    }
+
+    fn bincode<T: Serialize>(value: &T) -> Vec<u8> {
+        let config = bincode_next::config::legacy()
+            .with_big_endian()
+            .with_fixed_int_encoding();
+        bincode_next::serde::encode_to_vec(value, config).unwrap()
+    }
+
+    fn round_trip<T: VppEncode + VppDecode + Serialize + fmt::Debug>(value: T) -> T {
+        let encoded = encode_to_vec(&value).unwrap();
+        assert_eq!(encoded, bincode(&value), "{value:?}");
+        let (decoded, len) = decode_from_slice::<T>(&encoded).unwrap();
+        assert_eq!(len, encoded.len());
+        decoded
+    }
+
+    #[test]
+    fn test_same_layout_as_bincode() {
+        assert_eq!(round_trip(-2i32), -2);
+        assert_eq!(round_trip(0x1234u16), 0x1234);
+        assert_eq!(round_trip(1.5f64), 1.5);
+        assert_eq!(round_trip(F64(1.5)).0, 1.5);
+        assert!(round_trip(true));
+        assert_eq!(round_trip([1u8, 2, 3, 4]), [1, 2, 3, 4]);
+
+        let s: FixedSizeString<U8> = "eth0".try_into().unwrap();
+        assert_eq!(round_trip(s).to_string(), "eth0");
+        let s: VariableSizeString = "show version".try_into().unwrap();
+        assert_eq!(round_trip(s).to_string(), "show version");
+        let a: FixedSizeArray<u16, U4> = vec![1, 2].try_into().unwrap();
+        assert_eq!(round_trip(a).0.as_slice(), [1, 2, 0, 0]);
+        assert_eq!(round_trip(VariableSizeArray(vec![7u32, 8])).0, [7, 8]);
+        let flags: EnumFlag<TestFlags> = vec![TestFlags::A, TestFlags::B].try_into().unwrap();
+        assert!(round_trip(flags).contains(TestFlags::B));
+    }
+
+    #[test]
+    fn test_sized_enums_and_nested_arrays() {
+        let config = bincode_next::config::legacy()
+            .with_big_endian()
+            .with_fixed_int_encoding();
+        let value: SizedEnum<TestEnum, u8> = SizedEnum::new(TestEnum::High);
+        assert_eq!(encode_to_vec(&value).unwrap(), [0x80]);
+        assert_eq!(*round_trip(value).value(), TestEnum::High);
+        let (value, _): (SizedEnum<TestEnum, u16>, _) =
+            bincode_next::serde::decode_from_slice(&[0, 1], config).unwrap();
+        assert_eq!(*value.value(), TestEnum::Low);
+        assert!(decode_from_slice::<SizedEnum<TestEnum, u8>>(&[2]).is_err());
+        assert!(
+            bincode_next::serde::decode_from_slice::<SizedEnum<TestEnum, u8>, _>(&[2], config)
+                .is_err()
+        );
+
+        let inner: FixedSizeArray<u16, U4> = vec![1, 2, 3].try_into().unwrap();
+        let outer: FixedSizeArray<FixedSizeArray<u16, U4>, typenum::U2> =
+            vec![inner.clone(), inner].try_into().unwrap();
+        let encoded = bincode(&outer);
+        let (decoded, len): (FixedSizeArray<FixedSizeArray<u16, U4>, typenum::U2>, _) =
+            bincode_next::serde::decode_from_slice(&encoded, config).unwrap();
+        assert_eq!(len, 16);
+        assert_eq!(decoded.0[1].0.as_slice(), [1, 2, 3, 0]);
+        assert_eq!(round_trip(decoded).0[0].0.as_slice(), [1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn test_floats_in_network_order() {
+        let bytes = [0x3f, 0xf8, 0, 0, 0, 0, 0, 0];
+        assert_eq!(encode_to_vec(&1.5f64).unwrap(), bytes);
+        assert_eq!(encode_to_vec(&F64(1.5)).unwrap(), bytes);
+        assert_eq!(bincode(&F64(1.5)), bytes);
+        assert_eq!(decode_from_slice::<F64>(&bytes).unwrap().0.0, 1.5);
+    }
+
+    #[test]
+    fn test_counted_arrays() {
+        assert_eq!(linked_count(0u8, 3, "n").unwrap(), 3);
+        assert_eq!(linked_count(3u32, 3, "n").unwrap(), 3);
+        assert!(linked_count(2u32, 3, "n").is_err());
+        assert!(linked_count(0u8, 256, "n").is_err());
+
+        let mut input = &[0, 1, 0, 2, 0, 3][..];
+        let a: VariableSizeArray<u16> = decode_counted(&mut input, 2).unwrap();
+        assert_eq!(a.0, [1, 2]);
+        assert_eq!(input, [0, 3]);
+        assert!(decode_counted::<u16>(&mut &[0, 1][..], u32::MAX as usize).is_err());
+
+        // elements without bytes must not spin on the rest of the message
+        let empty =
+            decode_from_slice::<VariableSizeArray<FixedSizeArray<u8, typenum::U0>>>(&[1, 2]);
+        assert!(matches!(empty, Err(WireError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_decode_untrusted() {
+        assert!(matches!(
+            decode_from_slice::<u32>(&[1, 2]),
+            Err(WireError::UnexpectedEnd {
+                needed: 4,
+                remaining: 2,
+                ..
+            })
+        ));
+        // claims 4 GiB, carries three bytes
+        assert!(
+            decode_from_slice::<VariableSizeString>(&[0xff, 0xff, 0xff, 0xff, 1, 2, 3]).is_err()
+        );
+        assert!(decode_from_slice::<VariableSizeArray<u32>>(&[0, 0, 0, 1, 0, 0]).is_err());
+        assert!(decode_from_slice::<bool>(&[2]).is_err());
+        let (flags, _) = decode_from_slice::<EnumFlag<TestFlags>>(&[0, 2]).unwrap();
+        assert_eq!(encode_to_vec(&flags).unwrap(), [0, 2]);
+        assert!(encode_to_vec(&EnumFlag::<TestFlags>::from_bits(0x10000)).is_err());
+    }
+}
@@ -0,0 +1,50 @@
+use crate::interface::*;
+use futures::TryStreamExt;
+use vpp_api_transport::VppTransport;
+use vpp_api_transport::afunix;
+use vpp_api_transport::shmem::{blocking, non_blocking};
+
+async fn check_transport<C: VppTransport>(client: &mut C) {
+    let vl_msg_id_res = client.get_message_index("control_ping_51077d14");
+    assert_eq!(vl_msg_id_res.is_ok(), true);
+
+    let retval = client.control_ping().await.unwrap();
+    assert_eq!(retval, 0);
+
+    let version = client.run_cli_inband("show version").await.unwrap();
+    assert!(version.starts_with("vpp "));
+
+    let interfaces: Vec<SwInterfaceDetails> = client
+        .send_dump(SwInterfaceDump {
+            client_index: 0,
+            context: 0,
+            sw_if_index: u32::MAX,
+            name_filter_valid: false,
+            name_filter: "".try_into().unwrap(),
+        })
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(!interfaces.is_empty());
+}
+
+#[tokio::test]
+async fn test_transport_afunix() {
+    let mut client = afunix::client::Client::connect("/run/vpp/api.sock", "api-test").await.unwrap();
+    check_transport(&mut client).await;
+    client.disconnect().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_transport_blocking() {
+    let mut client = blocking::Client::connect("test_blocking_client", None, 32, 2).await.unwrap();
+    check_transport(&mut client).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_transport_nonblocking() {
+    let mut client = non_blocking::Client::connect("test_non_blocking_client", None, 32).await.unwrap();
+    check_transport(&mut client).await;
+}
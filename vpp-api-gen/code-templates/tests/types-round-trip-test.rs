@@ -0,0 +1,180 @@
+use crate::ethernet_types::*;
+use crate::fib_types::*;
+use crate::ip_types::*;
+use crate::mactime::*;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use vpp_api_encoding::wire::{decode_from_slice, encode_to_vec, VppDecode, VppEncode};
+
+/// Round-trips a value through bincode and through the wire codec.
+///
+/// Both have to produce the same bytes, take all of them up when decoding,
+/// and encode what they decoded to the same bytes again.
+fn assert_round_trip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + VppEncode + VppDecode,
+{
+    let name = std::any::type_name::<T>();
+    let config = bincode_next::config::legacy()
+        .with_big_endian()
+        .with_fixed_int_encoding();
+    let encoded = bincode_next::serde::encode_to_vec(value, config).unwrap();
+    assert_eq!(encode_to_vec(value).unwrap(), encoded, "{name}");
+
+    let (decoded, len): (T, usize) =
+        bincode_next::serde::decode_from_slice(&encoded, config).unwrap();
+    assert_eq!(len, encoded.len(), "{name}");
+    assert_eq!(
+        bincode_next::serde::encode_to_vec(&decoded, config).unwrap(),
+        encoded,
+        "{name}"
+    );
+
+    let (decoded, len) = decode_from_slice::<T>(&encoded).unwrap();
+    assert_eq!(len, encoded.len(), "{name}");
+    assert_eq!(encode_to_vec(&decoded).unwrap(), encoded, "{name}");
+}
+
+fn ip4() -> AddressUnion {
+    AddressUnion::new_Ip4Address([192, 0, 2, 1])
+}
+
+fn ip6() -> AddressUnion {
+    AddressUnion::new_Ip6Address([0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])
+}
+
+#[test]
+fn test_ip_types_round_trip() {
+    let address = Address {
+        af: AddressFamily::ADDRESS_IP6,
+        un: ip6(),
+    };
+    assert_round_trip(&address);
+    assert_round_trip(&Prefix { address, len: 64 });
+    assert_round_trip(&Ip4AddressAndMask {
+        addr: [10, 1, 2, 3],
+        mask: [255, 255, 0, 0],
+    });
+    assert_round_trip(&Ip6AddressAndMask {
+        addr: [0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+        mask: [0xff; 16],
+    });
+    assert_round_trip(&Mprefix {
+        af: AddressFamily::ADDRESS_IP4,
+        grp_address_length: 32,
+        grp_address: AddressUnion::new_Ip4Address([239, 1, 1, 1]),
+        src_address: ip4(),
+    });
+    assert_round_trip(&Ip6Prefix {
+        address: [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        len: 32,
+    });
+    assert_round_trip(&Ip4Prefix {
+        address: [172, 16, 0, 0],
+        len: 12,
+    });
+    assert_round_trip(&PrefixMatcher { le: 24, ge: 16 });
+    assert_round_trip(&ip4());
+    assert_round_trip(&ip6());
+
+    assert_round_trip(&AddressFamily::ADDRESS_IP6);
+    assert_round_trip(&IpFeatureLocation::IP_API_FEATURE_DROP);
+    assert_round_trip(&IpEcn::IP_API_ECN_CE);
+    assert_round_trip(&IpDscp::IP_API_DSCP_CS1);
+    assert_round_trip(&IpProto::IP_API_PROTO_UDP);
+}
+
+#[test]
+fn test_enums_serialize_as_integers() {
+    assert_eq!(serde_json::to_string(&AddressFamily::ADDRESS_IP6).unwrap(), "1");
+    assert_eq!(serde_json::to_string(&AddressFamily::Unknown(7)).unwrap(), "7");
+    assert_eq!(
+        serde_json::from_str::<AddressFamily>("1").unwrap(),
+        AddressFamily::ADDRESS_IP6
+    );
+    assert_eq!(
+        serde_json::from_str::<AddressFamily>("7").unwrap(),
+        AddressFamily::Unknown(7)
+    );
+    assert!(serde_json::to_string(&AddressFamily::Unknown(256)).is_err());
+    assert_eq!(
+        serde_json::to_string(&FibPathType::FIB_API_PATH_TYPE_UDP_ENCAP).unwrap(),
+        "3"
+    );
+    assert_eq!(
+        serde_json::from_str::<FibPathType>("70000").unwrap(),
+        FibPathType::Unknown(70000)
+    );
+    assert_round_trip(&AddressFamily::Unknown(7));
+}
+
+#[test]
+fn test_fib_types_round_trip() {
+    let label = FibMplsLabel {
+        is_uniform: 1,
+        label: 0x12345,
+        ttl: 64,
+        exp: 3,
+    };
+    assert_round_trip(&label);
+    let nh = FibPathNh {
+        address: ip4(),
+        via_label: 16,
+        obj_id: 7,
+        classify_table_index: u32::MAX,
+    };
+    assert_round_trip(&nh);
+    assert_round_trip(&FibPath {
+        sw_if_index: 2,
+        table_id: 10,
+        rpf_id: 3,
+        weight: 1,
+        preference: 5,
+        typ: FibPathType::FIB_API_PATH_TYPE_UDP_ENCAP,
+        flags: FibPathFlags::FIB_API_PATH_FLAG_RESOLVE_VIA_HOST
+            | FibPathFlags::FIB_API_PATH_FLAG_POP_PW_CW,
+        proto: FibPathNhProto::FIB_API_PATH_NH_PROTO_MPLS,
+        nh,
+        n_labels: 2,
+        label_stack: vec![label.clone(), label].try_into().unwrap(),
+    });
+
+    assert_round_trip(&FibPathNhProto::FIB_API_PATH_NH_PROTO_BIER);
+    assert_round_trip(&FibPathType::FIB_API_PATH_TYPE_CLASSIFY);
+    assert_round_trip(&EnumFlag::from(
+        FibPathFlags::FIB_API_PATH_FLAG_RESOLVE_VIA_ATTACHED,
+    ));
+    assert_round_trip(&EnumFlag::<FibPathFlags>::all());
+
+    let flags = EnumFlag::<FibPathFlags>::from_bits(0x82);
+    assert_eq!(
+        flags.iter().collect::<Vec<_>>(),
+        [
+            FibPathFlags::FIB_API_PATH_FLAG_RESOLVE_VIA_HOST,
+            FibPathFlags::Unknown(0x80)
+        ]
+    );
+    assert!(EnumFlag::empty().contains(FibPathFlags::FIB_API_PATH_FLAG_NONE));
+}
+
+#[test]
+fn test_ethernet_types_round_trip() {
+    let mac: MacAddress = [0x02, 0xfe, 0x12, 0x34, 0x56, 0x78];
+    assert_round_trip(&mac);
+    let macs: FixedSizeArray<MacAddress, typenum::U2> = vec![mac, [0xff; 6]].try_into().unwrap();
+    assert_round_trip(&macs);
+}
+
+#[test]
+fn test_floats_round_trip() {
+    let range = TimeRange {
+        start: 1.5,
+        end: -0.25,
+    };
+    assert_round_trip(&range);
+    assert_eq!(
+        encode_to_vec(&range).unwrap(),
+        [0x3f, 0xf8, 0, 0, 0, 0, 0, 0, 0xbf, 0xd0, 0, 0, 0, 0, 0, 0]
+    );
+    assert_round_trip(&F64(1.5));
+}
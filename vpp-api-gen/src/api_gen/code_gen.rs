@@ -134,10 +134,144 @@ pub fn generate_mod_file(
     for name in names_vec {
         code.push_str(&format!("pub mod {};\n", name));
     }
+
+    let mut messages: Vec<String> = api_files
+        .values()
+        .flat_map(|f| f.messages.iter())
+        .map(|m| format!("{}_{}", m.name, m.info.crc.trim_start_matches("0x")))
+        .collect();
+    messages.sort();
+    messages.dedup();
+    code.push_str("\n/// The name and CRC of every message in these bindings, as used in the VPP message table.\n");
+    code.push_str("pub const MESSAGE_NAMES_AND_CRCS: &[&str] = &[\n");
+    for message in messages {
+        code.push_str(&format!("\t\"{}\",\n", message));
+    }
+    code.push_str("];\n");
+
+    let mut visited: Vec<String> = vec![];
+    code.push_str(
+        "\n/// Calls `visitor` with the type of the message that has the given name and CRC.\n",
+    );
+    code.push_str("///\n/// Returns `None` if the message is not part of these bindings.\n");
+    code.push_str("pub fn visit_message<V: vpp_api_message::VppApiMessageVisitor>(name_crc: &str, visitor: V) -> Option<V::Output> {\n");
+    code.push_str("\tlet output = match name_crc {\n");
+    for (name, f) in api_files {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"/[a-z_0-9]*.api.json").unwrap();
+        }
+        let module = RE
+            .find(name)
+            .unwrap()
+            .as_str()
+            .trim_end_matches(".api.json")
+            .trim_start_matches("/");
+        for m in &f.messages {
+            let name_crc = format!("{}_{}", m.name, m.info.crc.trim_start_matches("0x"));
+            if visited.contains(&name_crc) {
+                continue;
+            }
+            code.push_str(&format!(
+                "\t\t\"{}\" => visitor.visit::<{}::{}>(),\n",
+                name_crc,
+                module,
+                camelize_ident(&m.name)
+            ));
+            visited.push(name_crc);
+        }
+    }
+    code.push_str("\t\t_ => return None,\n\t};\n\tSome(output)\n}\n");
+    code.push_str("\npub use vpp_api_transport::VppApiError;\n");
     let mut file = File::create(format!("{}/{}/src/mod.rs", package_path, package_name)).unwrap();
     file.write_all(code.as_bytes()).unwrap();
     // println!("{}", code);
 }
+/// Generates `api_errno.rs` with the `VppApiError` enum from a list of VPP error codes.
+///
+/// `errno_file` holds the `foreach_vnet_api_error` macro of VPP, one
+/// `_(NAME, retval, "description")` entry per line. The error codes are the
+/// same for every VPP version, so the enum lives in the transport, which
+/// reports them, and the generated packages re-export it.
+pub fn generate_errno_file(errno_file: &str, out_file: &str) {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r#"_\((\w+),\s*(-?\d+),\s*"([^"]*)"\)"#).unwrap();
+    }
+    let data = fs::read_to_string(errno_file)
+        .unwrap_or_else(|_| panic!("Could not read errno file {}", errno_file));
+    // (NAME, Variant, retval, description)
+    let errors: Vec<(String, String, String, String)> = RE
+        .captures_iter(&data)
+        .map(|c| {
+            (
+                c[1].to_string(),
+                camelize_ident(&c[1].to_lowercase()),
+                c[2].to_string(),
+                c[3].to_string(),
+            )
+        })
+        .collect();
+
+    let mut code = String::new();
+    code.push_str(
+        "/// An error VPP reports in the `retval` of a reply, as listed in `vnet/api_errno.h`.\n",
+    );
+    code.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+    code.push_str("pub enum VppApiError {\n");
+    for (_, variant, _, description) in &errors {
+        code.push_str(&format!("\t/// {}\n\t{},\n", description, variant));
+    }
+    code.push_str("\t/// A negative retval these bindings do not know.\n\tUnknown(i32),\n}\n\n");
+
+    code.push_str("impl VppApiError {\n");
+    code.push_str("\t/// Returns the error a `retval` stands for, or `None` if it does not signal an error.\n");
+    code.push_str(
+        "\tpub fn from_retval(retval: i32) -> Option<VppApiError> {\n\t\tmatch retval {\n",
+    );
+    code.push_str("\t\t\t0.. => None,\n");
+    for (_, variant, retval, _) in &errors {
+        code.push_str(&format!(
+            "\t\t\t{} => Some(VppApiError::{}),\n",
+            retval, variant
+        ));
+    }
+    code.push_str("\t\t\t_ => Some(VppApiError::Unknown(retval)),\n\t\t}\n\t}\n\n");
+
+    code.push_str("\t/// Returns the `retval` VPP uses for the error.\n");
+    code.push_str("\tpub fn retval(&self) -> i32 {\n\t\tmatch self {\n");
+    for (_, variant, retval, _) in &errors {
+        code.push_str(&format!("\t\t\tVppApiError::{} => {},\n", variant, retval));
+    }
+    code.push_str("\t\t\tVppApiError::Unknown(retval) => *retval,\n\t\t}\n\t}\n\n");
+
+    code.push_str("\t/// Returns the name of the error in VPP, e.g. `INVALID_SW_IF_INDEX`.\n");
+    code.push_str("\tpub fn name(&self) -> &'static str {\n\t\tmatch self {\n");
+    for (name, variant, _, _) in &errors {
+        code.push_str(&format!(
+            "\t\t\tVppApiError::{} => \"{}\",\n",
+            variant, name
+        ));
+    }
+    code.push_str("\t\t\tVppApiError::Unknown(_) => \"UNKNOWN\",\n\t\t}\n\t}\n\n");
+
+    code.push_str("\t/// Returns the description VPP gives for the error.\n");
+    code.push_str("\tpub fn description(&self) -> &'static str {\n\t\tmatch self {\n");
+    for (_, variant, _, description) in &errors {
+        code.push_str(&format!(
+            "\t\t\tVppApiError::{} => \"{}\",\n",
+            variant, description
+        ));
+    }
+    code.push_str("\t\t\tVppApiError::Unknown(_) => \"Unknown error\",\n\t\t}\n\t}\n}\n\n");
+
+    code.push_str("impl std::fmt::Display for VppApiError {\n");
+    code.push_str("\tfn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n");
+    code.push_str("\t\twrite!(f, \"{} ({}, retval {})\", self.description(), self.name(), self.retval())\n\t}\n}\n\n");
+    code.push_str("impl std::error::Error for VppApiError {}\n");
+
+    let mut file = File::create(out_file)
+        .unwrap_or_else(|_| panic!("Could not create errno file {}", out_file));
+    file.write_all(code.as_bytes()).unwrap();
+}
 pub fn copy_file_with_fixup(
     package_path: &str,
     example_file: &str,
@@ -149,54 +149,135 @@ impl VppJsApiEnum {
         }
         false
     }
-    pub fn generate_as_enumflag_trait(&self) -> String {
+    /// Returns the integer type the enum is carried as on the wire.
+    pub fn repr(&self) -> &str {
+        self.info.enumtype.as_deref().unwrap_or("u32")
+    }
+    /// Returns the discriminant to give the `Unknown` variant, if the one the
+    /// compiler would pick does not fit the repr or is taken by another variant.
+    fn unknown_discriminant(&self) -> Option<i64> {
+        let max = match self.repr() {
+            "u8" => u8::MAX as i64,
+            "u16" => u16::MAX as i64,
+            _ => u32::MAX as i64,
+        };
+        let taken = |value: i64| self.values.iter().any(|v| v.value == value);
+        let implicit = self.values.last().map(|v| v.value + 1).unwrap_or(0);
+        if implicit <= max && !taken(implicit) {
+            None
+        } else {
+            (0..=max).find(|value| !taken(*value))
+        }
+    }
+    pub fn generate_as_u32_trait(&self) -> String {
+        let name = camelize_ident(&self.name);
         let mut code = String::new();
-        code.push_str(&format!(
-            "impl AsEnumFlag for {} {{\n",
-            camelize_ident(&self.name)
-        ));
-        code.push_str("\t fn as_u32(data: &Self) -> u32{\n");
-        code.push_str("\t\t *data as u32\n");
+        code.push_str(&format!("impl AsU32 for {} {{\n", name));
+        code.push_str("\t fn as_u32(data: Self) -> u32{\n");
+        code.push_str("\t\t match data{\n");
+        for x in 0..self.values.len() {
+            code.push_str(&format!(
+                "\t\t\t {}::{} => {},\n",
+                name,
+                get_ident(&self.values[x].name),
+                self.values[x].value
+            ));
+        }
+        code.push_str(&format!("\t\t\t {}::Unknown(value) => value,\n", name));
+        code.push_str("\t\t }\n");
         code.push_str("\t }\n");
-        code.push_str("\t fn from_u32(data: u32) -> Self{\n");
+        code.push_str("\t fn from_u32(data: u32) -> Option<Self>{\n");
         code.push_str("\t\t match data{\n");
         for x in 0..self.values.len() {
             code.push_str(&format!(
-                "\t\t\t {} => {}::{},\n",
+                "\t\t\t {} => Some({}::{}),\n",
                 self.values[x].value,
-                camelize_ident(&self.name),
+                name,
                 get_ident(&self.values[x].name)
             ));
         }
-        code.push_str("\t\t\t_ => panic!(\"Invalid Enum Descriminant\")\n");
+        code.push_str(&format!(
+            "\t\t\t value => Some({}::Unknown(value)),\n",
+            name
+        ));
         code.push_str("\t\t }\n");
         code.push_str("\t }\n");
-        code.push_str("\t fn size_of_enum_flag() -> u32{\n");
-        match &self.info.enumtype {
-            Some(len) => code.push_str(&format!("\t\t {} as u32\n", len.trim_start_matches("u"))),
-            _ => code.push_str("\t\t 32 as u32\n"),
+        code.push_str("}\n");
+        code
+    }
+    /// Serializes the enum as a plain integer of its repr, the same way the wire
+    /// encoding does, e.g. `1` in JSON; `Unknown` carries its raw value.
+    pub fn generate_serde(&self) -> String {
+        let name = camelize_ident(&self.name);
+        let repr = self.repr();
+        let mut code = String::new();
+        code.push_str(&format!("impl Serialize for {} {{\n", name));
+        code.push_str("\t fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{\n");
+        code.push_str("\t\t let value = <Self as AsU32>::as_u32(*self);\n");
+        if repr != "u32" {
+            code.push_str(&format!(
+                "\t\t let value: {} = value.try_into().map_err(|_| serde::ser::Error::custom(format!(\"{} value {{}} does not fit {}\", value)))?;\n",
+                repr, name, repr
+            ));
         }
+        code.push_str(&format!("\t\t serializer.serialize_{}(value)\n", repr));
+        code.push_str("\t }\n");
+        code.push_str("}\n");
+        code.push_str(&format!("impl<'de> Deserialize<'de> for {} {{\n", name));
+        code.push_str("\t fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>{\n");
+        code.push_str(&format!(
+            "\t\t let value = u32::from({}::deserialize(deserializer)?);\n",
+            repr
+        ));
+        code.push_str(&format!(
+            "\t\t Ok(<Self as AsU32>::from_u32(value).unwrap_or({}::Unknown(value)))\n",
+            name
+        ));
+        code.push_str("\t }\n");
+        code.push_str("}\n");
+        code
+    }
+    pub fn generate_as_enumflag_trait(&self) -> String {
+        let mut code = String::new();
+        code.push_str(&format!(
+            "impl AsEnumFlag for {} {{\n",
+            camelize_ident(&self.name)
+        ));
+        code.push_str("\t fn as_u32(data: &Self) -> u32{\n");
+        code.push_str("\t\t <Self as AsU32>::as_u32(*data)\n");
+        code.push_str("\t }\n");
+        code.push_str("\t fn from_u32(data: u32) -> Option<Self>{\n");
+        code.push_str("\t\t <Self as AsU32>::from_u32(data)\n");
+        code.push_str("\t }\n");
+        code.push_str("\t fn size_of_enum_flag() -> u32{\n");
+        code.push_str(&format!(
+            "\t\t {} as u32\n",
+            self.repr().trim_start_matches("u")
+        ));
+        code.push_str("\t}\n");
+        code.push_str("\t fn all_enum_flags() -> u32{\n");
+        let all = self.values.iter().fold(0, |acc, v| acc | v.value);
+        code.push_str(&format!("\t\t {:#x}\n", all));
         code.push_str("\t}\n");
         code.push_str("}\n");
+        // lets flags be combined into a set, IF_STATUS_API_FLAG_ADMIN_UP | IF_STATUS_API_FLAG_LINK_UP
+        code.push_str(&format!(
+            "impl std::ops::BitOr for {} {{\n",
+            camelize_ident(&self.name)
+        ));
+        code.push_str("\t type Output = EnumFlag<Self>;\n");
+        code.push_str("\t fn bitor(self, other: Self) -> EnumFlag<Self>{\n");
+        code.push_str("\t\t EnumFlag::from(self) | other\n");
+        code.push_str("\t }\n");
+        code.push_str("}\n");
         code
     }
     pub fn generate_code(&self) -> String {
         let mut code = String::new();
-        if self.if_flag() {
-            // This tells if the enum is a flag or not
-            code.push_str("#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]\n");
-            /* match &self.info.enumtype {
-                Some(len) => code.push_str(&format!("#[repr({})]\n", &len)),
-                _ => code.push_str(&format!("#[repr({})]\n")),
-            }*/
-        } else {
-            // This tells if the enum is a flag or not
-            code.push_str("#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]\n");
-            match &self.info.enumtype {
-                Some(len) => code.push_str(&format!("#[repr({})]\n", &len)),
-                _ => code.push_str("#[repr(u32)]\n"),
-            }
-        }
+        // values a newer VPP adds end up in Unknown, so they survive a round trip
+        code.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, VppEncode, VppDecode)]\n");
+        // the wire encoding writes the enum as its repr
+        code.push_str(&format!("#[repr({})]\n", self.repr()));
         code.push_str(&format!("pub enum {} {{\n", camelize_ident(&self.name)));
         for x in 0..self.values.len() {
             code.push_str(&format!(
@@ -205,9 +286,14 @@ impl VppJsApiEnum {
                 self.values[x].value
             ));
         }
-        // code.push_str("\t #[serde(other)]\n\t Invalid\n");
+        match self.unknown_discriminant() {
+            Some(value) => code.push_str(&format!("\t Unknown(u32)={},\n", value)),
+            None => code.push_str("\t Unknown(u32),\n"),
+        }
         code.push_str("}\n");
         code.push_str(&self.impl_default());
+        code.push_str(&self.generate_as_u32_trait());
+        code.push_str(&self.generate_serde());
         if self.if_flag() {
             code.push_str(&self.generate_as_enumflag_trait());
         }
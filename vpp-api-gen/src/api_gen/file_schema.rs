@@ -101,13 +101,12 @@ impl VppJsApiFile {
         let mut preamble: String = String::new();
         preamble.push_str(&VppJsApiFile::generate_header());
         preamble.push_str("#![allow(dead_code,unused_mut,unused_variables,unused_must_use,non_camel_case_types,unused_imports,non_snake_case)]\n");
-        preamble.push_str("use vpp_api_macros::{VppMessage,VppUnionIdent};\n");
+        preamble.push_str("use vpp_api_macros::{VppMessage,VppUnionIdent,VppEncode,VppDecode};\n");
         preamble.push_str("use std::convert::TryInto;\n");
         preamble.push_str("use serde::{de::DeserializeOwned, Deserialize, Serialize};\n");
         preamble.push_str("pub use vpp_api_encoding::typ::*;\n");
         preamble.push_str("pub use vpp_api_encoding;\n");
         preamble.push_str("use vpp_api_message::VppApiMessage;\n");
-        preamble.push_str("use serde_repr::{Serialize_repr, Deserialize_repr};\n");
         preamble.push_str("use typenum;\n");
         let mut import_table: Vec<(String, Vec<String>)> = vec![];
         let typstructs = VppJsApiType::iter_and_generate_code(
@@ -159,6 +158,27 @@ impl VppJsApiFile {
         preamble.push_str(&typenumflags);
         preamble.push_str(&typalias);
         preamble.push_str(&typmessage);
+        preamble.push_str(&self.generate_message_list());
         preamble
     }
+
+    /// Generates `MESSAGES`, the name and CRC of every message of the file in declaration order.
+    ///
+    /// VPP numbers the messages of a module in this order, starting at the
+    /// first message id of the module, so the list is what a client needs to
+    /// move the module to the ids `get_first_msg_id` reports.
+    fn generate_message_list(&self) -> String {
+        let mut code = String::new();
+        code.push_str("/// The name and CRC of every message of this module, in the order VPP numbers them.\n");
+        code.push_str("pub const MESSAGES: &[&str] = &[\n");
+        for m in &self.messages {
+            code.push_str(&format!(
+                "\t\"{}_{}\",\n",
+                m.name,
+                m.info.crc.trim_start_matches("0x")
+            ));
+        }
+        code.push_str("];\n");
+        code
+    }
 }
@@ -92,7 +92,9 @@ impl<'de> Deserialize<'de> for VppJsApiMessage {
 impl VppJsApiMessage {
     pub fn generate_code(&self) -> String {
         let mut code = String::new();
-        code.push_str("#[derive(Debug, Clone, Serialize, Deserialize, VppMessage)]\n");
+        code.push_str(
+            "#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, VppMessage)]\n",
+        );
         code.push_str(&format!(
             "#[message_name_and_crc({}_{})]\n",
             self.name,
@@ -126,6 +128,7 @@ impl VppJsApiMessage {
                     get_type(&self.fields[x].ctype)
                 ));
             } else {
+                code.push_str(&self.fields[x].count_attribute());
                 code.push_str(&format!("\tpub {}: ", get_ident(&self.fields[x].name)));
                 match &self.fields[x].maybe_size {
                     Some(cont) => match cont {
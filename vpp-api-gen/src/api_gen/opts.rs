@@ -41,6 +41,11 @@ pub struct Opts {
     #[clap(long, default_value = "../")]
     pub package_path: String,
 
+    /// Where to write api_errno.rs with the VppApiError enum, e.g.
+    /// vpp-api-transport/src/api_errno.rs; it is not written if not given
+    #[clap(long)]
+    pub errno_file: Option<String>,
+
     /// Print message names
     #[clap(long)]
     pub print_message_names: bool,
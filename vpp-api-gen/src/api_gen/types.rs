@@ -70,13 +70,16 @@ impl VppJsApiType {
     pub fn generate_code(&self) -> String {
         let mut code = String::new();
         code.push_str(&format!("// Implementation for {}\n", &self.type_name));
-        code.push_str("#[derive(Debug, Clone, Serialize, Deserialize, Default)]\n");
+        code.push_str(
+            "#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]\n",
+        );
         code.push_str(&format!(
             "pub struct {} {{\n",
             camelize_ident(&self.type_name)
         ));
         for x in 0..self.fields.len() {
             // println!("{:#?}", self.fields);
+            code.push_str(&self.fields[x].count_attribute());
             code.push_str(&format!("\tpub {}: ", get_ident(&self.fields[x].name)));
             if self.fields[x].ctype == "string" {
                 match &self.fields[x].maybe_size {
@@ -113,7 +116,9 @@ impl VppJsApiType {
     }
     pub fn generate_code_union(&self, apifile: &VppJsApiFile) -> String {
         let mut code = String::new();
-        code.push_str("#[derive(Debug, Clone, Serialize, Deserialize, Default, VppUnionIdent)]\n");
+        code.push_str(
+            "#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default, VppUnionIdent)]\n",
+        );
         for x in 0..self.fields.len() {
             let size_of_typ = field_size(&self.fields[x], apifile);
             let ident = get_type(&self.fields[x].ctype);
@@ -231,6 +236,19 @@ pub struct VppJsApiMessageFieldDef {
     pub maybe_options: Option<VppJsApiFieldOptions>,
 }
 
+impl VppJsApiMessageFieldDef {
+    /// Returns the `#[vpp(count = "...")]` attribute tying a variable array to
+    /// the field that carries its length, or nothing for other fields.
+    pub fn count_attribute(&self) -> String {
+        match &self.maybe_size {
+            Some(VppJsApiFieldSize::Variable(Some(count))) if self.ctype != "string" => {
+                format!("\t#[vpp(count = \"{}\")]\n", get_ident(count))
+            }
+            _ => String::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum VppJsApiMessageFieldHelper {
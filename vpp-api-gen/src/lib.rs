@@ -13,7 +13,9 @@ use crate::api_gen::util::ImportsFiles;
 use crate::api_gen::util::merge_sort;
 use crate::file_schema::VppJsApiFile;
 use crate::parser_helper::*;
-use api_gen::code_gen::{copy_file_with_fixup, gen_code, gen_code_file, generate_mod_file};
+use api_gen::code_gen::{
+    copy_file_with_fixup, gen_code, gen_code_file, generate_errno_file, generate_mod_file,
+};
 use std::fs;
 
 pub fn parse_type_file(opts: &Opts, data: &str) {
@@ -109,6 +111,12 @@ pub fn parse_type_tree(opts: &Opts) {
             }
         }
     }
+    if let Some(errno_file) = &opts.errno_file {
+        generate_errno_file(
+            &format!("{}/code-templates/api_errno.h", env!("CARGO_MANIFEST_DIR")),
+            errno_file,
+        );
+    }
     if opts.create_package {
         // println!("{}", opts.package_name);
         let mut api_definition: Vec<(String, String)> = vec![];
@@ -155,6 +163,36 @@ pub fn parse_type_tree(opts: &Opts) {
             &opts.package_name,
             "tests/nonblocking_interface_test.rs",
         );
+        copy_file_with_fixup(
+            &opts.package_path,
+            &format!(
+                "{}/code-templates/tests/transport-interface-test.rs",
+                crate_dir
+            ),
+            &opts.package_name,
+            "tests/transport_interface_test.rs",
+        );
+        copy_file_with_fixup(
+            &opts.package_path,
+            &format!("{}/code-templates/tests/fake-interface-test.rs", crate_dir),
+            &opts.package_name,
+            "tests/fake_interface_test.rs",
+        );
+        copy_file_with_fixup(
+            &opts.package_path,
+            &format!("{}/code-templates/tests/wire-encoding-test.rs", crate_dir),
+            &opts.package_name,
+            "tests/wire_encoding_test.rs",
+        );
+        copy_file_with_fixup(
+            &opts.package_path,
+            &format!(
+                "{}/code-templates/tests/types-round-trip-test.rs",
+                crate_dir
+            ),
+            &opts.package_name,
+            "tests/types_round_trip_test.rs",
+        );
         copy_file_with_fixup(
             &opts.package_path,
             &format!("{}/code-templates/examples/progressive-vpp.rs", crate_dir),
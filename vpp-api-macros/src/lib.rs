@@ -2,7 +2,7 @@ extern crate proc_macro;
 use proc_macro2::TokenStream;
 use proc_macro2::TokenTree;
 use quote::ToTokens;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{DeriveInput, parse_macro_input};
 
 #[proc_macro_derive(VppMessage, attributes(message_name_and_crc))]
@@ -96,6 +96,42 @@ pub fn derive_message(input: proc_macro::TokenStream) -> proc_macro::TokenStream
         })
         .collect();
 
+    let field_named = |field_name: &str| {
+        fields
+            .iter()
+            .find(|f| f.ident.clone().map(|i| i.to_string()).unwrap_or("".into()) == field_name)
+    };
+    let enable_field = field_named("enable_disable").or_else(|| field_named("enable"));
+    let want_impl = match (enable_field, field_named("pid")) {
+        (Some(enable_field), Some(_)) => {
+            let enable_ident = &enable_field.ident;
+            quote! {
+                impl vpp_api_message::VppApiWantMessage for #name {
+                    fn set_enable(&mut self, enable: bool) {
+                        self.#enable_ident = enable.into();
+                    }
+                    fn set_pid(&mut self, pid: u32) {
+                        self.pid = pid;
+                    }
+                }
+            }
+        }
+        _ => quote! {},
+    };
+
+    // a few messages carry an unsigned `retval` that is not an error code
+    let is_i32 = |f: &syn::Field| matches!(&f.ty, syn::Type::Path(tp) if tp.path.is_ident("i32"));
+    let reply_impl = match field_named("retval").filter(|f| is_i32(f)) {
+        Some(_) => quote! {
+            impl vpp_api_message::VppApiReply for #name {
+                fn retval(&self) -> i32 {
+                    self.retval
+                }
+            }
+        },
+        None => quote! {},
+    };
+
     let builder_ident = syn::Ident::new(&format!("Builder{}", name), name.span());
     let expanded = quote! {
          pub struct #builder_ident{
@@ -120,6 +156,8 @@ pub fn derive_message(input: proc_macro::TokenStream) -> proc_macro::TokenStream
                 #(#client_index_setter)*
             }
          }
+         #want_impl
+         #reply_impl
          impl #name {
             pub fn get_message_name_and_crc() -> String {
                  String::from(#ident)
@@ -217,9 +255,8 @@ pub fn derive_unionident(input: proc_macro::TokenStream) -> proc_macro::TokenStr
         quote! {
                 pub fn #function_name_new_ident(some: #ident) -> #name{
                     let mut arr: Vec<u8> = vec![0;#maxsize_literal];
-                    let encoder = bincode_next::config::legacy();
                     let some_arr: Vec<u8> =
-                        bincode_next::serde::encode_to_vec(&some, encoder).unwrap();
+                        ::vpp_api_encoding::wire::encode_to_vec(&some).unwrap();
                     for x in 0..#liter{
                         arr[x] = some_arr[x];
                     }
@@ -229,13 +266,9 @@ pub fn derive_unionident(input: proc_macro::TokenStream) -> proc_macro::TokenStr
                     let mut some_arr: Vec<u8> = bincode::serialize(&some).unwrap();
                     self.0.0[0..#liter].clone_from_slice(&some);
                 } */
-                pub fn #function_name_get_ident(&self) -> #ident{
-                    let some = self.0.0.clone();
-                    let mut someIdent: Vec<u8> = vec![0;#liter];
-                    someIdent.clone_from_slice(&some[0..#liter]);
-                    let encoder = bincode_next::config::legacy();
-                    let (decoded, _): (#ident, usize) = bincode_next::serde::decode_from_slice(&someIdent, encoder).unwrap();
-                    decoded
+                pub fn #function_name_get_ident(&self) -> Result<#ident, ::vpp_api_encoding::wire::WireError>{
+                    let (decoded, _): (#ident, usize) = ::vpp_api_encoding::wire::decode_from_slice(&self.0.0[0..#liter])?;
+                    Ok(decoded)
                 }
         }
     });
@@ -250,3 +283,303 @@ pub fn derive_unionident(input: proc_macro::TokenStream) -> proc_macro::TokenStr
     };
     expanded.into()
 }
+
+/// Returns the type a fieldless enum is represented as, from its `#[repr]`, `u32` if there is none.
+fn enum_repr(input: &DeriveInput) -> syn::Ident {
+    input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+        .find_map(|attr| attr.parse_args::<syn::Ident>().ok())
+        .unwrap_or_else(|| syn::Ident::new("u32", proc_macro2::Span::call_site()))
+}
+
+/// The variants of an enum the wire encoding supports.
+struct EnumVariants<'a> {
+    /// The fieldless variants, with their explicit discriminant if there is one.
+    known: Vec<(&'a syn::Ident, Option<&'a syn::Expr>)>,
+    /// The variant with a single field that carries any other value.
+    unknown: Option<&'a syn::Ident>,
+}
+
+/// Splits the variants of an enum into fieldless ones and at most one catch-all
+/// variant with a single field, such as `Unknown(u32)`.
+///
+/// With a catch-all variant every fieldless variant needs an explicit discriminant.
+fn enum_variants(data: &syn::DataEnum) -> syn::Result<EnumVariants<'_>> {
+    let mut variants = EnumVariants {
+        known: vec![],
+        unknown: None,
+    };
+    for v in &data.variants {
+        match &v.fields {
+            syn::Fields::Unit => variants
+                .known
+                .push((&v.ident, v.discriminant.as_ref().map(|(_, expr)| expr))),
+            syn::Fields::Unnamed(fields)
+                if fields.unnamed.len() == 1 && variants.unknown.is_none() =>
+            {
+                variants.unknown = Some(&v.ident)
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    v,
+                    "VPP wire encoding only supports fieldless variants and one variant with a single field for unknown values",
+                ));
+            }
+        }
+    }
+    let missing = variants.known.iter().find(|(_, expr)| expr.is_none());
+    if let (Some(_), Some((ident, _))) = (variants.unknown, missing) {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "an enum with a variant for unknown values needs explicit discriminants",
+        ));
+    }
+    Ok(variants)
+}
+
+/// Returns the field named by `#[vpp(count = "...")]`, which carries the length of a variable array.
+fn count_field(field: &syn::Field) -> syn::Result<Option<syn::Ident>> {
+    let mut count = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("vpp"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("count") {
+                let name: syn::LitStr = meta.value()?.parse()?;
+                count = Some(name.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `count = \"field\"`"))
+            }
+        })?;
+    }
+    Ok(count)
+}
+
+/// Pairs every count field of a struct with the variable array it carries the length of.
+///
+/// The count field has to come before the array, since it is needed to decode it.
+fn count_links(fields: &syn::FieldsNamed) -> syn::Result<Vec<(syn::Ident, syn::Ident)>> {
+    let mut links = vec![];
+    for (index, field) in fields.named.iter().enumerate() {
+        if let Some(count) = count_field(field)? {
+            if !fields
+                .named
+                .iter()
+                .take(index)
+                .any(|f| f.ident.as_ref() == Some(&count))
+            {
+                return Err(syn::Error::new_spanned(
+                    &count,
+                    format!("no field `{count}` before the array it counts"),
+                ));
+            }
+            links.push((count, field.ident.clone().unwrap()));
+        }
+    }
+    Ok(links)
+}
+
+/// Adds `bound` to every type parameter of the type the trait is derived for.
+fn add_trait_bound(generics: &mut syn::Generics, bound: TokenStream) {
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!(#bound));
+    }
+}
+
+/// Derives `vpp_api_encoding::wire::VppEncode`.
+///
+/// Structs are encoded field by field in declaration order, enums as the integer
+/// type of their `#[repr]`; a variant like `Unknown(u32)` writes the value it
+/// carries. A variable array marked `#[vpp(count = "field")]` has its length
+/// written to `field`, which has to be zero or already match it.
+#[proc_macro_derive(VppEncode, attributes(vpp))]
+pub fn derive_vpp_encode(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+    let wire = quote! { ::vpp_api_encoding::wire };
+    let body = match &input.data {
+        syn::Data::Struct(data) => {
+            let links = match &data.fields {
+                syn::Fields::Named(fields) => match count_links(fields) {
+                    Ok(links) => links,
+                    Err(e) => return e.to_compile_error().into(),
+                },
+                _ => vec![],
+            };
+            let fields = data.fields.iter().enumerate().map(|(index, f)| {
+                let member = match &f.ident {
+                    Some(ident) => quote! { #ident },
+                    None => {
+                        let index = syn::Index::from(index);
+                        quote! { #index }
+                    }
+                };
+                match links.iter().find(|(count, _)| f.ident.as_ref() == Some(count)) {
+                    Some((count, array)) => quote! {
+                        let count = #wire::linked_count(self.#count, self.#array.0.len(), stringify!(#count))?;
+                        #wire::VppEncode::vpp_encode(&count, buf)?;
+                    },
+                    None => quote! { #wire::VppEncode::vpp_encode(&self.#member, buf)?; },
+                }
+            });
+            quote! {
+                #(#fields)*
+                Ok(())
+            }
+        }
+        syn::Data::Enum(data) => {
+            let variants = match enum_variants(data) {
+                Ok(variants) => variants,
+                Err(e) => return e.to_compile_error().into(),
+            };
+            let repr = enum_repr(&input);
+            let known = variants
+                .known
+                .iter()
+                .map(|(ident, expr)| match (variants.unknown, expr) {
+                    (Some(_), Some(expr)) => quote! { Self::#ident => #expr, },
+                    _ => quote! { Self::#ident => Self::#ident as #repr, },
+                });
+            let unknown = variants.unknown.map(|ident| {
+                quote! {
+                    Self::#ident(value) => <#repr>::try_from(*value).map_err(|_| {
+                        #wire::WireError::Invalid(format!("enum value {} does not fit", value))
+                    })?,
+                }
+            });
+            quote! {
+                let value: #repr = match self {
+                    #(#known)*
+                    #unknown
+                };
+                #wire::VppEncode::vpp_encode(&value, buf)
+            }
+        }
+        syn::Data::Union(_) => {
+            return syn::Error::new_spanned(&input.ident, "VppEncode cannot be derived for unions")
+                .to_compile_error()
+                .into();
+        }
+    };
+    add_trait_bound(&mut input.generics, quote! { #wire::VppEncode });
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let expanded = quote! {
+        impl #impl_generics #wire::VppEncode for #name #ty_generics #where_clause {
+            fn vpp_encode<VppBuf: #wire::BufMut>(&self, buf: &mut VppBuf) -> Result<(), #wire::WireError> {
+                #body
+            }
+        }
+    };
+    expanded.into()
+}
+
+/// Derives `vpp_api_encoding::wire::VppDecode`.
+///
+/// Decodes the layout written by `#[derive(VppEncode)]`; an enum value without
+/// a variant goes to the `Unknown(u32)`-like variant, or is a decode error if
+/// the enum has none. A variable array marked `#[vpp(count = "field")]`
+/// is decoded with as many elements as `field` says, any other variable array
+/// takes up the rest of the input.
+#[proc_macro_derive(VppDecode, attributes(vpp))]
+pub fn derive_vpp_decode(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+    let wire = quote! { ::vpp_api_encoding::wire };
+    let name = &input.ident;
+    let body = match &input.data {
+        syn::Data::Struct(data) => {
+            let decode = quote! { #wire::VppDecode::vpp_decode(input)? };
+            match &data.fields {
+                syn::Fields::Named(fields) => {
+                    let links = match count_links(fields) {
+                        Ok(links) => links,
+                        Err(e) => return e.to_compile_error().into(),
+                    };
+                    let local = |ident: &syn::Ident| {
+                        let index = fields
+                            .named
+                            .iter()
+                            .position(|f| f.ident.as_ref() == Some(ident));
+                        format_ident!("field_{}", index.unwrap())
+                    };
+                    let decodes = fields.named.iter().map(|f| {
+                        let ident = f.ident.as_ref().unwrap();
+                        let value = local(ident);
+                        match links.iter().find(|(_, array)| array == ident) {
+                            Some((count, _)) => {
+                                let count = local(count);
+                                quote! { let #value = #wire::decode_counted(input, #count as usize)?; }
+                            }
+                            None => quote! { let #value = #decode; },
+                        }
+                    });
+                    let fields = fields.named.iter().map(|f| {
+                        let ident = f.ident.as_ref().unwrap();
+                        let value = local(ident);
+                        quote! { #ident: #value }
+                    });
+                    quote! {
+                        #(#decodes)*
+                        Ok(Self { #(#fields,)* })
+                    }
+                }
+                syn::Fields::Unnamed(fields) => {
+                    let fields = fields.unnamed.iter().map(|_| &decode);
+                    quote! { Ok(Self(#(#fields,)*)) }
+                }
+                syn::Fields::Unit => quote! { Ok(Self) },
+            }
+        }
+        syn::Data::Enum(data) => {
+            let variants = match enum_variants(data) {
+                Ok(variants) => variants,
+                Err(e) => return e.to_compile_error().into(),
+            };
+            let repr = enum_repr(&input);
+            let known = variants
+                .known
+                .iter()
+                .map(|(ident, expr)| match (variants.unknown, expr) {
+                    (Some(_), Some(expr)) => {
+                        quote! { if value == #expr { return Ok(Self::#ident); } }
+                    }
+                    _ => quote! { if value == Self::#ident as #repr { return Ok(Self::#ident); } },
+                });
+            let fallback = match variants.unknown {
+                Some(ident) => quote! { Ok(Self::#ident(value.into())) },
+                None => quote! {
+                    Err(#wire::WireError::Invalid(format!(
+                        "unknown value {} for {}",
+                        value,
+                        stringify!(#name)
+                    )))
+                },
+            };
+            quote! {
+                let value = <#repr as #wire::VppDecode>::vpp_decode(input)?;
+                #(#known)*
+                #fallback
+            }
+        }
+        syn::Data::Union(_) => {
+            return syn::Error::new_spanned(name, "VppDecode cannot be derived for unions")
+                .to_compile_error()
+                .into();
+        }
+    };
+    let name = name.clone();
+    add_trait_bound(&mut input.generics, quote! { #wire::VppDecode });
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let expanded = quote! {
+        impl #impl_generics #wire::VppDecode for #name #ty_generics #where_clause {
+            fn vpp_decode(input: &mut &[u8]) -> Result<Self, #wire::WireError> {
+                #body
+            }
+        }
+    };
+    expanded.into()
+}
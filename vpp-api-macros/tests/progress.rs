@@ -3,4 +3,7 @@ fn tests() {
     let t = trybuild::TestCases::new();
     t.pass("tests/message-test.rs");
     t.pass("tests/unit-test.rs");
+    t.pass("tests/want-message-test.rs");
+    t.pass("tests/reply-message-test.rs");
+    t.pass("tests/wire-test.rs");
 }
@@ -0,0 +1,19 @@
+use vpp_api_macros::VppMessage;
+use vpp_api_message::{VppApiMessage, VppApiReply};
+
+#[derive(VppMessage, Debug, Clone)]
+#[message_name_and_crc(sw_interface_set_flags_reply_e8d4e804)]
+pub struct SwInterfaceSetFlagsReply {
+    context: u32,
+    retval: i32,
+}
+
+fn main() {
+    let mut reply = SwInterfaceSetFlagsReply {
+        context: 0,
+        retval: -2,
+    };
+    reply.set_context(7);
+    assert_eq!(reply.retval(), -2);
+    assert_eq!(reply.context, 7);
+}
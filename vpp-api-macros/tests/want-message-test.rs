@@ -0,0 +1,48 @@
+use vpp_api_macros::VppMessage;
+use vpp_api_message::{VppApiMessage, VppApiWantMessage};
+
+#[derive(VppMessage, Debug, Clone)]
+#[message_name_and_crc(want_interface_events_476f5a08)]
+pub struct WantInterfaceEvents {
+    client_index: u32,
+    context: u32,
+    enable_disable: u32,
+    pid: u32,
+}
+
+#[derive(VppMessage, Debug, Clone)]
+#[message_name_and_crc(want_ip6_ra_events_3ec6d6c2)]
+pub struct WantIp6RaEvents {
+    client_index: u32,
+    context: u32,
+    enable: bool,
+    pid: u32,
+}
+
+fn main() {
+    let mut want = WantInterfaceEvents {
+        client_index: 0,
+        context: 0,
+        enable_disable: 0,
+        pid: 0,
+    };
+    want.set_enable(true);
+    want.set_pid(42);
+    want.set_context(7);
+    assert_eq!(want.enable_disable, 1);
+    assert_eq!(want.pid, 42);
+    assert_eq!(want.context, 7);
+    assert_eq!(want.client_index, 0);
+
+    let mut want = WantIp6RaEvents {
+        client_index: 0,
+        context: 0,
+        enable: true,
+        pid: 0,
+    };
+    want.set_enable(false);
+    assert!(!want.enable);
+    assert_eq!(want.pid, 0);
+    assert_eq!(want.context, 0);
+    assert_eq!(want.client_index, 0);
+}
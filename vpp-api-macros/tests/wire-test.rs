@@ -0,0 +1,90 @@
+use vpp_api_encoding::typ::*;
+use vpp_api_encoding::wire::{VppDecode, VppEncode, decode_from_slice, encode_to_vec};
+use vpp_api_macros::{VppDecode, VppEncode};
+
+#[derive(Debug, Clone, Copy, PartialEq, VppEncode, VppDecode)]
+#[repr(u8)]
+pub enum AddressFamily {
+    Ip4 = 0,
+    Ip6 = 1,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, VppEncode, VppDecode)]
+#[repr(u8)]
+pub enum Proto {
+    Tcp = 6,
+    Udp = 17,
+    Unknown(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, VppEncode, VppDecode)]
+pub struct Ip4Address([u8; 4]);
+
+#[derive(Debug, Clone, VppEncode, VppDecode)]
+pub struct Prefix {
+    pub af: AddressFamily,
+    pub address: Ip4Address,
+    pub len: u8,
+    pub tag: FixedSizeString<typenum::U8>,
+    pub routes: u32,
+    pub metrics: VariableSizeArray<u16>,
+}
+
+#[derive(Debug, Clone, VppEncode, VppDecode)]
+pub struct Metrics {
+    pub n_metrics: u8,
+    #[vpp(count = "n_metrics")]
+    pub metrics: VariableSizeArray<u16>,
+    pub flags: u16,
+}
+
+fn main() {
+    let prefix = Prefix {
+        af: AddressFamily::Ip6,
+        address: Ip4Address([10, 0, 0, 1]),
+        len: 24,
+        tag: "lan".try_into().unwrap(),
+        routes: 2,
+        metrics: VariableSizeArray(vec![5, 6]),
+    };
+    let mut buf = vec![];
+    prefix.vpp_encode(&mut buf).unwrap();
+    assert_eq!(
+        buf,
+        [
+            1, 10, 0, 0, 1, 24, b'l', b'a', b'n', 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 5, 0, 6
+        ]
+    );
+    assert_eq!(encode_to_vec(&prefix).unwrap(), buf);
+
+    let (decoded, len) = decode_from_slice::<Prefix>(&buf).unwrap();
+    assert_eq!(len, buf.len());
+    assert_eq!(decoded.af, AddressFamily::Ip6);
+    assert_eq!(decoded.address, Ip4Address([10, 0, 0, 1]));
+    assert_eq!(decoded.tag.to_string(), "lan");
+    assert_eq!(decoded.metrics.0, [5, 6]);
+
+    assert!(AddressFamily::vpp_decode(&mut &[2u8][..]).is_err());
+    assert!(decode_from_slice::<Prefix>(&buf[..10]).is_err());
+
+    let metrics = Metrics {
+        n_metrics: 0,
+        metrics: VariableSizeArray(vec![5, 6]),
+        flags: 7,
+    };
+    let buf = encode_to_vec(&metrics).unwrap();
+    assert_eq!(buf, [2, 0, 5, 0, 6, 0, 7]);
+    let (decoded, _) = decode_from_slice::<Metrics>(&buf).unwrap();
+    assert_eq!(decoded.n_metrics, 2);
+    assert_eq!(decoded.metrics.0, [5, 6]);
+    assert_eq!(decoded.flags, 7);
+
+    assert_eq!(encode_to_vec(&Proto::Udp).unwrap(), [17]);
+    assert_eq!(encode_to_vec(&Proto::Unknown(132)).unwrap(), [132]);
+    assert!(encode_to_vec(&Proto::Unknown(256)).is_err());
+    assert_eq!(decode_from_slice::<Proto>(&[6]).unwrap().0, Proto::Tcp);
+    assert_eq!(
+        decode_from_slice::<Proto>(&[132]).unwrap().0,
+        Proto::Unknown(132)
+    );
+}
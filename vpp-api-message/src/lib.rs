@@ -1,3 +1,8 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+use vpp_api_encoding::wire::{VppDecode, VppEncode};
+
 /// Trait for VPP API messages.
 ///
 /// Defines the interface for VPP API message types, providing methods to retrieve
@@ -24,3 +29,48 @@ pub trait VppApiMessage {
     /// * `client_index` - The client index identifier to associate with this message.
     fn set_client_index(&mut self, client_index: u32);
 }
+
+/// Trait for `want_*` messages that subscribe a client to VPP events.
+///
+/// Implemented for messages carrying an enable flag and a `pid` field, so that a
+/// transport can turn event delivery on and off without knowing the concrete message.
+pub trait VppApiWantMessage {
+    /// Sets the enable flag of the subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - `true` to start receiving events, `false` to stop.
+    fn set_enable(&mut self, enable: bool);
+
+    /// Sets the process id the events are registered for.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The process id of the subscribing client.
+    fn set_pid(&mut self, pid: u32);
+}
+
+/// Trait for replies, which report the outcome of a request in a `retval` field.
+///
+/// Implemented for every message with a `retval` field, so that a transport can
+/// check the outcome of a request without knowing the concrete reply.
+pub trait VppApiReply {
+    /// Returns the `retval` of the reply: zero or positive on success, one of the
+    /// negative `vnet_api_error` codes of VPP on failure.
+    fn retval(&self) -> i32;
+}
+
+/// Trait for code that works with a message type chosen at runtime.
+///
+/// Generated bindings provide a `visit_message` function, which calls
+/// [`VppApiMessageVisitor::visit`] with the message type that has a given name
+/// and CRC, e.g. to decode the messages of an API trace.
+pub trait VppApiMessageVisitor {
+    /// The result of the visit.
+    type Output;
+
+    /// Called with the type of the message.
+    fn visit<T>(self) -> Self::Output
+    where
+        T: VppApiMessage + VppEncode + VppDecode + Serialize + DeserializeOwned + Debug + 'static;
+}
@@ -85,7 +85,7 @@ async fn bench_blocking(opts: &Opts, t: &mut crate::shmem::blocking::Client) {
     }
 }
 
-async fn bench(opts: &Opts, client: &mut crate::afunix::client::Client) -> Result<()> {
+async fn bench(opts: &Opts, client: &crate::afunix::client::Client) -> Result<()> {
     let now = SystemTime::now();
     let mut last_show = now;
 
@@ -167,9 +167,9 @@ fn main() {
 
     if let Some(afunix_path) = &opts.socket_path {
         let result: Result<(), anyhow::Error> = runtime.block_on(async {
-            let mut client = afunix::client::Client::connect(&afunix_path, "api-test").await?;
-            bench(&opts, &mut client).await?;
-            client.disconnect();
+            let client = afunix::client::Client::connect(&afunix_path, "api-test").await?;
+            bench(&opts, &client).await?;
+            client.disconnect().await?;
             Ok(())
         });
         result.unwrap()
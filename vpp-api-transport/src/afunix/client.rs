@@ -1,35 +1,150 @@
+use crate::batch::{BatchResult, run_windowed};
+use crate::capture::{CaptureTap, Direction, Recorder};
+use crate::codec::VppFrameCodec;
+use crate::compat::CompatibilityReport;
+use crate::dump::{DumpIds, decode_dump_reply};
+use crate::error::{Result, TransportError, check_retval, retval_error};
+use crate::events::EventStream;
 use crate::helpers::*;
 use crate::message::*;
-use anyhow::{Result, anyhow};
-use bincode_next::config::BigEndian;
-use bincode_next::config::Configuration;
-use bincode_next::config::Fixint;
-use serde::Serialize;
-use serde::de::DeserializeOwned;
+use crate::router::{PendingRequest, ReplyRouter};
+use crate::table::MessageTable;
+use crate::transport::VppTransport;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::{SinkExt, StreamExt};
 use std::collections::HashMap;
 use std::convert::TryInto;
-use std::sync::atomic::AtomicU32;
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use tokio::net::UnixStream;
-use vpp_api_message::VppApiMessage;
+use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::sync::{Mutex, mpsc};
+use tokio::task::JoinHandle;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_util::codec::{Framed, FramedRead, FramedWrite};
+use tracing::{debug, warn};
+use vpp_api_encoding::wire::{VppDecode, VppEncode};
+use vpp_api_message::{VppApiMessage, VppApiReply, VppApiWantMessage};
+
+/// How long [`Client::disconnect`] waits for VPP to confirm the delete.
+pub const DISCONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The sending side of the framed socket, encoding messages with their message id.
+type FrameSink = FramedWrite<OwnedWriteHalf, VppFrameCodec>;
+
+/// The receiving side of the framed socket, yielding frames without the socket header.
+type FrameStream = FramedRead<OwnedReadHalf, VppFrameCodec>;
 
 /// A client for communicating with the VPP API over a Unix socket.
 ///
 /// This client establishes a connection to the VPP API server using a Unix domain socket,
 /// handles message serialization/deserialization, and provides methods for sending and
 /// receiving API messages.
+///
+/// The client is a cheap, cloneable handle: all clones share one connection. A background
+/// reader task decodes incoming frames and routes each reply to the request that is waiting
+/// for its context, so any number of tasks can have requests in flight at the same time.
+#[derive(Clone)]
 pub struct Client {
-    /// The underlying Unix socket stream for communication with the VPP API server.
-    stream: UnixStream,
+    inner: Arc<Inner>,
+}
+
+/// The connection state shared by all clones of a [`Client`].
+struct Inner {
+    /// The sending side of the socket, shared by all senders and the reader task.
+    writer: Arc<Mutex<FrameSink>>,
     /// The unique client index assigned by the VPP API server.
     client_index: u32,
     /// An atomic counter for generating unique context IDs for API requests.
     context_id: AtomicU32,
-    /// A mapping of message names to their corresponding message IDs.
-    // message_name_to_id: HashMap<String, u16>,
-    /// The bincode configuration used for serializing/deserializing messages.
-    config: Configuration<BigEndian, Fixint>,
+    /// The message table VPP sent during the handshake, kept up to date by
+    /// [`Client::refresh_message_table`].
+    messages: RwLock<MessageTable>,
+    /// Waiters for in-flight requests, completed by the reader task.
+    router: Arc<ReplyRouter>,
+    /// The number of keepalives answered by the reader task.
+    keepalives: Arc<AtomicU64>,
+    /// Records the frames sent and received while a capture is running.
+    capture: CaptureTap,
+    /// The background task reading frames off the socket.
+    reader: JoinHandle<()>,
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
 
-    resolver: Box<dyn Fn(String) -> Result<u16> + 'static>,
+/// Answers `memclnt_keepalive` messages from the reader task, so VPP does not
+/// consider the client dead while the application is idle.
+///
+/// The reader only queues the replies; [`KeepaliveResponder::write_loop`] sends them,
+/// so a caller holding the writer lock can never stall the reader.
+struct KeepaliveResponder {
+    /// The message id of `memclnt_keepalive`.
+    request_id: u16,
+    /// Hands the replies to the writer task.
+    replies: mpsc::UnboundedSender<RawMemclntKeepaliveReply>,
+}
+
+impl KeepaliveResponder {
+    /// Creates the responder and spawns the task writing its replies.
+    ///
+    /// The task stops once the responder is dropped, i.e. when the reader stops.
+    ///
+    /// # Arguments
+    ///
+    /// * `request_id` - The message id of `memclnt_keepalive`
+    /// * `reply_id` - The message id of `memclnt_keepalive_reply`
+    /// * `writer` - The sending side of the socket the replies are written to
+    /// * `count` - The number of keepalives answered so far
+    /// * `capture` - Records the replies while a capture is running
+    fn spawn(
+        request_id: u16,
+        reply_id: u16,
+        writer: Arc<Mutex<FrameSink>>,
+        count: Arc<AtomicU64>,
+        capture: CaptureTap,
+    ) -> Self {
+        let (replies, queued) = mpsc::unbounded_channel();
+        tokio::spawn(Self::write_loop(queued, reply_id, writer, count, capture));
+        KeepaliveResponder {
+            request_id,
+            replies,
+        }
+    }
+
+    /// Queues the reply to the keepalive carried in `data`, without waiting for the socket.
+    fn answer(&self, data: &[u8]) -> Result<()> {
+        let keepalive: RawMemclntKeepalive = read_msg_from_slice(data)?;
+        let reply = RawMemclntKeepaliveReply::answering(&keepalive);
+        self.replies
+            .send(reply)
+            .map_err(|_| TransportError::Connection("keepalive writer stopped".to_string()))
+    }
+
+    /// Writes the queued keepalive replies to the socket until the queue is closed.
+    async fn write_loop(
+        mut queued: mpsc::UnboundedReceiver<RawMemclntKeepaliveReply>,
+        reply_id: u16,
+        writer: Arc<Mutex<FrameSink>>,
+        count: Arc<AtomicU64>,
+        capture: CaptureTap,
+    ) {
+        while let Some(reply) = queued.recv().await {
+            capture.record_message(reply_id, &reply);
+            match writer.lock().await.send((reply_id, &reply)).await {
+                Ok(()) => {
+                    count.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(e) => warn!("failed to answer keepalive: {e}"),
+            }
+        }
+    }
 }
 
 impl Client {
@@ -44,8 +159,10 @@ impl Client {
     ///
     /// A new `Client` instance if the connection is successful, or an error otherwise.
     pub async fn connect(path: &str, name: &str) -> Result<Client> {
-        let config = Client::new_encoder();
-        let mut stream = UnixStream::connect(path).await?;
+        let stream = UnixStream::connect(path)
+            .await
+            .map_err(|e| TransportError::Connection(format!("{path}: {e}")))?;
+        let mut framed = Framed::new(stream, VppFrameCodec::new());
         let mut message_name_to_id = HashMap::new();
 
         message_name_to_id.insert(
@@ -57,18 +174,15 @@ impl Client {
             MsgSockClntCreateReplyHdr::get_message_id(),
         );
 
-        let resolve_id = |name| {
-            message_name_to_id
-                .get(&name)
-                .cloned()
-                .ok_or(anyhow!("Cannot find message id for {}", name))
-        };
-
         let create_msg: MsgSockClntCreate = name.try_into()?;
-        write_object(&mut stream, &create_msg, &resolve_id, config, true).await?;
+        framed
+            .send((MsgSockClntCreate::get_message_id(), &create_msg))
+            .await?;
 
-        let reply: MsgSockClntCreateReplyHdr =
-            read_object(&mut stream, &resolve_id, config, true).await?;
+        let frame = framed.next().await.ok_or_else(|| {
+            TransportError::Connection(format!("{path}: closed before sockclnt_create_reply"))
+        })??;
+        let reply: MsgSockClntCreateReplyHdr = read_object_from_slice(&frame, &message_name_to_id)?;
 
         for msg_entry in reply.message_table.0.iter() {
             let msg_name: String = msg_entry.name.to_string();
@@ -76,31 +190,124 @@ impl Client {
         }
         let client_index = reply.index;
 
-        let resolve_id = move |name| {
-            message_name_to_id
-                .get(&name)
-                .cloned()
-                .ok_or(anyhow!("Cannot find message id for {}", name))
+        let messages: MessageTable = message_name_to_id.into_iter().collect();
+
+        let parts = framed.into_parts();
+        let (read_half, write_half) = parts.io.into_split();
+        let mut frames = FramedRead::new(read_half, parts.codec);
+        // anything VPP sent right after the handshake reply is already buffered
+        *frames.read_buffer_mut() = parts.read_buf;
+        let writer = Arc::new(Mutex::new(FramedWrite::new(write_half, parts.codec)));
+        let router = Arc::new(ReplyRouter::default());
+        let keepalives = Arc::new(AtomicU64::new(0));
+        let capture = CaptureTap::default();
+        let keepalive = match (
+            messages.id(&RawMemclntKeepalive::get_message_name_and_crc()),
+            messages.id(&RawMemclntKeepaliveReply::get_message_name_and_crc()),
+        ) {
+            (Ok(request_id), Ok(reply_id)) => Some(KeepaliveResponder::spawn(
+                request_id,
+                reply_id,
+                writer.clone(),
+                keepalives.clone(),
+                capture.clone(),
+            )),
+            _ => None,
         };
+        let reader = tokio::spawn(Client::read_loop(
+            frames,
+            router.clone(),
+            keepalive,
+            capture.clone(),
+        ));
 
         Ok(Client {
-            context_id: AtomicU32::new(1),
-            client_index,
-            stream,
-            config,
-            resolver: Box::new(resolve_id),
+            inner: Arc::new(Inner {
+                writer,
+                client_index,
+                context_id: AtomicU32::new(1),
+                messages: RwLock::new(messages),
+                router,
+                keepalives,
+                capture,
+                reader,
+            }),
         })
     }
 
-    /// Creates a new bincode configuration for message encoding/decoding.
-    fn new_encoder() -> Configuration<BigEndian, Fixint> {
-        bincode_next::config::legacy()
-            .with_big_endian()
-            .with_fixed_int_encoding()
+    /// Connects like [`Client::connect`], but refuses the connection if VPP does
+    /// not know any of the `used` messages with the same CRC.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the Unix socket (e.g., "/run/vpp/api.sock")
+    /// * `name` - The name to register with the VPP API server
+    /// * `used` - The `name_crc` of every message the application sends or receives
+    ///
+    /// # Returns
+    ///
+    /// A new `Client` instance, or an error describing the incompatible messages.
+    pub async fn connect_strict<I>(path: &str, name: &str, used: I) -> Result<Client>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let client = Client::connect(path, name).await?;
+        let report = client.compatibility_report(used);
+        if !report.is_compatible() {
+            if let Err(e) = client.disconnect().await {
+                debug!("disconnect after failed compatibility check: {e}");
+            }
+            return Err(TransportError::Incompatible(report.to_string()));
+        }
+        Ok(client)
+    }
+
+    /// Reads frames off the socket and hands each one to the request waiting for its context.
+    ///
+    /// Keepalives never reach the router; their replies are queued for the keepalive
+    /// writer task, so the reader never waits on the writer lock. Runs until the
+    /// socket is closed or a framing error occurs, at which point all in-flight requests
+    /// are failed.
+    async fn read_loop(
+        mut frames: FrameStream,
+        router: Arc<ReplyRouter>,
+        keepalive: Option<KeepaliveResponder>,
+        capture: CaptureTap,
+    ) {
+        let reason = loop {
+            let frame = match frames.next().await {
+                Some(Ok(frame)) => frame,
+                Some(Err(e)) => break e.to_string(),
+                None => break "connection closed by VPP".to_string(),
+            };
+            capture.record(Direction::Received, &frame);
+            match split_frame(frame.freeze()) {
+                Ok((msg_id, data)) => match &keepalive {
+                    Some(keepalive) if msg_id == keepalive.request_id => {
+                        if let Err(e) = keepalive.answer(&data) {
+                            warn!("failed to answer keepalive: {e}");
+                        }
+                    }
+                    _ => {
+                        router.route(msg_id, data);
+                    }
+                },
+                Err(e) => warn!("discarding malformed frame: {e}"),
+            }
+        };
+        debug!("afunix reader stopped: {reason}");
+        router.close(reason);
     }
 
     /// Sends a message and waits for a response.
     ///
+    /// The reply is matched by the context assigned to the request, so several
+    /// `send_rcv` calls may be in flight concurrently on clones of the same client.
+    /// The call is cancellation safe: if the returned future is dropped, the socket
+    /// never sees a partial request, and the reply to a request that did go out is
+    /// discarded when it arrives.
+    ///
     /// # Arguments
     ///
     /// * `msg` - The message to send
@@ -108,16 +315,33 @@ impl Client {
     /// # Returns
     ///
     /// The response message or an error.
-    pub async fn send_rcv<T, R>(&mut self, msg: T) -> Result<R>
+    pub async fn send_rcv<T, R>(&self, mut msg: T) -> Result<R>
     where
-        T: Serialize + VppApiMessage,
-        R: DeserializeOwned + VppApiMessage,
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage,
     {
-        self.send(msg).await?;
-        self.receive().await
+        let expected_msg_id = self.get_message_index(&R::get_message_name_and_crc())?;
+        let context = self.get_next_context();
+        msg.set_client_index(self.inner.client_index);
+        msg.set_context(context);
+
+        let waiter = self.inner.router.register(context)?;
+        let _pending = PendingRequest {
+            router: &self.inner.router,
+            context,
+        };
+        self.write(&msg).await?;
+        let reply = waiter.await.map_err(|_| {
+            TransportError::Connection(format!("reply channel for context {context} closed"))
+        })??;
+
+        if reply.msg_id != expected_msg_id {
+            return Err(unexpected_message::<R>(reply.msg_id, expected_msg_id));
+        }
+        read_msg_from_slice(&reply.data)
     }
 
-    /// Sends a message to the VPP API server.
+    /// Sends a message and waits for a response, failing if VPP reports an error.
     ///
     /// # Arguments
     ///
@@ -125,42 +349,260 @@ impl Client {
     ///
     /// # Returns
     ///
-    /// Ok(()) if successful, or an error otherwise.
-    pub async fn send<T>(&mut self, mut msg: T) -> Result<()>
+    /// The response message, or an error. A negative `retval` in the reply becomes
+    /// a [`TransportError::Retval`].
+    pub async fn send_rcv_checked<T, R>(&self, msg: T) -> Result<R>
     where
-        T: Serialize + VppApiMessage,
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage + VppApiReply,
     {
-        msg.set_client_index(self.client_index);
-        msg.set_context(self.get_next_context());
+        check_retval::<T, R>(self.send_rcv(msg).await?)
+    }
 
-        write_object(&mut self.stream, &msg, &self.resolver, self.config, true).await
+    /// Sends a batch of requests back-to-back and collects their replies.
+    ///
+    /// Up to `window` requests are in flight at once, each with its own context, so
+    /// a large batch costs about one round trip per window rather than one per request.
+    ///
+    /// # Arguments
+    ///
+    /// * `msgs` - The requests to send
+    /// * `window` - The most requests in flight at once, e.g. [`crate::batch::DEFAULT_BATCH_WINDOW`]
+    ///
+    /// # Returns
+    ///
+    /// The result of every request in order, or an error if `window` is zero.
+    pub async fn send_rcv_batch<T, R, I>(&self, msgs: I, window: usize) -> Result<BatchResult<R>>
+    where
+        I: IntoIterator<Item = T>,
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage + VppApiReply,
+    {
+        run_windowed(msgs, window, |msg| self.send_rcv_checked(msg)).await
     }
 
-    /// Receives a message from the VPP API server.
+    /// Sends a message and waits at most `timeout` for a response.
+    ///
+    /// A reply arriving after the deadline is discarded and never mistaken for the
+    /// reply to a later request.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send
+    /// * `timeout` - How long to wait for the reply
+    ///
+    /// # Returns
+    ///
+    /// The response message, or an error if the request failed or timed out.
+    pub async fn send_rcv_with_timeout<T, R>(&self, msg: T, timeout: Duration) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage,
+    {
+        tokio::time::timeout(timeout, self.send_rcv(msg))
+            .await
+            .map_err(|_| {
+                TransportError::Timeout(format!(
+                    "no reply to {} within {timeout:?}",
+                    T::get_message_name_and_crc()
+                ))
+            })?
+    }
+
+    /// Sends a dump request and returns a stream of the details messages it produces.
+    ///
+    /// The dump is followed by a control ping with the same context; the stream ends
+    /// when the control ping reply arrives. Replies for other contexts never appear
+    /// in the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The dump message to send
+    ///
+    /// # Returns
+    ///
+    /// A stream of decoded details messages, or an error if the request could not be sent.
+    pub async fn send_dump<T, D>(&self, mut msg: T) -> Result<BoxStream<'static, Result<D>>>
+    where
+        T: VppEncode + VppApiMessage,
+        D: VppDecode + VppApiMessage + Send + 'static,
+    {
+        let ids = DumpIds {
+            details: self.get_message_index(&D::get_message_name_and_crc())?,
+            done: self.get_message_index(&RawControlPingReply::get_message_name_and_crc())?,
+        };
+        let context = self.get_next_context();
+        msg.set_client_index(self.inner.client_index);
+        msg.set_context(context);
+        let mut ping = RawControlPing::default();
+        ping.set_client_index(self.inner.client_index);
+        ping.set_context(context);
+
+        let replies = self.inner.router.register_stream(context, ids.done)?;
+        let written = async {
+            self.write(&msg).await?;
+            self.write(&ping).await
+        };
+        if let Err(e) = written.await {
+            self.inner.router.unregister(context);
+            return Err(e);
+        }
+
+        let details = UnboundedReceiverStream::new(replies).filter_map(move |reply| {
+            let item = reply
+                .and_then(|reply| decode_dump_reply(reply.msg_id, &reply.data, ids))
+                .transpose();
+            futures::future::ready(item)
+        });
+        Ok(details.boxed())
+    }
+
+    /// Subscribes to an event and returns a stream of the events VPP sends.
+    ///
+    /// The `want` message is sent with its enable flag set and the pid of this
+    /// process. When the returned stream is dropped, and no other subscription for
+    /// the same event is active on this connection, the `want` message is sent
+    /// again with the enable flag cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `want` - The `want_*` message that enables the event, e.g. `WantInterfaceEvents`
+    ///
+    /// # Returns
+    ///
+    /// A stream of decoded events, or an error if VPP refused the subscription.
+    pub async fn subscribe<W, E>(&self, mut want: W) -> Result<EventStream<E>>
+    where
+        W: VppEncode + VppApiMessage + VppApiWantMessage + Send + Sync + 'static,
+        E: VppDecode + VppApiMessage + Send + 'static,
+    {
+        let event_id = self.get_message_index(&E::get_message_name_and_crc())?;
+        let (token, events) = self.inner.router.subscribe(event_id)?;
+
+        want.set_enable(true);
+        want.set_pid(std::process::id());
+        let retval = match self.request_retval(&mut want).await {
+            Ok(retval) => retval,
+            Err(e) => {
+                self.inner.router.unsubscribe(event_id, token);
+                return Err(e);
+            }
+        };
+        if retval != 0 {
+            self.inner.router.unsubscribe(event_id, token);
+            return Err(retval_error::<W>(retval));
+        }
+
+        let events = UnboundedReceiverStream::new(events)
+            .map(move |event| event.and_then(|event| read_msg_from_slice(&event.data)));
+
+        let client = self.clone();
+        Ok(EventStream::new(events.boxed(), move || {
+            if !client.inner.router.unsubscribe(event_id, token) {
+                return;
+            }
+            let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+                warn!("no runtime to disable {}", W::get_message_name_and_crc());
+                return;
+            };
+            runtime.spawn(async move {
+                want.set_enable(false);
+                if let Err(e) = client.request_retval(&mut want).await {
+                    debug!("failed to disable {}: {e}", W::get_message_name_and_crc());
+                }
+            });
+        }))
+    }
+
+    /// Waits for the next message of type `R` that is not a reply to a request.
+    ///
+    /// A message no request was waiting for is kept for a while, so one that
+    /// arrived shortly before the call is returned too; use [`Client::subscribe`]
+    /// to receive events continuously.
     ///
     /// # Returns
     ///
-    /// The received message or an error.
-    pub async fn receive<R>(&mut self) -> Result<R>
+    /// The decoded message, or an error if the connection is closed.
+    pub async fn receive<R>(&self) -> Result<R>
     where
-        R: DeserializeOwned + VppApiMessage,
+        R: VppDecode + VppApiMessage,
     {
-        read_object(&mut self.stream, &self.resolver, self.config, true).await
+        let msg_id = self.get_message_index(&R::get_message_name_and_crc())?;
+        let message = self.inner.router.receive(msg_id)?;
+        let message = message
+            .await
+            .map_err(|_| TransportError::Connection("connection closed".into()))??;
+        read_msg_from_slice(&message.data)
     }
 
-    /// Sends a control ping message to the VPP API server.
-    pub async fn send_control_ping(&mut self) -> Result<()> {
-        self.send(RawControlPing::default()).await
+    /// Sends a request and returns the `retval` of its reply, whatever the reply type.
+    async fn request_retval<T>(&self, msg: &mut T) -> Result<i32>
+    where
+        T: VppEncode + VppApiMessage,
+    {
+        let context = self.get_next_context();
+        msg.set_client_index(self.inner.client_index);
+        msg.set_context(context);
+
+        let waiter = self.inner.router.register(context)?;
+        let _pending = PendingRequest {
+            router: &self.inner.router,
+            context,
+        };
+        self.write(msg).await?;
+        let reply = waiter.await.map_err(|_| {
+            TransportError::Connection(format!("reply channel for context {context} closed"))
+        })??;
+        let header: RawReplyHeader = read_msg_from_slice(&reply.data)?;
+        Ok(header.retval)
     }
 
-    /// Receives a control ping reply from the VPP API server.
+    /// Sends a message to the VPP API server without waiting for a reply.
+    ///
+    /// Any reply VPP sends for this message is discarded by the reader task.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) if successful, or an error otherwise.
+    pub async fn send<T>(&self, mut msg: T) -> Result<()>
+    where
+        T: VppEncode + VppApiMessage,
+    {
+        msg.set_client_index(self.inner.client_index);
+        msg.set_context(self.get_next_context());
+        self.write(&msg).await
+    }
+
+    /// Encodes and writes a single framed message to the socket.
+    ///
+    /// The codec takes the whole frame into its write buffer before any of it is
+    /// written, so a caller that is cancelled half-way cannot leave a partial frame
+    /// on the socket; whatever is left in the buffer goes out with the next write.
+    async fn write<T>(&self, msg: &T) -> Result<()>
+    where
+        T: VppEncode + VppApiMessage,
+    {
+        let msg_id = self.get_message_index(&T::get_message_name_and_crc())?;
+        self.inner.capture.record_message(msg_id, msg);
+        self.inner.writer.lock().await.send((msg_id, msg)).await
+    }
+
+    /// Sends a control ping message to the VPP API server and waits for the reply.
     ///
     /// # Returns
     ///
     /// The return value from the control ping reply.
-    pub async fn rcv_control_ping_reply(&mut self) -> Result<i32> {
-        let reply = self.receive::<RawControlPingReply>().await?;
-        Ok(reply.retval)
+    pub async fn control_ping(&self) -> Result<i32> {
+        Ok(self.control_ping_reply().await?.retval)
+    }
+
+    /// Sends a control ping and returns the whole reply, including the pid of VPP.
+    pub(crate) async fn control_ping_reply(&self) -> Result<RawControlPingReply> {
+        self.send_rcv(RawControlPing::default()).await
     }
 
     /// Executes a VPP CLI command and returns the output.
@@ -172,49 +614,406 @@ impl Client {
     /// # Returns
     ///
     /// The CLI command output or an error.
-    pub async fn run_cli_inband(&mut self, cmd: &str) -> Result<String> {
+    pub async fn run_cli_inband(&self, cmd: &str) -> Result<String> {
         let in_msg = RawCliInband::new(cmd)?;
         let out_msg: RawCliInbandReply = self.send_rcv(in_msg).await?;
         Ok(out_msg.reply.to_string())
     }
 
+    /// Checks messages against the message table VPP sent when the client connected.
+    ///
+    /// # Arguments
+    ///
+    /// * `compiled` - The `name_crc` of every message to check, e.g.
+    ///   `vpp_api_client::MESSAGE_NAMES_AND_CRCS` for all generated bindings
+    ///
+    /// # Returns
+    ///
+    /// A report of the messages that are missing, have a different CRC, or are only known to VPP.
+    pub fn compatibility_report<I>(&self, compiled: I) -> CompatibilityReport
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let messages = self.inner.messages.read().unwrap();
+        CompatibilityReport::new(compiled, messages.iter().map(|(name, _)| name))
+    }
+
     /// Returns the client index assigned by the VPP API server.
     pub fn get_client_index(&self) -> u32 {
-        self.client_index
+        self.inner.client_index
+    }
+
+    /// Returns the number of `memclnt_keepalive` messages answered on this connection.
+    pub fn keepalive_count(&self) -> u64 {
+        self.inner.keepalives.load(Ordering::Relaxed)
+    }
+
+    /// Starts writing every frame sent and received on this connection to a capture file.
+    ///
+    /// The records are named using the message table of the connection. A capture
+    /// already in progress is closed and replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Where to write the capture; an existing file is replaced
+    pub fn start_recording(&self, path: impl AsRef<Path>) -> Result<()> {
+        let names = self
+            .inner
+            .messages
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(name, id)| (id, name.to_string()))
+            .collect();
+        let recorder = Recorder::create(path, names)?;
+        self.inner.capture.start(recorder);
+        Ok(())
+    }
+
+    /// Stops recording and closes the capture file, if a capture is in progress.
+    pub fn stop_recording(&self) -> Result<()> {
+        self.inner.capture.stop()
+    }
+
+    /// Returns `true` once the connection to VPP is gone.
+    ///
+    /// A closed client fails every request; a new one has to be connected.
+    pub fn is_closed(&self) -> bool {
+        self.inner.router.is_closed()
+    }
+
+    /// Waits until the connection to VPP is gone and returns the reason.
+    pub async fn closed(&self) -> String {
+        self.inner.router.closed().await
+    }
+
+    /// Returns the number of messages dropped because nobody subscribed to or received them.
+    pub fn dropped_event_count(&self) -> u64 {
+        self.inner.router.dropped_events()
     }
 
     /// Generates the next unique context ID for an API request.
-    fn get_next_context(&mut self) -> u32 {
-        self.context_id
-            .fetch_add(1, std::sync::atomic::Ordering::AcqRel)
+    fn get_next_context(&self) -> u32 {
+        self.inner.context_id.fetch_add(1, Ordering::AcqRel)
+    }
+
+    pub fn get_message_index(&self, name: &str) -> Result<u16> {
+        self.inner.messages.read().unwrap().id(name)
+    }
+
+    /// Returns every message VPP knows on this connection, as `name_crc` and message id.
+    pub fn messages(&self) -> impl Iterator<Item = (String, u16)> + use<> {
+        let messages = self.inner.messages.read().unwrap();
+        let snapshot: Vec<_> = messages
+            .iter()
+            .map(|(name, id)| (name.to_string(), id))
+            .collect();
+        snapshot.into_iter()
+    }
+
+    /// Asks VPP where the messages of a module start now and updates the message table.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The name the module registered its messages under, `<module>_<crc>`,
+    ///   e.g. `acl_5b3a5b0c`
+    /// * `messages` - The `name_crc` of every message of the module in `.api` declaration
+    ///   order, e.g. the `MESSAGES` of the generated module
+    ///
+    /// # Returns
+    ///
+    /// The number of messages whose ids were updated, or an error if VPP does not know the module.
+    pub async fn refresh_message_table(&self, module: &str, messages: &[&str]) -> Result<usize> {
+        let reply: RawGetFirstMsgIdReply = self
+            .send_rcv_checked(RawGetFirstMsgId::new(module)?)
+            .await?;
+        self.inner
+            .messages
+            .write()
+            .unwrap()
+            .rebase(messages, reply.first_msg_id)
+    }
+
+    /// Disconnects from the VPP API server, waiting up to [`DISCONNECT_TIMEOUT`] for VPP to agree.
+    ///
+    /// See [`Client::disconnect_with_timeout`].
+    pub async fn disconnect(self) -> Result<()> {
+        self.disconnect_with_timeout(DISCONNECT_TIMEOUT).await
+    }
+
+    /// Deregisters the client from the VPP API server and closes the connection.
+    ///
+    /// Sends `sockclnt_delete` and waits for its reply, so the registration is gone
+    /// from `show api clients` once this returns. The connection is shut down for all
+    /// clones of the client, even if VPP refuses the delete or does not answer.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - How long to wait for the reply to `sockclnt_delete`
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) if VPP confirmed the delete, or an error otherwise.
+    pub async fn disconnect_with_timeout(self, timeout: Duration) -> Result<()> {
+        let delete = RawSockclntDelete {
+            index: self.inner.client_index,
+            ..Default::default()
+        };
+        let reply =
+            tokio::time::timeout(timeout, self.send_rcv::<_, RawSockclntDeleteReply>(delete)).await;
+        SinkExt::<Bytes>::close(&mut *self.inner.writer.lock().await)
+            .await
+            .ok();
+
+        let reply = reply.map_err(|_| {
+            TransportError::Timeout(format!("no reply to sockclnt_delete within {timeout:?}"))
+        })??;
+        if reply.response != 0 {
+            return Err(retval_error::<RawSockclntDelete>(reply.response));
+        }
+        Ok(())
+    }
+}
+
+impl VppTransport for Client {
+    async fn send<T>(&mut self, msg: T) -> Result<()>
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+    {
+        Client::send(self, msg).await
+    }
+
+    async fn receive<R>(&mut self) -> Result<R>
+    where
+        R: VppDecode + VppApiMessage + Send,
+    {
+        Client::receive(self).await
     }
 
-    pub fn get_message_index(&self, name: &String) -> Result<u16> {
-        self.resolver.as_ref()(name.to_owned())
+    async fn send_rcv<T, R>(&mut self, msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+        R: VppDecode + VppApiMessage + Send,
+    {
+        Client::send_rcv(self, msg).await
+    }
+
+    async fn send_dump<T, D>(&mut self, msg: T) -> Result<BoxStream<'_, Result<D>>>
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+        D: VppDecode + VppApiMessage + Send + 'static,
+    {
+        Client::send_dump(self, msg).await
     }
 
-    /// Disconnects from the VPP API server.
-    pub fn disconnect(self) {}
+    async fn subscribe<W, E>(&mut self, want: W) -> Result<BoxStream<'_, Result<E>>>
+    where
+        W: VppEncode + VppApiMessage + VppApiWantMessage + Send + Sync + 'static,
+        E: VppDecode + VppApiMessage + Send + 'static,
+    {
+        Ok(Client::subscribe(self, want).await?.boxed())
+    }
+
+    fn get_message_index(&self, name: &str) -> Result<u16> {
+        Client::get_message_index(self, name)
+    }
+
+    async fn control_ping(&mut self) -> Result<i32> {
+        Client::control_ping(self).await
+    }
+
+    async fn run_cli_inband(&mut self, cmd: &str) -> Result<String> {
+        Client::run_cli_inband(self, cmd).await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::FakeVpp;
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn test_afunix_connect() {
-        let mut client = Client::connect("/run/vpp/api.sock", "socket-client")
+        let client = Client::connect("/run/vpp/api.sock", "socket-client")
             .await
             .unwrap();
 
-        client.send_control_ping().await.unwrap();
-        let res = client.rcv_control_ping_reply().await.unwrap();
+        let res = client.control_ping().await.unwrap();
         assert_eq!(res, 0);
 
         let s = client.run_cli_inband("show version").await.unwrap();
         assert!(s.starts_with("vpp "));
         println!("\n {s}");
 
-        client.disconnect();
+        client.disconnect().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_afunix_concurrent_requests() {
+        let client = Client::connect("/run/vpp/api.sock", "socket-client")
+            .await
+            .unwrap();
+
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move { client.run_cli_inband("show version").await })
+            })
+            .collect();
+        for task in tasks {
+            let s = task.await.unwrap().unwrap();
+            assert!(s.starts_with("vpp "));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_receive_leaves_replies_to_requests() {
+        let vpp = FakeVpp::start().unwrap();
+        let client = Client::connect(vpp.path(), "receive").await.unwrap();
+
+        let waiting = client.clone();
+        let pending = tokio::spawn(async move { waiting.receive::<RawControlPingReply>().await });
+        tokio::task::yield_now().await;
+        let ping = tokio::time::timeout(Duration::from_secs(5), client.control_ping());
+        assert_eq!(ping.await.unwrap().unwrap(), 0);
+        assert!(!pending.is_finished());
+
+        // a reply nobody waits for goes to the pending receive
+        client.send(RawControlPing::default()).await.unwrap();
+        let received = tokio::time::timeout(Duration::from_secs(5), pending).await;
+        assert_eq!(received.unwrap().unwrap().unwrap().retval, 0);
+        client.disconnect().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_keepalive_does_not_wait_for_the_writer() {
+        let (local, remote) = UnixStream::pair().unwrap();
+        let (_, write_half) = local.into_split();
+        let writer = Arc::new(Mutex::new(FramedWrite::new(
+            write_half,
+            VppFrameCodec::new(),
+        )));
+        let count = Arc::new(AtomicU64::new(0));
+        let keepalive =
+            KeepaliveResponder::spawn(1, 2, writer.clone(), count.clone(), CaptureTap::default());
+
+        // a request holding the writer must not keep the reader from answering
+        let held = writer.lock().await;
+        let mut data = Vec::new();
+        RawMemclntKeepalive {
+            client_index: 0,
+            context: 7,
+        }
+        .vpp_encode(&mut data)
+        .unwrap();
+        keepalive.answer(&data).unwrap();
+        assert_eq!(count.load(Ordering::Relaxed), 0);
+        drop(held);
+
+        let mut frames = FramedRead::new(remote, VppFrameCodec::new());
+        let frame = tokio::time::timeout(Duration::from_secs(5), frames.next()).await;
+        let (msg_id, data) = split_frame(frame.unwrap().unwrap().unwrap().freeze()).unwrap();
+        assert_eq!(msg_id, 2);
+        let reply: RawMemclntKeepaliveReply = read_msg_from_slice(&data).unwrap();
+        assert_eq!(reply.context, 7);
+        // the writer task counts the reply once the send has completed
+        let answered = tokio::time::timeout(Duration::from_secs(5), async {
+            while count.load(Ordering::Relaxed) == 0 {
+                tokio::task::yield_now().await;
+            }
+        });
+        answered.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_send_rcv_batch() {
+        let vpp =
+            FakeVpp::start_with_messages(["cli_inband_f8377302", "cli_inband_reply_05879051"])
+                .unwrap();
+        vpp.on::<RawCliInband, _>(|req| RawCliInbandReply {
+            context: 0,
+            retval: if req.cmd.to_string() == "cmd 42" {
+                -7
+            } else {
+                0
+            },
+            reply: req.cmd.to_string().as_str().try_into().unwrap(),
+        });
+        let client = Client::connect(vpp.path(), "batch").await.unwrap();
+
+        let msgs = (0..100).map(|i| RawCliInband::new(&format!("cmd {i}")).unwrap());
+        let batch = client
+            .send_rcv_batch::<_, RawCliInbandReply, _>(msgs, 8)
+            .await
+            .unwrap();
+        assert_eq!(batch.results.len(), 100);
+        assert_eq!(batch.failed(), 1);
+        let (index, err) = batch.first_failure().unwrap();
+        assert_eq!(index, 42);
+        assert_eq!(err.retval(), Some(-7));
+        for (i, result) in batch.results.iter().enumerate().filter(|(i, _)| *i != 42) {
+            assert_eq!(
+                result.as_ref().unwrap().reply.to_string(),
+                format!("cmd {i}")
+            );
+        }
+        client.disconnect().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_refresh_message_table() {
+        let vpp = FakeVpp::start_with_messages([
+            "get_first_msg_id_ebf79a66",
+            "get_first_msg_id_reply_7d337472",
+            "acl_add_replace_ee5c2f18",
+            "acl_del_ef34fea4",
+        ])
+        .unwrap();
+        vpp.on::<RawGetFirstMsgId, _>(|req| RawGetFirstMsgIdReply {
+            context: 0,
+            retval: if req.name.to_string() == "acl_5b3a5b0c" {
+                0
+            } else {
+                -6
+            },
+            first_msg_id: 500,
+        });
+        let client = Client::connect(vpp.path(), "table").await.unwrap();
+        let acl_del = client.get_message_index("acl_del_ef34fea4").unwrap();
+        let acl_add = client
+            .get_message_index("acl_add_replace_ee5c2f18")
+            .unwrap();
+        assert_eq!(acl_del, acl_add + 1);
+
+        assert_eq!(
+            client
+                .refresh_message_table(
+                    "acl_5b3a5b0c",
+                    &["acl_add_replace_ee5c2f18", "acl_del_ef34fea4"]
+                )
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            client
+                .get_message_index("acl_add_replace_ee5c2f18")
+                .unwrap(),
+            500
+        );
+        assert_eq!(client.get_message_index("acl_del_ef34fea4").unwrap(), 501);
+        assert!(
+            client
+                .messages()
+                .any(|(name, id)| name == "acl_del_ef34fea4" && id == 501)
+        );
+        assert!(
+            client
+                .refresh_message_table("nat44_ed_2de0a8b1", &[])
+                .await
+                .is_err()
+        );
+        client.disconnect().await.unwrap();
     }
 }
@@ -0,0 +1,562 @@
+use crate::afunix::client::Client;
+use crate::error::{Result, TransportError};
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::future::Future;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+use vpp_api_encoding::wire::{VppDecode, VppEncode};
+use vpp_api_message::{VppApiMessage, VppApiReply};
+
+/// How often a [`VppFleet`] pings its instances unless told otherwise.
+pub const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long an instance may take to connect or to answer a health check ping.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The health of one VPP instance of a [`VppFleet`], as seen by the last control ping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstanceHealth {
+    /// The instance answered the control ping.
+    Up {
+        /// The pid of the VPP process behind the connection.
+        vpe_pid: u32,
+    },
+    /// The instance did not answer, or could not be connected to.
+    Down {
+        /// Why the instance is considered down.
+        reason: String,
+    },
+}
+
+impl InstanceHealth {
+    /// Returns `true` if the instance answered the last control ping.
+    pub fn is_up(&self) -> bool {
+        matches!(self, InstanceHealth::Up { .. })
+    }
+}
+
+/// The outcome of a request broadcast to a [`VppFleet`], one result per instance.
+#[derive(Debug)]
+pub struct FleetResult<R> {
+    /// The reply or the error of every instance, by instance name.
+    pub results: BTreeMap<String, Result<R>>,
+}
+
+impl<R> FleetResult<R> {
+    /// Returns the number of instances that answered.
+    pub fn succeeded(&self) -> usize {
+        self.results
+            .values()
+            .filter(|result| result.is_ok())
+            .count()
+    }
+
+    /// Returns the number of instances that failed.
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.succeeded()
+    }
+
+    /// Returns the replies of the instances that answered, by instance name.
+    pub fn replies(&self) -> impl Iterator<Item = (&str, &R)> {
+        self.results
+            .iter()
+            .filter_map(|(name, result)| result.as_ref().ok().map(|reply| (name.as_str(), reply)))
+    }
+
+    /// Returns the errors of the instances that failed, by instance name.
+    pub fn errors(&self) -> impl Iterator<Item = (&str, &TransportError)> {
+        self.results
+            .iter()
+            .filter_map(|(name, result)| result.as_ref().err().map(|e| (name.as_str(), e)))
+    }
+
+    /// Returns all replies, or an error describing the first failed instance.
+    ///
+    /// # Returns
+    ///
+    /// The replies by instance name, or a [`TransportError::Instance`] wrapping the
+    /// error of the first failed instance in name order.
+    pub fn into_result(self) -> Result<BTreeMap<String, R>> {
+        let failed = self.failed();
+        let mut replies = BTreeMap::new();
+        for (name, result) in self.results {
+            match result {
+                Ok(reply) => {
+                    replies.insert(name, reply);
+                }
+                Err(e) => {
+                    return Err(TransportError::Instance {
+                        instance: name,
+                        failed,
+                        source: Box::new(e),
+                    });
+                }
+            }
+        }
+        Ok(replies)
+    }
+}
+
+/// One VPP instance of a fleet.
+struct Instance {
+    /// The path of the VPP API socket of the instance.
+    path: String,
+    /// The connection, if there is one.
+    client: Option<Client>,
+    /// The health as of the last control ping.
+    health: InstanceHealth,
+}
+
+/// The state shared by all clones of a [`VppFleet`].
+struct Shared {
+    /// The name every connection registers with.
+    name: String,
+    /// How often the instances are pinged.
+    health_check_interval: Duration,
+    /// The instances, by name.
+    instances: Mutex<BTreeMap<String, Instance>>,
+    /// The task pinging the instances.
+    monitor: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Drop for Shared {
+    fn drop(&mut self) {
+        if let Some(monitor) = self.monitor.get_mut().unwrap().take() {
+            monitor.abort();
+        }
+    }
+}
+
+/// Connections to several VPP instances, e.g. one per network namespace, by name.
+///
+/// Every instance is reached through its own API socket with an afunix
+/// [`Client`]. A background task sends a control ping to every instance each
+/// health check interval and records the outcome; an instance whose connection
+/// was lost is connected to again on the next health check.
+///
+/// Requests go either to a single instance by name, or to all instances at once
+/// with [`VppFleet::broadcast`], which collects one result per instance.
+#[derive(Clone)]
+pub struct VppFleet {
+    shared: Arc<Shared>,
+}
+
+impl VppFleet {
+    /// Creates an empty fleet that pings its instances every [`DEFAULT_HEALTH_CHECK_INTERVAL`].
+    ///
+    /// Must be called from within a tokio runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name to register with every VPP API server
+    pub fn new(name: &str) -> VppFleet {
+        VppFleet::with_health_check_interval(name, DEFAULT_HEALTH_CHECK_INTERVAL)
+    }
+
+    /// Creates an empty fleet that pings its instances every `interval`.
+    ///
+    /// Must be called from within a tokio runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name to register with every VPP API server
+    /// * `interval` - How often to send a control ping to every instance
+    pub fn with_health_check_interval(name: &str, interval: Duration) -> VppFleet {
+        let shared = Arc::new(Shared {
+            name: name.to_string(),
+            health_check_interval: interval,
+            instances: Mutex::new(BTreeMap::new()),
+            monitor: Mutex::new(None),
+        });
+        let monitor = tokio::spawn(VppFleet::monitor(Arc::downgrade(&shared)));
+        *shared.monitor.lock().unwrap() = Some(monitor);
+        VppFleet { shared }
+    }
+
+    /// Pings the instances every health check interval.
+    ///
+    /// Holds the shared state only weakly, so the task ends once the last
+    /// handle is dropped.
+    async fn monitor(shared: Weak<Shared>) {
+        loop {
+            let Some(interval) = shared.upgrade().map(|s| s.health_check_interval) else {
+                return;
+            };
+            tokio::time::sleep(interval).await;
+            let Some(shared) = shared.upgrade() else {
+                return;
+            };
+            VppFleet { shared }.check_health().await;
+        }
+    }
+
+    /// Connects to a VPP instance and adds it to the fleet.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance` - The name the instance is known by in the fleet
+    /// * `path` - The path to the Unix socket of the instance (e.g., "/run/vpp/api.sock")
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the instance answered a control ping, or an error if the name
+    /// is taken or the instance cannot be reached. The instance is not added on error.
+    pub async fn add(&self, instance: &str, path: &str) -> Result<()> {
+        if self.shared.instances.lock().unwrap().contains_key(instance) {
+            return Err(duplicate_instance(instance));
+        }
+        let (client, vpe_pid) = self.connect(path).await?;
+        // Another call may have added the same name while this one was connecting.
+        let duplicate = match self
+            .shared
+            .instances
+            .lock()
+            .unwrap()
+            .entry(instance.to_string())
+        {
+            Entry::Occupied(_) => Some(client),
+            Entry::Vacant(entry) => {
+                entry.insert(Instance {
+                    path: path.to_string(),
+                    client: Some(client),
+                    health: InstanceHealth::Up { vpe_pid },
+                });
+                None
+            }
+        };
+        if let Some(client) = duplicate {
+            client.disconnect().await.ok();
+            return Err(duplicate_instance(instance));
+        }
+        Ok(())
+    }
+
+    /// Removes an instance from the fleet and disconnects from it.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance` - The name of the instance
+    ///
+    /// # Returns
+    ///
+    /// An error if the instance is not part of the fleet or did not acknowledge the disconnect.
+    pub async fn remove(&self, instance: &str) -> Result<()> {
+        let removed = self.shared.instances.lock().unwrap().remove(instance);
+        match removed {
+            Some(Instance {
+                client: Some(client),
+                ..
+            }) if !client.is_closed() => client.disconnect().await,
+            Some(_) => Ok(()),
+            None => Err(unknown_instance(instance)),
+        }
+    }
+
+    /// Returns the names of the instances in the fleet, in order.
+    pub fn instances(&self) -> Vec<String> {
+        self.shared
+            .instances
+            .lock()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the health of an instance as of the last control ping, or `None` if it is not part of the fleet.
+    pub fn health(&self, instance: &str) -> Option<InstanceHealth> {
+        self.shared
+            .instances
+            .lock()
+            .unwrap()
+            .get(instance)
+            .map(|instance| instance.health.clone())
+    }
+
+    /// Returns the health of every instance as of the last control ping, by instance name.
+    pub fn health_all(&self) -> BTreeMap<String, InstanceHealth> {
+        self.shared
+            .instances
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, instance)| (name.clone(), instance.health.clone()))
+            .collect()
+    }
+
+    /// Returns the client connected to an instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance` - The name of the instance
+    ///
+    /// # Returns
+    ///
+    /// The client, or an error if the instance is not part of the fleet or is not connected.
+    pub fn client(&self, instance: &str) -> Result<Client> {
+        let instances = self.shared.instances.lock().unwrap();
+        let entry = instances
+            .get(instance)
+            .ok_or_else(|| unknown_instance(instance))?;
+        match &entry.client {
+            Some(client) if !client.is_closed() => Ok(client.clone()),
+            _ => Err(TransportError::Connection(format!(
+                "instance {instance} is not connected"
+            ))),
+        }
+    }
+
+    /// Sends a control ping to every instance now, reconnecting to the ones whose connection was lost.
+    ///
+    /// The fleet does this on its own every health check interval.
+    ///
+    /// # Returns
+    ///
+    /// The health of every instance, by instance name.
+    pub async fn check_health(&self) -> BTreeMap<String, InstanceHealth> {
+        let targets: Vec<_> = self
+            .shared
+            .instances
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, instance)| (name.clone(), instance.path.clone(), instance.client.clone()))
+            .collect();
+        let checks = targets.into_iter().map(|(name, path, client)| async move {
+            let check = async {
+                match client {
+                    Some(client) if !client.is_closed() => client
+                        .control_ping_reply()
+                        .await
+                        .map(|reply| (client, reply.vpe_pid)),
+                    _ => {
+                        debug!("reconnecting to instance {name} at {path}");
+                        self.connect(&path).await
+                    }
+                }
+            };
+            let checked = tokio::time::timeout(HEALTH_CHECK_TIMEOUT, check)
+                .await
+                .unwrap_or_else(|_| {
+                    Err(TransportError::Connection(format!(
+                        "no answer within {HEALTH_CHECK_TIMEOUT:?}"
+                    )))
+                });
+            (name, path, checked)
+        });
+        let checked = futures::future::join_all(checks).await;
+
+        let mut instances = self.shared.instances.lock().unwrap();
+        for (name, path, checked) in checked {
+            // The instance may have been removed, or replaced, while it was pinged.
+            let Some(instance) = instances.get_mut(&name).filter(|i| i.path == path) else {
+                continue;
+            };
+            match checked {
+                Ok((client, vpe_pid)) => {
+                    instance.client = Some(client);
+                    instance.health = InstanceHealth::Up { vpe_pid };
+                }
+                Err(e) => {
+                    if instance.health.is_up() {
+                        warn!("VPP instance {name} is down: {e}");
+                    }
+                    instance.health = InstanceHealth::Down {
+                        reason: e.to_string(),
+                    };
+                }
+            }
+        }
+        instances
+            .iter()
+            .map(|(name, instance)| (name.clone(), instance.health.clone()))
+            .collect()
+    }
+
+    /// Sends a message to one instance and waits for the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance` - The name of the instance
+    /// * `msg` - The message to send
+    ///
+    /// # Returns
+    ///
+    /// The response message or an error.
+    pub async fn send_rcv<T, R>(&self, instance: &str, msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage,
+    {
+        self.client(instance)?.send_rcv(msg).await
+    }
+
+    /// Sends a message to one instance and fails if the reply carries a negative `retval`.
+    ///
+    /// See [`Client::send_rcv_checked`].
+    pub async fn send_rcv_checked<T, R>(&self, instance: &str, msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage + VppApiReply,
+    {
+        self.client(instance)?.send_rcv_checked(msg).await
+    }
+
+    /// Sends a message to every instance at once and collects the responses.
+    ///
+    /// An instance that is not connected fails with a [`TransportError::Connection`]
+    /// without holding up the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send, cloned for every instance
+    ///
+    /// # Returns
+    ///
+    /// The response or the error of every instance, by instance name.
+    pub async fn broadcast<T, R>(&self, msg: T) -> FleetResult<R>
+    where
+        T: VppEncode + VppApiMessage + Clone,
+        R: VppDecode + VppApiMessage,
+    {
+        self.for_each(|client| {
+            let msg = msg.clone();
+            async move { client.send_rcv(msg).await }
+        })
+        .await
+    }
+
+    /// Sends a message to every instance at once, treating a negative `retval` as a failure.
+    ///
+    /// See [`VppFleet::broadcast`] and [`Client::send_rcv_checked`].
+    pub async fn broadcast_checked<T, R>(&self, msg: T) -> FleetResult<R>
+    where
+        T: VppEncode + VppApiMessage + Clone,
+        R: VppDecode + VppApiMessage + VppApiReply,
+    {
+        self.for_each(|client| {
+            let msg = msg.clone();
+            async move { client.send_rcv_checked(msg).await }
+        })
+        .await
+    }
+
+    /// Disconnects from every instance and stops the health checks.
+    ///
+    /// # Returns
+    ///
+    /// The outcome of the disconnect for every instance, by instance name.
+    pub async fn disconnect(self) -> FleetResult<()> {
+        if let Some(monitor) = self.shared.monitor.lock().unwrap().take() {
+            monitor.abort();
+        }
+        let result = self.for_each(|client| client.disconnect()).await;
+        self.shared.instances.lock().unwrap().clear();
+        result
+    }
+
+    /// Runs `request` against the client of every instance concurrently.
+    async fn for_each<R, F, Fut>(&self, request: F) -> FleetResult<R>
+    where
+        F: Fn(Client) -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        let names = self.instances();
+        let requests = names.iter().map(|name| {
+            let client = self.client(name);
+            let request = &request;
+            async move { request(client?).await }
+        });
+        let results = futures::future::join_all(requests).await;
+        FleetResult {
+            results: names.into_iter().zip(results).collect(),
+        }
+    }
+
+    /// Connects to an instance and pings it.
+    async fn connect(&self, path: &str) -> Result<(Client, u32)> {
+        let client = Client::connect(path, &self.shared.name).await?;
+        let vpe_pid = client.control_ping_reply().await?.vpe_pid;
+        Ok((client, vpe_pid))
+    }
+}
+
+/// The error for a name that is not part of the fleet.
+fn unknown_instance(instance: &str) -> TransportError {
+    TransportError::InvalidRequest(format!("instance {instance} is not part of the fleet"))
+}
+
+/// The error for a name that is already part of the fleet.
+fn duplicate_instance(instance: &str) -> TransportError {
+    TransportError::InvalidRequest(format!("instance {instance} is already part of the fleet"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::{RawCliInband, RawCliInbandReply};
+    use crate::testing::FakeVpp;
+
+    #[tokio::test]
+    async fn test_fleet() {
+        let messages = ["cli_inband_f8377302", "cli_inband_reply_05879051"];
+        let (red, blue) = (
+            FakeVpp::start_with_messages(messages).unwrap(),
+            FakeVpp::start_with_messages(messages).unwrap(),
+        );
+        for (vpp, colour) in [(&red, "red"), (&blue, "blue")] {
+            vpp.on::<RawCliInband, _>(move |req| RawCliInbandReply {
+                context: 0,
+                retval: 0,
+                reply: format!("{colour}: {}", req.cmd)
+                    .as_str()
+                    .try_into()
+                    .unwrap(),
+            });
+        }
+
+        let fleet = VppFleet::with_health_check_interval("fleet", Duration::from_secs(3600));
+        fleet.add("red", red.path()).await.unwrap();
+        fleet.add("blue", blue.path()).await.unwrap();
+        assert!(fleet.add("red", blue.path()).await.is_err());
+        assert_eq!(fleet.instances(), ["blue", "red"]);
+
+        let reply: RawCliInbandReply = fleet
+            .send_rcv("red", RawCliInband::new("show version").unwrap())
+            .await
+            .unwrap();
+        assert_eq!(reply.reply.to_string(), "red: show version");
+
+        let broadcast = fleet
+            .broadcast::<_, RawCliInbandReply>(RawCliInband::new("show run").unwrap())
+            .await;
+        assert_eq!(broadcast.succeeded(), 2);
+        let replies = broadcast.into_result().unwrap();
+        assert_eq!(replies["blue"].reply.to_string(), "blue: show run");
+
+        drop(blue);
+        let health = fleet.check_health().await;
+        assert!(health["red"].is_up());
+        assert!(!health["blue"].is_up());
+        assert_eq!(fleet.health("blue"), Some(health["blue"].clone()));
+
+        let broadcast = fleet
+            .broadcast::<_, RawCliInbandReply>(RawCliInband::new("show run").unwrap())
+            .await;
+        assert_eq!(broadcast.failed(), 1);
+        assert_eq!(broadcast.errors().next().unwrap().0, "blue");
+        assert!(
+            fleet
+                .send_rcv::<_, RawCliInbandReply>("green", RawCliInband::new("x").unwrap())
+                .await
+                .is_err()
+        );
+
+        fleet.remove("blue").await.unwrap();
+        assert_eq!(fleet.instances(), ["red"]);
+        assert_eq!(fleet.disconnect().await.succeeded(), 1);
+    }
+}
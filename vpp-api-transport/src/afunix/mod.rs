@@ -1 +1,4 @@
 pub mod client;
+pub mod fleet;
+pub mod reconnect;
+pub mod sync;
@@ -0,0 +1,539 @@
+use crate::afunix::client::Client;
+use crate::compat::CompatibilityReport;
+use crate::error::{Result, TransportError};
+use crate::events::EventStream;
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::{debug, warn};
+use vpp_api_encoding::wire::{VppDecode, VppEncode};
+use vpp_api_message::{VppApiMessage, VppApiReply, VppApiWantMessage};
+
+/// A change in the state of the connection behind a [`ReconnectingClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// The connection was lost; requests fail until it has been re-established.
+    Disconnected {
+        /// Why the connection was considered lost.
+        reason: String,
+    },
+    /// A new connection has been established and the event subscriptions were renewed.
+    ///
+    /// Configuration pushed into VPP does not survive a restart, so applications
+    /// should replay it when `restarted` is set.
+    Reconnected {
+        /// The pid of the VPP process now serving the connection.
+        vpe_pid: u32,
+        /// Whether VPP itself was restarted, rather than only the socket being dropped.
+        restarted: bool,
+    },
+}
+
+/// Timing of the connection supervision done by a [`ReconnectingClient`].
+#[derive(Debug, Clone)]
+pub struct ReconnectOptions {
+    /// How long to wait between attempts to reconnect.
+    pub retry_interval: Duration,
+    /// How often to send a control ping to detect a restarted VPP.
+    pub health_check_interval: Duration,
+}
+
+impl Default for ReconnectOptions {
+    fn default() -> Self {
+        ReconnectOptions {
+            retry_interval: Duration::from_secs(1),
+            health_check_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Renews a single event subscription on a freshly connected client.
+type Resubscribe = Box<dyn Fn(Client) -> BoxFuture<'static, Result<JoinHandle<()>>> + Send + Sync>;
+
+/// An event subscription that outlives the connection it was made on.
+struct Subscription {
+    /// Identifies the subscription in the registry.
+    id: u64,
+    /// Subscribes again and forwards the events to the subscriber.
+    resubscribe: Resubscribe,
+    /// The task forwarding events from the current connection.
+    pump: Option<JoinHandle<()>>,
+}
+
+/// The state shared by all clones of a [`ReconnectingClient`].
+struct Shared {
+    /// The path of the VPP API socket.
+    path: String,
+    /// The name the client registers with.
+    name: String,
+    /// The supervision timing.
+    options: ReconnectOptions,
+    /// The connection currently in use.
+    current: Mutex<Client>,
+    /// The pid of the VPP process behind the current connection.
+    vpe_pid: AtomicU32,
+    /// The event subscriptions renewed on every reconnect.
+    subscriptions: Mutex<Vec<Subscription>>,
+    /// Source of subscription ids.
+    next_subscription: AtomicU64,
+    /// Listeners for connection events.
+    listeners: Mutex<Vec<mpsc::UnboundedSender<ConnectionEvent>>>,
+    /// The task watching the connection.
+    supervisor: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Drop for Shared {
+    fn drop(&mut self) {
+        if let Some(supervisor) = self.supervisor.get_mut().unwrap().take() {
+            supervisor.abort();
+        }
+        for subscription in self.subscriptions.get_mut().unwrap().drain(..) {
+            if let Some(pump) = subscription.pump {
+                pump.abort();
+            }
+        }
+    }
+}
+
+impl Shared {
+    /// Sends an event to every listener that is still around.
+    fn emit(&self, event: ConnectionEvent) {
+        self.listeners
+            .lock()
+            .unwrap()
+            .retain(|listener| listener.send(event.clone()).is_ok());
+    }
+
+    /// Returns the client for the connection currently in use.
+    fn client(&self) -> Client {
+        self.current.lock().unwrap().clone()
+    }
+
+    /// Installs the forwarding task of a renewed subscription.
+    ///
+    /// The subscription may have been dropped while it was being renewed, in
+    /// which case the new task is stopped right away.
+    fn replace_pump(&self, id: u64, pump: JoinHandle<()>) {
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        match subscriptions.iter_mut().find(|s| s.id == id) {
+            Some(subscription) => {
+                if let Some(old) = subscription.pump.replace(pump) {
+                    old.abort();
+                }
+            }
+            None => pump.abort(),
+        }
+    }
+
+    /// Ends a subscription whose stream was dropped.
+    fn remove_subscription(&self, id: u64) {
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        if let Some(position) = subscriptions.iter().position(|s| s.id == id) {
+            let subscription = subscriptions.remove(position);
+            if let Some(pump) = subscription.pump {
+                pump.abort();
+            }
+        }
+    }
+}
+
+/// An afunix client that survives restarts of VPP.
+///
+/// A background task watches the connection. When the socket is closed, or a
+/// periodic control ping reports a different `vpe_pid`, it connects again with
+/// the same name, which re-runs the `sockclnt_create` handshake and picks up the
+/// new message table. Event subscriptions made through [`ReconnectingClient::subscribe`]
+/// are renewed on the new connection and keep delivering into the same stream.
+///
+/// Requests are never retried: a request in flight when the connection goes away
+/// fails, and requests made while reconnecting fail until the new connection is up.
+#[derive(Clone)]
+pub struct ReconnectingClient {
+    shared: Arc<Shared>,
+}
+
+impl ReconnectingClient {
+    /// Connects to the VPP API server with the default [`ReconnectOptions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the Unix socket (e.g., "/run/vpp/api.sock")
+    /// * `name` - The name to register with the VPP API server
+    ///
+    /// # Returns
+    ///
+    /// A new `ReconnectingClient` if the first connection is successful, or an error otherwise.
+    pub async fn connect(path: &str, name: &str) -> Result<ReconnectingClient> {
+        ReconnectingClient::connect_with_options(path, name, ReconnectOptions::default()).await
+    }
+
+    /// Connects to the VPP API server, supervising the connection as set out in `options`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the Unix socket (e.g., "/run/vpp/api.sock")
+    /// * `name` - The name to register with the VPP API server
+    /// * `options` - How often to check the connection and to retry connecting
+    ///
+    /// # Returns
+    ///
+    /// A new `ReconnectingClient` if the first connection is successful, or an error otherwise.
+    pub async fn connect_with_options(
+        path: &str,
+        name: &str,
+        options: ReconnectOptions,
+    ) -> Result<ReconnectingClient> {
+        let client = Client::connect(path, name).await?;
+        let vpe_pid = client.control_ping_reply().await?.vpe_pid;
+        let shared = Arc::new(Shared {
+            path: path.to_string(),
+            name: name.to_string(),
+            options,
+            current: Mutex::new(client.clone()),
+            vpe_pid: AtomicU32::new(vpe_pid),
+            subscriptions: Mutex::new(vec![]),
+            next_subscription: AtomicU64::new(0),
+            listeners: Mutex::new(vec![]),
+            supervisor: Mutex::new(None),
+        });
+        let supervisor = tokio::spawn(ReconnectingClient::supervise(
+            Arc::downgrade(&shared),
+            client,
+        ));
+        *shared.supervisor.lock().unwrap() = Some(supervisor);
+        Ok(ReconnectingClient { shared })
+    }
+
+    /// Watches the connection and replaces it whenever it is lost.
+    ///
+    /// Holds the shared state only weakly, so the task ends once the last
+    /// handle is dropped.
+    async fn supervise(shared: Weak<Shared>, mut client: Client) {
+        loop {
+            let Some(interval) = shared.upgrade().map(|s| s.options.health_check_interval) else {
+                return;
+            };
+            let reason = tokio::select! {
+                reason = client.closed() => reason,
+                _ = tokio::time::sleep(interval) => {
+                    let Some(shared) = shared.upgrade() else {
+                        return;
+                    };
+                    match client.control_ping_reply().await {
+                        Ok(reply) if reply.vpe_pid == shared.vpe_pid.load(Ordering::Relaxed) => {
+                            continue;
+                        }
+                        Ok(reply) => format!(
+                            "VPP restarted, vpe_pid changed from {} to {}",
+                            shared.vpe_pid.load(Ordering::Relaxed),
+                            reply.vpe_pid
+                        ),
+                        Err(e) if client.is_closed() => e.to_string(),
+                        Err(e) => {
+                            debug!("health check failed: {e}");
+                            continue;
+                        }
+                    }
+                }
+            };
+            warn!("VPP connection lost: {reason}");
+            match shared.upgrade() {
+                Some(shared) => shared.emit(ConnectionEvent::Disconnected { reason }),
+                None => return,
+            }
+            match ReconnectingClient::reestablish(&shared).await {
+                Some(new_client) => client = new_client,
+                None => return,
+            }
+        }
+    }
+
+    /// Connects again until it succeeds, then renews the subscriptions.
+    ///
+    /// # Returns
+    ///
+    /// The new client, or `None` if the last handle was dropped in the meantime.
+    async fn reestablish(shared: &Weak<Shared>) -> Option<Client> {
+        loop {
+            let shared = shared.upgrade()?;
+            let connected = async {
+                let client = Client::connect(&shared.path, &shared.name).await?;
+                let vpe_pid = client.control_ping_reply().await?.vpe_pid;
+                Ok::<_, TransportError>((client, vpe_pid))
+            };
+            let (client, vpe_pid) = match connected.await {
+                Ok(connected) => connected,
+                Err(e) => {
+                    debug!("reconnect to {} failed: {e}", shared.path);
+                    let retry_interval = shared.options.retry_interval;
+                    drop(shared);
+                    tokio::time::sleep(retry_interval).await;
+                    continue;
+                }
+            };
+            let restarted = shared.vpe_pid.swap(vpe_pid, Ordering::Relaxed) != vpe_pid;
+            *shared.current.lock().unwrap() = client.clone();
+
+            let renewals: Vec<_> = shared
+                .subscriptions
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|subscription| (subscription.id, (subscription.resubscribe)(client.clone())))
+                .collect();
+            for (id, renewal) in renewals {
+                match renewal.await {
+                    Ok(pump) => shared.replace_pump(id, pump),
+                    Err(e) => warn!("failed to renew subscription after reconnect: {e}"),
+                }
+            }
+
+            shared.emit(ConnectionEvent::Reconnected { vpe_pid, restarted });
+            return Some(client);
+        }
+    }
+
+    /// Returns a stream of changes in the connection state.
+    ///
+    /// Only events that happen after the call are delivered.
+    pub fn connection_events(&self) -> BoxStream<'static, ConnectionEvent> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.shared.listeners.lock().unwrap().push(sender);
+        UnboundedReceiverStream::new(receiver).boxed()
+    }
+
+    /// Returns the client for the connection currently in use.
+    ///
+    /// The returned client is not replaced on reconnect; it fails once its
+    /// connection is gone.
+    pub fn client(&self) -> Client {
+        self.shared.client()
+    }
+
+    /// Sends a message on the current connection and waits for a response.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send
+    ///
+    /// # Returns
+    ///
+    /// The response message or an error.
+    pub async fn send_rcv<T, R>(&self, msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage,
+    {
+        self.client().send_rcv(msg).await
+    }
+
+    /// Sends a message on the current connection and waits for a response, failing if
+    /// VPP reports an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send
+    ///
+    /// # Returns
+    ///
+    /// The response message, or an error. A negative `retval` in the reply becomes
+    /// a [`crate::TransportError::Retval`].
+    pub async fn send_rcv_checked<T, R>(&self, msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage + VppApiReply,
+    {
+        self.client().send_rcv_checked(msg).await
+    }
+
+    /// Sends a message on the current connection and waits at most `timeout` for a response.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send
+    /// * `timeout` - How long to wait for the reply
+    ///
+    /// # Returns
+    ///
+    /// The response message, or an error if the request failed or timed out.
+    pub async fn send_rcv_with_timeout<T, R>(&self, msg: T, timeout: Duration) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage,
+    {
+        self.client().send_rcv_with_timeout(msg, timeout).await
+    }
+
+    /// Sends a dump request on the current connection and returns a stream of the details.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The dump message to send
+    ///
+    /// # Returns
+    ///
+    /// A stream of decoded details messages, or an error if the request could not be sent.
+    pub async fn send_dump<T, D>(&self, msg: T) -> Result<BoxStream<'static, Result<D>>>
+    where
+        T: VppEncode + VppApiMessage,
+        D: VppDecode + VppApiMessage + Send + 'static,
+    {
+        self.client().send_dump(msg).await
+    }
+
+    /// Subscribes to an event, renewing the subscription on every reconnect.
+    ///
+    /// The returned stream keeps delivering events across reconnects; events sent
+    /// while the connection was down are lost.
+    ///
+    /// # Arguments
+    ///
+    /// * `want` - The `want_*` message that enables the event, e.g. `WantInterfaceEvents`
+    ///
+    /// # Returns
+    ///
+    /// A stream of decoded events, or an error if VPP refused the subscription.
+    pub async fn subscribe<W, E>(&self, want: W) -> Result<EventStream<E>>
+    where
+        W: VppEncode + VppApiMessage + VppApiWantMessage + Clone + Send + Sync + 'static,
+        E: VppDecode + VppApiMessage + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let resubscribe: Resubscribe = Box::new(move |client: Client| {
+            let want = want.clone();
+            let sender = sender.clone();
+            Box::pin(async move {
+                let mut events = client.subscribe::<W, E>(want).await?;
+                Ok(tokio::spawn(async move {
+                    while let Some(event) = events.next().await {
+                        // the error reporting a lost connection is not the subscriber's concern
+                        if event.is_err() && client.is_closed() {
+                            break;
+                        }
+                        if sender.send(event).is_err() {
+                            break;
+                        }
+                    }
+                }))
+            })
+        });
+
+        let pump = resubscribe(self.client()).await?;
+        let id = self
+            .shared
+            .next_subscription
+            .fetch_add(1, Ordering::Relaxed);
+        self.shared
+            .subscriptions
+            .lock()
+            .unwrap()
+            .push(Subscription {
+                id,
+                resubscribe,
+                pump: Some(pump),
+            });
+
+        let shared = Arc::downgrade(&self.shared);
+        let events = UnboundedReceiverStream::new(receiver).boxed();
+        Ok(EventStream::new(events, move || {
+            if let Some(shared) = shared.upgrade() {
+                shared.remove_subscription(id);
+            }
+        }))
+    }
+
+    /// Sends a control ping on the current connection and waits for the reply.
+    ///
+    /// # Returns
+    ///
+    /// The return value from the control ping reply.
+    pub async fn control_ping(&self) -> Result<i32> {
+        self.client().control_ping().await
+    }
+
+    /// Executes a VPP CLI command on the current connection and returns the output.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The CLI command to execute
+    ///
+    /// # Returns
+    ///
+    /// The CLI command output or an error.
+    pub async fn run_cli_inband(&self, cmd: &str) -> Result<String> {
+        self.client().run_cli_inband(cmd).await
+    }
+
+    /// Returns the pid of the VPP process behind the current connection.
+    pub fn vpe_pid(&self) -> u32 {
+        self.shared.vpe_pid.load(Ordering::Relaxed)
+    }
+
+    /// Returns the message id of `name` in the message table of the current connection.
+    pub fn get_message_index(&self, name: &str) -> Result<u16> {
+        self.client().get_message_index(name)
+    }
+
+    /// Returns every message in the message table of the current connection.
+    ///
+    /// See [`Client::messages`].
+    pub fn messages(&self) -> impl Iterator<Item = (String, u16)> + use<> {
+        self.client().messages()
+    }
+
+    /// Updates the message ids of a module on the current connection.
+    ///
+    /// See [`Client::refresh_message_table`].
+    pub async fn refresh_message_table(&self, module: &str, messages: &[&str]) -> Result<usize> {
+        self.client().refresh_message_table(module, messages).await
+    }
+
+    /// Checks messages against the message table of the current connection.
+    ///
+    /// See [`Client::compatibility_report`].
+    pub fn compatibility_report<I>(&self, compiled: I) -> CompatibilityReport
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.client().compatibility_report(compiled)
+    }
+
+    /// Stops supervising the connection and disconnects from the VPP API server.
+    ///
+    /// See [`Client::disconnect`].
+    pub async fn disconnect(self) -> Result<()> {
+        if let Some(supervisor) = self.shared.supervisor.lock().unwrap().take() {
+            supervisor.abort();
+        }
+        self.client().disconnect().await
+    }
+
+    /// Returns `false` while the connection is down, e.g. during a reconnect.
+    pub fn is_connected(&self) -> bool {
+        !self.client().is_closed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_afunix_reconnecting_client() {
+        let client = ReconnectingClient::connect("/run/vpp/api.sock", "reconnecting-client")
+            .await
+            .unwrap();
+        assert!(client.is_connected());
+        assert_ne!(client.vpe_pid(), 0);
+
+        let res = client.control_ping().await.unwrap();
+        assert_eq!(res, 0);
+    }
+}
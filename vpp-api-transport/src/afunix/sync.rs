@@ -0,0 +1,416 @@
+use crate::codec::VppFrameCodec;
+use crate::dump::{DumpIds, decode_dump_reply};
+use crate::error::{Result, TransportError, check_retval, retval_error};
+use crate::helpers::*;
+use crate::message::*;
+use crate::router::reply_context;
+use crate::table::MessageTable;
+use bytes::{Bytes, BytesMut};
+use std::collections::HashMap;
+use std::io::{ErrorKind, Read, Write};
+use std::net::Shutdown;
+use std::os::unix::net::UnixStream;
+use std::time::Duration;
+use tokio_util::codec::{Decoder, Encoder};
+use tracing::debug;
+use vpp_api_encoding::wire::{VppDecode, VppEncode};
+use vpp_api_message::{VppApiMessage, VppApiReply};
+
+/// How long a read or write on the socket may block unless told otherwise.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The most bytes read off the socket at once.
+const READ_CHUNK_LEN: usize = 16 * 1024;
+
+/// A blocking client for the VPP API over a Unix socket, for programs without a tokio runtime.
+///
+/// It speaks the same protocol as [`crate::afunix::client::Client`] and shares its
+/// framing, but every call blocks the calling thread until VPP answers or the socket
+/// timeout expires. Requests are sent one at a time; a reply that arrives for an
+/// earlier request that timed out is discarded.
+pub struct Client {
+    /// The connection to VPP.
+    stream: UnixStream,
+    /// Splits the bytes read off the socket into frames and frames outgoing messages.
+    codec: VppFrameCodec,
+    /// Bytes read off the socket that do not make up a whole frame yet.
+    read_buf: BytesMut,
+    /// The frames encoded for the socket, reused between writes.
+    write_buf: BytesMut,
+    /// The unique client index assigned by the VPP API server.
+    client_index: u32,
+    /// The context of the last request sent.
+    context_id: u32,
+    /// The message table VPP sent during the handshake, by `name_crc`.
+    messages: MessageTable,
+    /// The number of keepalives answered on this connection.
+    keepalives: u64,
+}
+
+impl Client {
+    /// Establishes a connection to the VPP API server at the specified socket path.
+    ///
+    /// Reads and writes on the socket time out after [`DEFAULT_TIMEOUT`], see
+    /// [`Client::set_timeout`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the Unix socket (e.g., "/run/vpp/api.sock")
+    /// * `name` - The name to register with the VPP API server
+    ///
+    /// # Returns
+    ///
+    /// A new `Client` instance if the connection is successful, or an error otherwise.
+    pub fn connect(path: &str, name: &str) -> Result<Client> {
+        let stream = UnixStream::connect(path)
+            .map_err(|e| TransportError::Connection(format!("{path}: {e}")))?;
+        let mut handshake = HashMap::new();
+        handshake.insert(
+            MsgSockClntCreate::get_message_name_and_crc(),
+            MsgSockClntCreate::get_message_id(),
+        );
+        handshake.insert(
+            MsgSockClntCreateReplyHdr::get_message_name_and_crc(),
+            MsgSockClntCreateReplyHdr::get_message_id(),
+        );
+
+        let mut client = Client {
+            stream,
+            codec: VppFrameCodec::new(),
+            read_buf: BytesMut::new(),
+            write_buf: BytesMut::new(),
+            client_index: 0,
+            context_id: 0,
+            messages: handshake
+                .iter()
+                .map(|(name, id)| (name.clone(), *id))
+                .collect(),
+            keepalives: 0,
+        };
+        client.set_timeout(Some(DEFAULT_TIMEOUT))?;
+
+        let create_msg: MsgSockClntCreate = name.try_into()?;
+        client.write(&create_msg)?;
+        let frame = client.read_frame()?;
+        let reply: MsgSockClntCreateReplyHdr = read_object_from_slice(&frame, &handshake)?;
+
+        for msg_entry in reply.message_table.0.iter() {
+            client
+                .messages
+                .insert(msg_entry.name.to_string(), msg_entry.index);
+        }
+        client.client_index = reply.index;
+        Ok(client)
+    }
+
+    /// Sets how long a read or write on the socket may block.
+    ///
+    /// A request whose reply does not arrive in time fails with a
+    /// [`TransportError::Connection`]; the client stays usable.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The timeout, or `None` to block indefinitely
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) -> Result<()> {
+        self.stream.set_read_timeout(timeout)?;
+        self.stream.set_write_timeout(timeout)?;
+        Ok(())
+    }
+
+    /// Sends a message and waits for a response.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send
+    ///
+    /// # Returns
+    ///
+    /// The response message or an error.
+    pub fn send_rcv<T, R>(&mut self, mut msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage,
+    {
+        let expected_msg_id = self.get_message_index(&R::get_message_name_and_crc())?;
+        let context = self.prepare(&mut msg);
+        self.write(&msg)?;
+
+        let (msg_id, data) = self.read_reply(context)?;
+        if msg_id != expected_msg_id {
+            return Err(unexpected_message::<R>(msg_id, expected_msg_id));
+        }
+        read_msg_from_slice(&data)
+    }
+
+    /// Sends a message and waits for a response, failing if VPP reports an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send
+    ///
+    /// # Returns
+    ///
+    /// The response message, or an error. A negative `retval` in the reply becomes
+    /// a [`TransportError::Retval`].
+    pub fn send_rcv_checked<T, R>(&mut self, msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage + VppApiReply,
+    {
+        check_retval::<T, R>(self.send_rcv(msg)?)
+    }
+
+    /// Sends a dump request and collects the details messages it produces.
+    ///
+    /// The dump is followed by a control ping with the same context, whose reply
+    /// marks the end of the dump.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The dump message to send
+    ///
+    /// # Returns
+    ///
+    /// The decoded details messages, or an error.
+    pub fn send_dump<T, D>(&mut self, mut msg: T) -> Result<Vec<D>>
+    where
+        T: VppEncode + VppApiMessage,
+        D: VppDecode + VppApiMessage,
+    {
+        let ids = DumpIds {
+            details: self.get_message_index(&D::get_message_name_and_crc())?,
+            done: self.get_message_index(&RawControlPingReply::get_message_name_and_crc())?,
+        };
+        let context = self.prepare(&mut msg);
+        let mut ping = RawControlPing::default();
+        ping.set_client_index(self.client_index);
+        ping.set_context(context);
+        self.write(&msg)?;
+        self.write(&ping)?;
+
+        let mut details = vec![];
+        loop {
+            let (msg_id, data) = self.read_reply(context)?;
+            match decode_dump_reply(msg_id, &data, ids)? {
+                Some(item) => details.push(item),
+                None => return Ok(details),
+            }
+        }
+    }
+
+    /// Sends a message to the VPP API server without waiting for a reply.
+    ///
+    /// Any reply VPP sends for this message is discarded by later requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send
+    pub fn send<T>(&mut self, mut msg: T) -> Result<()>
+    where
+        T: VppEncode + VppApiMessage,
+    {
+        self.prepare(&mut msg);
+        self.write(&msg)
+    }
+
+    /// Sends a control ping message to the VPP API server and waits for the reply.
+    ///
+    /// # Returns
+    ///
+    /// The return value from the control ping reply.
+    pub fn control_ping(&mut self) -> Result<i32> {
+        let reply: RawControlPingReply = self.send_rcv(RawControlPing::default())?;
+        Ok(reply.retval)
+    }
+
+    /// Executes a VPP CLI command and returns the output.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The CLI command to execute
+    ///
+    /// # Returns
+    ///
+    /// The CLI command output or an error.
+    pub fn run_cli_inband(&mut self, cmd: &str) -> Result<String> {
+        let reply: RawCliInbandReply = self.send_rcv(RawCliInband::new(cmd)?)?;
+        Ok(reply.reply.to_string())
+    }
+
+    /// Returns the client index assigned by the VPP API server.
+    pub fn get_client_index(&self) -> u32 {
+        self.client_index
+    }
+
+    /// Returns the number of `memclnt_keepalive` messages answered on this connection.
+    pub fn keepalive_count(&self) -> u64 {
+        self.keepalives
+    }
+
+    /// Returns the message id of `name` in the message table of the connection.
+    pub fn get_message_index(&self, name: &str) -> Result<u16> {
+        self.messages.id(name)
+    }
+
+    /// Returns the message table of the connection.
+    pub fn messages(&self) -> &MessageTable {
+        &self.messages
+    }
+
+    /// Asks VPP where the messages of a module start now and updates the message table.
+    ///
+    /// See [`crate::afunix::Client::refresh_message_table`].
+    pub fn refresh_message_table(&mut self, module: &str, messages: &[&str]) -> Result<usize> {
+        let reply: RawGetFirstMsgIdReply = self.send_rcv_checked(RawGetFirstMsgId::new(module)?)?;
+        self.messages.rebase(messages, reply.first_msg_id)
+    }
+
+    /// Deregisters the client from the VPP API server and closes the connection.
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) if VPP confirmed the delete, or an error otherwise.
+    pub fn disconnect(mut self) -> Result<()> {
+        let delete = RawSockclntDelete {
+            index: self.client_index,
+            ..Default::default()
+        };
+        let reply = self.send_rcv::<_, RawSockclntDeleteReply>(delete);
+        self.stream.shutdown(Shutdown::Both).ok();
+        let reply = reply?;
+        if reply.response != 0 {
+            return Err(retval_error::<RawSockclntDelete>(reply.response));
+        }
+        Ok(())
+    }
+
+    /// Assigns the client index and the next context to a request.
+    fn prepare<T: VppApiMessage>(&mut self, msg: &mut T) -> u32 {
+        self.context_id = self.context_id.wrapping_add(1);
+        msg.set_client_index(self.client_index);
+        msg.set_context(self.context_id);
+        self.context_id
+    }
+
+    /// Encodes and writes a single framed message to the socket.
+    fn write<T>(&mut self, msg: &T) -> Result<()>
+    where
+        T: VppEncode + VppApiMessage,
+    {
+        let msg_id = self.get_message_index(&T::get_message_name_and_crc())?;
+        self.write_buf.clear();
+        self.codec.encode((msg_id, msg), &mut self.write_buf)?;
+        self.stream
+            .write_all(&self.write_buf)
+            .map_err(|e| io_error("write", e))
+    }
+
+    /// Reads frames until the reply for `context` arrives, answering keepalives on the way.
+    ///
+    /// # Returns
+    ///
+    /// The message id and the payload of the reply.
+    fn read_reply(&mut self, context: u32) -> Result<(u16, Bytes)> {
+        let keepalive = self
+            .get_message_index(&RawMemclntKeepalive::get_message_name_and_crc())
+            .ok();
+        loop {
+            let frame = self.read_frame()?;
+            let (msg_id, data) = split_frame(frame.freeze())?;
+            if Some(msg_id) == keepalive {
+                self.answer_keepalive(&data)?;
+            } else if reply_context(&data) == Some(context) {
+                return Ok((msg_id, data));
+            } else {
+                debug!("discarding message {msg_id} not for context {context}");
+            }
+        }
+    }
+
+    /// Replies to the keepalive carried in `data`.
+    fn answer_keepalive(&mut self, data: &[u8]) -> Result<()> {
+        let keepalive: RawMemclntKeepalive = read_msg_from_slice(data)?;
+        self.write(&RawMemclntKeepaliveReply::answering(&keepalive))?;
+        self.keepalives += 1;
+        Ok(())
+    }
+
+    /// Reads the next frame off the socket.
+    ///
+    /// Bytes of a frame that is cut short by a timeout stay buffered, so the next
+    /// read picks up where this one stopped.
+    fn read_frame(&mut self) -> Result<BytesMut> {
+        loop {
+            if let Some(frame) = self.codec.decode(&mut self.read_buf)? {
+                return Ok(frame);
+            }
+            let mut chunk = [0; READ_CHUNK_LEN];
+            match self.stream.read(&mut chunk) {
+                Ok(0) => {
+                    return Err(TransportError::Connection(
+                        "connection closed by VPP".into(),
+                    ));
+                }
+                Ok(n) => self.read_buf.extend_from_slice(&chunk[..n]),
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(io_error("read", e)),
+            }
+        }
+    }
+}
+
+/// Turns a failed read or write on the socket into a [`TransportError::Timeout`] or
+/// a [`TransportError::Connection`].
+fn io_error(op: &str, e: std::io::Error) -> TransportError {
+    match e.kind() {
+        ErrorKind::WouldBlock | ErrorKind::TimedOut => {
+            TransportError::Timeout(format!("{op} timed out"))
+        }
+        _ => TransportError::Connection(format!("{op} failed: {e}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::FakeVpp;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_sync_client() {
+        let vpp =
+            FakeVpp::start_with_messages(["cli_inband_f8377302", "cli_inband_reply_05879051"])
+                .unwrap();
+        vpp.on::<RawCliInband, _>(|req| {
+            if req.cmd.to_string() == "slow" {
+                std::thread::sleep(Duration::from_millis(300));
+            }
+            RawCliInbandReply {
+                context: 0,
+                retval: 0,
+                reply: req.cmd.to_string().as_str().try_into().unwrap(),
+            }
+        });
+        let path = vpp.path().to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let mut client = Client::connect(&path, "sync").unwrap();
+            assert_eq!(client.control_ping().unwrap(), 0);
+            assert_eq!(
+                client.run_cli_inband("show version").unwrap(),
+                "show version"
+            );
+
+            client
+                .set_timeout(Some(Duration::from_millis(100)))
+                .unwrap();
+            let err = client.run_cli_inband("slow").unwrap_err();
+            assert!(matches!(err, TransportError::Timeout(_)));
+            client.set_timeout(Some(DEFAULT_TIMEOUT)).unwrap();
+            // the late reply to "slow" is skipped
+            assert_eq!(client.run_cli_inband("fast").unwrap(), "fast");
+            client.disconnect().unwrap();
+        })
+        .await
+        .unwrap();
+    }
+}
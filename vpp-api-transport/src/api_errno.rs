@@ -0,0 +1,903 @@
+/// An error VPP reports in the `retval` of a reply, as listed in `vnet/api_errno.h`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VppApiError {
+	/// Unspecified Error
+	Unspecified,
+	/// Invalid sw_if_index
+	InvalidSwIfIndex,
+	/// No such FIB / VRF
+	NoSuchFib,
+	/// No such inner FIB / VRF
+	NoSuchInnerFib,
+	/// No such label
+	NoSuchLabel,
+	/// No such entry
+	NoSuchEntry,
+	/// Invalid value
+	InvalidValue,
+	/// Invalid value #2
+	InvalidValue2,
+	/// Unimplemented
+	Unimplemented,
+	/// Invalid sw_if_index #2
+	InvalidSwIfIndex2,
+	/// System call error #1
+	SyscallError1,
+	/// System call error #2
+	SyscallError2,
+	/// System call error #3
+	SyscallError3,
+	/// System call error #4
+	SyscallError4,
+	/// System call error #5
+	SyscallError5,
+	/// System call error #6
+	SyscallError6,
+	/// System call error #7
+	SyscallError7,
+	/// System call error #8
+	SyscallError8,
+	/// System call error #9
+	SyscallError9,
+	/// System call error #10
+	SyscallError10,
+	/// Feature disabled by configuration
+	FeatureDisabled,
+	/// Invalid registration
+	InvalidRegistration,
+	/// Next hop not in FIB
+	NextHopNotInFib,
+	/// Unknown destination
+	UnknownDestination,
+	/// No paths specified in route
+	NoPathsInRoute,
+	/// Next hop not found (multipath)
+	NextHopNotFoundMp,
+	/// No matching interface for probe
+	NoMatchingInterface,
+	/// Invalid VLAN
+	InvalidVlan,
+	/// VLAN subif already exists
+	VlanAlreadyExists,
+	/// Invalid src address
+	InvalidSrcAddress,
+	/// Invalid dst address
+	InvalidDstAddress,
+	/// Address length mismatch
+	AddressLengthMismatch,
+	/// Address not found for interface
+	AddressNotFoundForInterface,
+	/// Address not deletable
+	AddressNotDeletable,
+	/// ip6 not enabled
+	Ip6NotEnabled,
+	/// No such graph node
+	NoSuchNode,
+	/// No such graph node #2
+	NoSuchNode2,
+	/// No such table
+	NoSuchTable,
+	/// No such table #2
+	NoSuchTable2,
+	/// No such table #3
+	NoSuchTable3,
+	/// Subinterface already exists
+	SubifAlreadyExists,
+	/// Subinterface creation failed
+	SubifCreateFailed,
+	/// Invalid memory size requested
+	InvalidMemorySize,
+	/// Invalid interface
+	InvalidInterface,
+	/// Invalid number of tags for requested operation
+	InvalidVlanTagCount,
+	/// Invalid argument
+	InvalidArgument,
+	/// Unexpected interface state
+	UnexpectedIntfState,
+	/// Tunnel already exists
+	TunnelExist,
+	/// Invalid decap-next
+	InvalidDecapNext,
+	/// Response not ready
+	ResponseNotReady,
+	/// Not connected to the data plane
+	NotConnected,
+	/// Interface already exists
+	IfAlreadyExists,
+	/// Operation not allowed on slave of BondEthernet
+	BondSlaveNotAllowed,
+	/// Value already exists
+	ValueExist,
+	/// Source and destination are the same
+	SameSrcDst,
+	/// IP6 multicast address required
+	Ip6MulticastAddressNotPresent,
+	/// Segment routing policy name required
+	SrPolicyNameNotPresent,
+	/// Not running as root
+	NotRunningAsRoot,
+	/// Connection to the data plane already exists
+	AlreadyConnected,
+	/// Unsupported JNI version
+	UnsupportedJniVersion,
+	/// IP prefix invalid (masked bits set in address
+	IpPrefixInvalid,
+	/// Invalid worker thread
+	InvalidWorker,
+	/// LISP is disabled
+	LispDisabled,
+	/// Classify table not found
+	ClassifyTableNotFound,
+	/// Unsupported LISP EID type
+	InvalidEidType,
+	/// Cannot create pcap file
+	CannotCreatePcapFile,
+	/// Invalid adjacency type for this operation
+	IncorrectAdjacencyType,
+	/// Operation would exceed configured capacity of ranges
+	ExceededNumberOfRangesCapacity,
+	/// Operation would exceed capacity of number of ports
+	ExceededNumberOfPortsCapacity,
+	/// Invalid address family
+	InvalidAddressFamily,
+	/// Invalid sub-interface sw_if_index
+	InvalidSubSwIfIndex,
+	/// Table too big
+	TableTooBig,
+	/// Cannot enable/disable feature
+	CannotEnableDisableFeature,
+	/// Duplicate BFD object
+	BfdEexist,
+	/// No such BFD object
+	BfdEnoent,
+	/// BFD object in use
+	BfdEinuse,
+	/// BFD feature not supported
+	BfdNotsupp,
+	/// Address in use
+	AddressInUse,
+	/// Address not in use
+	AddressNotInUse,
+	/// Queue full
+	QueueFull,
+	/// Unsupported application config
+	AppUnsupportedCfg,
+	/// URI FIFO segment create failed
+	UriFifoCreateFailed,
+	/// RLOC address is local
+	LispRlocLocal,
+	/// BFD object cannot be manipulated at this time
+	BfdEagain,
+	/// Invalid GPE mode
+	InvalidGpeMode,
+	/// LISP GPE entries are present
+	LispGpeEntriesPresent,
+	/// Address found for interface
+	AddressFoundForInterface,
+	/// Session failed to connect
+	SessionConnect,
+	/// Entry already exists
+	EntryAlreadyExists,
+	/// Svm segment create fail
+	SvmSegmentCreateFail,
+	/// application not attached
+	ApplicationNotAttached,
+	/// Bridge domain already exists
+	BdAlreadyExists,
+	/// Bridge domain has member interfaces
+	BdInUse,
+	/// Bridge domain 0 can't be deleted/modified
+	BdNotModifiable,
+	/// Bridge domain ID exceeds 16M limit
+	BdIdExceedMax,
+	/// Subinterface doesn't exist
+	SubifDoesntExist,
+	/// Client already exist for L2 MACs events
+	L2MacsEventClinetPresent,
+	/// Invalid queue
+	InvalidQueue,
+	/// Unsupported
+	Unsupported,
+	/// Address already present on another interface
+	DuplicateIfAddress,
+	/// Invalid application namespace
+	AppInvalidNs,
+	/// Wrong app namespace secret
+	AppWrongNsSecret,
+	/// Connect scope
+	AppConnectScope,
+	/// App already attached
+	AppAlreadyAttached,
+	/// Redirect failed
+	SessionRedirect,
+	/// Illegal name
+	IllegalName,
+	/// No name servers configured
+	NoNameServers,
+	/// Name server not found
+	NameServerNotFound,
+	/// Name resolution not enabled
+	NameResolutionNotEnabled,
+	/// Server format error (bug!)
+	NameServerFormatError,
+	/// No such name
+	NameServerNoSuchName,
+	/// No addresses available
+	NameServerNoAddresses,
+	/// Retry with new server
+	NameServerNextServer,
+	/// Connect was filtered
+	AppConnectFiltered,
+	/// Inbound ACL in use
+	AclInUseInbound,
+	/// Outbound ACL in use
+	AclInUseOutbound,
+	/// Initialization Failed
+	InitFailed,
+	/// Netlink error
+	NetlinkError,
+	/// BIER bit-string-length unsupported
+	BierBslUnsup,
+	/// Instance in use
+	InstanceInUse,
+	/// Session ID out of range
+	InvalidSessionId,
+	/// ACL in use by a lookup context
+	AclInUseByLookupContext,
+	/// Invalid value #3
+	InvalidValue3,
+	/// Interface is not an Ethernet interface
+	NonEthernet,
+	/// Bridge domain already has a BVI interface
+	BdAlreadyHasBvi,
+	/// Invalid Protocol
+	InvalidProtocol,
+	/// Invalid Algorithm
+	InvalidAlgorithm,
+	/// Resource In Use
+	RsrcInUse,
+	/// invalid Key Length
+	KeyLength,
+	/// Unsupported FIB Path protocol
+	FibPathUnsupportedNhProto,
+	/// Endian mismatch detected
+	ApiEndianFailed,
+	/// No change in table
+	NoChange,
+	/// Missing certifcate or key
+	MissingCertKey,
+	/// limit exceeded
+	LimitExceeded,
+	/// port not managed by IKE
+	IkeNoPort,
+	/// UDP port already taken
+	UdpPortTaken,
+	/// Retry stream call with cursor
+	Eagain,
+	/// Invalid value #4
+	InvalidValue4,
+	/// Busy
+	Busy,
+	/// Bug
+	Bug,
+	/// Feature already disabled
+	FeatureAlreadyDisabled,
+	/// Feature already enabled
+	FeatureAlreadyEnabled,
+	/// Invalid prefix length
+	InvalidPrefixLength,
+	/// A negative retval these bindings do not know.
+	Unknown(i32),
+}
+
+impl VppApiError {
+	/// Returns the error a `retval` stands for, or `None` if it does not signal an error.
+	pub fn from_retval(retval: i32) -> Option<VppApiError> {
+		match retval {
+			0.. => None,
+			-1 => Some(VppApiError::Unspecified),
+			-2 => Some(VppApiError::InvalidSwIfIndex),
+			-3 => Some(VppApiError::NoSuchFib),
+			-4 => Some(VppApiError::NoSuchInnerFib),
+			-5 => Some(VppApiError::NoSuchLabel),
+			-6 => Some(VppApiError::NoSuchEntry),
+			-7 => Some(VppApiError::InvalidValue),
+			-8 => Some(VppApiError::InvalidValue2),
+			-9 => Some(VppApiError::Unimplemented),
+			-10 => Some(VppApiError::InvalidSwIfIndex2),
+			-11 => Some(VppApiError::SyscallError1),
+			-12 => Some(VppApiError::SyscallError2),
+			-13 => Some(VppApiError::SyscallError3),
+			-14 => Some(VppApiError::SyscallError4),
+			-15 => Some(VppApiError::SyscallError5),
+			-16 => Some(VppApiError::SyscallError6),
+			-17 => Some(VppApiError::SyscallError7),
+			-18 => Some(VppApiError::SyscallError8),
+			-19 => Some(VppApiError::SyscallError9),
+			-20 => Some(VppApiError::SyscallError10),
+			-30 => Some(VppApiError::FeatureDisabled),
+			-31 => Some(VppApiError::InvalidRegistration),
+			-50 => Some(VppApiError::NextHopNotInFib),
+			-51 => Some(VppApiError::UnknownDestination),
+			-52 => Some(VppApiError::NoPathsInRoute),
+			-53 => Some(VppApiError::NextHopNotFoundMp),
+			-54 => Some(VppApiError::NoMatchingInterface),
+			-55 => Some(VppApiError::InvalidVlan),
+			-56 => Some(VppApiError::VlanAlreadyExists),
+			-57 => Some(VppApiError::InvalidSrcAddress),
+			-58 => Some(VppApiError::InvalidDstAddress),
+			-59 => Some(VppApiError::AddressLengthMismatch),
+			-60 => Some(VppApiError::AddressNotFoundForInterface),
+			-61 => Some(VppApiError::AddressNotDeletable),
+			-62 => Some(VppApiError::Ip6NotEnabled),
+			-63 => Some(VppApiError::NoSuchNode),
+			-64 => Some(VppApiError::NoSuchNode2),
+			-65 => Some(VppApiError::NoSuchTable),
+			-66 => Some(VppApiError::NoSuchTable2),
+			-67 => Some(VppApiError::NoSuchTable3),
+			-68 => Some(VppApiError::SubifAlreadyExists),
+			-69 => Some(VppApiError::SubifCreateFailed),
+			-70 => Some(VppApiError::InvalidMemorySize),
+			-71 => Some(VppApiError::InvalidInterface),
+			-72 => Some(VppApiError::InvalidVlanTagCount),
+			-73 => Some(VppApiError::InvalidArgument),
+			-74 => Some(VppApiError::UnexpectedIntfState),
+			-75 => Some(VppApiError::TunnelExist),
+			-76 => Some(VppApiError::InvalidDecapNext),
+			-77 => Some(VppApiError::ResponseNotReady),
+			-78 => Some(VppApiError::NotConnected),
+			-79 => Some(VppApiError::IfAlreadyExists),
+			-80 => Some(VppApiError::BondSlaveNotAllowed),
+			-81 => Some(VppApiError::ValueExist),
+			-82 => Some(VppApiError::SameSrcDst),
+			-83 => Some(VppApiError::Ip6MulticastAddressNotPresent),
+			-84 => Some(VppApiError::SrPolicyNameNotPresent),
+			-85 => Some(VppApiError::NotRunningAsRoot),
+			-86 => Some(VppApiError::AlreadyConnected),
+			-87 => Some(VppApiError::UnsupportedJniVersion),
+			-88 => Some(VppApiError::IpPrefixInvalid),
+			-89 => Some(VppApiError::InvalidWorker),
+			-90 => Some(VppApiError::LispDisabled),
+			-91 => Some(VppApiError::ClassifyTableNotFound),
+			-92 => Some(VppApiError::InvalidEidType),
+			-93 => Some(VppApiError::CannotCreatePcapFile),
+			-94 => Some(VppApiError::IncorrectAdjacencyType),
+			-95 => Some(VppApiError::ExceededNumberOfRangesCapacity),
+			-96 => Some(VppApiError::ExceededNumberOfPortsCapacity),
+			-97 => Some(VppApiError::InvalidAddressFamily),
+			-98 => Some(VppApiError::InvalidSubSwIfIndex),
+			-99 => Some(VppApiError::TableTooBig),
+			-100 => Some(VppApiError::CannotEnableDisableFeature),
+			-101 => Some(VppApiError::BfdEexist),
+			-102 => Some(VppApiError::BfdEnoent),
+			-103 => Some(VppApiError::BfdEinuse),
+			-104 => Some(VppApiError::BfdNotsupp),
+			-105 => Some(VppApiError::AddressInUse),
+			-106 => Some(VppApiError::AddressNotInUse),
+			-107 => Some(VppApiError::QueueFull),
+			-108 => Some(VppApiError::AppUnsupportedCfg),
+			-109 => Some(VppApiError::UriFifoCreateFailed),
+			-110 => Some(VppApiError::LispRlocLocal),
+			-111 => Some(VppApiError::BfdEagain),
+			-112 => Some(VppApiError::InvalidGpeMode),
+			-113 => Some(VppApiError::LispGpeEntriesPresent),
+			-114 => Some(VppApiError::AddressFoundForInterface),
+			-115 => Some(VppApiError::SessionConnect),
+			-116 => Some(VppApiError::EntryAlreadyExists),
+			-117 => Some(VppApiError::SvmSegmentCreateFail),
+			-118 => Some(VppApiError::ApplicationNotAttached),
+			-119 => Some(VppApiError::BdAlreadyExists),
+			-120 => Some(VppApiError::BdInUse),
+			-121 => Some(VppApiError::BdNotModifiable),
+			-122 => Some(VppApiError::BdIdExceedMax),
+			-123 => Some(VppApiError::SubifDoesntExist),
+			-124 => Some(VppApiError::L2MacsEventClinetPresent),
+			-125 => Some(VppApiError::InvalidQueue),
+			-126 => Some(VppApiError::Unsupported),
+			-127 => Some(VppApiError::DuplicateIfAddress),
+			-128 => Some(VppApiError::AppInvalidNs),
+			-129 => Some(VppApiError::AppWrongNsSecret),
+			-130 => Some(VppApiError::AppConnectScope),
+			-131 => Some(VppApiError::AppAlreadyAttached),
+			-132 => Some(VppApiError::SessionRedirect),
+			-133 => Some(VppApiError::IllegalName),
+			-134 => Some(VppApiError::NoNameServers),
+			-135 => Some(VppApiError::NameServerNotFound),
+			-136 => Some(VppApiError::NameResolutionNotEnabled),
+			-137 => Some(VppApiError::NameServerFormatError),
+			-138 => Some(VppApiError::NameServerNoSuchName),
+			-139 => Some(VppApiError::NameServerNoAddresses),
+			-140 => Some(VppApiError::NameServerNextServer),
+			-141 => Some(VppApiError::AppConnectFiltered),
+			-142 => Some(VppApiError::AclInUseInbound),
+			-143 => Some(VppApiError::AclInUseOutbound),
+			-144 => Some(VppApiError::InitFailed),
+			-145 => Some(VppApiError::NetlinkError),
+			-146 => Some(VppApiError::BierBslUnsup),
+			-147 => Some(VppApiError::InstanceInUse),
+			-148 => Some(VppApiError::InvalidSessionId),
+			-149 => Some(VppApiError::AclInUseByLookupContext),
+			-150 => Some(VppApiError::InvalidValue3),
+			-151 => Some(VppApiError::NonEthernet),
+			-152 => Some(VppApiError::BdAlreadyHasBvi),
+			-153 => Some(VppApiError::InvalidProtocol),
+			-154 => Some(VppApiError::InvalidAlgorithm),
+			-155 => Some(VppApiError::RsrcInUse),
+			-156 => Some(VppApiError::KeyLength),
+			-157 => Some(VppApiError::FibPathUnsupportedNhProto),
+			-159 => Some(VppApiError::ApiEndianFailed),
+			-160 => Some(VppApiError::NoChange),
+			-161 => Some(VppApiError::MissingCertKey),
+			-162 => Some(VppApiError::LimitExceeded),
+			-163 => Some(VppApiError::IkeNoPort),
+			-164 => Some(VppApiError::UdpPortTaken),
+			-165 => Some(VppApiError::Eagain),
+			-166 => Some(VppApiError::InvalidValue4),
+			-167 => Some(VppApiError::Busy),
+			-168 => Some(VppApiError::Bug),
+			-169 => Some(VppApiError::FeatureAlreadyDisabled),
+			-170 => Some(VppApiError::FeatureAlreadyEnabled),
+			-171 => Some(VppApiError::InvalidPrefixLength),
+			_ => Some(VppApiError::Unknown(retval)),
+		}
+	}
+
+	/// Returns the `retval` VPP uses for the error.
+	pub fn retval(&self) -> i32 {
+		match self {
+			VppApiError::Unspecified => -1,
+			VppApiError::InvalidSwIfIndex => -2,
+			VppApiError::NoSuchFib => -3,
+			VppApiError::NoSuchInnerFib => -4,
+			VppApiError::NoSuchLabel => -5,
+			VppApiError::NoSuchEntry => -6,
+			VppApiError::InvalidValue => -7,
+			VppApiError::InvalidValue2 => -8,
+			VppApiError::Unimplemented => -9,
+			VppApiError::InvalidSwIfIndex2 => -10,
+			VppApiError::SyscallError1 => -11,
+			VppApiError::SyscallError2 => -12,
+			VppApiError::SyscallError3 => -13,
+			VppApiError::SyscallError4 => -14,
+			VppApiError::SyscallError5 => -15,
+			VppApiError::SyscallError6 => -16,
+			VppApiError::SyscallError7 => -17,
+			VppApiError::SyscallError8 => -18,
+			VppApiError::SyscallError9 => -19,
+			VppApiError::SyscallError10 => -20,
+			VppApiError::FeatureDisabled => -30,
+			VppApiError::InvalidRegistration => -31,
+			VppApiError::NextHopNotInFib => -50,
+			VppApiError::UnknownDestination => -51,
+			VppApiError::NoPathsInRoute => -52,
+			VppApiError::NextHopNotFoundMp => -53,
+			VppApiError::NoMatchingInterface => -54,
+			VppApiError::InvalidVlan => -55,
+			VppApiError::VlanAlreadyExists => -56,
+			VppApiError::InvalidSrcAddress => -57,
+			VppApiError::InvalidDstAddress => -58,
+			VppApiError::AddressLengthMismatch => -59,
+			VppApiError::AddressNotFoundForInterface => -60,
+			VppApiError::AddressNotDeletable => -61,
+			VppApiError::Ip6NotEnabled => -62,
+			VppApiError::NoSuchNode => -63,
+			VppApiError::NoSuchNode2 => -64,
+			VppApiError::NoSuchTable => -65,
+			VppApiError::NoSuchTable2 => -66,
+			VppApiError::NoSuchTable3 => -67,
+			VppApiError::SubifAlreadyExists => -68,
+			VppApiError::SubifCreateFailed => -69,
+			VppApiError::InvalidMemorySize => -70,
+			VppApiError::InvalidInterface => -71,
+			VppApiError::InvalidVlanTagCount => -72,
+			VppApiError::InvalidArgument => -73,
+			VppApiError::UnexpectedIntfState => -74,
+			VppApiError::TunnelExist => -75,
+			VppApiError::InvalidDecapNext => -76,
+			VppApiError::ResponseNotReady => -77,
+			VppApiError::NotConnected => -78,
+			VppApiError::IfAlreadyExists => -79,
+			VppApiError::BondSlaveNotAllowed => -80,
+			VppApiError::ValueExist => -81,
+			VppApiError::SameSrcDst => -82,
+			VppApiError::Ip6MulticastAddressNotPresent => -83,
+			VppApiError::SrPolicyNameNotPresent => -84,
+			VppApiError::NotRunningAsRoot => -85,
+			VppApiError::AlreadyConnected => -86,
+			VppApiError::UnsupportedJniVersion => -87,
+			VppApiError::IpPrefixInvalid => -88,
+			VppApiError::InvalidWorker => -89,
+			VppApiError::LispDisabled => -90,
+			VppApiError::ClassifyTableNotFound => -91,
+			VppApiError::InvalidEidType => -92,
+			VppApiError::CannotCreatePcapFile => -93,
+			VppApiError::IncorrectAdjacencyType => -94,
+			VppApiError::ExceededNumberOfRangesCapacity => -95,
+			VppApiError::ExceededNumberOfPortsCapacity => -96,
+			VppApiError::InvalidAddressFamily => -97,
+			VppApiError::InvalidSubSwIfIndex => -98,
+			VppApiError::TableTooBig => -99,
+			VppApiError::CannotEnableDisableFeature => -100,
+			VppApiError::BfdEexist => -101,
+			VppApiError::BfdEnoent => -102,
+			VppApiError::BfdEinuse => -103,
+			VppApiError::BfdNotsupp => -104,
+			VppApiError::AddressInUse => -105,
+			VppApiError::AddressNotInUse => -106,
+			VppApiError::QueueFull => -107,
+			VppApiError::AppUnsupportedCfg => -108,
+			VppApiError::UriFifoCreateFailed => -109,
+			VppApiError::LispRlocLocal => -110,
+			VppApiError::BfdEagain => -111,
+			VppApiError::InvalidGpeMode => -112,
+			VppApiError::LispGpeEntriesPresent => -113,
+			VppApiError::AddressFoundForInterface => -114,
+			VppApiError::SessionConnect => -115,
+			VppApiError::EntryAlreadyExists => -116,
+			VppApiError::SvmSegmentCreateFail => -117,
+			VppApiError::ApplicationNotAttached => -118,
+			VppApiError::BdAlreadyExists => -119,
+			VppApiError::BdInUse => -120,
+			VppApiError::BdNotModifiable => -121,
+			VppApiError::BdIdExceedMax => -122,
+			VppApiError::SubifDoesntExist => -123,
+			VppApiError::L2MacsEventClinetPresent => -124,
+			VppApiError::InvalidQueue => -125,
+			VppApiError::Unsupported => -126,
+			VppApiError::DuplicateIfAddress => -127,
+			VppApiError::AppInvalidNs => -128,
+			VppApiError::AppWrongNsSecret => -129,
+			VppApiError::AppConnectScope => -130,
+			VppApiError::AppAlreadyAttached => -131,
+			VppApiError::SessionRedirect => -132,
+			VppApiError::IllegalName => -133,
+			VppApiError::NoNameServers => -134,
+			VppApiError::NameServerNotFound => -135,
+			VppApiError::NameResolutionNotEnabled => -136,
+			VppApiError::NameServerFormatError => -137,
+			VppApiError::NameServerNoSuchName => -138,
+			VppApiError::NameServerNoAddresses => -139,
+			VppApiError::NameServerNextServer => -140,
+			VppApiError::AppConnectFiltered => -141,
+			VppApiError::AclInUseInbound => -142,
+			VppApiError::AclInUseOutbound => -143,
+			VppApiError::InitFailed => -144,
+			VppApiError::NetlinkError => -145,
+			VppApiError::BierBslUnsup => -146,
+			VppApiError::InstanceInUse => -147,
+			VppApiError::InvalidSessionId => -148,
+			VppApiError::AclInUseByLookupContext => -149,
+			VppApiError::InvalidValue3 => -150,
+			VppApiError::NonEthernet => -151,
+			VppApiError::BdAlreadyHasBvi => -152,
+			VppApiError::InvalidProtocol => -153,
+			VppApiError::InvalidAlgorithm => -154,
+			VppApiError::RsrcInUse => -155,
+			VppApiError::KeyLength => -156,
+			VppApiError::FibPathUnsupportedNhProto => -157,
+			VppApiError::ApiEndianFailed => -159,
+			VppApiError::NoChange => -160,
+			VppApiError::MissingCertKey => -161,
+			VppApiError::LimitExceeded => -162,
+			VppApiError::IkeNoPort => -163,
+			VppApiError::UdpPortTaken => -164,
+			VppApiError::Eagain => -165,
+			VppApiError::InvalidValue4 => -166,
+			VppApiError::Busy => -167,
+			VppApiError::Bug => -168,
+			VppApiError::FeatureAlreadyDisabled => -169,
+			VppApiError::FeatureAlreadyEnabled => -170,
+			VppApiError::InvalidPrefixLength => -171,
+			VppApiError::Unknown(retval) => *retval,
+		}
+	}
+
+	/// Returns the name of the error in VPP, e.g. `INVALID_SW_IF_INDEX`.
+	pub fn name(&self) -> &'static str {
+		match self {
+			VppApiError::Unspecified => "UNSPECIFIED",
+			VppApiError::InvalidSwIfIndex => "INVALID_SW_IF_INDEX",
+			VppApiError::NoSuchFib => "NO_SUCH_FIB",
+			VppApiError::NoSuchInnerFib => "NO_SUCH_INNER_FIB",
+			VppApiError::NoSuchLabel => "NO_SUCH_LABEL",
+			VppApiError::NoSuchEntry => "NO_SUCH_ENTRY",
+			VppApiError::InvalidValue => "INVALID_VALUE",
+			VppApiError::InvalidValue2 => "INVALID_VALUE_2",
+			VppApiError::Unimplemented => "UNIMPLEMENTED",
+			VppApiError::InvalidSwIfIndex2 => "INVALID_SW_IF_INDEX_2",
+			VppApiError::SyscallError1 => "SYSCALL_ERROR_1",
+			VppApiError::SyscallError2 => "SYSCALL_ERROR_2",
+			VppApiError::SyscallError3 => "SYSCALL_ERROR_3",
+			VppApiError::SyscallError4 => "SYSCALL_ERROR_4",
+			VppApiError::SyscallError5 => "SYSCALL_ERROR_5",
+			VppApiError::SyscallError6 => "SYSCALL_ERROR_6",
+			VppApiError::SyscallError7 => "SYSCALL_ERROR_7",
+			VppApiError::SyscallError8 => "SYSCALL_ERROR_8",
+			VppApiError::SyscallError9 => "SYSCALL_ERROR_9",
+			VppApiError::SyscallError10 => "SYSCALL_ERROR_10",
+			VppApiError::FeatureDisabled => "FEATURE_DISABLED",
+			VppApiError::InvalidRegistration => "INVALID_REGISTRATION",
+			VppApiError::NextHopNotInFib => "NEXT_HOP_NOT_IN_FIB",
+			VppApiError::UnknownDestination => "UNKNOWN_DESTINATION",
+			VppApiError::NoPathsInRoute => "NO_PATHS_IN_ROUTE",
+			VppApiError::NextHopNotFoundMp => "NEXT_HOP_NOT_FOUND_MP",
+			VppApiError::NoMatchingInterface => "NO_MATCHING_INTERFACE",
+			VppApiError::InvalidVlan => "INVALID_VLAN",
+			VppApiError::VlanAlreadyExists => "VLAN_ALREADY_EXISTS",
+			VppApiError::InvalidSrcAddress => "INVALID_SRC_ADDRESS",
+			VppApiError::InvalidDstAddress => "INVALID_DST_ADDRESS",
+			VppApiError::AddressLengthMismatch => "ADDRESS_LENGTH_MISMATCH",
+			VppApiError::AddressNotFoundForInterface => "ADDRESS_NOT_FOUND_FOR_INTERFACE",
+			VppApiError::AddressNotDeletable => "ADDRESS_NOT_DELETABLE",
+			VppApiError::Ip6NotEnabled => "IP6_NOT_ENABLED",
+			VppApiError::NoSuchNode => "NO_SUCH_NODE",
+			VppApiError::NoSuchNode2 => "NO_SUCH_NODE2",
+			VppApiError::NoSuchTable => "NO_SUCH_TABLE",
+			VppApiError::NoSuchTable2 => "NO_SUCH_TABLE2",
+			VppApiError::NoSuchTable3 => "NO_SUCH_TABLE3",
+			VppApiError::SubifAlreadyExists => "SUBIF_ALREADY_EXISTS",
+			VppApiError::SubifCreateFailed => "SUBIF_CREATE_FAILED",
+			VppApiError::InvalidMemorySize => "INVALID_MEMORY_SIZE",
+			VppApiError::InvalidInterface => "INVALID_INTERFACE",
+			VppApiError::InvalidVlanTagCount => "INVALID_VLAN_TAG_COUNT",
+			VppApiError::InvalidArgument => "INVALID_ARGUMENT",
+			VppApiError::UnexpectedIntfState => "UNEXPECTED_INTF_STATE",
+			VppApiError::TunnelExist => "TUNNEL_EXIST",
+			VppApiError::InvalidDecapNext => "INVALID_DECAP_NEXT",
+			VppApiError::ResponseNotReady => "RESPONSE_NOT_READY",
+			VppApiError::NotConnected => "NOT_CONNECTED",
+			VppApiError::IfAlreadyExists => "IF_ALREADY_EXISTS",
+			VppApiError::BondSlaveNotAllowed => "BOND_SLAVE_NOT_ALLOWED",
+			VppApiError::ValueExist => "VALUE_EXIST",
+			VppApiError::SameSrcDst => "SAME_SRC_DST",
+			VppApiError::Ip6MulticastAddressNotPresent => "IP6_MULTICAST_ADDRESS_NOT_PRESENT",
+			VppApiError::SrPolicyNameNotPresent => "SR_POLICY_NAME_NOT_PRESENT",
+			VppApiError::NotRunningAsRoot => "NOT_RUNNING_AS_ROOT",
+			VppApiError::AlreadyConnected => "ALREADY_CONNECTED",
+			VppApiError::UnsupportedJniVersion => "UNSUPPORTED_JNI_VERSION",
+			VppApiError::IpPrefixInvalid => "IP_PREFIX_INVALID",
+			VppApiError::InvalidWorker => "INVALID_WORKER",
+			VppApiError::LispDisabled => "LISP_DISABLED",
+			VppApiError::ClassifyTableNotFound => "CLASSIFY_TABLE_NOT_FOUND",
+			VppApiError::InvalidEidType => "INVALID_EID_TYPE",
+			VppApiError::CannotCreatePcapFile => "CANNOT_CREATE_PCAP_FILE",
+			VppApiError::IncorrectAdjacencyType => "INCORRECT_ADJACENCY_TYPE",
+			VppApiError::ExceededNumberOfRangesCapacity => "EXCEEDED_NUMBER_OF_RANGES_CAPACITY",
+			VppApiError::ExceededNumberOfPortsCapacity => "EXCEEDED_NUMBER_OF_PORTS_CAPACITY",
+			VppApiError::InvalidAddressFamily => "INVALID_ADDRESS_FAMILY",
+			VppApiError::InvalidSubSwIfIndex => "INVALID_SUB_SW_IF_INDEX",
+			VppApiError::TableTooBig => "TABLE_TOO_BIG",
+			VppApiError::CannotEnableDisableFeature => "CANNOT_ENABLE_DISABLE_FEATURE",
+			VppApiError::BfdEexist => "BFD_EEXIST",
+			VppApiError::BfdEnoent => "BFD_ENOENT",
+			VppApiError::BfdEinuse => "BFD_EINUSE",
+			VppApiError::BfdNotsupp => "BFD_NOTSUPP",
+			VppApiError::AddressInUse => "ADDRESS_IN_USE",
+			VppApiError::AddressNotInUse => "ADDRESS_NOT_IN_USE",
+			VppApiError::QueueFull => "QUEUE_FULL",
+			VppApiError::AppUnsupportedCfg => "APP_UNSUPPORTED_CFG",
+			VppApiError::UriFifoCreateFailed => "URI_FIFO_CREATE_FAILED",
+			VppApiError::LispRlocLocal => "LISP_RLOC_LOCAL",
+			VppApiError::BfdEagain => "BFD_EAGAIN",
+			VppApiError::InvalidGpeMode => "INVALID_GPE_MODE",
+			VppApiError::LispGpeEntriesPresent => "LISP_GPE_ENTRIES_PRESENT",
+			VppApiError::AddressFoundForInterface => "ADDRESS_FOUND_FOR_INTERFACE",
+			VppApiError::SessionConnect => "SESSION_CONNECT",
+			VppApiError::EntryAlreadyExists => "ENTRY_ALREADY_EXISTS",
+			VppApiError::SvmSegmentCreateFail => "SVM_SEGMENT_CREATE_FAIL",
+			VppApiError::ApplicationNotAttached => "APPLICATION_NOT_ATTACHED",
+			VppApiError::BdAlreadyExists => "BD_ALREADY_EXISTS",
+			VppApiError::BdInUse => "BD_IN_USE",
+			VppApiError::BdNotModifiable => "BD_NOT_MODIFIABLE",
+			VppApiError::BdIdExceedMax => "BD_ID_EXCEED_MAX",
+			VppApiError::SubifDoesntExist => "SUBIF_DOESNT_EXIST",
+			VppApiError::L2MacsEventClinetPresent => "L2_MACS_EVENT_CLINET_PRESENT",
+			VppApiError::InvalidQueue => "INVALID_QUEUE",
+			VppApiError::Unsupported => "UNSUPPORTED",
+			VppApiError::DuplicateIfAddress => "DUPLICATE_IF_ADDRESS",
+			VppApiError::AppInvalidNs => "APP_INVALID_NS",
+			VppApiError::AppWrongNsSecret => "APP_WRONG_NS_SECRET",
+			VppApiError::AppConnectScope => "APP_CONNECT_SCOPE",
+			VppApiError::AppAlreadyAttached => "APP_ALREADY_ATTACHED",
+			VppApiError::SessionRedirect => "SESSION_REDIRECT",
+			VppApiError::IllegalName => "ILLEGAL_NAME",
+			VppApiError::NoNameServers => "NO_NAME_SERVERS",
+			VppApiError::NameServerNotFound => "NAME_SERVER_NOT_FOUND",
+			VppApiError::NameResolutionNotEnabled => "NAME_RESOLUTION_NOT_ENABLED",
+			VppApiError::NameServerFormatError => "NAME_SERVER_FORMAT_ERROR",
+			VppApiError::NameServerNoSuchName => "NAME_SERVER_NO_SUCH_NAME",
+			VppApiError::NameServerNoAddresses => "NAME_SERVER_NO_ADDRESSES",
+			VppApiError::NameServerNextServer => "NAME_SERVER_NEXT_SERVER",
+			VppApiError::AppConnectFiltered => "APP_CONNECT_FILTERED",
+			VppApiError::AclInUseInbound => "ACL_IN_USE_INBOUND",
+			VppApiError::AclInUseOutbound => "ACL_IN_USE_OUTBOUND",
+			VppApiError::InitFailed => "INIT_FAILED",
+			VppApiError::NetlinkError => "NETLINK_ERROR",
+			VppApiError::BierBslUnsup => "BIER_BSL_UNSUP",
+			VppApiError::InstanceInUse => "INSTANCE_IN_USE",
+			VppApiError::InvalidSessionId => "INVALID_SESSION_ID",
+			VppApiError::AclInUseByLookupContext => "ACL_IN_USE_BY_LOOKUP_CONTEXT",
+			VppApiError::InvalidValue3 => "INVALID_VALUE_3",
+			VppApiError::NonEthernet => "NON_ETHERNET",
+			VppApiError::BdAlreadyHasBvi => "BD_ALREADY_HAS_BVI",
+			VppApiError::InvalidProtocol => "INVALID_PROTOCOL",
+			VppApiError::InvalidAlgorithm => "INVALID_ALGORITHM",
+			VppApiError::RsrcInUse => "RSRC_IN_USE",
+			VppApiError::KeyLength => "KEY_LENGTH",
+			VppApiError::FibPathUnsupportedNhProto => "FIB_PATH_UNSUPPORTED_NH_PROTO",
+			VppApiError::ApiEndianFailed => "API_ENDIAN_FAILED",
+			VppApiError::NoChange => "NO_CHANGE",
+			VppApiError::MissingCertKey => "MISSING_CERT_KEY",
+			VppApiError::LimitExceeded => "LIMIT_EXCEEDED",
+			VppApiError::IkeNoPort => "IKE_NO_PORT",
+			VppApiError::UdpPortTaken => "UDP_PORT_TAKEN",
+			VppApiError::Eagain => "EAGAIN",
+			VppApiError::InvalidValue4 => "INVALID_VALUE_4",
+			VppApiError::Busy => "BUSY",
+			VppApiError::Bug => "BUG",
+			VppApiError::FeatureAlreadyDisabled => "FEATURE_ALREADY_DISABLED",
+			VppApiError::FeatureAlreadyEnabled => "FEATURE_ALREADY_ENABLED",
+			VppApiError::InvalidPrefixLength => "INVALID_PREFIX_LENGTH",
+			VppApiError::Unknown(_) => "UNKNOWN",
+		}
+	}
+
+	/// Returns the description VPP gives for the error.
+	pub fn description(&self) -> &'static str {
+		match self {
+			VppApiError::Unspecified => "Unspecified Error",
+			VppApiError::InvalidSwIfIndex => "Invalid sw_if_index",
+			VppApiError::NoSuchFib => "No such FIB / VRF",
+			VppApiError::NoSuchInnerFib => "No such inner FIB / VRF",
+			VppApiError::NoSuchLabel => "No such label",
+			VppApiError::NoSuchEntry => "No such entry",
+			VppApiError::InvalidValue => "Invalid value",
+			VppApiError::InvalidValue2 => "Invalid value #2",
+			VppApiError::Unimplemented => "Unimplemented",
+			VppApiError::InvalidSwIfIndex2 => "Invalid sw_if_index #2",
+			VppApiError::SyscallError1 => "System call error #1",
+			VppApiError::SyscallError2 => "System call error #2",
+			VppApiError::SyscallError3 => "System call error #3",
+			VppApiError::SyscallError4 => "System call error #4",
+			VppApiError::SyscallError5 => "System call error #5",
+			VppApiError::SyscallError6 => "System call error #6",
+			VppApiError::SyscallError7 => "System call error #7",
+			VppApiError::SyscallError8 => "System call error #8",
+			VppApiError::SyscallError9 => "System call error #9",
+			VppApiError::SyscallError10 => "System call error #10",
+			VppApiError::FeatureDisabled => "Feature disabled by configuration",
+			VppApiError::InvalidRegistration => "Invalid registration",
+			VppApiError::NextHopNotInFib => "Next hop not in FIB",
+			VppApiError::UnknownDestination => "Unknown destination",
+			VppApiError::NoPathsInRoute => "No paths specified in route",
+			VppApiError::NextHopNotFoundMp => "Next hop not found (multipath)",
+			VppApiError::NoMatchingInterface => "No matching interface for probe",
+			VppApiError::InvalidVlan => "Invalid VLAN",
+			VppApiError::VlanAlreadyExists => "VLAN subif already exists",
+			VppApiError::InvalidSrcAddress => "Invalid src address",
+			VppApiError::InvalidDstAddress => "Invalid dst address",
+			VppApiError::AddressLengthMismatch => "Address length mismatch",
+			VppApiError::AddressNotFoundForInterface => "Address not found for interface",
+			VppApiError::AddressNotDeletable => "Address not deletable",
+			VppApiError::Ip6NotEnabled => "ip6 not enabled",
+			VppApiError::NoSuchNode => "No such graph node",
+			VppApiError::NoSuchNode2 => "No such graph node #2",
+			VppApiError::NoSuchTable => "No such table",
+			VppApiError::NoSuchTable2 => "No such table #2",
+			VppApiError::NoSuchTable3 => "No such table #3",
+			VppApiError::SubifAlreadyExists => "Subinterface already exists",
+			VppApiError::SubifCreateFailed => "Subinterface creation failed",
+			VppApiError::InvalidMemorySize => "Invalid memory size requested",
+			VppApiError::InvalidInterface => "Invalid interface",
+			VppApiError::InvalidVlanTagCount => "Invalid number of tags for requested operation",
+			VppApiError::InvalidArgument => "Invalid argument",
+			VppApiError::UnexpectedIntfState => "Unexpected interface state",
+			VppApiError::TunnelExist => "Tunnel already exists",
+			VppApiError::InvalidDecapNext => "Invalid decap-next",
+			VppApiError::ResponseNotReady => "Response not ready",
+			VppApiError::NotConnected => "Not connected to the data plane",
+			VppApiError::IfAlreadyExists => "Interface already exists",
+			VppApiError::BondSlaveNotAllowed => "Operation not allowed on slave of BondEthernet",
+			VppApiError::ValueExist => "Value already exists",
+			VppApiError::SameSrcDst => "Source and destination are the same",
+			VppApiError::Ip6MulticastAddressNotPresent => "IP6 multicast address required",
+			VppApiError::SrPolicyNameNotPresent => "Segment routing policy name required",
+			VppApiError::NotRunningAsRoot => "Not running as root",
+			VppApiError::AlreadyConnected => "Connection to the data plane already exists",
+			VppApiError::UnsupportedJniVersion => "Unsupported JNI version",
+			VppApiError::IpPrefixInvalid => "IP prefix invalid (masked bits set in address",
+			VppApiError::InvalidWorker => "Invalid worker thread",
+			VppApiError::LispDisabled => "LISP is disabled",
+			VppApiError::ClassifyTableNotFound => "Classify table not found",
+			VppApiError::InvalidEidType => "Unsupported LISP EID type",
+			VppApiError::CannotCreatePcapFile => "Cannot create pcap file",
+			VppApiError::IncorrectAdjacencyType => "Invalid adjacency type for this operation",
+			VppApiError::ExceededNumberOfRangesCapacity => "Operation would exceed configured capacity of ranges",
+			VppApiError::ExceededNumberOfPortsCapacity => "Operation would exceed capacity of number of ports",
+			VppApiError::InvalidAddressFamily => "Invalid address family",
+			VppApiError::InvalidSubSwIfIndex => "Invalid sub-interface sw_if_index",
+			VppApiError::TableTooBig => "Table too big",
+			VppApiError::CannotEnableDisableFeature => "Cannot enable/disable feature",
+			VppApiError::BfdEexist => "Duplicate BFD object",
+			VppApiError::BfdEnoent => "No such BFD object",
+			VppApiError::BfdEinuse => "BFD object in use",
+			VppApiError::BfdNotsupp => "BFD feature not supported",
+			VppApiError::AddressInUse => "Address in use",
+			VppApiError::AddressNotInUse => "Address not in use",
+			VppApiError::QueueFull => "Queue full",
+			VppApiError::AppUnsupportedCfg => "Unsupported application config",
+			VppApiError::UriFifoCreateFailed => "URI FIFO segment create failed",
+			VppApiError::LispRlocLocal => "RLOC address is local",
+			VppApiError::BfdEagain => "BFD object cannot be manipulated at this time",
+			VppApiError::InvalidGpeMode => "Invalid GPE mode",
+			VppApiError::LispGpeEntriesPresent => "LISP GPE entries are present",
+			VppApiError::AddressFoundForInterface => "Address found for interface",
+			VppApiError::SessionConnect => "Session failed to connect",
+			VppApiError::EntryAlreadyExists => "Entry already exists",
+			VppApiError::SvmSegmentCreateFail => "Svm segment create fail",
+			VppApiError::ApplicationNotAttached => "application not attached",
+			VppApiError::BdAlreadyExists => "Bridge domain already exists",
+			VppApiError::BdInUse => "Bridge domain has member interfaces",
+			VppApiError::BdNotModifiable => "Bridge domain 0 can't be deleted/modified",
+			VppApiError::BdIdExceedMax => "Bridge domain ID exceeds 16M limit",
+			VppApiError::SubifDoesntExist => "Subinterface doesn't exist",
+			VppApiError::L2MacsEventClinetPresent => "Client already exist for L2 MACs events",
+			VppApiError::InvalidQueue => "Invalid queue",
+			VppApiError::Unsupported => "Unsupported",
+			VppApiError::DuplicateIfAddress => "Address already present on another interface",
+			VppApiError::AppInvalidNs => "Invalid application namespace",
+			VppApiError::AppWrongNsSecret => "Wrong app namespace secret",
+			VppApiError::AppConnectScope => "Connect scope",
+			VppApiError::AppAlreadyAttached => "App already attached",
+			VppApiError::SessionRedirect => "Redirect failed",
+			VppApiError::IllegalName => "Illegal name",
+			VppApiError::NoNameServers => "No name servers configured",
+			VppApiError::NameServerNotFound => "Name server not found",
+			VppApiError::NameResolutionNotEnabled => "Name resolution not enabled",
+			VppApiError::NameServerFormatError => "Server format error (bug!)",
+			VppApiError::NameServerNoSuchName => "No such name",
+			VppApiError::NameServerNoAddresses => "No addresses available",
+			VppApiError::NameServerNextServer => "Retry with new server",
+			VppApiError::AppConnectFiltered => "Connect was filtered",
+			VppApiError::AclInUseInbound => "Inbound ACL in use",
+			VppApiError::AclInUseOutbound => "Outbound ACL in use",
+			VppApiError::InitFailed => "Initialization Failed",
+			VppApiError::NetlinkError => "Netlink error",
+			VppApiError::BierBslUnsup => "BIER bit-string-length unsupported",
+			VppApiError::InstanceInUse => "Instance in use",
+			VppApiError::InvalidSessionId => "Session ID out of range",
+			VppApiError::AclInUseByLookupContext => "ACL in use by a lookup context",
+			VppApiError::InvalidValue3 => "Invalid value #3",
+			VppApiError::NonEthernet => "Interface is not an Ethernet interface",
+			VppApiError::BdAlreadyHasBvi => "Bridge domain already has a BVI interface",
+			VppApiError::InvalidProtocol => "Invalid Protocol",
+			VppApiError::InvalidAlgorithm => "Invalid Algorithm",
+			VppApiError::RsrcInUse => "Resource In Use",
+			VppApiError::KeyLength => "invalid Key Length",
+			VppApiError::FibPathUnsupportedNhProto => "Unsupported FIB Path protocol",
+			VppApiError::ApiEndianFailed => "Endian mismatch detected",
+			VppApiError::NoChange => "No change in table",
+			VppApiError::MissingCertKey => "Missing certifcate or key",
+			VppApiError::LimitExceeded => "limit exceeded",
+			VppApiError::IkeNoPort => "port not managed by IKE",
+			VppApiError::UdpPortTaken => "UDP port already taken",
+			VppApiError::Eagain => "Retry stream call with cursor",
+			VppApiError::InvalidValue4 => "Invalid value #4",
+			VppApiError::Busy => "Busy",
+			VppApiError::Bug => "Bug",
+			VppApiError::FeatureAlreadyDisabled => "Feature already disabled",
+			VppApiError::FeatureAlreadyEnabled => "Feature already enabled",
+			VppApiError::InvalidPrefixLength => "Invalid prefix length",
+			VppApiError::Unknown(_) => "Unknown error",
+		}
+	}
+}
+
+impl std::fmt::Display for VppApiError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{} ({}, retval {})", self.description(), self.name(), self.retval())
+	}
+}
+
+impl std::error::Error for VppApiError {}
@@ -0,0 +1,135 @@
+use crate::error::{Result, TransportError};
+use futures::StreamExt;
+use std::future::Future;
+
+/// The number of requests a batch keeps in flight unless told otherwise.
+pub const DEFAULT_BATCH_WINDOW: usize = 64;
+
+/// The outcome of a batch of requests, one result per request in the order they were given.
+///
+/// A reply with a negative `retval` is a failed item, carrying a
+/// [`crate::TransportError::Retval`], just like with `send_rcv_checked`.
+#[derive(Debug)]
+pub struct BatchResult<R> {
+    /// The reply or the error of every request, in request order.
+    pub results: Vec<Result<R>>,
+}
+
+impl<R> BatchResult<R> {
+    /// Returns the number of requests that succeeded.
+    pub fn succeeded(&self) -> usize {
+        self.results.iter().filter(|result| result.is_ok()).count()
+    }
+
+    /// Returns the number of requests that failed.
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.succeeded()
+    }
+
+    /// Returns the position and the error of the first failed request, if any.
+    pub fn first_failure(&self) -> Option<(usize, &TransportError)> {
+        self.results
+            .iter()
+            .enumerate()
+            .find_map(|(index, result)| result.as_ref().err().map(|e| (index, e)))
+    }
+
+    /// Returns all replies, or an error describing the first failure.
+    ///
+    /// # Returns
+    ///
+    /// The replies in request order, or a [`TransportError::Batch`] wrapping the
+    /// error of the first failed request.
+    pub fn into_result(self) -> Result<Vec<R>> {
+        let total = self.results.len();
+        let failed = self.failed();
+        let mut replies = Vec::with_capacity(total);
+        for (index, result) in self.results.into_iter().enumerate() {
+            match result {
+                Ok(reply) => replies.push(reply),
+                Err(e) => {
+                    return Err(TransportError::Batch {
+                        index,
+                        total,
+                        failed,
+                        source: Box::new(e),
+                    });
+                }
+            }
+        }
+        Ok(replies)
+    }
+}
+
+/// Checks that a batch may have at least one request in flight.
+pub(crate) fn check_window(window: usize) -> Result<()> {
+    if window == 0 {
+        return Err(TransportError::InvalidRequest(
+            "a batch needs a window of at least one request".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Runs `request` for every message, with at most `window` of them in flight at once.
+///
+/// For clients that route replies by context, so requests can simply overlap.
+pub(crate) async fn run_windowed<T, R, I, F, Fut>(
+    msgs: I,
+    window: usize,
+    request: F,
+) -> Result<BatchResult<R>>
+where
+    I: IntoIterator<Item = T>,
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = Result<R>>,
+{
+    check_window(window)?;
+    let results = futures::stream::iter(msgs)
+        .map(request)
+        .buffered(window)
+        .collect()
+        .await;
+    Ok(BatchResult { results })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_run_windowed() {
+        let in_flight = AtomicUsize::new(0);
+        let most = AtomicUsize::new(0);
+        let batch = run_windowed(0..20u32, 4, |n| {
+            let (in_flight, most) = (&in_flight, &most);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                most.fetch_max(now, Ordering::SeqCst);
+                tokio::task::yield_now().await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                if n % 7 == 3 {
+                    Err(TransportError::InvalidRequest(format!("odd one {n}")))
+                } else {
+                    Ok(n)
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        assert!(most.into_inner() <= 4);
+        assert_eq!(batch.results.len(), 20);
+        assert_eq!(batch.failed(), 3);
+        assert_eq!(batch.first_failure().unwrap().0, 3);
+        assert_eq!(*batch.results[4].as_ref().unwrap(), 4);
+        let err = batch.into_result().unwrap_err();
+        assert_eq!(err.to_string(), "request 3 of 20 failed, 3 failed in total");
+        assert!(
+            run_windowed(0..1u32, 0, |n| async move { Ok(n) })
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,317 @@
+use crate::codec::encode_frame;
+use crate::error::{Result, TransportError};
+use crate::router::{reply_context, request_context};
+use bincode_next::config::BigEndian;
+use bincode_next::config::Configuration;
+use bincode_next::config::Fixint;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::warn;
+use vpp_api_encoding::wire::VppEncode;
+
+/// The bytes every capture file starts with, including the format version.
+pub const CAPTURE_MAGIC: &[u8; 8] = b"VPPCAP01";
+
+/// The direction of a captured frame, seen from the application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Direction {
+    /// Written by the application to VPP.
+    Sent,
+    /// Read by the application from VPP.
+    Received,
+}
+
+/// One frame of a captured API session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaptureRecord {
+    /// When the frame passed the transport, in microseconds since the Unix epoch.
+    pub timestamp_us: u64,
+    /// Whether the frame was sent or received.
+    pub direction: Direction,
+    /// The message id the frame carried.
+    pub msg_id: u16,
+    /// The `name_crc` of the message, or an empty string if the id was not in the message table.
+    pub name_crc: String,
+    /// The context of the message, if it is long enough to carry one.
+    ///
+    /// Taken from where requests carry it for sent messages and from where replies
+    /// carry it for received ones, so it is meaningless for events.
+    pub context: Option<u32>,
+    /// The encoded message, without the message id.
+    pub payload: Vec<u8>,
+}
+
+impl CaptureRecord {
+    /// Builds the record for a frame made of the message id and the payload.
+    fn new(direction: Direction, frame: &[u8], names: &HashMap<u16, String>) -> Result<Self> {
+        let id_bytes: [u8; 2] = frame
+            .get(0..2)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| recording("frame too short to carry a message id"))?;
+        let msg_id = u16::from_be_bytes(id_bytes);
+        let payload = frame[2..].to_vec();
+        let name_crc = names.get(&msg_id).cloned().unwrap_or_default();
+        let context = if direction == Direction::Sent && !is_reply(&name_crc) {
+            request_context(&payload)
+        } else {
+            reply_context(&payload)
+        };
+        let timestamp_us = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_micros() as u64)
+            .unwrap_or_default();
+        Ok(CaptureRecord {
+            timestamp_us,
+            direction,
+            msg_id,
+            name_crc,
+            context,
+            payload,
+        })
+    }
+}
+
+/// Returns `true` for messages laid out like replies, starting with the context.
+fn is_reply(name_crc: &str) -> bool {
+    name_crc.contains("_reply_") || name_crc.contains("_details_")
+}
+
+/// Creates the bincode configuration used for capture files.
+fn capture_encoder() -> Configuration<BigEndian, Fixint> {
+    bincode_next::config::legacy()
+        .with_big_endian()
+        .with_fixed_int_encoding()
+}
+
+/// Writes the frames of an API session to a capture file.
+///
+/// A capture file is [`CAPTURE_MAGIC`] followed by [`CaptureRecord`]s, encoded with
+/// big-endian, fixed-size-integer bincode.
+/// Every record is flushed as it is written, so the capture survives a crash of
+/// the application.
+pub struct Recorder {
+    /// The capture file.
+    writer: Mutex<BufWriter<File>>,
+    /// The names of the messages, by message id.
+    names: HashMap<u16, String>,
+    /// The bincode configuration used for the records.
+    config: Configuration<BigEndian, Fixint>,
+}
+
+impl Recorder {
+    /// Creates a capture file, replacing any existing file at `path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Where to write the capture
+    /// * `names` - The `name_crc` of the messages, by message id, used to name the records
+    ///
+    /// # Returns
+    ///
+    /// The recorder, or an error if the file cannot be created.
+    pub fn create(path: impl AsRef<Path>, names: HashMap<u16, String>) -> Result<Recorder> {
+        let mut writer = BufWriter::new(File::create(path).map_err(recording)?);
+        writer.write_all(CAPTURE_MAGIC).map_err(recording)?;
+        writer.flush().map_err(recording)?;
+        Ok(Recorder {
+            writer: Mutex::new(writer),
+            names,
+            config: capture_encoder(),
+        })
+    }
+
+    /// Appends a frame, made of the message id and the payload, to the capture.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - Whether the frame was sent or received
+    /// * `frame` - The message id followed by the encoded message
+    pub fn record(&self, direction: Direction, frame: &[u8]) -> Result<()> {
+        let record = CaptureRecord::new(direction, frame, &self.names)?;
+        let encoded =
+            bincode_next::serde::encode_to_vec(&record, self.config).map_err(recording)?;
+        let mut writer = self.writer.lock().unwrap();
+        writer.write_all(&encoded).map_err(recording)?;
+        writer.flush().map_err(recording)
+    }
+}
+
+/// The optional recorder of a transport, shared by its reading and writing sides.
+#[derive(Clone, Default)]
+pub(crate) struct CaptureTap(Arc<RwLock<Option<Recorder>>>);
+
+impl CaptureTap {
+    /// Records a frame if recording is on; failures are logged rather than failing the I/O.
+    pub fn record(&self, direction: Direction, frame: &[u8]) {
+        if let Some(recorder) = self.0.read().unwrap().as_ref()
+            && let Err(e) = recorder.record(direction, frame)
+        {
+            warn!("failed to record {direction:?} frame: {e}");
+        }
+    }
+
+    /// Records a message sent as `msg_id`, encoding it only if recording is on.
+    pub fn record_message<T: VppEncode>(&self, msg_id: u16, msg: &T) {
+        if self.0.read().unwrap().is_none() {
+            return;
+        }
+        match encode_frame(msg_id, msg) {
+            Ok(frame) => self.record(Direction::Sent, &frame),
+            Err(e) => warn!("failed to record message id {msg_id}: {e}"),
+        }
+    }
+
+    /// Starts recording with `recorder`, replacing any recording in progress.
+    pub fn start(&self, recorder: Recorder) {
+        *self.0.write().unwrap() = Some(recorder);
+    }
+
+    /// Stops recording and closes the capture file.
+    pub fn stop(&self) -> Result<()> {
+        match self.0.write().unwrap().take() {
+            Some(recorder) => recorder
+                .writer
+                .into_inner()
+                .unwrap()
+                .flush()
+                .map_err(recording),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Reads the records of a capture file one at a time.
+pub struct CaptureReader<R> {
+    /// The capture, positioned after the magic.
+    reader: R,
+    /// The bincode configuration used for the records.
+    config: Configuration<BigEndian, Fixint>,
+}
+
+impl CaptureReader<BufReader<File>> {
+    /// Opens a capture file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The capture file written by a [`Recorder`]
+    ///
+    /// # Returns
+    ///
+    /// The reader, or an error if the file cannot be opened or is not a capture.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        CaptureReader::new(BufReader::new(File::open(path).map_err(invalid)?))
+    }
+}
+
+impl<R: Read> CaptureReader<R> {
+    /// Reads a capture from any reader, checking the magic first.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The capture, positioned at its start
+    pub fn new(mut reader: R) -> Result<Self> {
+        let mut magic = [0u8; CAPTURE_MAGIC.len()];
+        reader.read_exact(&mut magic).map_err(invalid)?;
+        if &magic != CAPTURE_MAGIC {
+            return Err(invalid(format!(
+                "not a VPP API capture: bad magic {magic:x?}"
+            )));
+        }
+        Ok(CaptureReader {
+            reader,
+            config: capture_encoder(),
+        })
+    }
+}
+
+impl<R: Read> Iterator for CaptureReader<R> {
+    type Item = Result<CaptureRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // a capture cut short by a crash ends at the last complete record
+        let mut first = [0u8; 1];
+        match self.reader.read(&mut first) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(e) if e.kind() == ErrorKind::Interrupted => return self.next(),
+            Err(e) => return Some(Err(invalid(e))),
+        }
+        let mut reader = first.as_slice().chain(&mut self.reader);
+        Some(
+            bincode_next::serde::decode_from_std_read(&mut reader, self.config)
+                .map_err(|e| invalid(format!("truncated or corrupt capture record: {e}"))),
+        )
+    }
+}
+
+/// Builds the error writing a capture fails with.
+fn recording(e: impl std::fmt::Display) -> TransportError {
+    TransportError::Recording(e.to_string())
+}
+
+/// Builds the error reading a capture fails with.
+fn invalid(e: impl std::fmt::Display) -> TransportError {
+    TransportError::InvalidCapture(e.to_string())
+}
+
+/// Reads all records of a capture file.
+///
+/// # Arguments
+///
+/// * `path` - The capture file written by a [`Recorder`]
+///
+/// # Returns
+///
+/// The records in the order they were captured, or an error if the file is not a valid capture.
+pub fn read_capture(path: impl AsRef<Path>) -> Result<Vec<CaptureRecord>> {
+    CaptureReader::open(path)?.collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_read_capture() {
+        let path = std::env::temp_dir().join(format!("vpp-api-capture-{}.cap", std::process::id()));
+        let names = HashMap::from([
+            (10, "control_ping_51077d14".to_string()),
+            (11, "control_ping_reply_f6b0b8ca".to_string()),
+        ]);
+        let recorder = Recorder::create(&path, names).unwrap();
+        recorder
+            .record(Direction::Sent, &[0, 10, 0, 0, 0, 1, 0, 0, 0, 7])
+            .unwrap();
+        recorder
+            .record(Direction::Received, &[0, 11, 0, 0, 0, 7, 0, 0, 0, 0])
+            .unwrap();
+        recorder.record(Direction::Received, &[0, 99, 1]).unwrap();
+        drop(recorder);
+
+        let records = read_capture(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        let summary: Vec<_> = records
+            .iter()
+            .map(|r| (r.direction, r.msg_id, r.name_crc.as_str(), r.context))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (Direction::Sent, 10, "control_ping_51077d14", Some(7)),
+                (
+                    Direction::Received,
+                    11,
+                    "control_ping_reply_f6b0b8ca",
+                    Some(7)
+                ),
+                (Direction::Received, 99, "", None),
+            ]
+        );
+        assert_eq!(records[2].payload, [1]);
+    }
+}
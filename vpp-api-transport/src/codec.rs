@@ -0,0 +1,280 @@
+use crate::error::{Result, TransportError};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+use vpp_api_encoding::wire::VppEncode;
+
+/// The size of the `SockMsgHeader` in front of every message on the API socket.
+pub const SOCKET_HEADER_LEN: usize = 16;
+
+/// The largest message a [`VppFrameCodec`] accepts unless told otherwise.
+///
+/// The biggest message VPP sends in practice is the message table in the reply to
+/// `sockclnt_create`, a few hundred kilobytes.
+pub const DEFAULT_MAX_MESSAGE_LEN: usize = 16 * 1024 * 1024;
+
+/// Splits the byte stream of the VPP API socket into messages, and frames messages for it.
+///
+/// On the socket every message is preceded by a 16-byte `SockMsgHeader`, which
+/// carries the length of the message in network byte order. The frames the codec
+/// yields and takes are the messages without that header: the message id followed
+/// by the encoded message.
+///
+/// Frames are split off the read buffer without copying, so the buffer is reused
+/// once they are dropped. A header announcing an empty message or one longer than
+/// the maximum length is a [`TransportError::Framing`] error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VppFrameCodec {
+    /// The largest message accepted in either direction.
+    max_length: usize,
+}
+
+impl VppFrameCodec {
+    /// Creates a codec accepting messages up to [`DEFAULT_MAX_MESSAGE_LEN`] bytes.
+    pub fn new() -> Self {
+        VppFrameCodec::with_max_length(DEFAULT_MAX_MESSAGE_LEN)
+    }
+
+    /// Creates a codec accepting messages up to `max_length` bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_length` - The largest message, without the socket header, in bytes
+    pub fn with_max_length(max_length: usize) -> Self {
+        VppFrameCodec { max_length }
+    }
+
+    /// Returns the largest message the codec accepts, in bytes.
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    /// Checks the length of a message against the limits of the codec.
+    pub(crate) fn check_length(&self, msglen: usize) -> Result<()> {
+        if msglen == 0 {
+            return Err(TransportError::Framing(
+                "Invalid header, header.msglen == 0".into(),
+            ));
+        }
+        if msglen > self.max_length {
+            return Err(TransportError::Framing(format!(
+                "message of {msglen} bytes exceeds the maximum of {} bytes",
+                self.max_length
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Default for VppFrameCodec {
+    fn default() -> Self {
+        VppFrameCodec::new()
+    }
+}
+
+impl Decoder for VppFrameCodec {
+    type Item = BytesMut;
+    type Error = TransportError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<BytesMut>> {
+        if src.len() < SOCKET_HEADER_LEN {
+            src.reserve(SOCKET_HEADER_LEN - src.len());
+            return Ok(None);
+        }
+        let msglen = u32::from_be_bytes([src[8], src[9], src[10], src[11]]) as usize;
+        self.check_length(msglen)?;
+
+        let frame_len = SOCKET_HEADER_LEN + msglen;
+        if src.len() < frame_len {
+            src.reserve(frame_len - src.len());
+            return Ok(None);
+        }
+        src.advance(SOCKET_HEADER_LEN);
+        Ok(Some(src.split_to(msglen)))
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<BytesMut>> {
+        match self.decode(src)? {
+            Some(frame) => Ok(Some(frame)),
+            None if src.is_empty() => Ok(None),
+            None => Err(TransportError::Framing(format!(
+                "connection closed in the middle of a message, {} bytes left",
+                src.len()
+            ))),
+        }
+    }
+}
+
+impl Encoder<Bytes> for VppFrameCodec {
+    type Error = TransportError;
+
+    fn encode(&mut self, frame: Bytes, dst: &mut BytesMut) -> Result<()> {
+        self.check_length(frame.len())?;
+        dst.reserve(SOCKET_HEADER_LEN + frame.len());
+        dst.put_u64(0);
+        dst.put_u32(frame.len() as u32);
+        dst.put_u32(0);
+        dst.extend_from_slice(&frame);
+        Ok(())
+    }
+}
+
+/// Encodes a message, given with the message id VPP knows it by, straight into the write buffer.
+///
+/// The socket header is written first and its length filled in once the message
+/// is encoded, so the frame is never built anywhere else. If the message cannot be
+/// encoded, nothing is left in the buffer.
+impl<T: VppEncode> Encoder<(u16, &T)> for VppFrameCodec {
+    type Error = TransportError;
+
+    fn encode(&mut self, (msg_id, msg): (u16, &T), dst: &mut BytesMut) -> Result<()> {
+        let start = dst.len();
+        dst.reserve(SOCKET_HEADER_LEN + 2);
+        dst.put_u64(0);
+        dst.put_u32(0);
+        dst.put_u32(0);
+        dst.put_u16(msg_id);
+        let encoded = msg
+            .vpp_encode(dst)
+            .map_err(|e| TransportError::InvalidRequest(format!("cannot encode message: {e}")))
+            .and_then(|()| {
+                let msglen = dst.len() - start - SOCKET_HEADER_LEN;
+                self.check_length(msglen)?;
+                Ok(msglen)
+            });
+        match encoded {
+            Ok(msglen) => {
+                dst[start + 8..start + 12].copy_from_slice(&(msglen as u32).to_be_bytes());
+                Ok(())
+            }
+            Err(e) => {
+                dst.truncate(start);
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Encodes a message into a frame: the message id followed by the encoded message.
+///
+/// For writing to a socket, encode `(msg_id, &msg)` with a [`VppFrameCodec`]
+/// instead, which skips the separate buffer.
+///
+/// # Arguments
+///
+/// * `msg_id` - The message id VPP knows the message by
+/// * `msg` - The message to encode
+///
+/// # Returns
+///
+/// The frame, in a single buffer, or a [`TransportError::InvalidRequest`] if the
+/// message cannot be encoded.
+pub fn encode_frame<T>(msg_id: u16, msg: &T) -> Result<Bytes>
+where
+    T: VppEncode,
+{
+    let mut frame = BytesMut::new();
+    frame.put_u16(msg_id);
+    msg.vpp_encode(&mut frame)
+        .map_err(|e| TransportError::InvalidRequest(format!("cannot encode message: {e}")))?;
+    Ok(frame.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn socket_bytes(frames: &[&[u8]]) -> BytesMut {
+        let mut codec = VppFrameCodec::new();
+        let mut bytes = BytesMut::new();
+        for frame in frames {
+            codec
+                .encode(Bytes::copy_from_slice(frame), &mut bytes)
+                .unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_decode_partial_and_coalesced() {
+        let frames: [&[u8]; 3] = [&[0, 12, 1, 2, 3], &[0, 13, 4], &[0, 14, 5, 6, 7, 8, 9]];
+        let stream = socket_bytes(&frames);
+        assert_eq!(
+            &stream[..SOCKET_HEADER_LEN],
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0]
+        );
+
+        // all frames in one read
+        let mut codec = VppFrameCodec::new();
+        let mut src = stream.clone();
+        for frame in frames {
+            assert_eq!(&codec.decode(&mut src).unwrap().unwrap()[..], frame);
+        }
+        assert!(codec.decode(&mut src).unwrap().is_none());
+        assert!(codec.decode_eof(&mut src).unwrap().is_none());
+
+        // one byte per read
+        let mut src = BytesMut::new();
+        let mut decoded = vec![];
+        for byte in stream.iter() {
+            src.put_u8(*byte);
+            if let Some(frame) = codec.decode(&mut src).unwrap() {
+                decoded.push(frame);
+            }
+        }
+        assert_eq!(decoded, frames);
+
+        let mut truncated = stream.clone();
+        truncated.truncate(stream.len() - 1);
+        for _ in 0..2 {
+            codec.decode(&mut truncated).unwrap().unwrap();
+        }
+        assert!(codec.decode(&mut truncated).unwrap().is_none());
+        assert!(codec.decode_eof(&mut truncated).is_err());
+    }
+
+    #[test]
+    fn test_encode_message_in_place() {
+        let mut codec = VppFrameCodec::new();
+        let mut dst = BytesMut::new();
+        codec.encode((12, &[1u8, 2, 3]), &mut dst).unwrap();
+        codec.encode((13, &0x0405u16), &mut dst).unwrap();
+        assert_eq!(dst, socket_bytes(&[&[0, 12, 1, 2, 3], &[0, 13, 4, 5]]));
+        assert_eq!(
+            encode_frame(12, &[1u8, 2, 3]).unwrap(),
+            Bytes::from_static(&[0, 12, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_split_frame_shares_the_read_buffer() {
+        let mut src = socket_bytes(&[&[0, 12, 1, 2, 3]]);
+        let frame = VppFrameCodec::new().decode(&mut src).unwrap().unwrap();
+        let start = frame.as_ptr();
+        let (msg_id, payload) = crate::helpers::split_frame(frame.freeze()).unwrap();
+        assert_eq!(msg_id, 12);
+        assert_eq!(&payload[..], [1, 2, 3]);
+        assert_eq!(payload.as_ptr(), start.wrapping_add(2));
+        assert!(crate::helpers::split_frame(Bytes::from_static(&[0, 12])).is_err());
+    }
+
+    #[test]
+    fn test_max_length() {
+        let mut codec = VppFrameCodec::with_max_length(4);
+        let mut src = socket_bytes(&[&[0, 12, 1, 2, 3]]);
+        let err = codec.decode(&mut src).unwrap_err();
+        assert!(matches!(err, TransportError::Framing(_)));
+        assert!(
+            codec
+                .encode(Bytes::from_static(&[0, 12, 1, 2, 3]), &mut BytesMut::new())
+                .is_err()
+        );
+
+        // a message that does not fit leaves the buffer as it was
+        let mut dst = BytesMut::from(&b"pending"[..]);
+        assert!(codec.encode((12, &[1u8, 2, 3]), &mut dst).is_err());
+        assert_eq!(&dst[..], b"pending");
+
+        let mut empty = BytesMut::from(&[0u8; SOCKET_HEADER_LEN][..]);
+        assert!(VppFrameCodec::new().decode(&mut empty).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::fmt;
+
+/// A message whose CRC in the bindings differs from the one VPP uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrcMismatch {
+    /// The message name without the CRC, e.g. `sw_interface_dump`.
+    pub name: String,
+    /// The CRC the bindings were generated with.
+    pub compiled_crc: String,
+    /// The CRC in the live message table.
+    pub live_crc: String,
+}
+
+/// The result of checking the messages of a set of bindings against the message table of VPP.
+///
+/// Messages are compared by their `name_crc` strings, as returned by
+/// `VppApiMessage::get_message_name_and_crc()`. A message is compatible when VPP
+/// knows it with the same CRC, i.e. the wire layout of both sides agrees.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompatibilityReport {
+    /// Messages in the bindings that VPP does not know under any CRC.
+    pub missing: Vec<String>,
+    /// Messages VPP knows, but with a different CRC than the bindings.
+    pub crc_changed: Vec<CrcMismatch>,
+    /// Messages VPP knows that are not in the bindings.
+    pub only_in_vpp: Vec<String>,
+}
+
+impl CompatibilityReport {
+    /// Compares the messages of the bindings with the live message table.
+    ///
+    /// # Arguments
+    ///
+    /// * `compiled` - The `name_crc` of every message to check
+    /// * `live` - The `name_crc` of every message in the message table of VPP
+    pub fn new<C, L>(compiled: C, live: L) -> CompatibilityReport
+    where
+        C: IntoIterator,
+        C::Item: AsRef<str>,
+        L: IntoIterator,
+        L::Item: AsRef<str>,
+    {
+        let mut live_crcs: HashMap<String, String> = live
+            .into_iter()
+            .map(|name_crc| {
+                let (name, crc) = split_name_and_crc(name_crc.as_ref());
+                (name.to_string(), crc.to_string())
+            })
+            .collect();
+
+        let mut report = CompatibilityReport::default();
+        for name_crc in compiled {
+            let (name, crc) = split_name_and_crc(name_crc.as_ref());
+            match live_crcs.remove(name) {
+                None => report.missing.push(name_crc.as_ref().to_string()),
+                Some(live_crc) if live_crc != crc => report.crc_changed.push(CrcMismatch {
+                    name: name.to_string(),
+                    compiled_crc: crc.to_string(),
+                    live_crc,
+                }),
+                Some(_) => {}
+            }
+        }
+        report.only_in_vpp = live_crcs
+            .into_iter()
+            .map(|(name, crc)| format!("{name}_{crc}"))
+            .collect();
+
+        report.missing.sort();
+        report.crc_changed.sort_by(|a, b| a.name.cmp(&b.name));
+        report.only_in_vpp.sort();
+        report
+    }
+
+    /// Returns `true` if every checked message is known to VPP with the same CRC.
+    ///
+    /// Messages only VPP has do not affect compatibility.
+    pub fn is_compatible(&self) -> bool {
+        self.missing.is_empty() && self.crc_changed.is_empty()
+    }
+}
+
+impl fmt::Display for CompatibilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} missing, {} with changed CRC, {} only in VPP",
+            self.missing.len(),
+            self.crc_changed.len(),
+            self.only_in_vpp.len()
+        )?;
+        for name in &self.missing {
+            write!(f, "\n  missing: {name}")?;
+        }
+        for mismatch in &self.crc_changed {
+            write!(
+                f,
+                "\n  crc changed: {} (compiled {}, vpp {})",
+                mismatch.name, mismatch.compiled_crc, mismatch.live_crc
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits a `name_crc` string at the last underscore.
+fn split_name_and_crc(name_crc: &str) -> (&str, &str) {
+    name_crc.rsplit_once('_').unwrap_or((name_crc, ""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compatibility_report() {
+        let compiled = [
+            "control_ping_51077d14",
+            "sw_interface_dump_aa610c27",
+            "my_plugin_enable_12345678",
+        ];
+        let live = [
+            "control_ping_51077d14",
+            "sw_interface_dump_deadbeef",
+            "show_version_51077d14",
+        ];
+        let report = CompatibilityReport::new(compiled, live);
+
+        assert!(!report.is_compatible());
+        assert_eq!(report.missing, vec!["my_plugin_enable_12345678"]);
+        assert_eq!(
+            report.crc_changed,
+            vec![CrcMismatch {
+                name: "sw_interface_dump".into(),
+                compiled_crc: "aa610c27".into(),
+                live_crc: "deadbeef".into(),
+            }]
+        );
+        assert_eq!(report.only_in_vpp, vec!["show_version_51077d14"]);
+
+        let report = CompatibilityReport::new(["control_ping_51077d14"], live);
+        assert!(report.is_compatible());
+    }
+}
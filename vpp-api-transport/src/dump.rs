@@ -0,0 +1,44 @@
+use crate::error::{Result, TransportError};
+use crate::helpers::read_msg_from_slice;
+use vpp_api_encoding::wire::VppDecode;
+
+/// Message ids that make up the reply side of a dump.
+///
+/// A dump is sent together with a `control_ping` carrying the same context.
+/// VPP answers with any number of details messages followed by the
+/// `control_ping_reply`, which marks the end of the dump.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DumpIds {
+    /// The message id of the details messages.
+    pub details: u16,
+    /// The message id of the control ping reply that terminates the dump.
+    pub done: u16,
+}
+
+/// Decodes a single frame received in reply to a dump.
+///
+/// # Returns
+/// `Some(details)` for a details message, `None` once the terminating
+/// control ping reply has been received.
+///
+/// # Errors
+/// Returns an error if the frame is neither a details message nor the control
+/// ping reply, or if decoding fails.
+pub(crate) fn decode_dump_reply<D>(msg_id: u16, data: &[u8], ids: DumpIds) -> Result<Option<D>>
+where
+    D: VppDecode,
+{
+    if msg_id == ids.details {
+        read_msg_from_slice(data).map(Some)
+    } else if msg_id == ids.done {
+        Ok(None)
+    } else {
+        Err(TransportError::Decode {
+            message: std::any::type_name::<D>().to_string(),
+            reason: format!(
+                "Unexpected message id '{msg_id}' in dump, Expected '{}' or '{}'",
+                ids.details, ids.done
+            ),
+        })
+    }
+}
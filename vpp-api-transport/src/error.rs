@@ -0,0 +1,139 @@
+use crate::api_errno::VppApiError;
+use thiserror::Error;
+use vpp_api_message::{VppApiMessage, VppApiReply};
+
+/// The result of a request to VPP.
+pub type Result<T, E = TransportError> = std::result::Result<T, E>;
+
+/// The ways a request to VPP can fail.
+///
+/// Every transport reports its failures as a `TransportError`, so callers can
+/// e.g. tell a closed connection from a request VPP refused, and match the
+/// [`VppApiError`] of a refused request.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum TransportError {
+    /// Connecting to VPP failed, or the connection was lost.
+    #[error("connection to VPP failed: {0}")]
+    Connection(String),
+    /// VPP did not answer in time.
+    #[error("timed out: {0}")]
+    Timeout(String),
+    /// A frame read from VPP was truncated or malformed.
+    #[error("framing error: {0}")]
+    Framing(String),
+    /// The message is not in the message table VPP sent.
+    #[error("Cannot find message id for {0}")]
+    UnknownMessage(String),
+    /// The message table of VPP does not match the messages the caller needs.
+    #[error("incompatible VPP message table: {0}")]
+    Incompatible(String),
+    /// The request cannot be sent as given.
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+    /// A frame could not be decoded as the expected message.
+    #[error("cannot decode {message}: {reason}")]
+    Decode {
+        /// The message, or type, the frame was decoded as.
+        message: String,
+        /// Why decoding failed.
+        reason: String,
+    },
+    /// VPP answered the request with a negative `retval`.
+    #[error("{message} failed: {error}")]
+    Retval {
+        /// The `name_crc` of the request.
+        message: String,
+        /// The error the `retval` of the reply stands for.
+        error: VppApiError,
+    },
+    /// Recording the API traffic to a capture file failed.
+    #[error("recording failed: {0}")]
+    Recording(String),
+    /// A capture or API trace file cannot be read or is malformed.
+    #[error("cannot read capture: {0}")]
+    InvalidCapture(String),
+    /// A replayed session asked for something the capture does not hold.
+    #[error("replay diverged: {0}")]
+    Replay(String),
+    /// A request of a batch failed.
+    #[error("request {index} of {total} failed, {failed} failed in total")]
+    Batch {
+        /// The position of the first failed request.
+        index: usize,
+        /// The number of requests in the batch.
+        total: usize,
+        /// The number of failed requests.
+        failed: usize,
+        /// The error of the first failed request.
+        #[source]
+        source: Box<TransportError>,
+    },
+    /// A request to an instance of a fleet failed.
+    #[error("instance {instance} failed, {failed} failed in total")]
+    Instance {
+        /// The name of the first failed instance.
+        instance: String,
+        /// The number of failed instances.
+        failed: usize,
+        /// The error of the first failed instance.
+        #[source]
+        source: Box<TransportError>,
+    },
+}
+
+impl TransportError {
+    /// Returns the error VPP answered with, if the request was refused.
+    ///
+    /// Looks through [`TransportError::Batch`] and [`TransportError::Instance`]
+    /// to the failure they wrap.
+    pub fn vpp_error(&self) -> Option<VppApiError> {
+        match self {
+            TransportError::Retval { error, .. } => Some(*error),
+            TransportError::Batch { source, .. } | TransportError::Instance { source, .. } => {
+                source.vpp_error()
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the `retval` VPP answered with, if the request was refused.
+    pub fn retval(&self) -> Option<i32> {
+        self.vpp_error().map(|error| error.retval())
+    }
+}
+
+impl From<std::io::Error> for TransportError {
+    fn from(e: std::io::Error) -> Self {
+        TransportError::Connection(e.to_string())
+    }
+}
+
+impl From<tokio::task::JoinError> for TransportError {
+    fn from(e: tokio::task::JoinError) -> Self {
+        TransportError::Connection(format!("transport task failed: {e}"))
+    }
+}
+
+/// Returns the [`TransportError::Retval`] for a request `T` VPP answered with a non-zero `retval`.
+pub(crate) fn retval_error<T: VppApiMessage>(retval: i32) -> TransportError {
+    TransportError::Retval {
+        message: T::get_message_name_and_crc(),
+        error: VppApiError::from_retval(retval).unwrap_or(VppApiError::Unknown(retval)),
+    }
+}
+
+/// Turns a reply to the request `T` into a [`TransportError::Retval`] if its `retval` is negative.
+pub(crate) fn check_retval<T, R>(reply: R) -> Result<R>
+where
+    T: VppApiMessage,
+    R: VppApiReply,
+{
+    match VppApiError::from_retval(reply.retval()) {
+        Some(error) => Err(TransportError::Retval {
+            message: T::get_message_name_and_crc(),
+            error,
+        }),
+        None => Ok(reply),
+    }
+}
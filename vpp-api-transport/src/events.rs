@@ -0,0 +1,45 @@
+use crate::error::Result;
+use futures::stream::{BoxStream, Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A stream of decoded event messages for an active subscription.
+///
+/// Events are delivered in the order VPP sends them. Dropping the stream ends
+/// the subscription; once the last subscriber for an event is gone, the
+/// transport asks VPP to stop sending it.
+pub struct EventStream<E> {
+    /// The decoded events.
+    events: BoxStream<'static, Result<E>>,
+    /// Tears down the subscription when the stream is dropped.
+    on_drop: Option<Box<dyn FnOnce() + Send>>,
+}
+
+impl<E> EventStream<E> {
+    /// Creates an event stream that runs `on_drop` when it goes away.
+    pub(crate) fn new(
+        events: BoxStream<'static, Result<E>>,
+        on_drop: impl FnOnce() + Send + 'static,
+    ) -> Self {
+        EventStream {
+            events,
+            on_drop: Some(Box::new(on_drop)),
+        }
+    }
+}
+
+impl<E> Stream for EventStream<E> {
+    type Item = Result<E>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.events.poll_next_unpin(cx)
+    }
+}
+
+impl<E> Drop for EventStream<E> {
+    fn drop(&mut self) {
+        if let Some(on_drop) = self.on_drop.take() {
+            on_drop();
+        }
+    }
+}
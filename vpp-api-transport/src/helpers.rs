@@ -1,44 +1,42 @@
+use crate::codec::{VppFrameCodec, encode_frame};
+use crate::error::{Result, TransportError};
 use crate::message::SockMsgHeader;
-use anyhow::{Result, anyhow};
-use serde::{Deserialize, Serialize};
+use bytes::{Buf, Bytes};
 use std::collections::HashMap;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tracing::warn;
+use vpp_api_encoding::wire::{self, VppDecode, VppEncode};
 use vpp_api_message::VppApiMessage;
 
+/// The most bytes read at once for a message without a socket header.
+const UNFRAMED_READ_LEN: usize = 64 * 1024;
+
 /// Writes a serialized message object to the provided async writer.
 ///
 /// # Arguments
 /// * `writer` - The async writer to write to
 /// * `msg` - The message to serialize and write
-/// * `message_ids` - Map of message names to their IDs
-/// * `config` - The bincode configuration to use for encoding
+/// * `resolve_id` - Resolves a `name_crc` to its message ID
 /// * `has_socket_header` - flag indicates that message socket header is expected
 ///
 /// # Errors
 /// Returns an error if the message ID cannot be found or if writing fails
-pub async fn write_object<W, T, C, F>(
+pub async fn write_object<W, T, F>(
     writer: &mut W,
     msg: &T,
     resolve_id: &F,
-    config: C,
     has_socket_header: bool,
 ) -> Result<()>
 where
     W: AsyncWriteExt + Unpin,
-    T: Serialize + VppApiMessage,
-    C: bincode_next::config::Config,
-    F: Fn(String) -> Result<u16>,
+    T: VppEncode + VppApiMessage,
+    F: Fn(&str) -> Result<u16> + ?Sized,
 {
     let name = &T::get_message_name_and_crc();
-    let msg_id: u16 = resolve_id(name.clone())?;
-
-    let mut encoded = bincode_next::serde::encode_to_vec(msg_id, config)?;
-    let encoded_msg = bincode_next::serde::encode_to_vec(msg, config)?;
+    let msg_id: u16 = resolve_id(name)?;
 
-    encoded.extend_from_slice(&encoded_msg);
-
-    write_frame(writer, &encoded, config, has_socket_header).await
+    let frame = encode_frame(msg_id, msg)?;
+    write_frame(writer, &frame, has_socket_header).await
 }
 
 /// Writes a framed message to the provided async writer.
@@ -46,20 +44,17 @@ where
 /// # Arguments
 /// * `writer` - The async writer to write to
 /// * `bytes` - The message bytes to write
-/// * `config` - The bincode configuration to use for encoding
 /// * `has_socket_header` - flag indicates that message socket header is expected
 ///
 /// # Errors
 /// Returns an error if encoding the header or writing fails
-async fn write_frame<W, C>(
+pub(crate) async fn write_frame<W>(
     writer: &mut W,
     bytes: &[u8],
-    config: C,
     has_socket_header: bool,
 ) -> Result<()>
 where
     W: AsyncWriteExt + Unpin,
-    C: bincode_next::config::Config,
 {
     if has_socket_header {
         let hdr = SockMsgHeader {
@@ -67,7 +62,8 @@ where
             msglen: bytes.len() as u32,
             gc_mark: 0,
         };
-        let hdre = bincode_next::serde::encode_to_vec(&hdr, config)?;
+        let hdre = wire::encode_to_vec(&hdr)
+            .map_err(|e| TransportError::Framing(format!("cannot encode header: {e}")))?;
 
         writer.write_all(&hdre).await?;
     }
@@ -81,8 +77,7 @@ where
 ///
 /// # Arguments
 /// * `reader` - The async reader to read from
-/// * `message_ids` - Map of message names to their IDs
-/// * `config` - The bincode configuration to use for decoding
+/// * `resolve_id` - Resolves a `name_crc` to its message ID
 /// * `has_socket_header` - flag indicates that message socket header is expected
 ///
 /// # Errors
@@ -91,26 +86,21 @@ where
 pub async fn read_object<R, T, C, F>(
     reader: &mut R,
     resolve_id: &F,
-    config: C,
     has_socket_header: bool,
 ) -> Result<T>
 where
     R: AsyncReadExt + Unpin,
-    T: for<'a> Deserialize<'a> + VppApiMessage,
-    C: bincode_next::config::Config,
-    F: Fn(String) -> Result<u16>,
+    T: VppDecode + VppApiMessage,
+    F: Fn(&str) -> Result<u16> + ?Sized,
 {
     let name = &T::get_message_name_and_crc();
-    let expected_msg_id: u16 = resolve_id(name.clone())?;
-    let encoded = read_frame(reader, config, has_socket_header).await?;
+    let expected_msg_id: u16 = resolve_id(name)?;
+    let encoded = read_frame(reader, has_socket_header).await?;
     let (msg_id, data) = split_into_id_and_msg(&encoded)?;
     if msg_id == expected_msg_id {
-        let (decode_result, _) = bincode_next::serde::decode_from_slice(&data, config)?;
-        Ok(decode_result)
+        read_msg_from_slice(data)
     } else {
-        Err(anyhow!(
-            "Unexpected message id '{msg_id}', Expected '{expected_msg_id}'"
-        ))
+        Err(unexpected_message::<T>(msg_id, expected_msg_id))
     }
 }
 
@@ -119,32 +109,35 @@ where
 /// # Arguments
 /// * `slice` - The byte slice containing the encoded message
 /// * `message_ids` - Map of message names to their IDs
-/// * `config` - The bincode configuration to use for decoding
 ///
 /// # Errors
 /// Returns an error if the message ID cannot be found, if the message ID doesn't match
 /// the expected ID, or if decoding fails
-pub fn read_object_from_slice<T, C>(
-    slice: &[u8],
-    message_ids: &HashMap<String, u16>,
-    config: C,
-) -> Result<T>
+pub fn read_object_from_slice<T>(slice: &[u8], message_ids: &HashMap<String, u16>) -> Result<T>
 where
-    T: for<'a> Deserialize<'a> + VppApiMessage,
-    C: bincode_next::config::Config,
+    T: VppDecode + VppApiMessage,
 {
     let name = &T::get_message_name_and_crc();
     let expected_msg_id = message_ids
         .get(name)
-        .ok_or(anyhow!("Cannot find message id for {name}"))?;
+        .ok_or_else(|| TransportError::UnknownMessage(name.clone()))?;
 
     let (msg_id, data) = split_into_id_and_msg(slice)?;
     if msg_id == *expected_msg_id {
-        read_msg_from_slice(&data, config)
+        read_msg_from_slice(data)
     } else {
-        Err(anyhow!(
-            "Unexpected message id '{msg_id}', Expected '{expected_msg_id}'"
-        ))
+        Err(unexpected_message::<T>(msg_id, *expected_msg_id))
+    }
+}
+
+/// Builds the error for a reply that carries another message id than the expected `T`.
+pub(crate) fn unexpected_message<T: VppApiMessage>(
+    msg_id: u16,
+    expected_msg_id: u16,
+) -> TransportError {
+    TransportError::Decode {
+        message: T::get_message_name_and_crc(),
+        reason: format!("Unexpected message id '{msg_id}', Expected '{expected_msg_id}'"),
     }
 }
 
@@ -152,24 +145,29 @@ where
 ///
 /// # Arguments
 /// * `slice` - The byte slice containing the encoded message
-/// * `config` - The bincode configuration to use for decoding
 ///
 /// # Errors
 /// Returns an error if decoding fails
-pub fn read_msg_from_slice<T, C>(slice: &[u8], config: C) -> Result<T>
+pub fn read_msg_from_slice<T>(slice: &[u8]) -> Result<T>
 where
-    T: for<'a> Deserialize<'a>,
-    C: bincode_next::config::Config,
+    T: VppDecode,
 {
-    let (decode_result, _) = bincode_next::serde::decode_from_slice(slice, config)?;
+    let (decode_result, _) =
+        wire::decode_from_slice(slice).map_err(|e| TransportError::Decode {
+            message: std::any::type_name::<T>().to_string(),
+            reason: e.to_string(),
+        })?;
     Ok(decode_result)
 }
 
 /// Reads a framed message from the provided async reader.
 ///
+/// Without the socket header nothing tells where a message ends, so whatever a
+/// single read returns, up to [`UNFRAMED_READ_LEN`] bytes, is taken as one message.
+/// Streams with headers are better read through a [`VppFrameCodec`].
+///
 /// # Arguments
 /// * `reader` - The async reader to read from
-/// * `config` - The bincode configuration to use for decoding
 /// * `has_socket_header` - flag indicates that message socket header should prepend the message
 ///
 /// # Returns
@@ -178,41 +176,39 @@ where
 /// # Errors
 /// Returns an error if the header is invalid, if the message length is invalid,
 /// or if reading fails
-async fn read_frame<R, C>(reader: &mut R, config: C, has_socket_header: bool) -> Result<Vec<u8>>
+pub(crate) async fn read_frame<R>(reader: &mut R, has_socket_header: bool) -> Result<Vec<u8>>
 where
     R: AsyncReadExt + Unpin,
-    C: bincode_next::config::Config,
 {
     if has_socket_header {
-        let header = read_header(reader, config).await?;
+        let header = read_header(reader).await?;
 
         match header.msglen.try_into() {
             Ok(msglen) => {
-                if msglen == 0 {
-                    return Err(anyhow!("Invalid header, header.msglen == 0"));
-                }
+                VppFrameCodec::new().check_length(msglen)?;
                 let mut data = vec![0; msglen];
                 if let Err(e) = reader.read_exact(&mut data).await {
                     warn!("expected {} byte message, got error: {:?}", msglen, e);
-                    return Err(anyhow!("Invalid Message {e}"));
+                    return Err(TransportError::Framing(format!("Invalid Message {e}")));
                 }
                 Ok(data)
             }
-            Err(e) => Err(anyhow!(
+            Err(e) => Err(TransportError::Framing(format!(
                 "msg length {} couldn't be converted to usize: {}",
-                header.msglen,
-                e
-            )),
+                header.msglen, e
+            ))),
         }
     } else {
-        let mut data = vec![0; 655336];
-        match reader.read(&mut data).await {
+        let mut data = Vec::with_capacity(UNFRAMED_READ_LEN);
+        match reader.read_buf(&mut data).await {
             Err(e) => {
                 warn!("expected message, got error: {:?}", e);
-                Err(anyhow!("Invalid Message {e}"))
+                Err(TransportError::Framing(format!("Invalid Message {e}")))
             }
-            Ok(0) => Err(anyhow!("Invalid Message: 0 bytes len")),
-            Ok(n) => Ok(data[0..n].to_vec()),
+            Ok(0) => Err(TransportError::Framing(
+                "Invalid Message: 0 bytes len".into(),
+            )),
+            Ok(_) => Ok(data),
         }
     }
 }
@@ -221,27 +217,23 @@ where
 ///
 /// # Arguments
 /// * `reader` - The async reader to read from
-/// * `config` - The bincode configuration to use for decoding
 ///
 /// # Returns
 /// Returns the decoded message header
 ///
 /// # Errors
 /// Returns an error if reading fails or if the header cannot be decoded
-async fn read_header<R, C>(reader: &mut R, config: C) -> Result<SockMsgHeader>
+async fn read_header<R>(reader: &mut R) -> Result<SockMsgHeader>
 where
     R: AsyncReadExt + Unpin,
-    C: bincode_next::config::Config,
 {
     let mut header_buf = [0; 16];
 
     if let Err(e) = reader.read_exact(&mut header_buf).await {
         warn!("read invalid header: {:?} err: {:?}", header_buf, e);
-        return Err(anyhow!("Invalid header"));
+        return Err(TransportError::Framing("Invalid header".into()));
     }
-    let (header, _): (SockMsgHeader, usize) =
-        bincode_next::serde::decode_from_slice(&header_buf[..], config)?;
-    Ok(header)
+    read_msg_from_slice(&header_buf[..])
 }
 
 /// Splits encoded message data into message ID and payload.
@@ -250,18 +242,34 @@ where
 /// * `data` - The byte slice containing the encoded message ID and payload
 ///
 /// # Returns
-/// Returns a tuple of (message_id, payload_bytes)
+/// Returns a tuple of (message_id, payload_bytes), borrowing the payload from `data`
 ///
 /// # Errors
 /// Returns an error if the data length is less than 3 bytes
-fn split_into_id_and_msg(data: &[u8]) -> Result<(u16, Vec<u8>)> {
+pub(crate) fn split_into_id_and_msg(data: &[u8]) -> Result<(u16, &[u8])> {
     if data.len() < 3 {
-        return Err(anyhow!(
+        return Err(TransportError::Framing(format!(
             "short read message len: {}  {:x?}",
             data.len(),
             data
-        ));
+        )));
     }
     let msg_id: u16 = ((data[0] as u16) << 8) + (data[1] as u16);
-    Ok((msg_id, data[2..].to_vec()))
+    Ok((msg_id, &data[2..]))
+}
+
+/// Splits a received frame into message ID and payload without copying the payload.
+///
+/// # Arguments
+/// * `frame` - The frame, the encoded message ID followed by the payload
+///
+/// # Returns
+/// Returns a tuple of (message_id, payload), the payload sharing the buffer of `frame`
+///
+/// # Errors
+/// Returns an error if the frame is shorter than 3 bytes
+pub(crate) fn split_frame(mut frame: Bytes) -> Result<(u16, Bytes)> {
+    let (msg_id, _) = split_into_id_and_msg(&frame)?;
+    frame.advance(2);
+    Ok((msg_id, frame))
 }
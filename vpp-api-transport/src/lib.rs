@@ -1,6 +1,27 @@
 #[macro_use]
 mod macros;
 pub mod afunix;
+#[rustfmt::skip]
+pub mod api_errno;
+pub mod batch;
+pub mod capture;
+pub mod codec;
+pub mod compat;
+mod dump;
+pub mod error;
+pub mod events;
 pub mod helpers;
 pub mod message;
+pub mod replay;
+mod router;
 pub mod shmem;
+pub mod table;
+pub mod testing;
+pub mod trace;
+pub mod transport;
+
+pub use api_errno::VppApiError;
+pub use codec::VppFrameCodec;
+pub use error::TransportError;
+pub use table::MessageTable;
+pub use transport::VppTransport;
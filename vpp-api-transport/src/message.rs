@@ -1,17 +1,27 @@
-use anyhow::{Result, anyhow};
+use crate::error::{Result, TransportError};
 use serde::{Deserialize, Serialize};
 use std::convert::TryInto;
 use vpp_api_encoding::typ::*;
-use vpp_api_message::VppApiMessage;
+use vpp_api_macros::{VppDecode, VppEncode};
+use vpp_api_message::{VppApiMessage, VppApiReply};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, VppEncode, VppDecode, Debug)]
 pub(crate) struct SockMsgHeader {
     pub _q: u64,
     pub msglen: u32,
     pub gc_mark: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+/// The fields every reply message starts with.
+///
+/// Used to check the outcome of requests whose reply type the transport does not know.
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode)]
+pub(crate) struct RawReplyHeader {
+    pub context: u32,
+    pub retval: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub(crate) struct RawControlPing {
     pub client_index: u32,
     pub context: u32,
@@ -31,7 +41,7 @@ impl VppApiMessage for RawControlPing {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode)]
 #[allow(dead_code)]
 pub(crate) struct RawControlPingReply {
     pub context: u32,
@@ -40,6 +50,12 @@ pub(crate) struct RawControlPingReply {
     pub vpe_pid: u32,
 }
 
+impl VppApiReply for RawControlPingReply {
+    fn retval(&self) -> i32 {
+        self.retval
+    }
+}
+
 impl VppApiMessage for RawControlPingReply {
     fn get_message_name_and_crc() -> String {
         "control_ping_reply_f6b0b8ca".into()
@@ -54,7 +70,55 @@ impl VppApiMessage for RawControlPingReply {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
+pub(crate) struct RawMemclntKeepalive {
+    pub client_index: u32,
+    pub context: u32,
+}
+
+impl VppApiMessage for RawMemclntKeepalive {
+    fn get_message_name_and_crc() -> String {
+        "memclnt_keepalive_51077d14".into()
+    }
+
+    fn set_context(&mut self, context: u32) {
+        self.context = context;
+    }
+
+    fn set_client_index(&mut self, client_index: u32) {
+        self.client_index = client_index;
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
+pub(crate) struct RawMemclntKeepaliveReply {
+    pub context: u32,
+    pub retval: i32,
+}
+
+impl RawMemclntKeepaliveReply {
+    /// Builds the reply to a keepalive, echoing the context VPP sent it with.
+    pub fn answering(keepalive: &RawMemclntKeepalive) -> Self {
+        RawMemclntKeepaliveReply {
+            context: keepalive.context,
+            retval: 0,
+        }
+    }
+}
+
+impl VppApiMessage for RawMemclntKeepaliveReply {
+    fn get_message_name_and_crc() -> String {
+        "memclnt_keepalive_reply_e8d4e804".into()
+    }
+
+    fn set_context(&mut self, context: u32) {
+        self.context = context;
+    }
+
+    fn set_client_index(&mut self, _client_index: u32) {}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode)]
 pub(crate) struct RawCliInband {
     pub client_index: u32,
     pub context: u32,
@@ -66,7 +130,7 @@ impl RawCliInband {
         Ok(RawCliInband {
             client_index: 0,
             context: 0,
-            cmd: cmd.try_into().map_err(|e| anyhow!("{e}"))?,
+            cmd: cmd.try_into().map_err(TransportError::InvalidRequest)?,
         })
     }
 }
@@ -85,13 +149,19 @@ impl VppApiMessage for RawCliInband {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode)]
 pub(crate) struct RawCliInbandReply {
     pub context: u32,
     pub retval: i32,
     pub reply: VariableSizeString,
 }
 
+impl VppApiReply for RawCliInbandReply {
+    fn retval(&self) -> i32 {
+        self.retval
+    }
+}
+
 impl VppApiMessage for RawCliInbandReply {
     fn get_message_name_and_crc() -> String {
         "cli_inband_reply_05879051".into()
@@ -104,17 +174,74 @@ impl VppApiMessage for RawCliInbandReply {
     fn set_client_index(&mut self, _client_index: u32) {}
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Asks VPP for the id of the first message of a plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode)]
+pub(crate) struct RawGetFirstMsgId {
+    pub client_index: u32,
+    pub context: u32,
+    pub name: FixedSizeString<typenum::U64>,
+}
+
+impl RawGetFirstMsgId {
+    pub fn new(name: &str) -> Result<Self> {
+        Ok(RawGetFirstMsgId {
+            client_index: 0,
+            context: 0,
+            name: name.try_into().map_err(TransportError::InvalidRequest)?,
+        })
+    }
+}
+
+impl VppApiMessage for RawGetFirstMsgId {
+    fn get_message_name_and_crc() -> String {
+        "get_first_msg_id_ebf79a66".into()
+    }
+
+    fn set_context(&mut self, context: u32) {
+        self.context = context;
+    }
+
+    fn set_client_index(&mut self, client_index: u32) {
+        self.client_index = client_index;
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode)]
+pub(crate) struct RawGetFirstMsgIdReply {
+    pub context: u32,
+    pub retval: i32,
+    pub first_msg_id: u16,
+}
+
+impl VppApiReply for RawGetFirstMsgIdReply {
+    fn retval(&self) -> i32 {
+        self.retval
+    }
+}
+
+impl VppApiMessage for RawGetFirstMsgIdReply {
+    fn get_message_name_and_crc() -> String {
+        "get_first_msg_id_reply_7d337472".into()
+    }
+
+    fn set_context(&mut self, context: u32) {
+        self.context = context;
+    }
+
+    fn set_client_index(&mut self, _client_index: u32) {}
+}
+
+#[derive(Serialize, Deserialize, VppEncode, VppDecode, Debug, Clone)]
 pub(crate) struct MsgSockClntCreate {
     pub context: u32,
     pub name: FixedSizeString<typenum::U64>,
 }
 
 impl TryFrom<&str> for MsgSockClntCreate {
-    type Error = anyhow::Error;
+    type Error = TransportError;
 
     fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
-        let name_fixed_str = value.try_into().map_err(|e| anyhow!("{e}"))?;
+        let name_fixed_str = value.try_into().map_err(TransportError::InvalidRequest)?;
         Ok(MsgSockClntCreate {
             context: 0,
             name: name_fixed_str,
@@ -140,7 +267,7 @@ impl VppApiMessage for MsgSockClntCreate {
     fn set_client_index(&mut self, _client_index: u32) {}
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, VppEncode, VppDecode, Debug)]
 pub(crate) struct MsgSockClntCreateReplyHdr {
     pub client_index: u32,
     pub context: u32,
@@ -170,7 +297,46 @@ impl VppApiMessage for MsgSockClntCreateReplyHdr {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
+pub(crate) struct RawSockclntDelete {
+    pub client_index: u32,
+    pub context: u32,
+    pub index: u32,
+}
+
+impl VppApiMessage for RawSockclntDelete {
+    fn get_message_name_and_crc() -> String {
+        "sockclnt_delete_8ac76db6".into()
+    }
+
+    fn set_context(&mut self, context: u32) {
+        self.context = context;
+    }
+
+    fn set_client_index(&mut self, client_index: u32) {
+        self.client_index = client_index;
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode)]
+pub(crate) struct RawSockclntDeleteReply {
+    pub context: u32,
+    pub response: i32,
+}
+
+impl VppApiMessage for RawSockclntDeleteReply {
+    fn get_message_name_and_crc() -> String {
+        "sockclnt_delete_reply_8f38b1ee".into()
+    }
+
+    fn set_context(&mut self, context: u32) {
+        self.context = context;
+    }
+
+    fn set_client_index(&mut self, _client_index: u32) {}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, VppEncode, VppDecode, Default)]
 pub(crate) struct MessageTableEntry {
     pub index: u16,
     pub name: FixedSizeString<typenum::U64>,
@@ -0,0 +1,265 @@
+use crate::capture::{CaptureRecord, Direction, read_capture};
+use crate::error::{Result, TransportError, retval_error};
+use crate::helpers::read_msg_from_slice;
+use crate::message::*;
+use crate::transport::VppTransport;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use vpp_api_encoding::wire::{VppDecode, VppEncode};
+use vpp_api_message::{VppApiMessage, VppApiWantMessage};
+
+/// A transport that plays a captured API session back to the application.
+///
+/// Every message the application sends is matched against the next message sent
+/// in the capture, by `name_crc`; the replies are the ones VPP gave to that message
+/// during the capture, with the context rewritten to the one of the replayed
+/// request. The contents of the sent messages are not compared, so an
+/// application that makes the same calls in the same order gets the same answers.
+/// Keepalives in the capture are skipped.
+pub struct ReplayClient {
+    /// The records not played back yet.
+    records: VecDeque<CaptureRecord>,
+    /// The message table seen in the capture, by `name_crc`.
+    messages: HashMap<String, u16>,
+    /// The context given to the next request.
+    context_id: u32,
+}
+
+impl ReplayClient {
+    /// Opens a capture file for replay.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A capture written by [`crate::capture::Recorder`]
+    ///
+    /// # Returns
+    ///
+    /// The replay client, or an error if the capture cannot be read.
+    pub fn open(path: impl AsRef<Path>) -> Result<ReplayClient> {
+        Ok(ReplayClient::new(read_capture(path)?))
+    }
+
+    /// Creates a replay client from records in capture order.
+    ///
+    /// # Arguments
+    ///
+    /// * `records` - The captured session
+    pub fn new(records: Vec<CaptureRecord>) -> ReplayClient {
+        let keepalives = [
+            RawMemclntKeepalive::get_message_name_and_crc(),
+            RawMemclntKeepaliveReply::get_message_name_and_crc(),
+        ];
+        let messages = records
+            .iter()
+            .filter(|record| !record.name_crc.is_empty())
+            .map(|record| (record.name_crc.clone(), record.msg_id))
+            .collect();
+        ReplayClient {
+            records: records
+                .into_iter()
+                .filter(|record| !keepalives.contains(&record.name_crc))
+                .collect(),
+            messages,
+            context_id: 1,
+        }
+    }
+
+    /// Returns the number of captured messages that have not been played back.
+    pub fn remaining(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Takes the next sent record, which must be the message the application sends now.
+    fn next_sent(&mut self, name: &str) -> Result<CaptureRecord> {
+        let position = self
+            .records
+            .iter()
+            .position(|record| record.direction == Direction::Sent)
+            .ok_or_else(|| {
+                TransportError::Replay(format!("{name} sent after the end of the capture"))
+            })?;
+        let record = self.records.remove(position).unwrap();
+        if record.name_crc != name {
+            return Err(TransportError::Replay(format!(
+                "application sent {name}, capture has {}",
+                record.name_crc
+            )));
+        }
+        Ok(record)
+    }
+
+    /// Takes the first received record that matches `wanted`.
+    fn take_received(&mut self, wanted: impl Fn(&CaptureRecord) -> bool) -> Option<CaptureRecord> {
+        let position = self
+            .records
+            .iter()
+            .position(|record| record.direction == Direction::Received && wanted(record))?;
+        self.records.remove(position)
+    }
+
+    /// Takes the reply VPP gave to `request` during the capture.
+    fn take_reply(&mut self, request: &CaptureRecord) -> Result<CaptureRecord> {
+        let context = request.context;
+        self.take_received(|record| record.context == context)
+            .ok_or_else(|| {
+                TransportError::Replay(format!("capture has no reply to {}", request.name_crc))
+            })
+    }
+
+    /// Decodes a received record as `R`, giving it the context of the replayed request.
+    fn decode<R>(&self, record: &CaptureRecord, context: u32) -> Result<R>
+    where
+        R: VppDecode + VppApiMessage,
+    {
+        let name = R::get_message_name_and_crc();
+        if record.name_crc != name {
+            return Err(TransportError::Replay(format!(
+                "expected {name}, capture has {}",
+                record.name_crc
+            )));
+        }
+        let mut msg: R = read_msg_from_slice(&record.payload)?;
+        msg.set_context(context);
+        Ok(msg)
+    }
+
+    /// Hands out the context for the next request.
+    fn get_next_context(&mut self) -> u32 {
+        let context = self.context_id;
+        self.context_id += 1;
+        context
+    }
+}
+
+impl VppTransport for ReplayClient {
+    async fn send<T>(&mut self, _msg: T) -> Result<()>
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+    {
+        self.next_sent(&T::get_message_name_and_crc())?;
+        Ok(())
+    }
+
+    async fn receive<R>(&mut self) -> Result<R>
+    where
+        R: VppDecode + VppApiMessage + Send,
+    {
+        let name = R::get_message_name_and_crc();
+        let record = self
+            .take_received(|record| record.name_crc == name)
+            .ok_or_else(|| TransportError::Replay(format!("capture has no more {name}")))?;
+        let context = record.context.unwrap_or_default();
+        self.decode(&record, context)
+    }
+
+    async fn send_rcv<T, R>(&mut self, _msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+        R: VppDecode + VppApiMessage + Send,
+    {
+        let request = self.next_sent(&T::get_message_name_and_crc())?;
+        let reply = self.take_reply(&request)?;
+        let context = self.get_next_context();
+        self.decode(&reply, context)
+    }
+
+    async fn send_dump<T, D>(&mut self, _msg: T) -> Result<BoxStream<'_, Result<D>>>
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+        D: VppDecode + VppApiMessage + Send + 'static,
+    {
+        let request = self.next_sent(&T::get_message_name_and_crc())?;
+        self.next_sent(&RawControlPing::get_message_name_and_crc())?;
+        let context = self.get_next_context();
+        let done = RawControlPingReply::get_message_name_and_crc();
+        let mut details = vec![];
+        loop {
+            let reply = self.take_reply(&request)?;
+            if reply.name_crc == done {
+                break;
+            }
+            details.push(self.decode(&reply, context));
+        }
+        Ok(futures::stream::iter(details).boxed())
+    }
+
+    async fn subscribe<W, E>(&mut self, _want: W) -> Result<BoxStream<'_, Result<E>>>
+    where
+        W: VppEncode + VppApiMessage + VppApiWantMessage + Send + Sync + 'static,
+        E: VppDecode + VppApiMessage + Send + 'static,
+    {
+        let request = self.next_sent(&W::get_message_name_and_crc())?;
+        let reply = self.take_reply(&request)?;
+        let header: RawReplyHeader = read_msg_from_slice(&reply.payload)?;
+        if header.retval != 0 {
+            return Err(retval_error::<W>(header.retval));
+        }
+        let name = E::get_message_name_and_crc();
+        let mut events = vec![];
+        while let Some(record) = self.take_received(|record| record.name_crc == name) {
+            let context = record.context.unwrap_or_default();
+            events.push(self.decode(&record, context));
+        }
+        Ok(futures::stream::iter(events).boxed())
+    }
+
+    fn get_message_index(&self, name: &str) -> Result<u16> {
+        self.messages
+            .get(name)
+            .copied()
+            .ok_or_else(|| TransportError::UnknownMessage(name.to_owned()))
+    }
+
+    async fn control_ping(&mut self) -> Result<i32> {
+        let reply: RawControlPingReply = self.send_rcv(RawControlPing::default()).await?;
+        Ok(reply.retval)
+    }
+
+    async fn run_cli_inband(&mut self, cmd: &str) -> Result<String> {
+        let reply: RawCliInbandReply = self.send_rcv(RawCliInband::new(cmd)?).await?;
+        Ok(reply.reply.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::afunix::client::Client;
+    use crate::testing::FakeVpp;
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let path = std::env::temp_dir().join(format!("vpp-api-replay-{}.cap", std::process::id()));
+        let vpp =
+            FakeVpp::start_with_messages(["cli_inband_f8377302", "cli_inband_reply_05879051"])
+                .unwrap();
+        vpp.on::<RawCliInband, _>(|req| RawCliInbandReply {
+            context: 0,
+            retval: 0,
+            reply: format!("ran {}", req.cmd).as_str().try_into().unwrap(),
+        });
+
+        let client = Client::connect(vpp.path(), "recorded").await.unwrap();
+        client.start_recording(&path).unwrap();
+        assert_eq!(client.control_ping().await.unwrap(), 0);
+        let recorded = client.run_cli_inband("show version").await.unwrap();
+        client.stop_recording().unwrap();
+        client.disconnect().await.unwrap();
+        drop(vpp);
+
+        let mut replay = ReplayClient::open(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(replay.remaining(), 4);
+        assert_eq!(VppTransport::control_ping(&mut replay).await.unwrap(), 0);
+        assert_eq!(
+            VppTransport::run_cli_inband(&mut replay, "show version")
+                .await
+                .unwrap(),
+            recorded
+        );
+        assert_eq!(replay.remaining(), 0);
+        assert!(VppTransport::control_ping(&mut replay).await.is_err());
+    }
+}
@@ -0,0 +1,464 @@
+use crate::error::{Result, TransportError};
+use bytes::Bytes;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use tokio::sync::{Notify, mpsc, oneshot};
+use tracing::debug;
+
+/// A raw reply frame, already split into the message id and the payload.
+#[derive(Debug, Clone)]
+pub(crate) struct RawReply {
+    pub msg_id: u16,
+    pub data: Bytes,
+}
+
+/// Routes reply frames to the requests waiting for them.
+///
+/// Every request registers a waiter under the context it was sent with. Replies
+/// carry the same context as the first field after the message id, which is
+/// used to complete the matching waiter. Event messages carry no context and
+/// are instead delivered by message id to every event subscriber, for as long
+/// as the event has a subscriber. Any other message no request waits for is
+/// handed to the oldest [`ReplyRouter::receive`] call for its message id, or
+/// kept until one asks for it.
+#[derive(Default)]
+pub(crate) struct ReplyRouter {
+    state: Mutex<RouterState>,
+    /// Woken once the router is closed.
+    closed: Notify,
+}
+
+/// The number of unsolicited messages kept for [`ReplyRouter::receive`] until
+/// the oldest is dropped.
+pub(crate) const UNSOLICITED_BACKLOG: usize = 64;
+
+/// The sending side of a waiter that receives more than one reply.
+type ReplySender = mpsc::UnboundedSender<Result<RawReply>>;
+
+/// A request waiting for one or more replies.
+enum Waiter {
+    /// Completed by the first reply carrying the context.
+    Reply(oneshot::Sender<Result<RawReply>>),
+    /// Receives every reply carrying the context until the `done` message id arrives.
+    Stream { sender: ReplySender, done: u16 },
+}
+
+impl Waiter {
+    fn fail(self, error: TransportError) {
+        match self {
+            Waiter::Reply(sender) => {
+                sender.send(Err(error)).ok();
+            }
+            Waiter::Stream { sender, .. } => {
+                sender.send(Err(error)).ok();
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct RouterState {
+    /// Waiters for in-flight requests, keyed by context.
+    waiters: HashMap<u32, Waiter>,
+    /// Event subscribers keyed by event message id, each with its subscription token.
+    subscribers: HashMap<u16, Vec<(u64, ReplySender)>>,
+    /// Callers of [`ReplyRouter::receive`] keyed by message id, oldest first.
+    receivers: HashMap<u16, VecDeque<oneshot::Sender<Result<RawReply>>>>,
+    /// Messages nobody waited for, oldest first, at most [`UNSOLICITED_BACKLOG`].
+    unsolicited: VecDeque<RawReply>,
+    /// Number of unsolicited messages dropped from the backlog unreceived.
+    dropped_events: u64,
+    /// Source of subscription tokens.
+    next_token: u64,
+    /// Set once the connection is gone; new registrations fail with this reason.
+    closed: Option<String>,
+}
+
+impl ReplyRouter {
+    /// Registers a waiter for the reply with the given context.
+    ///
+    /// # Errors
+    /// Returns an error if the connection has already been closed.
+    pub fn register(&self, context: u32) -> Result<oneshot::Receiver<Result<RawReply>>> {
+        let (sender, receiver) = oneshot::channel();
+        self.insert(context, Waiter::Reply(sender))?;
+        Ok(receiver)
+    }
+
+    /// Registers a waiter for all replies with the given context.
+    ///
+    /// The waiter stays registered until a reply with the `done` message id has
+    /// been delivered, after which the returned receiver is closed.
+    ///
+    /// # Errors
+    /// Returns an error if the connection has already been closed.
+    pub fn register_stream(
+        &self,
+        context: u32,
+        done: u16,
+    ) -> Result<mpsc::UnboundedReceiver<Result<RawReply>>> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.insert(context, Waiter::Stream { sender, done })?;
+        Ok(receiver)
+    }
+
+    fn insert(&self, context: u32, waiter: Waiter) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(reason) = &state.closed {
+            return Err(closed(reason));
+        }
+        state.waiters.insert(context, waiter);
+        Ok(())
+    }
+
+    /// Subscribes to all messages with the given event message id.
+    ///
+    /// # Returns
+    /// A token identifying the subscription and the receiver the events are delivered to.
+    ///
+    /// # Errors
+    /// Returns an error if the connection has already been closed.
+    pub fn subscribe(
+        &self,
+        msg_id: u16,
+    ) -> Result<(u64, mpsc::UnboundedReceiver<Result<RawReply>>)> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(reason) = &state.closed {
+            return Err(closed(reason));
+        }
+        let (sender, receiver) = mpsc::unbounded_channel();
+        state.next_token += 1;
+        let token = state.next_token;
+        state
+            .subscribers
+            .entry(msg_id)
+            .or_default()
+            .push((token, sender));
+        Ok((token, receiver))
+    }
+
+    /// Removes an event subscription.
+    ///
+    /// Once the last subscriber is gone, the message id is no longer an event
+    /// and its messages are routed like any other.
+    ///
+    /// # Returns
+    /// `true` if this was the last subscriber for the event message id.
+    pub fn unsubscribe(&self, msg_id: u16, token: u64) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let Some(subscribers) = state.subscribers.get_mut(&msg_id) else {
+            return false;
+        };
+        subscribers.retain(|(t, _)| *t != token);
+        if subscribers.is_empty() {
+            state.subscribers.remove(&msg_id);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Waits for the next message with the given message id no request or
+    /// subscriber takes.
+    ///
+    /// A matching message that arrived before the call and is still in the
+    /// backlog completes the receiver right away.
+    ///
+    /// # Errors
+    /// Returns an error if the connection has already been closed.
+    pub fn receive(&self, msg_id: u16) -> Result<oneshot::Receiver<Result<RawReply>>> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(reason) = &state.closed {
+            return Err(closed(reason));
+        }
+        let (sender, receiver) = oneshot::channel();
+        let queued = state.unsolicited.iter().position(|m| m.msg_id == msg_id);
+        match queued.and_then(|index| state.unsolicited.remove(index)) {
+            Some(message) => {
+                sender.send(Ok(message)).ok();
+            }
+            None => {
+                let receivers = state.receivers.entry(msg_id).or_default();
+                // forget callers that gave up waiting
+                receivers.retain(|r| !r.is_closed());
+                receivers.push_back(sender);
+            }
+        }
+        Ok(receiver)
+    }
+
+    /// Removes the waiter for the given context, if any.
+    pub fn unregister(&self, context: u32) {
+        self.state.lock().unwrap().waiters.remove(&context);
+    }
+
+    /// Fails the request a frame was meant for, after the frame had to be dropped.
+    ///
+    /// Events are simply lost; a request waiting for the context of a dropped
+    /// reply fails with `error` instead of waiting forever.
+    pub fn fail_undelivered(&self, msg_id: u16, data: &[u8], error: TransportError) {
+        let mut state = self.state.lock().unwrap();
+        if state.subscribers.contains_key(&msg_id) {
+            return;
+        }
+        let Some(context) = reply_context(data) else {
+            return;
+        };
+        if let Some(waiter) = state.waiters.remove(&context) {
+            waiter.fail(error);
+        }
+    }
+
+    /// Delivers a reply frame to the waiter registered for its context, an
+    /// event to its subscribers, or any other message to a receiver.
+    ///
+    /// # Returns
+    /// `true` if a waiter, subscriber or receiver was found, `false` if the
+    /// frame was kept for a later [`ReplyRouter::receive`] instead.
+    pub fn route(&self, msg_id: u16, data: Bytes) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if let Some(subscribers) = state.subscribers.get(&msg_id) {
+            for (_, sender) in subscribers {
+                sender
+                    .send(Ok(RawReply {
+                        msg_id,
+                        data: data.clone(),
+                    }))
+                    .ok();
+            }
+            return true;
+        }
+        let waiter = reply_context(&data).and_then(|context| {
+            let waiter = state.waiters.remove(&context)?;
+            Some((context, waiter))
+        });
+        let Some((context, waiter)) = waiter else {
+            return state.unsolicited(RawReply { msg_id, data });
+        };
+        match waiter {
+            Waiter::Reply(sender) => {
+                sender.send(Ok(RawReply { msg_id, data })).ok();
+            }
+            Waiter::Stream { sender, done } => {
+                // keep the stream registered until it is finished or its consumer is gone
+                if sender.send(Ok(RawReply { msg_id, data })).is_ok() && msg_id != done {
+                    state
+                        .waiters
+                        .insert(context, Waiter::Stream { sender, done });
+                }
+            }
+        }
+        true
+    }
+
+    /// Fails all in-flight requests and refuses new ones.
+    pub fn close(&self, reason: String) {
+        let mut state = self.state.lock().unwrap();
+        for (_, waiter) in state.waiters.drain() {
+            waiter.fail(closed(&reason));
+        }
+        for (_, sender) in state.subscribers.drain().flat_map(|(_, s)| s) {
+            sender.send(Err(closed(&reason))).ok();
+        }
+        for sender in state.receivers.drain().flat_map(|(_, r)| r) {
+            sender.send(Err(closed(&reason))).ok();
+        }
+        state.closed = Some(reason);
+        drop(state);
+        self.closed.notify_waiters();
+    }
+
+    /// Returns the number of messages dropped because nobody subscribed to or received them.
+    pub fn dropped_events(&self) -> u64 {
+        self.state.lock().unwrap().dropped_events
+    }
+
+    /// Returns `true` once the router has been closed.
+    pub fn is_closed(&self) -> bool {
+        self.state.lock().unwrap().closed.is_some()
+    }
+
+    /// Waits until the router is closed and returns the reason.
+    pub async fn closed(&self) -> String {
+        loop {
+            // created before checking, so a close in between still wakes it
+            let notified = self.closed.notified();
+            if let Some(reason) = &self.state.lock().unwrap().closed {
+                return reason.clone();
+            }
+            notified.await;
+        }
+    }
+}
+
+impl RouterState {
+    /// Hands a message no request waits for to the oldest receiver for its
+    /// message id, or keeps it in the backlog.
+    ///
+    /// # Returns
+    /// `true` if a receiver took the message.
+    fn unsolicited(&mut self, message: RawReply) -> bool {
+        let msg_id = message.msg_id;
+        let mut message = Ok(message);
+        if let Some(receivers) = self.receivers.get_mut(&msg_id) {
+            while let Some(receiver) = receivers.pop_front() {
+                match receiver.send(message) {
+                    Ok(()) => return true,
+                    // that caller gave up, try the next one
+                    Err(back) => message = back,
+                }
+            }
+            self.receivers.remove(&msg_id);
+        }
+        let Ok(message) = message else {
+            return false;
+        };
+        debug!("keeping message id {msg_id}: nobody is waiting for it");
+        if self.unsolicited.len() == UNSOLICITED_BACKLOG {
+            self.unsolicited.pop_front();
+            self.dropped_events += 1;
+        }
+        self.unsolicited.push_back(message);
+        false
+    }
+}
+
+/// Builds the error requests fail with once the connection is gone.
+fn closed(reason: &str) -> TransportError {
+    TransportError::Connection(format!("connection closed: {reason}"))
+}
+
+/// Unregisters the waiter of a request when the request is finished or abandoned.
+///
+/// Keeps a late reply to a cancelled request from piling up in the router.
+pub(crate) struct PendingRequest<'a> {
+    pub router: &'a ReplyRouter,
+    pub context: u32,
+}
+
+impl Drop for PendingRequest<'_> {
+    fn drop(&mut self) {
+        self.router.unregister(self.context);
+    }
+}
+
+/// Extracts the context of a reply payload, which VPP places right after the message id.
+pub(crate) fn reply_context(data: &[u8]) -> Option<u32> {
+    let bytes: [u8; 4] = data.get(0..4)?.try_into().ok()?;
+    Some(u32::from_be_bytes(bytes))
+}
+
+/// Extracts the context of a request payload, which follows the client index.
+pub(crate) fn request_context(data: &[u8]) -> Option<u32> {
+    reply_context(data.get(4..)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_route_by_context() {
+        let router = ReplyRouter::default();
+        let first = router.register(1).unwrap();
+        let second = router.register(2).unwrap();
+
+        assert!(router.route(10, Bytes::from_static(&[0, 0, 0, 2, 0xaa])));
+        assert!(router.route(10, Bytes::from_static(&[0, 0, 0, 1, 0xbb])));
+        assert!(!router.route(10, Bytes::from_static(&[0, 0, 0, 3, 0xcc])));
+
+        assert_eq!(first.await.unwrap().unwrap().data[4], 0xbb);
+        assert_eq!(second.await.unwrap().unwrap().data[4], 0xaa);
+    }
+
+    #[tokio::test]
+    async fn test_stream_until_done() {
+        let router = ReplyRouter::default();
+        let mut stream = router.register_stream(7, 99).unwrap();
+
+        assert!(router.route(10, Bytes::from_static(&[0, 0, 0, 7, 1])));
+        assert!(!router.route(10, Bytes::from_static(&[0, 0, 0, 8, 2])));
+        assert!(router.route(10, Bytes::from_static(&[0, 0, 0, 7, 3])));
+        assert!(router.route(99, Bytes::from_static(&[0, 0, 0, 7])));
+        assert!(!router.route(10, Bytes::from_static(&[0, 0, 0, 7, 4])));
+
+        let mut ids = vec![];
+        while let Some(reply) = stream.recv().await {
+            ids.push(reply.unwrap().msg_id);
+        }
+        assert_eq!(ids, vec![10, 10, 99]);
+    }
+
+    #[tokio::test]
+    async fn test_events_by_msg_id() {
+        let router = ReplyRouter::default();
+        let (first_token, mut first) = router.subscribe(50).unwrap();
+        let (second_token, mut second) = router.subscribe(50).unwrap();
+        let waiter = router.register(0).unwrap();
+
+        // events carry a client index rather than a context in the first field
+        assert!(router.route(50, Bytes::from_static(&[0, 0, 0, 0, 1])));
+        assert_eq!(first.recv().await.unwrap().unwrap().data[4], 1);
+        assert_eq!(second.recv().await.unwrap().unwrap().data[4], 1);
+
+        assert!(!router.unsubscribe(50, first_token));
+        assert!(router.unsubscribe(50, second_token));
+        // without subscribers the message id is routed by context again
+        assert!(router.route(50, Bytes::from_static(&[0, 0, 0, 0, 2])));
+        assert_eq!(waiter.await.unwrap().unwrap().msg_id, 50);
+    }
+
+    #[tokio::test]
+    async fn test_receive_leaves_replies_to_requests() {
+        let router = ReplyRouter::default();
+        let received = router.receive(10).unwrap();
+        let reply = router.register(4).unwrap();
+
+        assert!(router.route(10, Bytes::from_static(&[0, 0, 0, 4, 1])));
+        assert_eq!(reply.await.unwrap().unwrap().data[4], 1);
+        assert!(router.route(10, Bytes::from_static(&[0, 0, 0, 9, 2])));
+        assert_eq!(received.await.unwrap().unwrap().data[4], 2);
+
+        // a message that arrives before anybody asks for it is kept
+        assert!(!router.route(10, Bytes::from_static(&[0, 0, 0, 9, 3])));
+        let received = router.receive(10).unwrap();
+        assert_eq!(received.await.unwrap().unwrap().data[4], 3);
+
+        for i in 0..=UNSOLICITED_BACKLOG {
+            router.route(11, Bytes::from(vec![0, 0, 0, 9, i as u8]));
+        }
+        assert_eq!(router.dropped_events(), 1);
+        let received = router.receive(11).unwrap();
+        assert_eq!(received.await.unwrap().unwrap().data[4], 1);
+    }
+
+    #[tokio::test]
+    async fn test_fail_undelivered() {
+        let router = ReplyRouter::default();
+        let waiter = router.register(3).unwrap();
+        let (_, mut events) = router.subscribe(50).unwrap();
+
+        let dropped = TransportError::Connection("dropped".into());
+        router.fail_undelivered(50, &[0, 0, 0, 3], dropped.clone());
+        router.fail_undelivered(10, &[0, 0, 0, 3], dropped.clone());
+        assert!(router.route(50, Bytes::from_static(&[0, 0, 0, 3])));
+
+        let err = waiter.await.unwrap().unwrap_err();
+        assert_eq!(err, dropped);
+        assert!(events.recv().await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_close_fails_waiters() {
+        let router = ReplyRouter::default();
+        let waiter = router.register(1).unwrap();
+        let received = router.receive(10).unwrap();
+        router.close("eof".into());
+
+        assert!(waiter.await.unwrap().is_err());
+        assert!(received.await.unwrap().is_err());
+        assert!(router.register(2).is_err());
+        assert!(router.is_closed());
+        assert_eq!(router.closed().await, "eof");
+    }
+}
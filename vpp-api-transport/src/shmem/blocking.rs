@@ -1,16 +1,52 @@
+use crate::batch::{BatchResult, check_window};
+use crate::capture::{CaptureTap, Direction, Recorder};
+use crate::codec::encode_frame;
+use crate::dump::{DumpIds, decode_dump_reply};
+use crate::error::{Result, TransportError, check_retval, retval_error};
 use crate::helpers::*;
 use crate::message::*;
+use crate::router::reply_context;
 use crate::shmem::vac::*;
-use anyhow::{Result, anyhow};
-use bincode_next::config::BigEndian;
-use bincode_next::config::Configuration;
-use bincode_next::config::Fixint;
-use serde::Serialize;
-use serde::de::DeserializeOwned;
-use std::io::Cursor;
+use crate::transport::VppTransport;
+use bytes::Bytes;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use std::collections::HashMap;
 use std::os::raw::c_void;
-use std::sync::atomic::AtomicU32;
-use vpp_api_message::VppApiMessage;
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tracing::debug;
+use vpp_api_encoding::wire::{VppDecode, VppEncode};
+use vpp_api_message::{VppApiMessage, VppApiReply, VppApiWantMessage};
+
+/// Resolves a `name_crc` to the message id VPP assigned it.
+type Resolver = Box<dyn Fn(&str) -> Result<u16> + Send + Sync>;
+
+/// Sends the `want_*` message that disables an event when its stream is dropped.
+///
+/// The stream borrows the client, so the frame is encoded when subscribing and
+/// written straight to the shared memory queue, without waiting for the reply.
+struct DisableOnDrop {
+    /// The encoded `want_*` message with the enable flag cleared.
+    frame: Vec<u8>,
+    /// Records the frame if a capture is running.
+    capture: CaptureTap,
+    /// The `name_crc` of the `want_*` message, for logging.
+    name: String,
+}
+
+impl Drop for DisableOnDrop {
+    fn drop(&mut self) {
+        let frame = std::mem::take(&mut self.frame);
+        self.capture.record(Direction::Sent, &frame);
+        if let Err(e) = vac_write_wrapper(frame) {
+            debug!("failed to disable {}: {e}", self.name);
+        }
+    }
+}
 
 /// A blocking client for communicating with VPP through shared memory.
 ///
@@ -23,10 +59,18 @@ pub struct Client {
     context_id: AtomicU32,
     /// Timeout in seconds for blocking operations.
     timeout_seconds: u16,
-    /// Bincode serialization configuration for message encoding.
-    config: Configuration<BigEndian, Fixint>,
     /// Function to resolve message names to their VPP API indices.
-    resolver: Box<dyn Fn(String) -> Result<u16> + 'static>,
+    resolver: Resolver,
+    /// The message id of `memclnt_keepalive`, if VPP knows it.
+    keepalive_id: Option<u16>,
+    /// The number of keepalives answered while reading replies.
+    keepalives: AtomicU64,
+    /// Cleared once the client has disconnected from VPP.
+    connected: bool,
+    /// A read left running by a request that was cancelled; its result is consumed first.
+    pending_read: Option<JoinHandle<Result<Vec<u8>>>>,
+    /// Records the frames sent and received while a capture is running.
+    capture: CaptureTap,
 }
 
 impl Client {
@@ -50,60 +94,241 @@ impl Client {
         tokio::task::spawn_blocking(move || {
             vac_mem_init_wrapper();
             vac_set_error_handler_wrapper(Some(vac_error_handler));
-            vac_connect_wrapper(name, chroot_prefix, None, rx_qlen)?;
-            Ok::<(), anyhow::Error>(())
+            vac_connect_wrapper(name, chroot_prefix, None, rx_qlen)
         })
         .await??;
 
-        let resolve_id = |name: String| {
+        let resolve_id = |name: &str| {
             vac_get_msg_index_wrapper(name.to_owned())
-                .ok_or(anyhow!("Cannot find message id for {}", name))
+                .ok_or_else(|| TransportError::UnknownMessage(name.to_owned()))
         };
 
-        let config = Client::new_encoder();
+        let keepalive_id = resolve_id(&RawMemclntKeepalive::get_message_name_and_crc()).ok();
+
         Ok(Client {
             context_id: AtomicU32::new(1),
             client_index: 0,
-            config,
             timeout_seconds,
             resolver: Box::new(resolve_id),
+            keepalive_id,
+            keepalives: AtomicU64::new(0),
+            connected: true,
+            pending_read: None,
+            capture: CaptureTap::default(),
         })
     }
 
-    /// Creates a new bincode encoder configuration.
+    /// Sends a message and receives a reply in a single operation.
+    ///
+    /// Each read waits up to the timeout the client was connected with. Replies to
+    /// earlier requests that were cancelled or timed out are discarded.
+    ///
+    /// # Type Parameters
+    /// * `T` - The message type to send (must implement VppEncode and VppApiMessage)
+    /// * `R` - The expected reply type (must implement VppDecode and VppApiMessage)
+    ///
+    /// # Parameters
+    /// * `msg` - The message to send to VPP
     ///
     /// # Returns
-    /// A `Configuration` instance set up with big-endian byte order and fixed integer encoding.
-    fn new_encoder() -> Configuration<BigEndian, Fixint> {
-        bincode_next::config::legacy()
-            .with_big_endian()
-            .with_fixed_int_encoding()
+    /// A Result containing the received reply message or an error if the operation fails
+    pub async fn send_rcv<T, R>(&mut self, msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage,
+    {
+        self.request(msg, None).await
     }
 
-    /// Sends a message and receives a reply in a single operation.
+    /// Sends a message and receives a reply, failing if VPP reports an error.
     ///
     /// # Type Parameters
-    /// * `T` - The message type to send (must implement Serialize and VppApiMessage)
-    /// * `R` - The expected reply type (must implement DeserializeOwned and VppApiMessage)
+    /// * `T` - The message type to send (must implement VppEncode and VppApiMessage)
+    /// * `R` - The expected reply type (must implement VppDecode, VppApiMessage and VppApiReply)
     ///
     /// # Parameters
     /// * `msg` - The message to send to VPP
     ///
     /// # Returns
-    /// A Result containing the received reply message or an error if the operation fails
-    pub async fn send_rcv<T, R>(&mut self, msg: T) -> Result<R>
+    /// A Result containing the received reply message or an error if the operation fails;
+    /// a negative `retval` in the reply becomes a [`TransportError::Retval`]
+    pub async fn send_rcv_checked<T, R>(&mut self, msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage + VppApiReply,
+    {
+        check_retval::<T, R>(self.send_rcv(msg).await?)
+    }
+
+    /// Sends a batch of requests back-to-back and collects their replies.
+    ///
+    /// Up to `window` requests are in flight at once, each with its own context, so
+    /// a large batch costs about one round trip per window rather than one per request.
+    ///
+    /// Replies are read as they come and matched to their request by context;
+    /// other messages read meanwhile are discarded, as with [`Client::send_rcv`].
+    /// A request that cannot be written fails on its own, and a read that fails or
+    /// times out fails the requests in flight at the time; the batch goes on with
+    /// the rest, and late replies to the failed requests are discarded.
+    ///
+    /// # Type Parameters
+    /// * `T` - The message type to send (must implement VppEncode and VppApiMessage)
+    /// * `R` - The expected reply type (must implement VppDecode, VppApiMessage and VppApiReply)
+    ///
+    /// # Parameters
+    /// * `msgs` - The messages to send to VPP
+    /// * `window` - The most requests in flight at once, e.g. [`crate::batch::DEFAULT_BATCH_WINDOW`]
+    ///
+    /// # Returns
+    /// A Result containing the result of every request in order, or an error if `window`
+    /// is zero or VPP does not know the reply message
+    pub async fn send_rcv_batch<T, R, I>(
+        &mut self,
+        msgs: I,
+        window: usize,
+    ) -> Result<BatchResult<R>>
+    where
+        I: IntoIterator<Item = T>,
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage + VppApiReply,
+    {
+        check_window(window)?;
+        let expected_msg_id = self.get_message_index(&R::get_message_name_and_crc())?;
+        let mut msgs = msgs.into_iter();
+        let mut results: Vec<Option<Result<R>>> = vec![];
+        // the position in `results` of every request still waiting, by context
+        let mut in_flight = HashMap::new();
+        loop {
+            while in_flight.len() < window {
+                let Some(mut msg) = msgs.next() else {
+                    break;
+                };
+                let context = self.get_next_context();
+                msg.set_client_index(self.client_index);
+                msg.set_context(context);
+                if let Err(e) = self.write(&msg).await {
+                    results.push(Some(Err(e)));
+                    continue;
+                }
+                in_flight.insert(context, results.len());
+                results.push(None);
+            }
+            if in_flight.is_empty() {
+                break;
+            }
+            let (msg_id, data) = match self.read_message(None).await {
+                Ok(message) => message,
+                Err(e) => {
+                    for (_, index) in in_flight.drain() {
+                        results[index] = Some(Err(e.clone()));
+                    }
+                    continue;
+                }
+            };
+            let Some(index) = reply_context(&data).and_then(|context| in_flight.remove(&context))
+            else {
+                debug!("discarding message id {msg_id} outside of the batch");
+                continue;
+            };
+            results[index] = Some(if msg_id == expected_msg_id {
+                read_msg_from_slice(&data).and_then(check_retval::<T, R>)
+            } else {
+                Err(unexpected_message::<R>(msg_id, expected_msg_id))
+            });
+        }
+        Ok(BatchResult {
+            results: results.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Sends a message and waits at most `timeout` for the reply.
+    ///
+    /// A reply arriving after the deadline is discarded by a later request instead
+    /// of being taken as its answer.
+    ///
+    /// # Type Parameters
+    /// * `T` - The message type to send (must implement VppEncode and VppApiMessage)
+    /// * `R` - The expected reply type (must implement VppDecode and VppApiMessage)
+    ///
+    /// # Parameters
+    /// * `msg` - The message to send to VPP
+    /// * `timeout` - How long to wait for the reply
+    ///
+    /// # Returns
+    /// A Result containing the received reply message or an error if the operation fails or times out
+    pub async fn send_rcv_with_timeout<T, R>(&mut self, msg: T, timeout: Duration) -> Result<R>
     where
-        T: Serialize + VppApiMessage,
-        R: DeserializeOwned + VppApiMessage,
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage,
     {
-        self.send(msg).await?;
-        self.receive().await
+        self.request(msg, Some(Instant::now() + timeout)).await
+    }
+
+    /// Sends a message and reads until the reply carrying its context arrives.
+    ///
+    /// # Parameters
+    /// * `msg` - The message to send to VPP
+    /// * `deadline` - When to give up waiting, or `None` to use the client timeout for each read
+    ///
+    /// # Returns
+    /// A Result containing the received reply message or an error if the operation fails or times out
+    async fn request<T, R>(&mut self, mut msg: T, deadline: Option<Instant>) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage,
+    {
+        let expected_msg_id = self.get_message_index(&R::get_message_name_and_crc())?;
+        let (msg_id, data) = self.request_frame(&mut msg, deadline).await?;
+        if msg_id != expected_msg_id {
+            return Err(unexpected_message::<R>(msg_id, expected_msg_id));
+        }
+        read_msg_from_slice(&data)
+    }
+
+    /// Sends a request and returns the `retval` of its reply, whatever the reply type.
+    async fn request_retval<T>(&mut self, msg: &mut T) -> Result<i32>
+    where
+        T: VppEncode + VppApiMessage,
+    {
+        let (_, data) = self.request_frame(msg, None).await?;
+        let header: RawReplyHeader = read_msg_from_slice(&data)?;
+        Ok(header.retval)
+    }
+
+    /// Sends a message and returns the first frame carrying its context.
+    ///
+    /// # Parameters
+    /// * `msg` - The message to send to VPP; its client index and context are set here
+    /// * `deadline` - When to give up waiting, or `None` to use the client timeout for each read
+    ///
+    /// # Returns
+    /// A Result containing the message id and payload of the reply or an error if the operation fails or times out
+    async fn request_frame<T>(
+        &mut self,
+        msg: &mut T,
+        deadline: Option<Instant>,
+    ) -> Result<(u16, Bytes)>
+    where
+        T: VppEncode + VppApiMessage,
+    {
+        let context = self.get_next_context();
+        msg.set_client_index(self.client_index);
+        msg.set_context(context);
+        self.write(msg).await?;
+
+        loop {
+            let (msg_id, data) = self.read_message(deadline).await?;
+            if reply_context(&data) == Some(context) {
+                return Ok((msg_id, data));
+            }
+            debug!("discarding message id {msg_id} outside of request context {context}");
+        }
     }
 
     /// Sends a message to VPP through shared memory.
     ///
     /// # Type Parameters
-    /// * `T` - The message type to send (must implement Serialize and VppApiMessage)
+    /// * `T` - The message type to send (must implement VppEncode and VppApiMessage)
     ///
     /// # Parameters
     /// * `msg` - The message to send; will be modified to include client index and context ID
@@ -112,31 +337,233 @@ impl Client {
     /// A Result indicating success or an error if the send operation fails
     async fn send<T>(&mut self, mut msg: T) -> Result<()>
     where
-        T: Serialize + VppApiMessage,
+        T: VppEncode + VppApiMessage,
     {
         msg.set_client_index(self.client_index);
         msg.set_context(self.get_next_context());
+        self.write(&msg).await
+    }
+
+    /// Encodes a message and writes it to VPP through shared memory.
+    async fn write<T>(&self, msg: &T) -> Result<()>
+    where
+        T: VppEncode + VppApiMessage,
+    {
         let mut writer: Vec<u8> = Vec::new();
-        write_object(&mut writer, &msg, &self.resolver, self.config, false).await?;
+        write_object(&mut writer, msg, &self.resolver, false).await?;
+        self.capture.record(Direction::Sent, &writer);
         tokio::task::spawn_blocking(|| vac_write_wrapper(writer)).await?
     }
 
-    /// Receives a message from VPP through shared memory.
+    /// Sends a dump request and returns a stream of the details messages it produces.
+    ///
+    /// The dump is followed by a control ping with the same context; the stream ends when
+    /// the control ping reply arrives. Messages carrying a different context are skipped.
+    /// The stream borrows the client, so no other request can be interleaved with the dump.
     ///
     /// # Type Parameters
-    /// * `R` - The expected message type to receive (must implement DeserializeOwned and VppApiMessage)
+    /// * `T` - The dump message type to send
+    /// * `D` - The details message type produced by the dump
+    ///
+    /// # Parameters
+    /// * `msg` - The dump message to send to VPP
+    ///
+    /// # Returns
+    /// A Result containing a stream of decoded details messages or an error if sending fails
+    pub async fn send_dump<T, D>(&mut self, mut msg: T) -> Result<BoxStream<'_, Result<D>>>
+    where
+        T: VppEncode + VppApiMessage,
+        D: VppDecode + VppApiMessage + Send + 'static,
+    {
+        let ids = DumpIds {
+            details: self.get_message_index(&D::get_message_name_and_crc())?,
+            done: self.get_message_index(&RawControlPingReply::get_message_name_and_crc())?,
+        };
+        let context = self.get_next_context();
+        msg.set_client_index(self.client_index);
+        msg.set_context(context);
+        let mut ping = RawControlPing::default();
+        ping.set_client_index(self.client_index);
+        ping.set_context(context);
+        self.write(&msg).await?;
+        self.write(&ping).await?;
+
+        let details = futures::stream::try_unfold(self, move |client| async move {
+            loop {
+                let (msg_id, data) = client.read_message(None).await?;
+                if reply_context(&data) != Some(context) {
+                    debug!("skipping message id {msg_id} outside of dump context {context}");
+                    continue;
+                }
+                let details = decode_dump_reply(msg_id, &data, ids)?;
+                return Ok(details.map(|details| (details, client)));
+            }
+        });
+        Ok(details.boxed())
+    }
+
+    /// Receives the next message of type `R` from VPP through shared memory.
+    ///
+    /// Messages of other types that arrive first are discarded.
+    ///
+    /// # Type Parameters
+    /// * `R` - The expected message type to receive (must implement VppDecode and VppApiMessage)
     ///
     /// # Returns
     /// A Result containing the received message or an error if the receive operation fails or times out
     async fn receive<R>(&mut self) -> Result<R>
     where
-        R: DeserializeOwned + VppApiMessage,
+        R: VppDecode + VppApiMessage,
     {
-        let timeout_seconds = self.timeout_seconds;
-        let msg = tokio::task::spawn_blocking(move || vac_read_wrapper(timeout_seconds)).await??;
+        let expected_msg_id = self.get_message_index(&R::get_message_name_and_crc())?;
+        loop {
+            let (msg_id, data) = self.read_message(None).await?;
+            if msg_id == expected_msg_id {
+                return read_msg_from_slice(&data);
+            }
+            debug!("discarding message id {msg_id}, waiting for {expected_msg_id}");
+        }
+    }
 
-        let mut reader = Cursor::new(msg);
-        read_object(&mut reader, &self.resolver, self.config, false).await
+    /// Subscribes to an event and returns a stream of the events VPP sends.
+    ///
+    /// The `want` message is sent with its enable flag set and the pid of this
+    /// process. The stream borrows the client and reads until it is dropped,
+    /// discarding any other messages. When the stream is dropped, the `want`
+    /// message is sent again with the enable flag cleared; its reply is
+    /// discarded by the next read.
+    ///
+    /// # Type Parameters
+    /// * `W` - The `want_*` message that enables the event, e.g. `WantInterfaceEvents`
+    /// * `E` - The event message type
+    ///
+    /// # Parameters
+    /// * `want` - The message enabling the event
+    ///
+    /// # Returns
+    /// A Result containing a stream of decoded events or an error if VPP refused the subscription
+    pub async fn subscribe<W, E>(&mut self, mut want: W) -> Result<BoxStream<'_, Result<E>>>
+    where
+        W: VppEncode + VppApiMessage + VppApiWantMessage,
+        E: VppDecode + VppApiMessage + Send + 'static,
+    {
+        let event_id = self.get_message_index(&E::get_message_name_and_crc())?;
+        want.set_enable(true);
+        want.set_pid(std::process::id());
+        let retval = self.request_retval(&mut want).await?;
+        if retval != 0 {
+            return Err(retval_error::<W>(retval));
+        }
+        want.set_enable(false);
+        want.set_context(self.get_next_context());
+        let want_id = (self.resolver)(&W::get_message_name_and_crc())?;
+        let disable = DisableOnDrop {
+            frame: encode_frame(want_id, &want)?.to_vec(),
+            capture: self.capture.clone(),
+            name: W::get_message_name_and_crc(),
+        };
+
+        // the guard lives in the stream state, so it goes away with the stream
+        let events =
+            futures::stream::try_unfold((self, disable), move |(client, disable)| async move {
+                loop {
+                    let (msg_id, data) = match client.read_message(None).await {
+                        Ok(message) => message,
+                        // events may be far apart, a quiet period is not an error
+                        Err(TransportError::Timeout(_)) => continue,
+                        Err(e) => return Err(e),
+                    };
+                    if msg_id != event_id {
+                        debug!("skipping message id {msg_id} while waiting for events {event_id}");
+                        continue;
+                    }
+                    let event = read_msg_from_slice(&data)?;
+                    return Ok(Some((event, (client, disable))));
+                }
+            });
+        Ok(events.boxed())
+    }
+
+    /// Reads the next message from VPP, answering any keepalives that arrive first.
+    ///
+    /// # Parameters
+    /// * `deadline` - When to give up waiting, or `None` to use the client timeout
+    ///
+    /// # Returns
+    /// A Result containing the message id and payload or an error if the read fails or times out
+    async fn read_message(&mut self, deadline: Option<Instant>) -> Result<(u16, Bytes)> {
+        loop {
+            let timeout_seconds = match deadline {
+                Some(deadline) => timeout_seconds_until(deadline)?,
+                None => self.timeout_seconds,
+            };
+            let read = self.pending_read.get_or_insert_with(|| {
+                tokio::task::spawn_blocking(move || vac_read_wrapper(timeout_seconds))
+            });
+            // the read stays pending if this future is dropped, so its message is not lost
+            let msg = match deadline {
+                Some(deadline) => tokio::time::timeout_at(deadline, read)
+                    .await
+                    .map_err(|_| TransportError::Timeout("no reply from VPP arrived".into()))?,
+                None => read.await,
+            };
+            self.pending_read = None;
+            let msg = msg??;
+            self.capture.record(Direction::Received, &msg);
+            let (msg_id, data) = split_frame(msg.into())?;
+            if Some(msg_id) != self.keepalive_id {
+                return Ok((msg_id, data));
+            }
+            let keepalive: RawMemclntKeepalive = read_msg_from_slice(&data)?;
+            self.write(&RawMemclntKeepaliveReply::answering(&keepalive))
+                .await?;
+            self.keepalives.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns the number of `memclnt_keepalive` messages answered by this client.
+    pub fn keepalive_count(&self) -> u64 {
+        self.keepalives.load(Ordering::Relaxed)
+    }
+
+    /// Starts writing every frame sent and received by this client to a capture file.
+    ///
+    /// The shared memory API cannot list its message table, so the records are
+    /// named after the `messages` VPP knows. A capture already in progress is
+    /// closed and replaced.
+    ///
+    /// # Parameters
+    /// * `path` - Where to write the capture; an existing file is replaced
+    /// * `messages` - The `name_crc` of the messages to name the records with,
+    ///   e.g. the `MESSAGE_NAMES_AND_CRCS` of the bindings
+    pub fn start_recording<I>(&self, path: impl AsRef<Path>, messages: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let names = messages
+            .into_iter()
+            .filter_map(|name| {
+                let name = name.as_ref().to_string();
+                Some((self.get_message_index(&name).ok()?, name))
+            })
+            .collect();
+        self.capture.start(Recorder::create(path, names)?);
+        Ok(())
+    }
+
+    /// Stops recording and closes the capture file, if a capture is in progress.
+    pub fn stop_recording(&self) -> Result<()> {
+        self.capture.stop()
+    }
+
+    /// Sends a control ping message to VPP and waits for the reply.
+    ///
+    /// # Returns
+    /// A Result containing the return value from the control ping reply or an error if the request fails
+    pub async fn control_ping(&mut self) -> Result<i32> {
+        let reply: RawControlPingReply = self.send_rcv(RawControlPing::default()).await?;
+        Ok(reply.retval)
     }
 
     /// Sends a control ping message to VPP.
@@ -174,8 +601,7 @@ impl Client {
     /// # Returns
     /// A unique u32 context ID for the next message request
     fn get_next_context(&mut self) -> u32 {
-        self.context_id
-            .fetch_add(1, std::sync::atomic::Ordering::AcqRel)
+        self.context_id.fetch_add(1, Ordering::AcqRel)
     }
 
     /// Resolves a message name to its VPP API index.
@@ -185,14 +611,96 @@ impl Client {
     ///
     /// # Returns
     /// A Result containing the message index (u16) or an error if the message name cannot be resolved
-    pub fn get_message_index(&self, name: &String) -> Result<u16> {
-        self.resolver.as_ref()(name.to_owned())
+    pub fn get_message_index(&self, name: &str) -> Result<u16> {
+        self.resolver.as_ref()(name)
+    }
+
+    /// Deregisters the client from VPP and unmaps the shared memory.
+    ///
+    /// `vac_disconnect` sends `memclnt_delete` with the registration handle only
+    /// the client library knows and waits for VPP to confirm it, so the client is
+    /// gone from `show api clients` once this returns.
+    ///
+    /// # Returns
+    /// A Result indicating success or an error if VPP could not be told about the disconnect
+    pub async fn disconnect(mut self) -> Result<()> {
+        self.connected = false;
+        tokio::task::spawn_blocking(vac_disconnect_wrapper).await?
+    }
+}
+
+/// Converts a deadline into the whole-second timeout `vac_read` takes, rounding up.
+///
+/// # Returns
+/// The timeout in seconds, or an error if the deadline has already passed
+fn timeout_seconds_until(deadline: Instant) -> Result<u16> {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    if remaining.is_zero() {
+        return Err(TransportError::Timeout("no reply from VPP arrived".into()));
+    }
+    let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    Ok(seconds.try_into().unwrap_or(u16::MAX))
+}
+
+impl VppTransport for Client {
+    async fn send<T>(&mut self, msg: T) -> Result<()>
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+    {
+        Client::send(self, msg).await
+    }
+
+    async fn receive<R>(&mut self) -> Result<R>
+    where
+        R: VppDecode + VppApiMessage + Send,
+    {
+        Client::receive(self).await
+    }
+
+    async fn send_rcv<T, R>(&mut self, msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+        R: VppDecode + VppApiMessage + Send,
+    {
+        Client::send_rcv(self, msg).await
+    }
+
+    async fn send_dump<T, D>(&mut self, msg: T) -> Result<BoxStream<'_, Result<D>>>
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+        D: VppDecode + VppApiMessage + Send + 'static,
+    {
+        Client::send_dump(self, msg).await
+    }
+
+    async fn subscribe<W, E>(&mut self, want: W) -> Result<BoxStream<'_, Result<E>>>
+    where
+        W: VppEncode + VppApiMessage + VppApiWantMessage + Send + Sync + 'static,
+        E: VppDecode + VppApiMessage + Send + 'static,
+    {
+        Client::subscribe(self, want).await
+    }
+
+    fn get_message_index(&self, name: &str) -> Result<u16> {
+        Client::get_message_index(self, name)
+    }
+
+    async fn control_ping(&mut self) -> Result<i32> {
+        Client::control_ping(self).await
+    }
+
+    async fn run_cli_inband(&mut self, cmd: &str) -> Result<String> {
+        Client::run_cli_inband(self, cmd).await
     }
 }
 
 impl Drop for Client {
     fn drop(&mut self) {
-        vac_disconnect_wrapper()
+        if self.connected
+            && let Err(e) = vac_disconnect_wrapper()
+        {
+            debug!("disconnect on drop failed: {e}");
+        }
     }
 }
 /// Error handler callback for VAC (VPP API Client) errors.
@@ -230,4 +738,18 @@ mod tests {
         assert!(s.starts_with("vpp "));
         println!("\n {s}");
     }
+
+    #[tokio::test]
+    async fn test_timeout_seconds_until() {
+        let now = Instant::now();
+        assert_eq!(
+            timeout_seconds_until(now + Duration::from_millis(1500)).unwrap(),
+            2
+        );
+        assert_eq!(
+            timeout_seconds_until(now + Duration::from_secs(3)).unwrap(),
+            3
+        );
+        assert!(timeout_seconds_until(now).is_err());
+    }
 }
@@ -1,48 +1,172 @@
+use crate::batch::{BatchResult, run_windowed};
+use crate::capture::{CaptureTap, Direction, Recorder};
+use crate::dump::{DumpIds, decode_dump_reply};
+use crate::error::{Result, TransportError, check_retval, retval_error};
+use crate::events::EventStream;
 use crate::helpers::*;
 use crate::message::*;
+use crate::router::{PendingRequest, ReplyRouter};
 use crate::shmem::shmem_bindgen::*;
 use crate::shmem::vac::*;
-use anyhow::{Result, anyhow};
-use bincode_next::config::BigEndian;
-use bincode_next::config::Configuration;
-use bincode_next::config::Fixint;
-use serde::Serialize;
-use serde::de::DeserializeOwned;
-use std::io::Cursor;
+use crate::transport::VppTransport;
+use futures::StreamExt;
+use futures::stream::BoxStream;
 use std::os::raw::c_void;
-use std::sync::Arc;
-use std::sync::OnceLock;
-use std::sync::atomic::AtomicU32;
-use tokio::sync::Mutex;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use tokio::sync::mpsc;
-use vpp_api_message::VppApiMessage;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::task::JoinHandle;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::{debug, warn};
+use vpp_api_encoding::wire::{VppDecode, VppEncode};
+use vpp_api_message::{VppApiMessage, VppApiReply, VppApiWantMessage};
+
+/// Resolves a `name_crc` to the message id VPP assigned it.
+type Resolver = Box<dyn Fn(&str) -> Result<u16> + Send + Sync>;
+
+/// The number of messages buffered between VPP's receive thread and the client by default.
+pub const DEFAULT_QUEUE_DEPTH: usize = 64;
 
 /// A non-blocking asynchronous client for communicating with VPP via shared memory.
 ///
 /// This client manages the lifecycle of a connection to the VPP API and provides
 /// methods for sending and receiving messages asynchronously.
+///
+/// Messages from VPP's receive thread are buffered in a queue of configurable depth
+/// and routed by context to the request waiting for them, so clones of the client
+/// can have requests in flight at the same time. The receive thread never waits for
+/// room in the queue: when it is full the message is dropped, counted in
+/// [`Client::overflow_count`], and the request it was meant for fails.
+///
+/// The VPP client library supports a single shared memory connection per process;
+/// once it is disconnected, a new one can be made.
+#[derive(Clone)]
 pub struct Client {
+    inner: Arc<Inner>,
+}
+
+/// The connection state shared by all clones of a [`Client`].
+struct Inner {
     /// The client index assigned by VPP upon connection.
     client_index: u32,
     /// An atomic counter for generating unique context IDs for each message.
     context_id: AtomicU32,
-    /// The encoder configuration for serializing messages.
-    config: Configuration<BigEndian, Fixint>,
     /// A resolver function that maps message names to their message IDs.
-    resolver: Box<dyn Fn(String) -> Result<u16> + 'static>,
+    resolver: Resolver,
+    /// Waiters for in-flight requests, completed by the dispatcher task.
+    router: Arc<ReplyRouter>,
+    /// The queue filled by the receive thread.
+    queue: Arc<ReceiveQueue>,
+    /// The number of keepalives answered by the dispatcher task.
+    keepalives: Arc<AtomicU64>,
+    /// Records the frames sent and received while a capture is running.
+    capture: CaptureTap,
+    /// The task routing queued messages to their waiters.
+    dispatcher: JoinHandle<()>,
+    /// Cleared once the client has disconnected from VPP.
+    connected: AtomicBool,
+}
+
+impl Inner {
+    /// Stops the dispatcher task and fails every request still in flight.
+    ///
+    /// The receive queue is shared by all clones, so its sender outlives a
+    /// disconnect and the dispatcher would otherwise wait on it forever.
+    fn shut_down(&self) {
+        self.dispatcher.abort();
+        if !self.router.is_closed() {
+            self.router.close("disconnected".into());
+        }
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        if self.connected.swap(false, Ordering::AcqRel)
+            && let Err(e) = disconnect_from_vpp()
+        {
+            debug!("disconnect on drop failed: {e}");
+        }
+        self.shut_down();
+    }
+}
+
+/// The receive queue of the connected client.
+///
+/// The VPP client library passes no user data to [`vac_write_callback`], so the
+/// queue of the one connection a process can have is reachable through a static.
+static RECEIVE_QUEUE: RwLock<Option<Arc<ReceiveQueue>>> = RwLock::new(None);
+
+/// Buffers the messages VPP's receive thread hands to [`vac_write_callback`].
+struct ReceiveQueue {
+    /// The sending side of the queue read by the dispatcher task.
+    sender: mpsc::Sender<Vec<u8>>,
+    /// Used to fail the requests whose replies had to be dropped.
+    router: Arc<ReplyRouter>,
+    /// The number of messages dropped because the queue was full.
+    overflows: AtomicU64,
+}
+
+impl ReceiveQueue {
+    /// Queues a message without blocking the receive thread.
+    fn push(&self, msg: Vec<u8>) {
+        let msg = match self.sender.try_send(msg) {
+            Ok(()) | Err(TrySendError::Closed(_)) => return,
+            Err(TrySendError::Full(msg)) => msg,
+        };
+        self.overflows.fetch_add(1, Ordering::Relaxed);
+        warn!("receive queue full, dropping message from VPP");
+        if let Ok((msg_id, data)) = split_into_id_and_msg(&msg) {
+            self.router.fail_undelivered(
+                msg_id,
+                data,
+                TransportError::Connection(
+                    "receive queue overflow, the reply from VPP was dropped".into(),
+                ),
+            );
+        }
+    }
+}
+
+/// Answers `memclnt_keepalive` messages from the dispatcher task, so VPP does not
+/// consider the client dead while the application is idle.
+struct KeepaliveResponder {
+    /// The message id of `memclnt_keepalive`.
+    request_id: u16,
+    /// The message id of `memclnt_keepalive_reply`.
+    reply_id: u16,
+    /// The number of keepalives answered so far.
+    count: Arc<AtomicU64>,
+    /// Records the replies while a capture is running.
+    capture: CaptureTap,
+}
+
+impl KeepaliveResponder {
+    /// Replies to the keepalive carried in `data`.
+    async fn answer(&self, data: &[u8]) -> Result<()> {
+        let keepalive: RawMemclntKeepalive = read_msg_from_slice(data)?;
+        let reply = RawMemclntKeepaliveReply::answering(&keepalive);
+        let reply_id = |_: &str| Ok(self.reply_id);
+        let mut writer: Vec<u8> = Vec::new();
+        write_object(&mut writer, &reply, &reply_id, false).await?;
+        self.capture.record(Direction::Sent, &writer);
+        tokio::task::spawn_blocking(|| vac_write_wrapper(writer)).await??;
+        self.count.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
 }
 
 impl Client {
-    /// Connects to the VPP API via shared memory.
-    ///
-    /// Initializes a connection to the VPP daemon by setting up the global message queue
-    /// and performing all necessary initialization steps.
+    /// Connects to the VPP API via shared memory with a receive queue of [`DEFAULT_QUEUE_DEPTH`].
     ///
     /// # Arguments
     ///
     /// * `name` - The name identifier for this client connection.
     /// * `chroot_prefix` - Optional chroot prefix path for VPP API shared memory path.
-    /// * `rx_qlen` - The receive queue length for buffering incoming messages.
+    /// * `rx_qlen` - The length of the queue VPP uses to send messages to this client.
     ///
     /// # Returns
     ///
@@ -52,113 +176,496 @@ impl Client {
         chroot_prefix: Option<String>,
         rx_qlen: i32,
     ) -> Result<Client> {
-        let name = name.into();
+        Client::connect_with_queue_depth(name, chroot_prefix, rx_qlen, DEFAULT_QUEUE_DEPTH).await
+    }
 
-        init_global_queue();
+    /// Connects to the VPP API via shared memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name identifier for this client connection.
+    /// * `chroot_prefix` - Optional chroot prefix path for VPP API shared memory path.
+    /// * `rx_qlen` - The length of the queue VPP uses to send messages to this client.
+    /// * `queue_depth` - The number of received messages buffered until they are routed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the newly created `Client` instance or an error if connection
+    /// fails or another shared memory client is connected in this process.
+    pub async fn connect_with_queue_depth(
+        name: &str,
+        chroot_prefix: Option<String>,
+        rx_qlen: i32,
+        queue_depth: usize,
+    ) -> Result<Client> {
+        if queue_depth == 0 {
+            return Err(TransportError::InvalidRequest(
+                "the receive queue needs room for at least one message".into(),
+            ));
+        }
+        let router = Arc::new(ReplyRouter::default());
+        let (sender, receiver) = mpsc::channel(queue_depth);
+        let queue = Arc::new(ReceiveQueue {
+            sender,
+            router: router.clone(),
+            overflows: AtomicU64::new(0),
+        });
+        {
+            let mut active = RECEIVE_QUEUE.write().unwrap();
+            if active.is_some() {
+                return Err(TransportError::Connection(
+                    "a shared memory client is already connected in this process".into(),
+                ));
+            }
+            *active = Some(queue.clone());
+        }
 
-        tokio::task::spawn_blocking(move || {
+        let name = name.into();
+        let connected = tokio::task::spawn_blocking(move || {
             vac_mem_init_wrapper();
             vac_set_error_handler_wrapper(Some(vac_error_handler));
-            vac_connect_wrapper(name, chroot_prefix, Some(vac_write_callback), rx_qlen)?;
-            Ok::<(), anyhow::Error>(())
+            vac_connect_wrapper(name, chroot_prefix, Some(vac_write_callback), rx_qlen)
         })
-        .await??;
+        .await
+        .map_err(TransportError::from)
+        .and_then(|connected| connected);
+        if let Err(e) = connected {
+            RECEIVE_QUEUE.write().unwrap().take();
+            return Err(e);
+        }
 
-        let resolve_id = |name: String| {
+        let resolve_id = |name: &str| {
             vac_get_msg_index_wrapper(name.to_owned())
-                .ok_or(anyhow!("Cannot find message id for {}", name))
+                .ok_or_else(|| TransportError::UnknownMessage(name.to_owned()))
         };
 
-        let config = Client::new_encoder();
+        let keepalives = Arc::new(AtomicU64::new(0));
+        let capture = CaptureTap::default();
+        let keepalive = match (
+            resolve_id(&RawMemclntKeepalive::get_message_name_and_crc()),
+            resolve_id(&RawMemclntKeepaliveReply::get_message_name_and_crc()),
+        ) {
+            (Ok(request_id), Ok(reply_id)) => Some(KeepaliveResponder {
+                request_id,
+                reply_id,
+                count: keepalives.clone(),
+                capture: capture.clone(),
+            }),
+            _ => None,
+        };
+        let dispatcher = tokio::spawn(Client::dispatch(
+            receiver,
+            router.clone(),
+            keepalive,
+            capture.clone(),
+        ));
+
         Ok(Client {
-            context_id: AtomicU32::new(1),
-            client_index: 0,
-            config,
-            resolver: Box::new(resolve_id),
+            inner: Arc::new(Inner {
+                context_id: AtomicU32::new(1),
+                client_index: 0,
+                resolver: Box::new(resolve_id),
+                router,
+                queue,
+                keepalives,
+                capture,
+                dispatcher,
+                connected: AtomicBool::new(true),
+            }),
         })
     }
 
-    /// Creates a new encoder configuration for message serialization.
+    /// Takes messages off the receive queue and hands each one to the request waiting for it.
+    ///
+    /// Keepalives are answered right here and never reach the router. Runs until the
+    /// queue is closed by a disconnect, at which point all in-flight requests are failed.
+    async fn dispatch(
+        mut receiver: mpsc::Receiver<Vec<u8>>,
+        router: Arc<ReplyRouter>,
+        keepalive: Option<KeepaliveResponder>,
+        capture: CaptureTap,
+    ) {
+        while let Some(msg) = receiver.recv().await {
+            capture.record(Direction::Received, &msg);
+            match split_frame(msg.into()) {
+                Ok((msg_id, data)) => match &keepalive {
+                    Some(keepalive) if msg_id == keepalive.request_id => {
+                        if let Err(e) = keepalive.answer(&data).await {
+                            warn!("failed to answer keepalive: {e}");
+                        }
+                    }
+                    _ => {
+                        router.route(msg_id, data);
+                    }
+                },
+                Err(e) => warn!("discarding malformed message: {e}"),
+            }
+        }
+        router.close("disconnected".into());
+    }
+
+    /// Sends a message and receives the corresponding response asynchronously.
     ///
-    /// Configures the bincode encoder with big-endian byte order and fixed-size integer encoding.
+    /// The reply is matched by the context assigned to the request, so several
+    /// `send_rcv` calls may be in flight concurrently on clones of the same client.
+    /// If the returned future is dropped, the reply is discarded when it arrives.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send, must implement `VppEncode` and `VppApiMessage`.
     ///
     /// # Returns
     ///
-    /// A `Configuration` object with the appropriate settings for VPP message encoding.
-    fn new_encoder() -> Configuration<BigEndian, Fixint> {
-        bincode_next::config::legacy()
-            .with_big_endian()
-            .with_fixed_int_encoding()
+    /// A `Result` containing the response message or an error if the operation fails.
+    pub async fn send_rcv<T, R>(&self, mut msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage,
+    {
+        let expected_msg_id = self.get_message_index(&R::get_message_name_and_crc())?;
+        let context = self.get_next_context();
+        msg.set_client_index(self.inner.client_index);
+        msg.set_context(context);
+
+        let waiter = self.inner.router.register(context)?;
+        let _pending = PendingRequest {
+            router: &self.inner.router,
+            context,
+        };
+        self.write(&msg).await?;
+        let reply = waiter.await.map_err(|_| {
+            TransportError::Connection(format!("reply channel for context {context} closed"))
+        })??;
+
+        if reply.msg_id != expected_msg_id {
+            return Err(unexpected_message::<R>(reply.msg_id, expected_msg_id));
+        }
+        read_msg_from_slice(&reply.data)
     }
 
-    /// Sends a message and receives the corresponding response asynchronously.
-    ///
-    /// This method is a convenience wrapper that sends a message to VPP and waits for
-    /// the corresponding response in a single operation.
+    /// Sends a message and waits for the response, failing if VPP reports an error.
     ///
     /// # Arguments
     ///
-    /// * `msg` - The message to send, must implement `Serialize` and `VppApiMessage`.
+    /// * `msg` - The message to send, must implement `VppEncode` and `VppApiMessage`.
     ///
     /// # Returns
     ///
     /// A `Result` containing the response message or an error if the operation fails.
-    pub async fn send_rcv<T, R>(&mut self, msg: T) -> Result<R>
+    /// A negative `retval` in the reply becomes a [`TransportError::Retval`].
+    pub async fn send_rcv_checked<T, R>(&self, msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage + VppApiReply,
+    {
+        check_retval::<T, R>(self.send_rcv(msg).await?)
+    }
+
+    /// Sends a batch of requests back-to-back and collects their replies.
+    ///
+    /// Up to `window` requests are in flight at once, each with its own context, so
+    /// a large batch costs about one round trip per window rather than one per request.
+    ///
+    /// # Arguments
+    ///
+    /// * `msgs` - The requests to send, must implement `VppEncode` and `VppApiMessage`.
+    /// * `window` - The most requests in flight at once, e.g. [`crate::batch::DEFAULT_BATCH_WINDOW`].
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the result of every request in order, or an error if
+    /// `window` is zero.
+    pub async fn send_rcv_batch<T, R, I>(&self, msgs: I, window: usize) -> Result<BatchResult<R>>
     where
-        T: Serialize + VppApiMessage,
-        R: DeserializeOwned + VppApiMessage,
+        I: IntoIterator<Item = T>,
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage + VppApiReply,
     {
-        let receiver = get_global_receiver()?;
-        let mut receiver_guard = receiver.lock().await;
-        self.send(msg).await?;
-        self.receive(&mut receiver_guard).await
+        run_windowed(msgs, window, |msg| self.send_rcv_checked(msg)).await
     }
 
-    /// Sends a message to VPP asynchronously.
+    /// Sends a message and waits at most `timeout` for the response.
+    ///
+    /// A reply arriving after the deadline is discarded instead of being taken as the
+    /// answer to a later request.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send, must implement `VppEncode` and `VppApiMessage`.
+    /// * `timeout` - How long to wait for the reply.
     ///
-    /// Prepares the message with client index and context, serializes it using the configured
-    /// encoder, and sends it to VPP via the VAC interface on a blocking task.
+    /// # Returns
+    ///
+    /// A `Result` containing the response message or an error if the operation fails or times out.
+    pub async fn send_rcv_with_timeout<T, R>(&self, msg: T, timeout: Duration) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage,
+        R: VppDecode + VppApiMessage,
+    {
+        tokio::time::timeout(timeout, self.send_rcv(msg))
+            .await
+            .map_err(|_| {
+                TransportError::Timeout(format!(
+                    "no reply to {} within {timeout:?}",
+                    T::get_message_name_and_crc()
+                ))
+            })?
+    }
+
+    /// Sends a request and returns the `retval` of its reply, whatever the reply type.
     ///
     /// # Arguments
     ///
-    /// * `msg` - The message to send, must implement `Serialize` and `VppApiMessage`.
+    /// * `msg` - The message to send; its client index and context are set here.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `retval` of the reply or an error if the operation fails.
+    async fn request_retval<T>(&self, msg: &mut T) -> Result<i32>
+    where
+        T: VppEncode + VppApiMessage,
+    {
+        let context = self.get_next_context();
+        msg.set_client_index(self.inner.client_index);
+        msg.set_context(context);
+
+        let waiter = self.inner.router.register(context)?;
+        let _pending = PendingRequest {
+            router: &self.inner.router,
+            context,
+        };
+        self.write(msg).await?;
+        let reply = waiter.await.map_err(|_| {
+            TransportError::Connection(format!("reply channel for context {context} closed"))
+        })??;
+        let header: RawReplyHeader = read_msg_from_slice(&reply.data)?;
+        Ok(header.retval)
+    }
+
+    /// Sends a message to VPP without waiting for a reply.
+    ///
+    /// Any reply VPP sends for this message is discarded by the dispatcher task.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send, must implement `VppEncode` and `VppApiMessage`.
     ///
     /// # Returns
     ///
     /// A `Result` indicating success or an error if serialization or sending fails.
-    async fn send<T>(&mut self, mut msg: T) -> Result<()>
+    pub async fn send<T>(&self, mut msg: T) -> Result<()>
     where
-        T: Serialize + VppApiMessage,
+        T: VppEncode + VppApiMessage,
     {
-        msg.set_client_index(self.client_index);
+        msg.set_client_index(self.inner.client_index);
         msg.set_context(self.get_next_context());
+        self.write(&msg).await
+    }
+
+    /// Waits for the next message of type `R` that is not a reply to a request.
+    ///
+    /// A message no request was waiting for is kept for a while, so one that
+    /// arrived shortly before the call is returned too; use [`Client::subscribe`]
+    /// to receive events continuously.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the received message or an error if the client is disconnected.
+    pub async fn receive<R>(&self) -> Result<R>
+    where
+        R: VppDecode + VppApiMessage,
+    {
+        let msg_id = self.get_message_index(&R::get_message_name_and_crc())?;
+        let message = self.inner.router.receive(msg_id)?;
+        let message = message
+            .await
+            .map_err(|_| TransportError::Connection("connection closed".into()))??;
+        read_msg_from_slice(&message.data)
+    }
+
+    /// Encodes a message and writes it to VPP via the VAC interface on a blocking task.
+    ///
+    /// The blocking task runs to completion even if the caller is cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to write, with client index and context already set.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or an error if serialization or sending fails.
+    async fn write<T>(&self, msg: &T) -> Result<()>
+    where
+        T: VppEncode + VppApiMessage,
+    {
         let mut writer: Vec<u8> = Vec::new();
-        write_object(&mut writer, &msg, &self.resolver, self.config, false).await?;
+        write_object(&mut writer, msg, &self.inner.resolver, false).await?;
+        self.inner.capture.record(Direction::Sent, &writer);
         tokio::task::spawn_blocking(|| vac_write_wrapper(writer)).await?
     }
 
-    /// Receives a message from VPP asynchronously.
+    /// Sends a dump request and returns a stream of the details messages it produces.
     ///
-    /// Waits for a message from the global receiver queue and deserializes it using the configured
-    /// decoder.
+    /// The dump is followed by a control ping with the same context; the stream ends when
+    /// the control ping reply arrives. Replies for other contexts never appear in the stream.
     ///
     /// # Arguments
     ///
-    /// * `receiver` - A mutable reference to the message receiver queue.
+    /// * `msg` - The dump message to send, must implement `VppEncode` and `VppApiMessage`.
     ///
     /// # Returns
     ///
-    /// A `Result` containing the deserialized message or an error if reception or deserialization fails.
-    async fn receive<R>(&mut self, receiver: &mut tokio::sync::mpsc::Receiver<Vec<u8>>) -> Result<R>
+    /// A `Result` containing a stream of decoded details messages or an error if sending fails.
+    pub async fn send_dump<T, D>(&self, mut msg: T) -> Result<BoxStream<'static, Result<D>>>
     where
-        R: DeserializeOwned + VppApiMessage,
+        T: VppEncode + VppApiMessage,
+        D: VppDecode + VppApiMessage + Send + 'static,
     {
-        let msg = receiver
-            .recv()
-            .await
-            .ok_or(anyhow!("empty response received from global queue"))?;
-        let mut reader = Cursor::new(msg);
-        read_object(&mut reader, &self.resolver, self.config, false).await
+        let ids = DumpIds {
+            details: self.get_message_index(&D::get_message_name_and_crc())?,
+            done: self.get_message_index(&RawControlPingReply::get_message_name_and_crc())?,
+        };
+        let context = self.get_next_context();
+        msg.set_client_index(self.inner.client_index);
+        msg.set_context(context);
+        let mut ping = RawControlPing::default();
+        ping.set_client_index(self.inner.client_index);
+        ping.set_context(context);
+
+        let replies = self.inner.router.register_stream(context, ids.done)?;
+        let written = async {
+            self.write(&msg).await?;
+            self.write(&ping).await
+        };
+        if let Err(e) = written.await {
+            self.inner.router.unregister(context);
+            return Err(e);
+        }
+
+        let details = UnboundedReceiverStream::new(replies).filter_map(move |reply| {
+            let item = reply
+                .and_then(|reply| decode_dump_reply(reply.msg_id, &reply.data, ids))
+                .transpose();
+            futures::future::ready(item)
+        });
+        Ok(details.boxed())
+    }
+
+    /// Subscribes to an event and returns a stream of the events VPP sends.
+    ///
+    /// The `want` message is sent with its enable flag set and the pid of this
+    /// process. When the returned stream is dropped, and no other subscription for
+    /// the same event is active on this connection, the `want` message is sent
+    /// again with the enable flag cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `want` - The `want_*` message that enables the event, e.g. `WantInterfaceEvents`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a stream of decoded events or an error if VPP refused the subscription.
+    pub async fn subscribe<W, E>(&self, mut want: W) -> Result<EventStream<E>>
+    where
+        W: VppEncode + VppApiMessage + VppApiWantMessage + Send + Sync + 'static,
+        E: VppDecode + VppApiMessage + Send + 'static,
+    {
+        let event_id = self.get_message_index(&E::get_message_name_and_crc())?;
+        let (token, events) = self.inner.router.subscribe(event_id)?;
+
+        want.set_enable(true);
+        want.set_pid(std::process::id());
+        let retval = match self.request_retval(&mut want).await {
+            Ok(retval) => retval,
+            Err(e) => {
+                self.inner.router.unsubscribe(event_id, token);
+                return Err(e);
+            }
+        };
+        if retval != 0 {
+            self.inner.router.unsubscribe(event_id, token);
+            return Err(retval_error::<W>(retval));
+        }
+
+        let events = UnboundedReceiverStream::new(events)
+            .map(move |event| event.and_then(|event| read_msg_from_slice(&event.data)));
+
+        let client = self.clone();
+        Ok(EventStream::new(events.boxed(), move || {
+            if !client.inner.router.unsubscribe(event_id, token) {
+                return;
+            }
+            let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+                warn!("no runtime to disable {}", W::get_message_name_and_crc());
+                return;
+            };
+            runtime.spawn(async move {
+                want.set_enable(false);
+                if let Err(e) = client.request_retval(&mut want).await {
+                    debug!("failed to disable {}: {e}", W::get_message_name_and_crc());
+                }
+            });
+        }))
+    }
+
+    /// Returns the number of `memclnt_keepalive` messages answered by this client.
+    ///
+    /// # Returns
+    ///
+    /// The count of keepalives answered since the client connected.
+    pub fn keepalive_count(&self) -> u64 {
+        self.inner.keepalives.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of messages dropped because the receive queue was full.
+    ///
+    /// # Returns
+    ///
+    /// The count of dropped messages since the client connected.
+    pub fn overflow_count(&self) -> u64 {
+        self.inner.queue.overflows.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of messages dropped because nobody subscribed to or received them.
+    ///
+    /// # Returns
+    ///
+    /// The count of dropped events since the client connected.
+    pub fn dropped_event_count(&self) -> u64 {
+        self.inner.router.dropped_events()
+    }
+
+    /// Starts writing every frame sent and received on this connection to a capture file.
+    ///
+    /// The shared memory API cannot list its message table, so the records are
+    /// named after the `messages` VPP knows. A capture already in progress is
+    /// closed and replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Where to write the capture; an existing file is replaced
+    /// * `messages` - The `name_crc` of the messages to name the records with,
+    ///   e.g. the `MESSAGE_NAMES_AND_CRCS` of the bindings
+    pub fn start_recording<I>(&self, path: impl AsRef<Path>, messages: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let names = messages
+            .into_iter()
+            .filter_map(|name| {
+                let name = name.as_ref().to_string();
+                Some((self.get_message_index(&name).ok()?, name))
+            })
+            .collect();
+        let recorder = Recorder::create(path, names)?;
+        self.inner.capture.start(recorder);
+        Ok(())
+    }
+
+    /// Stops recording and closes the capture file, if a capture is in progress.
+    pub fn stop_recording(&self) -> Result<()> {
+        self.inner.capture.stop()
     }
 
     /// Sends a control ping message to VPP and retrieves the response.
@@ -168,9 +675,7 @@ impl Client {
     /// # Returns
     ///
     /// A `Result` containing the return value from the control ping response or an error if the operation fails.
-    /// Generates and returns the next unique context ID for a message.
-    ///
-    pub async fn control_ping(&mut self) -> Result<i32> {
+    pub async fn control_ping(&self) -> Result<i32> {
         let reply: RawControlPingReply = self.send_rcv(RawControlPing::default()).await?;
         Ok(reply.retval)
     }
@@ -187,7 +692,7 @@ impl Client {
     ///
     /// A `Result` containing the command output as a string or an error if the operation fails.
     ///
-    pub async fn run_cli_inband(&mut self, cmd: &str) -> Result<String> {
+    pub async fn run_cli_inband(&self, cmd: &str) -> Result<String> {
         let in_msg = RawCliInband::new(cmd)?;
         let out_msg: RawCliInbandReply = self.send_rcv(in_msg).await?;
         Ok(out_msg.reply.to_string())
@@ -200,9 +705,8 @@ impl Client {
     ///
     /// The next available context ID.
     ///
-    fn get_next_context(&mut self) -> u32 {
-        self.context_id
-            .fetch_add(1, std::sync::atomic::Ordering::AcqRel)
+    fn get_next_context(&self) -> u32 {
+        self.inner.context_id.fetch_add(1, Ordering::AcqRel)
     }
     ///
     /// Resolves a message name to its message ID.
@@ -217,70 +721,102 @@ impl Client {
     ///
     /// A `Result` containing the message ID or an error if the message name cannot be resolved.
     ///
-    pub fn get_message_index(&self, name: &String) -> Result<u16> {
-        self.resolver.as_ref()(name.to_owned())
+    pub fn get_message_index(&self, name: &str) -> Result<u16> {
+        self.inner.resolver.as_ref()(name)
     }
-}
 
-impl Drop for Client {
-    fn drop(&mut self) {
-        vac_disconnect_wrapper()
+    /// Deregisters the client from VPP and unmaps the shared memory.
+    ///
+    /// `vac_disconnect` sends `memclnt_delete` with the registration handle only
+    /// the client library knows and waits for VPP to confirm it, so the client is
+    /// gone from `show api clients` once this returns. The connection is closed for
+    /// all clones of the client: requests in flight on them fail, and a new client
+    /// can be connected afterwards.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or an error if VPP could not be told about the disconnect.
+    pub async fn disconnect(self) -> Result<()> {
+        let result = if self.inner.connected.swap(false, Ordering::AcqRel) {
+            tokio::task::spawn_blocking(disconnect_from_vpp)
+                .await
+                .map_err(TransportError::from)
+                .and_then(|result| result)
+        } else {
+            Ok(())
+        };
+        self.inner.shut_down();
+        result
     }
 }
 
-#[derive(Debug)]
-struct GlobalQueue {
-    sender: mpsc::Sender<Vec<u8>>,
-    receiver: Arc<Mutex<mpsc::Receiver<Vec<u8>>>>,
-}
+impl VppTransport for Client {
+    async fn send<T>(&mut self, msg: T) -> Result<()>
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+    {
+        Client::send(self, msg).await
+    }
 
-static QUEUE: OnceLock<GlobalQueue> = OnceLock::new();
+    async fn receive<R>(&mut self) -> Result<R>
+    where
+        R: VppDecode + VppApiMessage + Send,
+    {
+        Client::receive(self).await
+    }
 
-///
-/// Initializes the global message queue for inter-task communication.
-///
-/// Creates a new MPSC channel with a buffer capacity of 16 and stores it in a static OnceLock.
-/// This queue is used to route messages received from VPP to waiting tasks.
-pub fn init_global_queue() {
-    let (sender, receiver) = mpsc::channel::<Vec<u8>>(16);
-    let queue = GlobalQueue {
-        sender,
-        receiver: Arc::new(Mutex::new(receiver)),
-    };
-    QUEUE.get_or_init(|| queue);
-}
+    async fn send_rcv<T, R>(&mut self, msg: T) -> Result<R>
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+        R: VppDecode + VppApiMessage + Send,
+    {
+        Client::send_rcv(self, msg).await
+    }
 
-///
-/// Retrieves the global message receiver from the queue.
-///
-/// Returns a cloned reference to the mutex-protected receiver for receiving messages
-/// from VPP.
-pub fn get_global_receiver() -> Result<Arc<Mutex<mpsc::Receiver<Vec<u8>>>>> {
-    let queue = QUEUE.get().ok_or(anyhow!("global queue not initialized"))?;
-    Ok(queue.receiver.clone())
+    async fn send_dump<T, D>(&mut self, msg: T) -> Result<BoxStream<'_, Result<D>>>
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+        D: VppDecode + VppApiMessage + Send + 'static,
+    {
+        Client::send_dump(self, msg).await
+    }
+
+    async fn subscribe<W, E>(&mut self, want: W) -> Result<BoxStream<'_, Result<E>>>
+    where
+        W: VppEncode + VppApiMessage + VppApiWantMessage + Send + Sync + 'static,
+        E: VppDecode + VppApiMessage + Send + 'static,
+    {
+        Ok(Client::subscribe(self, want).await?.boxed())
+    }
+
+    fn get_message_index(&self, name: &str) -> Result<u16> {
+        Client::get_message_index(self, name)
+    }
+
+    async fn control_ping(&mut self) -> Result<i32> {
+        Client::control_ping(self).await
+    }
+
+    async fn run_cli_inband(&mut self, cmd: &str) -> Result<String> {
+        Client::run_cli_inband(self, cmd).await
+    }
 }
 
+/// Disconnects from VPP and releases the receive queue for the next connection.
 ///
-/// Retrieves the global message sender from the queue.
-///
-/// Returns a static reference to the sender for posting messages received from VPP
-/// into the global queue.
-///
-/// # Panics
-///
-/// Panics if the global queue has not been initialized.
-/// # Returns
-///
-/// A `Result` containing the receiver or an error if the global queue has not been initialized.
-pub fn get_global_sender() -> &'static mpsc::Sender<Vec<u8>> {
-    let queue = QUEUE.get().expect("global queue not initialized");
-    &queue.sender
+/// The receive thread never blocks on the queue, so `vac_disconnect` can stop it
+/// while messages are still arriving. The dispatcher task is stopped separately,
+/// by [`Inner::shut_down`].
+fn disconnect_from_vpp() -> Result<()> {
+    let result = vac_disconnect_wrapper();
+    RECEIVE_QUEUE.write().unwrap().take();
+    result
 }
 
-/// Callback function invoked by the VAC layer when data is ready to send to VPP.
+/// Callback function invoked by the VAC layer when a message from VPP has been received.
 ///
-/// Converts the raw data pointer to a vector, sends it through the global message sender,
-/// and frees the allocated memory.
+/// Copies the message into the receive queue of the connected client and frees the
+/// allocated memory. Never blocks: if the queue is full the message is dropped.
 ///
 /// # Arguments
 ///
@@ -294,12 +830,14 @@ pub fn get_global_sender() -> &'static mpsc::Sender<Vec<u8>> {
 ///
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn vac_write_callback(raw_data: *const u8, len: i32) {
-    let sender = get_global_sender();
     let msg = unsafe { std::slice::from_raw_parts(raw_data, len as usize) }.to_vec();
-    sender.blocking_send(msg).ok();
     unsafe {
         vac_free(raw_data as *mut c_void);
     }
+    match RECEIVE_QUEUE.read().unwrap().as_ref() {
+        Some(queue) => queue.push(msg),
+        None => debug!("dropping message received while not connected"),
+    }
 }
 
 /// Callback function invoked by the VAC layer when an error occurs.
@@ -329,7 +867,7 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 3)]
     async fn test_connect() {
-        let mut client = Client::connect("test_non_blocking_client", None, 32)
+        let client = Client::connect("test_non_blocking_client", None, 32)
             .await
             .unwrap();
 
@@ -339,5 +877,80 @@ mod tests {
         let s = client.run_cli_inband("show version").await.unwrap();
         assert!(s.starts_with("vpp "));
         println!("\n {s}");
+
+        // VPP does not answer a keepalive reply, so this request waits until the disconnect
+        let waiting = client.clone();
+        let pending = tokio::spawn(async move {
+            waiting
+                .send_rcv::<_, RawMemclntKeepaliveReply>(RawMemclntKeepaliveReply::default())
+                .await
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client.disconnect().await.unwrap();
+        let result = tokio::time::timeout(Duration::from_secs(5), pending).await;
+        assert!(result.unwrap().unwrap().is_err());
+
+        let client = Client::connect("test_non_blocking_client", None, 32)
+            .await
+            .unwrap();
+        let res = client.control_ping().await.unwrap();
+        assert_eq!(res, 0);
+    }
+
+    #[test]
+    fn test_queue_overflow_fails_request() {
+        let router = Arc::new(ReplyRouter::default());
+        let (sender, _receiver) = mpsc::channel(1);
+        let queue = ReceiveQueue {
+            sender,
+            router: router.clone(),
+            overflows: AtomicU64::new(0),
+        };
+        let mut waiter = router.register(7).unwrap();
+
+        queue.push(vec![0, 10, 0, 0, 0, 1]);
+        queue.push(vec![0, 10, 0, 0, 0, 7]);
+
+        assert_eq!(queue.overflows.load(Ordering::Relaxed), 1);
+        assert!(waiter.try_recv().unwrap().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_fails_waiting_clones() {
+        let router = Arc::new(ReplyRouter::default());
+        let (sender, receiver) = mpsc::channel(1);
+        let queue = Arc::new(ReceiveQueue {
+            sender,
+            router: router.clone(),
+            overflows: AtomicU64::new(0),
+        });
+        let dispatcher = tokio::spawn(Client::dispatch(
+            receiver,
+            router.clone(),
+            None,
+            CaptureTap::default(),
+        ));
+        // never connected to VPP, so disconnect only has to shut the client down
+        let client = Client {
+            inner: Arc::new(Inner {
+                client_index: 0,
+                context_id: AtomicU32::new(1),
+                resolver: Box::new(|_| Ok(10)),
+                router,
+                queue,
+                keepalives: Arc::new(AtomicU64::new(0)),
+                capture: CaptureTap::default(),
+                dispatcher,
+                connected: AtomicBool::new(false),
+            }),
+        };
+
+        let waiting = client.clone();
+        let pending = tokio::spawn(async move { waiting.receive::<RawControlPingReply>().await });
+        tokio::task::yield_now().await;
+        client.disconnect().await.unwrap();
+
+        let result = tokio::time::timeout(Duration::from_secs(5), pending).await;
+        assert!(result.unwrap().unwrap().is_err());
     }
 }
@@ -1,5 +1,5 @@
+use crate::error::{Result, TransportError};
 use crate::shmem::shmem_bindgen::*;
-use anyhow::{Result, anyhow};
 use std::ffi::CString;
 use std::os::raw::{c_int, c_ushort, c_void};
 
@@ -11,8 +11,14 @@ pub(crate) fn vac_set_error_handler_wrapper(callback: vac_error_callback_t) {
     unsafe { vac_set_error_handler(callback) };
 }
 
-pub(crate) fn vac_disconnect_wrapper() {
-    unsafe { vac_disconnect() };
+pub(crate) fn vac_disconnect_wrapper() -> Result<()> {
+    let rc = unsafe { vac_disconnect() };
+    if rc < 0 {
+        return Err(TransportError::Connection(format!(
+            "vac_disconnect returned {rc}"
+        )));
+    }
+    Ok(())
 }
 
 pub(crate) fn vac_connect_wrapper(
@@ -21,7 +27,7 @@ pub(crate) fn vac_connect_wrapper(
     callback: vac_callback_t,
     rx_qlen: i32,
 ) -> Result<()> {
-    let name_c = CString::new(name)?;
+    let name_c = CString::new(name).map_err(|e| TransportError::InvalidRequest(e.to_string()))?;
     let chroot_prefix_c = chroot_prefix.map(|x| CString::new(x).unwrap());
 
     let name_arg = name_c.as_ptr();
@@ -34,7 +40,9 @@ pub(crate) fn vac_connect_wrapper(
     let err = unsafe { vac_connect(name_arg, chroot_prefix_arg, callback, rx_qlen) };
 
     if err < 0 {
-        return Err(std::io::Error::other(format!("vac_connect returned {}", err)).into());
+        return Err(TransportError::Connection(format!(
+            "vac_connect returned {err}"
+        )));
     }
 
     Ok(())
@@ -44,7 +52,9 @@ pub(crate) fn vac_write_wrapper(msg: Vec<u8>) -> Result<()> {
     let wr_len = msg.len();
     let err = unsafe { vac_write(msg.as_ptr(), wr_len as i32) };
     if err < 0 {
-        return Err(anyhow!("vac_write returned {err}"));
+        return Err(TransportError::Connection(format!(
+            "vac_write returned {err}"
+        )));
     }
     Ok(())
 }
@@ -53,8 +63,15 @@ pub(crate) fn vac_read_wrapper(timeout: u16) -> Result<Vec<u8>> {
     let mut ptr: *mut u8 = std::ptr::null_mut();
     let mut len: c_int = 0;
     let rc = unsafe { vac_read(&mut ptr, &mut len, timeout as c_ushort) };
+    if rc == VacErrorNo::VacTimeout as c_int {
+        return Err(TransportError::Timeout(
+            "no message from VPP arrived".into(),
+        ));
+    }
     if rc < 0 {
-        return Err(anyhow!("vac_read returned {rc}"));
+        return Err(TransportError::Connection(format!(
+            "vac_read returned {rc}"
+        )));
     }
 
     if ptr.is_null() || len <= 0 {
@@ -0,0 +1,161 @@
+use crate::error::{Result, TransportError};
+use std::collections::HashMap;
+
+/// The message ids VPP assigned on a connection, by `name_crc`.
+///
+/// VPP sends the table when the client connects. Core messages keep their ids,
+/// but the messages of a plugin start wherever VPP placed the plugin, so ids
+/// must always be looked up here rather than hard-coded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageTable {
+    /// The message ids, by `name_crc`.
+    ids: HashMap<String, u16>,
+}
+
+impl MessageTable {
+    /// Returns the id of a message, or `None` if VPP does not know it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name_crc` - The name of the message followed by its CRC, e.g. `control_ping_51077d14`
+    pub fn get(&self, name_crc: &str) -> Option<u16> {
+        self.ids.get(name_crc).copied()
+    }
+
+    /// Returns the id of a message, or a [`TransportError::UnknownMessage`] if VPP does not know it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name_crc` - The name of the message followed by its CRC, e.g. `control_ping_51077d14`
+    pub fn id(&self, name_crc: &str) -> Result<u16> {
+        self.get(name_crc)
+            .ok_or_else(|| TransportError::UnknownMessage(name_crc.to_string()))
+    }
+
+    /// Returns every message in the table with its id, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u16)> + '_ {
+        self.ids.iter().map(|(name, id)| (name.as_str(), *id))
+    }
+
+    /// Returns the number of messages in the table.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `true` if the table has no messages.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Adds a message to the table, replacing the id it had.
+    pub(crate) fn insert(&mut self, name_crc: String, id: u16) {
+        self.ids.insert(name_crc, id);
+    }
+
+    /// Moves the messages of a module so that they start at `first_msg_id`.
+    ///
+    /// VPP numbers the messages of a module in the order the `.api` file
+    /// declares them, so the message at `index` in `messages` gets
+    /// `first_msg_id + index`. Messages the table does not hold are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - The `name_crc` of every message of the module in declaration order,
+    ///   e.g. the `MESSAGES` of a generated module
+    /// * `first_msg_id` - The id VPP reports for the first message of the module
+    ///
+    /// # Returns
+    ///
+    /// The number of messages that were moved.
+    pub(crate) fn rebase(&mut self, messages: &[&str], first_msg_id: u16) -> Result<usize> {
+        let fits = messages.len() <= usize::from(u16::MAX - first_msg_id) + 1;
+        if !fits {
+            return Err(TransportError::Incompatible(format!(
+                "{} messages do not fit at first message id {first_msg_id}",
+                messages.len()
+            )));
+        }
+        let mut moved = 0;
+        for (index, name) in messages.iter().enumerate() {
+            if let Some(id) = self.ids.get_mut(*name) {
+                *id = first_msg_id + index as u16;
+                moved += 1;
+            }
+        }
+        Ok(moved)
+    }
+}
+
+impl FromIterator<(String, u16)> for MessageTable {
+    fn from_iter<I: IntoIterator<Item = (String, u16)>>(iter: I) -> Self {
+        MessageTable {
+            ids: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rebase() {
+        let mut table: MessageTable = [
+            ("control_ping_51077d14".to_string(), 10),
+            ("acl_add_replace_ee5c2f18".to_string(), 600),
+            ("acl_del_ef34fea4".to_string(), 601),
+            ("acl_dump_ef34fea4".to_string(), 604),
+            ("macip_acl_add_ce6fbad0".to_string(), 610),
+            ("macip_acl_del_ef34fea4".to_string(), 611),
+        ]
+        .into_iter()
+        .collect();
+        let acl = [
+            "acl_add_replace_ee5c2f18",
+            "acl_del_ef34fea4",
+            "acl_details_95babae0",
+            "acl_dump_ef34fea4",
+            "macip_acl_add_ce6fbad0",
+            "macip_acl_del_ef34fea4",
+        ];
+
+        assert_eq!(table.rebase(&acl, 700).unwrap(), 5);
+        assert_eq!(table.get("acl_add_replace_ee5c2f18"), Some(700));
+        assert_eq!(table.get("acl_dump_ef34fea4"), Some(703));
+        assert_eq!(table.get("macip_acl_add_ce6fbad0"), Some(704));
+        assert_eq!(table.get("macip_acl_del_ef34fea4"), Some(705));
+        assert_eq!(table.get("control_ping_51077d14"), Some(10));
+        assert!(table.id("acl_details_95babae0").is_err());
+        assert!(table.rebase(&acl, u16::MAX - 4).is_err());
+        assert_eq!(table.rebase(&acl, u16::MAX - 5).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_rebase_keeps_modules_sharing_a_prefix_apart() {
+        let mut table: MessageTable = [
+            ("nat44_ed_plugin_enable_disable_be17f8dd".to_string(), 800),
+            ("nat44_ed_set_fq_options_2399bd71".to_string(), 801),
+            ("nat44_ei_plugin_enable_disable_bf692c7d".to_string(), 900),
+            ("nat44_ei_set_workers_da926638".to_string(), 901),
+        ]
+        .into_iter()
+        .collect();
+
+        let nat44_ed = [
+            "nat44_ed_plugin_enable_disable_be17f8dd",
+            "nat44_ed_plugin_enable_disable_reply_e8d4e804",
+            "nat44_ed_set_fq_options_2399bd71",
+        ];
+        assert_eq!(table.rebase(&nat44_ed, 1000).unwrap(), 2);
+        assert_eq!(
+            table.get("nat44_ed_plugin_enable_disable_be17f8dd"),
+            Some(1000)
+        );
+        assert_eq!(table.get("nat44_ed_set_fq_options_2399bd71"), Some(1002));
+        assert_eq!(
+            table.get("nat44_ei_plugin_enable_disable_bf692c7d"),
+            Some(900)
+        );
+        assert_eq!(table.get("nat44_ei_set_workers_da926638"), Some(901));
+    }
+}
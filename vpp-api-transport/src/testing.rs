@@ -0,0 +1,510 @@
+use crate::codec::encode_frame;
+use crate::error::TransportError;
+use crate::helpers::*;
+use crate::message::*;
+use crate::router::request_context;
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::task::{JoinHandle, JoinSet};
+use tracing::{debug, warn};
+use vpp_api_encoding::typ::VariableSizeArray;
+use vpp_api_encoding::wire::{VppDecode, VppEncode};
+use vpp_api_message::VppApiMessage;
+
+/// The messages every [`FakeVpp`] knows, in addition to the ones it is started with.
+const BUILTIN_MESSAGES: &[&str] = &[
+    "control_ping_51077d14",
+    "control_ping_reply_f6b0b8ca",
+    "memclnt_keepalive_51077d14",
+    "memclnt_keepalive_reply_e8d4e804",
+    "sockclnt_delete_8ac76db6",
+    "sockclnt_delete_reply_8f38b1ee",
+];
+
+/// Source of unique socket names for the servers of this process.
+static NEXT_SERVER: AtomicU32 = AtomicU32::new(0);
+
+/// Answers a request with the encoded reply frames, each starting with the message id.
+///
+/// Shared so it can be called after the handler table is unlocked.
+type Handler = Arc<dyn Fn(&[u8]) -> Result<Vec<Vec<u8>>> + Send + Sync>;
+
+/// An in-process stand-in for the VPP API socket, for tests that cannot rely on a running VPP.
+///
+/// The server listens on a Unix socket in the temp directory and speaks the same
+/// protocol as `/run/vpp/api.sock`: it performs the `sockclnt_create` handshake,
+/// sending its message table, answers `control_ping` and `sockclnt_delete`, and
+/// hands every other request to the handler registered for its type. Requests
+/// without a handler are dropped, as VPP does with messages it does not know.
+///
+/// ```no_run
+/// # async fn example() -> anyhow::Result<()> {
+/// use vpp_api_transport::afunix::client::Client;
+/// use vpp_api_transport::testing::FakeVpp;
+///
+/// let vpp = FakeVpp::start()?;
+/// let client = Client::connect(vpp.path(), "test").await?;
+/// assert_eq!(client.control_ping().await?, 0);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The server and all its connections stop when the `FakeVpp` is dropped.
+pub struct FakeVpp {
+    /// The path of the listening socket.
+    path: PathBuf,
+    /// The state shared with the connection tasks.
+    state: Arc<State>,
+    /// The task accepting connections; owns the connection tasks.
+    server: JoinHandle<()>,
+}
+
+/// The state shared by the server and its connection tasks.
+struct State {
+    /// The message table sent during the handshake, by `name_crc`.
+    messages: HashMap<String, u16>,
+    /// Handlers for requests, by request message id.
+    handlers: Mutex<HashMap<u16, Handler>>,
+    /// Source of the client indexes assigned during the handshake.
+    next_client_index: AtomicU32,
+}
+
+impl FakeVpp {
+    /// Starts a server that only knows the messages it answers itself.
+    ///
+    /// Must be called from within a tokio runtime.
+    ///
+    /// # Returns
+    ///
+    /// The running server, or an error if the socket cannot be created.
+    pub fn start() -> Result<FakeVpp> {
+        FakeVpp::start_with_messages(std::iter::empty::<&str>())
+    }
+
+    /// Starts a server whose message table also contains `messages`.
+    ///
+    /// Message ids are assigned in the order the messages are given, with
+    /// `sockclnt_create` and its reply at the fixed ids the handshake uses.
+    /// Must be called from within a tokio runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - The `name_crc` of every message VPP should claim to know,
+    ///   e.g. the `MESSAGE_NAMES_AND_CRCS` of a set of bindings
+    ///
+    /// # Returns
+    ///
+    /// The running server, or an error if the socket cannot be created.
+    pub fn start_with_messages<I>(messages: I) -> Result<FakeVpp>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let reserved = [
+            MsgSockClntCreate::get_message_id(),
+            MsgSockClntCreateReplyHdr::get_message_id(),
+        ];
+        let mut table = HashMap::from([
+            (
+                MsgSockClntCreate::get_message_name_and_crc(),
+                MsgSockClntCreate::get_message_id(),
+            ),
+            (
+                MsgSockClntCreateReplyHdr::get_message_name_and_crc(),
+                MsgSockClntCreateReplyHdr::get_message_id(),
+            ),
+        ]);
+        let names = BUILTIN_MESSAGES
+            .iter()
+            .map(|name| name.to_string())
+            .chain(messages.into_iter().map(|name| name.as_ref().to_string()));
+        let mut ids = (1..).filter(|id| !reserved.contains(id));
+        for name in names {
+            if let Entry::Vacant(entry) = table.entry(name) {
+                entry.insert(ids.next().ok_or(anyhow!("too many messages"))?);
+            }
+        }
+
+        let path = std::env::temp_dir().join(format!(
+            "vpp-api-fake-{}-{}.sock",
+            std::process::id(),
+            NEXT_SERVER.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::remove_file(&path).ok();
+        let listener = UnixListener::bind(&path)?;
+
+        let state = Arc::new(State {
+            messages: table,
+            handlers: Mutex::new(HashMap::new()),
+            next_client_index: AtomicU32::new(1),
+        });
+        let server = tokio::spawn(FakeVpp::serve(listener, state.clone()));
+
+        Ok(FakeVpp {
+            path,
+            state,
+            server,
+        })
+    }
+
+    /// Returns the path of the socket to connect the client to.
+    pub fn path(&self) -> &str {
+        self.path.to_str().unwrap_or_default()
+    }
+
+    /// Returns the id of a message in the message table, if the server knows it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The `name_crc` of the message
+    pub fn message_id(&self, name: &str) -> Option<u16> {
+        self.state.messages.get(name).copied()
+    }
+
+    /// Answers every `Req` with the reply `handler` returns for it.
+    ///
+    /// The context of the reply is set to the one of the request. A handler
+    /// registered for the same request replaces the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - Builds the reply to a decoded request
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request or reply message is not in the message table.
+    pub fn on<Req, Rep>(&self, handler: impl Fn(Req) -> Rep + Send + Sync + 'static)
+    where
+        Req: VppDecode + VppApiMessage,
+        Rep: VppEncode + VppApiMessage,
+    {
+        let reply_id = self.known_message_id::<Rep>();
+        self.add_handler::<Req>(Arc::new(move |data| {
+            let context = context_of(data)?;
+            let mut reply = handler(read_msg_from_slice(data)?);
+            reply.set_context(context);
+            Ok(vec![encode_message(reply_id, &reply)?])
+        }));
+    }
+
+    /// Answers every dump request `Req` with the details messages `handler` returns for it.
+    ///
+    /// The client ends the dump with a `control_ping`, which the server answers
+    /// after the details, so the dump completes like it does against VPP.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - Builds the details messages for a decoded dump request
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dump or details message is not in the message table.
+    pub fn on_dump<Req, D>(&self, handler: impl Fn(Req) -> Vec<D> + Send + Sync + 'static)
+    where
+        Req: VppDecode + VppApiMessage,
+        D: VppEncode + VppApiMessage,
+    {
+        let details_id = self.known_message_id::<D>();
+        self.add_handler::<Req>(Arc::new(move |data| {
+            let context = context_of(data)?;
+            handler(read_msg_from_slice(data)?)
+                .into_iter()
+                .map(|mut details| {
+                    details.set_context(context);
+                    encode_message(details_id, &details)
+                })
+                .collect()
+        }));
+    }
+
+    /// Registers `handler` for the request message `Req`.
+    fn add_handler<Req: VppApiMessage>(&self, handler: Handler) {
+        let request_id = self.known_message_id::<Req>();
+        self.state
+            .handlers
+            .lock()
+            .unwrap()
+            .insert(request_id, handler);
+    }
+
+    /// Returns the id of the message `T`, which tests must have put in the message table.
+    fn known_message_id<T: VppApiMessage>(&self) -> u16 {
+        let name = T::get_message_name_and_crc();
+        self.message_id(&name)
+            .unwrap_or_else(|| panic!("{name} is not in the message table of the fake VPP"))
+    }
+
+    /// Accepts connections until the server is dropped.
+    async fn serve(listener: UnixListener, state: Arc<State>) {
+        // dropping the set when the server is aborted stops all connections
+        let mut connections = JoinSet::new();
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let state = state.clone();
+                    connections.spawn(async move {
+                        if let Err(e) = state.serve_connection(stream).await {
+                            debug!("fake VPP connection ended: {e}");
+                        }
+                    });
+                }
+                Err(e) => {
+                    warn!("fake VPP stopped accepting connections: {e}");
+                    return;
+                }
+            }
+            while connections.try_join_next().is_some() {}
+        }
+    }
+}
+
+impl Drop for FakeVpp {
+    fn drop(&mut self) {
+        self.server.abort();
+        std::fs::remove_file(&self.path).ok();
+    }
+}
+
+impl State {
+    /// Performs the handshake and answers requests until the client goes away.
+    async fn serve_connection(&self, mut stream: UnixStream) -> Result<()> {
+        let frame = read_frame(&mut stream, true).await?;
+        let (msg_id, data) = split_into_id_and_msg(&frame)?;
+        if msg_id != MsgSockClntCreate::get_message_id() {
+            return Err(anyhow!("expected sockclnt_create, got message id {msg_id}"));
+        }
+        let create: MsgSockClntCreate = read_msg_from_slice(data)?;
+        let client_index = self.next_client_index.fetch_add(1, Ordering::Relaxed);
+        let message_table = self
+            .messages
+            .iter()
+            .map(|(name, index)| {
+                Ok(MessageTableEntry {
+                    index: *index,
+                    name: name.as_str().try_into().map_err(|e| anyhow!("{e}"))?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let reply = MsgSockClntCreateReplyHdr {
+            client_index,
+            context: create.context,
+            response: 0,
+            index: client_index,
+            count: message_table.len() as u16,
+            message_table: VariableSizeArray(message_table),
+        };
+        let resolve_id = |name: &str| {
+            self.messages
+                .get(name)
+                .copied()
+                .ok_or_else(|| TransportError::UnknownMessage(name.to_owned()))
+        };
+        write_object(&mut stream, &reply, &resolve_id, true).await?;
+
+        loop {
+            let frame = read_frame(&mut stream, true).await?;
+            let (msg_id, data) = split_into_id_and_msg(&frame)?;
+            for reply in self.answer(msg_id, data, client_index)? {
+                write_frame(&mut stream, &reply, true).await?;
+            }
+            if Some(msg_id)
+                == self
+                    .messages
+                    .get(&RawSockclntDelete::get_message_name_and_crc())
+                    .copied()
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Returns the reply frames for a request, using the registered handlers first.
+    fn answer(&self, msg_id: u16, data: &[u8], client_index: u32) -> Result<Vec<Vec<u8>>> {
+        // the lock is released first, so the handler may register handlers itself
+        let handler = self.handlers.lock().unwrap().get(&msg_id).cloned();
+        if let Some(handler) = handler {
+            return handler(data);
+        }
+        let name = self
+            .messages
+            .iter()
+            .find(|(_, id)| **id == msg_id)
+            .map(|(name, _)| name.as_str())
+            .unwrap_or_default();
+        let context = context_of(data)?;
+        let id_of = |name: String| self.messages[&name];
+        let reply = if name == RawControlPing::get_message_name_and_crc() {
+            let reply = RawControlPingReply {
+                context,
+                retval: 0,
+                client_index,
+                vpe_pid: std::process::id(),
+            };
+            encode_message(
+                id_of(RawControlPingReply::get_message_name_and_crc()),
+                &reply,
+            )?
+        } else if name == RawSockclntDelete::get_message_name_and_crc() {
+            let reply = RawSockclntDeleteReply {
+                context,
+                response: 0,
+            };
+            encode_message(
+                id_of(RawSockclntDeleteReply::get_message_name_and_crc()),
+                &reply,
+            )?
+        } else {
+            if name != RawMemclntKeepaliveReply::get_message_name_and_crc() {
+                warn!("fake VPP has no handler for message id {msg_id} {name}");
+            }
+            return Ok(vec![]);
+        };
+        Ok(vec![reply])
+    }
+}
+
+/// Extracts the context of a request payload.
+fn context_of(data: &[u8]) -> Result<u32> {
+    request_context(data).ok_or(anyhow!("request too short to carry a context"))
+}
+
+/// Encodes a message with its id in front, ready to be framed.
+fn encode_message<T: VppEncode>(msg_id: u16, msg: &T) -> Result<Vec<u8>> {
+    Ok(encode_frame(msg_id, msg)?.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::afunix::client::Client;
+    use futures::TryStreamExt;
+    use vpp_api_macros::{VppDecode, VppEncode};
+
+    #[derive(Debug, Clone, VppEncode, VppDecode)]
+    struct ItemDump {
+        client_index: u32,
+        context: u32,
+        count: u32,
+    }
+
+    #[derive(Debug, Clone, VppEncode, VppDecode)]
+    struct ItemDetails {
+        context: u32,
+        value: u32,
+    }
+
+    impl VppApiMessage for ItemDump {
+        fn get_message_name_and_crc() -> String {
+            "item_dump_00000001".into()
+        }
+
+        fn set_context(&mut self, context: u32) {
+            self.context = context;
+        }
+
+        fn set_client_index(&mut self, client_index: u32) {
+            self.client_index = client_index;
+        }
+    }
+
+    impl VppApiMessage for ItemDetails {
+        fn get_message_name_and_crc() -> String {
+            "item_details_00000002".into()
+        }
+
+        fn set_context(&mut self, context: u32) {
+            self.context = context;
+        }
+
+        fn set_client_index(&mut self, _client_index: u32) {}
+    }
+
+    #[tokio::test]
+    async fn test_fake_vpp() {
+        let vpp = FakeVpp::start_with_messages([
+            "cli_inband_f8377302",
+            "cli_inband_reply_05879051",
+            "item_dump_00000001",
+            "item_details_00000002",
+        ])
+        .unwrap();
+        vpp.on::<RawCliInband, _>(|req| RawCliInbandReply {
+            context: 0,
+            retval: 0,
+            reply: format!("vpp {}", req.cmd).as_str().try_into().unwrap(),
+        });
+        vpp.on_dump::<ItemDump, _>(|req| {
+            (0..req.count)
+                .map(|value| ItemDetails { context: 0, value })
+                .collect()
+        });
+
+        let client = Client::connect(vpp.path(), "fake-client").await.unwrap();
+        assert_eq!(client.control_ping().await.unwrap(), 0);
+        assert_eq!(
+            client.control_ping_reply().await.unwrap().vpe_pid,
+            std::process::id()
+        );
+        assert_eq!(
+            client.run_cli_inband("show version").await.unwrap(),
+            "vpp show version"
+        );
+
+        let dump = ItemDump {
+            client_index: 0,
+            context: 0,
+            count: 3,
+        };
+        let items: Vec<ItemDetails> = client
+            .send_dump(dump)
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items.iter().map(|i| i.value).collect::<Vec<_>>(), [0, 1, 2]);
+
+        client.disconnect().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_handler_touching_the_fake() {
+        let vpp = Arc::new(
+            FakeVpp::start_with_messages(["cli_inband_f8377302", "cli_inband_reply_05879051"])
+                .unwrap(),
+        );
+        // weak, so the server still stops when the test drops it
+        let fake = Arc::downgrade(&vpp);
+        vpp.on::<RawCliInband, _>(move |_| {
+            // replaces this handler while it runs
+            fake.upgrade()
+                .unwrap()
+                .on::<RawCliInband, _>(|_| RawCliInbandReply {
+                    context: 0,
+                    retval: 0,
+                    reply: "second".try_into().unwrap(),
+                });
+            RawCliInbandReply {
+                context: 0,
+                retval: 0,
+                reply: "first".try_into().unwrap(),
+            }
+        });
+
+        let client = Client::connect(vpp.path(), "fake-client").await.unwrap();
+        assert_eq!(
+            client.run_cli_inband("show version").await.unwrap(),
+            "first"
+        );
+        assert_eq!(
+            client.run_cli_inband("show version").await.unwrap(),
+            "second"
+        );
+        client.disconnect().await.unwrap();
+    }
+}
@@ -0,0 +1,243 @@
+use crate::error::{Result, TransportError};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// The size of the header VPP writes at the start of a trace file.
+const TRACE_HEADER_LEN: usize = 10;
+
+/// A message recorded in a VPP API trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceMessage {
+    /// The message id the message was sent with.
+    pub msg_id: u16,
+    /// The `name_crc` of the message, if the id is in the message table of the trace.
+    pub name_crc: Option<String>,
+    /// The encoded message, without the message id.
+    pub payload: Vec<u8>,
+}
+
+/// An API trace saved by VPP with `api trace save <file>`.
+///
+/// The file starts with a packed header of an endian byte, a wrapped flag, the
+/// number of messages and the size of the message table, the counts in network
+/// byte order. The message table VPP had at the time follows, serialized with
+/// the clib serializer, and then every message, each preceded by its length.
+/// The messages themselves are in network byte order, like on the API socket.
+#[derive(Debug, Clone, Default)]
+pub struct ApiTrace {
+    /// Whether the trace buffer wrapped, i.e. the oldest messages were lost.
+    pub wrapped: bool,
+    /// The message table of VPP, by message id.
+    pub message_table: HashMap<u16, String>,
+    /// The traced messages, oldest first.
+    pub messages: Vec<TraceMessage>,
+}
+
+impl ApiTrace {
+    /// Reads a trace file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The file written by `api trace save`, usually in `/tmp`
+    ///
+    /// # Returns
+    ///
+    /// The parsed trace, or an error if the file cannot be read or is malformed.
+    pub fn read(path: impl AsRef<Path>) -> Result<ApiTrace> {
+        ApiTrace::parse(&std::fs::read(path).map_err(invalid)?)
+    }
+
+    /// Parses the contents of a trace file.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The whole trace file
+    ///
+    /// # Returns
+    ///
+    /// The parsed trace, or an error if the data is truncated or malformed.
+    pub fn parse(data: &[u8]) -> Result<ApiTrace> {
+        let mut header = Cursor::new(data.get(..TRACE_HEADER_LEN).ok_or_else(|| {
+            invalid(format!(
+                "trace file too short for its header: {} bytes",
+                data.len()
+            ))
+        })?);
+        let _endian = header.u8()?;
+        let wrapped = header.u8()? != 0;
+        let nitems = header.u32()?;
+        let msgtbl_size = header.u32()? as usize;
+
+        let mut body = Cursor::new(&data[TRACE_HEADER_LEN..]);
+        let message_table = parse_message_table(body.take(msgtbl_size)?)?;
+
+        let mut messages = Vec::with_capacity(nitems.min(1 << 16) as usize);
+        for index in 0..nitems {
+            let length = body.u32()? as usize;
+            let message = body
+                .take(length)
+                .map_err(|e| invalid(format!("message {index}: {e}")))?;
+            if message.len() < 2 {
+                return Err(invalid(format!(
+                    "message {index} too short for a message id"
+                )));
+            }
+            let msg_id = u16::from_be_bytes([message[0], message[1]]);
+            messages.push(TraceMessage {
+                msg_id,
+                name_crc: message_table.get(&msg_id).cloned(),
+                payload: message[2..].to_vec(),
+            });
+        }
+        if !body.is_empty() {
+            return Err(invalid(format!(
+                "{} unexpected bytes after {nitems} messages",
+                body.remaining()
+            )));
+        }
+
+        Ok(ApiTrace {
+            wrapped,
+            message_table,
+            messages,
+        })
+    }
+}
+
+/// Parses the message table as written by `vl_api_serialize_message_table`.
+///
+/// It is a `u32` count followed by an index and a name for every message.
+fn parse_message_table(data: &[u8]) -> Result<HashMap<u16, String>> {
+    let mut table = Cursor::new(data);
+    let count = table.u32()?;
+    let mut messages = HashMap::new();
+    for _ in 0..count {
+        let index = table.small_unsigned()?;
+        let index = u16::try_from(index)
+            .map_err(|_| invalid(format!("message index {index} too large")))?;
+        let length = table.small_unsigned()? as usize;
+        let name = String::from_utf8_lossy(table.take(length)?).into_owned();
+        messages.insert(index, name);
+    }
+    Ok(messages)
+}
+
+/// Builds the error reading a malformed trace fails with.
+fn invalid(e: impl std::fmt::Display) -> TransportError {
+    TransportError::InvalidCapture(e.to_string())
+}
+
+/// Reads the values of a trace file from a byte slice.
+struct Cursor<'a> {
+    /// The bytes not read yet.
+    data: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Cursor { data }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Takes the next `n` bytes.
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        if n > self.data.len() {
+            return Err(invalid(format!(
+                "truncated trace: need {n} bytes, {} left",
+                self.data.len()
+            )));
+        }
+        let (taken, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    /// Takes the next `N` bytes as an array.
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(self.take(N)?);
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Reads a `u32` in network byte order.
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.bytes()?))
+    }
+
+    /// Reads a value written by `serialize_likely_small_unsigned_integer`.
+    ///
+    /// The low bits of the first byte tell the width: `1` for one byte, `10` for
+    /// two and `100` for four bytes, little endian, and a zero byte for a full
+    /// `u64` that follows. Each wider form is offset by the range of the narrower ones.
+    fn small_unsigned(&mut self) -> Result<u64> {
+        let first = self
+            .data
+            .first()
+            .copied()
+            .ok_or_else(|| invalid("truncated trace"))?;
+        if first & 1 == 1 {
+            self.take(1)?;
+            return Ok(u64::from(first >> 1));
+        }
+        if first & 3 == 2 {
+            let value = u16::from_le_bytes(self.bytes()?);
+            return Ok(u64::from(value >> 2) + (1 << 7));
+        }
+        if first & 7 == 4 {
+            let value = u32::from_le_bytes(self.bytes()?);
+            return Ok(u64::from(value >> 3) + (1 << 7) + (1 << 14));
+        }
+        self.take(1)?;
+        let value = u64::from_le_bytes(self.bytes()?);
+        Ok(value + (1 << 7) + (1 << 14) + (1 << 29))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_trace() {
+        let mut table = vec![0, 0, 0, 2];
+        table.extend([1 + 2 * 12, 1 + 2 * 21]);
+        table.extend(b"control_ping_51077d14");
+        // index 300 does not fit in one byte: 4 * (300 - 128) + 2, little endian
+        table.extend(((300 - 128) * 4 + 2u16).to_le_bytes());
+        table.extend([1 + 2 * 14]);
+        table.extend(b"show_version_1");
+
+        let mut data = vec![1, 1, 0, 0, 0, 2];
+        data.extend((table.len() as u32).to_be_bytes());
+        data.extend(&table);
+        for message in [vec![0, 12, 0, 0, 0, 1, 0, 0, 0, 9], vec![0, 99, 7]] {
+            data.extend((message.len() as u32).to_be_bytes());
+            data.extend(message);
+        }
+
+        let trace = ApiTrace::parse(&data).unwrap();
+        assert!(trace.wrapped);
+        assert_eq!(trace.message_table[&300], "show_version_1");
+        assert_eq!(trace.messages.len(), 2);
+        assert_eq!(
+            trace.messages[0].name_crc.as_deref(),
+            Some("control_ping_51077d14")
+        );
+        assert_eq!(trace.messages[0].payload, [0, 0, 0, 1, 0, 0, 0, 9]);
+        assert_eq!(trace.messages[1].msg_id, 99);
+        assert_eq!(trace.messages[1].name_crc, None);
+
+        assert!(ApiTrace::parse(&data[..data.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::error::{Result, check_retval};
+use futures::stream::BoxStream;
+use std::future::Future;
+use vpp_api_encoding::wire::{VppDecode, VppEncode};
+use vpp_api_message::{VppApiMessage, VppApiReply, VppApiWantMessage};
+
+/// The operations every VPP API client supports, whatever the transport.
+///
+/// Implemented by the afunix socket client and both shared memory clients, so
+/// application code can be written once against `impl VppTransport` and run over
+/// either transport. Methods take `&mut self` because the shared memory clients
+/// need exclusive access; the afunix client only needs a shared reference and
+/// can be cloned instead.
+pub trait VppTransport {
+    /// Sends a message without waiting for a reply.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to send; its client index and context are filled in
+    fn send<T>(&mut self, msg: T) -> impl Future<Output = Result<()>> + Send
+    where
+        T: VppEncode + VppApiMessage + Send + Sync;
+
+    /// Waits for the next message of type `R` that is not a reply to a request
+    /// made through [`VppTransport::send_rcv`], [`VppTransport::send_dump`] or
+    /// [`VppTransport::subscribe`].
+    fn receive<R>(&mut self) -> impl Future<Output = Result<R>> + Send
+    where
+        R: VppDecode + VppApiMessage + Send;
+
+    /// Sends a request and waits for its reply.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The request to send
+    fn send_rcv<T, R>(&mut self, msg: T) -> impl Future<Output = Result<R>> + Send
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+        R: VppDecode + VppApiMessage + Send;
+
+    /// Sends a request and waits for its reply, failing if VPP reports an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The request to send
+    ///
+    /// # Returns
+    ///
+    /// The reply, or an error. A negative `retval` in the reply becomes a
+    /// [`crate::TransportError::Retval`].
+    fn send_rcv_checked<T, R>(&mut self, msg: T) -> impl Future<Output = Result<R>> + Send
+    where
+        Self: Send,
+        T: VppEncode + VppApiMessage + Send + Sync,
+        R: VppDecode + VppApiMessage + VppApiReply + Send,
+    {
+        async move { check_retval::<T, R>(self.send_rcv(msg).await?) }
+    }
+
+    /// Sends a dump request and returns a stream of the details messages it produces.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The dump request to send
+    fn send_dump<T, D>(
+        &mut self,
+        msg: T,
+    ) -> impl Future<Output = Result<BoxStream<'_, Result<D>>>> + Send
+    where
+        T: VppEncode + VppApiMessage + Send + Sync,
+        D: VppDecode + VppApiMessage + Send + 'static;
+
+    /// Enables an event with its `want_*` message and returns a stream of the events.
+    ///
+    /// # Arguments
+    ///
+    /// * `want` - The `want_*` message that enables the event, e.g. `WantInterfaceEvents`
+    fn subscribe<W, E>(
+        &mut self,
+        want: W,
+    ) -> impl Future<Output = Result<BoxStream<'_, Result<E>>>> + Send
+    where
+        W: VppEncode + VppApiMessage + VppApiWantMessage + Send + Sync + 'static,
+        E: VppDecode + VppApiMessage + Send + 'static;
+
+    /// Returns the id VPP assigned to the message with the given `name_crc`.
+    fn get_message_index(&self, name: &str) -> Result<u16>;
+
+    /// Sends a control ping and returns the `retval` of the reply.
+    fn control_ping(&mut self) -> impl Future<Output = Result<i32>> + Send;
+
+    /// Executes a VPP CLI command and returns the output.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The CLI command to execute
+    fn run_cli_inband(&mut self, cmd: &str) -> impl Future<Output = Result<String>> + Send;
+}